@@ -0,0 +1,868 @@
+//! Generate `src/builtins/functions.rs` and `src/builtins/versions.rs` from
+//! the Vim and Neovim help
+//!
+//! Usage:
+//!   cargo run --example gen_builtins -- functions <vim-runtime> <neovim-runtime> [<api-metadata>] > src/builtins/functions.rs
+//!   cargo run --example gen_builtins -- versions <vim-runtime> [<neovim-runtime>] > src/builtins/versions.rs
+//!
+//! # Functions
+//!
+//! Every `name()` tag in `doc/tags` whose help entry starts with a signature
+//! is a builtin function. Descriptions come from the summary table of
+//! `builtin.txt` (`:help function-list`), or the first sentence of the entry.
+//! A function is `Common` when both editors document it.
+//!
+//! The `nvim_*` functions come from Neovim's `api.txt` like any other entry.
+//! The API metadata, written by `nvim --api-info > api.msgpack` (or as JSON by
+//! `nvim --headless -c 'call writefile([json_encode(api_info())], "api.json")' -c q`),
+//! adds those the help is missing, with the parameter names of their metadata.
+//! Deprecated and internal (`nvim__*`) functions are left out.
+//!
+//! # Versions
+//!
+//! Vim's `version*.txt` has a section for each release listing the functions,
+//! options and autocmd events it added, followed by the patches made since the
+//! previous release. An item gets the first release mentioning it, narrowed
+//! down to the first of those patches whose solution adds it. Items of Vim 7.0
+//! and older are left out, as are Neovim versions of items Neovim inherited from
+//! Vim 7.4. Neovim versions are those of the API functions, from the "Since:"
+//! attribute of their help.
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Path, PathBuf};
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    match args.split_first() {
+        Some((command, args)) if command == "functions" => functions(args),
+        Some((command, args)) if command == "versions" => versions(args),
+        _ => usage(),
+    }
+}
+
+fn usage() -> ! {
+    eprintln!("usage: gen_builtins functions <vim-runtime> <neovim-runtime> [<api-metadata>]");
+    eprintln!("       gen_builtins versions <vim-runtime> [<neovim-runtime>]");
+    std::process::exit(2);
+}
+
+/// Help files whose function tags are not builtin functions
+const SKIPPED_FILES: &[&str] = &["deprecated.txt", "news.txt", "todo.txt", "vi_diff.txt"];
+
+struct Function {
+    signature: String,
+    description: String,
+}
+
+/// Print `functions.rs`
+fn functions(args: &[String]) {
+    let (vim, neovim, api_metadata) = match args {
+        [vim, neovim] => (vim, neovim, None),
+        [vim, neovim, api_metadata] => (vim, neovim, Some(api_metadata)),
+        _ => usage(),
+    };
+    let vim = read_functions(Path::new(vim));
+    let mut neovim = read_functions(Path::new(neovim));
+    if let Some(path) = api_metadata {
+        for (name, function) in read_api_functions(Path::new(path)) {
+            neovim.entry(name).or_insert(function);
+        }
+    }
+
+    let mut names: Vec<&String> = vim.keys().chain(neovim.keys()).collect();
+    names.sort();
+    names.dedup();
+
+    println!("//! Builtin functions of Vim and Neovim");
+    println!("//!");
+    println!("//! Generated from their help by `examples/gen_builtins.rs`. Regenerate with");
+    println!(
+        "//! `just gen-builtins <vim-runtime> <neovim-runtime> [<api-metadata>]` instead of editing by hand."
+    );
+    println!();
+    println!("use super::{{Availability, BuiltinFunction}};");
+    println!();
+    println!("/// List of Vim and Neovim builtin functions");
+    println!("/// Reference: :help function-list");
+    println!("pub static BUILTIN_FUNCTIONS: &[BuiltinFunction] = &[");
+    for name in names {
+        let (function, availability) = match (vim.get(name), neovim.get(name)) {
+            (Some(function), Some(_)) => (function, "Common"),
+            (Some(function), None) => (function, "VimOnly"),
+            (None, Some(function)) => (function, "NeovimOnly"),
+            (None, None) => unreachable!(),
+        };
+        println!("    BuiltinFunction {{");
+        println!("        name: {:?},", name);
+        println!("        signature: {:?},", function.signature);
+        println!("        description: {:?},", function.description);
+        println!("        availability: Availability::{},", availability);
+        println!("    }},");
+    }
+    println!("];");
+}
+
+/// Read the builtin functions documented in a runtime directory
+fn read_functions(runtime: &Path) -> BTreeMap<String, Function> {
+    let doc = runtime.join("doc");
+    let tags = std::fs::read_to_string(doc.join("tags")).unwrap_or_else(|e| {
+        eprintln!("error: {}: {}", doc.join("tags").display(), e);
+        std::process::exit(1);
+    });
+    let summaries = std::fs::read_to_string(doc.join("builtin.txt"))
+        .map(|content| parse_summary_table(&content))
+        .unwrap_or_default();
+
+    let mut files: BTreeMap<PathBuf, String> = BTreeMap::new();
+    let mut functions = BTreeMap::new();
+    for line in tags.lines() {
+        let mut fields = line.split('\t');
+        let (Some(tag), Some(file)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        let Some(name) = tag.strip_suffix("()") else {
+            continue;
+        };
+        if !is_function_name(name) || SKIPPED_FILES.contains(&file) {
+            continue;
+        }
+        let path = doc.join(file);
+        let content = files
+            .entry(path.clone())
+            .or_insert_with(|| std::fs::read_to_string(&path).unwrap_or_default());
+        let Some((signature, first_sentence)) = parse_entry(content, name) else {
+            continue;
+        };
+        let description = summaries.get(name).cloned().unwrap_or(first_sentence);
+        functions.insert(
+            name.to_string(),
+            Function {
+                signature,
+                description: capitalize(&description),
+            },
+        );
+    }
+    functions
+}
+
+/// Read the public, non-deprecated API functions from `api_info()` metadata,
+/// as MessagePack or JSON
+fn read_api_functions(path: &Path) -> BTreeMap<String, Function> {
+    let metadata: serde_json::Value = std::fs::read(path)
+        .map_err(|e| e.to_string())
+        .and_then(|bytes| {
+            if bytes.starts_with(b"{") {
+                serde_json::from_slice(&bytes).map_err(|e| e.to_string())
+            } else {
+                decode_msgpack(&mut bytes.as_slice())
+                    .ok_or_else(|| "invalid MessagePack".to_string())
+            }
+        })
+        .unwrap_or_else(|e| {
+            eprintln!("error: {}: {}", path.display(), e);
+            std::process::exit(1);
+        });
+    let mut functions = BTreeMap::new();
+    for function in metadata["functions"].as_array().into_iter().flatten() {
+        let Some(name) = function["name"].as_str() else {
+            continue;
+        };
+        if !name.starts_with("nvim_")
+            || name.starts_with("nvim__")
+            || !function["deprecated_since"].is_null()
+        {
+            continue;
+        }
+        // Parameters are [type, name] pairs
+        let parameters: Vec<String> = function["parameters"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|parameter| parameter[1].as_str())
+            .map(|parameter| format!("{{{}}}", parameter))
+            .collect();
+        functions.insert(
+            name.to_string(),
+            Function {
+                signature: format!("{}({})", name, parameters.join(", ")),
+                description: "Neovim API function".to_string(),
+            },
+        );
+    }
+    functions
+}
+
+/// Decode a MessagePack value. Binary and extension values, which the API
+/// metadata doesn't use, decode to null
+fn decode_msgpack(bytes: &mut &[u8]) -> Option<serde_json::Value> {
+    use serde_json::Value;
+    let marker = *take(bytes, 1)?.first()?;
+    let value = match marker {
+        0x00..=0x7f => Value::from(marker),
+        0x80..=0x8f => decode_map(bytes, usize::from(marker & 0x0f))?,
+        0x90..=0x9f => decode_array(bytes, usize::from(marker & 0x0f))?,
+        0xa0..=0xbf => decode_str(bytes, usize::from(marker & 0x1f))?,
+        0xc0 => Value::Null,
+        0xc2 => Value::Bool(false),
+        0xc3 => Value::Bool(true),
+        0xc4..=0xc6 => {
+            let len = take_len(bytes, marker - 0xc4)?;
+            take(bytes, len)?;
+            Value::Null
+        }
+        0xc7..=0xc9 => {
+            // The length excludes the type byte
+            let len = take_len(bytes, marker - 0xc7)?;
+            take(bytes, len + 1)?;
+            Value::Null
+        }
+        0xca => Value::from(f32::from_bits(take_uint(bytes, 4)? as u32)),
+        0xcb => Value::from(f64::from_bits(take_uint(bytes, 8)?)),
+        0xcc..=0xcf => Value::from(take_uint(bytes, 1 << (marker - 0xcc))?),
+        0xd0..=0xd3 => {
+            let size = 1 << (marker - 0xd0);
+            let shift = 64 - 8 * size;
+            Value::from(((take_uint(bytes, size)? << shift) as i64) >> shift)
+        }
+        0xd4..=0xd8 => {
+            take(bytes, 1 + (1 << (marker - 0xd4)))?;
+            Value::Null
+        }
+        0xd9..=0xdb => {
+            let len = take_len(bytes, marker - 0xd9)?;
+            decode_str(bytes, len)?
+        }
+        0xdc..=0xdd => {
+            let len = take_len(bytes, marker - 0xdc + 1)?;
+            decode_array(bytes, len)?
+        }
+        0xde..=0xdf => {
+            let len = take_len(bytes, marker - 0xde + 1)?;
+            decode_map(bytes, len)?
+        }
+        0xe0..=0xff => Value::from(marker as i8),
+        0xc1 => return None,
+    };
+    Some(value)
+}
+
+fn decode_str(bytes: &mut &[u8], len: usize) -> Option<serde_json::Value> {
+    Some(
+        String::from_utf8_lossy(take(bytes, len)?)
+            .into_owned()
+            .into(),
+    )
+}
+
+fn decode_array(bytes: &mut &[u8], len: usize) -> Option<serde_json::Value> {
+    (0..len)
+        .map(|_| decode_msgpack(bytes))
+        .collect::<Option<Vec<_>>>()
+        .map(serde_json::Value::Array)
+}
+
+fn decode_map(bytes: &mut &[u8], len: usize) -> Option<serde_json::Value> {
+    let mut map = serde_json::Map::new();
+    for _ in 0..len {
+        let key = match decode_msgpack(bytes)? {
+            serde_json::Value::String(key) => key,
+            key => key.to_string(),
+        };
+        map.insert(key, decode_msgpack(bytes)?);
+    }
+    Some(serde_json::Value::Object(map))
+}
+
+/// Take a big-endian length of `1 << size_log2` bytes
+fn take_len(bytes: &mut &[u8], size_log2: u8) -> Option<usize> {
+    usize::try_from(take_uint(bytes, 1 << size_log2)?).ok()
+}
+
+fn take_uint(bytes: &mut &[u8], size: usize) -> Option<u64> {
+    Some(
+        take(bytes, size)?
+            .iter()
+            .fold(0, |value, &byte| value << 8 | u64::from(byte)),
+    )
+}
+
+fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Option<&'a [u8]> {
+    if bytes.len() < len {
+        return None;
+    }
+    let (taken, rest) = bytes.split_at(len);
+    *bytes = rest;
+    Some(taken)
+}
+
+/// Builtin function names are lowercase (user functions start uppercase)
+fn is_function_name(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_lowercase())
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+/// Parse the `USAGE RESULT DESCRIPTION` table: name to description
+fn parse_summary_table(content: &str) -> BTreeMap<String, String> {
+    let mut summaries = BTreeMap::new();
+    let Some(start) = content.find("USAGE\t") else {
+        return summaries;
+    };
+    let table = &content[start..];
+    let table = &table[..table.find("\n==").unwrap_or(table.len())];
+
+    // Join continuation lines (starting with a tab) to their entry
+    let mut entries: Vec<String> = Vec::new();
+    for line in table.lines().skip(1) {
+        if line.starts_with('\t') {
+            if let Some(last) = entries.last_mut() {
+                last.push('\t');
+                last.push_str(line.trim());
+            }
+        } else if !line.trim().is_empty() {
+            entries.push(line.to_string());
+        }
+    }
+
+    for entry in entries {
+        let Some(paren) = entry.find('(') else {
+            continue;
+        };
+        let name = &entry[..paren];
+        let Some(close) = matching_paren(&entry, paren) else {
+            continue;
+        };
+        // The result type is separated from the description by a tab or spaces
+        let rest = entry[close + 1..].trim();
+        let description = rest
+            .split_once('\t')
+            .or_else(|| rest.split_once("  "))
+            .map(|(_, description)| description)
+            .unwrap_or("");
+        let description = description.split_whitespace().collect::<Vec<_>>().join(" ");
+        if is_function_name(name) && !description.is_empty() {
+            summaries.entry(name.to_string()).or_insert(description);
+        }
+    }
+    summaries
+}
+
+/// Find the signature and the first sentence of a function's help entry
+fn parse_entry(content: &str, name: &str) -> Option<(String, String)> {
+    let tag = format!("*{}()*", name);
+    let lines: Vec<&str> = content.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.split_whitespace().any(|word| word == tag))?;
+
+    // The signature is on the tag line, or on a line right below it
+    let prefix = format!("{}(", name);
+    let (index, line) = lines
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(3)
+        .find(|(_, line)| line.starts_with(&prefix))?;
+    // Long signatures continue on the next lines
+    let mut heading = line.to_string();
+    let mut index = index;
+    while matching_paren(&heading, name.len()).is_none() && index + 1 < lines.len().min(start + 6) {
+        index += 1;
+        heading.push(' ');
+        heading.push_str(lines[index].trim());
+    }
+    let close = matching_paren(&heading, name.len())?;
+    let signature = heading[..=close].to_string();
+
+    // The description follows the signature, up to the first period
+    let mut text = heading[close + 1..]
+        .split('*')
+        .next()
+        .unwrap_or("")
+        .trim()
+        .to_string();
+    for line in &lines[index + 1..] {
+        if line.trim().is_empty() || line.trim_end().ends_with('*') {
+            break;
+        }
+        text.push(' ');
+        text.push_str(line.trim());
+    }
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let sentence = match text.find(". ") {
+        Some(end) => &text[..end],
+        None => text.trim_end_matches('.'),
+    };
+    Some((signature, sentence.to_string()))
+}
+
+/// Find the byte index of the parenthesis closing the one at `open`
+fn matching_paren(text: &str, open: usize) -> Option<usize> {
+    let mut depth = 0;
+    for (i, c) in text.char_indices().skip_while(|(i, _)| *i < open) {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+fn capitalize(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Oldest Vim release recorded
+const OLDEST_VIM: (u32, u32) = (7, 1);
+
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Kind {
+    Function,
+    Option,
+    Event,
+}
+
+/// Names of the functions, options and events documented in a runtime
+#[derive(Default)]
+struct Names {
+    functions: BTreeSet<String>,
+    options: BTreeSet<String>,
+    events: BTreeSet<String>,
+}
+
+impl Names {
+    fn contains(&self, (kind, name): &(Kind, String)) -> bool {
+        match kind {
+            Kind::Function => self.functions.contains(name),
+            Kind::Option => self.options.contains(name),
+            Kind::Event => self.events.contains(name),
+        }
+    }
+}
+
+/// Items of a release, and the patches made for it
+struct Release {
+    version: String,
+    items: Vec<(Kind, String)>,
+    patches: Vec<Patch>,
+}
+
+/// A patch of the release notes
+#[derive(Default)]
+struct Patch {
+    number: String,
+    solution: String,
+    /// Whether the patch changes source files, not only the help
+    changes_source: bool,
+}
+
+/// Print `versions.rs`
+fn versions(args: &[String]) {
+    let (vim, neovim) = match args {
+        [vim] => (Path::new(vim), None),
+        [vim, neovim] => (Path::new(vim), Some(Path::new(neovim))),
+        _ => usage(),
+    };
+
+    let vim_names = read_names(vim);
+    let vim_versions = read_vim_versions(vim, &vim_names);
+    let neovim_versions = neovim
+        .map(|runtime| read_neovim_versions(runtime, &vim_names, &vim_versions))
+        .unwrap_or_default();
+
+    let mut entries: BTreeMap<(Kind, String), (Option<String>, Option<String>)> = BTreeMap::new();
+    for (item, version) in vim_versions {
+        if parse_release(&version) >= OLDEST_VIM {
+            entries.entry(item).or_default().0 = Some(version);
+        }
+    }
+    for (item, version) in neovim_versions {
+        entries.entry(item).or_default().1 = Some(version);
+    }
+
+    println!("//! Versions of Vim and Neovim that added builtin functions, options and");
+    println!("//! autocmd events");
+    println!("//!");
+    println!("//! Generated from their help by `examples/gen_builtins.rs`. Regenerate with");
+    println!("//! `just gen-builtins <vim-runtime> <neovim-runtime>` instead of editing by hand.");
+    println!();
+    println!("use super::Since;");
+    for (kind, table, doc) in [
+        (Kind::Function, "FUNCTION_VERSIONS", "Functions"),
+        (Kind::Option, "OPTION_VERSIONS", "Options"),
+        (Kind::Event, "EVENT_VERSIONS", "Autocmd events"),
+    ] {
+        println!();
+        println!("/// {} by name, sorted for binary search", doc);
+        println!("pub static {}: &[(&str, Since)] = &[", table);
+        for ((_, name), (vim, neovim)) in entries.iter().filter(|((k, _), _)| *k == kind) {
+            println!("    (");
+            println!("        {:?},", name);
+            println!("        Since {{");
+            println!("            vim: {},", literal(vim));
+            println!("            neovim: {},", literal(neovim));
+            println!("        }},");
+            println!("    ),");
+        }
+        println!("];");
+    }
+}
+
+fn literal(value: &Option<String>) -> String {
+    match value {
+        Some(value) => format!("Some({:?})", value),
+        None => "None".to_string(),
+    }
+}
+
+/// Read the names of the documented items from `doc/tags`
+fn read_names(runtime: &Path) -> Names {
+    let path = runtime.join("doc").join("tags");
+    let tags = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+        eprintln!("error: {}: {}", path.display(), e);
+        std::process::exit(1);
+    });
+    let mut names = Names::default();
+    for line in tags.lines() {
+        let mut fields = line.split('\t');
+        let (Some(tag), Some(file)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        if let Some(name) = tag.strip_suffix("()").filter(|name| is_function_name(name)) {
+            names.functions.insert(name.to_string());
+        } else if let Some(name) = tag
+            .strip_prefix('\'')
+            .and_then(|tag| tag.strip_suffix('\''))
+            .filter(|name| file == "options.txt" && name.len() > 1)
+            .filter(|name| name.chars().all(|c| c.is_ascii_lowercase()))
+        {
+            names.options.insert(name.to_string());
+        } else if file == "autocmd.txt"
+            && tag.starts_with(|c: char| c.is_ascii_uppercase())
+            && tag.chars().all(|c| c.is_ascii_alphabetic())
+        {
+            names.events.insert(tag.to_string());
+        }
+    }
+    names
+}
+
+/// Find the Vim release or patch that added each item
+fn read_vim_versions(runtime: &Path, names: &Names) -> BTreeMap<(Kind, String), String> {
+    let doc = runtime.join("doc");
+    let mut files: Vec<(u32, String)> = std::fs::read_dir(&doc)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    let name = entry.file_name().to_string_lossy().into_owned();
+                    let major = name.strip_prefix("version")?.strip_suffix(".txt")?;
+                    Some((major.parse().ok()?, name))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+
+    let mut versions = BTreeMap::new();
+    for (major, file) in files {
+        let content = read_latin1(&doc.join(&file));
+        for release in parse_releases(&content, major, names) {
+            for item in release.items {
+                if versions.contains_key(&item) {
+                    continue;
+                }
+                let version =
+                    find_patch(&release.patches, &item).unwrap_or_else(|| release.version.clone());
+                versions.insert(item, version);
+            }
+        }
+    }
+    versions
+}
+
+/// Split a `version*.txt` file into releases: the file starts with the
+/// major release, followed by `VERSION 8.1 *version-8.1*` sections
+fn parse_releases(content: &str, major: u32, names: &Names) -> Vec<Release> {
+    let mut releases = vec![Release {
+        version: format!("{}.0", major),
+        items: Vec::new(),
+        patches: Vec::new(),
+    }];
+    let mut notes = String::new();
+    let mut in_patches = false;
+    let mut field = "";
+    for line in content.lines() {
+        let release = releases.last_mut().unwrap();
+        let version = line.strip_prefix("VERSION ").and_then(|_| {
+            line.split_whitespace()
+                .find_map(|word| word.strip_prefix("*version-")?.strip_suffix('*'))
+        });
+        if let Some(version) = version {
+            release.items = collect_items(&notes, names);
+            notes.clear();
+            in_patches = false;
+            releases.push(Release {
+                version: version.to_string(),
+                items: Vec::new(),
+                patches: Vec::new(),
+            });
+            continue;
+        }
+        if line.contains("*patches-") || line.contains("*fixed-") {
+            in_patches = true;
+        }
+        if !in_patches {
+            notes.push_str(line);
+            notes.push('\n');
+            continue;
+        }
+        if let Some(patch) = line.strip_prefix("Patch ") {
+            release.patches.push(Patch {
+                number: patch.split_whitespace().next().unwrap_or("").to_string(),
+                ..Default::default()
+            });
+            field = "";
+            continue;
+        }
+        // "Solution:" and "Files:" continue on indented lines
+        let text = match line.split_once(':') {
+            Some((name, text)) if matches!(name, "Problem" | "Solution" | "Files") => {
+                field = name;
+                text
+            }
+            _ if line.starts_with(char::is_whitespace) && !line.trim().is_empty() => line,
+            _ => {
+                field = "";
+                continue;
+            }
+        };
+        let Some(patch) = release.patches.last_mut() else {
+            continue;
+        };
+        match field {
+            "Solution" => {
+                patch.solution.push(' ');
+                patch.solution.push_str(text.trim());
+            }
+            "Files" => patch.changes_source |= text.contains("src/"),
+            _ => {}
+        }
+    }
+    releases.last_mut().unwrap().items = collect_items(&notes, names);
+    releases
+}
+
+/// Collect the items listed in release notes, in order
+///
+/// Items mentioned in the text are often old ones, so only these count:
+/// - the first word of the lines under headings like "New functions: ~",
+///   "Options:" or "Autocommands:"
+/// - the items of paragraphs like "Added the 'colorcolumn' option"
+/// - "All the popup_ functions."
+fn collect_items(notes: &str, names: &Names) -> Vec<(Kind, String)> {
+    let mut items = Vec::new();
+    let mut in_list = false;
+    // Whether the previous line ended a paragraph or started with an item:
+    // an item starting a continuation line is only mentioned
+    let mut at_entry = true;
+    for line in notes.lines() {
+        let trimmed = line.trim();
+        if trimmed.ends_with(':') || trimmed.ends_with(": ~") || trimmed.starts_with("===") {
+            in_list = names_kind(trimmed);
+            at_entry = true;
+            continue;
+        }
+        let item = line
+            .split_whitespace()
+            .next()
+            .and_then(|word| find_item(word, names));
+        if let Some(item) = item.clone().filter(|_| in_list && at_entry) {
+            items.push(item);
+        }
+        at_entry = trimmed.is_empty() || item.is_some();
+    }
+
+    for paragraph in notes.split("\n\n") {
+        let paragraph = paragraph.trim();
+        items.extend(
+            added_items(paragraph)
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter_map(|word| find_item(word, names)),
+        );
+        if let Some(prefix) = paragraph
+            .strip_prefix("All the ")
+            .and_then(|rest| rest.strip_suffix(" functions."))
+            .filter(|prefix| prefix.ends_with('_'))
+        {
+            for name in names
+                .functions
+                .iter()
+                .filter(|name| name.starts_with(prefix))
+            {
+                items.push((Kind::Function, name.clone()));
+            }
+        }
+    }
+    items
+}
+
+/// Whether text names a kind of item ("functions", "option", ...)
+fn names_kind(text: &str) -> bool {
+    kind_word(text).is_some()
+}
+
+/// Find where text names a kind of item
+fn kind_word(text: &str) -> Option<usize> {
+    let text = text.to_lowercase();
+    ["function", "option", "event", "autocommand"]
+        .iter()
+        .filter_map(|kind| text.find(kind))
+        .min()
+}
+
+/// The part of a paragraph listing added items: "Added the |gettabvar()| and
+/// |settabvar()| functions" or "More floating point functions: |acos()|, ..."
+fn added_items(paragraph: &str) -> &str {
+    if let Some(rest) = paragraph.strip_prefix("Added ") {
+        let rest = rest.strip_prefix("the ").unwrap_or(rest);
+        let items = &rest[..kind_word(rest).unwrap_or(0)];
+        // Not "Added 'window' to the options window"
+        if items.starts_with(['|', '\'']) && items.trim_end().ends_with(['|', '\'']) {
+            return items;
+        }
+    } else if let Some((_, rest)) = paragraph.split_once("functions: ") {
+        return rest.split(". ").next().unwrap_or(rest);
+    }
+    ""
+}
+
+/// The item a word of the release notes refers to: `|name()|`, `'option'`
+/// or `|Event|`
+fn find_item(word: &str, names: &Names) -> Option<(Kind, String)> {
+    let tag = word
+        .trim_end_matches(['.', ',', ')', ';'])
+        .trim_matches('|');
+    if let Some(name) = tag.strip_suffix("()")
+        && names.functions.contains(name)
+    {
+        Some((Kind::Function, name.to_string()))
+    } else if let Some(name) = tag
+        .strip_prefix('\'')
+        .and_then(|tag| tag.strip_suffix('\''))
+        .filter(|name| names.options.contains(*name))
+    {
+        Some((Kind::Option, name.to_string()))
+    } else if word.starts_with('|') && names.events.contains(tag) {
+        Some((Kind::Event, tag.to_string()))
+    } else {
+        None
+    }
+}
+
+/// Find the first patch whose solution adds an item, not counting patches of
+/// the help alone (the design of a feature is often documented first)
+fn find_patch(patches: &[Patch], (kind, name): &(Kind, String)) -> Option<String> {
+    let mention = match kind {
+        Kind::Function => format!("{}()", name),
+        Kind::Option => format!("'{}'", name),
+        Kind::Event => name.clone(),
+    };
+    patches
+        .iter()
+        .find(|patch| {
+            patch.changes_source
+                && mentions(&patch.solution, &mention)
+                && ["add", "Add", "implement", "Implement", "new ", "New "]
+                    .iter()
+                    .any(|word| patch.solution.contains(word))
+        })
+        .map(|patch| patch.number.clone())
+}
+
+/// Whether text mentions a name as a whole word
+fn mentions(text: &str, name: &str) -> bool {
+    text.match_indices(name).any(|(i, _)| {
+        let before = text[..i].chars().next_back();
+        let after = text[i + name.len()..].chars().next();
+        !before.is_some_and(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '#' | '.'))
+            && !after.is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+    })
+}
+
+/// Find the Neovim release that added each function, from the "Since:"
+/// attribute of its help entry (`Attributes: ~ Since: 0.5.0`)
+fn read_neovim_versions(
+    runtime: &Path,
+    vim_names: &Names,
+    vim_versions: &BTreeMap<(Kind, String), String>,
+) -> BTreeMap<(Kind, String), String> {
+    let names = read_names(runtime);
+    let doc = runtime.join("doc");
+    let mut files: Vec<_> = std::fs::read_dir(&doc)
+        .map(|entries| {
+            entries
+                .filter_map(|entry| entry.ok())
+                .map(|entry| entry.path())
+                .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+                .collect()
+        })
+        .unwrap_or_default();
+    files.sort();
+
+    let mut versions = BTreeMap::new();
+    for path in files {
+        let content = std::fs::read_to_string(&path).unwrap_or_default();
+        let mut function: Option<String> = None;
+        for line in content.lines() {
+            // A help entry starts at its tag: "*nvim_buf_attach()*"
+            if line.trim_end().ends_with('*') {
+                function = line
+                    .split_whitespace()
+                    .filter_map(|word| word.strip_prefix('*')?.strip_suffix("()*"))
+                    .find(|name| names.functions.contains(*name))
+                    .map(str::to_string);
+                continue;
+            }
+            let Some(name) = &function else {
+                continue;
+            };
+            if let Some(since) = line.trim().strip_prefix("Since: ") {
+                let (major, minor) = parse_release(since);
+                let item = (Kind::Function, name.clone());
+                // Neovim started from Vim 7.4
+                let inherited = vim_names.contains(&item)
+                    && vim_versions
+                        .get(&item)
+                        .is_none_or(|version| parse_release(version) <= (7, 4));
+                if !inherited {
+                    versions.insert(item, format!("{}.{}", major, minor));
+                }
+                function = None;
+            }
+        }
+    }
+    versions
+}
+
+/// Parse the release of a version ("8.2" or "8.2.1978")
+fn parse_release(version: &str) -> (u32, u32) {
+    let mut parts = version.split('.').map(|part| part.parse().unwrap_or(0));
+    (parts.next().unwrap_or(0), parts.next().unwrap_or(0))
+}
+
+/// Old release notes are Latin-1
+fn read_latin1(path: &Path) -> String {
+    std::fs::read(path)
+        .map(|bytes| bytes.into_iter().map(char::from).collect())
+        .unwrap_or_default()
+}
@@ -0,0 +1,137 @@
+//! Standalone analysis of Vim script sources
+//!
+//! Runs the parser, symbol extraction and lint passes over a single source
+//! without an LSP client or a workspace, for tools embedding hjkls (plugin
+//! managers, CI bots). Rules looking up definitions in other files
+//! (`autoload_missing`, `arity_mismatch`, `undefined_function` and the Vim9
+//! import rules) need a workspace and aren't run.
+
+use std::path::PathBuf;
+
+use tower_lsp_server::ls_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Range};
+use tree_sitter::Tree;
+
+use crate::builtins::EditorMode;
+use crate::config::Config;
+use crate::diagnostics::{self, Dialect};
+use crate::modeline::Modeline;
+use crate::symbols::{self, Symbol};
+
+/// Options of an analysis
+#[derive(Debug, Clone, Default)]
+pub struct AnalyzeOptions {
+    /// Configuration, as read from `.hjkls.toml`
+    pub config: Config,
+    /// Path of the source, for the dialect (the file needn't exist)
+    pub path: Option<PathBuf>,
+    /// Editor the source targets when its dialect can't be told
+    pub editor_mode: EditorMode,
+}
+
+/// A lint finding
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Finding {
+    /// Rule ID (e.g., "hjkls/match_case", "hjkls/syntax_error"), `None` for
+    /// diagnostics without a code
+    pub rule: Option<String>,
+    pub severity: Option<DiagnosticSeverity>,
+    pub message: String,
+    pub range: Range,
+}
+
+impl From<Diagnostic> for Finding {
+    fn from(diagnostic: Diagnostic) -> Self {
+        let rule = match diagnostic.code {
+            Some(NumberOrString::String(code)) => Some(code),
+            Some(NumberOrString::Number(code)) => Some(code.to_string()),
+            None => None,
+        };
+        Self {
+            rule,
+            severity: diagnostic.severity,
+            message: diagnostic.message,
+            range: diagnostic.range,
+        }
+    }
+}
+
+/// Parse Vim script source
+///
+/// Heredocs the parser can't handle are masked, see `heredoc::mask_heredocs()`.
+pub fn parse(source: &str) -> Option<Tree> {
+    let mut parser = tree_sitter::Parser::new();
+    parser
+        .set_language(&tree_sitter_vim::language())
+        .expect("Error loading vim grammar");
+    parser.parse(crate::heredoc::mask_heredocs(source).as_ref(), None)
+}
+
+/// Lint a source, sorted by position
+pub fn analyze(source: &str, options: &AnalyzeOptions) -> Vec<Finding> {
+    let Some(tree) = parse(source) else {
+        return Vec::new();
+    };
+
+    let mut diagnostics: Vec<Diagnostic> = diagnostics::FILE_PASSES
+        .iter()
+        .flat_map(|pass| (pass.run)(&tree, source, None))
+        .collect();
+
+    let mut config = options.config.clone();
+    Modeline::parse(source).apply_thresholds(&mut config.lint.thresholds);
+    diagnostics.extend(diagnostics::collect_metric_hints(
+        &tree,
+        source,
+        &config.lint.thresholds,
+    ));
+
+    let dialect = Dialect::detect(source, options.path.as_deref(), options.editor_mode);
+    let mut diagnostics = diagnostics::finish_diagnostics(diagnostics, source, &config, &dialect);
+    diagnostics.sort_by_key(|d| (d.range.start.line, d.range.start.character));
+    diagnostics.into_iter().map(Finding::from).collect()
+}
+
+/// Extract the functions and variables defined in a source
+pub fn extract_symbols(source: &str) -> Vec<Symbol> {
+    match parse(source) {
+        Some(tree) => symbols::extract_symbols(&tree, source),
+        None => Vec::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_analyze() {
+        let source = "let l:x = 1\nfunction! s:Foo()\nendfunction\n";
+        let findings = analyze(source, &AnalyzeOptions::default());
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule.as_deref(), Some("hjkls/scope_violation"));
+        assert_eq!(findings[0].range.start.line, 0);
+
+        // Disabled rules are dropped
+        let options = AnalyzeOptions {
+            config: Config::parse("[lint]\ncorrectness = false\n").unwrap(),
+            ..Default::default()
+        };
+        assert!(analyze(source, &options).is_empty());
+
+        // The diagnostic limit only applies to what the server publishes
+        let options = AnalyzeOptions {
+            config: Config::parse("[lint]\nmax_diagnostics = 1\n").unwrap(),
+            ..Default::default()
+        };
+        let findings = analyze("let l:x = 1\nlet l:y = 2\n", &options);
+        assert_eq!(findings.len(), 2);
+        assert!(
+            findings
+                .iter()
+                .all(|finding| finding.rule.as_deref() == Some("hjkls/scope_violation"))
+        );
+
+        let symbols = extract_symbols(source);
+        assert!(symbols.iter().any(|symbol| symbol.full_name() == "s:Foo"));
+    }
+}
@@ -1,56 +1,184 @@
-use std::collections::HashMap;
-use std::path::PathBuf;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::{Arc, Mutex, RwLock};
+use std::time::{Duration, Instant};
 
-use salsa::Setter;
+use salsa::{Database, Setter};
 use texter::core::text::Text;
 use tower_lsp_server::jsonrpc::Result;
+use tower_lsp_server::ls_types::request::{GotoDeclarationParams, GotoDeclarationResponse};
 use tower_lsp_server::ls_types::*;
-use tower_lsp_server::{Client, LanguageServer};
+use tower_lsp_server::{Client, ClientSocket, LanguageServer, LspService};
 use tree_sitter::{Parser, Tree};
 
 use crate::builtins::{
-    AUTOCMD_EVENTS, BUILTIN_COMMANDS, BUILTIN_FUNCTIONS, BUILTIN_OPTIONS, BUILTIN_VARIABLES,
-    EditorMode, HAS_FEATURES, MAP_OPTIONS,
+    AUTOCMD_EVENTS, AUTOCMD_PATTERN_SNIPPETS, AutocmdEvent, Availability, BUILTIN_COMMANDS,
+    BUILTIN_FUNCTIONS, BUILTIN_OPTIONS, BUILTIN_VARIABLES, BuiltinFunction, BuiltinOption,
+    COMMAND_SPECIAL_ARGS, Deprecation, DeprecationKind, EditorMode, FILETYPES, HAS_FEATURES,
+    KEY_NOTATIONS, MAP_OPTIONS, OptionType, OptionValueKind, OptionValues, SYNTAX_ARGUMENTS,
+    SYNTAX_SUBCOMMANDS, Since, VIM9_DECLARATION_COMMANDS, VIM9_UNSUPPORTED_COMMANDS,
+    describe_has_version, describe_key_notation,
 };
-use crate::completion::CompletionContext;
-use crate::config::Config;
+use crate::completion::{self, CompletionContext, CompletionRank};
+use crate::config::{Config, FormatConfig, IndexFilter, LintConfig, Settings};
 use crate::db::{self, HjklsDatabase, SourceFile};
 use crate::diagnostics;
+use crate::help::{self, HelpTags};
+use crate::heredoc;
 use crate::log_debug;
+use crate::logger;
+use crate::lua;
+use crate::modeline::Modeline;
+use crate::runtimepath::Declarations;
 use crate::symbols::{
     self, SymbolKind, find_call_at_position, find_identifier_at_position, find_references,
     find_references_with_kind,
 };
+use crate::sync;
+use crate::tags;
+use crate::vim9;
+use crate::watchdog;
 
 /// Document state holding text and syntax tree
 pub(crate) struct Document {
-    text: Text,
+    /// Shared so that request handlers can release the documents lock
+    /// without copying the whole text
+    text: Arc<Text>,
     tree: Tree,
+    /// Version of the document as given by the client
+    version: i32,
 }
 
+/// Parameters of the `hjkls/syntaxTree` request
+#[derive(Debug, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SyntaxTreeParams {
+    pub text_document: TextDocumentIdentifier,
+    /// Only dump the smallest node enclosing this range
+    pub range: Option<Range>,
+}
+
+/// `$/progress` notification reporting a partial result
+///
+/// ls-types only models work done progress values, so partial results
+/// (chunks of the final result) get their own notification type.
+enum PartialResult {}
+
+#[derive(Debug, serde::Serialize, serde::Deserialize)]
+struct PartialResultProgressParams {
+    token: ProgressToken,
+    value: LSPAny,
+}
+
+impl notification::Notification for PartialResult {
+    type Params = PartialResultProgressParams;
+    const METHOD: &'static str = "$/progress";
+}
+
+/// Progress of the workspace indexing
+///
+/// Sent in the `hjkls/indexingStatus` notification while indexing, and
+/// returned by the request of the same name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexingStatus {
+    /// Whether every discovered file is indexed
+    pub complete: bool,
+    pub files_discovered: usize,
+    pub files_indexed: usize,
+    /// Number of files that couldn't be read
+    pub errors: usize,
+    /// Time spent indexing so far, in milliseconds
+    pub elapsed_ms: u64,
+}
+
+/// `hjkls/indexingStatus` notification
+enum IndexingStatusNotification {}
+
+impl notification::Notification for IndexingStatusNotification {
+    type Params = IndexingStatus;
+    const METHOD: &'static str = "hjkls/indexingStatus";
+}
+
+/// A `doc/` directory and the help tags defined by its files
+type DocTags = (PathBuf, HelpTags);
+
 /// LSP backend for Vim script
+///
+/// The state is shared between clones, which run CPU-heavy work on the
+/// blocking thread pool (see `Backend::blocking()`).
+#[derive(Clone)]
 pub struct Backend {
     client: Client,
-    parser: Mutex<Parser>,
-    documents: Mutex<HashMap<Uri, Document>>,
+    parser: Arc<Mutex<Parser>>,
+    documents: Arc<RwLock<HashMap<Uri, Document>>>,
+    /// Held while an open, change or close notification updates a document
+    ///
+    /// Notifications are handled concurrently; the lock is fair, so edits
+    /// apply in the order they were sent, and requests reading documents
+    /// see the edits sent before them (see [`Backend::documents_synced`]).
+    document_sync: Arc<tokio::sync::Mutex<()>>,
+    /// Held while the diagnostics of a document are checked to be current
+    /// and published, so that outdated ones never follow newer ones
+    diagnostics_publish: Arc<tokio::sync::Mutex<()>>,
     /// Workspace root directories
     workspace_roots: Arc<Mutex<Vec<PathBuf>>>,
     /// Salsa database for incremental computation
     salsa_db: Arc<Mutex<HjklsDatabase>>,
     /// Mapping from URI to salsa SourceFile
-    source_files: Arc<Mutex<HashMap<String, SourceFile>>>,
+    source_files: Arc<RwLock<HashMap<String, SourceFile>>>,
+    /// Files under $VIMRUNTIME indexed for definitions (opt-in, never edited)
+    runtime_files: Arc<RwLock<HashMap<String, SourceFile>>>,
     /// Whether workspace indexing is complete
     indexing_complete: Arc<AtomicBool>,
-    /// Editor mode for filtering completions
-    editor_mode: EditorMode,
-    /// Vim runtime path for autoload resolution
-    vimruntime: Option<PathBuf>,
+    /// Progress of the workspace indexing
+    indexing_status: Arc<Mutex<IndexingStatus>>,
+    /// Editor mode for filtering completions (the client settings may change it)
+    editor_mode: Arc<RwLock<EditorMode>>,
+    /// Vim runtime path for autoload resolution (the client settings may change it)
+    vimruntime: Arc<RwLock<Option<PathBuf>>>,
+    /// Runtime directories searched before $VIMRUNTIME (the client settings may change them)
+    runtimepath: Arc<RwLock<Vec<PathBuf>>>,
+    /// Runtime directories of the plugins declared by the workspace scripts
+    /// (`[index] runtimepath_from_scripts`)
+    script_runtimepath: Arc<RwLock<Vec<PathBuf>>>,
+    /// Settings given on the command line; client settings override them, and
+    /// the ones the client leaves out revert to them
+    cli_settings: Arc<Settings>,
     /// CLI-specified config file path
     config_path: Option<PathBuf>,
     /// Lint configuration loaded from .hjkls.toml
     config: Arc<Mutex<Config>>,
+    /// Lint settings from the client, applied over .hjkls.toml
+    lint_settings: Arc<Mutex<LintConfig>>,
+    /// Whether the client supports `CompletionItem.labelDetails`
+    label_details_support: Arc<AtomicBool>,
+    /// Whether the client supports `CompletionItem.commitCharacters`
+    commit_characters_support: Arc<AtomicBool>,
+    /// Whether the client can resolve the range of workspace symbols
+    workspace_symbol_resolve_support: Arc<AtomicBool>,
+    /// Whether the client accepts `LocationLink`s as definitions
+    definition_link_support: Arc<AtomicBool>,
+    /// Whether the client accepts `documentChanges` in workspace edits
+    document_changes_support: Arc<AtomicBool>,
+    /// Whether the client accepts `CreateFile` operations in workspace edits
+    create_file_support: Arc<AtomicBool>,
+    /// Whether the client accepts `RenameFile` operations in workspace edits
+    rename_file_support: Arc<AtomicBool>,
+    /// Whether the client answers `workspace/configuration` requests
+    configuration_support: Arc<AtomicBool>,
+    /// Whether the client wants `workspace/didChangeConfiguration` registered dynamically
+    configuration_registration: Arc<AtomicBool>,
+    /// Autoload functions under $VIMRUNTIME/autoload (scanned on first use,
+    /// cleared when the runtime path changes)
+    runtime_autoload_functions: Arc<RwLock<Option<Arc<Vec<symbols::Symbol>>>>>,
+    /// Help tags from $VIMRUNTIME/doc/tags (loaded on first use, cleared when
+    /// the runtime path changes)
+    help_tags: Arc<RwLock<Option<Arc<HelpTags>>>>,
+    /// Help tags of the `doc/` directories of the workspace roots (scanned on
+    /// first use)
+    workspace_help_tags: Arc<RwLock<Option<Arc<Vec<DocTags>>>>>,
 }
 
 impl Backend {
@@ -58,6 +186,7 @@ impl Backend {
         client: Client,
         editor_mode: EditorMode,
         vimruntime: Option<PathBuf>,
+        runtimepath: Vec<PathBuf>,
         config_path: Option<PathBuf>,
     ) -> Self {
         let mut parser = Parser::new();
@@ -67,222 +196,1204 @@ impl Backend {
 
         Self {
             client,
-            parser: Mutex::new(parser),
-            documents: Mutex::new(HashMap::new()),
+            parser: Arc::new(Mutex::new(parser)),
+            documents: Arc::new(RwLock::new(HashMap::new())),
+            document_sync: Arc::new(tokio::sync::Mutex::new(())),
+            diagnostics_publish: Arc::new(tokio::sync::Mutex::new(())),
             workspace_roots: Arc::new(Mutex::new(Vec::new())),
             salsa_db: Arc::new(Mutex::new(HjklsDatabase::default())),
-            source_files: Arc::new(Mutex::new(HashMap::new())),
+            source_files: Arc::new(RwLock::new(HashMap::new())),
+            runtime_files: Arc::new(RwLock::new(HashMap::new())),
             indexing_complete: Arc::new(AtomicBool::new(false)),
-            editor_mode,
-            vimruntime,
+            indexing_status: Arc::new(Mutex::new(IndexingStatus::default())),
+            editor_mode: Arc::new(RwLock::new(editor_mode)),
+            vimruntime: Arc::new(RwLock::new(vimruntime.clone())),
+            runtimepath: Arc::new(RwLock::new(runtimepath.clone())),
+            script_runtimepath: Arc::new(RwLock::new(Vec::new())),
+            cli_settings: Arc::new(Settings {
+                editor_mode: Some(editor_mode),
+                vimruntime,
+                runtimepath: Some(runtimepath),
+                ..Settings::default()
+            }),
             config_path,
             config: Arc::new(Mutex::new(Config::default())),
+            lint_settings: Arc::new(Mutex::new(LintConfig::default())),
+            label_details_support: Arc::new(AtomicBool::new(false)),
+            commit_characters_support: Arc::new(AtomicBool::new(false)),
+            workspace_symbol_resolve_support: Arc::new(AtomicBool::new(false)),
+            definition_link_support: Arc::new(AtomicBool::new(false)),
+            document_changes_support: Arc::new(AtomicBool::new(false)),
+            create_file_support: Arc::new(AtomicBool::new(false)),
+            rename_file_support: Arc::new(AtomicBool::new(false)),
+            configuration_support: Arc::new(AtomicBool::new(false)),
+            configuration_registration: Arc::new(AtomicBool::new(false)),
+            runtime_autoload_functions: Arc::new(RwLock::new(None)),
+            help_tags: Arc::new(RwLock::new(None)),
+            workspace_help_tags: Arc::new(RwLock::new(None)),
         }
     }
 
-    /// Get symbols for a document using salsa memoization
-    fn get_symbols(&self, uri: &str, content: &str) -> Vec<symbols::Symbol> {
-        let mut db = self.salsa_db.lock().unwrap();
-        let mut source_files = self.source_files.lock().unwrap();
+    /// Create the LSP service, with the custom `hjkls/*` methods
+    pub fn service(
+        editor_mode: EditorMode,
+        vimruntime: Option<PathBuf>,
+        runtimepath: Vec<PathBuf>,
+        config_path: Option<PathBuf>,
+    ) -> (LspService<Self>, ClientSocket) {
+        LspService::build(|client| {
+            Self::new(client, editor_mode, vimruntime, runtimepath, config_path)
+        })
+        .custom_method("hjkls/syntaxTree", Self::syntax_tree)
+        .custom_method("hjkls/indexingStatus", Self::indexing_status)
+        .finish()
+    }
 
-        let source_file = if let Some(sf) = source_files.get(uri) {
-            // Update existing SourceFile if content changed
-            if sf.content(&*db) != content {
-                sf.set_content(&mut *db).to(content.to_string());
-            }
-            *sf
-        } else {
-            // Create new SourceFile
-            let sf = SourceFile::new(&*db, uri.to_string(), content.to_string());
-            source_files.insert(uri.to_string(), sf);
-            sf
-        };
+    /// Wait for the documents to be updated by the notifications received
+    /// before the current request
+    async fn documents_synced(&self) {
+        drop(self.document_sync.lock().await);
+    }
+
+    /// Run CPU-heavy work (parsing, multi-file searches) on the blocking
+    /// thread pool
+    ///
+    /// Every handler is polled by the request loop of the server, so work
+    /// done in a handler stalls all the requests in flight. `f` gets a clone
+    /// of the backend sharing its state.
+    async fn blocking<T: Send + 'static>(
+        &self,
+        f: impl FnOnce(&Backend) -> T + Send + 'static,
+    ) -> T {
+        let backend = self.clone();
+        match tokio::task::spawn_blocking(move || f(&backend)).await {
+            Ok(value) => value,
+            Err(e) => std::panic::resume_unwind(e.into_panic()),
+        }
+    }
 
-        db::parse_symbols(&*db, source_file)
+    /// Get the editor mode used to filter builtins and detect dialects
+    fn editor_mode(&self) -> EditorMode {
+        *self.editor_mode.read().unwrap()
     }
 
-    /// Set workspace roots from initialize params
-    fn set_workspace_roots(&self, params: &InitializeParams) {
-        let mut roots = self.workspace_roots.lock().unwrap();
-        roots.clear();
+    /// Get the Vim runtime path used for autoload resolution
+    fn vimruntime(&self) -> Option<PathBuf> {
+        self.vimruntime.read().unwrap().clone()
+    }
 
-        // Try workspace folders first (LSP 3.6+)
-        if let Some(folders) = &params.workspace_folders {
-            for folder in folders {
-                if let Some(path) = folder.uri.to_file_path() {
-                    roots.push(path.into_owned());
-                }
+    /// Get the runtime directories in search order, like 'runtimepath'
+    ///
+    /// The configured runtime path entries come first, then the ones declared
+    /// by the workspace scripts, then $VIMRUNTIME.
+    fn runtime_dirs(&self) -> Vec<PathBuf> {
+        let mut dirs = self.runtimepath.read().unwrap().clone();
+        for dir in self.script_runtimepath.read().unwrap().iter() {
+            if !dirs.contains(dir) {
+                dirs.push(dir.clone());
+            }
+        }
+        if let Some(runtime) = self.vimruntime() {
+            if !dirs.contains(&runtime) {
+                dirs.push(runtime);
             }
         }
+        dirs
+    }
 
-        // Fall back to root_uri (deprecated but still used)
-        #[allow(deprecated)]
-        if roots.is_empty() {
-            if let Some(uri) = &params.root_uri {
-                if let Some(path) = uri.to_file_path() {
-                    roots.push(path.into_owned());
-                }
+    /// Get the workspace directories laid out like a runtime directory
+    ///
+    /// The plugin root of `current` comes first, then the workspace roots,
+    /// then the packages of their `pack/` directories, then the plugins nested
+    /// below them (e.g., `bundle/*/`). Like with 'packpath', `pack/*/opt/*`
+    /// plugins are left out unless `[index] opt_packages` is set.
+    fn workspace_runtime_dirs(&self, current: Option<&Path>) -> Vec<PathBuf> {
+        let roots = self.workspace_roots.lock().unwrap().clone();
+        let opt = self.config.lock().unwrap().index.opt_packages;
+        let mut plugin_roots: BTreeSet<PathBuf> = BTreeSet::new();
+        for (key, _) in self.source_file_entries() {
+            let path = source_file_path(&key);
+            if let Some(dir) = roots.iter().find_map(|root| {
+                plugin_root(path.strip_prefix(root).ok()?)
+                    .filter(|dir| opt || !is_opt_package(dir))
+                    .map(|dir| root.join(dir))
+            }) {
+                plugin_roots.insert(dir);
             }
         }
+        let packages: Vec<PathBuf> = roots
+            .iter()
+            .flat_map(|root| package_dirs(root, opt))
+            .collect();
 
-        // Load configuration: CLI path takes priority, then search workspace
-        let loaded_config = if let Some(ref path) = self.config_path {
-            match Config::load(path) {
-                Ok(cfg) => {
-                    log_debug!("Loaded config from CLI path: {:?}", path);
-                    Some(cfg)
-                }
-                Err(e) => {
-                    log_debug!("Failed to load config from {:?}: {}", path, e);
-                    None
-                }
+        let mut dirs: Vec<PathBuf> = current
+            .and_then(|path| {
+                roots.iter().find_map(|root| {
+                    plugin_root(path.strip_prefix(root).ok()?).map(|dir| root.join(dir))
+                })
+            })
+            .into_iter()
+            .collect();
+        for dir in roots.into_iter().chain(packages).chain(plugin_roots) {
+            if !dirs.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+        dirs
+    }
+
+    /// Get or create the salsa SourceFile for a document, updating its content
+    ///
+    /// `tree` is the tree of `content` when the document already parsed it,
+    /// so that salsa doesn't parse it again. The source file map and the
+    /// database are never locked at the same time.
+    fn source_file(&self, uri: &str, content: &str, tree: Option<&Tree>) -> SourceFile {
+        let tree = tree.map(|tree| db::SyntaxTree::from(tree.clone()));
+        let existing = self.source_files.read().unwrap().get(uri).copied();
+        if let Some(sf) = existing {
+            // Update existing SourceFile if content changed
+            let mut db = self.salsa_db.lock().unwrap();
+            if sf.text(&*db).as_deref() != Some(content) {
+                sf.set_text(&mut *db).to(Some(content.to_string()));
+                sf.set_tree(&mut *db).to(tree);
+            } else if tree.is_some() && sf.tree(&*db).is_none() {
+                sf.set_tree(&mut *db).to(tree);
             }
+            sf
         } else {
-            Config::find_in_workspace(&roots).inspect(|_| {
-                log_debug!("Loaded config from workspace");
+            // Create new SourceFile
+            let sf = SourceFile::new(
+                &*self.salsa_db.lock().unwrap(),
+                uri.to_string(),
+                Some(content.to_string()),
+                tree,
+                0,
+            );
+            // Another request may have registered the document meanwhile
+            *self
+                .source_files
+                .write()
+                .unwrap()
+                .entry(uri.to_string())
+                .or_insert(sf)
+        }
+    }
+
+    /// Snapshot all salsa SourceFiles with their keys
+    fn source_file_entries(&self) -> Vec<(String, SourceFile)> {
+        let source_files = self.source_files.read().unwrap();
+        source_files
+            .iter()
+            .map(|(key, source_file)| (key.clone(), *source_file))
+            .collect()
+    }
+
+    /// Snapshot the files indexed under $VIMRUNTIME with their paths
+    fn runtime_file_entries(&self) -> Vec<(String, SourceFile)> {
+        let runtime_files = self.runtime_files.read().unwrap();
+        runtime_files
+            .iter()
+            .map(|(key, source_file)| (key.clone(), *source_file))
+            .collect()
+    }
+
+    /// Get the functions callable from other files defined under $VIMRUNTIME
+    ///
+    /// Empty unless indexing $VIMRUNTIME is enabled in the configuration.
+    fn runtime_functions(&self) -> Vec<symbols::Symbol> {
+        let runtime_files = self.runtime_file_entries();
+        let db = self.salsa_db.lock().unwrap();
+        runtime_files
+            .iter()
+            .flat_map(|(_, sf)| db::parse_symbols(&*db, *sf))
+            .filter(|sym| {
+                sym.kind == SymbolKind::Function && sym.scope != symbols::VimScope::Script
             })
-        };
+            .collect()
+    }
 
-        if let Some(cfg) = loaded_config {
-            let mut config = self.config.lock().unwrap();
-            *config = cfg;
-        }
+    /// Whether a function is declared in the `[globals]` configuration
+    fn declares_function(&self, name: &str) -> bool {
+        self.config.lock().unwrap().globals.declares_function(name)
     }
 
-    /// Collect warnings for autoload function calls that reference non-existent files
-    fn collect_autoload_warnings(
-        &self,
-        tree: &Tree,
-        source: &str,
-        current_doc_uri: Option<&Uri>,
-    ) -> Vec<Diagnostic> {
-        let mut diagnostics = Vec::new();
-        let mut cursor = tree.walk();
+    /// Get the functions declared with a signature in the `[globals]` configuration
+    fn declared_functions(&self) -> Vec<symbols::Symbol> {
+        let config = self.config.lock().unwrap();
+        config
+            .globals
+            .functions
+            .iter()
+            .filter_map(|function| {
+                let name = function.split('(').next()?.trim();
+                let signature = config.globals.function_signature(name)?;
+                Some(symbols::Symbol {
+                    name: name.to_string(),
+                    scope: symbols::VimScope::Implicit,
+                    kind: SymbolKind::Function,
+                    start: (0, 0),
+                    end: (0, 0),
+                    extent: ((0, 0), (0, 0)),
+                    signature: Some(signature.to_string()),
+                    doc: None,
+                })
+            })
+            .collect()
+    }
 
-        self.collect_autoload_warnings_recursive(
-            &mut cursor,
-            source,
-            current_doc_uri,
-            &mut diagnostics,
-        );
+    /// Get symbols for a document using salsa memoization
+    fn get_symbols(&self, uri: &str, content: &str) -> Vec<symbols::Symbol> {
+        let source_file = self.source_file(uri, content, None);
+        db::parse_symbols(&*self.salsa_db.lock().unwrap(), source_file)
+    }
 
-        diagnostics
+    /// Get the symbols of a file, from its open document or the index
+    ///
+    /// Files that aren't indexed are read and parsed without being stored,
+    /// so that indexed entries keep following the disk.
+    fn file_symbols(&self, path: &Path) -> Option<Vec<symbols::Symbol>> {
+        let indexed = self
+            .indexed_files()
+            .into_iter()
+            .find(|(key, _)| source_file_path(key) == path);
+        match indexed {
+            Some((_, source_file)) => Some(db::parse_symbols(
+                &*self.salsa_db.lock().unwrap(),
+                source_file,
+            )),
+            None => {
+                let content = std::fs::read_to_string(path).ok()?;
+                Some(crate::analysis::extract_symbols(&content))
+            }
+        }
     }
 
-    fn collect_autoload_warnings_recursive(
-        &self,
-        cursor: &mut tree_sitter::TreeCursor,
-        source: &str,
-        current_doc_uri: Option<&Uri>,
-        diagnostics: &mut Vec<Diagnostic>,
-    ) {
-        loop {
-            let node = cursor.node();
+    /// Get the names of the functions callable from other files defined in a source file
+    fn defined_functions(&self, uri: &str) -> HashSet<String> {
+        let Some(source_file) = self.source_files.read().unwrap().get(uri).copied() else {
+            return HashSet::new();
+        };
+        db::parse_symbols(&*self.salsa_db.lock().unwrap(), source_file)
+            .into_iter()
+            .filter(|sym| {
+                sym.kind == SymbolKind::Function && sym.scope != symbols::VimScope::Script
+            })
+            .map(|sym| sym.full_name())
+            .collect()
+    }
 
-            // Check if this is a call_expression
-            if node.kind() == "call_expression" {
-                // Get the function name (first child is usually the function reference)
-                if let Some(func_node) = node.child(0) {
-                    let func_name = func_node.utf8_text(source.as_bytes()).unwrap_or("");
+    /// Recompute and publish the diagnostics of open documents mentioning any of `functions`
+    ///
+    /// Called when the definitions in `uri` change, so that undefined function
+    /// and arity warnings in other documents are updated.
+    async fn relint_dependents(&self, uri: &Uri, functions: &HashSet<String>) {
+        let dependents = self.dependent_documents(uri, functions);
+        self.republish_diagnostics(dependents).await;
+    }
 
-                    // Check if it's an autoload function call (contains #)
-                    if let Some(autoload_ref) = symbols::AutoloadRef::parse(func_name) {
-                        // Check if the autoload file exists
-                        if self
-                            .find_autoload_file(&autoload_ref, current_doc_uri)
-                            .is_none()
-                        {
-                            let start = func_node.start_position();
-                            let end = func_node.end_position();
-                            let expected_path = autoload_ref.to_file_path();
+    /// Get the open documents other than `uri` mentioning any of `functions`
+    fn dependent_documents(&self, uri: &Uri, functions: &HashSet<String>) -> Vec<Uri> {
+        if functions.is_empty() {
+            return Vec::new();
+        }
+        let docs = self.documents.read().unwrap();
+        docs.iter()
+            .filter(|(doc_uri, _)| *doc_uri != uri)
+            .filter(|(_, doc)| {
+                functions
+                    .iter()
+                    .any(|name| doc.text.text.contains(name.as_str()))
+            })
+            .map(|(doc_uri, _)| doc_uri.clone())
+            .collect()
+    }
 
-                            diagnostics.push(Diagnostic {
-                                range: Range {
-                                    start: Position {
-                                        line: start.row as u32,
-                                        character: start.column as u32,
-                                    },
-                                    end: Position {
-                                        line: end.row as u32,
-                                        character: end.column as u32,
-                                    },
-                                },
-                                severity: Some(DiagnosticSeverity::WARNING),
-                                source: Some("hjkls".to_string()),
-                                message: format!("Autoload file not found: {}", expected_path),
-                                code: Some(NumberOrString::String(
-                                    "hjkls/autoload_missing".to_string(),
-                                )),
-                                ..Default::default()
-                            });
-                        }
-                    }
-                }
+    /// Publish the diagnostics of a document unless it changed since `version`
+    async fn publish_versioned_diagnostics(&self, uri: Uri, version: i32) {
+        let diagnostics = {
+            let uri = uri.clone();
+            self.blocking(move |backend| {
+                let diagnostics = backend.versioned_diagnostics(&uri, version)?;
+                // Only what the client shows is capped, `hjkls check` reports everything
+                let max = backend.document_config(&uri).max_diagnostics();
+                Some(diagnostics::cap_diagnostics(diagnostics, max))
+            })
+            .await
+        };
+        let Some(diagnostics) = diagnostics else {
+            return;
+        };
+        let _publish = self.diagnostics_publish.lock().await;
+        let current = self
+            .documents
+            .read()
+            .unwrap()
+            .get(&uri)
+            .map(|doc| doc.version);
+        if current == Some(version) {
+            // The version lets clients drop diagnostics of outdated contents
+            self.client
+                .publish_diagnostics(uri, diagnostics, Some(version))
+                .await;
+        }
+    }
+
+    /// Recompute and publish the diagnostics of the given open documents
+    async fn republish_diagnostics(&self, uris: Vec<Uri>) {
+        for uri in uris {
+            let Some(version) = self
+                .documents
+                .read()
+                .unwrap()
+                .get(&uri)
+                .map(|doc| doc.version)
+            else {
+                continue;
+            };
+            log_debug!("relinting {}", uri.as_str());
+            self.publish_versioned_diagnostics(uri, version).await;
+        }
+    }
+
+    /// Pull the `hjkls` section of the client settings with `workspace/configuration`
+    async fn pull_settings(&self) -> Option<serde_json::Value> {
+        let item = ConfigurationItem {
+            scope_uri: None,
+            section: Some("hjkls".to_string()),
+        };
+        match self.client.configuration(vec![item]).await {
+            Ok(mut values) => values.pop(),
+            Err(e) => {
+                log_debug!("workspace/configuration failed: {:?}", e);
+                None
             }
+        }
+    }
 
-            // Recurse into children
-            if cursor.goto_first_child() {
-                self.collect_autoload_warnings_recursive(
-                    cursor,
-                    source,
-                    current_doc_uri,
-                    diagnostics,
-                );
-                cursor.goto_parent();
+    /// Apply changed client settings and relint the open documents
+    async fn update_settings(&self, value: serde_json::Value) {
+        let settings = match Settings::from_value(value) {
+            Ok(settings) => settings,
+            Err(e) => {
+                self.client
+                    .show_message(
+                        MessageType::WARNING,
+                        format!("hjkls: invalid settings: {e}"),
+                    )
+                    .await;
+                return;
             }
+        };
+        let runtime_changed = self.apply_settings(settings);
+        self.load_config();
+        self.blocking(|backend| backend.load_script_runtimepath())
+            .await;
+        if runtime_changed {
+            self.reset_runtime().await;
+        }
 
-            if !cursor.goto_next_sibling() {
-                break;
+        let open: Vec<Uri> = self.documents.read().unwrap().keys().cloned().collect();
+        self.republish_diagnostics(open).await;
+    }
+
+    /// Report a chunk of the result of a request with a `partialResultToken`
+    async fn send_partial_result<T: serde::Serialize>(&self, token: &ProgressToken, value: T) {
+        match serde_json::to_value(value) {
+            Ok(value) => {
+                self.client
+                    .send_notification::<PartialResult>(PartialResultProgressParams {
+                        token: token.clone(),
+                        value,
+                    })
+                    .await;
             }
+            Err(e) => log_debug!("failed to serialize partial result: {}", e),
         }
     }
 
-    /// Collect warnings for function calls with wrong number of arguments
-    fn collect_arity_warnings(&self, tree: &Tree, source: &str, uri: &Uri) -> Vec<Diagnostic> {
-        let mut diagnostics = Vec::new();
-        let mut cursor = tree.walk();
+    /// Build a Markdown `:help` link for a tag, prefixed by a blank line
+    ///
+    /// The help file is looked up in `$VIMRUNTIME/doc/tags`, falling back to
+    /// `default_file`. Returns an empty string when the file is unknown.
+    fn help_link(&self, tag: &str, default_file: Option<&str>) -> String {
+        let tags = self.help_tags();
+        let file = tags.file(tag).or(default_file);
+        help::url(tag, file, self.editor_mode() == EditorMode::NeovimOnly)
+            .map(|url| format!("\n\n{}", help::markdown_link(tag, &url)))
+            .unwrap_or_default()
+    }
 
-        // Get user-defined symbols for this document
-        let uri_str = uri.to_string();
-        let symbols = self.get_symbols(&uri_str, source);
+    /// Hover note of the versions that added a builtin (e.g., "*Added in Vim 8.2.0878*"),
+    /// or an empty string when unknown
+    fn since_note(&self, since: Option<&Since>) -> String {
+        since
+            .and_then(|since| since.label(self.editor_mode()))
+            .map(|label| format!("\n\n*{}*", label))
+            .unwrap_or_default()
+    }
 
-        Self::collect_arity_warnings_recursive(&mut cursor, source, &symbols, &mut diagnostics);
+    /// Get the help tags of `$VIMRUNTIME`, loaded on first use
+    fn help_tags(&self) -> Arc<HelpTags> {
+        let cached = self.help_tags.read().unwrap().clone();
+        cached.unwrap_or_else(|| {
+            let tags = Arc::new(
+                self.vimruntime()
+                    .as_deref()
+                    .map(HelpTags::load)
+                    .unwrap_or_default(),
+            );
+            Arc::clone(self.help_tags.write().unwrap().get_or_insert(tags))
+        })
+    }
 
-        diagnostics
+    /// Get the `$VIMRUNTIME` help section of a tag (e.g., "strlen()")
+    pub fn help_section(&self, tag: &str) -> Option<String> {
+        let file = self.help_tags().file(tag)?.to_string();
+        let content = std::fs::read_to_string(self.vimruntime()?.join("doc").join(file)).ok()?;
+        help::section(&content, tag)
     }
 
-    fn collect_arity_warnings_recursive(
-        cursor: &mut tree_sitter::TreeCursor,
-        source: &str,
-        symbols: &[symbols::Symbol],
-        diagnostics: &mut Vec<Diagnostic>,
-    ) {
-        loop {
-            let node = cursor.node();
+    /// Get the help tags of each `doc/` directory in the workspace roots, scanned on first use
+    fn workspace_help_tags(&self) -> Arc<Vec<DocTags>> {
+        let cached = self.workspace_help_tags.read().unwrap().clone();
+        cached.unwrap_or_else(|| {
+            let tags = Arc::new(
+                self.workspace_roots
+                    .lock()
+                    .unwrap()
+                    .iter()
+                    .map(|root| root.join("doc"))
+                    .filter(|doc| doc.is_dir())
+                    .map(|doc| {
+                        let tags = HelpTags::scan(&doc);
+                        (doc, tags)
+                    })
+                    .collect(),
+            );
+            Arc::clone(
+                self.workspace_help_tags
+                    .write()
+                    .unwrap()
+                    .get_or_insert(tags),
+            )
+        })
+    }
 
-            // Check if this is a call_expression
-            if node.kind() == "call_expression" {
-                if let Some(func_node) = node.child(0) {
-                    let func_name = func_node.utf8_text(source.as_bytes()).unwrap_or("");
+    /// Get the documentation of a plugin function from the workspace help
+    /// (the section of its `*name()*` tag)
+    fn function_help(&self, name: &str) -> Option<String> {
+        let tag = format!("{}()", name);
+        self.workspace_help_tags().iter().find_map(|(doc, tags)| {
+            let content = std::fs::read_to_string(doc.join(tags.file(&tag)?)).ok()?;
+            help::section(&content, &tag)
+        })
+    }
 
-                    // Skip autoload functions (handled separately) and empty names
-                    if func_name.is_empty() || func_name.contains('#') {
-                        // Continue to recurse but skip arity check
-                    } else {
-                        // Try to find signature - first check built-in functions
-                        let signature = BUILTIN_FUNCTIONS
-                            .iter()
-                            .find(|f| f.name == func_name)
+    /// Build hover contents for the feature name string in `has('...')`
+    fn has_feature_hover(
+        &self,
+        tree: &Tree,
+        source: &str,
+        row: usize,
+        col: usize,
+    ) -> Option<(String, Range)> {
+        let node = symbols::find_node_of_kind(tree, row, col, &["string_literal"])?;
+        let call = node.parent()?;
+        if call.kind() != "call_expression"
+            || call.child(0)?.utf8_text(source.as_bytes()).ok()? != "has"
+        {
+            return None;
+        }
+        let name = node
+            .utf8_text(source.as_bytes())
+            .ok()?
+            .trim_matches(|c| c == '\'' || c == '"');
+
+        let (description, availability) = match HAS_FEATURES.iter().find(|f| f.name == name) {
+            Some(feature) => (feature.description.to_string(), feature.availability),
+            None => (describe_has_version(name)?, Availability::Common),
+        };
+        let availability = match availability {
+            Availability::Common => "",
+            Availability::VimOnly => "\n\n*Vim only*",
+            Availability::NeovimOnly => "\n\n*Neovim only*",
+        };
+        let contents = format!(
+            "```vim\nhas('{}')\n```\n\n{}{}{}",
+            name,
+            description,
+            availability,
+            self.help_link("feature-list", Some("builtin.txt"))
+        );
+        Some((contents, node_range(&node)))
+    }
+
+    /// Build hover contents for `<...>` tokens: arguments (`<silent>`, ...) and
+    /// key notations (`<CR>`, `<C-r>`, ...) of map commands, and special arguments
+    /// of `:command` definitions (`<args>`, `<bang>`, ...)
+    ///
+    /// `<Leader>` and `<LocalLeader>` also show the configured `mapleader` and
+    /// `maplocalleader`.
+    fn angle_bracket_hover(
+        &self,
+        docs: &HashMap<Uri, Document>,
+        uri: &Uri,
+        row: usize,
+        col: usize,
+    ) -> Option<(String, Range)> {
+        let doc = docs.get(uri)?;
+        let (tree, source) = (&doc.tree, doc.text.text.as_str());
+        let line = source.lines().nth(row)?;
+        let col = sync::position_to_byte(line, Position::new(0, col as u32));
+        let (start, end) = symbols::find_angle_bracket_token(line, col)?;
+        let token = &line[start..end];
+        let statement =
+            symbols::find_node_of_kind(tree, row, col, &["map_statement", "command_statement"])?;
+        let (name, description, help_link) = if statement.kind() == "map_statement" {
+            match MAP_OPTIONS
+                .iter()
+                .find(|opt| opt.name.eq_ignore_ascii_case(token))
+            {
+                Some(opt) => (
+                    opt.name,
+                    opt.description.to_string(),
+                    self.help_link(&format!(":map-{}", opt.name), Some("map.txt")),
+                ),
+                None => {
+                    let mut description = describe_key_notation(token)?;
+                    let variable = match token.to_ascii_lowercase().as_str() {
+                        "<leader>" => Some("mapleader"),
+                        "<localleader>" => Some("maplocalleader"),
+                        _ => None,
+                    };
+                    if let Some((variable, value)) = variable.and_then(|variable| {
+                        Some((variable, self.global_assignment(docs, uri, variable)?))
+                    }) {
+                        description.push_str(&format!("\n\nCurrent `{}`: `{}`", variable, value));
+                    }
+                    let help_link = if KEY_NOTATIONS
+                        .iter()
+                        .any(|key| key.name.eq_ignore_ascii_case(token))
+                    {
+                        self.help_link("key-notation", Some("intro.txt"))
+                    } else {
+                        String::new()
+                    };
+                    (token, description, help_link)
+                }
+            }
+        } else {
+            COMMAND_SPECIAL_ARGS
+                .iter()
+                .find(|arg| arg.name.eq_ignore_ascii_case(token))
+                .map(|arg| {
+                    (
+                        arg.name,
+                        arg.description.to_string(),
+                        self.help_link(arg.name, Some("map.txt")),
+                    )
+                })?
+        };
+        let utf16_col = |byte: usize| line[..byte].encode_utf16().count() as u32;
+        let range = Range {
+            start: Position {
+                line: row as u32,
+                character: utf16_col(start),
+            },
+            end: Position {
+                line: row as u32,
+                character: utf16_col(end),
+            },
+        };
+        let contents = format!("```vim\n{}\n```\n\n{}{}", name, description, help_link);
+        Some((contents, range))
+    }
+
+    /// Find the value assigned to a global variable (e.g., `mapleader`)
+    ///
+    /// The current document is searched first, then other open documents and
+    /// the workspace index.
+    fn global_assignment(
+        &self,
+        docs: &HashMap<Uri, Document>,
+        uri: &Uri,
+        name: &str,
+    ) -> Option<String> {
+        let open = docs
+            .get(uri)
+            .into_iter()
+            .chain(docs.iter().filter(|(u, _)| *u != uri).map(|(_, doc)| doc))
+            .find_map(|doc| symbols::find_global_assignment(&doc.text.text, name));
+        if open.is_some() {
+            return open;
+        }
+        let source_files = self.source_file_entries();
+        let db = self.salsa_db.lock().unwrap();
+        source_files
+            .iter()
+            .find_map(|(_, sf)| symbols::find_global_assignment(&db::file_content(&*db, *sf), name))
+    }
+
+    /// Find a global or autoload function defined in an indexed file other than `uri`
+    ///
+    /// Files under $VIMRUNTIME are searched last, when indexed.
+    /// Returns the URI of the defining file and the function symbol.
+    fn workspace_function(&self, uri: &str, name: &str) -> Option<(String, symbols::Symbol)> {
+        let source_files = self.source_file_entries();
+        let runtime_files = self.runtime_file_entries();
+        let db = self.salsa_db.lock().unwrap();
+        source_files
+            .iter()
+            .filter(|(file_uri, _)| *file_uri != uri)
+            .chain(runtime_files.iter())
+            .find_map(|(file_uri, sf)| {
+                db::parse_symbols(&*db, *sf)
+                    .into_iter()
+                    .find(|sym| {
+                        sym.kind == SymbolKind::Function
+                            && sym.name == name
+                            && matches!(
+                                sym.scope,
+                                symbols::VimScope::Global | symbols::VimScope::Implicit
+                            )
+                    })
+                    .map(|sym| (file_uri.clone(), sym))
+            })
+    }
+
+    /// Find the first assignment to a `g:` variable in each indexed file other than `uri`
+    ///
+    /// Returns the defining files (sorted) and the variable symbols.
+    fn workspace_global_variables(&self, uri: &str, name: &str) -> Vec<(String, symbols::Symbol)> {
+        let current_path = source_file_path(uri);
+        let source_files = self.source_file_entries();
+        let db = self.salsa_db.lock().unwrap();
+        let mut definitions: Vec<_> = source_files
+            .iter()
+            .filter(|(file_uri, _)| source_file_path(file_uri) != current_path)
+            .filter_map(|(file_uri, sf)| {
+                db::parse_symbols(&*db, *sf)
+                    .into_iter()
+                    .find(|sym| {
+                        sym.kind == SymbolKind::Variable
+                            && sym.scope == symbols::VimScope::Global
+                            && sym.name == name
+                    })
+                    .map(|sym| (file_uri.clone(), sym))
+            })
+            .collect();
+        definitions.sort_by(|a, b| a.0.cmp(&b.0));
+        definitions
+    }
+
+    /// Format a file location for display, relative to the workspace root when possible
+    ///
+    /// `uri` is a source file key: a URI for opened documents, a path for indexed files.
+    fn display_location(&self, uri: &str, line: usize) -> String {
+        let path = source_file_path(uri);
+        let roots = self.workspace_roots.lock().unwrap();
+        let relative = roots
+            .iter()
+            .find_map(|root| path.strip_prefix(root).ok())
+            .unwrap_or(&path);
+        format!("{}:{}", relative.display(), line + 1)
+    }
+
+    /// Get the configuration applying to a document, with the overrides for
+    /// its directory
+    fn document_config(&self, uri: &Uri) -> Config {
+        let config = self.config.lock().unwrap();
+        let Some(path) = uri.to_file_path() else {
+            return config.clone();
+        };
+        let roots = self.workspace_roots.lock().unwrap();
+        match roots.iter().find_map(|root| path.strip_prefix(root).ok()) {
+            Some(relative) => config.for_path(&relative.to_string_lossy().replace('\\', "/")),
+            None => config.clone(),
+        }
+    }
+
+    /// Get autoload functions (`foo#bar#Baz`) defined in indexed files other than `uri`
+    fn workspace_autoload_functions(&self, uri: &str) -> Vec<symbols::Symbol> {
+        let source_files = self.source_file_entries();
+        let db = self.salsa_db.lock().unwrap();
+        let mut functions: Vec<symbols::Symbol> = Vec::new();
+        for (_, sf) in source_files.iter().filter(|(file_uri, _)| *file_uri != uri) {
+            for sym in db::parse_symbols(&*db, *sf) {
+                if sym.kind == SymbolKind::Function
+                    && sym.name.contains('#')
+                    && !functions.iter().any(|f| f.name == sym.name)
+                {
+                    functions.push(sym);
+                }
+            }
+        }
+        functions
+    }
+
+    /// Get autoload functions under $VIMRUNTIME/autoload
+    ///
+    /// The directory is scanned on first use, and again after the runtime
+    /// path changes.
+    fn runtime_autoload_functions(&self) -> Arc<Vec<symbols::Symbol>> {
+        if let Some(functions) = &*self.runtime_autoload_functions.read().unwrap() {
+            return Arc::clone(functions);
+        }
+        let functions = Arc::new(self.scan_runtime_autoload_functions());
+        Arc::clone(
+            self.runtime_autoload_functions
+                .write()
+                .unwrap()
+                .get_or_insert(functions),
+        )
+    }
+
+    /// Scan $VIMRUNTIME/autoload for autoload functions
+    fn scan_runtime_autoload_functions(&self) -> Vec<symbols::Symbol> {
+        let Some(runtime) = self.vimruntime() else {
+            return vec![];
+        };
+        let mut files = Vec::new();
+        let autoload = runtime.join("autoload");
+        scan_directory_recursive(&autoload, &autoload, &IndexFilter::default(), &mut files);
+
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_vim::language())
+            .expect("Error loading vim grammar");
+        let functions: Vec<symbols::Symbol> = files
+            .iter()
+            .filter_map(|path| std::fs::read_to_string(path).ok())
+            .filter_map(|content| {
+                let tree = parser.parse(heredoc::mask_heredocs(&content).as_ref(), None)?;
+                Some(symbols::extract_symbols(&tree, &content))
+            })
+            .flatten()
+            .filter(|sym| sym.kind == SymbolKind::Function && sym.name.contains('#'))
+            .collect();
+        log_debug!(
+            "runtime autoload: found {} functions in {} files",
+            functions.len(),
+            files.len()
+        );
+        functions
+    }
+
+    /// Get user-defined commands from a document and all indexed files
+    ///
+    /// Each command is paired with whether it is defined in the current
+    /// document. Commands from the current document come first; duplicates
+    /// (by name) are removed.
+    fn get_user_commands(&self, uri: &str, content: &str) -> Vec<(symbols::UserCommand, bool)> {
+        let current = self.source_file(uri, content, None);
+        let source_files = self.source_file_entries();
+        let db = self.salsa_db.lock().unwrap();
+
+        let mut commands: Vec<_> = db::parse_user_commands(&*db, current)
+            .into_iter()
+            .map(|cmd| (cmd, true))
+            .collect();
+        for (file_uri, sf) in source_files.iter() {
+            if file_uri == uri {
+                continue;
+            }
+            for cmd in db::parse_user_commands(&*db, *sf) {
+                if !commands.iter().any(|(c, _)| c.name == cmd.name) {
+                    commands.push((cmd, false));
+                }
+            }
+        }
+        commands
+    }
+
+    /// Set workspace roots from initialize params
+    fn set_workspace_roots(&self, params: &InitializeParams) {
+        let mut roots = self.workspace_roots.lock().unwrap();
+        roots.clear();
+
+        // Try workspace folders first (LSP 3.6+)
+        if let Some(folders) = &params.workspace_folders {
+            for folder in folders {
+                if let Some(path) = folder.uri.to_file_path() {
+                    roots.push(path.into_owned());
+                }
+            }
+        }
+
+        // Fall back to root_uri (deprecated but still used)
+        #[allow(deprecated)]
+        if roots.is_empty() {
+            if let Some(uri) = &params.root_uri {
+                if let Some(path) = uri.to_file_path() {
+                    roots.push(path.into_owned());
+                }
+            }
+        }
+
+        drop(roots);
+        self.load_config();
+    }
+
+    /// Use `root` as the only workspace root and load its configuration
+    pub fn set_workspace_root(&self, root: PathBuf) {
+        *self.workspace_roots.lock().unwrap() = vec![root];
+        self.load_config();
+    }
+
+    /// Get the format configuration
+    pub fn format_config(&self) -> FormatConfig {
+        self.config.lock().unwrap().format.clone()
+    }
+
+    /// Use `root` as the only workspace root and index it, without a client
+    ///
+    /// Used by the `check` command, which lints files on disk; indexing runs
+    /// on the calling thread and is complete when this returns.
+    pub fn index_workspace(&self, root: PathBuf) {
+        self.set_workspace_root(root);
+        self.load_script_runtimepath();
+        let filter = IndexFilter::new(&self.config.lock().unwrap().index);
+        index_workspace_background(
+            Arc::clone(&self.workspace_roots),
+            filter,
+            Arc::clone(&self.salsa_db),
+            Arc::clone(&self.source_files),
+            Arc::clone(&self.indexing_complete),
+            Arc::clone(&self.indexing_status),
+            |_| {},
+        );
+    }
+
+    /// Get the definitions of every indexed workspace file, keyed by path
+    ///
+    /// Functions, variables (without parameters) and user commands are
+    /// returned in the order they appear in each file.
+    pub fn workspace_definitions(
+        &self,
+    ) -> Vec<(String, Vec<symbols::Symbol>, Vec<symbols::UserCommand>)> {
+        let mut source_files = self.source_file_entries();
+        source_files.sort_by(|a, b| a.0.cmp(&b.0));
+        let db = self.salsa_db.lock().unwrap();
+        source_files
+            .into_iter()
+            .map(|(path, sf)| {
+                let symbols = db::parse_symbols(&*db, sf)
+                    .into_iter()
+                    .filter(|sym| sym.kind != SymbolKind::Parameter)
+                    .collect();
+                (path, symbols, db::parse_user_commands(&*db, sf))
+            })
+            .collect()
+    }
+
+    /// Read the runtime directories declared by the workspace scripts
+    ///
+    /// With `[index] runtimepath_from_scripts`, the `:set rtp+=`, `:Plug` and
+    /// `:packadd` lines of the indexed files add installed plugins to the
+    /// runtime path.
+    fn load_script_runtimepath(&self) {
+        let (enabled, filter) = {
+            let config = self.config.lock().unwrap();
+            (
+                config.index.runtimepath_from_scripts,
+                IndexFilter::new(&config.index),
+            )
+        };
+        let roots = self.workspace_roots.lock().unwrap().clone();
+        let dirs = if enabled {
+            let home = std::env::var_os("HOME").map(PathBuf::from);
+            let mut declarations = Declarations::default();
+            for root in &roots {
+                let mut files = Vec::new();
+                scan_directory_recursive(root, root, &filter, &mut files);
+                for source in files.iter().filter_map(|f| std::fs::read_to_string(f).ok()) {
+                    declarations.extend(Declarations::parse(&source, home.as_deref()));
+                }
+            }
+            declarations.resolve(&roots, home.as_deref())
+        } else {
+            Vec::new()
+        };
+        log_debug!("indexing: runtimepath from scripts {:?}", dirs);
+        *self.script_runtimepath.write().unwrap() = dirs;
+    }
+
+    /// Get the filter deciding which files under a directory are linted or indexed
+    pub fn index_filter(&self) -> IndexFilter {
+        IndexFilter::new(&self.config.lock().unwrap().index)
+    }
+
+    /// Load the configuration and apply the client lint settings over it
+    ///
+    /// The CLI config path takes priority, then `.hjkls.toml` in the workspace.
+    fn load_config(&self) {
+        let roots = self.workspace_roots.lock().unwrap().clone();
+        let loaded_config = if let Some(ref path) = self.config_path {
+            match Config::load(path) {
+                Ok(cfg) => {
+                    log_debug!("Loaded config from CLI path: {:?}", path);
+                    Some(cfg)
+                }
+                Err(e) => {
+                    log_debug!("Failed to load config from {:?}: {}", path, e);
+                    None
+                }
+            }
+        } else {
+            Config::find_in_workspace(&roots).inspect(|_| {
+                log_debug!("Loaded config from workspace");
+            })
+        };
+
+        let mut config = loaded_config.unwrap_or_default();
+        config
+            .lint
+            .merge(self.lint_settings.lock().unwrap().clone());
+        *self.config.lock().unwrap() = config;
+    }
+
+    /// Apply the client settings from `initializationOptions` or the client
+    /// configuration
+    ///
+    /// The settings replace the previous ones: a setting left out reverts to
+    /// its command-line value. Returns whether the runtime path changed.
+    fn apply_settings(&self, settings: Settings) -> bool {
+        let cli = &self.cli_settings;
+        if let Some(path) = settings.log {
+            logger::init(Some(path));
+        }
+
+        let mode = settings.editor_mode.or(cli.editor_mode).unwrap_or_default();
+        log_debug!("settings: editor mode {:?}", mode);
+        *self.editor_mode.write().unwrap() = mode;
+
+        let vimruntime = match settings.vimruntime {
+            Some(path) if path.exists() => Some(path),
+            Some(path) => {
+                log_debug!("settings: vimruntime not found: {:?}", path);
+                cli.vimruntime.clone()
+            }
+            None => cli.vimruntime.clone(),
+        };
+        log_debug!("settings: vimruntime {:?}", vimruntime);
+        let runtime_changed = {
+            let mut current = self.vimruntime.write().unwrap();
+            let changed = *current != vimruntime;
+            *current = vimruntime;
+            changed
+        };
+
+        let runtimepath = match settings.runtimepath {
+            Some(paths) => {
+                let (found, missing): (Vec<PathBuf>, Vec<PathBuf>) =
+                    paths.into_iter().partition(|path| path.is_dir());
+                if !missing.is_empty() {
+                    log_debug!("settings: runtimepath not found: {:?}", missing);
+                }
+                found
+            }
+            None => cli.runtimepath.clone().unwrap_or_default(),
+        };
+        log_debug!("settings: runtimepath {:?}", runtimepath);
+        *self.runtimepath.write().unwrap() = runtimepath;
+
+        *self.lint_settings.lock().unwrap() = settings.lint.unwrap_or_default();
+        runtime_changed
+    }
+
+    /// Drop what was read from the previous $VIMRUNTIME and index the new one
+    ///
+    /// Waits for the indexing, so that diagnostics published afterwards see
+    /// the functions of the new runtime.
+    async fn reset_runtime(&self) {
+        *self.runtime_autoload_functions.write().unwrap() = None;
+        *self.help_tags.write().unwrap() = None;
+        self.runtime_files.write().unwrap().clear();
+
+        // Before workspace indexing completes, the runtime is indexed after it
+        if !self.indexing_complete.load(Ordering::Relaxed) {
+            return;
+        }
+        let Some(runtime) = self
+            .vimruntime()
+            .filter(|_| self.config.lock().unwrap().index.vimruntime)
+        else {
+            return;
+        };
+        let salsa_db = Arc::clone(&self.salsa_db);
+        let runtime_files = Arc::clone(&self.runtime_files);
+        let indexing = tokio::task::spawn_blocking(move || {
+            index_runtime_background(&runtime, salsa_db, runtime_files)
+        });
+        if let Err(e) = indexing.await {
+            log_debug!("indexing: $VIMRUNTIME failed: {:?}", e);
+        }
+    }
+
+    /// Collect warnings for autoload function calls that reference non-existent files
+    fn collect_autoload_warnings(
+        &self,
+        tree: &Tree,
+        source: &str,
+        current_doc_uri: Option<&Uri>,
+    ) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let mut cursor = tree.walk();
+
+        self.collect_autoload_warnings_recursive(
+            &mut cursor,
+            source,
+            current_doc_uri,
+            &mut diagnostics,
+        );
+
+        diagnostics
+    }
+
+    fn collect_autoload_warnings_recursive(
+        &self,
+        cursor: &mut tree_sitter::TreeCursor,
+        source: &str,
+        current_doc_uri: Option<&Uri>,
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        loop {
+            let node = cursor.node();
+
+            // Check if this is a call_expression
+            if node.kind() == "call_expression" {
+                // Get the function name (first child is usually the function reference)
+                if let Some(func_node) = node.child(0) {
+                    let func_name = func_node.utf8_text(source.as_bytes()).unwrap_or("");
+
+                    // Check if it's an autoload function call (contains #)
+                    if let Some(autoload_ref) = symbols::AutoloadRef::parse(func_name) {
+                        // Check if the autoload file exists, unless the
+                        // function is declared in `[globals]`
+                        if !self.declares_function(func_name)
+                            && self
+                                .find_autoload_file(&autoload_ref, current_doc_uri)
+                                .is_none()
+                        {
+                            let start = func_node.start_position();
+                            let end = func_node.end_position();
+                            let expected_path = autoload_ref.to_file_path();
+
+                            diagnostics.push(Diagnostic {
+                                range: Range {
+                                    start: Position {
+                                        line: start.row as u32,
+                                        character: start.column as u32,
+                                    },
+                                    end: Position {
+                                        line: end.row as u32,
+                                        character: end.column as u32,
+                                    },
+                                },
+                                severity: Some(DiagnosticSeverity::WARNING),
+                                source: Some("hjkls".to_string()),
+                                message: format!("Autoload file not found: {}", expected_path),
+                                code: Some(NumberOrString::String(
+                                    "hjkls/autoload_missing".to_string(),
+                                )),
+                                ..Default::default()
+                            });
+                        }
+                    }
+                }
+            }
+
+            // Recurse into children
+            if cursor.goto_first_child() {
+                self.collect_autoload_warnings_recursive(
+                    cursor,
+                    source,
+                    current_doc_uri,
+                    diagnostics,
+                );
+                cursor.goto_parent();
+            }
+
+            if !cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+
+    /// Collect warnings for function calls with wrong number of arguments
+    fn collect_arity_warnings(&self, tree: &Tree, source: &str, uri: &Uri) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let mut cursor = tree.walk();
+
+        // Get user-defined symbols for this document, and functions provided
+        // by $VIMRUNTIME (when indexed) or declared in `[globals]`, with the
+        // location of their definition
+        let uri_str = uri.to_string();
+        let mut symbols: Vec<(symbols::Symbol, Option<Location>)> = self
+            .get_symbols(&uri_str, source)
+            .into_iter()
+            .map(|sym| {
+                let location = Location {
+                    uri: uri.clone(),
+                    range: symbol_range(&sym),
+                };
+                (sym, Some(location))
+            })
+            .collect();
+        let runtime_files = self.runtime_file_entries();
+        {
+            let db = self.salsa_db.lock().unwrap();
+            for (key, sf) in &runtime_files {
+                let file_uri = Uri::from_file_path(source_file_path(key));
+                for sym in db::parse_symbols(&*db, *sf) {
+                    if sym.kind == SymbolKind::Function && sym.scope != symbols::VimScope::Script {
+                        let location = file_uri.clone().map(|uri| Location {
+                            uri,
+                            range: symbol_range(&sym),
+                        });
+                        symbols.push((sym, location));
+                    }
+                }
+            }
+        }
+        symbols.extend(self.declared_functions().into_iter().map(|sym| (sym, None)));
+
+        Self::collect_arity_warnings_recursive(&mut cursor, source, &symbols, &mut diagnostics);
+
+        diagnostics
+    }
+
+    fn collect_arity_warnings_recursive(
+        cursor: &mut tree_sitter::TreeCursor,
+        source: &str,
+        symbols: &[(symbols::Symbol, Option<Location>)],
+        diagnostics: &mut Vec<Diagnostic>,
+    ) {
+        loop {
+            let node = cursor.node();
+
+            // Check if this is a call_expression
+            if node.kind() == "call_expression" {
+                if let Some(func_node) = node.child(0) {
+                    let func_name = func_node.utf8_text(source.as_bytes()).unwrap_or("");
+
+                    // Skip empty names; autoload functions are only checked
+                    // when defined locally or under an indexed $VIMRUNTIME
+                    if func_name.is_empty() {
+                        // Continue to recurse but skip arity check
+                    } else {
+                        // Try to find signature - first check built-in functions
+                        let mut definition = None;
+                        let signature = BuiltinFunction::find(func_name)
                             .map(|f| f.signature.to_string())
                             .or_else(|| {
                                 // Then check user-defined functions
-                                symbols
-                                    .iter()
-                                    .find(|s| {
-                                        s.kind == symbols::SymbolKind::Function
-                                            && s.full_name() == func_name
-                                    })
-                                    .and_then(|s| s.signature.clone())
+                                let (sym, location) = symbols.iter().find(|(s, _)| {
+                                    s.kind == symbols::SymbolKind::Function
+                                        && s.full_name() == func_name
+                                })?;
+                                definition = location.clone();
+                                sym.signature.clone()
                             });
 
                         if let Some(sig) = signature {
@@ -328,6 +1439,13 @@ impl Backend {
                                     code: Some(NumberOrString::String(
                                         "hjkls/arity_mismatch".to_string(),
                                     )),
+                                    // Point at the definition of a user function
+                                    related_information: definition.map(|location| {
+                                        vec![DiagnosticRelatedInformation {
+                                            location,
+                                            message: format!("Signature: {}", sig),
+                                        }]
+                                    }),
                                     ..Default::default()
                                 });
                             }
@@ -349,10 +1467,34 @@ impl Backend {
     }
 
     /// Collect warnings for scope violations (l: or a: used outside functions)
-    fn collect_scope_violations(&self, tree: &Tree, source: &str) -> Vec<Diagnostic> {
+    ///
+    /// With the `uri` of the document, the warnings point at their context:
+    /// the script-level block around the usage, and the end of the function
+    /// before it (a misplaced `endfunction` leaves the code outside).
+    pub(crate) fn collect_scope_violations(
+        tree: &Tree,
+        source: &str,
+        uri: Option<&Uri>,
+    ) -> Vec<Diagnostic> {
         let mut diagnostics = Vec::new();
         let root = tree.root_node();
         Self::collect_scope_violations_recursive(&root, source, false, &mut diagnostics);
+        if let Some(uri) = uri {
+            for diag in &mut diagnostics {
+                let related: Vec<DiagnosticRelatedInformation> =
+                    scope_violation_context(&root, source, diag.range)
+                        .into_iter()
+                        .map(|(range, message)| DiagnosticRelatedInformation {
+                            location: Location {
+                                uri: uri.clone(),
+                                range,
+                            },
+                            message,
+                        })
+                        .collect();
+                diag.related_information = (!related.is_empty()).then_some(related);
+            }
+        }
         diagnostics
     }
 
@@ -446,15 +1588,10 @@ impl Backend {
         }
     }
 
-    /// Collect style hints (code style suggestions, DiagnosticSeverity::HINT)
-    fn collect_style_hints(&self, tree: &Tree, source: &str) -> Vec<Diagnostic> {
-        diagnostics::collect_style_hints(tree, source)
-    }
-
     /// Collect warnings for undefined function calls.
     ///
     /// Checks:
-    /// - Built-in functions (786 in BUILTIN_FUNCTIONS)
+    /// - Built-in functions (787 in BUILTIN_FUNCTIONS)
     /// - Script-local functions (s:) - must be defined in the same file
     /// - Global functions - checked in local symbols and workspace
     ///
@@ -475,11 +1612,12 @@ impl Backend {
 
         // Get all workspace functions (if indexing is complete)
         let workspace_functions: Vec<String> = if self.indexing_complete.load(Ordering::SeqCst) {
-            let source_files = self.source_files.lock().unwrap();
+            let runtime_functions = self.runtime_functions();
+            let source_files = self.source_file_entries();
             let db = self.salsa_db.lock().unwrap();
             source_files
                 .iter()
-                .filter(|(file_uri, _)| *file_uri != &uri_str)
+                .filter(|(file_uri, _)| *file_uri != uri_str)
                 .flat_map(|(_, sf)| {
                     db::parse_symbols(&*db, *sf)
                         .iter()
@@ -491,6 +1629,7 @@ impl Backend {
                         .map(|s| s.full_name())
                         .collect::<Vec<_>>()
                 })
+                .chain(runtime_functions.iter().map(|s| s.full_name()))
                 .collect()
         } else {
             vec![]
@@ -605,8 +1744,12 @@ impl Backend {
         local_symbols: &[symbols::Symbol],
         workspace_functions: &[String],
     ) -> bool {
-        // Check built-in functions first
-        if BUILTIN_FUNCTIONS.iter().any(|f| f.name == func_name) {
+        // Check built-in functions (deprecated ones included, the `deprecated`
+        // rule reports them) and the ones declared in `[globals]` first
+        if BuiltinFunction::find(func_name).is_some()
+            || Deprecation::find(DeprecationKind::Function, func_name).is_some()
+            || self.declares_function(func_name)
+        {
             return false;
         }
 
@@ -653,10 +1796,19 @@ impl Backend {
             "while_loop",
             "try_statement",
             "augroup",
+            "dictionnary",
+            "list",
         ];
+        // Other statements fold when they span several lines: `\` line
+        // continuations and `let x =<< END` heredocs
+        let is_statement = node.is_named()
+            && node.kind() != "comment"
+            && node
+                .parent()
+                .is_some_and(|parent| matches!(parent.kind(), "script_file" | "body"));
 
         // Check if current node is foldable
-        if foldable_kinds.contains(&node.kind()) {
+        if foldable_kinds.contains(&node.kind()) || is_statement {
             let start_line = node.start_position().row as u32;
             let end_line = node.end_position().row as u32;
 
@@ -680,6 +1832,89 @@ impl Backend {
         }
     }
 
+    /// Collect folding ranges from fold markers (`" Section {{{1` ... `" }}}`)
+    ///
+    /// Like `foldmethod=marker`: a marker with a level starts (or ends) a fold
+    /// of that level, closing the deeper ones; without a level, it starts a
+    /// fold inside the current one (or ends the current one). The markers are
+    /// 'foldmarker' of the modeline, `{{{` and `}}}` by default.
+    /// Reference: :help fold-marker
+    fn collect_marker_folding_ranges(source: &str) -> Vec<FoldingRange> {
+        let (start_marker, end_marker) = Modeline::parse(source)
+            .foldmarker
+            .unwrap_or_else(|| ("{{{".to_string(), "}}}".to_string()));
+        let mut ranges = Vec::new();
+        // Open folds with their level and start line
+        let mut open: Vec<(usize, usize)> = Vec::new();
+        let mut close = |start: usize, end: usize| {
+            if end > start {
+                ranges.push(FoldingRange {
+                    start_line: start as u32,
+                    start_character: None,
+                    end_line: end as u32,
+                    end_character: None,
+                    kind: Some(FoldingRangeKind::Region),
+                    collapsed_text: None,
+                });
+            }
+        };
+
+        let mut last_line = 0;
+        for (row, line) in source.lines().enumerate() {
+            last_line = row;
+            let mut rest = line;
+            while let Some((index, is_start)) = [(&start_marker, true), (&end_marker, false)]
+                .into_iter()
+                .filter_map(|(marker, is_start)| Some((rest.find(marker.as_str())?, is_start)))
+                .min()
+            {
+                let marker_len = if is_start {
+                    start_marker.len()
+                } else {
+                    end_marker.len()
+                };
+                rest = &rest[index + marker_len..];
+                let digits =
+                    rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+                let level = rest[..digits].parse::<usize>().ok().filter(|&l| l > 0);
+                rest = &rest[digits..];
+
+                let current = open.last().map_or(0, |&(level, _)| level);
+                match (is_start, level) {
+                    (true, Some(level)) => {
+                        // A fold of the same level ends on the line before
+                        while let Some(&(open_level, start)) = open.last()
+                            && open_level >= level
+                        {
+                            open.pop();
+                            close(start, row.saturating_sub(1));
+                        }
+                        open.push((level, row));
+                    }
+                    (true, None) => open.push((current + 1, row)),
+                    (false, Some(level)) => {
+                        while let Some(&(open_level, start)) = open.last()
+                            && open_level >= level
+                        {
+                            open.pop();
+                            close(start, row);
+                        }
+                    }
+                    (false, None) => {
+                        if let Some((_, start)) = open.pop() {
+                            close(start, row);
+                        }
+                    }
+                }
+            }
+        }
+        // Folds still open run to the end of the file
+        while let Some((_, start)) = open.pop() {
+            close(start, last_line);
+        }
+        ranges
+    }
+
     /// Replace single dot concatenation with double dot in Vim script
     /// Only replaces `.` that is surrounded by spaces (string concatenation)
     fn replace_single_dot_with_double(text: &str) -> String {
@@ -709,6 +1944,112 @@ impl Backend {
         result
     }
 
+    /// Custom request `hjkls/syntaxTree`: the tree-sitter S-expression of a
+    /// document, for parse-related bug reports and plugin authors
+    ///
+    /// With a range, only the smallest named node enclosing it is dumped.
+    async fn syntax_tree(&self, params: SyntaxTreeParams) -> Result<Option<String>> {
+        self.documents_synced().await;
+        let docs = self.documents.read().unwrap();
+        let Some(doc) = docs.get(&params.text_document.uri) else {
+            return Ok(None);
+        };
+        Ok(Self::syntax_tree_sexp(
+            &doc.tree,
+            &doc.text.text,
+            params.range,
+        ))
+    }
+
+    /// Get the S-expression of a tree, or of the node enclosing a range
+    fn syntax_tree_sexp(tree: &Tree, source: &str, range: Option<Range>) -> Option<String> {
+        let node = match range {
+            Some(range) => tree.root_node().named_descendant_for_byte_range(
+                sync::position_to_byte(source, range.start),
+                sync::position_to_byte(source, range.end),
+            )?,
+            None => tree.root_node(),
+        };
+        Some(node.to_sexp())
+    }
+
+    /// Custom request `hjkls/indexingStatus`: the progress of the workspace
+    /// indexing, for statusline indicators
+    async fn indexing_status(&self) -> Result<IndexingStatus> {
+        Ok(self.indexing_status.lock().unwrap().clone())
+    }
+
+    /// Get the occurrences of the function-local variable at a position, for
+    /// linked editing
+    ///
+    /// `x` and `l:x` are the same variable inside a function. Only the names
+    /// are linked, so the `l:` prefixes stay as written. Parameters, fields
+    /// and called functions with the same name aren't the variable.
+    fn local_variable_ranges(tree: &Tree, source: &str, position: Position) -> Option<Vec<Range>> {
+        let (row, col) = (position.line as usize, position.character as usize);
+        let reference = find_identifier_at_position(tree, source, row, col)?;
+        if reference.is_call
+            || !matches!(
+                reference.scope,
+                symbols::VimScope::Implicit | symbols::VimScope::Local
+            )
+        {
+            return None;
+        }
+        let function = symbols::find_node_of_kind(tree, row, col, &["function_definition"])?;
+
+        let is_variable = |ident: &tree_sitter::Node| {
+            let Some(parent) = ident.parent() else {
+                return false;
+            };
+            match parent.kind() {
+                "parameters" | "argument" => false,
+                "field_expression" => parent.child_by_field_name("field") != Some(*ident),
+                "call_expression" => parent.child_by_field_name("function") != Some(*ident),
+                _ => true,
+            }
+        };
+        let mut ranges: Vec<Range> = [symbols::VimScope::Implicit, symbols::VimScope::Local]
+            .into_iter()
+            .flat_map(|scope| find_references_with_kind(tree, source, &reference.name, scope))
+            .filter_map(|r| {
+                let start = tree_sitter::Point::new(r.location.start.0, r.location.start.1);
+                let end = tree_sitter::Point::new(r.location.end.0, r.location.end.1);
+                if start < function.start_position() || end > function.end_position() {
+                    return None;
+                }
+                // Function names in strings and mappings aren't identifiers
+                let node = function.named_descendant_for_point_range(start, end)?;
+                let ident = match node.kind() {
+                    "identifier" => node,
+                    "scoped_identifier" => node.child(node.child_count().checked_sub(1)?)?,
+                    _ => return None,
+                };
+                if ident.kind() != "identifier" || !is_variable(&ident) {
+                    return None;
+                }
+                Some(Range::new(
+                    Position::new(
+                        ident.start_position().row as u32,
+                        ident.start_position().column as u32,
+                    ),
+                    Position::new(
+                        ident.end_position().row as u32,
+                        ident.end_position().column as u32,
+                    ),
+                ))
+            })
+            .collect();
+        ranges.sort_by_key(|range| (range.start.line, range.start.character));
+        ranges.dedup();
+
+        // The cursor may be on a field or a parameter of the same name
+        let on_variable = ranges.iter().any(|range| {
+            range.start <= position && position <= range.end && range.start.line == position.line
+        });
+        on_variable.then_some(ranges)
+    }
+
     /// Build a SelectionRange chain from the innermost node to the root
     fn build_selection_range(
         tree: &tree_sitter::Tree,
@@ -758,8 +2099,432 @@ impl Backend {
                 parent: result.map(Box::new),
             });
         }
-
-        result
+
+        result
+    }
+
+    /// Find the definition of an autoload function in its autoload file
+    fn find_autoload_definition(
+        &self,
+        autoload_ref: &symbols::AutoloadRef,
+        uri: &Uri,
+    ) -> Option<LocationLink> {
+        log_debug!("goto_definition: autoload={}", autoload_ref.full_name);
+
+        // Try to find the autoload file (search relative to current doc first)
+        let Some(file_path) = self.find_autoload_file(autoload_ref, Some(uri)) else {
+            log_debug!(
+                "goto_definition: file not found for {}",
+                autoload_ref.to_file_path()
+            );
+            return None;
+        };
+        log_debug!("goto_definition: found {:?}", file_path);
+
+        // Parse the file and find the function definition
+        let Some(symbols) = self.file_symbols(&file_path) else {
+            log_debug!("goto_definition: failed to read {:?}", file_path);
+            return None;
+        };
+        log_debug!(
+            "goto_definition: symbols={:?}",
+            symbols.iter().map(|s| &s.name).collect::<Vec<_>>()
+        );
+
+        // Look for the function with matching name
+        // Autoload files define functions with full name (e.g., myplugin#util#helper)
+        let Some(symbol) = symbols
+            .iter()
+            .find(|s| s.kind == SymbolKind::Function && s.name == autoload_ref.full_name)
+        else {
+            log_debug!("goto_definition: no match for '{}'", autoload_ref.full_name);
+            return None;
+        };
+
+        let Some(target_uri) = Uri::from_file_path(&file_path) else {
+            log_debug!("goto_definition: invalid URI for {:?}", file_path);
+            return None;
+        };
+
+        log_debug!("goto_definition: jumping to {:?}", target_uri);
+        Some(symbol_link(target_uri, symbol))
+    }
+
+    /// Resolve the definition or declaration at a position
+    ///
+    /// With `links` set, definitions of functions and variables are links
+    /// targeting the whole definition (`function` to `endfunction`) and
+    /// selecting the name. Otherwise they are the locations of the name.
+    fn find_definition(
+        &self,
+        params: GotoDefinitionParams,
+        links: bool,
+        target: Target,
+    ) -> Option<GotoDefinitionResponse> {
+        let uri = params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+
+        // Vim9 imports are not understood by tree-sitter-vim, so check them first
+        // (without holding the documents lock, as this reads the imported file)
+        let text = {
+            let docs = self.documents.read().unwrap();
+            let doc = docs.get(&uri)?;
+            Arc::clone(&doc.text)
+        };
+        if let Some(location) = self.find_vim9_import_definition(
+            &text.text,
+            &uri,
+            position.line as usize,
+            position.character as usize,
+        ) {
+            return Some(GotoDefinitionResponse::Scalar(location));
+        }
+
+        let docs = self.documents.read().unwrap();
+        let doc = docs.get(&uri)?;
+
+        // A file argument of `:source`/`:runtime` opens the file
+        if let Some(file) = symbols::find_sourced_file(
+            &doc.tree,
+            &doc.text.text,
+            position.line as usize,
+            position.character as usize,
+        ) {
+            drop(docs);
+            let location = self.find_sourced_file(&file, &uri).and_then(|path| {
+                Some(Location {
+                    uri: Uri::from_file_path(&path)?,
+                    range: Range::default(),
+                })
+            });
+            return location.map(GotoDefinitionResponse::Scalar);
+        }
+
+        // `v:lua.require'mod'.func` and `luaeval("require'mod'...")` open the
+        // Lua module under `lua/` (Neovim)
+        let line = text.text.lines().nth(position.line as usize).unwrap_or("");
+        let lua_reference = Availability::NeovimOnly
+            .is_compatible(self.editor_mode())
+            .then(|| lua::find_reference(line, position.character as usize))
+            .flatten();
+        if let Some(module) = lua_reference.as_ref().and_then(|r| r.module.as_ref()) {
+            drop(docs);
+            let path = self.resolve_lua_module(module)?;
+            let line = lua_reference
+                .as_ref()
+                .and_then(|r| r.function.as_ref())
+                .and_then(|name| {
+                    let source = std::fs::read_to_string(&path).ok()?;
+                    lua::parse_module_functions(&source)
+                        .into_iter()
+                        .find(|f| &f.name == name)
+                        .map(|f| f.line)
+                })
+                .unwrap_or(0);
+            let position = Position { line, character: 0 };
+            return Some(GotoDefinitionResponse::Scalar(Location {
+                uri: Uri::from_file_path(&path)?,
+                range: Range {
+                    start: position,
+                    end: position,
+                },
+            }));
+        }
+
+        // Find the identifier at the cursor position, or a function name in a
+        // string or mapping (e.g., `function('s:Func')`, `<SID>Func()`), or
+        // called from Lua (`luaeval('vim.fn.Func()')`)
+        let reference = find_identifier_at_position(
+            &doc.tree,
+            &doc.text.text,
+            position.line as usize,
+            position.character as usize,
+        )
+        .or_else(|| {
+            symbols::find_function_name_reference(
+                &doc.tree,
+                &doc.text.text,
+                position.line as usize,
+                position.character as usize,
+            )
+        })
+        .or_else(|| {
+            lua_reference
+                .filter(|r| r.bridge == lua::LuaBridge::VimFn)
+                .and_then(|r| symbols::parse_function_name(r.function.as_deref()?))
+        })?;
+
+        // Check if this is an autoload function call
+        if let Some(autoload_ref) = &reference.autoload {
+            // Release the lock before doing file I/O
+            drop(docs);
+
+            return match self.find_autoload_definition(autoload_ref, &uri) {
+                Some(link) => definition_response(vec![link], links),
+                None => self
+                    .find_tag_definition(&uri, &reference)
+                    .map(GotoDefinitionResponse::Scalar),
+            };
+        }
+
+        // Extract symbols and find the definition in current file
+        let uri_str = uri.to_string();
+        let is_global_variable = !reference.is_call
+            && symbols::find_token_at_position(
+                &doc.tree,
+                position.line as usize,
+                position.character as usize,
+            )
+            .and_then(|node| node.utf8_text(text.text.as_bytes()).ok())
+            .is_some_and(|text| text.starts_with("g:"));
+        drop(docs); // Release lock before calling get_symbols
+
+        let symbols = self.get_symbols(&uri_str, &text.text);
+
+        // Find matching symbol definition
+        let mut candidates = symbols.iter().filter(|s| {
+            s.name == reference.name
+                && (reference.scope == symbols::VimScope::Implicit || s.scope == reference.scope)
+                && (reference.is_call == (s.kind == SymbolKind::Function)
+                    || !reference.is_call && s.kind == SymbolKind::Variable)
+        });
+        let definition = if reference.is_call {
+            candidates.next()
+        } else {
+            variable_assignment(&symbols, candidates, reference.scope, position, target)
+        };
+
+        // Global variables are typically set in a different script than where
+        // they are read: offer the first assignment in each file
+        if is_global_variable {
+            let mut targets: Vec<LocationLink> = definition
+                .filter(|symbol| symbol.scope == symbols::VimScope::Global)
+                .map(|symbol| symbol_link(uri.clone(), symbol))
+                .into_iter()
+                .collect();
+            for (file_key, symbol) in self.workspace_global_variables(&uri_str, &reference.name) {
+                if let Some(file_uri) = Uri::from_file_path(source_file_path(&file_key)) {
+                    targets.push(symbol_link(file_uri, &symbol));
+                }
+            }
+            return definition_response(targets, links);
+        }
+
+        if let Some(symbol) = definition {
+            return definition_response(vec![symbol_link(uri.clone(), symbol)], links);
+        }
+
+        // Fall back to global functions defined in other files of the workspace
+        if reference.is_call
+            && matches!(
+                reference.scope,
+                symbols::VimScope::Implicit | symbols::VimScope::Global
+            )
+        {
+            if let Some((file_key, symbol)) = self.workspace_function(&uri_str, &reference.name) {
+                log_debug!("goto_definition: {} found in {}", reference.name, file_key);
+                if let Some(target_uri) = Uri::from_file_path(source_file_path(&file_key)) {
+                    return definition_response(vec![symbol_link(target_uri, &symbol)], links);
+                }
+            }
+        }
+
+        // Fall back to tags files (e.g., generated by ctags for huge runtimes)
+        self.find_tag_definition(&uri, &reference)
+            .map(GotoDefinitionResponse::Scalar)
+    }
+
+    /// Snapshot the indexed files, one entry per file
+    ///
+    /// Open documents are keyed by URI and also indexed by path; the open
+    /// document (holding unsaved changes) is preferred.
+    fn indexed_files(&self) -> Vec<(String, SourceFile)> {
+        let mut files = self.source_file_entries();
+        files.sort_by_key(|(key, _)| !key.starts_with("file://"));
+        let mut seen = HashSet::new();
+        files.retain(|(key, _)| seen.insert(source_file_path(key)));
+        files
+    }
+
+    /// Get the latest content of an indexed file, preferring its open document
+    fn indexed_content(&self, path: &Path) -> Option<String> {
+        let (_, source_file) = self
+            .indexed_files()
+            .into_iter()
+            .find(|(key, _)| source_file_path(key) == path)?;
+        Some(db::file_content(
+            &*self.salsa_db.lock().unwrap(),
+            source_file,
+        ))
+    }
+
+    /// Move the autoload file of a renamed function to match its new namespace
+    ///
+    /// The file is renamed when it defines no other function of the old
+    /// namespace; otherwise the function is moved to a newly created file.
+    /// Edits of the old file in `changes` are adjusted accordingly, and the
+    /// returned operations are to be applied after them. Nothing is moved
+    /// when the namespace is unchanged or the new file already exists.
+    fn autoload_file_operations(
+        &self,
+        autoload_ref: &symbols::AutoloadRef,
+        new_name: &str,
+        uri: &Uri,
+        changes: &mut HashMap<Uri, Vec<TextEdit>>,
+    ) -> Vec<DocumentChangeOperation> {
+        let Some(new_ref) = symbols::AutoloadRef::parse(new_name) else {
+            return vec![];
+        };
+        if new_ref.path_parts == autoload_ref.path_parts {
+            return vec![];
+        }
+        let Some(old_path) = self.find_autoload_file(autoload_ref, Some(uri)) else {
+            return vec![];
+        };
+        // `autoload/foo/bar.vim` is relative to the runtime directory
+        let Some(base) = old_path.ancestors().nth(autoload_ref.path_parts.len() + 1) else {
+            return vec![];
+        };
+        let new_path = base.join(new_ref.to_file_path());
+        if new_path.exists() {
+            log_debug!("rename: {:?} already exists, not moving", new_path);
+            return vec![];
+        }
+        let (Some(old_uri), Some(new_uri)) = (
+            Uri::from_file_path(&old_path),
+            Uri::from_file_path(&new_path),
+        ) else {
+            return vec![];
+        };
+        let Some(content) = self
+            .indexed_content(&old_path)
+            .or_else(|| std::fs::read_to_string(&old_path).ok())
+        else {
+            return vec![];
+        };
+
+        let namespace = format!("{}#", autoload_ref.path_parts.join("#"));
+        // Parsed apart: the content may be unsaved, unlike the indexed file
+        let symbols = crate::analysis::extract_symbols(&content);
+        let functions: Vec<_> = symbols
+            .iter()
+            .filter(|s| s.kind == SymbolKind::Function && s.name.starts_with(&namespace))
+            .collect();
+        let others = functions.iter().any(|s| s.name != autoload_ref.full_name);
+
+        if !others && self.rename_file_support.load(Ordering::Relaxed) {
+            return vec![DocumentChangeOperation::Op(ResourceOp::Rename(
+                RenameFile {
+                    old_uri,
+                    new_uri,
+                    options: None,
+                    annotation_id: None,
+                },
+            ))];
+        }
+        if !self.create_file_support.load(Ordering::Relaxed) {
+            return vec![];
+        }
+        let Some(function) = functions.iter().find(|s| s.name == autoload_ref.full_name) else {
+            return vec![];
+        };
+
+        // Move the lines of the function, applying the edits inside them
+        let ((first, _), (last, _)) = function.extent;
+        let mut lines: Vec<String> = content
+            .lines()
+            .skip(first)
+            .take(last - first + 1)
+            .map(String::from)
+            .collect();
+        let old_edits = changes.entry(old_uri.clone()).or_default();
+        let (mut moved, kept): (Vec<TextEdit>, Vec<TextEdit>) = old_edits
+            .drain(..)
+            .partition(|edit| (first..=last).contains(&(edit.range.start.line as usize)));
+        *old_edits = kept;
+        moved.sort_by_key(|edit| std::cmp::Reverse(edit.range.start));
+        for edit in moved {
+            let Some(line) = lines.get_mut(edit.range.start.line as usize - first) else {
+                continue;
+            };
+            let start = edit.range.start.character as usize;
+            let end = edit.range.end.character as usize;
+            if edit.range.start.line == edit.range.end.line && end <= line.len() {
+                line.replace_range(start..end, &edit.new_text);
+            }
+        }
+        old_edits.push(TextEdit {
+            range: Range {
+                start: Position {
+                    line: first as u32,
+                    character: 0,
+                },
+                end: Position {
+                    line: last as u32 + 1,
+                    character: 0,
+                },
+            },
+            new_text: String::new(),
+        });
+
+        vec![
+            DocumentChangeOperation::Op(ResourceOp::Create(CreateFile {
+                uri: new_uri.clone(),
+                options: None,
+                annotation_id: None,
+            })),
+            DocumentChangeOperation::Edit(TextDocumentEdit {
+                text_document: OptionalVersionedTextDocumentIdentifier {
+                    uri: new_uri,
+                    version: None,
+                },
+                edits: vec![OneOf::Left(TextEdit {
+                    range: Range::default(),
+                    new_text: lines.join("\n") + "\n",
+                })],
+            }),
+        ]
+    }
+
+    /// Find a definition in a ctags-compatible `tags` file
+    ///
+    /// Used as a fallback when a symbol cannot be resolved from the index.
+    fn find_tag_definition(&self, uri: &Uri, reference: &symbols::Reference) -> Option<Location> {
+        let full_name = format!("{}{}", reference.scope.as_str(), reference.name);
+        let names = [reference.name.as_str(), full_name.as_str()];
+        let current = uri.to_file_path();
+        let roots = self.workspace_roots.lock().unwrap().clone();
+        for candidate in tags::candidates(current.as_deref(), &roots) {
+            let Ok(content) = std::fs::read_to_string(&candidate) else {
+                continue;
+            };
+            let Some(entry) = tags::find_entry(&content, &names) else {
+                continue;
+            };
+            let file_path = candidate.parent()?.join(&entry.file);
+            let line = std::fs::read_to_string(&file_path)
+                .ok()
+                .and_then(|content| entry.line(&content))
+                .unwrap_or(0);
+            log_debug!(
+                "goto_definition: tag {} -> {:?}:{}",
+                entry.name,
+                file_path,
+                line
+            );
+            let position = Position {
+                line: line as u32,
+                character: 0,
+            };
+            return Some(Location {
+                uri: Uri::from_file_path(&file_path)?,
+                range: Range {
+                    start: position,
+                    end: position,
+                },
+            });
+        }
+        None
     }
 
     /// Find autoload file in workspace or relative to a document
@@ -783,177 +2548,811 @@ impl Backend {
             }
         }
 
-        // Then, try workspace roots
-        let roots = self.workspace_roots.lock().unwrap();
-        for root in roots.iter() {
-            let full_path = root.join(&relative_path);
-            if full_path.exists() {
-                return Some(full_path);
+        // Then, try the plugin of the document, the workspace roots and the
+        // other plugins in the workspace, and finally the runtime path and
+        // $VIMRUNTIME
+        let current = current_doc_uri.and_then(|uri| uri.to_file_path());
+        self.workspace_runtime_dirs(current.as_deref())
+            .into_iter()
+            .chain(self.runtime_dirs())
+            .map(|dir| dir.join(&relative_path))
+            .find(|full_path| full_path.exists())
+    }
+
+    /// Resolve the file argument of `:source`/`:runtime`
+    ///
+    /// `:source` paths are tried relative to the current document's directory
+    /// and the workspace roots; `:runtime` paths relative to the workspace
+    /// roots, the runtime path and $VIMRUNTIME. Wildcards in the file name
+    /// pick the first match.
+    fn find_sourced_file(
+        &self,
+        file: &symbols::SourcedFile,
+        current_doc_uri: &Uri,
+    ) -> Option<PathBuf> {
+        let path = file.path.as_str();
+        let candidates: Vec<PathBuf> = if path.starts_with('/') {
+            vec![PathBuf::from(path)]
+        } else if let Some(rest) = path.strip_prefix("~/") {
+            vec![PathBuf::from(std::env::var("HOME").ok()?).join(rest)]
+        } else {
+            let mut dirs = Vec::new();
+            if !file.runtime {
+                if let Some(doc_dir) = current_doc_uri
+                    .to_file_path()
+                    .and_then(|p| p.parent().map(Path::to_path_buf))
+                {
+                    dirs.push(doc_dir);
+                }
+            }
+            if file.runtime {
+                let current = current_doc_uri.to_file_path();
+                dirs.extend(self.workspace_runtime_dirs(current.as_deref()));
+                dirs.extend(self.runtime_dirs());
+            } else {
+                dirs.extend(self.workspace_roots.lock().unwrap().iter().cloned());
+            }
+            dirs.into_iter().map(|dir| dir.join(path)).collect()
+        };
+        candidates.into_iter().find_map(|candidate| {
+            let name = candidate.file_name()?.to_str()?;
+            if !name.contains(['*', '?']) {
+                return candidate.is_file().then_some(candidate);
+            }
+            let mut matches: Vec<PathBuf> = std::fs::read_dir(candidate.parent()?)
+                .ok()?
+                .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+                .filter(|p| {
+                    p.is_file()
+                        && p.file_name()
+                            .and_then(|n| n.to_str())
+                            .is_some_and(|n| wildcard_match(name, n))
+                })
+                .collect();
+            matches.sort();
+            matches.into_iter().next()
+        })
+    }
+
+    /// Resolve the target file of a Vim9 `import` statement
+    ///
+    /// - Relative paths (`./`, `../`) are resolved from the importing script
+    /// - Absolute paths are used as-is
+    /// - Other paths are searched under `import/` (or `autoload/` for
+    ///   `import autoload`) in workspace roots, the runtime path and $VIMRUNTIME
+    fn resolve_vim9_import(
+        &self,
+        import: &vim9::Import,
+        current_doc_uri: Option<&Uri>,
+    ) -> Option<PathBuf> {
+        let doc_dir = current_doc_uri
+            .and_then(|uri| uri.to_file_path())
+            .and_then(|path| path.parent().map(|p| p.to_path_buf()));
+
+        if import.is_relative() {
+            // Resolve `./` and `../` lexically so the path matches the opened document's URI
+            let mut full_path = doc_dir?;
+            for component in Path::new(&import.path).components() {
+                match component {
+                    std::path::Component::CurDir => {}
+                    std::path::Component::ParentDir => {
+                        full_path.pop();
+                    }
+                    other => full_path.push(other),
+                }
             }
+            return full_path.exists().then_some(full_path);
         }
 
-        // Finally, try $VIMRUNTIME
-        if let Some(runtime) = &self.vimruntime {
-            let full_path = runtime.join(&relative_path);
-            if full_path.exists() {
-                return Some(full_path);
+        if import.is_absolute() {
+            let path = match import.path.strip_prefix("~/") {
+                Some(rest) => PathBuf::from(std::env::var("HOME").ok()?).join(rest),
+                None => PathBuf::from(&import.path),
+            };
+            return path.exists().then_some(path);
+        }
+
+        let relative_path = PathBuf::from(import.runtime_subdir()).join(&import.path);
+
+        // `import autoload "x.vim"` may also be relative to the script itself
+        if import.autoload {
+            if let Some(full_path) = doc_dir.map(|dir| dir.join(&import.path)) {
+                if full_path.exists() {
+                    return Some(full_path);
+                }
             }
         }
 
-        None
+        let current = current_doc_uri.and_then(|uri| uri.to_file_path());
+        self.workspace_runtime_dirs(current.as_deref())
+            .into_iter()
+            .chain(self.runtime_dirs())
+            .map(|dir| dir.join(&relative_path))
+            .find(|full_path| full_path.exists())
+    }
+
+    /// Collect warnings for Vim9 imports that cannot be resolved, and for
+    /// `Alias.Name` uses where `Name` is not exported by the imported script
+    fn collect_vim9_import_warnings(&self, source: &str, uri: &Uri) -> Vec<Diagnostic> {
+        let mut diagnostics = Vec::new();
+        let imports = vim9::parse_imports(source);
+        if imports.is_empty() {
+            return diagnostics;
+        }
+
+        // Exported names of each successfully resolved import, keyed by import name
+        let mut exports_by_name: HashMap<String, (String, Vec<vim9::Export>)> = HashMap::new();
+
+        for import in &imports {
+            let Some(path) = self.resolve_vim9_import(import, Some(uri)) else {
+                diagnostics.push(Diagnostic {
+                    range: Range {
+                        start: Position {
+                            line: import.line as u32,
+                            character: import.path_start as u32,
+                        },
+                        end: Position {
+                            line: import.line as u32,
+                            character: import.path_end as u32,
+                        },
+                    },
+                    severity: Some(DiagnosticSeverity::WARNING),
+                    source: Some("hjkls".to_string()),
+                    message: format!("Import file not found: {}", import.path),
+                    code: Some(NumberOrString::String(
+                        "hjkls/import_unresolved".to_string(),
+                    )),
+                    ..Default::default()
+                });
+                continue;
+            };
+
+            if let Ok(content) = std::fs::read_to_string(&path) {
+                exports_by_name.insert(
+                    import.name(),
+                    (import.path.clone(), vim9::parse_exports(&content)),
+                );
+            }
+        }
+
+        let aliases: Vec<String> = exports_by_name.keys().cloned().collect();
+        for imported in vim9::find_imported_refs(source, &aliases) {
+            let Some((path, exports)) = exports_by_name.get(&imported.alias) else {
+                continue;
+            };
+            if exports.iter().any(|e| e.name == imported.member) {
+                continue;
+            }
+
+            diagnostics.push(Diagnostic {
+                range: Range {
+                    start: Position {
+                        line: imported.line as u32,
+                        character: imported.start_col as u32,
+                    },
+                    end: Position {
+                        line: imported.line as u32,
+                        character: imported.end_col as u32,
+                    },
+                },
+                severity: Some(DiagnosticSeverity::WARNING),
+                source: Some("hjkls".to_string()),
+                message: format!("'{}' is not exported by {}", imported.member, path),
+                code: Some(NumberOrString::String(
+                    "hjkls/import_not_exported".to_string(),
+                )),
+                ..Default::default()
+            });
+        }
+
+        diagnostics
+    }
+
+    /// Find the definition of a Vim9 imported name at the given position
+    ///
+    /// - On the member of `Alias.Member`: the `export` statement in the target file
+    /// - On the alias of `Alias.Member` or the path of an `import`: the target file
+    fn find_vim9_import_definition(
+        &self,
+        source: &str,
+        uri: &Uri,
+        line: usize,
+        col: usize,
+    ) -> Option<Location> {
+        let imports = vim9::parse_imports(source);
+        if imports.is_empty() {
+            return None;
+        }
+
+        let file_location = |import: &vim9::Import| {
+            let path = self.resolve_vim9_import(import, Some(uri))?;
+            Some(Location {
+                uri: Uri::from_file_path(&path)?,
+                range: Range::default(),
+            })
+        };
+
+        // Cursor on the import path
+        if let Some(import) = imports
+            .iter()
+            .find(|i| i.line == line && i.path_start <= col && col < i.path_end)
+        {
+            return file_location(import);
+        }
+
+        let aliases: Vec<String> = imports.iter().map(|i| i.name()).collect();
+        let imported = vim9::find_imported_refs(source, &aliases)
+            .into_iter()
+            .find(|r| {
+                r.line == line && r.start_col - 1 - r.alias.len() <= col && col < r.end_col
+            })?;
+        let import = imports.iter().find(|i| i.name() == imported.alias)?;
+
+        // Cursor on the alias
+        if col < imported.start_col {
+            return file_location(import);
+        }
+
+        let path = self.resolve_vim9_import(import, Some(uri))?;
+        let content = std::fs::read_to_string(&path).ok()?;
+        let export = vim9::parse_exports(&content)
+            .into_iter()
+            .find(|e| e.name == imported.member)?;
+        log_debug!(
+            "goto_definition: vim9 import {}.{} -> {:?}",
+            imported.alias,
+            imported.member,
+            path
+        );
+
+        Some(Location {
+            uri: Uri::from_file_path(&path)?,
+            range: Range {
+                start: Position {
+                    line: export.line as u32,
+                    character: export.start_col as u32,
+                },
+                end: Position {
+                    line: export.line as u32,
+                    character: export.end_col as u32,
+                },
+            },
+        })
     }
 
     /// Parse text and return tree
+    ///
+    /// Heredocs the parser can't handle are masked, see `heredoc::mask_heredocs()`.
     fn parse(&self, text: &str, old_tree: Option<&Tree>) -> Option<Tree> {
         let mut parser = self.parser.lock().unwrap();
-        parser.parse(text, old_tree)
+        parser.parse(heredoc::mask_heredocs(text).as_ref(), old_tree)
     }
 
-    /// Open a new document
-    fn open_document(&self, uri: Uri, content: String) -> Vec<Diagnostic> {
-        // Use UTF-16 encoding for VSCode compatibility
-        // TODO: Detect client encoding from capabilities
-        // Guard against empty content - texter panics if row count becomes 0
-        let content = if content.is_empty() {
-            "\n".to_string()
-        } else {
-            content
-        };
-        let text = Text::new_utf16(content);
-        let tree = match self.parse(&text.text, None) {
-            Some(t) => t,
-            None => return vec![],
-        };
+    /// Collect the diagnostics of a document
+    ///
+    /// Lint passes that only depend on the file itself are memoized by salsa;
+    /// the others look up definitions in the workspace.
+    fn document_diagnostics(&self, uri: &Uri, source: &str, tree: &Tree) -> Vec<Diagnostic> {
+        let source_file = self.source_file(uri.as_str(), source, Some(tree));
 
-        // Collect syntax errors
-        let mut diagnostics = {
-            let mut diags = vec![];
-            let mut cursor = tree.walk();
-            collect_errors(&mut cursor, &text.text, &mut diags);
-            diags
-        };
+        // Collect syntax errors and the other warnings of `diagnostics::FILE_PASSES`
+        let mut diagnostics = db::file_diagnostics(&*self.salsa_db.lock().unwrap(), source_file);
 
         // Collect autoload warnings
-        let autoload_warnings = self.collect_autoload_warnings(&tree, &text.text, Some(&uri));
+        let autoload_warnings = self.collect_autoload_warnings(tree, source, Some(uri));
         diagnostics.extend(autoload_warnings);
 
         // Collect arity warnings (argument count mismatch)
-        let arity_warnings = self.collect_arity_warnings(&tree, &text.text, &uri);
+        let arity_warnings = self.collect_arity_warnings(tree, source, uri);
         diagnostics.extend(arity_warnings);
 
-        // Collect scope violation warnings (l: or a: outside functions)
-        let scope_warnings = self.collect_scope_violations(&tree, &text.text);
-        diagnostics.extend(scope_warnings);
-
         // Collect undefined function warnings
-        let undefined_warnings = self.collect_undefined_function_warnings(&tree, &text.text, &uri);
+        let undefined_warnings = self.collect_undefined_function_warnings(tree, source, uri);
         diagnostics.extend(undefined_warnings);
 
-        // Collect suspicious lint warnings
-        diagnostics.extend(diagnostics::collect_suspicious_warnings(&tree, &text.text));
+        // Collect Vim9 import warnings
+        let import_warnings = self.collect_vim9_import_warnings(source, uri);
+        diagnostics.extend(import_warnings);
 
-        // Collect style hints
-        let style_hints = self.collect_style_hints(&tree, &text.text);
-        diagnostics.extend(style_hints);
+        // Collect metric hints (thresholds come from the config and the modeline)
+        let mut config = self.document_config(uri);
+        Modeline::parse(source).apply_thresholds(&mut config.lint.thresholds);
+        diagnostics.extend(diagnostics::collect_metric_hints(
+            tree,
+            source,
+            &config.lint.thresholds,
+        ));
 
-        // Filter diagnostics based on inline ignore directives
-        let directives = diagnostics::parse_ignore_directives(&text.text);
-        let diagnostics = diagnostics::filter_diagnostics(diagnostics, &directives);
+        let dialect =
+            diagnostics::Dialect::detect(source, uri.to_file_path().as_deref(), self.editor_mode());
+        diagnostics::finish_diagnostics(diagnostics, source, &config, &dialect)
+    }
 
-        // Filter diagnostics based on config settings
-        let diagnostics = {
-            let config = self.config.lock().unwrap();
-            diagnostics::filter_by_config(diagnostics, &config)
+    /// Collect the diagnostics of a file's content, without opening it
+    ///
+    /// `path` decides the configuration and dialect; the file needn't exist.
+    pub fn lint_source(&self, path: &Path, content: &str) -> std::io::Result<Vec<Diagnostic>> {
+        let path = std::path::absolute(path)?;
+        let uri = Uri::from_file_path(&path).ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, "not a file path")
+        })?;
+        let Some(tree) = self.parse(content, None) else {
+            return Ok(vec![]);
+        };
+        Ok(self.document_diagnostics(&uri, content, &tree))
+    }
+
+    /// Run each diagnostic pass over a file's content and time it
+    ///
+    /// Used by the `bench` command. Unlike `document_diagnostics`, nothing is
+    /// memoized, so every pass does its full work. Returns the pass names
+    /// (the rules they report) with their run time.
+    pub fn time_lint_passes(
+        &self,
+        path: &Path,
+        source: &str,
+        tree: &Tree,
+    ) -> std::io::Result<Vec<(&'static str, Duration)>> {
+        let path = std::path::absolute(path)?;
+        let uri = Uri::from_file_path(&path).ok_or_else(|| {
+            std::io::Error::new(std::io::ErrorKind::InvalidInput, "not a file path")
+        })?;
+        let mut config = self.document_config(&uri);
+        Modeline::parse(source).apply_thresholds(&mut config.lint.thresholds);
+
+        let mut timings = Vec::new();
+        let mut time = |name: &'static str, pass: &mut dyn FnMut() -> Vec<Diagnostic>| {
+            let start = Instant::now();
+            std::hint::black_box(pass());
+            timings.push((name, start.elapsed()));
+        };
+        for pass in diagnostics::FILE_PASSES {
+            time(pass.name, &mut || (pass.run)(tree, source, Some(&uri)));
+        }
+        time("autoload_missing", &mut || {
+            self.collect_autoload_warnings(tree, source, Some(&uri))
+        });
+        time("arity_mismatch", &mut || {
+            self.collect_arity_warnings(tree, source, &uri)
+        });
+        time("undefined_function", &mut || {
+            self.collect_undefined_function_warnings(tree, source, &uri)
+        });
+        time("import_unresolved, import_not_exported", &mut || {
+            self.collect_vim9_import_warnings(source, &uri)
+        });
+        time("line_length, function_length, nesting_depth", &mut || {
+            diagnostics::collect_metric_hints(tree, source, &config.lint.thresholds)
+        });
+        Ok(timings)
+    }
+
+    /// Parse content with the server's parser (for the `bench` command)
+    pub fn parse_source(&self, text: &str) -> Option<Tree> {
+        self.parse(text, None)
+    }
+
+    /// Get the quick fix of a diagnostic, as a title and a text edit
+    ///
+    /// Returns None for rules without a simple auto-fix.
+    pub fn quick_fix(source: &str, diag: &Diagnostic) -> Option<(String, Range, String)> {
+        let code = match &diag.code {
+            Some(NumberOrString::String(s)) => s.as_str(),
+            _ => return None,
         };
 
-        let mut docs = self.documents.lock().unwrap();
-        docs.insert(uri, Document { text, tree });
+        // Get the text at the diagnostic range
+        let start_line = diag.range.start.line as usize;
+        let end_line = diag.range.end.line as usize;
+        let lines: Vec<&str> = source.lines().collect();
 
-        diagnostics
+        if start_line >= lines.len() {
+            return None;
+        }
+
+        let line = lines.get(start_line).unwrap_or(&"");
+        let start_col = diag.range.start.character as usize;
+        let end_col = if start_line == end_line {
+            diag.range.end.character as usize
+        } else {
+            line.len()
+        };
+
+        // Create text edit based on the rule
+        match code {
+            "hjkls/deprecated" => {
+                // The lint computed the edit, it needs the syntax tree
+                let edit: TextEdit = serde_json::from_value(diag.data.clone()?).ok()?;
+                let name = line.get(start_col..end_col)?;
+                let deprecation = Deprecation::find(DeprecationKind::Function, name)?;
+                Some((
+                    format!("Replace with `{}()`", deprecation.replacement),
+                    edit.range,
+                    edit.new_text,
+                ))
+            }
+            "hjkls/double_dot" => {
+                if end_col <= line.len() {
+                    let text = &line[start_col..end_col];
+                    let new_text = Self::replace_single_dot_with_double(text);
+                    if new_text != text {
+                        Some((
+                            "Use `..` for string concatenation".to_string(),
+                            diag.range,
+                            new_text,
+                        ))
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            }
+            "hjkls/single_quote" => {
+                if end_col <= line.len() {
+                    let text = &line[start_col..end_col];
+                    if text.starts_with('"') && text.ends_with('"') && text.len() >= 2 {
+                        let inner = &text[1..text.len() - 1];
+                        Some((
+                            "Use single quotes".to_string(),
+                            diag.range,
+                            format!("'{}'", inner),
+                        ))
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                }
+            }
+            "hjkls/key_notation" => {
+                if end_col <= line.len() {
+                    let text = &line[start_col..end_col];
+                    diagnostics::style::normalize_key_notation(text).map(|normalized| {
+                        ("Normalize key notation".to_string(), diag.range, normalized)
+                    })
+                } else {
+                    None
+                }
+            }
+            "hjkls/bang_not_allowed" => (line.get(start_col..end_col) == Some("!"))
+                .then(|| ("Remove `!`".to_string(), diag.range, String::new())),
+            "hjkls/normal_bang" => line.get(start_col..).and_then(|text_after| {
+                text_after.to_lowercase().find("normal").and_then(|pos| {
+                    let normal_start = start_col + pos;
+                    let normal_end = normal_start + 6;
+                    let original = line.get(normal_start..normal_end)?;
+                    let after = line.get(normal_end..).unwrap_or("");
+
+                    if !after.starts_with('!') {
+                        Some((
+                            "Use `normal!` to ignore user mappings".to_string(),
+                            Range {
+                                start: Position {
+                                    line: diag.range.start.line,
+                                    character: normal_start as u32,
+                                },
+                                end: Position {
+                                    line: diag.range.start.line,
+                                    character: normal_end as u32,
+                                },
+                            },
+                            format!("{}!", original),
+                        ))
+                    } else {
+                        None
+                    }
+                })
+            }),
+            "hjkls/function_bang" => line.get(start_col..).and_then(|text_after| {
+                text_after.to_lowercase().find("function!").map(|pos| {
+                    let func_start = start_col + pos;
+                    let func_end = func_start + 9;
+                    let original = line.get(func_start..func_end).unwrap_or("function!");
+
+                    (
+                        "Remove unnecessary `!` from s: function".to_string(),
+                        Range {
+                            start: Position {
+                                line: diag.range.start.line,
+                                character: func_start as u32,
+                            },
+                            end: Position {
+                                line: diag.range.start.line,
+                                character: func_end as u32,
+                            },
+                        },
+                        original.get(..8).unwrap_or("function").to_string(),
+                    )
+                })
+            }),
+            "hjkls/match_case" => {
+                if end_col <= line.len() {
+                    let text = &line[start_col..end_col];
+                    text.find("=~").and_then(|pos| {
+                        let after = text.get(pos + 2..).unwrap_or("");
+                        if !after.starts_with('#') && !after.starts_with('?') {
+                            let op_start = start_col + pos;
+                            let op_end = op_start + 2;
+                            Some((
+                                "Use `=~#` for case-sensitive match".to_string(),
+                                Range {
+                                    start: Position {
+                                        line: diag.range.start.line,
+                                        character: op_start as u32,
+                                    },
+                                    end: Position {
+                                        line: diag.range.start.line,
+                                        character: op_end as u32,
+                                    },
+                                },
+                                "=~#".to_string(),
+                            ))
+                        } else {
+                            None
+                        }
+                    })
+                } else {
+                    None
+                }
+            }
+            "hjkls/abort" => {
+                // Add `abort` attribute to function definition
+                // The diagnostic range covers the first line of the function
+                // Insert ` abort` at the end of the line (before newline)
+                let line_end = line.len();
+                Some((
+                    "Add `abort` attribute".to_string(),
+                    Range {
+                        start: Position {
+                            line: diag.range.start.line,
+                            character: line_end as u32,
+                        },
+                        end: Position {
+                            line: diag.range.start.line,
+                            character: line_end as u32,
+                        },
+                    },
+                    " abort".to_string(),
+                ))
+            }
+            "hjkls/plug_noremap" => {
+                // Replace map command with noremap equivalent
+                // The diagnostic range covers just the map command (e.g., "nmap")
+                if end_col <= line.len() {
+                    let cmd = &line[start_col..end_col];
+                    diagnostics::style::get_noremap_equivalent(cmd).map(|noremap_cmd| {
+                        (
+                            "Use noremap for <Plug> mapping".to_string(),
+                            diag.range,
+                            noremap_cmd.to_string(),
+                        )
+                    })
+                } else {
+                    None
+                }
+            }
+            // Other rules don't have simple auto-fixes
+            _ => None,
+        }
     }
 
-    /// Update document with full replacement
-    /// Note: We recreate the document instead of using incremental update
-    /// because texter's internal state can become corrupted after certain
-    /// operations (like undo after rename), causing panics in eol_indexes.
-    fn update_document(&self, uri: &Uri, content: String) -> Vec<Diagnostic> {
+    /// Open a new document
+    fn open_document(&self, uri: Uri, content: String, version: i32) {
+        // Use UTF-16 encoding for VSCode compatibility
+        // TODO: Detect client encoding from capabilities
         // Guard against empty content - texter panics if row count becomes 0
         let content = if content.is_empty() {
             "\n".to_string()
         } else {
             content
         };
-
-        // Recreate document from scratch to avoid texter state corruption
-        let text = Text::new_utf16(content);
-        let tree = match self.parse(&text.text, None) {
-            Some(t) => t,
-            None => return vec![],
+        let text = Arc::new(Text::new_utf16(content));
+        let Some(tree) = self.parse(&text.text, None) else {
+            return;
         };
+        self.source_file(uri.as_str(), &text.text, Some(&tree));
+
+        let mut docs = self.documents.write().unwrap();
+        docs.insert(
+            uri,
+            Document {
+                text,
+                tree,
+                version,
+            },
+        );
+    }
 
-        // Collect syntax errors
-        let mut diagnostics = {
-            let mut diags = vec![];
-            let mut cursor = tree.walk();
-            collect_errors(&mut cursor, &text.text, &mut diags);
-            diags
+    /// Update document with incremental changes
+    ///
+    /// Each ranged change is applied to the text with `sync::apply_change()`,
+    /// and its `InputEdit` to the previous tree so that only the changed
+    /// regions are reparsed. A full-text change, or a heredoc whose masking
+    /// may move, drops the tree for a full reparse.
+    /// Note: the texter `Text` is still rebuilt from the new content, because
+    /// its internal state can become corrupted after certain operations (like
+    /// undo after rename), causing panics in eol_indexes.
+    fn update_document(
+        &self,
+        uri: &Uri,
+        changes: Vec<TextDocumentContentChangeEvent>,
+        version: i32,
+    ) {
+        // Apply the edits to the previous text and tree, so that tree-sitter
+        // only reparses the changed regions
+        let (mut content, mut old_tree) = {
+            let docs = self.documents.read().unwrap();
+            match docs.get(uri) {
+                Some(doc) => (doc.text.text.clone(), Some(doc.tree.clone())),
+                None => (String::new(), None),
+            }
         };
+        // Masking a heredoc changes lines outside the edits
+        let masked_before = heredoc::find_heredocs(&content).iter().any(|h| h.masked);
+        for change in changes {
+            match change.range {
+                Some(range) => {
+                    let edit = sync::apply_change(&mut content, range, &change.text);
+                    if let Some(tree) = old_tree.as_mut() {
+                        tree.edit(&edit);
+                    }
+                }
+                None => {
+                    content = change.text;
+                    old_tree = None;
+                }
+            }
+        }
 
-        // Collect autoload warnings
-        let autoload_warnings = self.collect_autoload_warnings(&tree, &text.text, Some(uri));
-        diagnostics.extend(autoload_warnings);
-
-        // Collect arity warnings (argument count mismatch)
-        let arity_warnings = self.collect_arity_warnings(&tree, &text.text, uri);
-        diagnostics.extend(arity_warnings);
-
-        // Collect scope violation warnings (l: or a: outside functions)
-        let scope_warnings = self.collect_scope_violations(&tree, &text.text);
-        diagnostics.extend(scope_warnings);
+        // Guard against empty content - texter panics if row count becomes 0
+        if content.is_empty() {
+            content = "\n".to_string();
+            old_tree = None;
+        }
+        if masked_before || heredoc::find_heredocs(&content).iter().any(|h| h.masked) {
+            old_tree = None;
+        }
 
-        // Collect undefined function warnings
-        let undefined_warnings = self.collect_undefined_function_warnings(&tree, &text.text, uri);
-        diagnostics.extend(undefined_warnings);
+        // Recreate document from scratch to avoid texter state corruption
+        let text = Arc::new(Text::new_utf16(content));
+        let Some(tree) = self.parse(&text.text, old_tree.as_ref()) else {
+            return;
+        };
+        self.source_file(uri.as_str(), &text.text, Some(&tree));
+
+        let mut docs = self.documents.write().unwrap();
+        docs.insert(
+            uri.clone(),
+            Document {
+                text,
+                tree,
+                version,
+            },
+        );
+    }
 
-        // Collect suspicious lint warnings
-        diagnostics.extend(diagnostics::collect_suspicious_warnings(&tree, &text.text));
+    /// Collect the diagnostics of an open document at a version
+    ///
+    /// Returns None once the document is closed or changed: the handler of
+    /// the newer version publishes its diagnostics.
+    fn versioned_diagnostics(&self, uri: &Uri, version: i32) -> Option<Vec<Diagnostic>> {
+        let (text, tree) = {
+            let docs = self.documents.read().unwrap();
+            let doc = docs.get(uri).filter(|doc| doc.version == version)?;
+            (Arc::clone(&doc.text), doc.tree.clone())
+        };
+        Some(self.document_diagnostics(uri, &text.text, &tree))
+    }
 
-        // Collect style hints
-        let style_hints = self.collect_style_hints(&tree, &text.text);
-        diagnostics.extend(style_hints);
+    /// Whether the client supports `CompletionItem.labelDetails`
+    fn supports_label_details(&self) -> bool {
+        self.label_details_support.load(Ordering::Relaxed)
+    }
 
-        // Filter diagnostics based on inline ignore directives
-        let directives = diagnostics::parse_ignore_directives(&text.text);
-        let diagnostics = diagnostics::filter_diagnostics(diagnostics, &directives);
+    /// Documentation of a built-in completion item
+    ///
+    /// The `[Vim only]` / `[Neovim only]` marker is prepended unless it is
+    /// shown in the label details instead.
+    fn builtin_documentation(&self, availability: Availability, description: &str) -> String {
+        let label_suffix = availability.label_suffix();
+        if label_suffix.is_empty() || self.supports_label_details() {
+            description.to_string()
+        } else {
+            format!("{}\n{}", label_suffix.trim(), description)
+        }
+    }
 
-        // Filter diagnostics based on config settings
-        let diagnostics = {
-            let config = self.config.lock().unwrap();
-            diagnostics::filter_by_config(diagnostics, &config)
+    /// Label details of a completion item, when the client supports them
+    ///
+    /// The parameter list of `signature` becomes the detail (shown right after
+    /// the label) and the availability becomes the description.
+    fn label_details(
+        &self,
+        signature: Option<&str>,
+        availability: Availability,
+    ) -> Option<CompletionItemLabelDetails> {
+        if !self.supports_label_details() {
+            return None;
+        }
+        let detail = signature.and_then(|sig| sig.find('(').map(|pos| sig[pos..].to_string()));
+        let description = match availability {
+            Availability::Common => None,
+            Availability::VimOnly => Some("Vim only".to_string()),
+            Availability::NeovimOnly => Some("Neovim only".to_string()),
         };
+        (detail.is_some() || description.is_some()).then_some(CompletionItemLabelDetails {
+            detail,
+            description,
+        })
+    }
 
-        let mut docs = self.documents.lock().unwrap();
-        docs.insert(uri.clone(), Document { text, tree });
+    /// Commit characters of a completion item, when the client supports them
+    ///
+    /// `(` for functions and `=` for options, so that accepting an item and
+    /// typing the next character flows naturally.
+    fn commit_characters(&self, kind: CompletionItemKind) -> Option<Vec<String>> {
+        if !self.commit_characters_support.load(Ordering::Relaxed) {
+            return None;
+        }
+        match kind {
+            CompletionItemKind::FUNCTION | CompletionItemKind::METHOD => {
+                Some(vec!["(".to_string()])
+            }
+            CompletionItemKind::PROPERTY => Some(vec!["=".to_string()]),
+            _ => None,
+        }
+    }
 
-        diagnostics
+    /// Signature help for the Ex command whose arguments are at a position
+    ///
+    /// The label is the command's synopsis (e.g., `:[range]d[elete] {args}`),
+    /// with the argument as its only parameter.
+    fn command_signature(&self, source: &str, row: usize, col: usize) -> Option<SignatureHelp> {
+        let name = symbols::find_command_call_at_position(source, row, col)?;
+        let cmd = BUILTIN_COMMANDS
+            .iter()
+            .filter(|c| c.availability.is_compatible(self.editor_mode()))
+            .find(|c| c.matches(&name))?;
+        let parameters = cmd.args.placeholder().map(|args| {
+            vec![ParameterInformation {
+                label: ParameterLabel::Simple(args.to_string()),
+                documentation: None,
+            }]
+        });
+        let active_parameter = parameters.as_ref().map(|_| 0);
+        let signature = SignatureInformation {
+            label: cmd.synopsis(),
+            documentation: Some(Documentation::String(cmd.description.to_string())),
+            parameters,
+            active_parameter,
+        };
+        Some(SignatureHelp {
+            signatures: vec![signature],
+            active_signature: Some(0),
+            active_parameter,
+        })
     }
 
     /// Build Ex command completions
-    fn build_command_completions(&self, edit_range: Range) -> Vec<CompletionItem> {
+    ///
+    /// In Vim9 script, legacy-only commands are hidden and declaration
+    /// commands (`def`, `var`, ...) are ranked first.
+    fn build_command_completions(&self, edit_range: Range, is_vim9: bool) -> Vec<CompletionItem> {
         BUILTIN_COMMANDS
             .iter()
-            .filter(|cmd| cmd.availability.is_compatible(self.editor_mode))
+            .filter(|cmd| cmd.availability.is_compatible(self.editor_mode()))
+            .filter(|cmd| !is_vim9 || !VIM9_UNSUPPORTED_COMMANDS.contains(&cmd.name))
             .map(|cmd| {
-                let label_suffix = cmd.availability.label_suffix();
-                let documentation = if label_suffix.is_empty() {
-                    cmd.description.to_string()
-                } else {
-                    format!("{}\n{}", label_suffix.trim(), cmd.description)
-                };
+                let sort_text = (is_vim9 && VIM9_DECLARATION_COMMANDS.contains(&cmd.name))
+                    .then(|| completion::sort_text(CompletionRank::PreferredBuiltin, cmd.name));
+                let documentation = self.builtin_documentation(cmd.availability, cmd.description);
                 CompletionItem {
                     label: cmd.name.to_string(),
                     kind: Some(CompletionItemKind::KEYWORD),
                     documentation: Some(Documentation::String(documentation)),
+                    label_details: self.label_details(None, cmd.availability),
+                    sort_text,
                     text_edit: Some(CompletionTextEdit::Edit(TextEdit {
                         range: edit_range,
                         new_text: cmd.name.to_string(),
@@ -964,22 +3363,117 @@ impl Backend {
             .collect()
     }
 
+    /// Build completions for user-defined commands in the workspace
+    fn build_user_command_completions(
+        &self,
+        edit_range: Range,
+        uri_str: &str,
+        content: &str,
+    ) -> Vec<CompletionItem> {
+        self.get_user_commands(uri_str, content)
+            .into_iter()
+            .map(|(cmd, is_local)| CompletionItem {
+                label: cmd.name.clone(),
+                kind: Some(CompletionItemKind::FUNCTION),
+                detail: Some("user command".to_string()),
+                sort_text: Some(completion::sort_text(
+                    if is_local {
+                        CompletionRank::Local
+                    } else {
+                        CompletionRank::Workspace
+                    },
+                    &cmd.name,
+                )),
+                documentation: Some(Documentation::MarkupContent(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value: format!("```vim\n{}\n```", cmd.definition),
+                })),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range: edit_range,
+                    new_text: cmd.name,
+                })),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    /// Build `<Plug>` mapping completions from the document and indexed files
+    ///
+    /// The edit range starts at `<Plug>` so the whole target name is replaced.
+    fn build_plug_mapping_completions(
+        &self,
+        position: Position,
+        line: &str,
+        uri_str: &str,
+        content: &str,
+    ) -> Vec<CompletionItem> {
+        let before_cursor = &line[..(position.character as usize).min(line.len())];
+        let Some(plug_start) = before_cursor.to_ascii_lowercase().rfind("<plug>") else {
+            return vec![];
+        };
+        let edit_range = Range {
+            start: Position {
+                line: position.line,
+                character: plug_start as u32,
+            },
+            end: position,
+        };
+
+        let mappings = {
+            let current = self.source_file(uri_str, content, None);
+            let source_files = self.source_file_entries();
+            let db = self.salsa_db.lock().unwrap();
+
+            let mut mappings: Vec<_> = db::parse_plug_mappings(&*db, current)
+                .into_iter()
+                .map(|m| (m, CompletionRank::Local))
+                .collect();
+            for (file_uri, sf) in source_files.iter() {
+                if file_uri == uri_str {
+                    continue;
+                }
+                for mapping in db::parse_plug_mappings(&*db, *sf) {
+                    if !mappings.iter().any(|(m, _)| m.name == mapping.name) {
+                        mappings.push((mapping, CompletionRank::Workspace));
+                    }
+                }
+            }
+            mappings
+        };
+
+        mappings
+            .into_iter()
+            .map(|(m, rank)| CompletionItem {
+                label: m.name.clone(),
+                kind: Some(CompletionItemKind::REFERENCE),
+                detail: Some("<Plug> mapping".to_string()),
+                sort_text: Some(completion::sort_text(rank, &m.name)),
+                documentation: Some(Documentation::MarkupContent(MarkupContent {
+                    kind: MarkupKind::Markdown,
+                    value: format!("```vim\n{}\n```", m.definition),
+                })),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range: edit_range,
+                    new_text: m.name,
+                })),
+                ..Default::default()
+            })
+            .collect()
+    }
+
     /// Build autocmd event completions
     fn build_autocmd_event_completions(&self, edit_range: Range) -> Vec<CompletionItem> {
         AUTOCMD_EVENTS
             .iter()
-            .filter(|event| event.availability.is_compatible(self.editor_mode))
+            .filter(|event| event.availability.is_compatible(self.editor_mode()))
             .map(|event| {
-                let label_suffix = event.availability.label_suffix();
-                let documentation = if label_suffix.is_empty() {
-                    event.description.to_string()
-                } else {
-                    format!("{}\n{}", label_suffix.trim(), event.description)
-                };
+                let documentation =
+                    self.builtin_documentation(event.availability, event.description);
                 CompletionItem {
                     label: event.name.to_string(),
                     kind: Some(CompletionItemKind::EVENT),
                     documentation: Some(Documentation::String(documentation)),
+                    label_details: self.label_details(None, event.availability),
                     text_edit: Some(CompletionTextEdit::Edit(TextEdit {
                         range: edit_range,
                         new_text: event.name.to_string(),
@@ -990,24 +3484,424 @@ impl Backend {
             .collect()
     }
 
+    /// Build autocmd pattern completions
+    ///
+    /// - `FileType`/`Syntax`: filetype names (bundled plus `ftplugin/` files in
+    ///   workspace roots and runtime directories)
+    /// - File events (`BufRead`, `BufNewFile`, ...): common glob snippets
+    fn build_autocmd_pattern_completions(
+        &self,
+        position: Position,
+        line: &str,
+    ) -> Vec<CompletionItem> {
+        let before_cursor = &line[..(position.character as usize).min(line.len())];
+        let Some(events) = crate::completion::autocmd_pattern_events(before_cursor) else {
+            return vec![];
+        };
+        let pattern_start = before_cursor
+            .rfind(char::is_whitespace)
+            .map_or(0, |pos| pos + 1);
+
+        if events
+            .iter()
+            .any(|e| e.eq_ignore_ascii_case("FileType") || e.eq_ignore_ascii_case("Syntax"))
+        {
+            // Filetype patterns may be comma-separated lists
+            let item_start = before_cursor[pattern_start..]
+                .rfind(',')
+                .map_or(pattern_start, |pos| pattern_start + pos + 1);
+            let edit_range = Range {
+                start: Position {
+                    line: position.line,
+                    character: item_start as u32,
+                },
+                end: position,
+            };
+            let mut filetypes: BTreeSet<String> =
+                FILETYPES.iter().map(|ft| ft.to_string()).collect();
+            filetypes.extend(self.collect_ftplugin_filetypes());
+            return filetypes
+                .into_iter()
+                .map(|ft| CompletionItem {
+                    label: ft.clone(),
+                    kind: Some(CompletionItemKind::ENUM_MEMBER),
+                    detail: Some("filetype".to_string()),
+                    text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                        range: edit_range,
+                        new_text: ft,
+                    })),
+                    ..Default::default()
+                })
+                .collect();
+        }
+
+        let is_file_event = |e: &&str| {
+            let e = e.to_ascii_lowercase();
+            (e.starts_with("buf") || e.starts_with("file")) && e != "filetype"
+        };
+        if !events.iter().any(is_file_event) {
+            return vec![];
+        }
+        let edit_range = Range {
+            start: Position {
+                line: position.line,
+                character: pattern_start as u32,
+            },
+            end: position,
+        };
+        AUTOCMD_PATTERN_SNIPPETS
+            .iter()
+            .map(|(label, snippet, description)| CompletionItem {
+                label: label.to_string(),
+                kind: Some(CompletionItemKind::SNIPPET),
+                documentation: Some(Documentation::String(description.to_string())),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range: edit_range,
+                    new_text: snippet.to_string(),
+                })),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    /// Collect filetype names from `ftplugin/` in workspace roots and the runtime path
+    ///
+    /// `ftplugin/{ft}.vim`, `ftplugin/{ft}_{name}.vim` and `ftplugin/{ft}/`
+    /// all define a plugin for `{ft}` (see :help ftplugin-name).
+    fn collect_ftplugin_filetypes(&self) -> BTreeSet<String> {
+        let mut dirs: Vec<PathBuf> = self
+            .workspace_runtime_dirs(None)
+            .into_iter()
+            .map(|root| root.join("ftplugin"))
+            .collect();
+        dirs.extend(
+            self.runtime_dirs()
+                .into_iter()
+                .map(|dir| dir.join("ftplugin")),
+        );
+
+        let mut filetypes = BTreeSet::new();
+        for entry in dirs
+            .iter()
+            .filter_map(|dir| std::fs::read_dir(dir).ok())
+            .flatten()
+            .flatten()
+        {
+            let path = entry.path();
+            let name = if path.is_dir() {
+                path.file_name()
+            } else if path
+                .extension()
+                .is_some_and(|ext| ext == "vim" || ext == "lua")
+            {
+                path.file_stem()
+            } else {
+                None
+            };
+            let Some(name) = name.and_then(|n| n.to_str()) else {
+                continue;
+            };
+            let filetype = name.split('_').next().unwrap_or(name);
+            if !filetype.is_empty() {
+                filetypes.insert(filetype.to_string());
+            }
+        }
+        filetypes
+    }
+
+    /// Build color scheme completions for the argument of `:colorscheme`
+    ///
+    /// Color schemes are `colors/{name}.vim` and `colors/{name}.lua` files in
+    /// workspace roots and the runtime path.
+    fn build_colorscheme_completions(&self, position: Position, line: &str) -> Vec<CompletionItem> {
+        let before_cursor = &line[..(position.character as usize).min(line.len())];
+        let Some(argument) = crate::completion::colorscheme_argument(before_cursor) else {
+            return vec![];
+        };
+        let edit_range = Range {
+            start: Position {
+                line: position.line,
+                character: (before_cursor.len() - argument.len()) as u32,
+            },
+            end: position,
+        };
+
+        let mut dirs: Vec<PathBuf> = self
+            .workspace_runtime_dirs(None)
+            .into_iter()
+            .map(|root| root.join("colors"))
+            .collect();
+        dirs.extend(
+            self.runtime_dirs()
+                .into_iter()
+                .map(|dir| dir.join("colors")),
+        );
+
+        let names: BTreeSet<String> = dirs
+            .iter()
+            .filter_map(|dir| std::fs::read_dir(dir).ok())
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| {
+                path.is_file()
+                    && path
+                        .extension()
+                        .is_some_and(|ext| ext == "vim" || ext == "lua")
+            })
+            .filter_map(|path| path.file_stem()?.to_str().map(String::from))
+            .collect();
+        names
+            .into_iter()
+            .map(|name| CompletionItem {
+                label: name.clone(),
+                kind: Some(CompletionItemKind::VALUE),
+                detail: Some("colorscheme".to_string()),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range: edit_range,
+                    new_text: name,
+                })),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    /// Build file path completions for the argument of `:source`, `:runtime`, `:edit`, ...
+    ///
+    /// Absolute (and `~/`) paths are completed as-is; other paths are
+    /// completed relative to workspace roots and runtime directories.
+    fn build_file_path_completions(&self, position: Position, line: &str) -> Vec<CompletionItem> {
+        let before_cursor = &line[..(position.character as usize).min(line.len())];
+        let Some(argument) = crate::completion::file_argument(before_cursor) else {
+            return vec![];
+        };
+        // Only the last path component is replaced
+        let (dir_part, name_part) = match argument.rfind('/') {
+            Some(pos) => (&argument[..=pos], &argument[pos + 1..]),
+            None => ("", argument),
+        };
+
+        let base_dirs: Vec<PathBuf> = if dir_part.starts_with('/') {
+            vec![PathBuf::from(dir_part)]
+        } else if let Some(rest) = dir_part.strip_prefix("~/") {
+            std::env::var("HOME")
+                .map(|home| vec![PathBuf::from(home).join(rest)])
+                .unwrap_or_default()
+        } else {
+            let mut dirs: Vec<PathBuf> = self
+                .workspace_roots
+                .lock()
+                .unwrap()
+                .iter()
+                .map(|root| root.join(dir_part))
+                .collect();
+            dirs.extend(
+                self.runtime_dirs()
+                    .into_iter()
+                    .map(|dir| dir.join(dir_part)),
+            );
+            dirs
+        };
+
+        let edit_range = Range {
+            start: Position {
+                line: position.line,
+                character: (before_cursor.len() - name_part.len()) as u32,
+            },
+            end: position,
+        };
+
+        // Directories are suffixed with `/`; the first base directory wins on duplicates
+        let mut entries: BTreeMap<String, bool> = BTreeMap::new();
+        for entry in base_dirs
+            .iter()
+            .filter_map(|dir| std::fs::read_dir(dir).ok())
+            .flatten()
+            .flatten()
+        {
+            let Ok(name) = entry.file_name().into_string() else {
+                continue;
+            };
+            // Hidden files are only offered when explicitly typed
+            if name.starts_with('.') && !name_part.starts_with('.') {
+                continue;
+            }
+            entries.entry(name).or_insert_with(|| entry.path().is_dir());
+        }
+
+        entries
+            .into_iter()
+            .map(|(name, is_dir)| {
+                let new_text = if is_dir { format!("{}/", name) } else { name };
+                CompletionItem {
+                    label: new_text.clone(),
+                    kind: Some(if is_dir {
+                        CompletionItemKind::FOLDER
+                    } else {
+                        CompletionItemKind::FILE
+                    }),
+                    text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                        range: edit_range,
+                        new_text,
+                    })),
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    /// `lua/` directories of the workspace roots
+    fn lua_dirs(&self) -> Vec<PathBuf> {
+        self.workspace_roots
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|root| root.join("lua"))
+            .filter(|dir| dir.is_dir())
+            .collect()
+    }
+
+    /// Resolve a Lua module name to its file in the workspace
+    fn resolve_lua_module(&self, name: &str) -> Option<PathBuf> {
+        self.lua_dirs()
+            .iter()
+            .find_map(|dir| lua::module_path(dir, name))
+    }
+
+    /// Build `v:lua.` completions (Neovim)
+    ///
+    /// - `v:lua.|` -> `require'module'` for modules under `lua/`
+    /// - `v:lua.require'module'.|` -> functions of the module
+    fn build_v_lua_completions(
+        &self,
+        position: Position,
+        before_cursor: &str,
+    ) -> Option<Vec<CompletionItem>> {
+        let target = lua::v_lua_completion(before_cursor)?;
+        let partial = match target {
+            lua::VLuaCompletion::Module(partial) => partial,
+            lua::VLuaCompletion::Function { partial, .. } => partial,
+        };
+        let edit_range = Range {
+            start: Position {
+                line: position.line,
+                character: (before_cursor.len() - partial.len()) as u32,
+            },
+            end: position,
+        };
+
+        let items = match target {
+            lua::VLuaCompletion::Module(_) => lua::find_modules(&self.lua_dirs())
+                .into_iter()
+                .map(|module| {
+                    let text = format!("require'{}'", module.name);
+                    CompletionItem {
+                        label: text.clone(),
+                        kind: Some(CompletionItemKind::MODULE),
+                        sort_text: Some(completion::sort_text(CompletionRank::Workspace, &text)),
+                        detail: Some(module.path.display().to_string()),
+                        text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                            range: edit_range,
+                            new_text: text,
+                        })),
+                        ..Default::default()
+                    }
+                })
+                .collect(),
+            lua::VLuaCompletion::Function { module, .. } => {
+                let path = self.resolve_lua_module(module)?;
+                let source = std::fs::read_to_string(&path).ok()?;
+                lua::parse_module_functions(&source)
+                    .into_iter()
+                    .map(|func| CompletionItem {
+                        label: func.name.clone(),
+                        kind: Some(CompletionItemKind::FUNCTION),
+                        commit_characters: self.commit_characters(CompletionItemKind::FUNCTION),
+                        sort_text: Some(completion::sort_text(
+                            CompletionRank::Workspace,
+                            &func.name,
+                        )),
+                        detail: Some(format!("{}({})", func.name, func.params)),
+                        documentation: Some(Documentation::String(format!(
+                            "Lua module `{}`",
+                            module
+                        ))),
+                        text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                            range: edit_range,
+                            new_text: func.name,
+                        })),
+                        ..Default::default()
+                    })
+                    .collect()
+            }
+        };
+        Some(items)
+    }
+
+    /// Build hover contents for a `v:lua` / `luaeval()` reference (Neovim)
+    fn lua_hover_contents(&self, reference: &lua::LuaReference) -> String {
+        // The bridges only exist in Neovim, so always link to its help
+        let (note, tag) = match reference.bridge {
+            lua::LuaBridge::VLua => ("Called from Vim script through `v:lua`", "v:lua-call"),
+            lua::LuaBridge::Luaeval => ("Evaluated as Lua by `luaeval()`", "lua-eval"),
+            lua::LuaBridge::VimFn => ("Vim function called from Lua through `vim.fn`", "vim.fn"),
+        };
+        let bridge = match help::url(tag, None, true) {
+            Some(url) => format!("\n\n{}\n\n{}", note, help::markdown_link(tag, &url)),
+            None => format!("\n\n{}", note),
+        };
+        let name = reference.function.as_deref().unwrap_or("");
+        if reference.bridge == lua::LuaBridge::VimFn {
+            return match BuiltinFunction::find(name) {
+                Some(builtin) => format!(
+                    "```vim\n{}\n```\n\n{}{}",
+                    builtin.signature, builtin.description, bridge
+                ),
+                None => format!("```vim\n{}()\n```{}", name, bridge),
+            };
+        }
+        let Some(module) = &reference.module else {
+            return format!("```lua\n{}()\n```\n\n*Lua global function*{}", name, bridge);
+        };
+        let Some(path) = self.resolve_lua_module(module) else {
+            return format!(
+                "```lua\nrequire'{}'\n```\n\n*Lua module* (not found in workspace){}",
+                module, bridge
+            );
+        };
+        let signature = reference.function.as_ref().and_then(|name| {
+            let source = std::fs::read_to_string(&path).ok()?;
+            lua::parse_module_functions(&source)
+                .into_iter()
+                .find(|f| &f.name == name)
+                .map(|f| format!("function {}.{}({})", module, f.name, f.params))
+        });
+        format!(
+            "```lua\n{}\n```\n\n*Lua module*\n\nFile: `{}`{}",
+            signature.unwrap_or_else(|| format!("require'{}'", module)),
+            path.display(),
+            bridge
+        )
+    }
+
     /// Build option completions
     fn build_option_completions(&self, edit_range: Range, _line: &str) -> Vec<CompletionItem> {
         BUILTIN_OPTIONS
             .iter()
-            .filter(|opt| opt.availability.is_compatible(self.editor_mode))
+            .filter(|opt| opt.availability.is_compatible(self.editor_mode()))
             .flat_map(|opt| {
-                let label_suffix = opt.availability.label_suffix();
-                let documentation = if label_suffix.is_empty() {
-                    opt.description.to_string()
-                } else {
-                    format!("{}\n{}", label_suffix.trim(), opt.description)
-                };
+                let description =
+                    format!("{}\n\n{}", opt.description, opt.summary(self.editor_mode()));
+                let documentation = self.builtin_documentation(opt.availability, &description);
 
                 let mut items = vec![CompletionItem {
                     label: opt.name.to_string(),
                     kind: Some(CompletionItemKind::PROPERTY),
+                    commit_characters: self.commit_characters(CompletionItemKind::PROPERTY),
                     detail: opt.short.map(|s| format!("short: {}", s)),
                     documentation: Some(Documentation::String(documentation.clone())),
+                    label_details: self.label_details(None, opt.availability),
                     text_edit: Some(CompletionTextEdit::Edit(TextEdit {
                         range: edit_range,
                         new_text: opt.name.to_string(),
@@ -1020,8 +3914,10 @@ impl Backend {
                     items.push(CompletionItem {
                         label: short.to_string(),
                         kind: Some(CompletionItemKind::PROPERTY),
+                        commit_characters: self.commit_characters(CompletionItemKind::PROPERTY),
                         detail: Some(format!("long: {}", opt.name)),
                         documentation: Some(Documentation::String(documentation)),
+                        label_details: self.label_details(None, opt.availability),
                         text_edit: Some(CompletionTextEdit::Edit(TextEdit {
                             range: edit_range,
                             new_text: short.to_string(),
@@ -1035,9 +3931,104 @@ impl Backend {
             .collect()
     }
 
+    /// Build option value completions (after `set option=`)
+    ///
+    /// For comma-separated list options only the item under the cursor is
+    /// replaced; values and flags that are already set are not offered again.
+    /// Options without enumerated values offer their default value.
+    fn build_option_value_completions(
+        &self,
+        position: Position,
+        line: &str,
+    ) -> Vec<CompletionItem> {
+        let before_cursor = &line[..(position.character as usize).min(line.len())];
+        let Some((name, value_start)) = crate::completion::find_option_value(before_cursor) else {
+            return vec![];
+        };
+        let Some(option) = OptionValues::find(name) else {
+            return self.build_option_default_completion(position, name, value_start);
+        };
+        let value = &before_cursor[value_start..];
+
+        let kind = option.kind();
+        let item_start = match kind {
+            OptionValueKind::Single => value_start,
+            OptionValueKind::CommaList => value_start + value.rfind(',').map_or(0, |pos| pos + 1),
+            OptionValueKind::Flags => before_cursor.len(),
+        };
+        let is_set = |candidate: &str| match kind {
+            OptionValueKind::Single => false,
+            OptionValueKind::CommaList => value.split(',').rev().skip(1).any(|v| v == candidate),
+            OptionValueKind::Flags => value.contains(candidate),
+        };
+        let edit_range = Range {
+            start: Position {
+                line: position.line,
+                character: item_start as u32,
+            },
+            end: position,
+        };
+
+        option
+            .values
+            .iter()
+            .filter(|(value, _)| !is_set(value))
+            .map(|(value, description)| CompletionItem {
+                label: value.to_string(),
+                kind: Some(CompletionItemKind::VALUE),
+                detail: Some(option.option.to_string()),
+                documentation: Some(Documentation::String(description.to_string())),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range: edit_range,
+                    new_text: value.to_string(),
+                })),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    /// Offer the default value of a number or string option
+    fn build_option_default_completion(
+        &self,
+        position: Position,
+        name: &str,
+        value_start: usize,
+    ) -> Vec<CompletionItem> {
+        let Some(option) = BuiltinOption::find(name) else {
+            return vec![];
+        };
+        let Some(default) = option
+            .default_value(self.editor_mode())
+            .filter(|value| !value.is_empty() && option.kind != OptionType::Boolean)
+        else {
+            return vec![];
+        };
+        // A space would end the value
+        let new_text = default.replace(' ', "\\ ");
+        vec![CompletionItem {
+            label: default.to_string(),
+            kind: Some(CompletionItemKind::VALUE),
+            detail: Some(format!("{} (default)", option.name)),
+            text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                range: Range {
+                    start: Position {
+                        line: position.line,
+                        character: value_start as u32,
+                    },
+                    end: position,
+                },
+                new_text,
+            })),
+            ..Default::default()
+        }]
+    }
+
     /// Build map option completions
+    ///
+    /// Map options precede the LHS, which may itself start with a key
+    /// notation, so key notations are offered as well.
     fn build_map_option_completions(&self, edit_range: Range) -> Vec<CompletionItem> {
-        MAP_OPTIONS
+        let mut items: Vec<CompletionItem> = MAP_OPTIONS
             .iter()
             .map(|opt| CompletionItem {
                 label: opt.name.to_string(),
@@ -1049,6 +4040,67 @@ impl Backend {
                 })),
                 ..Default::default()
             })
+            .collect();
+        items.extend(self.build_key_notation_completions(edit_range));
+        items
+    }
+
+    /// Build key notation completions (`<CR>`, `<Leader>`, `<C-w>`, ...)
+    fn build_key_notation_completions(&self, edit_range: Range) -> Vec<CompletionItem> {
+        KEY_NOTATIONS
+            .iter()
+            .map(|key| CompletionItem {
+                label: key.name.to_string(),
+                kind: Some(CompletionItemKind::CONSTANT),
+                documentation: Some(Documentation::String(key.description.to_string())),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range: edit_range,
+                    new_text: key.name.to_string(),
+                })),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    /// Build :syntax sub-command completions
+    fn build_syntax_subcommand_completions(&self, edit_range: Range) -> Vec<CompletionItem> {
+        SYNTAX_SUBCOMMANDS
+            .iter()
+            .map(|sub| CompletionItem {
+                label: sub.name.to_string(),
+                kind: Some(CompletionItemKind::KEYWORD),
+                documentation: Some(Documentation::String(sub.description.to_string())),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range: edit_range,
+                    new_text: sub.name.to_string(),
+                })),
+                ..Default::default()
+            })
+            .collect()
+    }
+
+    /// Build :syntax argument completions for the sub-command on the line
+    fn build_syntax_argument_completions(
+        &self,
+        edit_range: Range,
+        line: &str,
+    ) -> Vec<CompletionItem> {
+        let Some(subcommand) = crate::completion::syntax_subcommand(line) else {
+            return vec![];
+        };
+        SYNTAX_ARGUMENTS
+            .iter()
+            .filter(|arg| arg.subcommands.contains(&subcommand))
+            .map(|arg| CompletionItem {
+                label: arg.name.to_string(),
+                kind: Some(CompletionItemKind::PROPERTY),
+                documentation: Some(Documentation::String(arg.description.to_string())),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range: edit_range,
+                    new_text: arg.name.to_string(),
+                })),
+                ..Default::default()
+            })
             .collect()
     }
 
@@ -1056,18 +4108,14 @@ impl Backend {
     fn build_has_feature_completions(&self, edit_range: Range) -> Vec<CompletionItem> {
         HAS_FEATURES
             .iter()
-            .filter(|feat| feat.availability.is_compatible(self.editor_mode))
+            .filter(|feat| feat.availability.is_compatible(self.editor_mode()))
             .map(|feat| {
-                let label_suffix = feat.availability.label_suffix();
-                let documentation = if label_suffix.is_empty() {
-                    feat.description.to_string()
-                } else {
-                    format!("{}\n{}", label_suffix.trim(), feat.description)
-                };
+                let documentation = self.builtin_documentation(feat.availability, feat.description);
                 CompletionItem {
                     label: feat.name.to_string(),
                     kind: Some(CompletionItemKind::CONSTANT),
                     documentation: Some(Documentation::String(documentation)),
+                    label_details: self.label_details(None, feat.availability),
                     text_edit: Some(CompletionTextEdit::Edit(TextEdit {
                         range: edit_range,
                         new_text: feat.name.to_string(),
@@ -1078,6 +4126,123 @@ impl Backend {
             .collect()
     }
 
+    /// Build completion items for members of a Vim9 class
+    ///
+    /// Returns None when the class of `object` cannot be determined.
+    fn build_member_completions(
+        &self,
+        edit_range: Range,
+        content: &str,
+        line: usize,
+        object: &str,
+    ) -> Option<Vec<CompletionItem>> {
+        let classes = vim9::parse_classes(content);
+
+        // `ClassName.` accesses static members and constructors
+        let (class_name, is_class_access) = if object == "this" {
+            (vim9::class_at_line(&classes, line)?.name.clone(), false)
+        } else if classes.iter().any(|c| c.name == object) {
+            (object.to_string(), true)
+        } else {
+            (vim9::find_variable_class(content, object, line)?, false)
+        };
+
+        let items = vim9::class_members(&classes, &class_name)
+            .into_iter()
+            .filter(|m| {
+                let is_class_member = m.is_static || m.kind == vim9::ClassMemberKind::Constructor;
+                is_class_member == is_class_access
+            })
+            .map(|m| {
+                let kind = match m.kind {
+                    vim9::ClassMemberKind::Method => CompletionItemKind::METHOD,
+                    vim9::ClassMemberKind::Constructor => CompletionItemKind::CONSTRUCTOR,
+                    vim9::ClassMemberKind::Variable => CompletionItemKind::FIELD,
+                };
+                CompletionItem {
+                    label: m.name.clone(),
+                    kind: Some(kind),
+                    commit_characters: self.commit_characters(kind),
+                    detail: Some(m.signature.clone()),
+                    sort_text: Some(completion::sort_text(CompletionRank::Local, &m.name)),
+                    text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                        range: edit_range,
+                        new_text: m.name.clone(),
+                    })),
+                    ..Default::default()
+                }
+            })
+            .collect();
+
+        Some(items)
+    }
+
+    /// Build method completions (after `expr->`)
+    ///
+    /// Only functions taking at least one parameter are offered; the inserted
+    /// call omits the first argument, which is the receiver.
+    fn build_method_completions(
+        &self,
+        edit_range: Range,
+        uri_str: &str,
+        content: &str,
+    ) -> Vec<CompletionItem> {
+        let method_item = |name: &str, signature: &str, rank, documentation: Option<&str>| {
+            let params = completion::method_parameters(signature)?;
+            let mut detail_params: Vec<&str> = params.remaining.clone();
+            if params.has_optional {
+                detail_params.push("...");
+            }
+            Some(CompletionItem {
+                label: name.to_string(),
+                kind: Some(CompletionItemKind::METHOD),
+                detail: Some(format!("->{}({})", name, detail_params.join(", "))),
+                documentation: documentation.map(|d| Documentation::String(d.to_string())),
+                sort_text: Some(completion::sort_text(rank, name)),
+                insert_text_format: Some(InsertTextFormat::SNIPPET),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range: edit_range,
+                    new_text: params.snippet(name),
+                })),
+                ..Default::default()
+            })
+        };
+
+        let mut items: Vec<CompletionItem> = self
+            .get_symbols(uri_str, content)
+            .iter()
+            .filter(|sym| sym.kind == SymbolKind::Function)
+            .filter_map(|sym| {
+                let signature = sym.signature.as_deref()?;
+                method_item(&sym.full_name(), signature, CompletionRank::Local, None)
+            })
+            .collect();
+        items.extend(
+            self.workspace_autoload_functions(uri_str)
+                .iter()
+                .filter_map(|func| {
+                    let signature = func.signature.as_deref()?;
+                    method_item(&func.name, signature, CompletionRank::Workspace, None)
+                }),
+        );
+        items.extend(
+            BUILTIN_FUNCTIONS
+                .iter()
+                .filter(|func| func.availability.is_compatible(self.editor_mode()))
+                .filter_map(|func| {
+                    let documentation =
+                        self.builtin_documentation(func.availability, func.description);
+                    method_item(
+                        func.name,
+                        func.signature,
+                        CompletionRank::Builtin,
+                        Some(&documentation),
+                    )
+                }),
+        );
+        items
+    }
+
     /// Build function/variable completions (original behavior)
     fn build_function_completions(
         &self,
@@ -1085,23 +4250,21 @@ impl Backend {
         uri_str: &str,
         content: &str,
         input_has_scope: bool,
+        is_vim9: bool,
     ) -> Vec<CompletionItem> {
         // 1. Built-in functions (filtered by editor mode, with availability labels)
         let mut items: Vec<CompletionItem> = BUILTIN_FUNCTIONS
             .iter()
-            .filter(|func| func.availability.is_compatible(self.editor_mode))
+            .filter(|func| func.availability.is_compatible(self.editor_mode()))
             .map(|func| {
-                let label_suffix = func.availability.label_suffix();
-                let documentation = if label_suffix.is_empty() {
-                    func.description.to_string()
-                } else {
-                    format!("{}\n{}", label_suffix.trim(), func.description)
-                };
+                let documentation = self.builtin_documentation(func.availability, func.description);
                 CompletionItem {
                     label: func.name.to_string(),
                     kind: Some(CompletionItemKind::FUNCTION),
+                    commit_characters: self.commit_characters(CompletionItemKind::FUNCTION),
                     detail: Some(func.signature.to_string()),
                     documentation: Some(Documentation::String(documentation)),
+                    label_details: self.label_details(Some(func.signature), func.availability),
                     text_edit: Some(CompletionTextEdit::Edit(TextEdit {
                         range: edit_range,
                         new_text: func.name.to_string(),
@@ -1118,6 +4281,15 @@ impl Backend {
             if sym.kind == SymbolKind::Parameter || sym.name.is_empty() {
                 continue;
             }
+            // Vim9 script has no l: and a: scopes
+            if is_vim9
+                && matches!(
+                    sym.scope,
+                    symbols::VimScope::Local | symbols::VimScope::Argument
+                )
+            {
+                continue;
+            }
             let kind = match sym.kind {
                 SymbolKind::Function => CompletionItemKind::FUNCTION,
                 SymbolKind::Variable => CompletionItemKind::VARIABLE,
@@ -1146,7 +4318,11 @@ impl Backend {
                 label: full_name.clone(),
                 filter_text,
                 kind: Some(kind),
+                commit_characters: self.commit_characters(kind),
+                label_details: self.label_details(sym.signature.as_deref(), Availability::Common),
                 detail,
+                documentation: sym.doc.clone().map(Documentation::String),
+                sort_text: Some(completion::sort_text(CompletionRank::Local, &full_name)),
                 text_edit: Some(CompletionTextEdit::Edit(TextEdit {
                     range: edit_range,
                     new_text: full_name,
@@ -1155,22 +4331,51 @@ impl Backend {
             });
         }
 
-        // 3. Built-in variables (v:, b: scope)
+        // 3. Autoload functions from the workspace index and $VIMRUNTIME
+        let workspace_functions = self.workspace_autoload_functions(uri_str);
+        let mut seen: HashSet<String> = items.iter().map(|item| item.label.clone()).collect();
+        let runtime_functions = self.runtime_autoload_functions();
+        for func in workspace_functions.iter().chain(runtime_functions.iter()) {
+            if !seen.insert(func.name.clone()) {
+                continue;
+            }
+            items.push(CompletionItem {
+                label: func.name.clone(),
+                kind: Some(CompletionItemKind::FUNCTION),
+                commit_characters: self.commit_characters(CompletionItemKind::FUNCTION),
+                label_details: self.label_details(func.signature.as_deref(), Availability::Common),
+                detail: func.signature.clone(),
+                documentation: Some(Documentation::String(
+                    func.doc
+                        .clone()
+                        .or_else(|| self.function_help(&func.name))
+                        .unwrap_or_else(|| "autoload function".to_string()),
+                )),
+                sort_text: Some(completion::sort_text(CompletionRank::Workspace, &func.name)),
+                text_edit: Some(CompletionTextEdit::Edit(TextEdit {
+                    range: edit_range,
+                    new_text: func.name.clone(),
+                })),
+                ..Default::default()
+            });
+        }
+
+        // 4. Built-in variables (v:, b: scope)
         for var in BUILTIN_VARIABLES
             .iter()
-            .filter(|v| v.availability.is_compatible(self.editor_mode))
+            .filter(|v| v.availability.is_compatible(self.editor_mode()))
         {
-            let label_suffix = var.availability.label_suffix();
-            let documentation = if label_suffix.is_empty() {
-                var.description.to_string()
-            } else {
-                format!("{}\n{}", label_suffix.trim(), var.description)
-            };
+            let documentation = self.builtin_documentation(var.availability, var.description);
             items.push(CompletionItem {
                 label: var.name.to_string(),
                 kind: Some(CompletionItemKind::VARIABLE),
-                detail: Some("predefined variable".to_string()),
+                detail: Some(if var.readonly {
+                    "predefined variable (read-only)".to_string()
+                } else {
+                    "predefined variable".to_string()
+                }),
                 documentation: Some(Documentation::String(documentation)),
+                label_details: self.label_details(None, var.availability),
                 text_edit: Some(CompletionTextEdit::Edit(TextEdit {
                     range: edit_range,
                     new_text: var.name.to_string(),
@@ -1183,73 +4388,517 @@ impl Backend {
     }
 }
 
+/// Get the file path of a source file key (a URI for opened documents, a path for indexed files)
+fn source_file_path(key: &str) -> PathBuf {
+    key.parse::<Uri>()
+        .ok()
+        .and_then(|uri| uri.to_file_path().map(|path| path.into_owned()))
+        .unwrap_or_else(|| PathBuf::from(key))
+}
+
+/// Get the range of a symbol's name
+fn symbol_range(symbol: &symbols::Symbol) -> Range {
+    Range {
+        start: Position {
+            line: symbol.start.0 as u32,
+            character: symbol.start.1 as u32,
+        },
+        end: Position {
+            line: symbol.end.0 as u32,
+            character: symbol.end.1 as u32,
+        },
+    }
+}
+
+/// Find the locations to edit when renaming a symbol (including its declaration)
+///
+/// Function names written as text are skipped unless `string_references` is set.
+fn rename_locations(
+    tree: &Tree,
+    source: &str,
+    reference: &symbols::Reference,
+    string_references: bool,
+) -> Vec<symbols::SourceLocation> {
+    let locations = find_references(tree, source, &reference.name, reference.scope, true);
+    if string_references {
+        return locations;
+    }
+    let text_starts: HashSet<(usize, usize)> =
+        symbols::find_text_references(tree, source, &reference.name, reference.scope)
+            .iter()
+            .map(|loc| loc.start)
+            .collect();
+    locations
+        .into_iter()
+        .filter(|loc| !text_starts.contains(&loc.start))
+        .collect()
+}
+
+/// Build the replacement text for a renamed location
+///
+/// Script-local functions written with `<SID>` or `<SNR>{nr}_` (in mappings
+/// and strings) keep the prefix, followed by the new name without `s:`.
+fn renamed_text(source: &str, location: &symbols::SourceLocation, new_name: &str) -> String {
+    let written = source
+        .lines()
+        .nth(location.start.0)
+        .and_then(|line| line.get(location.start.1..));
+    match written.and_then(|text| Some((text, symbols::script_prefix_len(text)?))) {
+        Some((text, len)) => format!(
+            "{}{}",
+            &text[..len],
+            new_name.strip_prefix("s:").unwrap_or(new_name)
+        ),
+        None => new_name.to_string(),
+    }
+}
+
+/// Convert a symbol kind to its LSP counterpart
+fn lsp_symbol_kind(kind: SymbolKind) -> tower_lsp_server::ls_types::SymbolKind {
+    match kind {
+        SymbolKind::Function => tower_lsp_server::ls_types::SymbolKind::FUNCTION,
+        SymbolKind::Variable | SymbolKind::Parameter => {
+            tower_lsp_server::ls_types::SymbolKind::VARIABLE
+        }
+    }
+}
+
+/// Convert a source location to an LSP range
+fn source_location_range(location: &symbols::SourceLocation) -> Range {
+    Range {
+        start: Position {
+            line: location.start.0 as u32,
+            character: location.start.1 as u32,
+        },
+        end: Position {
+            line: location.end.0 as u32,
+            character: location.end.1 as u32,
+        },
+    }
+}
+
+/// What a definition request looks for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Target {
+    /// `textDocument/definition`: the assignment of a variable reaching the
+    /// reference
+    Definition,
+    /// `textDocument/declaration`: the first assignment of a variable
+    Declaration,
+}
+
+/// Pick the assignment a variable reference resolves to, among the matching
+/// symbols of its file
+///
+/// Inside a function, `l:` variables and variables without scope only match
+/// the assignments of that function. The declaration is the first of them,
+/// the definition the last one before the reference (or the one under it).
+fn variable_assignment<'a>(
+    symbols: &'a [symbols::Symbol],
+    candidates: impl Iterator<Item = &'a symbols::Symbol>,
+    scope: symbols::VimScope,
+    position: Position,
+    target: Target,
+) -> Option<&'a symbols::Symbol> {
+    let at = (position.line as usize, position.character as usize);
+    let function = symbols
+        .iter()
+        .rfind(|s| s.kind == SymbolKind::Function && s.extent.0 <= at && at < s.extent.1)
+        .filter(|_| {
+            matches!(
+                scope,
+                symbols::VimScope::Local | symbols::VimScope::Implicit
+            )
+        });
+    let assignments: Vec<&symbols::Symbol> = candidates
+        .filter(|s| function.is_none_or(|f| f.extent.0 <= s.start && s.start < f.extent.1))
+        .collect();
+    match target {
+        Target::Declaration => assignments.first().copied(),
+        Target::Definition => assignments
+            .iter()
+            .rev()
+            .find(|s| s.start.0 < at.0 || (s.start <= at && at <= s.end))
+            .or(assignments.first())
+            .copied(),
+    }
+}
+
+/// Link to a symbol definition, selecting its name
+fn symbol_link(uri: Uri, symbol: &symbols::Symbol) -> LocationLink {
+    LocationLink {
+        origin_selection_range: None,
+        target_uri: uri,
+        target_range: symbol_extent(symbol),
+        target_selection_range: symbol_range(symbol),
+    }
+}
+
+/// Respond with links to definitions, or with the locations of their names
+/// for clients not supporting links
+fn definition_response(targets: Vec<LocationLink>, links: bool) -> Option<GotoDefinitionResponse> {
+    if links {
+        return (!targets.is_empty()).then_some(GotoDefinitionResponse::Link(targets));
+    }
+    let mut locations: Vec<Location> = targets
+        .into_iter()
+        .map(|link| Location {
+            uri: link.target_uri,
+            range: link.target_selection_range,
+        })
+        .collect();
+    match locations.len() {
+        0 => None,
+        1 => locations.pop().map(GotoDefinitionResponse::Scalar),
+        _ => Some(GotoDefinitionResponse::Array(locations)),
+    }
+}
+
+/// Convert the span of a whole symbol definition to an LSP range
+fn symbol_extent(symbol: &symbols::Symbol) -> Range {
+    let (start, end) = symbol.extent;
+    Range {
+        start: Position {
+            line: start.0 as u32,
+            character: start.1 as u32,
+        },
+        end: Position {
+            line: end.0 as u32,
+            character: end.1 as u32,
+        },
+    }
+}
+
+/// Build a Markdown hover
+fn markdown_hover(value: String, range: Option<Range>) -> Hover {
+    Hover {
+        contents: HoverContents::Markup(MarkupContent {
+            kind: MarkupKind::Markdown,
+            value,
+        }),
+        range,
+    }
+}
+
+/// Convert the span of a syntax node to an LSP range
+fn node_range(node: &tree_sitter::Node) -> Range {
+    Range {
+        start: Position {
+            line: node.start_position().row as u32,
+            character: node.start_position().column as u32,
+        },
+        end: Position {
+            line: node.end_position().row as u32,
+            character: node.end_position().column as u32,
+        },
+    }
+}
+
+/// Match a file name against a pattern with `*` and `?` wildcards
+fn wildcard_match(pattern: &str, name: &str) -> bool {
+    match pattern.chars().next() {
+        None => name.is_empty(),
+        Some('*') => {
+            let rest = &pattern[1..];
+            name.char_indices()
+                .map(|(i, _)| i)
+                .chain(std::iter::once(name.len()))
+                .any(|i| wildcard_match(rest, &name[i..]))
+        }
+        Some(c) => {
+            name.chars().next().is_some_and(|n| c == '?' || c == n) && {
+                let n_len = name.chars().next().map_or(0, char::len_utf8);
+                wildcard_match(&pattern[c.len_utf8()..], &name[n_len..])
+            }
+        }
+    }
+}
+
+/// Give `$/cancelRequest` a chance to abort the current request
+///
+/// tower-lsp cancels a request by dropping its future, which can only happen
+/// at an await point; multi-file searches call this between files.
+async fn cancellation_point() {
+    tokio::task::yield_now().await;
+}
+
 /// Background workspace indexing function
+///
+/// `report` is given the status when the files are discovered, every 50
+/// indexed files, and when indexing is complete.
 fn index_workspace_background(
     workspace_roots: Arc<Mutex<Vec<PathBuf>>>,
+    filter: IndexFilter,
     salsa_db: Arc<Mutex<HjklsDatabase>>,
-    source_files: Arc<Mutex<HashMap<String, SourceFile>>>,
+    source_files: Arc<RwLock<HashMap<String, SourceFile>>>,
     indexing_complete: Arc<AtomicBool>,
+    indexing_status: Arc<Mutex<IndexingStatus>>,
+    report: impl Fn(IndexingStatus),
 ) {
-    // Scan for .vim files
+    let start = Instant::now();
+    let update = |indexed: usize, errors: usize, complete: bool| {
+        let mut status = indexing_status.lock().unwrap();
+        status.files_indexed = indexed;
+        status.errors = errors;
+        status.complete = complete;
+        status.elapsed_ms = start.elapsed().as_millis() as u64;
+        report(status.clone());
+    };
+
+    // Scan for .vim files and included files
     let vim_files: Vec<PathBuf> = {
         let roots = workspace_roots.lock().unwrap();
         let mut files = Vec::new();
         for root in roots.iter() {
-            scan_directory_recursive(root, &mut files);
+            scan_directory_recursive(root, root, &filter, &mut files);
         }
         files
     };
 
     let file_count = vim_files.len();
     log_debug!("indexing: starting, found {} .vim files", file_count);
+    indexing_status.lock().unwrap().files_discovered = file_count;
+    update(0, 0, false);
 
     // Index each file
+    let mut errors = 0;
     for (i, path) in vim_files.iter().enumerate() {
-        if let Ok(content) = std::fs::read_to_string(path) {
-            let uri = path.to_string_lossy().to_string();
+        let uri = path.to_string_lossy().to_string();
 
-            let db = salsa_db.lock().unwrap();
-            let mut sf_map = source_files.lock().unwrap();
-
-            if !sf_map.contains_key(&uri) {
-                let sf = SourceFile::new(&*db, uri.clone(), content);
-                sf_map.insert(uri.clone(), sf);
+        if !source_files.read().unwrap().contains_key(&uri) {
+            // The content is read from disk by salsa, and not kept once evicted
+            let sf = {
+                let db = salsa_db.lock().unwrap();
+                let sf = SourceFile::new(&*db, uri.clone(), None, None, 0);
                 // Trigger symbol parsing to populate cache
                 let _ = db::parse_symbols(&*db, sf);
-            }
+                // Unreadable files (permissions, invalid UTF-8) index as empty
+                if db::file_content(&*db, sf).is_empty()
+                    && std::fs::metadata(path).is_ok_and(|m| m.len() > 0)
+                {
+                    errors += 1;
+                }
+                sf
+            };
+            source_files.write().unwrap().entry(uri).or_insert(sf);
         }
 
         if (i + 1) % 50 == 0 {
             log_debug!("indexing: progress {}/{}", i + 1, file_count);
+            update(i + 1, errors, false);
+            // Drop file contents beyond the memory budget
+            salsa_db.lock().unwrap().trigger_lru_eviction();
         }
     }
+    salsa_db.lock().unwrap().trigger_lru_eviction();
 
     indexing_complete.store(true, Ordering::SeqCst);
+    update(file_count, errors, true);
     log_debug!("indexing: complete, indexed {} files", file_count);
 }
 
-/// Recursively scan a directory for .vim files
-fn scan_directory_recursive(dir: &PathBuf, files: &mut Vec<PathBuf>) {
+/// Background $VIMRUNTIME indexing function
+///
+/// Only `autoload/` and `plugin/` are indexed, for definitions of functions
+/// provided by the runtime. The thread yields between files so that it
+/// doesn't compete with request handling.
+fn index_runtime_background(
+    runtime: &Path,
+    salsa_db: Arc<Mutex<HjklsDatabase>>,
+    runtime_files: Arc<RwLock<HashMap<String, SourceFile>>>,
+) {
+    let mut files = Vec::new();
+    let filter = IndexFilter::default();
+    for dir in ["autoload", "plugin"] {
+        scan_directory_recursive(&runtime.join(dir), runtime, &filter, &mut files);
+    }
+    log_debug!(
+        "indexing: starting $VIMRUNTIME, found {} files",
+        files.len()
+    );
+
+    for (i, path) in files.iter().enumerate() {
+        std::thread::yield_now();
+        let key = path.to_string_lossy().to_string();
+        let sf = {
+            let db = salsa_db.lock().unwrap();
+            let sf = SourceFile::new(&*db, key.clone(), None, None, 0);
+            let _ = db::parse_symbols(&*db, sf);
+            sf
+        };
+        runtime_files.write().unwrap().insert(key, sf);
+
+        if (i + 1) % 50 == 0 {
+            salsa_db.lock().unwrap().trigger_lru_eviction();
+        }
+    }
+    salsa_db.lock().unwrap().trigger_lru_eviction();
+    log_debug!(
+        "indexing: $VIMRUNTIME complete, indexed {} files",
+        files.len()
+    );
+}
+
+/// Recursively scan a directory for files to index
+///
+/// Paths are matched against `filter` relative to `root`.
+pub(crate) fn scan_directory_recursive(
+    dir: &Path,
+    root: &Path,
+    filter: &IndexFilter,
+    files: &mut Vec<PathBuf>,
+) {
     let Ok(entries) = std::fs::read_dir(dir) else {
         return;
     };
 
     for entry in entries.flatten() {
         let path = entry.path();
+        let Ok(relative) = path.strip_prefix(root) else {
+            continue;
+        };
+        let relative = relative.to_string_lossy().replace('\\', "/");
 
-        // Skip hidden directories and common non-source directories
-        if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-            if name.starts_with('.') || name == "node_modules" || name == "target" {
-                continue;
+        if path.is_dir() {
+            if !filter.is_excluded_dir(&relative) {
+                scan_directory_recursive(&path, root, filter, files);
             }
+        } else if filter.is_indexed_file(&relative) {
+            files.push(path);
         }
+    }
+}
 
-        if path.is_dir() {
-            scan_directory_recursive(&path, files);
-        } else if path.extension().is_some_and(|ext| ext == "vim") {
-            files.push(path);
+/// The context of a scope violation at `range`, for `related_information`
+///
+/// The innermost script-level block containing the usage, and the end of
+/// the last function defined before it.
+fn scope_violation_context(
+    root: &tree_sitter::Node,
+    source: &str,
+    range: Range,
+) -> Vec<(Range, String)> {
+    let point = |position: Position| tree_sitter::Point {
+        row: position.line as usize,
+        column: position.character as usize,
+    };
+    let node_range = |node: &tree_sitter::Node| Range {
+        start: Position::new(
+            node.start_position().row as u32,
+            node.start_position().column as u32,
+        ),
+        end: Position::new(
+            node.end_position().row as u32,
+            node.end_position().column as u32,
+        ),
+    };
+    let mut context = Vec::new();
+
+    let mut node = root.descendant_for_point_range(point(range.start), point(range.end));
+    while let Some(current) = node {
+        let block = match current.kind() {
+            "if_statement" => Some("if"),
+            "for_loop" => Some("for"),
+            "while_loop" => Some("while"),
+            "try_statement" => Some("try"),
+            _ => None,
+        };
+        if let Some((block, keyword)) = block.zip(current.child(0)) {
+            context.push((
+                node_range(&keyword),
+                format!("The enclosing `{}` block is outside of any function", block),
+            ));
+            break;
+        }
+        node = current.parent();
+    }
+
+    // The last function ending before the usage
+    let mut last_function: Option<tree_sitter::Node> = None;
+    let mut stack = vec![*root];
+    while let Some(node) = stack.pop() {
+        if node.start_position() >= point(range.start) {
+            continue;
+        }
+        if node.kind() == "function_definition" {
+            if node.end_position() <= point(range.start)
+                && last_function.is_none_or(|f| f.end_byte() < node.end_byte())
+            {
+                last_function = Some(node);
+            }
+            continue;
         }
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
     }
+    let end = last_function.and_then(|function| {
+        function
+            .child(function.child_count().saturating_sub(1))
+            .filter(|end| end.kind() == "endfunction")
+            .map(|end| (function, end))
+    });
+    if let Some((function, end)) = end {
+        let name = function
+            .named_children(&mut function.walk())
+            .find(|child| child.kind() == "function_declaration")
+            .and_then(|declaration| declaration.child_by_field_name("name"))
+            .and_then(|name| name.utf8_text(source.as_bytes()).ok())
+            .unwrap_or("?");
+        context.push((
+            node_range(&end),
+            format!("The function `{}()` before it ends here", name),
+        ));
+    }
+    context
+}
+
+/// Directories that make their parent a plugin root
+const PLUGIN_DIRS: &[&str] = &["autoload", "ftplugin", "plugin", "syntax"];
+
+/// Get the plugin root of a file, relative to the workspace root
+///
+/// The plugin root is the directory containing the outermost `autoload/`,
+/// `ftplugin/`, `plugin/` or `syntax/` directory of the path, e.g.,
+/// `bundle/foo` for `bundle/foo/autoload/foo.vim`. Returns None when it is
+/// the workspace root itself, or when the file isn't in such a directory.
+fn plugin_root(relative: &Path) -> Option<PathBuf> {
+    let components: Vec<_> = relative.components().collect();
+    let index = components[..components.len().saturating_sub(1)]
+        .iter()
+        .position(|c| PLUGIN_DIRS.iter().any(|dir| c.as_os_str() == *dir))?;
+    (index > 0).then(|| components[..index].iter().collect())
+}
+
+/// Whether a plugin root is an optional package (`pack/*/opt/*`)
+fn is_opt_package(dir: &Path) -> bool {
+    let components: Vec<_> = dir.components().rev().take(4).collect();
+    components.len() == 4
+        && components[1].as_os_str() == "opt"
+        && components[3].as_os_str() == "pack"
+}
+
+/// Get the plugins of the packages under `{dir}/pack/`, like 'packpath'
+///
+/// `pack/*/start/*` plugins come first, then `pack/*/opt/*` ones with `opt`.
+/// Reference: :help packages
+fn package_dirs(dir: &Path, opt: bool) -> Vec<PathBuf> {
+    let subdirs = |dir: &Path| -> Vec<PathBuf> {
+        let mut dirs: Vec<PathBuf> = std::fs::read_dir(dir)
+            .into_iter()
+            .flatten()
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir())
+            .collect();
+        dirs.sort();
+        dirs
+    };
+    let packs = subdirs(&dir.join("pack"));
+    let kinds: &[&str] = if opt { &["start", "opt"] } else { &["start"] };
+    kinds
+        .iter()
+        .flat_map(|kind| packs.iter().flat_map(move |pack| subdirs(&pack.join(kind))))
+        .collect()
 }
 
 /// All map command node kinds recognized by tree-sitter-vim.
@@ -1326,8 +4975,31 @@ fn is_cmd_mapping_missing_keycode(node: &tree_sitter::Node, source: &str) -> boo
     false
 }
 
+/// Check if an ERROR node is a false positive from `v:lua.require'mod'`.
+///
+/// Neovim calls Lua modules as `v:lua.require'mod'.func()` (`:help v:lua-call`),
+/// which tree-sitter-vim cannot parse: the string right after `v:lua.require`
+/// becomes an ERROR node. An ERROR on a single line that follows such a call
+/// is treated as this false positive.
+fn is_v_lua_require_false_positive(node: &tree_sitter::Node, source: &str) -> bool {
+    if !node.is_error() || node.start_position().row != node.end_position().row {
+        return false;
+    }
+    let Some(line) = source.lines().nth(node.start_position().row) else {
+        return false;
+    };
+    let end = node.end_position().column.min(line.len());
+    line[..end]
+        .match_indices("v:lua.require")
+        .any(|(start, prefix)| {
+            line[start + prefix.len()..]
+                .trim_start()
+                .starts_with(['\'', '"'])
+        })
+}
+
 /// Recursively collect ERROR nodes from the syntax tree
-fn collect_errors(
+pub(crate) fn collect_errors(
     cursor: &mut tree_sitter::TreeCursor,
     source: &str,
     diagnostics: &mut Vec<Diagnostic>,
@@ -1335,9 +5007,11 @@ fn collect_errors(
     loop {
         let node = cursor.node();
 
-        // Skip false positives from <Cmd> mappings (tree-sitter-vim limitation)
+        // Skip false positives from <Cmd> mappings and `v:lua.require'mod'`
+        // (tree-sitter-vim limitations)
         if is_cmd_mapping_false_positive(&node, source)
             || is_cmd_mapping_missing_keycode(&node, source)
+            || is_v_lua_require_false_positive(&node, source)
         {
             if !cursor.goto_next_sibling() {
                 break;
@@ -1380,14 +5054,16 @@ fn collect_errors(
                     },
                 },
                 severity: Some(DiagnosticSeverity::ERROR),
+                code: Some(NumberOrString::String("hjkls/syntax_error".to_string())),
                 source: Some("hjkls".to_string()),
                 message,
                 ..Default::default()
             });
         }
 
-        // Recurse into children
-        if cursor.goto_first_child() {
+        // Recurse into children, except for ERROR nodes: anything broken
+        // inside them is a cascade of the error already reported
+        if !node.is_error() && cursor.goto_first_child() {
             collect_errors(cursor, source, diagnostics);
             cursor.goto_parent();
         }
@@ -1570,15 +5246,112 @@ fn count_call_arguments(node: tree_sitter::Node, _source: &str) -> usize {
 
 impl LanguageServer for Backend {
     async fn initialize(&self, params: InitializeParams) -> Result<InitializeResult> {
+        // Don't outlive the client when it crashes
+        if let Some(pid) = params.process_id {
+            watchdog::spawn_process_watch(pid);
+        }
+
+        // Apply client settings before loading the config they are merged into
+        if let Some(options) = params.initialization_options.clone() {
+            match Settings::from_value(options) {
+                Ok(settings) => {
+                    self.apply_settings(settings);
+                }
+                Err(e) => {
+                    self.client
+                        .show_message(
+                            MessageType::WARNING,
+                            format!("hjkls: invalid initializationOptions: {e}"),
+                        )
+                        .await;
+                }
+            }
+        }
+
         // Capture workspace roots for cross-file features
         self.set_workspace_roots(&params);
 
+        let completion_item = params
+            .capabilities
+            .text_document
+            .as_ref()
+            .and_then(|td| td.completion.as_ref())
+            .and_then(|c| c.completion_item.as_ref());
+        self.label_details_support.store(
+            completion_item
+                .and_then(|item| item.label_details_support)
+                .unwrap_or(false),
+            Ordering::Relaxed,
+        );
+        self.commit_characters_support.store(
+            completion_item
+                .and_then(|item| item.commit_characters_support)
+                .unwrap_or(false),
+            Ordering::Relaxed,
+        );
+
+        self.workspace_symbol_resolve_support.store(
+            params
+                .capabilities
+                .workspace
+                .as_ref()
+                .and_then(|ws| ws.symbol.as_ref())
+                .and_then(|symbol| symbol.resolve_support.as_ref())
+                .is_some_and(|support| support.properties.iter().any(|p| p == "location.range")),
+            Ordering::Relaxed,
+        );
+        self.definition_link_support.store(
+            params
+                .capabilities
+                .text_document
+                .as_ref()
+                .and_then(|td| td.definition.as_ref())
+                .and_then(|definition| definition.link_support)
+                .unwrap_or(false),
+            Ordering::Relaxed,
+        );
+
+        let workspace_edit = params
+            .capabilities
+            .workspace
+            .as_ref()
+            .and_then(|ws| ws.workspace_edit.as_ref());
+        let document_changes = workspace_edit.and_then(|edit| edit.document_changes) == Some(true);
+        self.document_changes_support
+            .store(document_changes, Ordering::Relaxed);
+        // Resource operations are only valid in `documentChanges`
+        let resource_operations = workspace_edit
+            .filter(|_| document_changes)
+            .and_then(|edit| edit.resource_operations.clone())
+            .unwrap_or_default();
+        self.create_file_support.store(
+            resource_operations.contains(&ResourceOperationKind::Create),
+            Ordering::Relaxed,
+        );
+        self.rename_file_support.store(
+            resource_operations.contains(&ResourceOperationKind::Rename),
+            Ordering::Relaxed,
+        );
+
+        let workspace = params.capabilities.workspace.as_ref();
+        self.configuration_support.store(
+            workspace.and_then(|ws| ws.configuration) == Some(true),
+            Ordering::Relaxed,
+        );
+        self.configuration_registration.store(
+            workspace
+                .and_then(|ws| ws.did_change_configuration.as_ref())
+                .and_then(|caps| caps.dynamic_registration)
+                == Some(true),
+            Ordering::Relaxed,
+        );
+
         Ok(InitializeResult {
             capabilities: ServerCapabilities {
                 text_document_sync: Some(TextDocumentSyncCapability::Options(
                     TextDocumentSyncOptions {
                         open_close: Some(true),
-                        change: Some(TextDocumentSyncKind::FULL),
+                        change: Some(TextDocumentSyncKind::INCREMENTAL),
                         will_save: None,
                         will_save_wait_until: None,
                         save: Some(TextDocumentSyncSaveOptions::SaveOptions(SaveOptions {
@@ -1586,22 +5359,40 @@ impl LanguageServer for Backend {
                         })),
                     },
                 )),
-                completion_provider: Some(CompletionOptions::default()),
+                completion_provider: Some(CompletionOptions {
+                    trigger_characters: Some(vec![
+                        ".".to_string(),
+                        "/".to_string(),
+                        ">".to_string(),
+                    ]),
+                    ..Default::default()
+                }),
                 signature_help_provider: Some(SignatureHelpOptions {
-                    trigger_characters: Some(vec!["(".to_string(), ",".to_string()]),
+                    trigger_characters: Some(vec![
+                        "(".to_string(),
+                        ",".to_string(),
+                        " ".to_string(),
+                    ]),
                     retrigger_characters: None,
                     work_done_progress_options: Default::default(),
                 }),
                 definition_provider: Some(OneOf::Left(true)),
+                declaration_provider: Some(DeclarationCapability::Simple(true)),
                 hover_provider: Some(HoverProviderCapability::Simple(true)),
                 references_provider: Some(OneOf::Left(true)),
                 document_symbol_provider: Some(OneOf::Left(true)),
-                workspace_symbol_provider: Some(OneOf::Left(true)),
+                workspace_symbol_provider: Some(OneOf::Right(WorkspaceSymbolOptions {
+                    resolve_provider: Some(true),
+                    work_done_progress_options: Default::default(),
+                })),
                 rename_provider: Some(OneOf::Right(RenameOptions {
                     prepare_provider: Some(true),
                     work_done_progress_options: Default::default(),
                 })),
                 document_highlight_provider: Some(OneOf::Left(true)),
+                linked_editing_range_provider: Some(LinkedEditingRangeServerCapabilities::Simple(
+                    true,
+                )),
                 folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
                 selection_range_provider: Some(SelectionRangeProviderCapability::Simple(true)),
                 code_action_provider: Some(CodeActionProviderCapability::Simple(true)),
@@ -1617,14 +5408,60 @@ impl LanguageServer for Backend {
             .log_message(MessageType::INFO, "hjkls initialized!")
             .await;
 
+        // Some clients only send configuration changes for registered servers
+        if self.configuration_registration.load(Ordering::Relaxed) {
+            let registration = Registration {
+                id: "hjkls/didChangeConfiguration".to_string(),
+                method: "workspace/didChangeConfiguration".to_string(),
+                register_options: None,
+            };
+            if let Err(e) = self.client.register_capability(vec![registration]).await {
+                log_debug!("registering didChangeConfiguration failed: {:?}", e);
+            }
+        }
+
+        // Settings from the client configuration replace initializationOptions
+        if self.configuration_support.load(Ordering::Relaxed) {
+            if let Some(value) = self.pull_settings().await.filter(|v| !v.is_null()) {
+                self.update_settings(value).await;
+            }
+        }
+
+        self.blocking(|backend| backend.load_script_runtimepath())
+            .await;
+
         // Start background indexing
         let workspace_roots = Arc::clone(&self.workspace_roots);
         let salsa_db = Arc::clone(&self.salsa_db);
         let source_files = Arc::clone(&self.source_files);
         let indexing_complete = Arc::clone(&self.indexing_complete);
+        let indexing_status = Arc::clone(&self.indexing_status);
+        let client = self.client.clone();
+        let handle = tokio::runtime::Handle::current();
+        let runtime_files = Arc::clone(&self.runtime_files);
+        let (runtime, filter) = {
+            let config = self.config.lock().unwrap();
+            let runtime = self.vimruntime().filter(|_| config.index.vimruntime);
+            (runtime, IndexFilter::new(&config.index))
+        };
 
         std::thread::spawn(move || {
-            index_workspace_background(workspace_roots, salsa_db, source_files, indexing_complete);
+            index_workspace_background(
+                workspace_roots,
+                filter,
+                Arc::clone(&salsa_db),
+                source_files,
+                indexing_complete,
+                indexing_status,
+                |status| {
+                    // In order, unlike spawned tasks
+                    handle.block_on(client.send_notification::<IndexingStatusNotification>(status));
+                },
+            );
+            // $VIMRUNTIME comes last, with the lowest priority
+            if let Some(runtime) = runtime {
+                index_runtime_background(&runtime, salsa_db, runtime_files);
+            }
         });
     }
 
@@ -1635,35 +5472,35 @@ impl LanguageServer for Backend {
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
         let uri = params.text_document.uri;
         let text = params.text_document.text;
+        let version = params.text_document.version;
 
-        let diagnostics = self.open_document(uri.clone(), text);
-        self.client
-            .publish_diagnostics(uri, diagnostics, None)
-            .await;
+        {
+            let _sync = self.document_sync.lock().await;
+            let uri = uri.clone();
+            self.blocking(move |backend| backend.open_document(uri, text, version))
+                .await;
+        }
+        self.publish_versioned_diagnostics(uri, version).await;
     }
 
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
         let uri = params.text_document.uri;
-        // We use FULL sync, so take the last change
-        let Some(change) = params.content_changes.into_iter().last() else {
-            return;
-        };
+        let version = params.text_document.version;
+        log_debug!("did_change: changes={}", params.content_changes.len());
 
-        log_debug!(
-            "did_change: len={}, lines={}, empty={}",
-            change.text.len(),
-            change.text.lines().count(),
-            change.text.is_empty()
-        );
-
-        let diagnostics = self.update_document(&uri, change.text);
-        self.client
-            .publish_diagnostics(uri, diagnostics, None)
-            .await;
+        {
+            let _sync = self.document_sync.lock().await;
+            let uri = uri.clone();
+            let changes = params.content_changes;
+            self.blocking(move |backend| backend.update_document(&uri, changes, version))
+                .await;
+        }
+        self.publish_versioned_diagnostics(uri, version).await;
     }
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
-        let mut docs = self.documents.lock().unwrap();
+        let _sync = self.document_sync.lock().await;
+        let mut docs = self.documents.write().unwrap();
         docs.remove(&params.text_document.uri);
     }
 
@@ -1677,7 +5514,7 @@ impl LanguageServer for Backend {
             text
         } else {
             // Fall back to reading from the document store
-            let docs = self.documents.lock().unwrap();
+            let docs = self.documents.read().unwrap();
             if let Some(doc) = docs.get(&uri) {
                 doc.text.text.clone()
             } else {
@@ -1685,25 +5522,73 @@ impl LanguageServer for Backend {
             }
         };
 
-        // Update the salsa cache
-        let _ = self.get_symbols(&uri_str, &content);
+        // Update the salsa cache, remembering the functions defined before and
+        // after saving: files calling them may have stale diagnostics
+        let mut functions = self.defined_functions(&uri_str);
+        {
+            let (uri_str, content) = (uri_str.clone(), content.clone());
+            self.blocking(move |backend| backend.get_symbols(&uri_str, &content))
+                .await;
+        }
+        functions.extend(self.defined_functions(&uri_str));
         log_debug!("did_save: updated index for {}", uri_str);
+
+        // The indexed entry of the file is re-read from disk
+        if let Some(path) = uri.to_file_path() {
+            let indexed = self
+                .source_files
+                .read()
+                .unwrap()
+                .get(path.to_string_lossy().as_ref())
+                .copied();
+            if let Some(source_file) = indexed {
+                let mut db = self.salsa_db.lock().unwrap();
+                let revision = source_file.revision(&*db);
+                source_file.set_revision(&mut *db).to(revision + 1);
+            }
+        }
+
+        // The file may add plugins to the runtime path
+        let home = std::env::var_os("HOME").map(PathBuf::from);
+        if self.config.lock().unwrap().index.runtimepath_from_scripts
+            && !Declarations::parse(&content, home.as_deref()).is_empty()
+        {
+            self.blocking(|backend| backend.load_script_runtimepath())
+                .await;
+        }
+
+        self.relint_dependents(&uri, &functions).await;
+    }
+
+    async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
+        // Clients supporting `workspace/configuration` may send no settings here
+        let value = if self.configuration_support.load(Ordering::Relaxed) {
+            self.pull_settings().await
+        } else {
+            Some(params.settings)
+        };
+        if let Some(value) = value.filter(|v| !v.is_null()) {
+            log_debug!("did_change_configuration: {}", value);
+            self.update_settings(value).await;
+        }
     }
 
     async fn completion(&self, params: CompletionParams) -> Result<Option<CompletionResponse>> {
+        self.documents_synced().await;
         let uri = params.text_document_position.text_document.uri;
         let position = params.text_document_position.position;
 
         // Get document content and determine completion context
-        let (uri_str, content, token_start, input_has_scope, context, line_text) = {
-            let docs = self.documents.lock().unwrap();
+        let (uri_str, text, token_start, input_has_scope, context, line_text) = {
+            let docs = self.documents.read().unwrap();
             let Some(doc) = docs.get(&uri) else {
                 return Ok(Some(CompletionResponse::Array(vec![])));
             };
-            let content = doc.text.text.clone();
+            let text = Arc::clone(&doc.text);
 
             // Find token start position (including scope prefix like s:, g:)
-            let line = content
+            let line = text
+                .text
                 .lines()
                 .nth(position.line as usize)
                 .unwrap_or("")
@@ -1720,7 +5605,7 @@ impl LanguageServer for Backend {
 
             (
                 uri.to_string(),
-                content,
+                text,
                 token_start,
                 input_has_scope,
                 context,
@@ -1728,6 +5613,8 @@ impl LanguageServer for Backend {
             )
         };
 
+        let content = text.text.as_str();
+
         // Create text edit range for replacing the current token
         let edit_range = Range {
             start: Position {
@@ -1737,38 +5624,130 @@ impl LanguageServer for Backend {
             end: position,
         };
 
+        let before_cursor = &line_text[..(position.character as usize).min(line_text.len())];
+        let typed = before_cursor.get(token_start..).unwrap_or("");
+
+        // Neovim `v:lua.` modules and functions
+        if Availability::NeovimOnly.is_compatible(self.editor_mode()) {
+            if let Some(mut items) = self.build_v_lua_completions(position, before_cursor) {
+                completion::apply_sort_text(&mut items, typed);
+                return Ok(Some(CompletionResponse::Array(items)));
+            }
+        }
+
+        // Vim9 class members: `this.`, `obj.` and `ClassName.`
+        if let Some(object) = crate::completion::find_member_object(&line_text, token_start) {
+            if let Some(mut items) =
+                self.build_member_completions(edit_range, content, position.line as usize, object)
+            {
+                completion::apply_sort_text(&mut items, typed);
+                return Ok(Some(CompletionResponse::Array(items)));
+            }
+        }
+        let trigger_character = params
+            .context
+            .as_ref()
+            .and_then(|c| c.trigger_character.as_deref());
+        // `.` is also string concatenation in legacy script; don't pop up unrelated items
+        if trigger_character == Some(".")
+            || (trigger_character == Some("/") && context != CompletionContext::FilePath)
+            || (trigger_character == Some(">") && context != CompletionContext::Method)
+        {
+            return Ok(Some(CompletionResponse::Array(vec![])));
+        }
+
+        // `<...>` map options and key notations replace the text from `<`
+        let key_range =
+            crate::completion::key_notation_start(before_cursor).map_or(edit_range, |start| {
+                Range {
+                    start: Position {
+                        line: position.line,
+                        character: start as u32,
+                    },
+                    end: position,
+                }
+            });
+
         // Build completions based on context
-        let items: Vec<CompletionItem> = match context {
+        let mut items: Vec<CompletionItem> = match context {
             CompletionContext::Command => {
                 // Ex commands completion
-                self.build_command_completions(edit_range)
+                let mut items =
+                    self.build_command_completions(edit_range, vim9::is_vim9script(content));
+                items.extend(self.build_user_command_completions(edit_range, &uri_str, content));
+                items
             }
             CompletionContext::AutocmdEvent => {
                 // Autocmd event completion
                 self.build_autocmd_event_completions(edit_range)
             }
+            CompletionContext::AutocmdPattern => {
+                // Filetype / file pattern completion
+                self.build_autocmd_pattern_completions(position, &line_text)
+            }
+            CompletionContext::FilePath => {
+                // File path completion
+                self.build_file_path_completions(position, &line_text)
+            }
+            CompletionContext::Colorscheme => {
+                // Color scheme completion
+                self.build_colorscheme_completions(position, &line_text)
+            }
+            CompletionContext::Method => {
+                // Method completion after `->`
+                self.build_method_completions(edit_range, &uri_str, content)
+            }
             CompletionContext::Option => {
                 // Option completion
                 self.build_option_completions(edit_range, &line_text)
             }
+            CompletionContext::OptionValue => {
+                // Option value completion
+                self.build_option_value_completions(position, &line_text)
+            }
             CompletionContext::MapOption => {
                 // Map option completion
-                self.build_map_option_completions(edit_range)
+                self.build_map_option_completions(key_range)
+            }
+            CompletionContext::KeyNotation => {
+                // Key notation completion
+                self.build_key_notation_completions(key_range)
+            }
+            CompletionContext::PlugMapping => {
+                // <Plug> mapping target completion
+                self.build_plug_mapping_completions(position, &line_text, &uri_str, content)
             }
             CompletionContext::HasFeature => {
                 // has() feature completion
                 self.build_has_feature_completions(edit_range)
             }
+            CompletionContext::SyntaxSubcommand => {
+                // :syntax sub-command completion
+                self.build_syntax_subcommand_completions(edit_range)
+            }
+            CompletionContext::SyntaxArgument => {
+                // :syntax argument completion
+                self.build_syntax_argument_completions(edit_range, &line_text)
+            }
             CompletionContext::Function => {
                 // Function/expression context - original behavior
-                self.build_function_completions(edit_range, &uri_str, &content, input_has_scope)
+                self.build_function_completions(
+                    edit_range,
+                    &uri_str,
+                    content,
+                    input_has_scope,
+                    vim9::is_vim9script(content),
+                )
             }
         };
 
+        completion::apply_sort_text(&mut items, typed);
+
         Ok(Some(CompletionResponse::Array(items)))
     }
 
     async fn signature_help(&self, params: SignatureHelpParams) -> Result<Option<SignatureHelp>> {
+        self.documents_synced().await;
         let uri = params.text_document_position_params.text_document.uri;
         let position = params.text_document_position_params.position;
 
@@ -1778,7 +5757,7 @@ impl LanguageServer for Backend {
             position.character
         );
 
-        let docs = self.documents.lock().unwrap();
+        let docs = self.documents.read().unwrap();
         let Some(doc) = docs.get(&uri) else {
             log_debug!("signature_help: document not found");
             return Ok(None);
@@ -1793,8 +5772,16 @@ impl LanguageServer for Backend {
         );
 
         let Some(call_info) = call_info else {
-            log_debug!("signature_help: no call found at position");
-            return Ok(None);
+            // Outside function calls, show the usage of the Ex command
+            let signature = self.command_signature(
+                &doc.text.text,
+                position.line as usize,
+                position.character as usize,
+            );
+            if signature.is_none() {
+                log_debug!("signature_help: no call found at position");
+            }
+            return Ok(signature);
         };
 
         log_debug!(
@@ -1804,10 +5791,7 @@ impl LanguageServer for Backend {
         );
 
         // First, check if it's a built-in function
-        if let Some(builtin) = BUILTIN_FUNCTIONS
-            .iter()
-            .find(|f| f.name == call_info.function_name)
-        {
+        if let Some(builtin) = BuiltinFunction::find(&call_info.function_name) {
             let params = parse_signature_params(builtin.signature);
             let parameters: Vec<ParameterInformation> = params
                 .iter()
@@ -1833,10 +5817,10 @@ impl LanguageServer for Backend {
 
         // Then, check user-defined functions
         let uri_str = uri.to_string();
-        let content = doc.text.text.clone();
+        let text = Arc::clone(&doc.text);
         drop(docs);
 
-        let symbols = self.get_symbols(&uri_str, &content);
+        let symbols = self.get_symbols(&uri_str, &text.text);
 
         // Look for matching function (handle both scoped and autoload)
         let symbol = symbols.iter().find(|s| {
@@ -1885,134 +5869,36 @@ impl LanguageServer for Backend {
         &self,
         params: GotoDefinitionParams,
     ) -> Result<Option<GotoDefinitionResponse>> {
-        let uri = params.text_document_position_params.text_document.uri;
-        let position = params.text_document_position_params.position;
-
-        let docs = self.documents.lock().unwrap();
-        let Some(doc) = docs.get(&uri) else {
-            return Ok(None);
-        };
-
-        // Find the identifier at the cursor position
-        let reference = find_identifier_at_position(
-            &doc.tree,
-            &doc.text.text,
-            position.line as usize,
-            position.character as usize,
-        );
-
-        let Some(reference) = reference else {
-            return Ok(None);
-        };
-
-        // Check if this is an autoload function call
-        if let Some(autoload_ref) = &reference.autoload {
-            // Release the lock before doing file I/O
-            drop(docs);
-
-            log_debug!("goto_definition: autoload={}", autoload_ref.full_name);
-
-            // Try to find the autoload file (search relative to current doc first)
-            let Some(file_path) = self.find_autoload_file(autoload_ref, Some(&uri)) else {
-                log_debug!(
-                    "goto_definition: file not found for {}",
-                    autoload_ref.to_file_path()
-                );
-                return Ok(None);
-            };
-            log_debug!("goto_definition: found {:?}", file_path);
-
-            // Parse the file and find the function definition
-            let content = match std::fs::read_to_string(&file_path) {
-                Ok(c) => c,
-                Err(_) => {
-                    log_debug!("goto_definition: failed to read {:?}", file_path);
-                    return Ok(None);
-                }
-            };
-
-            let file_uri = file_path.to_string_lossy().to_string();
-            let symbols = self.get_symbols(&file_uri, &content);
-            log_debug!(
-                "goto_definition: symbols={:?}",
-                symbols.iter().map(|s| &s.name).collect::<Vec<_>>()
-            );
-
-            // Look for the function with matching name
-            // Autoload files define functions with full name (e.g., myplugin#util#helper)
-            let Some(symbol) = symbols
-                .iter()
-                .find(|s| s.kind == SymbolKind::Function && s.name == autoload_ref.full_name)
-            else {
-                log_debug!("goto_definition: no match for '{}'", autoload_ref.full_name);
-                return Ok(None);
-            };
-
-            let Some(target_uri) = Uri::from_file_path(&file_path) else {
-                log_debug!("goto_definition: invalid URI for {:?}", file_path);
-                return Ok(None);
-            };
-
-            log_debug!("goto_definition: jumping to {:?}", target_uri);
-            let location = Location {
-                uri: target_uri,
-                range: Range {
-                    start: Position {
-                        line: symbol.start.0 as u32,
-                        character: symbol.start.1 as u32,
-                    },
-                    end: Position {
-                        line: symbol.end.0 as u32,
-                        character: symbol.end.1 as u32,
-                    },
-                },
-            };
-            return Ok(Some(GotoDefinitionResponse::Scalar(location)));
-        }
-
-        // Extract symbols and find the definition in current file
-        let uri_str = uri.to_string();
-        let content = doc.text.text.clone();
-        drop(docs); // Release lock before calling get_symbols
-
-        let symbols = self.get_symbols(&uri_str, &content);
-
-        // Find matching symbol definition
-        let definition = symbols.iter().find(|s| {
-            s.name == reference.name
-                && (reference.scope == symbols::VimScope::Implicit || s.scope == reference.scope)
-                && (reference.is_call == (s.kind == SymbolKind::Function)
-                    || !reference.is_call && s.kind == SymbolKind::Variable)
-        });
-
-        if let Some(symbol) = definition {
-            let location = Location {
-                uri: uri.clone(),
-                range: Range {
-                    start: Position {
-                        line: symbol.start.0 as u32,
-                        character: symbol.start.1 as u32,
-                    },
-                    end: Position {
-                        line: symbol.end.0 as u32,
-                        character: symbol.end.1 as u32,
-                    },
-                },
-            };
-            return Ok(Some(GotoDefinitionResponse::Scalar(location)));
-        }
+        self.documents_synced().await;
+        let links = self.definition_link_support.load(Ordering::Relaxed);
+        Ok(self.find_definition(params, links, Target::Definition))
+    }
 
-        Ok(None)
+    async fn goto_declaration(
+        &self,
+        params: GotoDeclarationParams,
+    ) -> Result<Option<GotoDeclarationResponse>> {
+        self.documents_synced().await;
+        // A variable's first assignment, where the definition is the
+        // assignment reaching the reference
+        Ok(self.find_definition(params, false, Target::Declaration))
     }
 
     async fn hover(&self, params: HoverParams) -> Result<Option<Hover>> {
+        self.documents_synced().await;
         let uri = params.text_document_position_params.text_document.uri;
         let position = params.text_document_position_params.position;
 
-        let docs = self.documents.lock().unwrap();
+        let docs = self.documents.read().unwrap();
         let Some(doc) = docs.get(&uri) else {
             return Ok(None);
         };
+        let token_range = symbols::find_token_at_position(
+            &doc.tree,
+            position.line as usize,
+            position.character as usize,
+        )
+        .map(|node| node_range(&node));
 
         // First, check if it's an Ex command
         if let Some(cmd_name) = symbols::find_command_at_position(
@@ -2024,33 +5910,128 @@ impl LanguageServer for Backend {
             // Find matching command using abbreviation matching
             if let Some(cmd) = BUILTIN_COMMANDS
                 .iter()
-                .filter(|c| c.availability.is_compatible(self.editor_mode))
+                .filter(|c| c.availability.is_compatible(self.editor_mode()))
                 .find(|c| c.matches(&cmd_name))
             {
-                let abbrev_display = if cmd.min_abbrev as usize == cmd.name.len() {
-                    // No abbreviation possible
-                    format!(":{}", cmd.name)
+                // Show abbreviation format: :q[uit]
+                let abbrev_display = format!(":{}", cmd.abbreviated_name());
+                let vim9_note = if VIM9_UNSUPPORTED_COMMANDS.contains(&cmd.name)
+                    && vim9::is_vim9script(&doc.text.text)
+                {
+                    "\n\n*Not available in Vim9 script*"
                 } else {
-                    // Show abbreviation format: :q[uit]
-                    format!(
-                        ":{}[{}]",
-                        cmd.min_name(),
-                        &cmd.name[cmd.min_abbrev as usize..]
-                    )
+                    ""
                 };
                 let contents = format!(
-                    "```vim\n{}\n```\n\n{}{}",
+                    "```vim\n{}\n```\n\n{}{}{}{}",
                     abbrev_display,
                     cmd.availability.label_suffix(),
-                    cmd.description
+                    cmd.description,
+                    vim9_note,
+                    self.help_link(&format!(":{}", cmd.name), None)
                 );
-                return Ok(Some(Hover {
-                    contents: HoverContents::Markup(MarkupContent {
-                        kind: MarkupKind::Markdown,
-                        value: contents,
-                    }),
-                    range: None,
-                }));
+                return Ok(Some(markdown_hover(contents, token_range)));
+            }
+        }
+
+        // Feature names in has('...')
+        if let Some((contents, range)) = self.has_feature_hover(
+            &doc.tree,
+            &doc.text.text,
+            position.line as usize,
+            position.character as usize,
+        ) {
+            return Ok(Some(markdown_hover(contents, Some(range))));
+        }
+
+        // Map arguments (<silent>, ...) and :command special arguments (<args>, ...)
+        if let Some((contents, range)) = self.angle_bracket_hover(
+            &docs,
+            &uri,
+            position.line as usize,
+            position.character as usize,
+        ) {
+            return Ok(Some(markdown_hover(contents, Some(range))));
+        }
+
+        // Autocmd event names
+        if let Some(node) = symbols::find_node_of_kind(
+            &doc.tree,
+            position.line as usize,
+            position.character as usize,
+            &["au_event"],
+        ) {
+            let name = node.utf8_text(doc.text.text.as_bytes()).unwrap_or("");
+            if let Some(event) = AutocmdEvent::find(name) {
+                let contents = format!(
+                    "```vim\nautocmd {}\n```\n\n{}{}\n\n`<amatch>`: {}{}{}",
+                    event.name,
+                    event.availability.label_suffix(),
+                    event.description,
+                    event.amatch(),
+                    self.since_note(event.since()),
+                    self.help_link(event.name, Some("autocmd.txt"))
+                );
+                return Ok(Some(markdown_hover(contents, Some(node_range(&node)))));
+            }
+        }
+
+        // Option names (`set tabstop=4`, `&shiftwidth`)
+        if let Some(node) = symbols::find_node_of_kind(
+            &doc.tree,
+            position.line as usize,
+            position.character as usize,
+            &["option_name"],
+        ) {
+            let name = node.utf8_text(doc.text.text.as_bytes()).unwrap_or("");
+            if let Some(option) = BuiltinOption::find(name) {
+                let short = option
+                    .short
+                    .map(|short| format!(" '{}'", short))
+                    .unwrap_or_default();
+                let contents = format!(
+                    "```vim
+'{}'{}
+```
+
+{}{}
+
+*{}*{}{}",
+                    option.name,
+                    short,
+                    option.availability.label_suffix(),
+                    option.description,
+                    option.summary(self.editor_mode()),
+                    self.since_note(option.since()),
+                    self.help_link(&format!("'{}'", option.name), Some("options.txt"))
+                );
+                return Ok(Some(markdown_hover(contents, Some(node_range(&node)))));
+            }
+        }
+
+        // `v:lua.require'mod'.func` and `luaeval("require'mod'...")` (Neovim)
+        if Availability::NeovimOnly.is_compatible(self.editor_mode()) {
+            let line = doc
+                .text
+                .text
+                .lines()
+                .nth(position.line as usize)
+                .unwrap_or("");
+            if let Some(reference) = lua::find_reference(line, position.character as usize) {
+                let lua_range = Range {
+                    start: Position {
+                        line: position.line,
+                        character: reference.range.0 as u32,
+                    },
+                    end: Position {
+                        line: position.line,
+                        character: reference.range.1 as u32,
+                    },
+                };
+                return Ok(Some(markdown_hover(
+                    self.lua_hover_contents(&reference),
+                    Some(lua_range),
+                )));
             }
         }
 
@@ -2068,48 +6049,102 @@ impl LanguageServer for Backend {
 
         // First, check if it's an autoload function
         if let Some(autoload) = &reference.autoload {
-            let contents = format!(
-                "```vim\n{}()\n```\n\n*autoload function*\n\nExpected file: `{}`",
-                autoload.full_name,
-                autoload.to_file_path()
+            let uri_str = uri.to_string();
+            let text = Arc::clone(&doc.text);
+            drop(docs);
+            let local = self
+                .get_symbols(&uri_str, &text.text)
+                .into_iter()
+                .find(|sym| sym.kind == SymbolKind::Function && sym.name == autoload.full_name);
+            let (definition, location) = match local {
+                Some(sym) => (Some(sym), None),
+                None => match self.workspace_function(&uri_str, &autoload.full_name) {
+                    Some((file_uri, sym)) => {
+                        let location = self.display_location(&file_uri, sym.start.0);
+                        (Some(sym), Some(location))
+                    }
+                    None => (
+                        self.runtime_autoload_functions()
+                            .iter()
+                            .find(|sym| sym.name == autoload.full_name)
+                            .cloned(),
+                        None,
+                    ),
+                },
+            };
+            let mut contents = format!(
+                "```vim\n{}\n```\n\n*autoload function*\n\n{}",
+                definition
+                    .as_ref()
+                    .and_then(|sym| sym.signature.clone())
+                    .unwrap_or_else(|| format!("{}()", autoload.full_name)),
+                match location {
+                    Some(location) => format!("Defined in `{}`", location),
+                    None => format!("Expected file: `{}`", autoload.to_file_path()),
+                }
             );
-            return Ok(Some(Hover {
-                contents: HoverContents::Markup(MarkupContent {
-                    kind: MarkupKind::Markdown,
-                    value: contents,
-                }),
-                range: None,
-            }));
+            if let Some(doc) = definition.and_then(|sym| sym.doc).or_else(|| {
+                self.function_help(&autoload.full_name)
+                    .map(|help| format!("```help\n{}\n```", help))
+            }) {
+                contents.push_str("\n\n");
+                contents.push_str(&doc);
+            }
+            return Ok(Some(markdown_hover(contents, token_range)));
         }
 
         // Then, check if it's a built-in function
         if reference.is_call {
-            if let Some(builtin) = BUILTIN_FUNCTIONS.iter().find(|f| f.name == reference.name) {
+            if let Some(builtin) = BuiltinFunction::find(&reference.name) {
                 let contents = format!(
-                    "```vim\n{}\n```\n\n{}",
-                    builtin.signature, builtin.description
+                    "```vim\n{}\n```\n\n{}{}{}",
+                    builtin.signature,
+                    builtin.description,
+                    self.since_note(builtin.since()),
+                    self.help_link(&format!("{}()", builtin.name), Some("builtin.txt"))
                 );
-                return Ok(Some(Hover {
-                    contents: HoverContents::Markup(MarkupContent {
-                        kind: MarkupKind::Markdown,
-                        value: contents,
-                    }),
-                    range: None,
-                }));
+                return Ok(Some(markdown_hover(contents, token_range)));
             }
         }
 
         // Then, check user-defined symbols
         let uri_str = uri.to_string();
-        let content = doc.text.text.clone();
+        let text = Arc::clone(&doc.text);
+        let assigned = symbols::find_assigned_value(
+            &doc.tree,
+            &doc.text.text,
+            &reference.name,
+            reference.scope,
+            position.line as usize,
+            position.character as usize,
+        );
         drop(docs); // Release lock before calling get_symbols
 
-        let symbols = self.get_symbols(&uri_str, &content);
-        let symbol = symbols.iter().find(|s| {
+        let symbols = self.get_symbols(&uri_str, &text.text);
+        let local = symbols.iter().find(|s| {
             s.name == reference.name
                 && (reference.scope == symbols::VimScope::Implicit || s.scope == reference.scope)
         });
 
+        // Global functions may be defined in another file of the workspace
+        let (symbol, location) = match local {
+            Some(symbol) => (Some(symbol.clone()), None),
+            None if matches!(
+                reference.scope,
+                symbols::VimScope::Implicit | symbols::VimScope::Global
+            ) =>
+            {
+                match self.workspace_function(&uri_str, &reference.name) {
+                    Some((file_uri, symbol)) => {
+                        let location = self.display_location(&file_uri, symbol.start.0);
+                        (Some(symbol), Some(location))
+                    }
+                    None => (None, None),
+                }
+            }
+            None => (None, None),
+        };
+
         if let Some(symbol) = symbol {
             let kind_str = match symbol.kind {
                 SymbolKind::Function => "function",
@@ -2117,7 +6152,7 @@ impl LanguageServer for Backend {
                 SymbolKind::Parameter => "parameter",
             };
 
-            let contents = if let Some(sig) = &symbol.signature {
+            let mut contents = if let Some(sig) = &symbol.signature {
                 format!("```vim\n{}\n```\n\n*{}*", sig, kind_str)
             } else {
                 format!(
@@ -2127,71 +6162,90 @@ impl LanguageServer for Backend {
                     kind_str
                 )
             };
+            if let Some(assigned) = assigned.filter(|_| symbol.kind == SymbolKind::Variable) {
+                contents.push_str(&format!(
+                    "\n\nValue: `{}` ({})",
+                    assigned.value, assigned.type_name
+                ));
+            }
+            if let Some(location) = location {
+                contents.push_str(&format!("\n\nDefined in `{}`", location));
+            }
+            let help = (symbol.doc.is_none()
+                && symbol.kind == SymbolKind::Function
+                && symbol.scope != symbols::VimScope::Script)
+                .then(|| self.function_help(&symbol.name))
+                .flatten()
+                .map(|help| format!("```help\n{}\n```", help));
+            if let Some(doc) = symbol.doc.as_ref().or(help.as_ref()) {
+                contents.push_str("\n\n");
+                contents.push_str(doc);
+            }
 
-            return Ok(Some(Hover {
-                contents: HoverContents::Markup(MarkupContent {
-                    kind: MarkupKind::Markdown,
-                    value: contents,
-                }),
-                range: None,
-            }));
+            return Ok(Some(markdown_hover(contents, token_range)));
         }
 
         Ok(None)
     }
 
     async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        self.documents_synced().await;
         let start_time = std::time::Instant::now();
 
         let uri = params.text_document_position.text_document.uri;
         let position = params.text_document_position.position;
         let include_declaration = params.context.include_declaration;
+        let partial_result_token = params.partial_result_params.partial_result_token;
 
-        let docs = self.documents.lock().unwrap();
-        let Some(doc) = docs.get(&uri) else {
-            return Ok(None);
-        };
-
-        // Find the identifier at the cursor position
-        let reference = find_identifier_at_position(
-            &doc.tree,
-            &doc.text.text,
-            position.line as usize,
-            position.character as usize,
-        );
-
-        let Some(reference) = reference else {
-            return Ok(None);
+        // Find the identifier at the cursor position, or a function name in a
+        // string or mapping, and its references in the current file (in a
+        // block so that the documents lock is released before yielding)
+        let (reference, current_file_locations) = {
+            let docs = self.documents.read().unwrap();
+            let Some(doc) = docs.get(&uri) else {
+                return Ok(None);
+            };
+            let reference = find_identifier_at_position(
+                &doc.tree,
+                &doc.text.text,
+                position.line as usize,
+                position.character as usize,
+            )
+            .or_else(|| {
+                symbols::find_function_name_reference(
+                    &doc.tree,
+                    &doc.text.text,
+                    position.line as usize,
+                    position.character as usize,
+                )
+            });
+            let Some(reference) = reference else {
+                return Ok(None);
+            };
+            let locations = find_references(
+                &doc.tree,
+                &doc.text.text,
+                &reference.name,
+                reference.scope,
+                include_declaration,
+            );
+            (reference, locations)
         };
 
-        // Find all references in the current file
-        let current_file_locations = find_references(
-            &doc.tree,
-            &doc.text.text,
-            &reference.name,
-            reference.scope,
-            include_declaration,
-        );
-
-        // Release the documents lock before searching other files
-        drop(docs);
-
         let mut result: Vec<Location> = current_file_locations
             .into_iter()
             .map(|loc| Location {
                 uri: uri.clone(),
-                range: Range {
-                    start: Position {
-                        line: loc.start.0 as u32,
-                        character: loc.start.1 as u32,
-                    },
-                    end: Position {
-                        line: loc.end.0 as u32,
-                        character: loc.end.1 as u32,
-                    },
-                },
+                range: source_location_range(&loc),
             })
             .collect();
+        let mut reported = 0;
+        if let Some(token) = &partial_result_token {
+            if !result.is_empty() {
+                self.send_partial_result(token, &result).await;
+                reported = result.len();
+            }
+        }
 
         // Search in other indexed files if:
         // 1. Indexing is complete
@@ -2201,50 +6255,48 @@ impl LanguageServer for Backend {
             || reference.scope == symbols::VimScope::Implicit && reference.name.contains('#');
 
         if is_cross_file_visible && self.indexing_complete.load(Ordering::SeqCst) {
-            let current_uri_str = uri.to_string();
-            let source_files = self.source_files.lock().unwrap();
-            let db = self.salsa_db.lock().unwrap();
+            let current_path = source_file_path(uri.as_str());
+            // Snapshot the file list so that no lock is held while yielding
+            let files: Vec<(String, SourceFile)> = self
+                .indexed_files()
+                .into_iter()
+                .filter(|(file_uri, _)| source_file_path(file_uri) != current_path)
+                .collect();
+            let shared = Arc::new(reference.clone());
 
-            for (file_uri, source_file) in source_files.iter() {
-                // Skip the current file (already searched)
-                if file_uri == &current_uri_str {
+            for (file_uri, source_file) in files {
+                cancellation_point().await;
+
+                let Some(target_uri) = Uri::from_file_path(source_file_path(&file_uri)) else {
                     continue;
-                }
+                };
+                let reference = Arc::clone(&shared);
+                let locations = self
+                    .blocking(move |backend| {
+                        let content =
+                            db::file_content(&*backend.salsa_db.lock().unwrap(), source_file);
+                        let tree = crate::analysis::parse(&content)?;
+                        Some(find_references(
+                            &tree,
+                            &content,
+                            &reference.name,
+                            reference.scope,
+                            include_declaration,
+                        ))
+                    })
+                    .await;
+                let Some(locations) = locations else {
+                    continue;
+                };
+                result.extend(locations.iter().map(|loc| Location {
+                    uri: target_uri.clone(),
+                    range: source_location_range(loc),
+                }));
 
-                let content = source_file.content(&*db);
-
-                // Parse the file to search for references
-                let mut parser = tree_sitter::Parser::new();
-                parser
-                    .set_language(&tree_sitter_vim::language())
-                    .expect("Error loading vim grammar");
-
-                if let Some(tree) = parser.parse(&content, None) {
-                    let locations = find_references(
-                        &tree,
-                        &content,
-                        &reference.name,
-                        reference.scope,
-                        include_declaration,
-                    );
-
-                    for loc in locations {
-                        // Convert file path to URI
-                        if let Some(file_uri) = Uri::from_file_path(file_uri) {
-                            result.push(Location {
-                                uri: file_uri,
-                                range: Range {
-                                    start: Position {
-                                        line: loc.start.0 as u32,
-                                        character: loc.start.1 as u32,
-                                    },
-                                    end: Position {
-                                        line: loc.end.0 as u32,
-                                        character: loc.end.1 as u32,
-                                    },
-                                },
-                            });
-                        }
+                if let Some(token) = &partial_result_token {
+                    if result.len() > reported {
+                        self.send_partial_result(token, &result[reported..]).await;
+                        reported = result.len();
                     }
                 }
             }
@@ -2261,28 +6313,70 @@ impl LanguageServer for Backend {
             return Ok(None);
         }
 
+        // All results have been reported as partial results; let the
+        // notifications be written before the (empty) final response
+        if partial_result_token.is_some() {
+            tokio::task::yield_now().await;
+            return Ok(Some(vec![]));
+        }
+
         Ok(Some(result))
     }
 
+    async fn linked_editing_range(
+        &self,
+        params: LinkedEditingRangeParams,
+    ) -> Result<Option<LinkedEditingRanges>> {
+        self.documents_synced().await;
+        let uri = params.text_document_position_params.text_document.uri;
+        let position = params.text_document_position_params.position;
+
+        let docs = self.documents.read().unwrap();
+        let Some(doc) = docs.get(&uri) else {
+            return Ok(None);
+        };
+        let Some(ranges) = Self::local_variable_ranges(&doc.tree, &doc.text.text, position) else {
+            return Ok(None);
+        };
+
+        log_debug!("linked_editing_range: found {} ranges", ranges.len());
+
+        Ok(Some(LinkedEditingRanges {
+            ranges,
+            // Typing anything else ends the linked editing
+            word_pattern: Some("[A-Za-z_][A-Za-z0-9_]*".to_string()),
+        }))
+    }
+
     async fn document_highlight(
         &self,
         params: DocumentHighlightParams,
     ) -> Result<Option<Vec<DocumentHighlight>>> {
+        self.documents_synced().await;
         let uri = params.text_document_position_params.text_document.uri;
         let position = params.text_document_position_params.position;
 
-        let docs = self.documents.lock().unwrap();
+        let docs = self.documents.read().unwrap();
         let Some(doc) = docs.get(&uri) else {
             return Ok(None);
         };
 
-        // Find the identifier at the cursor position
+        // Find the identifier at the cursor position, or a function name in a
+        // string or mapping
         let reference = find_identifier_at_position(
             &doc.tree,
             &doc.text.text,
             position.line as usize,
             position.character as usize,
-        );
+        )
+        .or_else(|| {
+            symbols::find_function_name_reference(
+                &doc.tree,
+                &doc.text.text,
+                position.line as usize,
+                position.character as usize,
+            )
+        });
 
         let Some(reference) = reference else {
             return Ok(None);
@@ -2327,15 +6421,35 @@ impl LanguageServer for Backend {
     }
 
     async fn folding_range(&self, params: FoldingRangeParams) -> Result<Option<Vec<FoldingRange>>> {
+        self.documents_synced().await;
         let uri = params.text_document.uri;
 
-        let docs = self.documents.lock().unwrap();
+        let docs = self.documents.read().unwrap();
         let Some(doc) = docs.get(&uri) else {
             return Ok(None);
         };
 
         let mut ranges = Vec::new();
         Self::collect_folding_ranges(&doc.tree.root_node(), &mut ranges);
+        ranges.extend(Self::collect_marker_folding_ranges(&doc.text.text));
+        // Heredocs of embedded code, including the ones masked out of the tree
+        ranges.extend(
+            heredoc::find_heredocs(&doc.text.text)
+                .into_iter()
+                .filter(|embedded| embedded.end_line > embedded.start_line)
+                .map(|embedded| FoldingRange {
+                    start_line: embedded.start_line as u32,
+                    start_character: None,
+                    end_line: embedded.end_line as u32,
+                    end_character: None,
+                    kind: Some(FoldingRangeKind::Region),
+                    collapsed_text: None,
+                }),
+        );
+
+        // Clients keep one fold per start line: the outermost one
+        ranges.sort_by_key(|range| (range.start_line, std::cmp::Reverse(range.end_line)));
+        ranges.dedup_by_key(|range| range.start_line);
 
         log_debug!("folding_range: found {} foldable regions", ranges.len());
 
@@ -2350,10 +6464,11 @@ impl LanguageServer for Backend {
         &self,
         params: DocumentSymbolParams,
     ) -> Result<Option<DocumentSymbolResponse>> {
+        self.documents_synced().await;
         let uri = params.text_document.uri;
 
         let (uri_str, content) = {
-            let docs = self.documents.lock().unwrap();
+            let docs = self.documents.read().unwrap();
             let Some(doc) = docs.get(&uri) else {
                 return Ok(None);
             };
@@ -2364,7 +6479,7 @@ impl LanguageServer for Backend {
 
         // Convert our symbols to LSP DocumentSymbol
         // Note: We use ls_types::SymbolKind to avoid conflict with our symbols::SymbolKind
-        let lsp_symbols: Vec<DocumentSymbol> = symbols
+        let mut lsp_symbols: Vec<DocumentSymbol> = symbols
             .into_iter()
             .map(|s| {
                 let kind = match s.kind {
@@ -2400,6 +6515,78 @@ impl LanguageServer for Backend {
             })
             .collect();
 
+        // Vim9 classes with their methods and member variables as children
+        let to_range = |line: usize, start: usize, end: usize| Range {
+            start: Position {
+                line: line as u32,
+                character: start as u32,
+            },
+            end: Position {
+                line: line as u32,
+                character: end as u32,
+            },
+        };
+        for class in vim9::parse_classes(&content) {
+            let children = class
+                .members
+                .iter()
+                .map(|m| {
+                    let kind = match m.kind {
+                        vim9::ClassMemberKind::Method => {
+                            tower_lsp_server::ls_types::SymbolKind::METHOD
+                        }
+                        vim9::ClassMemberKind::Constructor => {
+                            tower_lsp_server::ls_types::SymbolKind::CONSTRUCTOR
+                        }
+                        vim9::ClassMemberKind::Variable => {
+                            tower_lsp_server::ls_types::SymbolKind::FIELD
+                        }
+                    };
+                    let range = to_range(m.line, m.start_col, m.end_col);
+                    #[allow(deprecated)]
+                    DocumentSymbol {
+                        name: m.name.clone(),
+                        detail: Some(m.signature.clone()),
+                        kind,
+                        tags: None,
+                        deprecated: None,
+                        range,
+                        selection_range: range,
+                        children: None,
+                    }
+                })
+                .collect();
+
+            let kind = if class.is_interface {
+                tower_lsp_server::ls_types::SymbolKind::INTERFACE
+            } else {
+                tower_lsp_server::ls_types::SymbolKind::CLASS
+            };
+            let selection_range = to_range(class.line, class.start_col, class.end_col);
+            let range = Range {
+                start: Position {
+                    line: class.line as u32,
+                    character: 0,
+                },
+                end: Position {
+                    line: class.end_line as u32,
+                    character: 0,
+                },
+            };
+            #[allow(deprecated)]
+            lsp_symbols.push(DocumentSymbol {
+                name: class.name,
+                detail: class.extends.map(|parent| format!("extends {}", parent)),
+                kind,
+                tags: None,
+                deprecated: None,
+                range,
+                selection_range,
+                children: Some(children),
+            });
+        }
+        lsp_symbols.sort_by_key(|s| (s.range.start.line, s.range.start.character));
+
         Ok(Some(DocumentSymbolResponse::Nested(lsp_symbols)))
     }
 
@@ -2414,62 +6601,80 @@ impl LanguageServer for Backend {
         }
 
         let query = params.query.to_lowercase();
-        let mut results: Vec<SymbolInformation> = Vec::new();
+        let partial_result_token = params.partial_result_params.partial_result_token;
+        // Clients resolving symbols get them without ranges first
+        let resolve = self
+            .workspace_symbol_resolve_support
+            .load(Ordering::Relaxed);
+        let mut flat: Vec<SymbolInformation> = Vec::new();
+        let mut nested: Vec<WorkspaceSymbol> = Vec::new();
+        let mut count = 0;
 
         // Limit results to avoid overwhelming the client
         const MAX_RESULTS: usize = 500;
 
-        let source_files = self.source_files.lock().unwrap();
-        let db = self.salsa_db.lock().unwrap();
-
-        for (file_uri, source_file) in source_files.iter() {
-            if results.len() >= MAX_RESULTS {
+        for (file_key, source_file) in self.indexed_files() {
+            if count >= MAX_RESULTS {
                 break;
             }
+            cancellation_point().await;
 
-            let symbols = db::parse_symbols(&*db, *source_file);
-
-            for s in symbols {
-                // Filter by query (case-insensitive partial match)
-                // Empty query returns all symbols
-                if !query.is_empty() && !s.full_name().to_lowercase().contains(&query) {
-                    continue;
+            let symbols = self
+                .blocking(move |backend| {
+                    db::parse_symbols(&*backend.salsa_db.lock().unwrap(), source_file)
+                })
+                .await;
+            let Some(uri) = Uri::from_file_path(source_file_path(&file_key)) else {
+                continue;
+            };
+            // Filter by query (case-insensitive partial match)
+            // Empty query returns all symbols
+            let matched: Vec<symbols::Symbol> = symbols
+                .into_iter()
+                .filter(|s| query.is_empty() || s.full_name().to_lowercase().contains(&query))
+                .take(MAX_RESULTS - count)
+                .collect();
+            if matched.is_empty() {
+                continue;
+            }
+            count += matched.len();
+
+            if resolve {
+                let chunk: Vec<WorkspaceSymbol> = matched
+                    .iter()
+                    .map(|s| WorkspaceSymbol {
+                        name: s.full_name(),
+                        kind: lsp_symbol_kind(s.kind),
+                        tags: None,
+                        container_name: None,
+                        location: OneOf::Right(WorkspaceLocation { uri: uri.clone() }),
+                        // Tells apart symbols of the same name in the file
+                        data: serde_json::to_value(symbol_range(s).start).ok(),
+                    })
+                    .collect();
+                match &partial_result_token {
+                    Some(token) => self.send_partial_result(token, chunk).await,
+                    None => nested.extend(chunk),
                 }
-
-                let kind = match s.kind {
-                    SymbolKind::Function => tower_lsp_server::ls_types::SymbolKind::FUNCTION,
-                    SymbolKind::Variable => tower_lsp_server::ls_types::SymbolKind::VARIABLE,
-                    SymbolKind::Parameter => tower_lsp_server::ls_types::SymbolKind::VARIABLE,
-                };
-
-                let range = Range {
-                    start: Position {
-                        line: s.start.0 as u32,
-                        character: s.start.1 as u32,
-                    },
-                    end: Position {
-                        line: s.end.0 as u32,
-                        character: s.end.1 as u32,
-                    },
-                };
-
-                // Convert file path to URI
-                let Some(uri) = Uri::from_file_path(file_uri) else {
-                    continue;
-                };
-
+            } else {
                 #[allow(deprecated)]
-                results.push(SymbolInformation {
-                    name: s.full_name(),
-                    kind,
-                    tags: None,
-                    deprecated: None,
-                    location: Location { uri, range },
-                    container_name: s.signature,
-                });
-
-                if results.len() >= MAX_RESULTS {
-                    break;
+                let chunk: Vec<SymbolInformation> = matched
+                    .into_iter()
+                    .map(|s| SymbolInformation {
+                        name: s.full_name(),
+                        kind: lsp_symbol_kind(s.kind),
+                        tags: None,
+                        deprecated: None,
+                        location: Location {
+                            uri: uri.clone(),
+                            range: symbol_range(&s),
+                        },
+                        container_name: s.signature,
+                    })
+                    .collect();
+                match &partial_result_token {
+                    Some(token) => self.send_partial_result(token, chunk).await,
+                    None => flat.extend(chunk),
                 }
             }
         }
@@ -2477,20 +6682,59 @@ impl LanguageServer for Backend {
         log_debug!(
             "workspace_symbol: query='{}', found {} symbols",
             params.query,
-            results.len()
+            count
         );
 
-        Ok(Some(WorkspaceSymbolResponse::Flat(results)))
+        if resolve {
+            Ok(Some(WorkspaceSymbolResponse::Nested(nested)))
+        } else {
+            Ok(Some(WorkspaceSymbolResponse::Flat(flat)))
+        }
+    }
+
+    async fn symbol_resolve(&self, mut symbol: WorkspaceSymbol) -> Result<WorkspaceSymbol> {
+        let OneOf::Right(WorkspaceLocation { uri }) = &symbol.location else {
+            return Ok(symbol);
+        };
+        let Some(path) = uri.to_file_path().map(|path| path.into_owned()) else {
+            return Ok(symbol);
+        };
+        let start: Option<Position> = symbol
+            .data
+            .clone()
+            .and_then(|data| serde_json::from_value(data).ok());
+        let resolved = self
+            .indexed_files()
+            .into_iter()
+            .find(|(file_key, _)| source_file_path(file_key) == path)
+            .and_then(|(_, source_file)| {
+                db::parse_symbols(&*self.salsa_db.lock().unwrap(), source_file)
+                    .into_iter()
+                    .find(|s| {
+                        s.full_name() == symbol.name
+                            && lsp_symbol_kind(s.kind) == symbol.kind
+                            && start.is_none_or(|start| symbol_range(s).start == start)
+                    })
+            });
+        if let Some(s) = resolved {
+            symbol.location = OneOf::Left(Location {
+                uri: uri.clone(),
+                range: symbol_range(&s),
+            });
+            symbol.container_name = s.signature;
+        }
+        Ok(symbol)
     }
 
     async fn prepare_rename(
         &self,
         params: TextDocumentPositionParams,
     ) -> Result<Option<PrepareRenameResponse>> {
+        self.documents_synced().await;
         let uri = params.text_document.uri;
         let position = params.position;
 
-        let docs = self.documents.lock().unwrap();
+        let docs = self.documents.read().unwrap();
         let Some(doc) = docs.get(&uri) else {
             return Ok(None);
         };
@@ -2508,7 +6752,7 @@ impl LanguageServer for Backend {
         };
 
         // Don't allow renaming built-in functions
-        if reference.is_call && BUILTIN_FUNCTIONS.iter().any(|f| f.name == reference.name) {
+        if reference.is_call && BuiltinFunction::find(&reference.name).is_some() {
             return Ok(None);
         }
 
@@ -2532,60 +6776,48 @@ impl LanguageServer for Backend {
     }
 
     async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        self.documents_synced().await;
         let uri = params.text_document_position.text_document.uri;
         let position = params.text_document_position.position;
         let new_name = params.new_name;
 
-        let docs = self.documents.lock().unwrap();
-        let Some(doc) = docs.get(&uri) else {
-            return Ok(None);
-        };
+        // Function names written as text (e.g., `function('Name')`) can be
+        // excluded in the configuration
+        let string_references = self.config.lock().unwrap().rename.string_references;
 
-        // Find the identifier at the cursor position
-        let reference = find_identifier_at_position(
-            &doc.tree,
-            &doc.text.text,
-            position.line as usize,
-            position.character as usize,
-        );
+        // Find the identifier at the cursor position and the edits for the
+        // current file (in a block so that the documents lock is released
+        // before searching other files)
+        let (reference, current_edits) = {
+            let docs = self.documents.read().unwrap();
+            let Some(doc) = docs.get(&uri) else {
+                return Ok(None);
+            };
+            let reference = find_identifier_at_position(
+                &doc.tree,
+                &doc.text.text,
+                position.line as usize,
+                position.character as usize,
+            );
+            let Some(reference) = reference else {
+                return Ok(None);
+            };
 
-        let Some(reference) = reference else {
-            return Ok(None);
+            // Find all references in the current file
+            let current_edits: Vec<TextEdit> =
+                rename_locations(&doc.tree, &doc.text.text, &reference, string_references)
+                    .iter()
+                    .map(|loc| TextEdit {
+                        range: source_location_range(loc),
+                        new_text: renamed_text(&doc.text.text, loc, &new_name),
+                    })
+                    .collect();
+            (reference, current_edits)
         };
 
-        // Find all references in the current file
-        let current_file_locations = find_references(
-            &doc.tree,
-            &doc.text.text,
-            &reference.name,
-            reference.scope,
-            true, // include declaration
-        );
-
-        // Release the documents lock before searching other files
-        drop(docs);
-
         // Collect all edits grouped by file
         let mut changes: HashMap<Uri, Vec<TextEdit>> = HashMap::new();
 
-        // Add edits for current file
-        let current_edits: Vec<TextEdit> = current_file_locations
-            .into_iter()
-            .map(|loc| TextEdit {
-                range: Range {
-                    start: Position {
-                        line: loc.start.0 as u32,
-                        character: loc.start.1 as u32,
-                    },
-                    end: Position {
-                        line: loc.end.0 as u32,
-                        character: loc.end.1 as u32,
-                    },
-                },
-                new_text: new_name.clone(),
-            })
-            .collect();
-
         if !current_edits.is_empty() {
             changes.insert(uri.clone(), current_edits);
         }
@@ -2596,73 +6828,105 @@ impl LanguageServer for Backend {
             || reference.scope == symbols::VimScope::Implicit && reference.name.contains('#');
 
         if is_cross_file_visible && self.indexing_complete.load(Ordering::SeqCst) {
-            let current_uri_str = uri.to_string();
-            let source_files = self.source_files.lock().unwrap();
-            let db = self.salsa_db.lock().unwrap();
-
-            for (file_uri, source_file) in source_files.iter() {
-                // Skip the current file (already processed)
-                if file_uri == &current_uri_str {
+            let current_path = source_file_path(uri.as_str());
+            let target = Arc::new((reference.clone(), new_name.clone()));
+
+            // Open documents are edited from their buffer, not from disk
+            for (file_uri, source_file) in self.indexed_files() {
+                // Skip the current file (already processed), which may also
+                // be indexed by path
+                if source_file_path(&file_uri) == current_path {
                     continue;
                 }
+                cancellation_point().await;
 
-                let content = source_file.content(&*db);
-
-                // Parse the file to search for references
-                let mut parser = tree_sitter::Parser::new();
-                parser
-                    .set_language(&tree_sitter_vim::language())
-                    .expect("Error loading vim grammar");
-
-                if let Some(tree) = parser.parse(&content, None) {
-                    let locations = find_references(
-                        &tree,
-                        &content,
-                        &reference.name,
-                        reference.scope,
-                        true, // include declaration
-                    );
-
-                    if !locations.is_empty() {
-                        if let Some(file_uri_parsed) = Uri::from_file_path(file_uri) {
-                            let edits: Vec<TextEdit> = locations
-                                .into_iter()
+                let Some(file_uri_parsed) = Uri::from_file_path(source_file_path(&file_uri)) else {
+                    continue;
+                };
+                let target = Arc::clone(&target);
+                let edits = self
+                    .blocking(move |backend| {
+                        let (reference, new_name) = &*target;
+                        let content =
+                            db::file_content(&*backend.salsa_db.lock().unwrap(), source_file);
+                        let tree = crate::analysis::parse(&content)?;
+                        let edits: Vec<TextEdit> =
+                            rename_locations(&tree, &content, reference, string_references)
+                                .iter()
                                 .map(|loc| TextEdit {
-                                    range: Range {
-                                        start: Position {
-                                            line: loc.start.0 as u32,
-                                            character: loc.start.1 as u32,
-                                        },
-                                        end: Position {
-                                            line: loc.end.0 as u32,
-                                            character: loc.end.1 as u32,
-                                        },
-                                    },
-                                    new_text: new_name.clone(),
+                                    range: source_location_range(loc),
+                                    new_text: renamed_text(&content, loc, new_name),
                                 })
                                 .collect();
-
-                            changes.insert(file_uri_parsed, edits);
-                        }
-                    }
+                        Some(edits)
+                    })
+                    .await;
+                if let Some(edits) = edits.filter(|edits| !edits.is_empty()) {
+                    changes.insert(file_uri_parsed, edits);
                 }
             }
         }
 
+        // Renaming an autoload function into another namespace moves it
+        // to the matching autoload file
+        let file_operations = match &reference.autoload {
+            Some(autoload_ref) => {
+                self.autoload_file_operations(autoload_ref, &new_name, &uri, &mut changes)
+            }
+            None => vec![],
+        };
+
         log_debug!(
-            "rename: '{}' -> '{}', {} files affected",
+            "rename: '{}' -> '{}', {} files affected, {} file operations",
             reference.name,
             new_name,
-            changes.len()
+            changes.len(),
+            file_operations.len()
         );
 
-        if changes.is_empty() {
+        if changes.is_empty() && file_operations.is_empty() {
             return Ok(None);
         }
 
+        // File operations are only computed for clients supporting them
+        if !self.document_changes_support.load(Ordering::Relaxed) {
+            return Ok(Some(WorkspaceEdit {
+                changes: Some(changes),
+                document_changes: None,
+                change_annotations: None,
+            }));
+        }
+
+        // Edits of open documents carry their version, so that clients can
+        // reject them if the buffer changed in the meantime
+        let edits: Vec<TextDocumentEdit> = {
+            let docs = self.documents.read().unwrap();
+            changes
+                .into_iter()
+                .map(|(uri, edits)| TextDocumentEdit {
+                    text_document: OptionalVersionedTextDocumentIdentifier {
+                        version: docs.get(&uri).map(|doc| doc.version),
+                        uri,
+                    },
+                    edits: edits.into_iter().map(OneOf::Left).collect(),
+                })
+                .collect()
+        };
+        let document_changes = if file_operations.is_empty() {
+            DocumentChanges::Edits(edits)
+        } else {
+            // Text edits go first so that they apply to files before they are moved
+            DocumentChanges::Operations(
+                edits
+                    .into_iter()
+                    .map(DocumentChangeOperation::Edit)
+                    .chain(file_operations)
+                    .collect(),
+            )
+        };
         Ok(Some(WorkspaceEdit {
-            changes: Some(changes),
-            document_changes: None,
+            changes: None,
+            document_changes: Some(document_changes),
             change_annotations: None,
         }))
     }
@@ -2671,9 +6935,10 @@ impl LanguageServer for Backend {
         &self,
         params: SelectionRangeParams,
     ) -> Result<Option<Vec<SelectionRange>>> {
+        self.documents_synced().await;
         let uri = params.text_document.uri;
 
-        let docs = self.documents.lock().unwrap();
+        let docs = self.documents.read().unwrap();
         let Some(doc) = docs.get(&uri) else {
             return Ok(None);
         };
@@ -2698,222 +6963,45 @@ impl LanguageServer for Backend {
     }
 
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
+        self.documents_synced().await;
         let uri = params.text_document.uri;
 
-        let docs = self.documents.lock().unwrap();
+        let docs = self.documents.read().unwrap();
         let Some(doc) = docs.get(&uri) else {
             return Ok(None);
         };
         let source = doc.text.to_string();
-        drop(docs);
-
-        let mut actions = Vec::new();
-
-        for diag in params.context.diagnostics {
-            // Get the diagnostic code
-            let code = match &diag.code {
-                Some(NumberOrString::String(s)) => s.as_str(),
-                _ => continue,
-            };
-
-            // Get the text at the diagnostic range
-            let start_line = diag.range.start.line as usize;
-            let end_line = diag.range.end.line as usize;
-            let lines: Vec<&str> = source.lines().collect();
-
-            if start_line >= lines.len() {
-                continue;
-            }
-
-            let line = lines.get(start_line).unwrap_or(&"");
-            let start_col = diag.range.start.character as usize;
-            let end_col = if start_line == end_line {
-                diag.range.end.character as usize
-            } else {
-                line.len()
-            };
+        drop(docs);
 
-            // Create text edit based on the rule
-            // Returns: Option<(title, range, new_text)>
-            let edit: Option<(&str, Range, String)> = match code {
-                "hjkls/double_dot" => {
-                    if end_col <= line.len() {
-                        let text = &line[start_col..end_col];
-                        let new_text = Self::replace_single_dot_with_double(text);
-                        if new_text != text {
-                            Some(("Use `..` for string concatenation", diag.range, new_text))
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                }
-                "hjkls/single_quote" => {
-                    if end_col <= line.len() {
-                        let text = &line[start_col..end_col];
-                        if text.starts_with('"') && text.ends_with('"') && text.len() >= 2 {
-                            let inner = &text[1..text.len() - 1];
-                            Some(("Use single quotes", diag.range, format!("'{}'", inner)))
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                }
-                "hjkls/key_notation" => {
-                    if end_col <= line.len() {
-                        let text = &line[start_col..end_col];
-                        diagnostics::style::normalize_key_notation(text)
-                            .map(|normalized| ("Normalize key notation", diag.range, normalized))
-                    } else {
-                        None
-                    }
-                }
-                "hjkls/normal_bang" => line.get(start_col..).and_then(|text_after| {
-                    text_after.to_lowercase().find("normal").and_then(|pos| {
-                        let normal_start = start_col + pos;
-                        let normal_end = normal_start + 6;
-                        let original = line.get(normal_start..normal_end)?;
-                        let after = line.get(normal_end..).unwrap_or("");
-
-                        if !after.starts_with('!') {
-                            Some((
-                                "Use `normal!` to ignore user mappings",
-                                Range {
-                                    start: Position {
-                                        line: diag.range.start.line,
-                                        character: normal_start as u32,
-                                    },
-                                    end: Position {
-                                        line: diag.range.start.line,
-                                        character: normal_end as u32,
-                                    },
-                                },
-                                format!("{}!", original),
-                            ))
-                        } else {
-                            None
-                        }
-                    })
-                }),
-                "hjkls/function_bang" => line.get(start_col..).and_then(|text_after| {
-                    text_after.to_lowercase().find("function!").map(|pos| {
-                        let func_start = start_col + pos;
-                        let func_end = func_start + 9;
-                        let original = line.get(func_start..func_end).unwrap_or("function!");
+        let mut actions = Vec::new();
 
-                        (
-                            "Remove unnecessary `!` from s: function",
-                            Range {
-                                start: Position {
-                                    line: diag.range.start.line,
-                                    character: func_start as u32,
-                                },
-                                end: Position {
-                                    line: diag.range.start.line,
-                                    character: func_end as u32,
-                                },
-                            },
-                            original.get(..8).unwrap_or("function").to_string(),
-                        )
-                    })
-                }),
-                "hjkls/match_case" => {
-                    if end_col <= line.len() {
-                        let text = &line[start_col..end_col];
-                        text.find("=~").and_then(|pos| {
-                            let after = text.get(pos + 2..).unwrap_or("");
-                            if !after.starts_with('#') && !after.starts_with('?') {
-                                let op_start = start_col + pos;
-                                let op_end = op_start + 2;
-                                Some((
-                                    "Use `=~#` for case-sensitive match",
-                                    Range {
-                                        start: Position {
-                                            line: diag.range.start.line,
-                                            character: op_start as u32,
-                                        },
-                                        end: Position {
-                                            line: diag.range.start.line,
-                                            character: op_end as u32,
-                                        },
-                                    },
-                                    "=~#".to_string(),
-                                ))
-                            } else {
-                                None
-                            }
-                        })
-                    } else {
-                        None
-                    }
-                }
-                "hjkls/abort" => {
-                    // Add `abort` attribute to function definition
-                    // The diagnostic range covers the first line of the function
-                    // Insert ` abort` at the end of the line (before newline)
-                    let line_end = line.len();
-                    Some((
-                        "Add `abort` attribute",
-                        Range {
-                            start: Position {
-                                line: diag.range.start.line,
-                                character: line_end as u32,
-                            },
-                            end: Position {
-                                line: diag.range.start.line,
-                                character: line_end as u32,
-                            },
-                        },
-                        " abort".to_string(),
-                    ))
-                }
-                "hjkls/plug_noremap" => {
-                    // Replace map command with noremap equivalent
-                    // The diagnostic range covers just the map command (e.g., "nmap")
-                    if end_col <= line.len() {
-                        let cmd = &line[start_col..end_col];
-                        diagnostics::style::get_noremap_equivalent(cmd).map(|noremap_cmd| {
-                            (
-                                "Use noremap for <Plug> mapping",
-                                diag.range,
-                                noremap_cmd.to_string(),
-                            )
-                        })
-                    } else {
-                        None
-                    }
-                }
-                // Other rules don't have simple auto-fixes
-                _ => None,
+        for diag in params.context.diagnostics {
+            // Create the code action if the rule has a quick fix
+            let Some((title, range, new_text)) = Self::quick_fix(&source, &diag) else {
+                continue;
             };
 
-            // Create the code action if we have an edit
-            if let Some((title, range, new_text)) = edit {
-                let text_edit = TextEdit { range, new_text };
+            let text_edit = TextEdit { range, new_text };
 
-                let mut changes = HashMap::new();
-                changes.insert(uri.clone(), vec![text_edit]);
+            let mut changes = HashMap::new();
+            changes.insert(uri.clone(), vec![text_edit]);
 
-                let workspace_edit = WorkspaceEdit {
-                    changes: Some(changes),
-                    document_changes: None,
-                    change_annotations: None,
-                };
+            let workspace_edit = WorkspaceEdit {
+                changes: Some(changes),
+                document_changes: None,
+                change_annotations: None,
+            };
 
-                actions.push(CodeActionOrCommand::CodeAction(CodeAction {
-                    title: title.to_string(),
-                    kind: Some(CodeActionKind::QUICKFIX),
-                    diagnostics: Some(vec![diag.clone()]),
-                    edit: Some(workspace_edit),
-                    command: None,
-                    is_preferred: Some(true),
-                    disabled: None,
-                    data: None,
-                }));
-            }
+            actions.push(CodeActionOrCommand::CodeAction(CodeAction {
+                title,
+                kind: Some(CodeActionKind::QUICKFIX),
+                diagnostics: Some(vec![diag.clone()]),
+                edit: Some(workspace_edit),
+                command: None,
+                is_preferred: Some(true),
+                disabled: None,
+                data: None,
+            }));
         }
 
         if actions.is_empty() {
@@ -2924,10 +7012,11 @@ impl LanguageServer for Backend {
     }
 
     async fn formatting(&self, params: DocumentFormattingParams) -> Result<Option<Vec<TextEdit>>> {
+        self.documents_synced().await;
         let uri = params.text_document.uri;
 
         let (source, tree) = {
-            let docs = self.documents.lock().unwrap();
+            let docs = self.documents.read().unwrap();
             let Some(doc) = docs.get(&uri) else {
                 return Ok(None);
             };
@@ -2935,10 +7024,12 @@ impl LanguageServer for Backend {
         };
 
         // Clone format config to minimize lock hold time
-        let format_config = {
+        let mut format_config = {
             let config = self.config.lock().unwrap();
             config.format.clone()
         };
+        // The modeline of the file takes priority, as in Vim
+        Modeline::parse(&source).apply_format(&mut format_config);
         let edits = crate::formatter::format(&source, &tree, &format_config);
 
         if edits.is_empty() {
@@ -2953,6 +7044,41 @@ impl LanguageServer for Backend {
 mod tests {
     use super::*;
 
+    /// Create a workspace directory with files, replacing any previous one
+    fn temp_workspace(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("hjkls-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+        for (path, content) in files {
+            let path = root.join(path);
+            std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+            std::fs::write(path, content).unwrap();
+        }
+        root
+    }
+
+    /// Create a backend without a client, with `root` indexed
+    fn test_backend(root: &Path) -> Backend {
+        let (service, _) = Backend::service(EditorMode::default(), None, Vec::new(), None);
+        let backend = service.inner().clone();
+        backend.index_workspace(root.to_path_buf());
+        backend
+    }
+
+    fn file_uri(root: &Path, path: &str) -> Uri {
+        Uri::from_file_path(root.join(path)).unwrap()
+    }
+
+    fn rename_params(uri: Uri, line: u32, character: u32, new_name: &str) -> RenameParams {
+        RenameParams {
+            text_document_position: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                position: Position { line, character },
+            },
+            new_name: new_name.to_string(),
+            work_done_progress_params: Default::default(),
+        }
+    }
+
     #[test]
     fn test_replace_single_dot_with_double() {
         // Basic replacement
@@ -3040,6 +7166,22 @@ nnoremap <leader>b <CMD>echo 'upper'<CR>";
         );
     }
 
+    #[test]
+    fn test_v_lua_require_no_false_positive() {
+        let src = "\
+function! F() abort
+  call v:lua.require'foo'.setup({})
+  let x = v:lua.require\"foo.bar\".get(1, 2)
+  return x
+endfunction";
+        let diags = collect_diagnostics(src);
+        assert!(
+            diags.is_empty(),
+            "Expected no diagnostics for `v:lua.require'mod'` calls, got: {:?}",
+            diags
+        );
+    }
+
     #[test]
     fn test_real_syntax_errors_still_detected() {
         // Genuine syntax errors must still be reported
@@ -3049,4 +7191,600 @@ nnoremap <leader>b <CMD>echo 'upper'<CR>";
             "Expected syntax error for incomplete `if` statement"
         );
     }
+
+    #[test]
+    fn test_scope_violation_related_information() {
+        let source =
+            "function! F(x) abort\n  return 1\nendfunction\nfor i in [1]\n  echo l:y\nendfor\n";
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&tree_sitter_vim::language()).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        let uri: Uri = "file:///test.vim".parse().unwrap();
+        let diagnostics = Backend::collect_scope_violations(&tree, source, Some(&uri));
+        assert_eq!(diagnostics.len(), 1);
+        let related = diagnostics[0].related_information.as_ref().unwrap();
+        let context: Vec<(u32, &str)> = related
+            .iter()
+            .map(|r| (r.location.range.start.line, r.message.as_str()))
+            .collect();
+        assert_eq!(
+            context,
+            vec![
+                (3, "The enclosing `for` block is outside of any function"),
+                (2, "The function `F()` before it ends here"),
+            ]
+        );
+
+        // No context at script level before any function
+        let source = "echo l:y\n";
+        let tree = parser.parse(source, None).unwrap();
+        let diagnostics = Backend::collect_scope_violations(&tree, source, Some(&uri));
+        assert_eq!(diagnostics[0].related_information, None);
+    }
+
+    #[test]
+    fn test_marker_folding_ranges() {
+        let folds = |source: &str| -> Vec<(u32, u32)> {
+            Backend::collect_marker_folding_ranges(source)
+                .iter()
+                .map(|range| (range.start_line, range.end_line))
+                .collect()
+        };
+        // Nested markers without levels
+        assert_eq!(
+            folds("\" a {{{\n\" b {{{\nset nu\n\" }}}\n\" }}}\n"),
+            vec![(1, 3), (0, 4)]
+        );
+        // A marker with a level ends the previous fold of that level on the
+        // line before; the last fold runs to the end of the file
+        assert_eq!(
+            folds("\" a {{{1\nset nu\n\" b {{{2\nset et\n\" c {{{1\nset sw=2\n"),
+            vec![(2, 3), (0, 3), (4, 5)]
+        );
+        // An end marker with a level also ends the deeper folds
+        assert_eq!(
+            folds("\" a {{{1\n\" b {{{2\nset nu\n\" }}}1\nset et\n"),
+            vec![(1, 3), (0, 3)]
+        );
+        // 'foldmarker' from the modeline; unmatched end markers are ignored
+        assert_eq!(
+            folds("}}}\n\" a <<<\nset nu\n\" >>>\n\" vim: set fmr=<<<,>>>:\n"),
+            vec![(1, 3)]
+        );
+    }
+
+    #[test]
+    fn test_statement_folding_ranges() {
+        let source = "let s:lines =<< trim END
+  one
+  two
+END
+call Foo(1,
+      \\ 2)
+let s:opts = {
+      \\ 'a': [
+      \\   1,
+      \\ ],
+      \\ }
+let s:one = 1
+";
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&tree_sitter_vim::language()).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        let mut ranges = Vec::new();
+        Backend::collect_folding_ranges(&tree.root_node(), &mut ranges);
+        let folds: Vec<(u32, u32)> = ranges
+            .iter()
+            .map(|range| (range.start_line, range.end_line))
+            .collect();
+        // Heredoc, continued call, dictionary statement and the nested list
+        for fold in [(0, 3), (4, 5), (6, 10), (7, 9)] {
+            assert!(folds.contains(&fold), "missing {fold:?} in {folds:?}");
+        }
+        // Single-line statements don't fold
+        assert!(folds.iter().all(|&(start, _)| start != 11));
+    }
+
+    #[test]
+    fn test_local_variable_ranges() {
+        let source = "function! F(a) abort
+  let x = a:a
+  let l:x += d.x + x
+  call x(1)
+  return {-> x}
+endfunction
+let x = 1
+";
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&tree_sitter_vim::language()).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        let ranges = |line, character| {
+            Backend::local_variable_ranges(&tree, source, Position::new(line, character)).map(
+                |ranges| {
+                    ranges
+                        .iter()
+                        .map(|range| (range.start.line, range.start.character, range.end.character))
+                        .collect::<Vec<_>>()
+                },
+            )
+        };
+        // `x` and `l:x` in the function, but not the field, the called
+        // function nor the script-level `x`
+        let expected = vec![(1, 6, 7), (2, 8, 9), (2, 19, 20), (4, 13, 14)];
+        assert_eq!(ranges(1, 6), Some(expected.clone()));
+        assert_eq!(ranges(2, 8), Some(expected));
+        // Fields, parameters and script-level variables aren't linked
+        assert_eq!(ranges(2, 15), None);
+        assert_eq!(ranges(1, 12), None);
+        assert_eq!(ranges(6, 4), None);
+    }
+
+    #[test]
+    fn test_indexing_status_json() {
+        let status = IndexingStatus {
+            complete: true,
+            files_discovered: 3,
+            files_indexed: 3,
+            errors: 1,
+            elapsed_ms: 42,
+        };
+        assert_eq!(
+            serde_json::to_value(status).unwrap(),
+            serde_json::json!({
+                "complete": true,
+                "filesDiscovered": 3,
+                "filesIndexed": 3,
+                "errors": 1,
+                "elapsedMs": 42,
+            })
+        );
+    }
+
+    #[test]
+    fn test_syntax_tree_sexp() {
+        let source = "let s:x = 1\ncall F(s:x)\n";
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&tree_sitter_vim::language()).unwrap();
+        let tree = parser.parse(source, None).unwrap();
+        let sexp = Backend::syntax_tree_sexp(&tree, source, None).unwrap();
+        assert!(sexp.starts_with("(script_file (let_statement"));
+        // The smallest named node enclosing the range
+        let range = Range::new(Position::new(1, 5), Position::new(1, 10));
+        assert_eq!(
+            Backend::syntax_tree_sexp(&tree, source, Some(range)).unwrap(),
+            "(call_expression function: (identifier) (scoped_identifier (scope) (identifier)))"
+        );
+    }
+
+    #[test]
+    fn test_plugin_root() {
+        assert_eq!(
+            plugin_root(Path::new("bundle/foo/autoload/foo/bar.vim")),
+            Some(PathBuf::from("bundle/foo"))
+        );
+        assert_eq!(
+            plugin_root(Path::new("pack/a/start/b/syntax/b.vim")),
+            Some(PathBuf::from("pack/a/start/b"))
+        );
+        // The outermost directory counts
+        assert_eq!(
+            plugin_root(Path::new("vim/plugin/autoload/x.vim")),
+            Some(PathBuf::from("vim"))
+        );
+        assert_eq!(plugin_root(Path::new("autoload/foo.vim")), None);
+        assert_eq!(plugin_root(Path::new("bundle/foo/plugin")), None);
+        assert_eq!(plugin_root(Path::new("vimrc")), None);
+        assert!(is_opt_package(Path::new("pack/a/opt/b")));
+        assert!(is_opt_package(Path::new("dotfiles/pack/a/opt/b")));
+        assert!(!is_opt_package(Path::new("pack/a/start/b")));
+        assert!(!is_opt_package(Path::new("bundle/opt")));
+    }
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*.vim", "foo.vim"));
+        assert!(wildcard_match("f?o.vim", "foo.vim"));
+        assert!(wildcard_match("*", ""));
+        assert!(!wildcard_match("*.vim", "foo.lua"));
+        assert!(!wildcard_match("foo", "foobar"));
+    }
+
+    #[test]
+    fn test_renamed_text() {
+        let source =
+            "call s:Foo()\nnnoremap x :call <SID>Foo()<CR>\necho function('<SNR>12_Foo')\n";
+        let location = |row, col| symbols::SourceLocation {
+            start: (row, col),
+            end: (row, col),
+        };
+        assert_eq!(renamed_text(source, &location(0, 5), "s:Bar"), "s:Bar");
+        assert_eq!(renamed_text(source, &location(1, 17), "s:Bar"), "<SID>Bar");
+        assert_eq!(
+            renamed_text(source, &location(2, 15), "s:Bar"),
+            "<SNR>12_Bar"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_rename_autoload_function_keeps_index_on_disk() {
+        let root = temp_workspace(
+            "rename-autoload",
+            &[
+                ("plugin/a.vim", "call foo#bar#Baz()\n"),
+                (
+                    "autoload/foo/bar.vim",
+                    "function! foo#bar#Baz() abort\nendfunction\n",
+                ),
+            ],
+        );
+        let backend = test_backend(&root);
+        backend
+            .document_changes_support
+            .store(true, Ordering::Relaxed);
+        backend.create_file_support.store(true, Ordering::Relaxed);
+        backend.rename_file_support.store(true, Ordering::Relaxed);
+
+        // The autoload file has unsaved edits defining another function
+        let unsaved = "function! foo#bar#Baz() abort\nendfunction\nfunction! foo#bar#Other() abort\nendfunction\n";
+        backend.open_document(
+            file_uri(&root, "autoload/foo/bar.vim"),
+            unsaved.to_string(),
+            1,
+        );
+        backend.open_document(
+            file_uri(&root, "plugin/a.vim"),
+            "call foo#bar#Baz()\n".to_string(),
+            1,
+        );
+
+        let edit = backend
+            .rename(rename_params(
+                file_uri(&root, "plugin/a.vim"),
+                0,
+                6,
+                "foo#qux#Baz",
+            ))
+            .await
+            .unwrap()
+            .unwrap();
+        // The buffer defines another function of the namespace: the renamed
+        // one moves to a new file instead of renaming the file
+        let Some(DocumentChanges::Operations(operations)) = edit.document_changes else {
+            panic!("expected file operations");
+        };
+        assert!(operations.iter().any(|op| matches!(
+            op,
+            DocumentChangeOperation::Op(ResourceOp::Create(create))
+                if create.uri == file_uri(&root, "autoload/foo/qux.vim")
+        )));
+
+        // The indexed entry still follows the file on disk
+        let path = root.join("autoload/foo/bar.vim");
+        let source_file = backend.source_files.read().unwrap()[path.to_string_lossy().as_ref()];
+        let db = backend.salsa_db.lock().unwrap();
+        assert_eq!(source_file.text(&*db), None);
+        assert_eq!(
+            db::file_content(&*db, source_file),
+            "function! foo#bar#Baz() abort\nendfunction\n"
+        );
+        drop(db);
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[tokio::test]
+    async fn test_rename_across_open_files() {
+        let root = temp_workspace(
+            "rename-open",
+            &[
+                (
+                    "autoload/foo.vim",
+                    "function! foo#Bar() abort\nendfunction\n",
+                ),
+                ("plugin/b.vim", "call foo#Bar()\n"),
+            ],
+        );
+        let backend = test_backend(&root);
+        backend.open_document(
+            file_uri(&root, "autoload/foo.vim"),
+            "function! foo#Bar() abort\nendfunction\n".to_string(),
+            1,
+        );
+        // The call moved to the second line, unsaved
+        backend.open_document(
+            file_uri(&root, "plugin/b.vim"),
+            "\" Unsaved\ncall foo#Bar()\n".to_string(),
+            2,
+        );
+
+        // Edits of the other file come from its buffer, whatever the order
+        // of the indexed files
+        for _ in 0..8 {
+            let edit = backend
+                .rename(rename_params(
+                    file_uri(&root, "autoload/foo.vim"),
+                    0,
+                    10,
+                    "foo#Baz",
+                ))
+                .await
+                .unwrap()
+                .unwrap();
+            let changes = edit.changes.unwrap();
+            let edits = &changes[&file_uri(&root, "plugin/b.vim")];
+            assert_eq!(edits.len(), 1);
+            assert_eq!(edits[0].range.start, Position::new(1, 5));
+            assert_eq!(edits[0].new_text, "foo#Baz");
+        }
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_autoload_definition_keeps_index_on_disk() {
+        let root = temp_workspace(
+            "autoload-definition",
+            &[
+                ("plugin/a.vim", "call foo#Bar()\n"),
+                (
+                    "autoload/foo.vim",
+                    "\" foo\nfunction! foo#Bar() abort\nendfunction\n",
+                ),
+            ],
+        );
+        let backend = test_backend(&root);
+        let uri = file_uri(&root, "plugin/a.vim");
+        backend.open_document(uri.clone(), "call foo#Bar()\n".to_string(), 1);
+
+        let params = GotoDefinitionParams {
+            text_document_position_params: TextDocumentPositionParams {
+                text_document: TextDocumentIdentifier { uri },
+                position: Position::new(0, 6),
+            },
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        let Some(GotoDefinitionResponse::Scalar(location)) =
+            backend.find_definition(params.clone(), false, Target::Definition)
+        else {
+            panic!("expected a definition");
+        };
+        assert_eq!(location.uri, file_uri(&root, "autoload/foo.vim"));
+        assert_eq!(location.range.start, Position::new(1, 10));
+
+        // The indexed entry isn't pinned to the content read for the definition
+        let path = root.join("autoload/foo.vim");
+        let source_file = backend.source_files.read().unwrap()[path.to_string_lossy().as_ref()];
+        assert_eq!(source_file.text(&*backend.salsa_db.lock().unwrap()), None);
+
+        // It follows the file once saved
+        std::fs::write(&path, "function! foo#Bar() abort\nendfunction\n").unwrap();
+        let revision = source_file.revision(&*backend.salsa_db.lock().unwrap());
+        source_file
+            .set_revision(&mut *backend.salsa_db.lock().unwrap())
+            .to(revision + 1);
+        let Some(GotoDefinitionResponse::Scalar(location)) =
+            backend.find_definition(params, false, Target::Definition)
+        else {
+            panic!("expected a definition");
+        };
+        assert_eq!(location.range.start, Position::new(0, 10));
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn test_declaration_and_definition_of_variables() {
+        let source = "let s:count = 0\n\
+                      function! s:Inc() abort\n\
+                      \x20 let l:n = 1\n\
+                      \x20 let s:count = s:count + l:n\n\
+                      \x20 let l:n = 2\n\
+                      \x20 return l:n\n\
+                      endfunction\n\
+                      function! s:Other() abort\n\
+                      \x20 let l:n = 3\n\
+                      \x20 return l:n\n\
+                      endfunction\n\
+                      echo s:count\n";
+        let root = temp_workspace("declaration", &[("plugin/a.vim", source)]);
+        let backend = test_backend(&root);
+        let uri = file_uri(&root, "plugin/a.vim");
+        backend.open_document(uri.clone(), source.to_string(), 1);
+
+        let find = |line, character, target| {
+            let params = GotoDefinitionParams {
+                text_document_position_params: TextDocumentPositionParams {
+                    text_document: TextDocumentIdentifier { uri: uri.clone() },
+                    position: Position::new(line, character),
+                },
+                work_done_progress_params: Default::default(),
+                partial_result_params: Default::default(),
+            };
+            match backend.find_definition(params, false, target) {
+                Some(GotoDefinitionResponse::Scalar(location)) => location.range.start.line,
+                response => panic!("expected one location, got {:?}", response),
+            }
+        };
+
+        // The first assignment, and the one reaching the reference
+        assert_eq!(find(11, 6, Target::Declaration), 0);
+        assert_eq!(find(11, 6, Target::Definition), 3);
+        assert_eq!(find(5, 11, Target::Declaration), 2);
+        assert_eq!(find(5, 11, Target::Definition), 4);
+        // `l:` variables only match the assignments of their function
+        assert_eq!(find(9, 11, Target::Declaration), 8);
+        assert_eq!(find(9, 11, Target::Definition), 8);
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[tokio::test]
+    async fn test_removed_settings_revert_to_cli() {
+        let root = temp_workspace(
+            "settings",
+            &[("cli/plugin/a.vim", ""), ("client/plugin/b.vim", "")],
+        );
+        let (service, _) = Backend::service(
+            EditorMode::VimOnly,
+            Some(root.join("cli")),
+            vec![root.join("cli")],
+            None,
+        );
+        let backend = service.inner().clone();
+
+        backend
+            .update_settings(serde_json::json!({
+                "editorMode": "neovim",
+                "vimruntime": root.join("client"),
+                "runtimepath": [root.join("client")],
+                "lint": { "style": true },
+            }))
+            .await;
+        assert_eq!(backend.editor_mode(), EditorMode::NeovimOnly);
+        assert_eq!(backend.vimruntime(), Some(root.join("client")));
+        assert_eq!(
+            *backend.runtimepath.read().unwrap(),
+            vec![root.join("client")]
+        );
+        assert_eq!(backend.config.lock().unwrap().lint.style, Some(true));
+
+        // Settings left out of the next configuration revert to the flags
+        backend
+            .update_settings(serde_json::json!({ "editorMode": "both" }))
+            .await;
+        assert_eq!(backend.editor_mode(), EditorMode::Both);
+        assert_eq!(backend.vimruntime(), Some(root.join("cli")));
+        assert_eq!(*backend.runtimepath.read().unwrap(), vec![root.join("cli")]);
+        assert_eq!(backend.config.lock().unwrap().lint.style, None);
+
+        backend.update_settings(serde_json::json!({})).await;
+        assert_eq!(backend.editor_mode(), EditorMode::VimOnly);
+    }
+
+    #[tokio::test]
+    async fn test_requests_wait_for_earlier_edits() {
+        let root = temp_workspace("sync", &[]);
+        let backend = test_backend(&root);
+        let uri = file_uri(&root, "plugin/a.vim");
+        backend
+            .did_open(DidOpenTextDocumentParams {
+                text_document: TextDocumentItem {
+                    uri: uri.clone(),
+                    language_id: "vim".to_string(),
+                    version: 1,
+                    text: "function! First() abort\nendfunction\n".to_string(),
+                },
+            })
+            .await;
+
+        let change = |version: i32, text: &str| DidChangeTextDocumentParams {
+            text_document: VersionedTextDocumentIdentifier {
+                uri: uri.clone(),
+                version,
+            },
+            content_changes: vec![TextDocumentContentChangeEvent {
+                range: None,
+                range_length: None,
+                text: text.to_string(),
+            }],
+        };
+        let symbols = DocumentSymbolParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+
+        // The edits are applied off the executor; the request received after
+        // them still sees the last one
+        let (_, _, response) = tokio::join!(
+            backend.did_change(change(2, "function! Second() abort\nendfunction\n")),
+            backend.did_change(change(3, "function! Third() abort\nendfunction\n")),
+            backend.document_symbol(symbols),
+        );
+        let Some(DocumentSymbolResponse::Nested(symbols)) = response.unwrap() else {
+            panic!("expected document symbols");
+        };
+        let names: Vec<&str> = symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Third"]);
+        assert_eq!(backend.documents.read().unwrap()[&uri].version, 3);
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[tokio::test]
+    async fn test_rename_cancelled_between_files() {
+        let root = temp_workspace(
+            "rename-cancel",
+            &[
+                (
+                    "autoload/foo.vim",
+                    "function! foo#Bar() abort\nendfunction\n",
+                ),
+                ("plugin/a.vim", "call foo#Bar()\n"),
+                ("plugin/b.vim", "call foo#Bar()\n"),
+            ],
+        );
+        let backend = test_backend(&root);
+        let uri = file_uri(&root, "plugin/a.vim");
+        backend.open_document(uri.clone(), "call foo#Bar()\n".to_string(), 1);
+
+        // The search stops before the first file other than the current
+        // one, where `$/cancelRequest` can drop the future
+        let mut rename = Box::pin(backend.rename(rename_params(uri.clone(), 0, 5, "foo#Baz")));
+        let mut context = std::task::Context::from_waker(std::task::Waker::noop());
+        assert!(rename.as_mut().poll(&mut context).is_pending());
+        drop(rename);
+
+        // The dropped request leaves nothing locked
+        let edit = backend
+            .rename(rename_params(uri, 0, 5, "foo#Baz"))
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(edit.changes.unwrap().len(), 3);
+        let _ = std::fs::remove_dir_all(&root);
+    }
+
+    #[tokio::test]
+    async fn test_save_relints_dependents() {
+        let root = temp_workspace(
+            "relint",
+            &[
+                ("plugin/a.vim", "call Foo()\n"),
+                ("plugin/b.vim", ""),
+                ("plugin/c.vim", "call Other()\n"),
+            ],
+        );
+        let backend = test_backend(&root);
+        let (a, b, c) = (
+            file_uri(&root, "plugin/a.vim"),
+            file_uri(&root, "plugin/b.vim"),
+            file_uri(&root, "plugin/c.vim"),
+        );
+        backend.open_document(a.clone(), "call Foo()\n".to_string(), 1);
+        backend.open_document(b.clone(), String::new(), 1);
+        backend.open_document(c.clone(), "call Other()\n".to_string(), 1);
+        let undefined = |uri: &Uri| {
+            backend
+                .versioned_diagnostics(uri, 1)
+                .unwrap()
+                .iter()
+                .any(|d| d.code == Some(NumberOrString::String("hjkls/undefined_function".into())))
+        };
+        assert!(undefined(&a));
+
+        // Saving the definition updates the documents calling it
+        let text = "function! Foo() abort\nendfunction\n";
+        backend.open_document(b.clone(), text.to_string(), 2);
+        std::fs::write(root.join("plugin/b.vim"), text).unwrap();
+        backend
+            .did_save(DidSaveTextDocumentParams {
+                text_document: TextDocumentIdentifier { uri: b.clone() },
+                text: Some(text.to_string()),
+            })
+            .await;
+        assert!(!undefined(&a));
+        assert!(undefined(&c));
+
+        // Only the documents calling it are republished
+        let functions = backend.defined_functions(b.as_str());
+        assert_eq!(backend.dependent_documents(&b, &functions), vec![a]);
+        let _ = std::fs::remove_dir_all(&root);
+    }
 }
@@ -0,0 +1,126 @@
+//! Performance report from the command line: `hjkls bench [paths]`
+//!
+//! Times the workspace index build, then the parse and every diagnostic pass
+//! of each file, to find which file or analysis pass is slow.
+
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::backend::Backend;
+use crate::check;
+
+/// Timings of one file
+struct FileTiming {
+    path: PathBuf,
+    parse: Duration,
+    lint: Duration,
+}
+
+/// Time indexing the current directory and analyzing the given files and
+/// directories (the current directory when there are none)
+///
+/// Returns the process exit code: 1 when a path couldn't be read.
+pub fn run(backend: &Backend, paths: &[PathBuf]) -> i32 {
+    let root = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    let start = Instant::now();
+    backend.index_workspace(root.clone());
+    let index = start.elapsed();
+    let indexed = backend.workspace_definitions().len();
+
+    let paths = if paths.is_empty() {
+        vec![root.clone()]
+    } else {
+        paths.to_vec()
+    };
+    let (files, mut failed) = check::collect_files(backend, &paths);
+
+    let mut files_timings = Vec::new();
+    let mut passes: Vec<(&'static str, Duration)> = Vec::new();
+    for path in files {
+        let source = match std::fs::read_to_string(&path) {
+            Ok(source) => source,
+            Err(e) => {
+                eprintln!("error: {}: {}", path.display(), e);
+                failed = true;
+                continue;
+            }
+        };
+        let start = Instant::now();
+        let Some(tree) = backend.parse_source(&source) else {
+            continue;
+        };
+        let parse = start.elapsed();
+        let timings = match backend.time_lint_passes(&path, &source, &tree) {
+            Ok(timings) => timings,
+            Err(e) => {
+                eprintln!("error: {}: {}", path.display(), e);
+                failed = true;
+                continue;
+            }
+        };
+        for (name, elapsed) in &timings {
+            match passes.iter_mut().find(|(pass, _)| pass == name) {
+                Some((_, total)) => *total += *elapsed,
+                None => passes.push((name, *elapsed)),
+            }
+        }
+        files_timings.push(FileTiming {
+            path,
+            parse,
+            lint: timings.iter().map(|(_, elapsed)| *elapsed).sum(),
+        });
+    }
+
+    // Slowest first
+    files_timings.sort_by_key(|file| std::cmp::Reverse(file.parse + file.lint));
+    passes.sort_by_key(|(_, elapsed)| std::cmp::Reverse(*elapsed));
+
+    println!("{:<48} {:>10} {:>10}", "FILE", "PARSE", "LINT");
+    for file in &files_timings {
+        println!(
+            "{:<48} {:>10} {:>10}",
+            relative_path(&file.path, &root),
+            format_duration(file.parse),
+            format_duration(file.lint)
+        );
+    }
+    println!();
+    println!("{:<48} {:>10}", "PASS", "TIME");
+    for (name, elapsed) in &passes {
+        println!("{:<48} {:>10}", name, format_duration(*elapsed));
+    }
+    println!();
+    println!(
+        "indexed {} files in {}, analyzed {} files (parse {}, lint {})",
+        indexed,
+        format_duration(index),
+        files_timings.len(),
+        format_duration(files_timings.iter().map(|file| file.parse).sum()),
+        format_duration(files_timings.iter().map(|file| file.lint).sum())
+    );
+    i32::from(failed)
+}
+
+/// Display a path relative to the workspace root
+fn relative_path(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .display()
+        .to_string()
+}
+
+/// Format a duration in milliseconds (e.g., "1.234ms")
+fn format_duration(duration: Duration) -> String {
+    format!("{:.3}ms", duration.as_secs_f64() * 1000.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_micros(1234)), "1.234ms");
+        assert_eq!(format_duration(Duration::from_secs(2)), "2000.000ms");
+    }
+}
@@ -1,5 +1,15 @@
 //! Vim script built-in functions database
 
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use serde::Deserialize;
+
+mod functions;
+mod versions;
+
+pub use functions::BUILTIN_FUNCTIONS;
+
 /// Function availability in Vim/Neovim
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Availability {
@@ -12,14 +22,17 @@ pub enum Availability {
 }
 
 /// Editor mode for filtering completions
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
 pub enum EditorMode {
     /// Show all functions (default)
     #[default]
+    #[serde(rename = "both")]
     Both,
     /// Show only Vim-compatible functions
+    #[serde(rename = "vim")]
     VimOnly,
     /// Show only Neovim-compatible functions
+    #[serde(rename = "neovim")]
     NeovimOnly,
 }
 
@@ -44,6 +57,52 @@ impl Availability {
     }
 }
 
+/// Versions of Vim and Neovim that added a builtin function, option or event
+/// Reference: :help has-patch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Since {
+    /// Vim release ("8.1") or patch ("8.2.1978"), None for Vim 7.0 and older
+    pub vim: Option<&'static str>,
+    /// Neovim release ("0.10")
+    pub neovim: Option<&'static str>,
+}
+
+impl Since {
+    fn find(table: &'static [(&'static str, Since)], name: &str) -> Option<&'static Since> {
+        table
+            .binary_search_by(|(entry, _)| (*entry).cmp(name))
+            .ok()
+            .map(|i| &table[i].1)
+    }
+
+    /// Describe the versions relevant to an editor (e.g., "Added in Vim 8.2.1978")
+    pub fn label(&self, editor: EditorMode) -> Option<String> {
+        let vim = self
+            .vim
+            .filter(|_| editor != EditorMode::NeovimOnly)
+            .map(|version| format!("Vim {}", version));
+        let neovim = self
+            .neovim
+            .filter(|_| editor != EditorMode::VimOnly)
+            .map(|version| format!("Neovim {}", version));
+        match (vim, neovim) {
+            (Some(vim), Some(neovim)) => Some(format!("Added in {} and {}", vim, neovim)),
+            (Some(version), None) | (None, Some(version)) => Some(format!("Added in {}", version)),
+            (None, None) => None,
+        }
+    }
+}
+
+/// Parse a version ("8.2.1978", "8.1" or "0.10") into (major, minor, patch)
+pub fn parse_version(version: &str) -> (u32, u32, u32) {
+    let mut parts = version.split('.').map(|part| part.parse().unwrap_or(0));
+    (
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+    )
+}
+
 /// Information about a built-in function
 pub struct BuiltinFunction {
     pub name: &'static str,
@@ -52,4728 +111,320 @@ pub struct BuiltinFunction {
     pub availability: Availability,
 }
 
-/// Information about a built-in variable (v: scope)
-pub struct BuiltinVariable {
+impl BuiltinFunction {
+    /// Find a built-in function by name
+    ///
+    /// The name index is built on first use.
+    pub fn find(name: &str) -> Option<&'static BuiltinFunction> {
+        static INDEX: OnceLock<HashMap<&'static str, &'static BuiltinFunction>> = OnceLock::new();
+        INDEX
+            .get_or_init(|| {
+                let mut index = HashMap::with_capacity(BUILTIN_FUNCTIONS.len());
+                for f in BUILTIN_FUNCTIONS {
+                    index.entry(f.name).or_insert(f);
+                }
+                index
+            })
+            .get(name)
+            .copied()
+    }
+
+    /// Versions that added this function
+    pub fn since(&self) -> Option<&'static Since> {
+        Since::find(versions::FUNCTION_VERSIONS, self.name)
+    }
+}
+
+/// Kind of a deprecated construct
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeprecationKind {
+    /// A builtin function, called by name
+    Function,
+    /// An expression operator
+    Operator,
+}
+
+/// How to rewrite a deprecated construct with its replacement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DeprecationFix {
+    /// Use the replacement name, keeping the arguments
+    Rename,
+    /// Rewrite the call with a template, indexed by the number of arguments
+    /// (`$1` is the first argument, an empty template doesn't apply)
+    Call(&'static [&'static str]),
+    /// The replacement works differently, rewriting is left to the user
+    Manual,
+}
+
+/// A deprecated or obsolete construct and its modern replacement
+pub struct Deprecation {
+    pub kind: DeprecationKind,
     pub name: &'static str,
-    pub description: &'static str,
+    pub replacement: &'static str,
+    pub fix: DeprecationFix,
     pub availability: Availability,
 }
 
-/// List of Vim built-in functions
-/// Reference: :help function-list
-pub static BUILTIN_FUNCTIONS: &[BuiltinFunction] = &[
-    BuiltinFunction {
-        name: "strlen",
-        signature: "strlen({string})",
-        description: "Return the number of bytes in {string}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "strchars",
-        signature: "strchars({string} [, {skipcc}])",
-        description: "Return the number of characters in {string}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "strwidth",
-        signature: "strwidth({string})",
-        description: "Return the display width of {string}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "strdisplaywidth",
-        signature: "strdisplaywidth({string} [, {col}])",
-        description: "Return the display width of {string} starting at {col}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "substitute",
-        signature: "substitute({string}, {pat}, {sub}, {flags})",
-        description: "Replace {pat} with {sub} in {string}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "submatch",
-        signature: "submatch({nr} [, {list}])",
-        description: "Return a specific match in substitute",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "strpart",
-        signature: "strpart({string}, {start} [, {len} [, {chars}]])",
-        description: "Return part of a string",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "stridx",
-        signature: "stridx({haystack}, {needle} [, {start}])",
-        description: "Return index of {needle} in {haystack}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "strridx",
-        signature: "strridx({haystack}, {needle} [, {start}])",
-        description: "Return last index of {needle} in {haystack}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "split",
-        signature: "split({string} [, {pattern} [, {keepempty}]])",
-        description: "Split {string} into a List",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "join",
-        signature: "join({list} [, {sep}])",
-        description: "Join {list} items into a string",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "trim",
-        signature: "trim({string} [, {mask} [, {dir}]])",
-        description: "Remove characters from {string}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "tolower",
-        signature: "tolower({string})",
-        description: "Convert {string} to lowercase",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "toupper",
-        signature: "toupper({string})",
-        description: "Convert {string} to uppercase",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "tr",
-        signature: "tr({string}, {fromstr}, {tostr})",
-        description: "Translate characters in {string}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "printf",
-        signature: "printf({fmt}, {expr1}...)",
-        description: "Format a string like sprintf()",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "escape",
-        signature: "escape({string}, {chars})",
-        description: "Escape {chars} in {string} with backslash",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "shellescape",
-        signature: "shellescape({string} [, {special}])",
-        description: "Escape {string} for use as shell argument",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "fnameescape",
-        signature: "fnameescape({string})",
-        description: "Escape {string} for use as file name",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "match",
-        signature: "match({string}, {pattern} [, {start} [, {count}]])",
-        description: "Return index of {pattern} match in {string}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "matchend",
-        signature: "matchend({string}, {pattern} [, {start} [, {count}]])",
-        description: "Return end index of {pattern} match",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "matchstr",
-        signature: "matchstr({string}, {pattern} [, {start} [, {count}]])",
-        description: "Return matched string",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "matchlist",
-        signature: "matchlist({string}, {pattern} [, {start} [, {count}]])",
-        description: "Return match and submatches as List",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "len",
-        signature: "len({expr})",
-        description: "Return the length of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "empty",
-        signature: "empty({expr})",
-        description: "Return TRUE if {expr} is empty",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "get",
-        signature: "get({list}, {idx} [, {default}])",
-        description: "Get item {idx} from {list}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "add",
-        signature: "add({list}, {expr})",
-        description: "Append {expr} to {list}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "insert",
-        signature: "insert({list}, {item} [, {idx}])",
-        description: "Insert {item} into {list}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "remove",
-        signature: "remove({list}, {idx} [, {end}])",
-        description: "Remove items from {list}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "copy",
-        signature: "copy({expr})",
-        description: "Make a shallow copy of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "deepcopy",
-        signature: "deepcopy({expr} [, {noref}])",
-        description: "Make a deep copy of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "extend",
-        signature: "extend({list1}, {list2} [, {idx}])",
-        description: "Append {list2} to {list1}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "filter",
-        signature: "filter({expr}, {func})",
-        description: "Filter items in {expr} using {func}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "map",
-        signature: "map({expr}, {func})",
-        description: "Transform items in {expr} using {func}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "sort",
-        signature: "sort({list} [, {func} [, {dict}]])",
-        description: "Sort {list} in-place",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "reverse",
-        signature: "reverse({list})",
-        description: "Reverse {list} in-place",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "uniq",
-        signature: "uniq({list} [, {func} [, {dict}]])",
-        description: "Remove duplicate adjacent items",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "index",
-        signature: "index({list}, {expr} [, {start} [, {ic}]])",
-        description: "Return index of {expr} in {list}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "count",
-        signature: "count({list}, {expr} [, {ic} [, {max}]])",
-        description: "Count occurrences of {expr} in {list}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "range",
-        signature: "range({expr} [, {max} [, {stride}]])",
-        description: "Return a List of numbers",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "repeat",
-        signature: "repeat({expr}, {count})",
-        description: "Repeat {expr} {count} times",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "flatten",
-        signature: "flatten({list} [, {maxdepth}])",
-        description: "Flatten nested lists",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "keys",
-        signature: "keys({dict})",
-        description: "Return List of keys in {dict}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "values",
-        signature: "values({dict})",
-        description: "Return List of values in {dict}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "items",
-        signature: "items({dict})",
-        description: "Return List of [key, value] pairs",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "has_key",
-        signature: "has_key({dict}, {key})",
-        description: "Return TRUE if {dict} has {key}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "type",
-        signature: "type({expr})",
-        description: "Return the type of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "typename",
-        signature: "typename({expr})",
-        description: "Return the type name of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "bufnr",
-        signature: "bufnr([{expr} [, {create}]])",
-        description: "Return buffer number",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "bufname",
-        signature: "bufname([{expr}])",
-        description: "Return buffer name",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "bufexists",
-        signature: "bufexists({expr})",
-        description: "Return TRUE if buffer exists",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "buflisted",
-        signature: "buflisted({expr})",
-        description: "Return TRUE if buffer is listed",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "bufloaded",
-        signature: "bufloaded({expr})",
-        description: "Return TRUE if buffer is loaded",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getbufline",
-        signature: "getbufline({buf}, {lnum} [, {end}])",
-        description: "Return lines from buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setbufline",
-        signature: "setbufline({buf}, {lnum}, {text})",
-        description: "Set lines in buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "appendbufline",
-        signature: "appendbufline({buf}, {lnum}, {text})",
-        description: "Append lines to buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "deletebufline",
-        signature: "deletebufline({buf}, {first} [, {last}])",
-        description: "Delete lines from buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "winnr",
-        signature: "winnr([{arg}])",
-        description: "Return window number",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "winbufnr",
-        signature: "winbufnr({nr})",
-        description: "Return buffer number of window {nr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "tabpagenr",
-        signature: "tabpagenr([{arg}])",
-        description: "Return tab page number",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "tabpagebuflist",
-        signature: "tabpagebuflist([{arg}])",
-        description: "Return List of buffer numbers in tab",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "line",
-        signature: "line({expr} [, {winid}])",
-        description: "Return line number of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "col",
-        signature: "col({expr} [, {winid}])",
-        description: "Return column number of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "virtcol",
-        signature: "virtcol({expr} [, {list} [, {winid}]])",
-        description: "Return screen column of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getpos",
-        signature: "getpos({expr})",
-        description: "Return position of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setpos",
-        signature: "setpos({expr}, {list})",
-        description: "Set position of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "cursor",
-        signature: "cursor({lnum}, {col} [, {off}])",
-        description: "Move cursor to position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcurpos",
-        signature: "getcurpos([{winnr}])",
-        description: "Return cursor position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getline",
-        signature: "getline({lnum} [, {end}])",
-        description: "Return line(s) from current buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setline",
-        signature: "setline({lnum}, {text})",
-        description: "Set line {lnum} to {text}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "append",
-        signature: "append({lnum}, {text})",
-        description: "Append {text} after line {lnum}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "search",
-        signature: "search({pattern} [, {flags} [, {stopline} [, {timeout} [, {skip}]]]])",
-        description: "Search for {pattern}, return line number of match",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "searchpos",
-        signature: "searchpos({pattern} [, {flags} [, {stopline} [, {timeout} [, {skip}]]]])",
-        description: "Search for {pattern}, return [lnum, col] of match",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "searchpair",
-        signature: "searchpair({start}, {middle}, {end} [, {flags} [, {skip} [, {stopline} [, {timeout}]]]])",
-        description: "Search for matching pair of start/end patterns",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "searchpairpos",
-        signature: "searchpairpos({start}, {middle}, {end} [, {flags} [, {skip} [, {stopline} [, {timeout}]]]])",
-        description: "Search for matching pair, return [lnum, col]",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "expand",
-        signature: "expand({string} [, {nosuf} [, {list}]])",
-        description: "Expand wildcards and special keywords",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "glob",
-        signature: "glob({expr} [, {nosuf} [, {list} [, {alllinks}]]])",
-        description: "Expand file wildcards",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "globpath",
-        signature: "globpath({path}, {expr} [, {nosuf} [, {list} [, {alllinks}]]])",
-        description: "Expand file wildcards in {path}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "filereadable",
-        signature: "filereadable({file})",
-        description: "Return TRUE if {file} is readable",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "filewritable",
-        signature: "filewritable({file})",
-        description: "Return TRUE if {file} is writable",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "isdirectory",
-        signature: "isdirectory({directory})",
-        description: "Return TRUE if {directory} is a directory",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "fnamemodify",
-        signature: "fnamemodify({fname}, {mods})",
-        description: "Modify file name according to {mods}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "readfile",
-        signature: "readfile({fname} [, {type} [, {max}]])",
-        description: "Read file into a List",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "writefile",
-        signature: "writefile({list}, {fname} [, {flags}])",
-        description: "Write List to file",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "delete",
-        signature: "delete({fname} [, {flags}])",
-        description: "Delete file or directory",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "rename",
-        signature: "rename({from}, {to})",
-        description: "Rename file",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "mkdir",
-        signature: "mkdir({name} [, {path} [, {prot}]])",
-        description: "Create directory",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcwd",
-        signature: "getcwd([{winnr} [, {tabnr}]])",
-        description: "Return current working directory",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "chdir",
-        signature: "chdir({dir})",
-        description: "Change current directory",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "system",
-        signature: "system({cmd} [, {input}])",
-        description: "Execute shell command and return output",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "systemlist",
-        signature: "systemlist({cmd} [, {input} [, {keepempty}]])",
-        description: "Execute shell command and return List",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "executable",
-        signature: "executable({expr})",
-        description: "Return TRUE if {expr} is executable",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "exepath",
-        signature: "exepath({expr})",
-        description: "Return full path to executable",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "environ",
-        signature: "environ()",
-        description: "Return Dict of environment variables",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getenv",
-        signature: "getenv({name})",
-        description: "Return environment variable value",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setenv",
-        signature: "setenv({name}, {val})",
-        description: "Set environment variable",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "exists",
-        signature: "exists({expr})",
-        description: "Return TRUE if {expr} exists",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "has",
-        signature: "has({feature} [, {check}])",
-        description: "Return TRUE if feature is supported",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "eval",
-        signature: "eval({string})",
-        description: "Evaluate {string} as expression",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "execute",
-        signature: "execute({command} [, {silent}])",
-        description: "Execute Ex command and return output",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "input",
-        signature: "input({prompt} [, {text} [, {completion}]])",
-        description: "Get input from user",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "confirm",
-        signature: "confirm({msg} [, {choices} [, {default} [, {type}]]])",
-        description: "Show confirmation dialog",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "feedkeys",
-        signature: "feedkeys({string} [, {mode}])",
-        description: "Add keys to input buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "mode",
-        signature: "mode([{expr}])",
-        description: "Return current mode",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "visualmode",
-        signature: "visualmode([{expr}])",
-        description: "Return last visual mode",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "echo",
-        signature: "echo {expr1} ..",
-        description: "Echo expressions",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "echomsg",
-        signature: "echomsg {expr1} ..",
-        description: "Echo as message",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "echoerr",
-        signature: "echoerr {expr1} ..",
-        description: "Echo as error message",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "call",
-        signature: "call({func}, {arglist} [, {dict}])",
-        description: "Call {func} with arguments from {arglist}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "function",
-        signature: "function({name} [, {arglist}] [, {dict}])",
-        description: "Return Funcref to function {name}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "funcref",
-        signature: "funcref({name} [, {arglist}] [, {dict}])",
-        description: "Return Funcref like function()",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "json_encode",
-        signature: "json_encode({expr})",
-        description: "Encode {expr} as JSON",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "json_decode",
-        signature: "json_decode({string})",
-        description: "Decode JSON {string}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "timer_start",
-        signature: "timer_start({time}, {callback} [, {options}])",
-        description: "Create a timer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "timer_stop",
-        signature: "timer_stop({timer})",
-        description: "Stop a timer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "timer_stopall",
-        signature: "timer_stopall()",
-        description: "Stop all timers",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "abs",
-        signature: "abs({expr})",
-        description: "Return absolute value of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "acos",
-        signature: "acos({expr})",
-        description: "Return arc cosine of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "asin",
-        signature: "asin({expr})",
-        description: "Return arc sine of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "atan",
-        signature: "atan({expr})",
-        description: "Return arc tangent of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "atan2",
-        signature: "atan2({expr1}, {expr2})",
-        description: "Return arc tangent of {expr1}/{expr2}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "ceil",
-        signature: "ceil({expr})",
-        description: "Return smallest integer >= {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "cos",
-        signature: "cos({expr})",
-        description: "Return cosine of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "cosh",
-        signature: "cosh({expr})",
-        description: "Return hyperbolic cosine of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "exp",
-        signature: "exp({expr})",
-        description: "Return e to the power of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "floor",
-        signature: "floor({expr})",
-        description: "Return largest integer <= {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "fmod",
-        signature: "fmod({expr1}, {expr2})",
-        description: "Return remainder of {expr1}/{expr2}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "log",
-        signature: "log({expr})",
-        description: "Return natural logarithm of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "log10",
-        signature: "log10({expr})",
-        description: "Return base-10 logarithm of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "pow",
-        signature: "pow({x}, {y})",
-        description: "Return {x} to the power of {y}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "round",
-        signature: "round({expr})",
-        description: "Return {expr} rounded to nearest integer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "sin",
-        signature: "sin({expr})",
-        description: "Return sine of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "sinh",
-        signature: "sinh({expr})",
-        description: "Return hyperbolic sine of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "sqrt",
-        signature: "sqrt({expr})",
-        description: "Return square root of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "tan",
-        signature: "tan({expr})",
-        description: "Return tangent of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "tanh",
-        signature: "tanh({expr})",
-        description: "Return hyperbolic tangent of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "trunc",
-        signature: "trunc({expr})",
-        description: "Return integer part of {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "float2nr",
-        signature: "float2nr({expr})",
-        description: "Convert Float to Number",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "str2float",
-        signature: "str2float({string})",
-        description: "Convert String to Float",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "str2nr",
-        signature: "str2nr({string} [, {base}])",
-        description: "Convert String to Number",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "char2nr",
-        signature: "char2nr({string} [, {utf8}])",
-        description: "Return number value of first char in {string}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "nr2char",
-        signature: "nr2char({expr} [, {utf8}])",
-        description: "Return character with number value {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "byteidx",
-        signature: "byteidx({expr}, {nr} [, {utf16}])",
-        description: "Return byte index of {nr}th char in {expr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "byteidxcomp",
-        signature: "byteidxcomp({expr}, {nr} [, {utf16}])",
-        description: "Like byteidx() but count composing chars",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "charidx",
-        signature: "charidx({string}, {idx} [, {countcc} [, {utf16}]])",
-        description: "Return char index of byte {idx} in {string}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "strgetchar",
-        signature: "strgetchar({str}, {index})",
-        description: "Return char at {index} in {str}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "strcharpart",
-        signature: "strcharpart({str}, {start} [, {len} [, {skipcc}]])",
-        description: "Return part of {str} by char index",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "strcharlen",
-        signature: "strcharlen({string})",
-        description: "Return number of chars in {string}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "str2list",
-        signature: "str2list({string} [, {utf8}])",
-        description: "Return List of character numbers",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "list2str",
-        signature: "list2str({list} [, {utf8}])",
-        description: "Return String from List of numbers",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "winheight",
-        signature: "winheight({nr})",
-        description: "Return height of window {nr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "winwidth",
-        signature: "winwidth({nr})",
-        description: "Return width of window {nr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "winline",
-        signature: "winline()",
-        description: "Return window line of cursor",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "wincol",
-        signature: "wincol()",
-        description: "Return window column of cursor",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "winsaveview",
-        signature: "winsaveview()",
-        description: "Return Dict with current window view",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "winrestview",
-        signature: "winrestview({dict})",
-        description: "Restore window view from {dict}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "win_getid",
-        signature: "win_getid([{win} [, {tab}]])",
-        description: "Return window ID",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "win_gotoid",
-        signature: "win_gotoid({id})",
-        description: "Go to window with {id}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "win_id2win",
-        signature: "win_id2win({id})",
-        description: "Return window number of {id}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "win_id2tabwin",
-        signature: "win_id2tabwin({id})",
-        description: "Return [tabnr, winnr] of {id}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "win_findbuf",
-        signature: "win_findbuf({bufnr})",
-        description: "Return window IDs for {bufnr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "win_gettype",
-        signature: "win_gettype([{nr}])",
-        description: "Return type of window {nr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "win_screenpos",
-        signature: "win_screenpos({nr})",
-        description: "Return screen position of window {nr}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "win_execute",
-        signature: "win_execute({id}, {command} [, {silent}])",
-        description: "Execute {command} in window {id}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "win_splitmove",
-        signature: "win_splitmove({nr}, {target} [, {options}])",
-        description: "Move window {nr} to split of {target}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "winlayout",
-        signature: "winlayout([{tabnr}])",
-        description: "Return layout of windows in tab",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "winrestcmd",
-        signature: "winrestcmd()",
-        description: "Return command to restore window sizes",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getbufinfo",
-        signature: "getbufinfo([{buf}])",
-        description: "Return List of buffer information",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getbufvar",
-        signature: "getbufvar({buf}, {varname} [, {def}])",
-        description: "Return variable from buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setbufvar",
-        signature: "setbufvar({buf}, {varname}, {val})",
-        description: "Set variable in buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "bufadd",
-        signature: "bufadd({name})",
-        description: "Add buffer {name} to buffer list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "bufload",
-        signature: "bufload({buf})",
-        description: "Load buffer {buf} if not loaded",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getwininfo",
-        signature: "getwininfo([{winid}])",
-        description: "Return List of window information",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getwinvar",
-        signature: "getwinvar({winnr}, {varname} [, {def}])",
-        description: "Return variable from window",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setwinvar",
-        signature: "setwinvar({winnr}, {varname}, {val})",
-        description: "Set variable in window",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "gettabinfo",
-        signature: "gettabinfo([{tabnr}])",
-        description: "Return List of tab page information",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "gettabvar",
-        signature: "gettabvar({tabnr}, {varname} [, {def}])",
-        description: "Return variable from tab page",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "settabvar",
-        signature: "settabvar({tabnr}, {varname}, {val})",
-        description: "Set variable in tab page",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "gettabwinvar",
-        signature: "gettabwinvar({tabnr}, {winnr}, {varname} [, {def}])",
-        description: "Return variable from window in tab",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "settabwinvar",
-        signature: "settabwinvar({tabnr}, {winnr}, {varname}, {val})",
-        description: "Set variable in window of tab",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "localtime",
-        signature: "localtime()",
-        description: "Return current time in seconds",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "strftime",
-        signature: "strftime({format} [, {time}])",
-        description: "Format time as string",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "strptime",
-        signature: "strptime({format}, {timestring})",
-        description: "Parse time string",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "reltime",
-        signature: "reltime([{start} [, {end}]])",
-        description: "Return relative time",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "reltimestr",
-        signature: "reltimestr({time})",
-        description: "Return string representation of reltime",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "reltimefloat",
-        signature: "reltimefloat({time})",
-        description: "Return Float from reltime",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getpid",
-        signature: "getpid()",
-        description: "Return process ID of Vim",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "hostname",
-        signature: "hostname()",
-        description: "Return name of host machine",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "tempname",
-        signature: "tempname()",
-        description: "Return name of a temp file",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getchar",
-        signature: "getchar([{expr}])",
-        description: "Get one character from user",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcharstr",
-        signature: "getcharstr([{expr}])",
-        description: "Get one character as string",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcharmod",
-        signature: "getcharmod()",
-        description: "Return modifiers for last getchar()",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "inputlist",
-        signature: "inputlist({textlist})",
-        description: "Let user pick from a list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "inputsecret",
-        signature: "inputsecret({prompt} [, {text}])",
-        description: "Get input without showing it",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "inputsave",
-        signature: "inputsave()",
-        description: "Save typeahead",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "inputrestore",
-        signature: "inputrestore()",
-        description: "Restore typeahead",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "inputdialog",
-        signature: "inputdialog({prompt} [, {text} [, {cancelreturn}]])",
-        description: "Like input() but in a GUI dialog",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "matchadd",
-        signature: "matchadd({group}, {pattern} [, {priority} [, {id} [, {dict}]]])",
-        description: "Add match highlighting",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "matchaddpos",
-        signature: "matchaddpos({group}, {pos} [, {priority} [, {id} [, {dict}]]])",
-        description: "Add match at positions",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "matcharg",
-        signature: "matcharg({nr})",
-        description: "Return arguments of :match",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "matchdelete",
-        signature: "matchdelete({id} [, {win}])",
-        description: "Delete match by ID",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "clearmatches",
-        signature: "clearmatches([{win}])",
-        description: "Clear all matches",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getmatches",
-        signature: "getmatches([{win}])",
-        description: "Return list of matches",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setmatches",
-        signature: "setmatches({list} [, {win}])",
-        description: "Restore matches from list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "matchfuzzy",
-        signature: "matchfuzzy({list}, {str} [, {dict}])",
-        description: "Return fuzzy matches",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "matchfuzzypos",
-        signature: "matchfuzzypos({list}, {str} [, {dict}])",
-        description: "Return fuzzy matches with positions",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcharpos",
-        signature: "getcharpos({expr})",
-        description: "Return char position of mark",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setcharpos",
-        signature: "setcharpos({expr}, {list})",
-        description: "Set char position of mark",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcursorcharpos",
-        signature: "getcursorcharpos([{winnr}])",
-        description: "Return cursor char position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setcursorcharpos",
-        signature: "setcursorcharpos({lnum}, {col} [, {off}])",
-        description: "Set cursor char position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "charcol",
-        signature: "charcol({expr} [, {winid}])",
-        description: "Return char column of position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getmarklist",
-        signature: "getmarklist([{buf}])",
-        description: "Return list of marks",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getfsize",
-        signature: "getfsize({fname})",
-        description: "Return file size in bytes",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getftime",
-        signature: "getftime({fname})",
-        description: "Return file modification time",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getfperm",
-        signature: "getfperm({fname})",
-        description: "Return file permissions string",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setfperm",
-        signature: "setfperm({fname}, {mode})",
-        description: "Set file permissions",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getftype",
-        signature: "getftype({fname})",
-        description: "Return type of file",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "resolve",
-        signature: "resolve({filename})",
-        description: "Resolve symbolic links",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "simplify",
-        signature: "simplify({filename})",
-        description: "Simplify path without resolving links",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "pathshorten",
-        signature: "pathshorten({path} [, {len}])",
-        description: "Shorten directory names in path",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "isabsolutepath",
-        signature: "isabsolutepath({path})",
-        description: "Return TRUE if {path} is absolute",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "readdir",
-        signature: "readdir({dir} [, {expr}])",
-        description: "Return list of files in directory",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "readdirex",
-        signature: "readdirex({dir} [, {expr} [, {dict}]])",
-        description: "Return list of file info in directory",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "min",
-        signature: "min({expr})",
-        description: "Return minimum value in list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "max",
-        signature: "max({expr})",
-        description: "Return maximum value in list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "reduce",
-        signature: "reduce({object}, {func} [, {initial}])",
-        description: "Reduce list to single value",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "mapnew",
-        signature: "mapnew({expr1}, {expr2})",
-        description: "Like map() but creates new list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "extendnew",
-        signature: "extendnew({expr1}, {expr2} [, {expr3}])",
-        description: "Like extend() but creates new list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "flattennew",
-        signature: "flattennew({list} [, {maxdepth}])",
-        description: "Like flatten() but creates new list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "indexof",
-        signature: "indexof({object}, {expr} [, {opts}])",
-        description: "Return index where {expr} is true",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getqflist",
-        signature: "getqflist([{what}])",
-        description: "Return quickfix list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setqflist",
-        signature: "setqflist({list} [, {action} [, {what}]])",
-        description: "Set quickfix list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getloclist",
-        signature: "getloclist({nr} [, {what}])",
-        description: "Return location list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setloclist",
-        signature: "setloclist({nr}, {list} [, {action} [, {what}]])",
-        description: "Set location list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getjumplist",
-        signature: "getjumplist([{winnr} [, {tabnr}]])",
-        description: "Return jump list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getchangelist",
-        signature: "getchangelist([{buf}])",
-        description: "Return change list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "taglist",
-        signature: "taglist({expr} [, {filename}])",
-        description: "Return list of matching tags",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "tagfiles",
-        signature: "tagfiles()",
-        description: "Return list of tag files",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "gettagstack",
-        signature: "gettagstack([{winnr}])",
-        description: "Return tag stack",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "settagstack",
-        signature: "settagstack({winnr}, {dict} [, {action}])",
-        description: "Set tag stack",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getreg",
-        signature: "getreg([{regname} [, 1 [, {list}]]])",
-        description: "Return contents of register",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setreg",
-        signature: "setreg({regname}, {value} [, {options}])",
-        description: "Set register contents",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getregtype",
-        signature: "getregtype([{regname}])",
-        description: "Return type of register",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getreginfo",
-        signature: "getreginfo([{regname}])",
-        description: "Return info about register",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "synID",
-        signature: "synID({lnum}, {col}, {trans})",
-        description: "Return syntax ID at position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "synIDattr",
-        signature: "synIDattr({synID}, {what} [, {mode}])",
-        description: "Return attribute of syntax ID",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "synIDtrans",
-        signature: "synIDtrans({synID})",
-        description: "Return translated syntax ID",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "synstack",
-        signature: "synstack({lnum}, {col})",
-        description: "Return syntax ID stack at position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "synconcealed",
-        signature: "synconcealed({lnum}, {col})",
-        description: "Return concealed info at position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "hlID",
-        signature: "hlID({name})",
-        description: "Return highlight ID of {name}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "hlexists",
-        signature: "hlexists({name})",
-        description: "Return TRUE if highlight {name} exists",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "hlget",
-        signature: "hlget([{name} [, {resolve}]])",
-        description: "Return highlight definition",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "hlset",
-        signature: "hlset({list})",
-        description: "Set highlight definitions",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "complete",
-        signature: "complete({startcol}, {matches})",
-        description: "Set completion matches",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "complete_add",
-        signature: "complete_add({expr})",
-        description: "Add completion match",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "complete_check",
-        signature: "complete_check()",
-        description: "Return TRUE if completion interrupted",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "complete_info",
-        signature: "complete_info([{what}])",
-        description: "Return completion information",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "pumvisible",
-        signature: "pumvisible()",
-        description: "Return TRUE if popup menu visible",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "pum_getpos",
-        signature: "pum_getpos()",
-        description: "Return position of popup menu",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcmdline",
-        signature: "getcmdline()",
-        description: "Return current command line",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setcmdline",
-        signature: "setcmdline({str} [, {pos}])",
-        description: "Set command line contents",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcmdpos",
-        signature: "getcmdpos()",
-        description: "Return cursor position in cmdline",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setcmdpos",
-        signature: "setcmdpos({pos})",
-        description: "Set cursor position in cmdline",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcmdtype",
-        signature: "getcmdtype()",
-        description: "Return current command line type",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcmdwintype",
-        signature: "getcmdwintype()",
-        description: "Return command line window type",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcompletion",
-        signature: "getcompletion({pat}, {type} [, {filtered}])",
-        description: "Return command line completions",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "and",
-        signature: "and({expr}, {expr})",
-        description: "Bitwise AND",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "or",
-        signature: "or({expr}, {expr})",
-        description: "Bitwise OR",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "xor",
-        signature: "xor({expr}, {expr})",
-        description: "Bitwise XOR",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "invert",
-        signature: "invert({expr})",
-        description: "Bitwise invert",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "sha256",
-        signature: "sha256({string})",
-        description: "Return SHA256 checksum",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "rand",
-        signature: "rand([{expr}])",
-        description: "Return pseudo-random number",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "srand",
-        signature: "srand([{expr}])",
-        description: "Initialize random number seed",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "state",
-        signature: "state([{what}])",
-        description: "Return current state of Vim",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "undofile",
-        signature: "undofile({name})",
-        description: "Return undo file name for {name}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "undotree",
-        signature: "undotree([{buf}])",
-        description: "Return undo tree",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "shiftwidth",
-        signature: "shiftwidth([{col}])",
-        description: "Return effective shiftwidth value",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "wordcount",
-        signature: "wordcount()",
-        description: "Return word count statistics",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "nextnonblank",
-        signature: "nextnonblank({lnum})",
-        description: "Return line nr of next non-blank",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prevnonblank",
-        signature: "prevnonblank({lnum})",
-        description: "Return line nr of prev non-blank",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "byte2line",
-        signature: "byte2line({byte})",
-        description: "Return line number at byte count",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "line2byte",
-        signature: "line2byte({lnum})",
-        description: "Return byte count at line",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "diff_filler",
-        signature: "diff_filler({lnum})",
-        description: "Return filler lines at {lnum}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "diff_hlID",
-        signature: "diff_hlID({lnum}, {col})",
-        description: "Return diff highlight ID",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "foldclosed",
-        signature: "foldclosed({lnum})",
-        description: "Return first line of fold at {lnum}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "foldclosedend",
-        signature: "foldclosedend({lnum})",
-        description: "Return last line of fold at {lnum}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "foldlevel",
-        signature: "foldlevel({lnum})",
-        description: "Return fold level at {lnum}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "foldtext",
-        signature: "foldtext()",
-        description: "Return text for closed fold",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "foldtextresult",
-        signature: "foldtextresult({lnum})",
-        description: "Return text displayed for fold",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "screenattr",
-        signature: "screenattr({row}, {col})",
-        description: "Return attribute at screen position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "screenchar",
-        signature: "screenchar({row}, {col})",
-        description: "Return character at screen position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "screenchars",
-        signature: "screenchars({row}, {col})",
-        description: "Return characters at screen position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "screenstring",
-        signature: "screenstring({row}, {col})",
-        description: "Return string at screen position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "screenpos",
-        signature: "screenpos({winid}, {lnum}, {col})",
-        description: "Return screen position of text",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "screencol",
-        signature: "screencol()",
-        description: "Return cursor screen column",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "screenrow",
-        signature: "screenrow()",
-        description: "Return cursor screen row",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "stdpath",
-        signature: "stdpath({what})",
-        description: "Return standard path locations",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "api_info",
-        signature: "api_info()",
-        description: "Return API metadata",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "sign_define",
-        signature: "sign_define({name} [, {dict}])",
-        description: "Define or update a sign",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "sign_getdefined",
-        signature: "sign_getdefined([{name}])",
-        description: "Return list of defined signs",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "sign_getplaced",
-        signature: "sign_getplaced([{buf} [, {dict}]])",
-        description: "Return list of placed signs",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "sign_jump",
-        signature: "sign_jump({id}, {group}, {buf})",
-        description: "Jump to a placed sign",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "sign_place",
-        signature: "sign_place({id}, {group}, {name}, {buf} [, {dict}])",
-        description: "Place a sign",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "sign_placelist",
-        signature: "sign_placelist({list})",
-        description: "Place multiple signs",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "sign_undefine",
-        signature: "sign_undefine([{name}])",
-        description: "Undefine signs",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "sign_unplace",
-        signature: "sign_unplace({group} [, {dict}])",
-        description: "Unplace signs",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "sign_unplacelist",
-        signature: "sign_unplacelist({list})",
-        description: "Unplace multiple signs",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prop_add",
-        signature: "prop_add({lnum}, {col}, {props})",
-        description: "Add a text property",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prop_add_list",
-        signature: "prop_add_list({props}, {items})",
-        description: "Add text properties to multiple positions",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prop_clear",
-        signature: "prop_clear({lnum} [, {lnum_end} [, {props}]])",
-        description: "Clear text properties",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prop_find",
-        signature: "prop_find({props} [, {direction}])",
-        description: "Find a text property",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prop_list",
-        signature: "prop_list({lnum} [, {props}])",
-        description: "Return list of text properties",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prop_remove",
-        signature: "prop_remove({props} [, {lnum} [, {lnum_end}]])",
-        description: "Remove text properties",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prop_type_add",
-        signature: "prop_type_add({name}, {props})",
-        description: "Add a text property type",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prop_type_change",
-        signature: "prop_type_change({name}, {props})",
-        description: "Change a text property type",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prop_type_delete",
-        signature: "prop_type_delete({name} [, {props}])",
-        description: "Delete a text property type",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prop_type_get",
-        signature: "prop_type_get({name} [, {props}])",
-        description: "Return text property type definition",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prop_type_list",
-        signature: "prop_type_list([{props}])",
-        description: "Return list of text property types",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "spellbadword",
-        signature: "spellbadword([{sentence}])",
-        description: "Return misspelled word at cursor",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "spellsuggest",
-        signature: "spellsuggest({word} [, {max} [, {capital}]])",
-        description: "Return spelling suggestions",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "histadd",
-        signature: "histadd({history}, {item})",
-        description: "Add item to history",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "histdel",
-        signature: "histdel({history} [, {item}])",
-        description: "Delete item from history",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "histget",
-        signature: "histget({history} [, {index}])",
-        description: "Return item from history",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "histnr",
-        signature: "histnr({history})",
-        description: "Return number of items in history",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "assert_equal",
-        signature: "assert_equal({expected}, {actual} [, {msg}])",
-        description: "Assert two values are equal",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "assert_notequal",
-        signature: "assert_notequal({expected}, {actual} [, {msg}])",
-        description: "Assert two values are not equal",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "assert_true",
-        signature: "assert_true({actual} [, {msg}])",
-        description: "Assert value is true",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "assert_false",
-        signature: "assert_false({actual} [, {msg}])",
-        description: "Assert value is false",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "assert_match",
-        signature: "assert_match({pattern}, {actual} [, {msg}])",
-        description: "Assert value matches pattern",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "assert_notmatch",
-        signature: "assert_notmatch({pattern}, {actual} [, {msg}])",
-        description: "Assert value does not match pattern",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "assert_exception",
-        signature: "assert_exception({error} [, {msg}])",
-        description: "Assert exception was thrown",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "assert_beeps",
-        signature: "assert_beeps({cmd})",
-        description: "Assert command causes a beep",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "assert_nobeep",
-        signature: "assert_nobeep({cmd})",
-        description: "Assert command does not beep",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "assert_fails",
-        signature: "assert_fails({cmd} [, {error} [, {msg} [, {lnum} [, {context}]]]])",
-        description: "Assert command fails with error",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "assert_inrange",
-        signature: "assert_inrange({lower}, {upper}, {actual} [, {msg}])",
-        description: "Assert value is in range",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "assert_report",
-        signature: "assert_report({msg})",
-        description: "Report a test failure",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "listener_add",
-        signature: "listener_add({callback} [, {buf}])",
-        description: "Add a buffer change listener",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "listener_flush",
-        signature: "listener_flush([{buf}])",
-        description: "Invoke listeners for buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "listener_remove",
-        signature: "listener_remove({id})",
-        description: "Remove a listener",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "maparg",
-        signature: "maparg({name} [, {mode} [, {abbr} [, {dict}]]])",
-        description: "Return mapping definition",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "mapcheck",
-        signature: "mapcheck({name} [, {mode} [, {abbr}]])",
-        description: "Check if mapping exists",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "mapset",
-        signature: "mapset({mode}, {abbr}, {dict})",
-        description: "Set a mapping from dict",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "maplist",
-        signature: "maplist([{abbr}])",
-        description: "Return list of all mappings",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "hasmapto",
-        signature: "hasmapto({what} [, {mode} [, {abbr}]])",
-        description: "Check if mapping to {what} exists",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "autocmd_add",
-        signature: "autocmd_add({acmds})",
-        description: "Add autocommands from list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "autocmd_delete",
-        signature: "autocmd_delete({acmds})",
-        description: "Delete autocommands",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "autocmd_get",
-        signature: "autocmd_get([{opts}])",
-        description: "Return list of autocommands",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "changenr",
-        signature: "changenr()",
-        description: "Return current change number",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "iconv",
-        signature: "iconv({string}, {from}, {to})",
-        description: "Convert encoding of {string}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "serverlist",
-        signature: "serverlist()",
-        description: "Return list of server names",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "remote_expr",
-        signature: "remote_expr({server}, {string} [, {idvar} [, {timeout}]])",
-        description: "Send expression to server",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "remote_foreground",
-        signature: "remote_foreground({server})",
-        description: "Bring server to foreground",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "remote_peek",
-        signature: "remote_peek({serverid} [, {retvar}])",
-        description: "Check for server reply",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "remote_read",
-        signature: "remote_read({serverid} [, {timeout}])",
-        description: "Read reply from server",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "remote_send",
-        signature: "remote_send({server}, {string} [, {idvar}])",
-        description: "Send keys to server",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "remote_startserver",
-        signature: "remote_startserver({name})",
-        description: "Start server with {name}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "libcall",
-        signature: "libcall({lib}, {func}, {arg})",
-        description: "Call function in library",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "libcallnr",
-        signature: "libcallnr({lib}, {func}, {arg})",
-        description: "Call function in library, return number",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "luaeval",
-        signature: "luaeval({expr} [, {arg}])",
-        description: "Evaluate Lua expression",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "perleval",
-        signature: "perleval({expr})",
-        description: "Evaluate Perl expression",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "py3eval",
-        signature: "py3eval({expr})",
-        description: "Evaluate Python3 expression",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "pyeval",
-        signature: "pyeval({expr})",
-        description: "Evaluate Python expression",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "pyxeval",
-        signature: "pyxeval({expr})",
-        description: "Evaluate Python expression (2 or 3)",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "rubyeval",
-        signature: "rubyeval({expr})",
-        description: "Evaluate Ruby expression",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_create",
-        signature: "popup_create({what}, {options})",
-        description: "Create a popup window",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_atcursor",
-        signature: "popup_atcursor({what}, {options})",
-        description: "Create popup at cursor position",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_beval",
-        signature: "popup_beval({what}, {options})",
-        description: "Create popup for balloon eval",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_notification",
-        signature: "popup_notification({what}, {options})",
-        description: "Create a notification popup",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_dialog",
-        signature: "popup_dialog({what}, {options})",
-        description: "Create a dialog popup",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_menu",
-        signature: "popup_menu({what}, {options})",
-        description: "Create a menu popup",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_hide",
-        signature: "popup_hide({id})",
-        description: "Hide a popup",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_show",
-        signature: "popup_show({id})",
-        description: "Show a hidden popup",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_move",
-        signature: "popup_move({id}, {options})",
-        description: "Move popup to new position",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_setoptions",
-        signature: "popup_setoptions({id}, {options})",
-        description: "Set popup options",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_settext",
-        signature: "popup_settext({id}, {text})",
-        description: "Set popup text",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_close",
-        signature: "popup_close({id} [, {result}])",
-        description: "Close popup",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_clear",
-        signature: "popup_clear([{force}])",
-        description: "Close all popups",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_filter_menu",
-        signature: "popup_filter_menu({id}, {key})",
-        description: "Filter for popup menu",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_filter_yesno",
-        signature: "popup_filter_yesno({id}, {key})",
-        description: "Filter for yes/no popup",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_getoptions",
-        signature: "popup_getoptions({id})",
-        description: "Return popup options",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_getpos",
-        signature: "popup_getpos({id})",
-        description: "Return popup position",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_findinfo",
-        signature: "popup_findinfo()",
-        description: "Return info popup window ID",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_findpreview",
-        signature: "popup_findpreview()",
-        description: "Return preview popup window ID",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_list",
-        signature: "popup_list()",
-        description: "Return list of all popup IDs",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_locate",
-        signature: "popup_locate({row}, {col})",
-        description: "Return popup ID at screen position",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_canread",
-        signature: "ch_canread({handle})",
-        description: "Return TRUE if channel can be read",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_close",
-        signature: "ch_close({handle})",
-        description: "Close channel",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_close_in",
-        signature: "ch_close_in({handle})",
-        description: "Close input part of channel",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_evalexpr",
-        signature: "ch_evalexpr({handle}, {expr} [, {options}])",
-        description: "Send expression over channel, return response",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_evalraw",
-        signature: "ch_evalraw({handle}, {string} [, {options}])",
-        description: "Send raw string over channel",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_getbufnr",
-        signature: "ch_getbufnr({handle}, {what})",
-        description: "Return buffer number for channel",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_getjob",
-        signature: "ch_getjob({handle})",
-        description: "Return job for channel",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_info",
-        signature: "ch_info({handle})",
-        description: "Return info about channel",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_log",
-        signature: "ch_log({msg} [, {handle}])",
-        description: "Write message to channel log",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_logfile",
-        signature: "ch_logfile({fname} [, {mode}])",
-        description: "Start logging channel activity",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_open",
-        signature: "ch_open({address} [, {options}])",
-        description: "Open channel to {address}",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_read",
-        signature: "ch_read({handle} [, {options}])",
-        description: "Read from channel",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_readblob",
-        signature: "ch_readblob({handle} [, {options}])",
-        description: "Read blob from channel",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_readraw",
-        signature: "ch_readraw({handle} [, {options}])",
-        description: "Read raw string from channel",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_sendexpr",
-        signature: "ch_sendexpr({handle}, {expr} [, {options}])",
-        description: "Send expression over channel",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_sendraw",
-        signature: "ch_sendraw({handle}, {expr} [, {options}])",
-        description: "Send raw string over channel",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_setoptions",
-        signature: "ch_setoptions({handle}, {options})",
-        description: "Set channel options",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "ch_status",
-        signature: "ch_status({handle} [, {options}])",
-        description: "Return status of channel",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "job_getchannel",
-        signature: "job_getchannel({job})",
-        description: "Return channel for job",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "job_info",
-        signature: "job_info([{job}])",
-        description: "Return info about job",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "job_setoptions",
-        signature: "job_setoptions({job}, {options})",
-        description: "Set job options",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "job_start",
-        signature: "job_start({command} [, {options}])",
-        description: "Start a job",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "job_status",
-        signature: "job_status({job})",
-        description: "Return status of job",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "job_stop",
-        signature: "job_stop({job} [, {how}])",
-        description: "Stop a job",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_start",
-        signature: "term_start({cmd} [, {options}])",
-        description: "Start terminal in new window",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_list",
-        signature: "term_list()",
-        description: "Return list of terminal buffers",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_sendkeys",
-        signature: "term_sendkeys({buf}, {keys})",
-        description: "Send keys to terminal",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_wait",
-        signature: "term_wait({buf} [, {time}])",
-        description: "Wait for terminal to update",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_getjob",
-        signature: "term_getjob({buf})",
-        description: "Return job for terminal",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_getline",
-        signature: "term_getline({buf}, {row})",
-        description: "Return line from terminal",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_getscrolled",
-        signature: "term_getscrolled({buf})",
-        description: "Return scrolled lines count",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_getsize",
-        signature: "term_getsize({buf})",
-        description: "Return terminal size",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_getstatus",
-        signature: "term_getstatus({buf})",
-        description: "Return terminal status",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_gettitle",
-        signature: "term_gettitle({buf})",
-        description: "Return terminal title",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_gettty",
-        signature: "term_gettty({buf} [, {input}])",
-        description: "Return tty of terminal",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_setansicolors",
-        signature: "term_setansicolors({buf}, {colors})",
-        description: "Set ANSI colors for terminal",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_getansicolors",
-        signature: "term_getansicolors({buf})",
-        description: "Return ANSI colors of terminal",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_setapi",
-        signature: "term_setapi({buf}, {expr})",
-        description: "Set API function prefix for terminal",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_setkill",
-        signature: "term_setkill({buf}, {how})",
-        description: "Set how to kill terminal job",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_setrestore",
-        signature: "term_setrestore({buf}, {command})",
-        description: "Set command to restore terminal",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_setsize",
-        signature: "term_setsize({buf}, {rows}, {cols})",
-        description: "Set terminal size",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_dumpdiff",
-        signature: "term_dumpdiff({filename}, {filename} [, {options}])",
-        description: "Show diff of terminal dumps",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_dumpload",
-        signature: "term_dumpload({filename} [, {options}])",
-        description: "Load terminal dump in window",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_dumpwrite",
-        signature: "term_dumpwrite({buf}, {filename} [, {options}])",
-        description: "Write terminal dump to file",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_getattr",
-        signature: "term_getattr({attr}, {what})",
-        description: "Return attribute of terminal cell",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_getcursor",
-        signature: "term_getcursor({buf})",
-        description: "Return cursor position in terminal",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_create_buf",
-        signature: "nvim_create_buf({listed}, {scratch})",
-        description: "Create a new buffer",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_open_win",
-        signature: "nvim_open_win({buffer}, {enter}, {config})",
-        description: "Open a floating window",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_win_set_config",
-        signature: "nvim_win_set_config({window}, {config})",
-        description: "Set window config",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_win_get_config",
-        signature: "nvim_win_get_config({window})",
-        description: "Get window config",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_win_close",
-        signature: "nvim_win_close({window}, {force})",
-        description: "Close window",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_set_lines",
-        signature: "nvim_buf_set_lines({buffer}, {start}, {end}, {strict}, {replacement})",
-        description: "Set buffer lines",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_get_lines",
-        signature: "nvim_buf_get_lines({buffer}, {start}, {end}, {strict})",
-        description: "Get buffer lines",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_set_text",
-        signature: "nvim_buf_set_text({buffer}, {start_row}, {start_col}, {end_row}, {end_col}, {replacement})",
-        description: "Set text in buffer region",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_get_text",
-        signature: "nvim_buf_get_text({buffer}, {start_row}, {start_col}, {end_row}, {end_col}, {opts})",
-        description: "Get text from buffer region",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_set_extmark",
-        signature: "nvim_buf_set_extmark({buffer}, {ns_id}, {line}, {col}, {opts})",
-        description: "Create or update extmark",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_get_extmarks",
-        signature: "nvim_buf_get_extmarks({buffer}, {ns_id}, {start}, {end}, {opts})",
-        description: "Get extmarks in range",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_del_extmark",
-        signature: "nvim_buf_del_extmark({buffer}, {ns_id}, {id})",
-        description: "Delete extmark",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_create_namespace",
-        signature: "nvim_create_namespace({name})",
-        description: "Create namespace for extmarks",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_set_hl",
-        signature: "nvim_set_hl({ns_id}, {name}, {val})",
-        description: "Set highlight group",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_hl",
-        signature: "nvim_get_hl({ns_id}, {opts})",
-        description: "Get highlight definition",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "matchstrpos",
-        signature: "matchstrpos({string}, {pattern} [, {start} [, {count}]])",
-        description: "Return match and positions",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "bufwinid",
-        signature: "bufwinid({buf})",
-        description: "Return window ID of buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "tabpagewinnr",
-        signature: "tabpagewinnr({tabarg} [, {arg}])",
-        description: "Return window number in tab page",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "islocked",
-        signature: "islocked({expr})",
-        description: "Return TRUE if {expr} is locked",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setcellwidths",
-        signature: "setcellwidths({list})",
-        description: "Set character cell widths",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcellwidths",
-        signature: "getcellwidths()",
-        description: "Return character cell width overrides",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "charclass",
-        signature: "charclass({string})",
-        description: "Return character class",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcharpos",
-        signature: "getcharpos({expr})",
-        description: "Return character position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getmousepos",
-        signature: "getmousepos()",
-        description: "Return mouse position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getscriptinfo",
-        signature: "getscriptinfo([{opts}])",
-        description: "Return list of sourced scripts",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "gettext",
-        signature: "gettext({text})",
-        description: "Return translated text",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "searchcount",
-        signature: "searchcount([{options}])",
-        description: "Return search match count info",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "searchdecl",
-        signature: "searchdecl({name} [, {global} [, {thisblock}]])",
-        description: "Search for declaration",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setcmdline",
-        signature: "setcmdline({str} [, {pos}])",
-        description: "Set command line text",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setcharpos",
-        signature: "setcharpos({expr}, {list})",
-        description: "Set character position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setcharsearch",
-        signature: "setcharsearch({dict})",
-        description: "Set character search settings",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcharsearch",
-        signature: "getcharsearch()",
-        description: "Return character search settings",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setcursorcharpos",
-        signature: "setcursorcharpos({list})",
-        description: "Set cursor character position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcmdcompltype",
-        signature: "getcmdcompltype()",
-        description: "Return current command completion type",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcmdscreenpos",
-        signature: "getcmdscreenpos()",
-        description: "Return cursor screen position in cmdline",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "fullcommand",
-        signature: "fullcommand({name} [, {vim9}])",
-        description: "Return full command name",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getbufoneline",
-        signature: "getbufoneline({buf}, {lnum})",
-        description: "Return single line from buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "echoraw",
-        signature: "echoraw({string})",
-        description: "Output string without processing",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "keytrans",
-        signature: "keytrans({string})",
-        description: "Translate key codes to readable form",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setbufvar",
-        signature: "setbufvar({buf}, {varname}, {val})",
-        description: "Set buffer variable",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "setwinvar",
-        signature: "setwinvar({winnr}, {varname}, {val})",
-        description: "Set window variable",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "settabvar",
-        signature: "settabvar({tabnr}, {varname}, {val})",
-        description: "Set tab variable",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcursorcharpos",
-        signature: "getcursorcharpos([{winnr}])",
-        description: "Return cursor character position",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "virtcol2col",
-        signature: "virtcol2col({winid}, {lnum}, {col})",
-        description: "Convert virtual column to byte column",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "blob2list",
-        signature: "blob2list({blob})",
-        description: "Convert blob to list of numbers",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "list2blob",
-        signature: "list2blob({list})",
-        description: "Convert list of numbers to blob",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "sound_clear",
-        signature: "sound_clear()",
-        description: "Stop all sounds",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "sound_playevent",
-        signature: "sound_playevent({name} [, {callback}])",
-        description: "Play a sound event",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "sound_playfile",
-        signature: "sound_playfile({path} [, {callback}])",
-        description: "Play a sound file",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "sound_stop",
-        signature: "sound_stop({id})",
-        description: "Stop playing a sound",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "digraph_get",
-        signature: "digraph_get({chars})",
-        description: "Return digraph for {chars}",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "digraph_getlist",
-        signature: "digraph_getlist([{listall}])",
-        description: "Return list of digraphs",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "digraph_set",
-        signature: "digraph_set({chars}, {digraph})",
-        description: "Set a digraph",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "digraph_setlist",
-        signature: "digraph_setlist({list})",
-        description: "Set multiple digraphs",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prompt_getprompt",
-        signature: "prompt_getprompt({buf})",
-        description: "Return prompt text of buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prompt_setcallback",
-        signature: "prompt_setcallback({buf}, {callback})",
-        description: "Set callback for prompt buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prompt_setinterrupt",
-        signature: "prompt_setinterrupt({buf}, {callback})",
-        description: "Set interrupt callback for prompt",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "prompt_setprompt",
-        signature: "prompt_setprompt({buf}, {text})",
-        description: "Set prompt text for buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "timer_info",
-        signature: "timer_info([{id}])",
-        description: "Return information about timers",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "timer_pause",
-        signature: "timer_pause({id}, {pause})",
-        description: "Pause or unpause a timer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "reg_executing",
-        signature: "reg_executing()",
-        description: "Return register being executed",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "reg_recording",
-        signature: "reg_recording()",
-        description: "Return register being recorded to",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "browse",
-        signature: "browse({save}, {title}, {initdir}, {default})",
-        description: "Open file browser dialog",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "browsedir",
-        signature: "browsedir({title}, {initdir})",
-        description: "Open directory browser dialog",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "menu_info",
-        signature: "menu_info({name} [, {mode}])",
-        description: "Return information about a menu",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "eventhandler",
-        signature: "eventhandler()",
-        description: "Return TRUE if in event handler",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "interrupt",
-        signature: "interrupt()",
-        description: "Interrupt script execution",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "win_move_separator",
-        signature: "win_move_separator({nr}, {offset})",
-        description: "Move window vertical separator",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "win_move_statusline",
-        signature: "win_move_statusline({nr}, {offset})",
-        description: "Move window status line",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "mzeval",
-        signature: "mzeval({expr})",
-        description: "Evaluate MzScheme expression",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "debugbreak",
-        signature: "debugbreak({pid})",
-        description: "Interrupt process for debugging",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "balloon_gettext",
-        signature: "balloon_gettext()",
-        description: "Return current balloon text",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "balloon_show",
-        signature: "balloon_show({expr})",
-        description: "Show balloon with text",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "balloon_split",
-        signature: "balloon_split({msg})",
-        description: "Split message for balloon",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "getimstatus",
-        signature: "getimstatus()",
-        description: "Return IM status",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "id",
-        signature: "id({expr})",
-        description: "Return unique identifier for reference",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "nvim_win_set_cursor",
-        signature: "nvim_win_set_cursor({window}, {pos})",
-        description: "Set cursor position in window",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_win_get_cursor",
-        signature: "nvim_win_get_cursor({window})",
-        description: "Get cursor position in window",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_line_count",
-        signature: "nvim_buf_line_count({buffer})",
-        description: "Return line count of buffer",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_get_name",
-        signature: "nvim_buf_get_name({buffer})",
-        description: "Return buffer name",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_set_name",
-        signature: "nvim_buf_set_name({buffer}, {name})",
-        description: "Set buffer name",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_is_valid",
-        signature: "nvim_buf_is_valid({buffer})",
-        description: "Check if buffer is valid",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_delete",
-        signature: "nvim_buf_delete({buffer}, {opts})",
-        description: "Delete buffer",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_list_bufs",
-        signature: "nvim_list_bufs()",
-        description: "List all buffers",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_list_wins",
-        signature: "nvim_list_wins()",
-        description: "List all windows",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_current_buf",
-        signature: "nvim_get_current_buf()",
-        description: "Return current buffer handle",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_current_win",
-        signature: "nvim_get_current_win()",
-        description: "Return current window handle",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_set_current_buf",
-        signature: "nvim_set_current_buf({buffer})",
-        description: "Set current buffer",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_set_current_win",
-        signature: "nvim_set_current_win({window})",
-        description: "Set current window",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_echo",
-        signature: "nvim_echo({chunks}, {history}, {opts})",
-        description: "Echo message with highlights",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_notify",
-        signature: "nvim_notify({msg}, {log_level}, {opts})",
-        description: "Show notification message",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_exec_lua",
-        signature: "nvim_exec_lua({code}, {args})",
-        description: "Execute Lua code",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_command",
-        signature: "nvim_command({command})",
-        description: "Execute ex command",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_eval",
-        signature: "nvim_eval({expr})",
-        description: "Evaluate vimscript expression",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_call_function",
-        signature: "nvim_call_function({fn}, {args})",
-        description: "Call vimscript function",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_replace_termcodes",
-        signature: "nvim_replace_termcodes({str}, {from_part}, {do_lt}, {special})",
-        description: "Replace terminal codes",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_feedkeys",
-        signature: "nvim_feedkeys({keys}, {mode}, {escape_ks})",
-        description: "Feed keys to Neovim",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_input",
-        signature: "nvim_input({keys})",
-        description: "Queue raw user input",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_mode",
-        signature: "nvim_get_mode()",
-        description: "Return current mode",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_option_value",
-        signature: "nvim_get_option_value({name}, {opts})",
-        description: "Get option value",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_set_option_value",
-        signature: "nvim_set_option_value({name}, {value}, {opts})",
-        description: "Set option value",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_var",
-        signature: "nvim_get_var({name})",
-        description: "Get global variable",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_set_var",
-        signature: "nvim_set_var({name}, {value})",
-        description: "Set global variable",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_del_var",
-        signature: "nvim_del_var({name})",
-        description: "Delete global variable",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_get_var",
-        signature: "nvim_buf_get_var({buffer}, {name})",
-        description: "Get buffer variable",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_set_var",
-        signature: "nvim_buf_set_var({buffer}, {name}, {value})",
-        description: "Set buffer variable",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_win_get_var",
-        signature: "nvim_win_get_var({window}, {name})",
-        description: "Get window variable",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_win_set_var",
-        signature: "nvim_win_set_var({window}, {name}, {value})",
-        description: "Set window variable",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_create_augroup",
-        signature: "nvim_create_augroup({name}, {opts})",
-        description: "Create autocommand group",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_create_autocmd",
-        signature: "nvim_create_autocmd({event}, {opts})",
-        description: "Create autocommand",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_del_augroup_by_id",
-        signature: "nvim_del_augroup_by_id({id})",
-        description: "Delete autocommand group by ID",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_del_augroup_by_name",
-        signature: "nvim_del_augroup_by_name({name})",
-        description: "Delete autocommand group by name",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_del_autocmd",
-        signature: "nvim_del_autocmd({id})",
-        description: "Delete autocommand",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_set_keymap",
-        signature: "nvim_set_keymap({mode}, {lhs}, {rhs}, {opts})",
-        description: "Set global keymap",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_del_keymap",
-        signature: "nvim_del_keymap({mode}, {lhs})",
-        description: "Delete global keymap",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_set_keymap",
-        signature: "nvim_buf_set_keymap({buffer}, {mode}, {lhs}, {rhs}, {opts})",
-        description: "Set buffer-local keymap",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_del_keymap",
-        signature: "nvim_buf_del_keymap({buffer}, {mode}, {lhs})",
-        description: "Delete buffer-local keymap",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "argc",
-        signature: "argc([{winid}])",
-        description: "Return number of files in argument list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "argidx",
-        signature: "argidx()",
-        description: "Return current index in argument list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "arglistid",
-        signature: "arglistid([{winnr} [, {tabnr}]])",
-        description: "Return argument list ID",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "argv",
-        signature: "argv([{nr} [, {winid}]])",
-        description: "Return argument from argument list",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "base64_decode",
-        signature: "base64_decode({string})",
-        description: "Decode base64 encoded string",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "base64_encode",
-        signature: "base64_encode({blob})",
-        description: "Encode blob to base64 string",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "blob2str",
-        signature: "blob2str({blob})",
-        description: "Convert blob to string",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "str2blob",
-        signature: "str2blob({string})",
-        description: "Convert string to blob",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "bufwinnr",
-        signature: "bufwinnr({buf})",
-        description: "Return window number of buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "cindent",
-        signature: "cindent({lnum})",
-        description: "Return C indent for line",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "lispindent",
-        signature: "lispindent({lnum})",
-        description: "Return Lisp indent for line",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "indent",
-        signature: "indent({lnum})",
-        description: "Return indent of line",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "cmdcomplete_info",
-        signature: "cmdcomplete_info([{what}])",
-        description: "Return command line completion info",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "cscope_connection",
-        signature: "cscope_connection([{num} [, {dbpath} [, {prepend}]]])",
-        description: "Check cscope connection",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "did_filetype",
-        signature: "did_filetype()",
-        description: "Return TRUE if FileType autocommand was used",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "diff",
-        signature: "diff({fromlist}, {tolist} [, {options}])",
-        description: "Return diff between two lists",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "expandcmd",
-        signature: "expandcmd({string} [, {options}])",
-        description: "Expand special items in command string",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "finddir",
-        signature: "finddir({name} [, {path} [, {count}]])",
-        description: "Find directory in path",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "findfile",
-        signature: "findfile({name} [, {path} [, {count}]])",
-        description: "Find file in path",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "foreground",
-        signature: "foreground()",
-        description: "Bring Vim window to foreground",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "garbagecollect",
-        signature: "garbagecollect([{atexit}])",
-        description: "Free unused memory",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getfontname",
-        signature: "getfontname([{name}])",
-        description: "Return name of current font",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "getmouseshape",
-        signature: "getmouseshape()",
-        description: "Return current mouse shape name",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "getregion",
-        signature: "getregion({pos1}, {pos2} [, {opts}])",
-        description: "Return text in region",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getregionpos",
-        signature: "getregionpos({pos1}, {pos2} [, {opts}])",
-        description: "Return positions of region",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getwinpos",
-        signature: "getwinpos([{timeout}])",
-        description: "Return [X, Y] of GUI Vim window",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getwinposx",
-        signature: "getwinposx()",
-        description: "Return X position of GUI Vim window",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getwinposy",
-        signature: "getwinposy()",
-        description: "Return Y position of GUI Vim window",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "glob2regpat",
-        signature: "glob2regpat({string})",
-        description: "Convert glob pattern to regex",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "haslocaldir",
-        signature: "haslocaldir([{winnr} [, {tabnr}]])",
-        description: "Return TRUE if local directory is set",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "instanceof",
-        signature: "instanceof({object}, {class})",
-        description: "Return TRUE if object is instance of class",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "isinf",
-        signature: "isinf({expr})",
-        description: "Return TRUE if value is infinity",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "isnan",
-        signature: "isnan({expr})",
-        description: "Return TRUE if value is NaN",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "matchbufline",
-        signature: "matchbufline({buf}, {pat}, {lnum}, {end} [, {dict}])",
-        description: "Return all matches in buffer lines",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "matchstrlist",
-        signature: "matchstrlist({list}, {pat} [, {dict}])",
-        description: "Return all matches in list of strings",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "popup_findecho",
-        signature: "popup_findecho()",
-        description: "Return echo popup window ID",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "readblob",
-        signature: "readblob({fname} [, {offset} [, {size}]])",
-        description: "Read file as blob",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "server2client",
-        signature: "server2client({clientid}, {string})",
-        description: "Send reply to client",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "slice",
-        signature: "slice({expr}, {start} [, {end}])",
-        description: "Return slice of list or blob",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "soundfold",
-        signature: "soundfold({word})",
-        description: "Return sound-folded word",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "string",
-        signature: "string({expr})",
-        description: "Convert expression to string",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "strtrans",
-        signature: "strtrans({string})",
-        description: "Translate unprintable characters",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "swapfilelist",
-        signature: "swapfilelist()",
-        description: "Return list of swap file names",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "swapinfo",
-        signature: "swapinfo({fname})",
-        description: "Return info about swap file",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "swapname",
-        signature: "swapname({buf})",
-        description: "Return swap file name for buffer",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "term_getaltscreen",
-        signature: "term_getaltscreen({buf})",
-        description: "Return alternate screen flag",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "term_scrape",
-        signature: "term_scrape({buf}, {row})",
-        description: "Return terminal screen contents",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "utf16idx",
-        signature: "utf16idx({string}, {idx} [, {countcc} [, {charidx}]])",
-        description: "Return UTF-16 index of byte index",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "assert_equalfile",
-        signature: "assert_equalfile({fname1}, {fname2} [, {msg}])",
-        description: "Assert two files have equal contents",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "bindtextdomain",
-        signature: "bindtextdomain({package}, {path})",
-        description: "Set path for message translations",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "wildmenumode",
-        signature: "wildmenumode()",
-        description: "Return TRUE if wildmenu is active",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "windowsversion",
-        signature: "windowsversion()",
-        description: "Return Windows version string",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "chanclose",
-        signature: "chanclose({id} [, {stream}])",
-        description: "Close a channel or a specific stream",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "chansend",
-        signature: "chansend({id}, {data})",
-        description: "Send data to channel",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "jobpid",
-        signature: "jobpid({job})",
-        description: "Return the PID of a job",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "jobresize",
-        signature: "jobresize({job}, {width}, {height})",
-        description: "Resize the pseudo terminal window of a job",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "jobstart",
-        signature: "jobstart({cmd} [, {opts}])",
-        description: "Spawn a job",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "jobstop",
-        signature: "jobstop({id})",
-        description: "Stop a job",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "jobwait",
-        signature: "jobwait({jobs} [, {timeout}])",
-        description: "Wait for jobs to complete",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "rpcnotify",
-        signature: "rpcnotify({channel}, {event} [, {args}...])",
-        description: "Send RPC notification to channel",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "rpcrequest",
-        signature: "rpcrequest({channel}, {method} [, {args}...])",
-        description: "Send RPC request to channel",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "serverstart",
-        signature: "serverstart([{address}])",
-        description: "Start listening for RPC messages",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "serverstop",
-        signature: "serverstop({address})",
-        description: "Stop listening on address",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "sockconnect",
-        signature: "sockconnect({mode}, {address} [, {opts}])",
-        description: "Connect a socket to an address",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "stdioopen",
-        signature: "stdioopen({opts})",
-        description: "Open stdin and stdout as a channel",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "msgpackdump",
-        signature: "msgpackdump({list} [, {type}])",
-        description: "Convert list to msgpack",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "msgpackparse",
-        signature: "msgpackparse({data})",
-        description: "Convert msgpack to list",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "ctxget",
-        signature: "ctxget([{index}])",
-        description: "Return context at index from context stack",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "ctxpop",
-        signature: "ctxpop()",
-        description: "Pop and restore context from stack",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "ctxpush",
-        signature: "ctxpush([{types}])",
-        description: "Push current context on stack",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "ctxset",
-        signature: "ctxset({context} [, {index}])",
-        description: "Set context at index on stack",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "ctxsize",
-        signature: "ctxsize()",
-        description: "Return size of context stack",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "dictwatcheradd",
-        signature: "dictwatcheradd({dict}, {pattern}, {callback})",
-        description: "Add a watcher to a dictionary",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "dictwatcherdel",
-        signature: "dictwatcherdel({dict}, {pattern}, {callback})",
-        description: "Remove a watcher from a dictionary",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "wait",
-        signature: "wait({timeout}, {condition} [, {interval}])",
-        description: "Wait until condition is true or timeout",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "err_teapot",
-        signature: "err_teapot([{expr}])",
-        description: "Produce error E418 or E503",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "exists_compiled",
-        signature: "exists_compiled({expr})",
-        description: "Check if expression exists at compile time",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "filecopy",
-        signature: "filecopy({from}, {to})",
-        description: "Copy file from one location to another",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "foreach",
-        signature: "foreach({expr1}, {expr2})",
-        description: "Call function for each item without modifying",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "getcellpixels",
-        signature: "getcellpixels()",
-        description: "Return terminal cell pixel size",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "getcmdcomplpat",
-        signature: "getcmdcomplpat()",
-        description: "Return completion pattern of command-line",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "getcmdprompt",
-        signature: "getcmdprompt()",
-        description: "Return current command-line prompt",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "getcompletiontype",
-        signature: "getcompletiontype({pat})",
-        description: "Return type of command-line completion",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "getstacktrace",
-        signature: "getstacktrace()",
-        description: "Return current stack trace",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "js_decode",
-        signature: "js_decode({string})",
-        description: "Decode JS style JSON",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "js_encode",
-        signature: "js_encode({expr})",
-        description: "Encode JS style JSON",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "menu_get",
-        signature: "menu_get({path} [, {modes}])",
-        description: "Return list of menus matching path",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "ngettext",
-        signature: "ngettext({single}, {plural}, {number} [, {domain}])",
-        description: "Return translated string based on number",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "popup_setbuf",
-        signature: "popup_setbuf({id}, {buf})",
-        description: "Set buffer for popup window",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "preinserted",
-        signature: "preinserted()",
-        description: "Return if text is pre-inserted after cursor",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "prompt_getinput",
-        signature: "prompt_getinput({buf})",
-        description: "Get input from prompt buffer",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "redraw_listener_add",
-        signature: "redraw_listener_add({callback})",
-        description: "Add a callback for redraw events",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "redraw_listener_remove",
-        signature: "redraw_listener_remove({id})",
-        description: "Remove a redraw listener callback",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "reg_recorded",
-        signature: "reg_recorded()",
-        description: "Return name of last recorded register",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "terminalprops",
-        signature: "terminalprops()",
-        description: "Return terminal properties dictionary",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_alloc_fail",
-        signature: "test_alloc_fail({id}, {countdown}, {repeat})",
-        description: "Make memory allocation fail for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_autochdir",
-        signature: "test_autochdir()",
-        description: "Enable autochdir during startup for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_feedinput",
-        signature: "test_feedinput({string})",
-        description: "Add key sequence to input buffer for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_garbagecollect_now",
-        signature: "test_garbagecollect_now()",
-        description: "Free memory immediately for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_garbagecollect_soon",
-        signature: "test_garbagecollect_soon()",
-        description: "Free memory soon for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_getvalue",
-        signature: "test_getvalue({string})",
-        description: "Get value of internal variable for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_gui_event",
-        signature: "test_gui_event({event}, {args})",
-        description: "Generate GUI event for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_ignore_error",
-        signature: "test_ignore_error({expr})",
-        description: "Ignore specific error for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_mswin_event",
-        signature: "test_mswin_event({event}, {args})",
-        description: "Generate MS-Windows event for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_null_blob",
-        signature: "test_null_blob()",
-        description: "Return null blob for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_null_channel",
-        signature: "test_null_channel()",
-        description: "Return null channel for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_null_dict",
-        signature: "test_null_dict()",
-        description: "Return null dict for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_null_function",
-        signature: "test_null_function()",
-        description: "Return null function for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_null_job",
-        signature: "test_null_job()",
-        description: "Return null job for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_null_list",
-        signature: "test_null_list()",
-        description: "Return null list for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_null_partial",
-        signature: "test_null_partial()",
-        description: "Return null partial for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_null_string",
-        signature: "test_null_string()",
-        description: "Return null string for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_null_tuple",
-        signature: "test_null_tuple()",
-        description: "Return null tuple for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_option_not_set",
-        signature: "test_option_not_set({name})",
-        description: "Reset flag indicating option was set",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_override",
-        signature: "test_override({expr}, {val})",
-        description: "Override Vim internal for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_refcount",
-        signature: "test_refcount({expr})",
-        description: "Get reference count for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_setmouse",
-        signature: "test_setmouse({row}, {col})",
-        description: "Set mouse position for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_settime",
-        signature: "test_settime({expr})",
-        description: "Set current time for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_srand_seed",
-        signature: "test_srand_seed([{seed}])",
-        description: "Set seed for srand testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_unknown",
-        signature: "test_unknown()",
-        description: "Return unknown value for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "test_void",
-        signature: "test_void()",
-        description: "Return void value for testing",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "uri_decode",
-        signature: "uri_decode({string})",
-        description: "URI-decode a string",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "uri_encode",
-        signature: "uri_encode({string})",
-        description: "URI-encode a string",
-        availability: Availability::VimOnly,
-    },
-    BuiltinFunction {
-        name: "wildtrigger",
-        signature: "wildtrigger()",
-        description: "Start wildcard expansion in command-line",
-        availability: Availability::Common,
-    },
-    BuiltinFunction {
-        name: "nvim__complete_set",
-        signature: "nvim__complete_set({index}, {opts})",
-        description: "Set info for completion item at given index (experimental)",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim__exec_lua_fast",
-        signature: "nvim__exec_lua_fast({code}, {args})",
-        description: "Execute Lua code during api-fast contexts (experimental)",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim__get_runtime",
-        signature: "nvim__get_runtime({pat}, {all}, {opts})",
-        description: "Find files in runtime directories",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim__id",
-        signature: "nvim__id({obj})",
-        description: "Returns object given as argument (testing)",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim__id_array",
-        signature: "nvim__id_array({arr})",
-        description: "Returns array given as argument (testing)",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim__id_dict",
-        signature: "nvim__id_dict({dct})",
-        description: "Returns dict given as argument (testing)",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim__id_float",
-        signature: "nvim__id_float({flt})",
-        description: "Returns floating-point value given as argument (testing)",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim__inspect_cell",
-        signature: "nvim__inspect_cell({grid}, {row}, {col})",
-        description: "Inspect a cell in the grid",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim__invalidate_glyph_cache",
-        signature: "nvim__invalidate_glyph_cache()",
-        description: "Force a glyph cache clear (testing)",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim__ns_get",
-        signature: "nvim__ns_get({ns_id})",
-        description: "Get the properties for namespace (experimental)",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim__ns_set",
-        signature: "nvim__ns_set({ns_id}, {opts})",
-        description: "Set some properties for namespace (experimental)",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim__redraw",
-        signature: "nvim__redraw({opts})",
-        description: "Instruct Nvim to redraw various components (experimental)",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim__stats",
-        signature: "nvim__stats()",
-        description: "Gets internal stats",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_attach",
-        signature: "nvim_buf_attach({buffer}, {send_buffer}, {opts})",
-        description: "Activates buffer-update events on a channel or as Lua callbacks",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_call",
-        signature: "nvim_buf_call({buffer}, {fun})",
-        description: "Call a function with buffer as temporary current buffer",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_clear_namespace",
-        signature: "nvim_buf_clear_namespace({buffer}, {ns_id}, {line_start}, {line_end})",
-        description: "Clears namespaced objects from a region",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_create_user_command",
-        signature: "nvim_buf_create_user_command({buffer}, {name}, {command}, {opts})",
-        description: "Creates a buffer-local command",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_del_mark",
-        signature: "nvim_buf_del_mark({buffer}, {name})",
-        description: "Deletes a named mark in the buffer",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_del_user_command",
-        signature: "nvim_buf_del_user_command({buffer}, {name})",
-        description: "Delete a buffer-local user-defined command",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_del_var",
-        signature: "nvim_buf_del_var({buffer}, {name})",
-        description: "Removes a buffer-scoped (b:) variable",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_detach",
-        signature: "nvim_buf_detach({buffer})",
-        description: "Deactivates buffer-update events on the channel",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_get_changedtick",
-        signature: "nvim_buf_get_changedtick({buffer})",
-        description: "Gets a changed tick of a buffer",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_get_commands",
-        signature: "nvim_buf_get_commands({buffer}, {opts})",
-        description: "Gets a map of buffer-local user-commands",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_get_extmark_by_id",
-        signature: "nvim_buf_get_extmark_by_id({buffer}, {ns_id}, {id}, {opts})",
-        description: "Gets the position of an extmark",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_get_keymap",
-        signature: "nvim_buf_get_keymap({buffer}, {mode})",
-        description: "Gets a list of buffer-local mapping definitions",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_get_mark",
-        signature: "nvim_buf_get_mark({buffer}, {name})",
-        description: "Returns a (row,col) tuple representing the position of the named mark",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_get_offset",
-        signature: "nvim_buf_get_offset({buffer}, {index})",
-        description: "Returns the byte offset of a line (0-indexed)",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_is_loaded",
-        signature: "nvim_buf_is_loaded({buffer})",
-        description: "Checks if a buffer is valid and loaded",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_buf_set_mark",
-        signature: "nvim_buf_set_mark({buffer}, {name}, {line}, {col}, {opts})",
-        description: "Sets a named mark in the given buffer",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_call_dict_function",
-        signature: "nvim_call_dict_function({dict}, {fn}, {args})",
-        description: "Calls a Vimscript Dictionary-function with the given arguments",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_chan_send",
-        signature: "nvim_chan_send({chan}, {data})",
-        description: "Sends raw data to channel",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_clear_autocmds",
-        signature: "nvim_clear_autocmds({opts})",
-        description: "Clears all autocommands selected by opts",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_cmd",
-        signature: "nvim_cmd({cmd}, {opts})",
-        description: "Executes an Ex command (structured Dict)",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_create_user_command",
-        signature: "nvim_create_user_command({name}, {command}, {opts})",
-        description: "Creates a global user-commands command",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_del_current_line",
-        signature: "nvim_del_current_line()",
-        description: "Deletes the current line",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_del_mark",
-        signature: "nvim_del_mark({name})",
-        description: "Deletes an uppercase/file named mark",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_del_user_command",
-        signature: "nvim_del_user_command({name})",
-        description: "Delete a user-defined command",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_error_event",
-        signature: "nvim_error_event({type}, {msg})",
-        description: "Emitted on the client channel if an async API request responds with an error",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_eval_statusline",
-        signature: "nvim_eval_statusline({str}, {opts})",
-        description: "Evaluates statusline string",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_exec_autocmds",
-        signature: "nvim_exec_autocmds({event}, {opts})",
-        description: "Execute all autocommands for event that match the corresponding opts",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_all_options_info",
-        signature: "nvim_get_all_options_info()",
-        description: "Gets the option information for all options",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_api_info",
-        signature: "nvim_get_api_info()",
-        description: "Returns a 2-tuple (Array) with channel id and api-metadata map",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_autocmds",
-        signature: "nvim_get_autocmds({opts})",
-        description: "Get all autocommands that match the corresponding opts",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_chan_info",
-        signature: "nvim_get_chan_info({chan})",
-        description: "Gets information about a channel",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_color_by_name",
-        signature: "nvim_get_color_by_name({name})",
-        description: "Returns the 24-bit RGB value of a color name or #rrggbb string",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_color_map",
-        signature: "nvim_get_color_map()",
-        description: "Returns a map of color names and RGB values",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_commands",
-        signature: "nvim_get_commands({opts})",
-        description: "Gets a map of global (non-buffer-local) Ex commands",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_context",
-        signature: "nvim_get_context({opts})",
-        description: "Gets a map of the current editor state",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_current_line",
-        signature: "nvim_get_current_line()",
-        description: "Gets the current line",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_current_tabpage",
-        signature: "nvim_get_current_tabpage()",
-        description: "Gets the current tabpage",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_hl_id_by_name",
-        signature: "nvim_get_hl_id_by_name({name})",
-        description: "Gets a highlight group by name",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_hl_ns",
-        signature: "nvim_get_hl_ns({opts})",
-        description: "Gets the active highlight namespace",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_keymap",
-        signature: "nvim_get_keymap({mode})",
-        description: "Gets a list of global (non-buffer-local) mapping definitions",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_mark",
-        signature: "nvim_get_mark({name}, {opts})",
-        description: "Returns a (row, col, buffer, buffername) tuple for uppercase/file mark",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_namespaces",
-        signature: "nvim_get_namespaces()",
-        description: "Gets existing, non-anonymous namespaces",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_proc",
-        signature: "nvim_get_proc({pid})",
-        description: "Gets info describing process pid",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_proc_children",
-        signature: "nvim_get_proc_children({pid})",
-        description: "Gets the immediate children of process pid",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_runtime_file",
-        signature: "nvim_get_runtime_file({name}, {all})",
-        description: "Finds files in runtime directories, in runtimepath order",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_get_vvar",
-        signature: "nvim_get_vvar({name})",
-        description: "Gets a v: variable",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_input_mouse",
-        signature: "nvim_input_mouse({button}, {action}, {modifier}, {grid}, {row}, {col})",
-        description: "Send mouse event from GUI",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_list_chans",
-        signature: "nvim_list_chans()",
-        description: "Get information about all open channels",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_list_runtime_paths",
-        signature: "nvim_list_runtime_paths()",
-        description: "Gets the paths contained in runtime-search-path",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_list_tabpages",
-        signature: "nvim_list_tabpages()",
-        description: "Gets the current list of tab-IDs",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_list_uis",
-        signature: "nvim_list_uis()",
-        description: "Gets a list of dictionaries representing attached UIs",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_load_context",
-        signature: "nvim_load_context({dict})",
-        description: "Sets the current editor state from the given context map",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_open_term",
-        signature: "nvim_open_term({buffer}, {opts})",
-        description: "Open a terminal instance in a buffer",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_parse_cmd",
-        signature: "nvim_parse_cmd({str}, {opts})",
-        description: "Parse command line",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_parse_expression",
-        signature: "nvim_parse_expression({expr}, {flags}, {highlight})",
-        description: "Parse a Vimscript expression",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_paste",
-        signature: "nvim_paste({data}, {crlf}, {phase})",
-        description: "Pastes at cursor (in any mode), and sets redo so dot will repeat",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_put",
-        signature: "nvim_put({lines}, {type}, {after}, {follow})",
-        description: "Puts text at cursor, in any mode",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_select_popupmenu_item",
-        signature: "nvim_select_popupmenu_item({item}, {insert}, {finish}, {opts})",
-        description: "Selects an item in the completion popup menu",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_set_client_info",
-        signature: "nvim_set_client_info({name}, {version}, {type}, {methods}, {attributes})",
-        description: "Self-identifies the client, and sets optional flags on the channel",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_set_current_dir",
-        signature: "nvim_set_current_dir({dir})",
-        description: "Changes the global working directory",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_set_current_line",
-        signature: "nvim_set_current_line({line})",
-        description: "Sets the text on the current line",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_set_current_tabpage",
-        signature: "nvim_set_current_tabpage({tabpage})",
-        description: "Sets the current tabpage",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_set_decoration_provider",
-        signature: "nvim_set_decoration_provider({ns_id}, {opts})",
-        description: "Set or change decoration provider for a namespace",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_set_hl_ns",
-        signature: "nvim_set_hl_ns({ns_id})",
-        description: "Set active namespace for highlights defined with nvim_set_hl()",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_set_hl_ns_fast",
-        signature: "nvim_set_hl_ns_fast({ns_id})",
-        description: "Set active namespace for highlights while redrawing",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_set_vvar",
-        signature: "nvim_set_vvar({name}, {value})",
-        description: "Sets a v: variable, if it is not readonly",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_strwidth",
-        signature: "nvim_strwidth({text})",
-        description: "Calculates the number of display cells occupied by text",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_tabpage_del_var",
-        signature: "nvim_tabpage_del_var({tabpage}, {name})",
-        description: "Removes a tab-scoped (t:) variable",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_tabpage_get_number",
-        signature: "nvim_tabpage_get_number({tabpage})",
-        description: "Gets the tabpage number",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_tabpage_get_var",
-        signature: "nvim_tabpage_get_var({tabpage}, {name})",
-        description: "Gets a tab-scoped (t:) variable",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_tabpage_get_win",
-        signature: "nvim_tabpage_get_win({tabpage})",
-        description: "Gets the current window in a tabpage",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_tabpage_is_valid",
-        signature: "nvim_tabpage_is_valid({tabpage})",
-        description: "Checks if a tabpage is valid",
-        availability: Availability::NeovimOnly,
-    },
-    BuiltinFunction {
-        name: "nvim_tabpage_list_wins",
-        signature: "nvim_tabpage_list_wins({tabpage})",
-        description: "Gets the windows in a tabpage",
-        availability: Availability::NeovimOnly,
+/// Deprecated functions and operators
+/// Reference: :help deprecated (Neovim), :help bufexists() (obsolete names)
+///
+/// Only names are deprecated, never the option keys of a call: the job
+/// deprecations of Neovim are the functions around `jobstart()`.
+pub static DEPRECATIONS: &[Deprecation] = &[
+    // Obsolete names of Vim functions
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "buffer_exists",
+        replacement: "bufexists",
+        fix: DeprecationFix::Rename,
+        availability: Availability::Common,
     },
-    BuiltinFunction {
-        name: "nvim_tabpage_set_var",
-        signature: "nvim_tabpage_set_var({tabpage}, {name}, {value})",
-        description: "Sets a tab-scoped (t:) variable",
-        availability: Availability::NeovimOnly,
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "buffer_name",
+        replacement: "bufname",
+        fix: DeprecationFix::Rename,
+        availability: Availability::Common,
     },
-    BuiltinFunction {
-        name: "nvim_tabpage_set_win",
-        signature: "nvim_tabpage_set_win({tabpage}, {win})",
-        description: "Sets the current window in a tabpage",
-        availability: Availability::NeovimOnly,
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "buffer_number",
+        replacement: "bufnr",
+        fix: DeprecationFix::Rename,
+        availability: Availability::Common,
     },
-    BuiltinFunction {
-        name: "nvim_ui_attach",
-        signature: "nvim_ui_attach({width}, {height}, {options})",
-        description: "Activates UI events on the channel",
-        availability: Availability::NeovimOnly,
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "file_readable",
+        replacement: "filereadable",
+        fix: DeprecationFix::Rename,
+        availability: Availability::Common,
     },
-    BuiltinFunction {
-        name: "nvim_ui_detach",
-        signature: "nvim_ui_detach()",
-        description: "Deactivates UI events on the channel",
-        availability: Availability::NeovimOnly,
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "highlight_exists",
+        replacement: "hlexists",
+        fix: DeprecationFix::Rename,
+        availability: Availability::Common,
     },
-    BuiltinFunction {
-        name: "nvim_ui_pum_set_bounds",
-        signature: "nvim_ui_pum_set_bounds({width}, {height}, {row}, {col})",
-        description: "Tells Nvim the geometry of the popupmenu",
-        availability: Availability::NeovimOnly,
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "highlightID",
+        replacement: "hlID",
+        fix: DeprecationFix::Rename,
+        availability: Availability::Common,
     },
-    BuiltinFunction {
-        name: "nvim_ui_pum_set_height",
-        signature: "nvim_ui_pum_set_height({height})",
-        description: "Tells Nvim the number of elements displaying in the popupmenu",
-        availability: Availability::NeovimOnly,
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "last_buffer_nr",
+        replacement: "bufnr",
+        fix: DeprecationFix::Call(&["bufnr('$')"]),
+        availability: Availability::Common,
     },
-    BuiltinFunction {
-        name: "nvim_ui_send",
-        signature: "nvim_ui_send({content})",
-        description: "Sends arbitrary data to a UI (experimental)",
+    // Neovim API functions
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_buf_add_highlight",
+        replacement: "nvim_buf_set_extmark",
+        fix: DeprecationFix::Manual,
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_ui_set_focus",
-        signature: "nvim_ui_set_focus({gained})",
-        description: "Tells the nvim server if focus was gained or lost by the GUI",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_buf_clear_highlight",
+        replacement: "nvim_buf_clear_namespace",
+        fix: DeprecationFix::Rename,
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_ui_set_option",
-        signature: "nvim_ui_set_option({name}, {value})",
-        description: "Sets a UI option",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_buf_get_option",
+        replacement: "nvim_get_option_value",
+        fix: DeprecationFix::Call(&["", "", "nvim_get_option_value($2, {'buf': $1})"]),
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_ui_term_event",
-        signature: "nvim_ui_term_event({event}, {value})",
-        description: "Emitted by the TUI client to signal when a host-terminal event occurred",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_buf_set_option",
+        replacement: "nvim_set_option_value",
+        fix: DeprecationFix::Call(&["", "", "", "nvim_set_option_value($2, $3, {'buf': $1})"]),
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_ui_try_resize",
-        signature: "nvim_ui_try_resize({width}, {height})",
-        description: "Try to resize the UI",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_buf_set_virtual_text",
+        replacement: "nvim_buf_set_extmark",
+        fix: DeprecationFix::Manual,
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_ui_try_resize_grid",
-        signature: "nvim_ui_try_resize_grid({grid}, {width}, {height})",
-        description: "Tell Nvim to resize a grid",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_command_output",
+        replacement: "nvim_exec2",
+        fix: DeprecationFix::Call(&["", "nvim_exec2($1, {'output': v:true}).output"]),
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_call",
-        signature: "nvim_win_call({window}, {fun})",
-        description: "Calls a function with window as temporary current window",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_err_write",
+        replacement: "nvim_echo",
+        fix: DeprecationFix::Manual,
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_del_var",
-        signature: "nvim_win_del_var({window}, {name})",
-        description: "Removes a window-scoped (w:) variable",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_err_writeln",
+        replacement: "nvim_echo",
+        fix: DeprecationFix::Manual,
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_get_buf",
-        signature: "nvim_win_get_buf({window})",
-        description: "Gets the current buffer in a window",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_exec",
+        replacement: "nvim_exec2",
+        fix: DeprecationFix::Call(&["", "", "nvim_exec2($1, {'output': $2}).output"]),
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_get_height",
-        signature: "nvim_win_get_height({window})",
-        description: "Gets the window height",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_get_hl_by_id",
+        replacement: "nvim_get_hl",
+        fix: DeprecationFix::Manual,
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_get_number",
-        signature: "nvim_win_get_number({window})",
-        description: "Gets the window number",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_get_hl_by_name",
+        replacement: "nvim_get_hl",
+        fix: DeprecationFix::Manual,
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_get_position",
-        signature: "nvim_win_get_position({window})",
-        description: "Gets the window position in display cells",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_get_option",
+        replacement: "nvim_get_option_value",
+        fix: DeprecationFix::Call(&["", "nvim_get_option_value($1, {})"]),
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_get_tabpage",
-        signature: "nvim_win_get_tabpage({window})",
-        description: "Gets the window tabpage",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_get_option_info",
+        replacement: "nvim_get_option_info2",
+        fix: DeprecationFix::Call(&["", "nvim_get_option_info2($1, {})"]),
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_get_width",
-        signature: "nvim_win_get_width({window})",
-        description: "Gets the window width",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_out_write",
+        replacement: "nvim_echo",
+        fix: DeprecationFix::Manual,
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_hide",
-        signature: "nvim_win_hide({window})",
-        description: "Closes the window and hide the buffer it contains",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_set_option",
+        replacement: "nvim_set_option_value",
+        fix: DeprecationFix::Call(&["", "", "nvim_set_option_value($1, $2, {})"]),
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_is_valid",
-        signature: "nvim_win_is_valid({window})",
-        description: "Checks if a window is valid",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_win_get_option",
+        replacement: "nvim_get_option_value",
+        fix: DeprecationFix::Call(&["", "", "nvim_get_option_value($2, {'win': $1})"]),
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_set_buf",
-        signature: "nvim_win_set_buf({window}, {buffer})",
-        description: "Sets the current buffer in a window, without side effects",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "nvim_win_set_option",
+        replacement: "nvim_set_option_value",
+        fix: DeprecationFix::Call(&["", "", "", "nvim_set_option_value($2, $3, {'win': $1})"]),
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_set_height",
-        signature: "nvim_win_set_height({window}, {height})",
-        description: "Sets the window height",
+    // Obsolete names of Neovim job functions
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "jobclose",
+        replacement: "chanclose",
+        fix: DeprecationFix::Rename,
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_set_hl_ns",
-        signature: "nvim_win_set_hl_ns({window}, {ns_id})",
-        description: "Set highlight namespace for a window",
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "jobsend",
+        replacement: "chansend",
+        fix: DeprecationFix::Rename,
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_set_width",
-        signature: "nvim_win_set_width({window}, {width})",
-        description: "Sets the window width",
+    // `termopen()` became the `term` option of `jobstart()`
+    Deprecation {
+        kind: DeprecationKind::Function,
+        name: "termopen",
+        replacement: "jobstart",
+        fix: DeprecationFix::Call(&[
+            "",
+            "jobstart($1, {'term': v:true})",
+            "jobstart($1, extend($2, {'term': v:true}))",
+        ]),
         availability: Availability::NeovimOnly,
     },
-    BuiltinFunction {
-        name: "nvim_win_text_height",
-        signature: "nvim_win_text_height({window}, {opts})",
-        description: "Computes the number of screen lines occupied by a range of text",
-        availability: Availability::NeovimOnly,
+    // Operators
+    Deprecation {
+        kind: DeprecationKind::Operator,
+        name: ".",
+        replacement: "..",
+        fix: DeprecationFix::Rename,
+        availability: Availability::Common,
     },
 ];
 
+impl Deprecation {
+    /// Find the deprecation of a function or operator
+    pub fn find(kind: DeprecationKind, name: &str) -> Option<&'static Deprecation> {
+        DEPRECATIONS
+            .iter()
+            .find(|d| d.kind == kind && d.name == name)
+    }
+
+    /// Help file documenting the replacement: the API functions have their own
+    pub fn help_file(&self) -> &'static str {
+        if self.replacement.starts_with("nvim_") {
+            "api.txt"
+        } else {
+            "builtin.txt"
+        }
+    }
+
+    /// Rewrite a call with the given argument texts, for `DeprecationFix::Call`
+    pub fn rewrite_call(&self, arguments: &[&str]) -> Option<String> {
+        let DeprecationFix::Call(templates) = self.fix else {
+            return None;
+        };
+        let template = templates.get(arguments.len()).filter(|t| !t.is_empty())?;
+        let mut call = String::new();
+        let mut chars = template.chars().peekable();
+        while let Some(c) = chars.next() {
+            match chars.peek().and_then(|d| d.to_digit(10)) {
+                Some(n) if c == '$' && n >= 1 => {
+                    chars.next();
+                    call.push_str(arguments.get(n as usize - 1)?);
+                }
+                _ => call.push(c),
+            }
+        }
+        Some(call)
+    }
+}
+
+/// Information about a built-in variable (v: scope)
+pub struct BuiltinVariable {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub availability: Availability,
+    /// Whether assigning it fails (E46)
+    pub readonly: bool,
+}
+
 // ============================================================================
 // Ex Commands
 // ============================================================================
@@ -4785,6 +436,43 @@ pub struct BuiltinCommand {
     pub availability: Availability,
     /// Minimum abbreviation length (e.g., 1 for "q[uit]" means "q" is valid)
     pub min_abbrev: u8,
+    /// Whether a range can precede the command (e.g., `:1,5delete`)
+    pub range: bool,
+    /// Whether `!` can follow the command (E477 otherwise)
+    pub bang: bool,
+    /// What the command takes as its argument
+    pub args: CommandArgs,
+}
+
+/// Kind of argument an Ex command takes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandArgs {
+    /// No argument (e.g., `:nohlsearch`)
+    None,
+    /// File names (e.g., `:edit {file}`)
+    File,
+    /// A search pattern (e.g., `:global /{pattern}/`)
+    Pattern,
+    /// An expression (e.g., `:echo {expr}`)
+    Expression,
+    /// Another Ex command (e.g., `:silent {command}`)
+    Command,
+    /// Anything else, specific to the command (e.g., `:set {option}`)
+    Other,
+}
+
+impl CommandArgs {
+    /// Placeholder for the argument in a command synopsis
+    pub fn placeholder(self) -> Option<&'static str> {
+        match self {
+            CommandArgs::None => None,
+            CommandArgs::File => Some("{file}"),
+            CommandArgs::Pattern => Some("/{pattern}/"),
+            CommandArgs::Expression => Some("{expr}"),
+            CommandArgs::Command => Some("{command}"),
+            CommandArgs::Other => Some("{args}"),
+        }
+    }
 }
 
 impl BuiltinCommand {
@@ -4793,6 +481,30 @@ impl BuiltinCommand {
         &self.name[..self.min_abbrev as usize]
     }
 
+    /// Name showing the optional part of the command (e.g., "q[uit]")
+    pub fn abbreviated_name(&self) -> String {
+        if self.min_abbrev as usize == self.name.len() {
+            self.name.to_string()
+        } else {
+            format!(
+                "{}[{}]",
+                self.min_name(),
+                &self.name[self.min_abbrev as usize..]
+            )
+        }
+    }
+
+    /// Usage of the command, like the help headings (e.g., ":[range]d[elete]")
+    pub fn synopsis(&self) -> String {
+        let range = if self.range { "[range]" } else { "" };
+        let name = self.abbreviated_name();
+        let bang = if self.bang { "[!]" } else { "" };
+        match self.args.placeholder() {
+            Some(args) => format!(":{range}{name}{bang} {args}"),
+            None => format!(":{range}{name}{bang}"),
+        }
+    }
+
     /// Check if the input matches this command (considering abbreviation)
     pub fn matches(&self, input: &str) -> bool {
         input.len() >= self.min_abbrev as usize
@@ -4801,6 +513,29 @@ impl BuiltinCommand {
     }
 }
 
+/// Ex commands that cannot be used in Vim9 script
+/// Reference: :help E1100, :help E1126
+pub static VIM9_UNSUPPORTED_COMMANDS: &[&str] = &["let", "append", "change", "insert", "k", "t"];
+
+/// Commands that declare functions, variables and types in Vim9 script
+/// These are ranked first in command completion for Vim9 script files
+pub static VIM9_DECLARATION_COMMANDS: &[&str] = &[
+    "def",
+    "enddef",
+    "var",
+    "final",
+    "const",
+    "import",
+    "export",
+    "class",
+    "endclass",
+    "interface",
+    "endinterface",
+    "enum",
+    "endenum",
+    "type",
+];
+
 /// List of commonly used Vim Ex commands
 /// Reference: :help ex-cmd-index
 pub static BUILTIN_COMMANDS: &[BuiltinCommand] = &[
@@ -4809,3359 +544,5039 @@ pub static BUILTIN_COMMANDS: &[BuiltinCommand] = &[
         description: "Execute commands when condition is true",
         availability: Availability::Common,
         min_abbrev: 2, // :if
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "else",
         description: "Execute commands when 'if' condition is false",
         availability: Availability::Common,
         min_abbrev: 2, // :el[se]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "elseif",
         description: "Execute commands when condition is true",
         availability: Availability::Common,
         min_abbrev: 5, // :elsei[f]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "endif",
         description: "End 'if' block",
         availability: Availability::Common,
         min_abbrev: 2, // :en[dif]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "for",
         description: "Loop over a list",
         availability: Availability::Common,
         min_abbrev: 3, // :for
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "endfor",
         description: "End 'for' loop",
         availability: Availability::Common,
         min_abbrev: 5, // :endfo[r]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "while",
         description: "Loop while condition is true",
         availability: Availability::Common,
         min_abbrev: 2, // :wh[ile]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "endwhile",
         description: "End 'while' loop",
         availability: Availability::Common,
         min_abbrev: 4, // :endw[hile]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "try",
         description: "Start try block for exception handling",
         availability: Availability::Common,
         min_abbrev: 3, // :try
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "catch",
         description: "Catch exceptions",
         availability: Availability::Common,
         min_abbrev: 3, // :cat[ch]
+        range: false,
+        bang: false,
+        args: CommandArgs::Pattern,
     },
     BuiltinCommand {
         name: "finally",
         description: "Execute commands regardless of exception",
         availability: Availability::Common,
         min_abbrev: 4, // :fina[lly]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "endtry",
         description: "End 'try' block",
         availability: Availability::Common,
         min_abbrev: 4, // :endt[ry]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "throw",
         description: "Throw an exception",
         availability: Availability::Common,
         min_abbrev: 2, // :th[row]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "break",
         description: "Break out of loop",
         availability: Availability::Common,
         min_abbrev: 4, // :brea[k]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "continue",
         description: "Continue loop from start",
         availability: Availability::Common,
         min_abbrev: 3, // :con[tinue]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "function",
         description: "Define a function",
         availability: Availability::Common,
         min_abbrev: 2, // :fu[nction]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "endfunction",
         description: "End function definition",
         availability: Availability::Common,
         min_abbrev: 4, // :endf[unction]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "return",
         description: "Return from function",
         availability: Availability::Common,
         min_abbrev: 4, // :retu[rn]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "call",
         description: "Call a function",
         availability: Availability::Common,
         min_abbrev: 3, // :cal[l]
+        range: true,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "let",
         description: "Assign value to variable",
         availability: Availability::Common,
         min_abbrev: 3, // :let
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "const",
         description: "Define a constant",
         availability: Availability::Common,
         min_abbrev: 4, // :cons[t]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "unlet",
         description: "Delete variable",
         availability: Availability::Common,
         min_abbrev: 3, // :unl[et]
+        range: false,
+        bang: true,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "lockvar",
         description: "Lock variable",
         availability: Availability::Common,
         min_abbrev: 5, // :lockv[ar]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "unlockvar",
         description: "Unlock variable",
         availability: Availability::Common,
         min_abbrev: 4, // :unlo[ckvar]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "echo",
         description: "Echo expression",
         availability: Availability::Common,
         min_abbrev: 2, // :ec[ho]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "echom",
         description: "Echo message and save in history",
         availability: Availability::Common,
         min_abbrev: 5, // :echom[sg] (alias)
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "echomsg",
         description: "Echo message and save in history",
         availability: Availability::Common,
         min_abbrev: 5, // :echom[sg]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "echoerr",
         description: "Echo error message",
         availability: Availability::Common,
         min_abbrev: 5, // :echoe[rr]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "echon",
         description: "Echo without newline",
         availability: Availability::Common,
         min_abbrev: 5, // :echon
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "echohl",
         description: "Set highlight group for echo",
         availability: Availability::Common,
         min_abbrev: 5, // :echoh[l]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "echowindow",
         description: "Echo in popup window",
         availability: Availability::Common,
         min_abbrev: 5, // :echow[indow]
+        range: true,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "map",
         description: "Define key mapping (all modes)",
         availability: Availability::Common,
         min_abbrev: 3, // :map
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "nmap",
         description: "Define normal mode mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :nm[ap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "vmap",
         description: "Define visual mode mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :vm[ap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "xmap",
         description: "Define visual (not select) mode mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :xm[ap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "smap",
         description: "Define select mode mapping",
         availability: Availability::Common,
         min_abbrev: 4, // :smap
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "imap",
         description: "Define insert mode mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :im[ap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cmap",
         description: "Define command-line mode mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :cm[ap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "omap",
         description: "Define operator-pending mode mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :om[ap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lmap",
         description: "Define language mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :lm[ap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tmap",
         description: "Define terminal mode mapping",
         availability: Availability::Common,
         min_abbrev: 3, // :tma[p]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "noremap",
         description: "Define non-recursive mapping (all modes)",
         availability: Availability::Common,
         min_abbrev: 2, // :no[remap]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "nnoremap",
         description: "Define non-recursive normal mode mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :nn[oremap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "vnoremap",
         description: "Define non-recursive visual mode mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :vn[oremap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "xnoremap",
         description: "Define non-recursive visual (not select) mode mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :xn[oremap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "snoremap",
         description: "Define non-recursive select mode mapping",
         availability: Availability::Common,
         min_abbrev: 4, // :snor[emap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "inoremap",
         description: "Define non-recursive insert mode mapping",
         availability: Availability::Common,
         min_abbrev: 3, // :ino[remap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cnoremap",
         description: "Define non-recursive command-line mode mapping",
         availability: Availability::Common,
         min_abbrev: 3, // :cno[remap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "onoremap",
         description: "Define non-recursive operator-pending mode mapping",
         availability: Availability::Common,
         min_abbrev: 3, // :ono[remap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lnoremap",
         description: "Define non-recursive language mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :ln[oremap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tnoremap",
         description: "Define non-recursive terminal mode mapping",
         availability: Availability::Common,
         min_abbrev: 3, // :tno[remap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "unmap",
         description: "Remove mapping (all modes)",
         availability: Availability::Common,
         min_abbrev: 3, // :unm[ap]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "nunmap",
         description: "Remove normal mode mapping",
         availability: Availability::Common,
         min_abbrev: 3, // :nun[map]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "vunmap",
         description: "Remove visual mode mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :vu[nmap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "xunmap",
         description: "Remove visual (not select) mode mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :xu[nmap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sunmap",
         description: "Remove select mode mapping",
         availability: Availability::Common,
         min_abbrev: 4, // :sunm[ap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "iunmap",
         description: "Remove insert mode mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :iu[nmap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cunmap",
         description: "Remove command-line mode mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :cu[nmap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "ounmap",
         description: "Remove operator-pending mode mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :ou[nmap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lunmap",
         description: "Remove language mapping",
         availability: Availability::Common,
         min_abbrev: 2, // :lu[nmap]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tunmap",
         description: "Remove terminal mode mapping",
         availability: Availability::Common,
         min_abbrev: 5, // :tunma[p]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "mapclear",
         description: "Clear all mappings (all modes)",
         availability: Availability::Common,
         min_abbrev: 4, // :mapc[lear]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "autocmd",
         description: "Define autocommand",
         availability: Availability::Common,
         min_abbrev: 2, // :au[tocmd]
+        range: false,
+        bang: true,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "augroup",
         description: "Define autocommand group",
         availability: Availability::Common,
         min_abbrev: 3, // :aug[roup]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "doautocmd",
         description: "Execute autocommands",
         availability: Availability::Common,
         min_abbrev: 2, // :do[autocmd]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "doautoall",
         description: "Execute autocommands for all buffers",
         availability: Availability::Common,
         min_abbrev: 7, // :doautoa[ll]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "set",
         description: "Set option value",
         availability: Availability::Common,
         min_abbrev: 2, // :se[t]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "setlocal",
         description: "Set local option value",
         availability: Availability::Common,
         min_abbrev: 4, // :setl[ocal]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "setglobal",
         description: "Set global option value",
         availability: Availability::Common,
         min_abbrev: 4, // :setg[lobal]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "highlight",
         description: "Define highlighting",
         availability: Availability::Common,
         min_abbrev: 2, // :hi[ghlight]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "syntax",
         description: "Define syntax highlighting",
         availability: Availability::Common,
         min_abbrev: 2, // :sy[ntax]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "colorscheme",
         description: "Load colorscheme",
         availability: Availability::Common,
         min_abbrev: 4, // :colo[rscheme]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "command",
         description: "Define user command",
         availability: Availability::Common,
         min_abbrev: 3, // :com[mand]
+        range: false,
+        bang: true,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "delcommand",
         description: "Delete user command",
         availability: Availability::Common,
         min_abbrev: 4, // :delc[ommand]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "comclear",
         description: "Clear all user commands",
         availability: Availability::Common,
         min_abbrev: 4, // :comc[lear]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "execute",
         description: "Execute string as Ex command",
         availability: Availability::Common,
         min_abbrev: 3, // :exe[cute]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "normal",
         description: "Execute normal mode commands",
         availability: Availability::Common,
         min_abbrev: 4, // :norm[al]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "source",
         description: "Read and execute commands from file",
         availability: Availability::Common,
         min_abbrev: 2, // :so[urce]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "runtime",
         description: "Source files from 'runtimepath'",
         availability: Availability::Common,
         min_abbrev: 2, // :ru[ntime]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "finish",
         description: "Stop sourcing current script",
         availability: Availability::Common,
         min_abbrev: 4, // :fini[sh]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "edit",
         description: "Edit a file",
         availability: Availability::Common,
         min_abbrev: 1, // :e[dit]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "enew",
         description: "Edit a new unnamed buffer",
         availability: Availability::Common,
         min_abbrev: 3, // :ene[w]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "buffer",
         description: "Go to buffer",
         availability: Availability::Common,
         min_abbrev: 1, // :b[uffer]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "bdelete",
         description: "Delete buffer",
         availability: Availability::Common,
         min_abbrev: 2, // :bd[elete]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "bwipeout",
         description: "Wipe out buffer",
         availability: Availability::Common,
         min_abbrev: 2, // :bw[ipeout]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "split",
         description: "Split window horizontally",
         availability: Availability::Common,
         min_abbrev: 2, // :sp[lit]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "vsplit",
         description: "Split window vertically",
         availability: Availability::Common,
         min_abbrev: 2, // :vs[plit]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "new",
         description: "Create new window with empty buffer",
         availability: Availability::Common,
         min_abbrev: 3, // :new
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "vnew",
         description: "Create new vertical window with empty buffer",
         availability: Availability::Common,
         min_abbrev: 3, // :vne[w]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "close",
         description: "Close window",
         availability: Availability::Common,
         min_abbrev: 3, // :clo[se]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "only",
         description: "Close all other windows",
         availability: Availability::Common,
         min_abbrev: 2, // :on[ly]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "tabnew",
         description: "Create new tab",
         availability: Availability::Common,
         min_abbrev: 6, // :tabnew
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "tabclose",
         description: "Close tab",
         availability: Availability::Common,
         min_abbrev: 4, // :tabc[lose]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tabnext",
         description: "Go to next tab",
         availability: Availability::Common,
         min_abbrev: 4, // :tabn[ext]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tabprevious",
         description: "Go to previous tab",
         availability: Availability::Common,
         min_abbrev: 4, // :tabp[revious]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "write",
         description: "Write buffer to file",
         availability: Availability::Common,
         min_abbrev: 1, // :w[rite]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "wall",
         description: "Write all buffers",
         availability: Availability::Common,
         min_abbrev: 2, // :wa[ll]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "quit",
         description: "Quit window",
         availability: Availability::Common,
         min_abbrev: 1, // :q[uit]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "qall",
         description: "Quit all windows",
         availability: Availability::Common,
         min_abbrev: 2, // :qa[ll]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "wq",
         description: "Write and quit",
         availability: Availability::Common,
         min_abbrev: 2, // :wq
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "wqall",
         description: "Write all and quit",
         availability: Availability::Common,
         min_abbrev: 3, // :wqa[ll]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "saveas",
         description: "Save buffer to new file",
         availability: Availability::Common,
         min_abbrev: 3, // :sav[eas]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "substitute",
         description: "Search and replace",
         availability: Availability::Common,
         min_abbrev: 1, // :s[ubstitute]
+        range: true,
+        bang: false,
+        args: CommandArgs::Pattern,
     },
     BuiltinCommand {
         name: "global",
         description: "Execute command on matching lines",
         availability: Availability::Common,
         min_abbrev: 1, // :g[lobal]
+        range: true,
+        bang: true,
+        args: CommandArgs::Pattern,
     },
     BuiltinCommand {
         name: "vglobal",
         description: "Execute command on non-matching lines",
         availability: Availability::Common,
         min_abbrev: 1, // :v[global]
+        range: true,
+        bang: false,
+        args: CommandArgs::Pattern,
     },
     BuiltinCommand {
         name: "silent",
         description: "Execute command silently",
         availability: Availability::Common,
         min_abbrev: 3, // :sil[ent]
+        range: false,
+        bang: true,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "redraw",
         description: "Redraw screen",
         availability: Availability::Common,
         min_abbrev: 4, // :redr[aw]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "sleep",
         description: "Pause execution",
         availability: Availability::Common,
         min_abbrev: 2, // :sl[eep]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "filetype",
         description: "Set filetype options",
         availability: Availability::Common,
         min_abbrev: 5, // :filet[ype]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lua",
         description: "Execute Lua code",
         availability: Availability::NeovimOnly,
         min_abbrev: 3, // :lua (no abbreviation)
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "luado",
         description: "Execute Lua for each line",
         availability: Availability::NeovimOnly,
         min_abbrev: 4, // :luad[o]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "luafile",
         description: "Execute Lua file",
         availability: Availability::NeovimOnly,
         min_abbrev: 4, // :luaf[ile]
+        range: true,
+        bang: false,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "vim9script",
         description: "Start Vim9 script",
         availability: Availability::VimOnly,
         min_abbrev: 5, // :vim9s[cript]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "!",
         description: "filter lines or execute an external command",
         availability: Availability::Common,
         min_abbrev: 1, // :!
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "!!",
         description: "repeat last \":!\" command",
         availability: Availability::Common,
         min_abbrev: 2, // :!!
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "#",
         description: "same as \":number\"",
         availability: Availability::Common,
         min_abbrev: 1, // :#
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "&",
         description: "repeat last \":substitute\"",
         availability: Availability::Common,
         min_abbrev: 1, // :&
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "2match",
         description: "define a second match to highlight",
         availability: Availability::Common,
         min_abbrev: 4, // :2mat[ch]
+        range: false,
+        bang: false,
+        args: CommandArgs::Pattern,
     },
     BuiltinCommand {
         name: "3match",
         description: "define a third match to highlight",
         availability: Availability::Common,
         min_abbrev: 4, // :3mat[ch]
+        range: false,
+        bang: false,
+        args: CommandArgs::Pattern,
     },
     BuiltinCommand {
         name: "<",
         description: "shift lines one 'shiftwidth' left",
         availability: Availability::Common,
         min_abbrev: 1, // :<
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "=",
         description: "print the last line number",
         availability: Availability::Common,
         min_abbrev: 1, // :=
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: ">",
         description: "shift lines one 'shiftwidth' right",
         availability: Availability::Common,
         min_abbrev: 1, // :>
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "@",
         description: "execute contents of a register",
         availability: Availability::Common,
         min_abbrev: 1, // :@
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "@@",
         description: "repeat the previous \":@\"",
         availability: Availability::Common,
         min_abbrev: 2, // :@@
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "~",
         description: "repeat last \":substitute\"",
         availability: Availability::Common,
         min_abbrev: 1, // :~
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "Next",
         description: "go to previous file in the argument list",
         availability: Availability::Common,
         min_abbrev: 1, // :N[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "argadd",
         description: "add items to the argument list",
         availability: Availability::Common,
         min_abbrev: 4, // :arga[dd]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "argdedupe",
         description: "remove duplicates from the argument list",
         availability: Availability::Common,
         min_abbrev: 6, // :argded[upe]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "argdelete",
         description: "delete items from the argument list",
         availability: Availability::Common,
         min_abbrev: 4, // :argd[elete]
+        range: true,
+        bang: false,
+        args: CommandArgs::Pattern,
     },
     BuiltinCommand {
         name: "argdo",
         description: "do a command on all items in the argument list",
         availability: Availability::Common,
         min_abbrev: 5, // :argdo (no abbreviation)
+        range: true,
+        bang: true,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "argedit",
         description: "add item to the argument list and edit it",
         availability: Availability::Common,
         min_abbrev: 4, // :arge[dit]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "argglobal",
         description: "define the global argument list",
         availability: Availability::Common,
         min_abbrev: 4, // :argg[lobal]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "arglocal",
         description: "define a local argument list",
         availability: Availability::Common,
         min_abbrev: 4, // :argl[ocal]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "args",
         description: "print the argument list",
         availability: Availability::Common,
         min_abbrev: 2, // :ar[gs]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "argument",
         description: "go to specific file in the argument list",
         availability: Availability::Common,
         min_abbrev: 4, // :argu[ment]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "first",
         description: "go to the first file in the argument list",
         availability: Availability::Common,
         min_abbrev: 3, // :fir[st]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "last",
         description: "go to the last file in the argument list",
         availability: Availability::Common,
         min_abbrev: 2, // :la[st]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "next",
         description: "go to next file in the argument list",
         availability: Availability::Common,
         min_abbrev: 1, // :n[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "previous",
         description: "go to previous file in argument list",
         availability: Availability::Common,
         min_abbrev: 4, // :prev[ious]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "rewind",
         description: "go to the first file in the argument list",
         availability: Availability::Common,
         min_abbrev: 3, // :rew[ind]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "bNext",
         description: "go to previous buffer in the buffer list",
         availability: Availability::Common,
         min_abbrev: 2, // :bN[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "badd",
         description: "add buffer to the buffer list",
         availability: Availability::Common,
         min_abbrev: 3, // :bad[d]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "ball",
         description: "open a window for each buffer in the buffer list",
         availability: Availability::Common,
         min_abbrev: 2, // :ba[ll]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "balt",
         description: "like \":badd\" but also set the alternate file",
         availability: Availability::Common,
         min_abbrev: 4, // :balt (no abbreviation)
+        range: false,
+        bang: false,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "bfirst",
         description: "go to first buffer in the buffer list",
         availability: Availability::Common,
         min_abbrev: 2, // :bf[irst]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "blast",
         description: "go to last buffer in the buffer list",
         availability: Availability::Common,
         min_abbrev: 2, // :bl[ast]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "bmodified",
         description: "go to next buffer in the buffer list that has been modified",
         availability: Availability::Common,
         min_abbrev: 2, // :bm[odified]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "bnext",
         description: "go to next buffer in the buffer list",
         availability: Availability::Common,
         min_abbrev: 2, // :bn[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "bprevious",
         description: "go to previous buffer in the buffer list",
         availability: Availability::Common,
         min_abbrev: 2, // :bp[revious]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "brewind",
         description: "go to first buffer in the buffer list",
         availability: Availability::Common,
         min_abbrev: 2, // :br[ewind]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "bufdo",
         description: "execute command in each listed buffer",
         availability: Availability::Common,
         min_abbrev: 4, // :bufd[o]
+        range: true,
+        bang: true,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "buffers",
         description: "list all files in the buffer list",
         availability: Availability::Common,
         min_abbrev: 7, // :buffers (no abbreviation)
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "bunload",
         description: "unload a specific buffer",
         availability: Availability::Common,
         min_abbrev: 3, // :bun[load]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "files",
         description: "list all files in the buffer list",
         availability: Availability::Common,
         min_abbrev: 5, // :files (no abbreviation)
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "ls",
         description: "list all buffers",
         availability: Availability::Common,
         min_abbrev: 2, // :ls (no abbreviation)
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "aboveleft",
         description: "make split window appear left or above",
         availability: Availability::Common,
         min_abbrev: 3, // :abo[veleft]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "belowright",
         description: "make split window appear right or below",
         availability: Availability::Common,
         min_abbrev: 3, // :bel[owright]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "botright",
         description: "make split window appear at bottom or far right",
         availability: Availability::Common,
         min_abbrev: 2, // :bo[tright]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "browse",
         description: "use file selection dialog",
         availability: Availability::Common,
         min_abbrev: 3, // :bro[wse]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "confirm",
         description: "prompt user when confirmation required",
         availability: Availability::Common,
         min_abbrev: 4, // :conf[irm]
+        range: false,
+        bang: false,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "hide",
         description: "hide current buffer for a command",
         availability: Availability::Common,
         min_abbrev: 3, // :hid[e]
+        range: true,
+        bang: true,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "horizontal",
         description: "following window command work horizontally",
         availability: Availability::Common,
         min_abbrev: 3, // :hor[izontal]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "leftabove",
         description: "make split window appear left or above",
         availability: Availability::Common,
         min_abbrev: 5, // :lefta[bove]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "rightbelow",
         description: "make split window appear right or below",
         availability: Availability::Common,
         min_abbrev: 6, // :rightb[elow]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "tab",
         description: "create new tab when opening new window",
         availability: Availability::Common,
         min_abbrev: 3, // :tab
+        range: true,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "topleft",
         description: "make split window appear at top or far left",
         availability: Availability::Common,
         min_abbrev: 2, // :to[pleft]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "vertical",
         description: "make following command split vertically",
         availability: Availability::Common,
         min_abbrev: 4, // :vert[ical]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "cNext",
         description: "go to previous error",
         availability: Availability::Common,
         min_abbrev: 2, // :cN[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "cNfile",
         description: "go to last error in previous file",
         availability: Availability::Common,
         min_abbrev: 3, // :cNf[ile]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "cabove",
         description: "go to error above current line",
         availability: Availability::Common,
         min_abbrev: 4, // :cabo[ve]
+        range: true,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "caddbuffer",
         description: "add errors from buffer",
         availability: Availability::Common,
         min_abbrev: 3, // :cad[dbuffer]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "caddexpr",
         description: "add errors from expr",
         availability: Availability::Common,
         min_abbrev: 5, // :cadde[xpr]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "caddfile",
         description: "add error message to current quickfix list",
         availability: Availability::Common,
         min_abbrev: 5, // :caddf[ile]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cafter",
         description: "go to error after current cursor",
         availability: Availability::Common,
         min_abbrev: 3, // :caf[ter]
+        range: true,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "cbefore",
         description: "go to error before current cursor",
         availability: Availability::Common,
         min_abbrev: 3, // :cbe[fore]
+        range: true,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "cbelow",
         description: "go to error below current line",
         availability: Availability::Common,
         min_abbrev: 4, // :cbel[ow]
+        range: true,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "cbottom",
         description: "scroll to the bottom of the quickfix window",
         availability: Availability::Common,
         min_abbrev: 3, // :cbo[ttom]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "cbuffer",
         description: "parse error messages and jump to first error",
         availability: Availability::Common,
         min_abbrev: 2, // :cb[uffer]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cc",
         description: "go to specific error",
         availability: Availability::Common,
         min_abbrev: 2, // :cc
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cclose",
         description: "close quickfix window",
         availability: Availability::Common,
         min_abbrev: 3, // :ccl[ose]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "cdo",
         description: "execute command in each valid error list entry",
         availability: Availability::Common,
         min_abbrev: 3, // :cdo
+        range: false,
+        bang: true,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "cexpr",
         description: "read errors from expr and jump to first",
         availability: Availability::Common,
         min_abbrev: 3, // :cex[pr]
+        range: false,
+        bang: true,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "cfdo",
         description: "execute command in each file in error list",
         availability: Availability::Common,
         min_abbrev: 3, // :cfd[o]
+        range: false,
+        bang: true,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "cfile",
         description: "read file with error messages and jump to first",
         availability: Availability::Common,
         min_abbrev: 2, // :cf[ile]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "cfirst",
         description: "go to the specified error, default first one",
         availability: Availability::Common,
         min_abbrev: 4, // :cfir[st]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cgetbuffer",
         description: "get errors from buffer",
         availability: Availability::Common,
         min_abbrev: 5, // :cgetb[uffer]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cgetexpr",
         description: "get errors from expr",
         availability: Availability::Common,
         min_abbrev: 5, // :cgete[xpr]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "cgetfile",
         description: "read file with error messages",
         availability: Availability::Common,
         min_abbrev: 2, // :cg[etfile]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "chistory",
         description: "list the error lists",
         availability: Availability::Common,
         min_abbrev: 3, // :chi[story]
+        range: true,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "clast",
         description: "go to the specified error, default last one",
         availability: Availability::Common,
         min_abbrev: 3, // :cla[st]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "clist",
         description: "list all errors",
         availability: Availability::Common,
         min_abbrev: 2, // :cl[ist]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cnewer",
         description: "go to newer error list",
         availability: Availability::Common,
         min_abbrev: 4, // :cnew[er]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cnext",
         description: "go to next error",
         availability: Availability::Common,
         min_abbrev: 2, // :cn[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "cnfile",
         description: "go to first error in next file",
         availability: Availability::Common,
         min_abbrev: 3, // :cnf[ile]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "colder",
         description: "go to older error list",
         availability: Availability::Common,
         min_abbrev: 3, // :col[der]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "copen",
         description: "open quickfix window",
         availability: Availability::Common,
         min_abbrev: 4, // :cope[n]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cpfile",
         description: "go to last error in previous file",
         availability: Availability::Common,
         min_abbrev: 3, // :cpf[ile]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "cprevious",
         description: "go to previous error",
         availability: Availability::Common,
         min_abbrev: 2, // :cp[revious]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "cquit",
         description: "quit Vim with an error code",
         availability: Availability::Common,
         min_abbrev: 2, // :cq[uit]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "crewind",
         description: "go to the specified error, default first one",
         availability: Availability::Common,
         min_abbrev: 2, // :cr[ewind]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cwindow",
         description: "open or close quickfix window",
         availability: Availability::Common,
         min_abbrev: 2, // :cw[indow]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lNext",
         description: "go to previous entry in location list",
         availability: Availability::Common,
         min_abbrev: 2, // :lN[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "lNfile",
         description: "go to last entry in previous file",
         availability: Availability::Common,
         min_abbrev: 3, // :lNf[ile]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "labove",
         description: "go to location above current line",
         availability: Availability::Common,
         min_abbrev: 3, // :lab[ove]
+        range: true,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "laddbuffer",
         description: "add locations from buffer",
         availability: Availability::Common,
         min_abbrev: 5, // :laddb[uffer]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "laddexpr",
         description: "add locations from expr",
         availability: Availability::Common,
         min_abbrev: 3, // :lad[dexpr]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "laddfile",
         description: "add locations to current location list",
         availability: Availability::Common,
         min_abbrev: 5, // :laddf[ile]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lafter",
         description: "go to location after current cursor",
         availability: Availability::Common,
         min_abbrev: 3, // :laf[ter]
+        range: true,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "lbefore",
         description: "go to location before current cursor",
         availability: Availability::Common,
         min_abbrev: 3, // :lbe[fore]
+        range: true,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "lbelow",
         description: "go to location below current line",
         availability: Availability::Common,
         min_abbrev: 4, // :lbel[ow]
+        range: true,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "lbottom",
         description: "scroll to the bottom of the location window",
         availability: Availability::Common,
         min_abbrev: 3, // :lbo[ttom]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "lbuffer",
         description: "parse locations and jump to first location",
         availability: Availability::Common,
         min_abbrev: 2, // :lb[uffer]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lclose",
         description: "close location window",
         availability: Availability::Common,
         min_abbrev: 3, // :lcl[ose]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "ldo",
         description: "execute command in valid location list entries",
         availability: Availability::Common,
         min_abbrev: 2, // :ld[o]
+        range: false,
+        bang: true,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "lexpr",
         description: "read locations from expr and jump to first",
         availability: Availability::Common,
         min_abbrev: 3, // :lex[pr]
+        range: false,
+        bang: true,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "lfdo",
         description: "execute command in each file in location list",
         availability: Availability::Common,
         min_abbrev: 3, // :lfd[o]
+        range: false,
+        bang: true,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "lfile",
         description: "read file with locations and jump to first",
         availability: Availability::Common,
         min_abbrev: 2, // :lf[ile]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lfirst",
         description: "go to the specified location, default first one",
         availability: Availability::Common,
         min_abbrev: 4, // :lfir[st]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lgetbuffer",
         description: "get locations from buffer",
         availability: Availability::Common,
         min_abbrev: 5, // :lgetb[uffer]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lgetexpr",
         description: "get locations from expr",
         availability: Availability::Common,
         min_abbrev: 5, // :lgete[xpr]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "lgetfile",
         description: "read file with locations",
         availability: Availability::Common,
         min_abbrev: 2, // :lg[etfile]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lgrep",
         description: "run 'grepprg' and jump to first match",
         availability: Availability::Common,
         min_abbrev: 3, // :lgr[ep]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lgrepadd",
         description: "like :grep, but append to current list",
         availability: Availability::Common,
         min_abbrev: 6, // :lgrepa[dd]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lhelpgrep",
         description: "like \":helpgrep\" but uses location list",
         availability: Availability::Common,
         min_abbrev: 2, // :lh[elpgrep]
+        range: false,
+        bang: false,
+        args: CommandArgs::Pattern,
     },
     BuiltinCommand {
         name: "lhistory",
         description: "list the location lists",
         availability: Availability::Common,
         min_abbrev: 3, // :lhi[story]
+        range: true,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "ll",
         description: "go to specific location",
         availability: Availability::Common,
         min_abbrev: 2, // :ll
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "llast",
         description: "go to the specified location, default last one",
         availability: Availability::Common,
         min_abbrev: 3, // :lla[st]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "llist",
         description: "list all locations",
         availability: Availability::Common,
         min_abbrev: 3, // :lli[st]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lmake",
         description: "execute external command 'makeprg' and parse",
         availability: Availability::Common,
         min_abbrev: 4, // :lmak[e]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lnewer",
         description: "go to newer location list",
         availability: Availability::Common,
         min_abbrev: 4, // :lnew[er]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lnext",
         description: "go to next location",
         availability: Availability::Common,
         min_abbrev: 3, // :lne[xt]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "lnfile",
         description: "go to first location in next file",
         availability: Availability::Common,
         min_abbrev: 3, // :lnf[ile]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "lolder",
         description: "go to older location list",
         availability: Availability::Common,
         min_abbrev: 3, // :lol[der]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lopen",
         description: "open location window",
         availability: Availability::Common,
         min_abbrev: 3, // :lop[en]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lpfile",
         description: "go to last location in previous file",
         availability: Availability::Common,
         min_abbrev: 3, // :lpf[ile]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "lprevious",
         description: "go to previous location",
         availability: Availability::Common,
         min_abbrev: 2, // :lp[revious]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "lrewind",
         description: "go to the specified location, default first one",
         availability: Availability::Common,
         min_abbrev: 2, // :lr[ewind]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "ltag",
         description: "jump to tag and add matching tags to the location list",
         availability: Availability::Common,
         min_abbrev: 2, // :lt[ag]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lvimgrep",
         description: "search for pattern in files",
         availability: Availability::Common,
         min_abbrev: 2, // :lv[imgrep]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "lvimgrepadd",
         description: "like :vimgrep, but append to current list",
         availability: Availability::Common,
         min_abbrev: 8, // :lvimgrepa[dd]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "lwindow",
         description: "open or close location window",
         availability: Availability::Common,
         min_abbrev: 2, // :lw[indow]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cd",
         description: "change directory",
         availability: Availability::Common,
         min_abbrev: 2, // :cd
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "chdir",
         description: "change directory",
         availability: Availability::Common,
         min_abbrev: 3, // :chd[ir]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "lcd",
         description: "change directory locally",
         availability: Availability::Common,
         min_abbrev: 2, // :lc[d]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "lchdir",
         description: "change directory locally",
         availability: Availability::Common,
         min_abbrev: 3, // :lch[dir]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "pwd",
         description: "print current directory",
         availability: Availability::Common,
         min_abbrev: 2, // :pw[d]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "tcd",
         description: "change directory for tab page",
         availability: Availability::Common,
         min_abbrev: 2, // :tc[d]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "tchdir",
         description: "change directory for tab page",
         availability: Availability::Common,
         min_abbrev: 3, // :tch[dir]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "Print",
         description: "print lines",
         availability: Availability::Common,
         min_abbrev: 1, // :P[rint]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "append",
         description: "append text",
         availability: Availability::Common,
         min_abbrev: 1, // :a[ppend]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "center",
         description: "format lines at the center",
         availability: Availability::Common,
         min_abbrev: 2, // :ce[nter]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "change",
         description: "replace a line or series of lines",
         availability: Availability::Common,
         min_abbrev: 1, // :c[hange]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "copy",
         description: "copy lines",
         availability: Availability::Common,
         min_abbrev: 2, // :co[py]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "delete",
         description: "delete lines",
         availability: Availability::Common,
         min_abbrev: 1, // :d[elete]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "insert",
         description: "insert text",
         availability: Availability::Common,
         min_abbrev: 1, // :i[nsert]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "join",
         description: "join lines",
         availability: Availability::Common,
         min_abbrev: 1, // :j[oin]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "left",
         description: "left align lines",
         availability: Availability::Common,
         min_abbrev: 2, // :le[ft]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "list",
         description: "print lines",
         availability: Availability::Common,
         min_abbrev: 1, // :l[ist]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "move",
         description: "move lines",
         availability: Availability::Common,
         min_abbrev: 1, // :m[ove]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "number",
         description: "print lines with line number",
         availability: Availability::Common,
         min_abbrev: 2, // :nu[mber]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "print",
         description: "print lines",
         availability: Availability::Common,
         min_abbrev: 1, // :p[rint]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "put",
         description: "insert contents of register in the text",
         availability: Availability::Common,
         min_abbrev: 2, // :pu[t]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "read",
         description: "read file into the text",
         availability: Availability::Common,
         min_abbrev: 1, // :r[ead]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "retab",
         description: "change tab size",
         availability: Availability::Common,
         min_abbrev: 3, // :ret[ab]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "right",
         description: "right align text",
         availability: Availability::Common,
         min_abbrev: 2, // :ri[ght]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sort",
         description: "sort lines",
         availability: Availability::Common,
         min_abbrev: 3, // :sor[t]
+        range: true,
+        bang: true,
+        args: CommandArgs::Pattern,
     },
     BuiltinCommand {
         name: "t",
         description: "same as \":copy\"",
         availability: Availability::Common,
         min_abbrev: 1, // :t
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "uniq",
         description: "uniq lines",
         availability: Availability::Common,
         min_abbrev: 3, // :uni[q]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "yank",
         description: "yank lines into a register",
         availability: Availability::Common,
         min_abbrev: 1, // :y[ank]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "z",
         description: "print some lines",
         availability: Availability::Common,
         min_abbrev: 1, // :z
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "earlier",
         description: "go to older change, undo",
         availability: Availability::Common,
         min_abbrev: 2, // :ea[rlier]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "later",
         description: "go to newer change, redo",
         availability: Availability::Common,
         min_abbrev: 3, // :lat[er]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "redo",
         description: "redo one undone change",
         availability: Availability::Common,
         min_abbrev: 3, // :red[o]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "rundo",
         description: "read undo information from a file",
         availability: Availability::Common,
         min_abbrev: 4, // :rund[o]
+        range: false,
+        bang: false,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "undo",
         description: "undo last change(s)",
         availability: Availability::Common,
         min_abbrev: 1, // :u[ndo]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "undojoin",
         description: "join next change with previous undo block",
         availability: Availability::Common,
         min_abbrev: 5, // :undoj[oin]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "undolist",
         description: "list leafs of the undo tree",
         availability: Availability::Common,
         min_abbrev: 5, // :undol[ist]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "wundo",
         description: "write undo information to a file",
         availability: Availability::Common,
         min_abbrev: 2, // :wu[ndo]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "changes",
         description: "print the change list",
         availability: Availability::Common,
         min_abbrev: 7, // :changes
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "clearjumps",
         description: "clear the jump list",
         availability: Availability::Common,
         min_abbrev: 3, // :cle[arjumps]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "delmarks",
         description: "delete marks",
         availability: Availability::Common,
         min_abbrev: 4, // :delm[arks]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "jumps",
         description: "print the jump list",
         availability: Availability::Common,
         min_abbrev: 2, // :ju[mps]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "k",
         description: "set a mark",
         availability: Availability::Common,
         min_abbrev: 1, // :k
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "mark",
         description: "set a mark",
         availability: Availability::Common,
         min_abbrev: 2, // :ma[rk]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "marks",
         description: "list all marks",
         availability: Availability::Common,
         min_abbrev: 5, // :marks
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "ascii",
         description: "print ascii value of character under the cursor",
         availability: Availability::Common,
         min_abbrev: 2, // :as[cii]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "display",
         description: "display registers",
         availability: Availability::Common,
         min_abbrev: 2, // :di[splay]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "registers",
         description: "display the contents of registers",
         availability: Availability::Common,
         min_abbrev: 3, // :reg[isters]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cscope",
         description: "execute cscope command",
         availability: Availability::Common,
         min_abbrev: 2, // :cs[cope]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cstag",
         description: "use cscope to jump to a tag",
         availability: Availability::Common,
         min_abbrev: 3, // :cst[ag]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lcscope",
         description: "like \":cscope\" but uses location list",
         availability: Availability::Common,
         min_abbrev: 3, // :lcs[cope]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "pop",
         description: "jump to older entry in tag stack",
         availability: Availability::Common,
         min_abbrev: 2, // :po[p]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "ptNext",
         description: ":tNext in preview window",
         availability: Availability::Common,
         min_abbrev: 3, // :ptN[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "ptag",
         description: "show tag in preview window",
         availability: Availability::Common,
         min_abbrev: 2, // :pt[ag]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "ptfirst",
         description: ":trewind in preview window",
         availability: Availability::Common,
         min_abbrev: 3, // :ptf[irst]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "ptjump",
         description: ":tjump and show tag in preview window",
         availability: Availability::Common,
         min_abbrev: 3, // :ptj[ump]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "ptlast",
         description: ":tlast in preview window",
         availability: Availability::Common,
         min_abbrev: 3, // :ptl[ast]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "ptnext",
         description: ":tnext in preview window",
         availability: Availability::Common,
         min_abbrev: 3, // :ptn[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "ptprevious",
         description: ":tprevious in preview window",
         availability: Availability::Common,
         min_abbrev: 3, // :ptp[revious]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "ptrewind",
         description: ":trewind in preview window",
         availability: Availability::Common,
         min_abbrev: 3, // :ptr[ewind]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "ptselect",
         description: ":tselect and show tag in preview window",
         availability: Availability::Common,
         min_abbrev: 3, // :pts[elect]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "scscope",
         description: "split window and execute cscope command",
         availability: Availability::Common,
         min_abbrev: 3, // :scs[cope]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "stag",
         description: "split window and jump to a tag",
         availability: Availability::Common,
         min_abbrev: 3, // :sta[g]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "stjump",
         description: "do \":tjump\" and split window",
         availability: Availability::Common,
         min_abbrev: 3, // :stj[ump]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "stselect",
         description: "do \":tselect\" and split window",
         availability: Availability::Common,
         min_abbrev: 3, // :sts[elect]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tNext",
         description: "jump to previous matching tag",
         availability: Availability::Common,
         min_abbrev: 2, // :tN[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "tag",
         description: "jump to tag",
         availability: Availability::Common,
         min_abbrev: 2, // :ta[g]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tags",
         description: "show the contents of the tag stack",
         availability: Availability::Common,
         min_abbrev: 4, // :tags
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "tfirst",
         description: "jump to first matching tag",
         availability: Availability::Common,
         min_abbrev: 2, // :tf[irst]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "tjump",
         description: "like \":tselect\", but jump directly when there is only one match",
         availability: Availability::Common,
         min_abbrev: 2, // :tj[ump]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tlast",
         description: "jump to last matching tag",
         availability: Availability::Common,
         min_abbrev: 2, // :tl[ast]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "tnext",
         description: "jump to next matching tag",
         availability: Availability::Common,
         min_abbrev: 2, // :tn[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "tprevious",
         description: "jump to previous matching tag",
         availability: Availability::Common,
         min_abbrev: 2, // :tp[revious]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "trewind",
         description: "jump to first matching tag",
         availability: Availability::Common,
         min_abbrev: 2, // :tr[ewind]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "tselect",
         description: "list matching tags and select one",
         availability: Availability::Common,
         min_abbrev: 2, // :ts[elect]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tabNext",
         description: "go to previous tab page",
         availability: Availability::Common,
         min_abbrev: 4, // :tabN[ext]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tabdo",
         description: "execute command in each tab page",
         availability: Availability::Common,
         min_abbrev: 4, // :tabd[o]
+        range: true,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "tabedit",
         description: "edit a file in a new tab page",
         availability: Availability::Common,
         min_abbrev: 4, // :tabe[dit]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "tabfind",
         description: "find file in 'path', edit it in a new tab page",
         availability: Availability::Common,
         min_abbrev: 4, // :tabf[ind]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "tabfirst",
         description: "go to first tab page",
         availability: Availability::Common,
         min_abbrev: 6, // :tabfir[st]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "tablast",
         description: "go to last tab page",
         availability: Availability::Common,
         min_abbrev: 4, // :tabl[ast]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "tabmove",
         description: "move tab page to other position",
         availability: Availability::Common,
         min_abbrev: 4, // :tabm[ove]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tabonly",
         description: "close all tab pages except the current one",
         availability: Availability::Common,
         min_abbrev: 4, // :tabo[nly]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tabrewind",
         description: "go to first tab page",
         availability: Availability::Common,
         min_abbrev: 4, // :tabr[ewind]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "tabs",
         description: "list the tab pages and what they contain",
         availability: Availability::Common,
         min_abbrev: 4, // :tabs
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "all",
         description: "open a window for each file in the argument list",
         availability: Availability::Common,
         min_abbrev: 2, // :al[l]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "pbuffer",
         description: "edit buffer in the preview window",
         availability: Availability::Common,
         min_abbrev: 2, // :pb[uffer]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "pclose",
         description: "close preview window",
         availability: Availability::Common,
         min_abbrev: 2, // :pc[lose]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "pedit",
         description: "edit file in the preview window",
         availability: Availability::Common,
         min_abbrev: 3, // :ped[it]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "ppop",
         description: "\":pop\" in preview window",
         availability: Availability::Common,
         min_abbrev: 2, // :pp[op]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "psearch",
         description: "like \":ijump\" but shows match in preview window",
         availability: Availability::Common,
         min_abbrev: 2, // :ps[earch]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "resize",
         description: "change current window height",
         availability: Availability::Common,
         min_abbrev: 3, // :res[ize]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sNext",
         description: "split window and go to previous file in argument list",
         availability: Availability::Common,
         min_abbrev: 2, // :sN[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "sall",
         description: "open a window for each file in argument list",
         availability: Availability::Common,
         min_abbrev: 3, // :sal[l]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sargument",
         description: "split window and go to specific file in argument list",
         availability: Availability::Common,
         min_abbrev: 2, // :sa[rgument]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "sbNext",
         description: "split window and go to previous file in the buffer list",
         availability: Availability::Common,
         min_abbrev: 3, // :sbN[ext]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sball",
         description: "open a window for each file in the buffer list",
         availability: Availability::Common,
         min_abbrev: 3, // :sba[ll]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sbfirst",
         description: "split window and go to first file in the buffer list",
         availability: Availability::Common,
         min_abbrev: 3, // :sbf[irst]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sblast",
         description: "split window and go to last file in buffer list",
         availability: Availability::Common,
         min_abbrev: 3, // :sbl[ast]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sbmodified",
         description: "split window and go to modified file in the buffer list",
         availability: Availability::Common,
         min_abbrev: 3, // :sbm[odified]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sbnext",
         description: "split window and go to next file in the buffer list",
         availability: Availability::Common,
         min_abbrev: 3, // :sbn[ext]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sbprevious",
         description: "split window and go to previous file in the buffer list",
         availability: Availability::Common,
         min_abbrev: 3, // :sbp[revious]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sbrewind",
         description: "split window and go to first file in the buffer list",
         availability: Availability::Common,
         min_abbrev: 3, // :sbr[ewind]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sbuffer",
         description: "split window and go to specific file in the buffer list",
         availability: Availability::Common,
         min_abbrev: 2, // :sb[uffer]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sfind",
         description: "split current window and edit file in 'path'",
         availability: Availability::Common,
         min_abbrev: 2, // :sf[ind]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "sfirst",
         description: "split window and go to first file in the argument list",
         availability: Availability::Common,
         min_abbrev: 4, // :sfir[st]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "slast",
         description: "split window and go to last file in the argument list",
         availability: Availability::Common,
         min_abbrev: 3, // :sla[st]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "snext",
         description: "split window and go to next file in the argument list",
         availability: Availability::Common,
         min_abbrev: 2, // :sn[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "sprevious",
         description: "split window and go to previous file in the argument list",
         availability: Availability::Common,
         min_abbrev: 3, // :spr[evious]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "srewind",
         description: "split window and go to first file in the argument list",
         availability: Availability::Common,
         min_abbrev: 3, // :sre[wind]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "sunhide",
         description: "same as \":unhide\"",
         availability: Availability::Common,
         min_abbrev: 3, // :sun[hide]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sview",
         description: "split window and edit file read-only",
         availability: Availability::Common,
         min_abbrev: 2, // :sv[iew]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "unhide",
         description: "open a window for each loaded file in the buffer list",
         availability: Availability::Common,
         min_abbrev: 3, // :unh[ide]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "wincmd",
         description: "execute a Window (CTRL-W) command",
         availability: Availability::Common,
         min_abbrev: 4, // :winc[md]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "windo",
         description: "execute command in each window",
         availability: Availability::Common,
         min_abbrev: 4, // :wind[o]
+        range: true,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "winpos",
         description: "get or set window position",
         availability: Availability::Common,
         min_abbrev: 4, // :winp[os]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "winsize",
         description: "get or set window size (obsolete)",
         availability: Availability::Common,
         min_abbrev: 2, // :wi[nsize]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "X",
         description: "ask for encryption key",
         availability: Availability::Common,
         min_abbrev: 1, // :X
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "drop",
         description: "jump to window editing file or edit file in current window",
         availability: Availability::Common,
         min_abbrev: 2, // :dr[op]
+        range: false,
+        bang: false,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "ex",
         description: "same as \":edit\"",
         availability: Availability::Common,
         min_abbrev: 2, // :ex
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "exit",
         description: "same as \":xit\"",
         availability: Availability::Common,
         min_abbrev: 3, // :exi[t]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "file",
         description: "show or set the current file name",
         availability: Availability::Common,
         min_abbrev: 1, // :f[ile]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "find",
         description: "find file in 'path' and edit it",
         availability: Availability::Common,
         min_abbrev: 3, // :fin[d]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "loadview",
         description: "load view for current window from a file",
         availability: Availability::Common,
         min_abbrev: 2, // :lo[adview]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "mkexrc",
         description: "write current mappings and settings to a file",
         availability: Availability::Common,
         min_abbrev: 2, // :mk[exrc]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "mksession",
         description: "write session info to a file",
         availability: Availability::Common,
         min_abbrev: 3, // :mks[ession]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "mkview",
         description: "write view of current window to a file",
         availability: Availability::Common,
         min_abbrev: 5, // :mkvie[w]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "mkvimrc",
         description: "write current mappings and settings to a file",
         availability: Availability::Common,
         min_abbrev: 3, // :mkv[imrc]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "oldfiles",
         description: "list files that have marks in the viminfo file",
         availability: Availability::Common,
         min_abbrev: 2, // :ol[dfiles]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "preserve",
         description: "write all text to swap file",
         availability: Availability::Common,
         min_abbrev: 3, // :pre[serve]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "quitall",
         description: "quit Vim",
         availability: Availability::Common,
         min_abbrev: 5, // :quita[ll]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "recover",
         description: "recover a file from a swap file",
         availability: Availability::Common,
         min_abbrev: 3, // :rec[over]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "rviminfo",
         description: "read from viminfo file",
         availability: Availability::Common,
         min_abbrev: 2, // :rv[iminfo]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "swapname",
         description: "show the name of the current swap file",
         availability: Availability::Common,
         min_abbrev: 2, // :sw[apname]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "update",
         description: "write buffer if modified",
         availability: Availability::Common,
         min_abbrev: 2, // :up[date]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "view",
         description: "edit a file read-only",
         availability: Availability::Common,
         min_abbrev: 3, // :vie[w]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "visual",
         description: "same as \":edit\", but turns off \"Ex\" mode",
         availability: Availability::Common,
         min_abbrev: 2, // :vi[sual]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "wNext",
         description: "write to a file and go to previous file in argument list",
         availability: Availability::Common,
         min_abbrev: 2, // :wN[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "wnext",
         description: "write to a file and go to next file in argument list",
         availability: Availability::Common,
         min_abbrev: 2, // :wn[ext]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "wprevious",
         description: "write to a file and go to previous file in argument list",
         availability: Availability::Common,
         min_abbrev: 2, // :wp[revious]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "wviminfo",
         description: "write to viminfo file",
         availability: Availability::Common,
         min_abbrev: 2, // :wv[iminfo]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "xall",
         description: "same as \":wqall\"",
         availability: Availability::Common,
         min_abbrev: 2, // :xa[ll]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "xit",
         description: "write if buffer changed and close window",
         availability: Availability::Common,
         min_abbrev: 1, // :x[it]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "diffget",
         description: "remove differences in current buffer",
         availability: Availability::Common,
         min_abbrev: 5, // :diffg[et]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "diffoff",
         description: "switch off diff mode",
         availability: Availability::Common,
         min_abbrev: 5, // :diffo[ff]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "diffpatch",
         description: "apply a patch and show differences",
         availability: Availability::Common,
         min_abbrev: 5, // :diffp[atch]
+        range: false,
+        bang: false,
+        args: CommandArgs::Pattern,
     },
     BuiltinCommand {
         name: "diffput",
         description: "remove differences in other buffer",
         availability: Availability::Common,
         min_abbrev: 6, // :diffpu[t]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "diffsplit",
         description: "show differences with another file",
         availability: Availability::Common,
         min_abbrev: 5, // :diffs[plit]
+        range: false,
+        bang: false,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "diffthis",
         description: "make current window a diff window",
         availability: Availability::Common,
         min_abbrev: 5, // :difft[his]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "diffupdate",
         description: "update 'diff' buffers",
         availability: Availability::Common,
         min_abbrev: 3, // :dif[fupdate]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "fold",
         description: "create a fold",
         availability: Availability::Common,
         min_abbrev: 2, // :fo[ld]
+        range: true,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "foldclose",
         description: "close folds",
         availability: Availability::Common,
         min_abbrev: 5, // :foldc[lose]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "folddoclosed",
         description: "execute command on lines in a closed fold",
         availability: Availability::Common,
         min_abbrev: 7, // :folddoc[losed]
+        range: true,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "folddoopen",
         description: "execute command on lines not in a closed fold",
         availability: Availability::Common,
         min_abbrev: 5, // :foldd[oopen]
+        range: true,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "foldopen",
         description: "open folds",
         availability: Availability::Common,
         min_abbrev: 5, // :foldo[pen]
+        range: true,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "abbreviate",
         description: "enter abbreviation",
         availability: Availability::Common,
         min_abbrev: 2, // :ab[breviate]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "abclear",
         description: "remove all abbreviations",
         availability: Availability::Common,
         min_abbrev: 3, // :abc[lear]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cabbrev",
         description: "like \":abbreviate\" but for Command-line mode",
         availability: Availability::Common,
         min_abbrev: 2, // :ca[bbrev]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cabclear",
         description: "clear all abbreviations for Command-line mode",
         availability: Availability::Common,
         min_abbrev: 4, // :cabc[lear]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cnoreabbrev",
         description: "like \":noreabbrev\" but for Command-line mode",
         availability: Availability::Common,
         min_abbrev: 6, // :cnorea[bbrev]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cunabbrev",
         description: "like \":unabbrev\" but for Command-line mode",
         availability: Availability::Common,
         min_abbrev: 4, // :cuna[bbrev]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "iabbrev",
         description: "like \":abbrev\" but for Insert mode",
         availability: Availability::Common,
         min_abbrev: 2, // :ia[bbrev]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "iabclear",
         description: "like \":abclear\" but for Insert mode",
         availability: Availability::Common,
         min_abbrev: 4, // :iabc[lear]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "inoreabbrev",
         description: "like \":noreabbrev\" but for Insert mode",
         availability: Availability::Common,
         min_abbrev: 6, // :inorea[bbrev]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "iunabbrev",
         description: "like \":unabbrev\" but for Insert mode",
         availability: Availability::Common,
         min_abbrev: 4, // :iuna[bbrev]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "noreabbrev",
         description: "enter an abbreviation that will not be remapped",
         availability: Availability::Common,
         min_abbrev: 5, // :norea[bbrev]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "unabbreviate",
         description: "remove abbreviation",
         availability: Availability::Common,
         min_abbrev: 3, // :una[bbreviate]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "amenu",
         description: "enter new menu item for all modes",
         availability: Availability::Common,
         min_abbrev: 2, // :am[enu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "anoremenu",
         description: "enter a new menu for all modes that will not be remapped",
         availability: Availability::Common,
         min_abbrev: 2, // :an[oremenu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "aunmenu",
         description: "remove menu for all modes",
         availability: Availability::Common,
         min_abbrev: 3, // :aun[menu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cmenu",
         description: "add menu for Command-line mode",
         availability: Availability::Common,
         min_abbrev: 3, // :cme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cnoremenu",
         description: "like \":noremenu\" but for Command-line mode",
         availability: Availability::Common,
         min_abbrev: 7, // :cnoreme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cunmenu",
         description: "remove menu for Command-line mode",
         availability: Availability::Common,
         min_abbrev: 5, // :cunme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "emenu",
         description: "execute a menu by name",
         availability: Availability::Common,
         min_abbrev: 2, // :em[enu]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "imenu",
         description: "add menu for Insert mode",
         availability: Availability::Common,
         min_abbrev: 3, // :ime[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "inoremenu",
         description: "like \":noremenu\" but for Insert mode",
         availability: Availability::Common,
         min_abbrev: 7, // :inoreme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "iunmenu",
         description: "remove menu for Insert mode",
         availability: Availability::Common,
         min_abbrev: 5, // :iunme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "menu",
         description: "enter a new menu item",
         availability: Availability::Common,
         min_abbrev: 2, // :me[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "menutranslate",
         description: "add a menu translation item",
         availability: Availability::Common,
         min_abbrev: 5, // :menut[ranslate]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "nmenu",
         description: "add menu for Normal mode",
         availability: Availability::Common,
         min_abbrev: 3, // :nme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "nnoremenu",
         description: "like \":noremenu\" but for Normal mode",
         availability: Availability::Common,
         min_abbrev: 7, // :nnoreme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "noremenu",
         description: "enter a menu that will not be remapped",
         availability: Availability::Common,
         min_abbrev: 6, // :noreme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "nunmenu",
         description: "remove menu for Normal mode",
         availability: Availability::Common,
         min_abbrev: 5, // :nunme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "omenu",
         description: "add menu for Operator-pending mode",
         availability: Availability::Common,
         min_abbrev: 3, // :ome[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "onoremenu",
         description: "like \":noremenu\" but for Operator-pending mode",
         availability: Availability::Common,
         min_abbrev: 7, // :onoreme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "ounmenu",
         description: "remove menu for Operator-pending mode",
         availability: Availability::Common,
         min_abbrev: 5, // :ounme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "popup",
         description: "popup a menu by name",
         availability: Availability::Common,
         min_abbrev: 4, // :popu[p]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "smenu",
         description: "add menu for Select mode",
         availability: Availability::Common,
         min_abbrev: 3, // :sme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "snoremenu",
         description: "like \":noremenu\" but for Select mode",
         availability: Availability::Common,
         min_abbrev: 7, // :snoreme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sunmenu",
         description: "remove menu for Select mode",
         availability: Availability::Common,
         min_abbrev: 5, // :sunme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tearoff",
         description: "tear-off a menu",
         availability: Availability::Common,
         min_abbrev: 2, // :te[aroff]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tlmenu",
         description: "add menu for Terminal-Job mode",
         availability: Availability::Common,
         min_abbrev: 3, // :tlm[enu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tlnoremenu",
         description: "like \":noremenu\" but for Terminal-Job mode",
         availability: Availability::Common,
         min_abbrev: 3, // :tln[oremenu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tlunmenu",
         description: "remove menu for Terminal-Job mode",
         availability: Availability::Common,
         min_abbrev: 3, // :tlu[nmenu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tmenu",
         description: "define menu tooltip",
         availability: Availability::Common,
         min_abbrev: 2, // :tm[enu]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "tunmenu",
         description: "remove menu tooltip",
         availability: Availability::Common,
         min_abbrev: 2, // :tu[nmenu]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "unmenu",
         description: "remove menu",
         availability: Availability::Common,
         min_abbrev: 4, // :unme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "vmenu",
         description: "add menu for Visual+Select mode",
         availability: Availability::Common,
         min_abbrev: 3, // :vme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "vnoremenu",
         description: "like \":noremenu\" but for Visual+Select mode",
         availability: Availability::Common,
         min_abbrev: 7, // :vnoreme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "vunmenu",
         description: "remove menu for Visual+Select mode",
         availability: Availability::Common,
         min_abbrev: 5, // :vunme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "xmenu",
         description: "add menu for Visual mode",
         availability: Availability::Common,
         min_abbrev: 3, // :xme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "xnoremenu",
         description: "like \":noremenu\" but for Visual mode",
         availability: Availability::Common,
         min_abbrev: 7, // :xnoreme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "xunmenu",
         description: "remove menu for Visual mode",
         availability: Availability::Common,
         min_abbrev: 5, // :xunme[nu]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "cmapclear",
         description: "clear all mappings for Command-line mode",
         availability: Availability::Common,
         min_abbrev: 5, // :cmapc[lear]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "imapclear",
         description: "like \":mapclear\" but for Insert mode",
         availability: Availability::Common,
         min_abbrev: 5, // :imapc[lear]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "lmapclear",
         description: "like \":mapclear!\" but includes Lang-Arg mode",
         availability: Availability::Common,
         min_abbrev: 5, // :lmapc[lear]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "nmapclear",
         description: "clear all mappings for Normal mode",
         availability: Availability::Common,
         min_abbrev: 5, // :nmapc[lear]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "omapclear",
         description: "remove all mappings for Operator-pending mode",
         availability: Availability::Common,
         min_abbrev: 5, // :omapc[lear]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "smapclear",
         description: "remove all mappings for Select mode",
         availability: Availability::Common,
         min_abbrev: 5, // :smapc[lear]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "tmapclear",
         description: "remove all mappings for Terminal-Job mode",
         availability: Availability::Common,
         min_abbrev: 5, // :tmapc[lear]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "vmapclear",
         description: "remove all mappings for Visual+Select mode",
         availability: Availability::Common,
         min_abbrev: 5, // :vmapc[lear]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "xmapclear",
         description: "remove all mappings for Visual mode",
         availability: Availability::Common,
         min_abbrev: 5, // :xmapc[lear]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "exusage",
         description: "overview of Ex commands",
         availability: Availability::Common,
         min_abbrev: 3, // :exu[sage]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "help",
         description: "open a help window",
         availability: Availability::Common,
         min_abbrev: 1, // :h[elp]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "helpclose",
         description: "close one help window",
         availability: Availability::Common,
         min_abbrev: 5, // :helpc[lose]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "helpfind",
         description: "dialog to open a help window",
         availability: Availability::Common,
         min_abbrev: 5, // :helpf[ind]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "helpgrep",
         description: "like \":grep\" but searches help files",
         availability: Availability::Common,
         min_abbrev: 5, // :helpg[rep]
+        range: false,
+        bang: false,
+        args: CommandArgs::Pattern,
     },
     BuiltinCommand {
         name: "helptags",
         description: "generate help tags for a directory",
         availability: Availability::Common,
         min_abbrev: 5, // :helpt[ags]
+        range: false,
+        bang: false,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "intro",
         description: "print the introductory message",
         availability: Availability::Common,
         min_abbrev: 3, // :int[ro]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "messages",
         description: "view previously displayed messages",
         availability: Availability::Common,
         min_abbrev: 3, // :mes[sages]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "version",
         description: "print version number and other info",
         availability: Availability::Common,
         min_abbrev: 2, // :ve[rsion]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "viusage",
         description: "overview of Normal mode commands",
         availability: Availability::Common,
         min_abbrev: 3, // :viu[sage]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "grep",
         description: "run 'grepprg' and jump to first match",
         availability: Availability::Common,
         min_abbrev: 2, // :gr[ep]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "grepadd",
         description: "like :grep, but append to current list",
         availability: Availability::Common,
         min_abbrev: 5, // :grepa[dd]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "make",
         description: "execute external command 'makeprg' and parse",
         availability: Availability::Common,
         min_abbrev: 3, // :mak[e]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "nohlsearch",
         description: "suspend 'hlsearch' highlighting",
         availability: Availability::Common,
         min_abbrev: 3, // :noh[lsearch]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "smagic",
         description: ":substitute with 'magic'",
         availability: Availability::Common,
         min_abbrev: 2, // :sm[agic]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "snomagic",
         description: ":substitute with 'nomagic'",
         availability: Availability::Common,
         min_abbrev: 3, // :sno[magic]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "vimgrep",
         description: "search for pattern in files",
         availability: Availability::Common,
         min_abbrev: 3, // :vim[grep]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "vimgrepadd",
         description: "like :vimgrep, but append to current list",
         availability: Availability::Common,
         min_abbrev: 7, // :vimgrepa[dd]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "checkpath",
         description: "list included files",
         availability: Availability::Common,
         min_abbrev: 3, // :che[ckpath]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "djump",
         description: "jump to #define",
         availability: Availability::Common,
         min_abbrev: 2, // :dj[ump]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "dl",
         description: "short for :delete with the 'l' flag",
         availability: Availability::Common,
         min_abbrev: 2, // :dl
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "dlist",
         description: "list #defines",
         availability: Availability::Common,
         min_abbrev: 3, // :dli[st]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "dp",
         description: "short for :delete with the 'p' flag",
         availability: Availability::Common,
         min_abbrev: 2, // :dp
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "dsearch",
         description: "list one #define",
         availability: Availability::Common,
         min_abbrev: 2, // :ds[earch]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "dsplit",
         description: "split window and jump to #define",
         availability: Availability::Common,
         min_abbrev: 3, // :dsp[lit]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "ijump",
         description: "jump to definition of identifier",
         availability: Availability::Common,
         min_abbrev: 2, // :ij[ump]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "ilist",
         description: "list lines where identifier matches",
         availability: Availability::Common,
         min_abbrev: 2, // :il[ist]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "iput",
         description: "like :put, but adjust the indent",
         availability: Availability::Common,
         min_abbrev: 2, // :ip[ut]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "isearch",
         description: "list one line where identifier matches",
         availability: Availability::Common,
         min_abbrev: 2, // :is[earch]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "isplit",
         description: "split window and jump to definition of identifier",
         availability: Availability::Common,
         min_abbrev: 3, // :isp[lit]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "mkspell",
         description: "produce .spl spell file",
         availability: Availability::Common,
         min_abbrev: 4, // :mksp[ell]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "spelldump",
         description: "split window and fill with all correct words",
         availability: Availability::Common,
         min_abbrev: 6, // :spelld[ump]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "spellgood",
         description: "add good word for spelling",
         availability: Availability::Common,
         min_abbrev: 3, // :spe[llgood]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "spellinfo",
         description: "show info about loaded spell files",
         availability: Availability::Common,
         min_abbrev: 6, // :spelli[nfo]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "spellrare",
         description: "add rare word for spelling",
         availability: Availability::Common,
         min_abbrev: 7, // :spellra[re]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "spellrepall",
         description: "replace all bad words like last |z=|",
         availability: Availability::Common,
         min_abbrev: 6, // :spellr[epall]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "spellundo",
         description: "remove good or bad word",
         availability: Availability::Common,
         min_abbrev: 6, // :spellu[ndo]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "spellwrong",
         description: "add spelling mistake",
         availability: Availability::Common,
         min_abbrev: 6, // :spellw[rong]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "breakadd",
         description: "add a debugger breakpoint",
         availability: Availability::Common,
         min_abbrev: 6, // :breaka[dd]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "breakdel",
         description: "delete a debugger breakpoint",
         availability: Availability::Common,
         min_abbrev: 6, // :breakd[el]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "breaklist",
         description: "list debugger breakpoints",
         availability: Availability::Common,
         min_abbrev: 6, // :breakl[ist]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "debug",
         description: "run a command in debugging mode",
         availability: Availability::Common,
         min_abbrev: 3, // :deb[ug]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "debuggreedy",
         description: "read debug mode commands from normal input",
         availability: Availability::Common,
         min_abbrev: 6, // :debugg[reedy]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "profile",
         description: "profiling functions and scripts",
         availability: Availability::Common,
         min_abbrev: 4, // :prof[ile]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "profdel",
         description: "stop profiling a function or script",
         availability: Availability::Common,
         min_abbrev: 5, // :profd[el]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "delfunction",
         description: "delete a user function",
         availability: Availability::Common,
         min_abbrev: 4, // :delf[unction]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "scriptencoding",
         description: "encoding used in sourced Vim script",
         availability: Availability::Common,
         min_abbrev: 7, // :scripte[ncoding]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "scriptnames",
         description: "list names of all sourced Vim scripts",
         availability: Availability::Common,
         min_abbrev: 3, // :scr[iptnames]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "scriptversion",
         description: "version of Vim script used",
         availability: Availability::Common,
         min_abbrev: 7, // :scriptv[ersion]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "behave",
         description: "set mouse and selection behavior",
         availability: Availability::Common,
         min_abbrev: 2, // :be[have]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "checktime",
         description: "check timestamp of loaded buffers",
         availability: Availability::Common,
         min_abbrev: 6, // :checkt[ime]
+        range: true,
+        bang: false,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "clipreset",
         description: "reset 'clipmethod'",
         availability: Availability::Common,
         min_abbrev: 4, // :clip[reset]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "compiler",
         description: "do settings for a specific compiler",
         availability: Availability::Common,
         min_abbrev: 4, // :comp[iler]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "defer",
         description: "call function when current function is done",
         availability: Availability::Common,
         min_abbrev: 4, // :defe[r]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "digraphs",
         description: "show or enter digraphs",
         availability: Availability::Common,
         min_abbrev: 3, // :dig[raphs]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "eval",
         description: "evaluate an expression and discard the result",
         availability: Availability::Common,
         min_abbrev: 2, // :ev[al]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "filter",
         description: "filter output of following command",
         availability: Availability::Common,
         min_abbrev: 4, // :filt[er]
+        range: false,
+        bang: true,
+        args: CommandArgs::Pattern,
     },
     BuiltinCommand {
         name: "fixdel",
         description: "set key code of <Del>",
         availability: Availability::Common,
         min_abbrev: 3, // :fix[del]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "goto",
         description: "go to byte in the buffer",
         availability: Availability::Common,
         min_abbrev: 2, // :go[to]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "hardcopy",
         description: "send text to the printer",
         availability: Availability::Common,
         min_abbrev: 2, // :ha[rdcopy]
+        range: true,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "history",
         description: "print a history list",
         availability: Availability::Common,
         min_abbrev: 3, // :his[tory]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "keepalt",
         description: "following command keeps the alternate file",
         availability: Availability::Common,
         min_abbrev: 5, // :keepa[lt]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "keepjumps",
         description: "following command keeps jumplist and marks",
         availability: Availability::Common,
         min_abbrev: 5, // :keepj[umps]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "keepmarks",
         description: "following command keeps marks where they are",
         availability: Availability::Common,
         min_abbrev: 3, // :kee[pmarks]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "keeppatterns",
         description: "following command keeps search pattern history",
         availability: Availability::Common,
         min_abbrev: 5, // :keepp[atterns]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "language",
         description: "set the language (locale)",
         availability: Availability::Common,
         min_abbrev: 3, // :lan[guage]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "legacy",
         description: "make following command use legacy script syntax",
         availability: Availability::Common,
         min_abbrev: 3, // :leg[acy]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "loadkeymap",
         description: "load the following keymaps until EOF",
         availability: Availability::Common,
         min_abbrev: 5, // :loadk[eymap]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "lockmarks",
         description: "following command keeps marks where they are",
         availability: Availability::Common,
         min_abbrev: 3, // :loc[kmarks]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "lsp",
         description: "LSP client command",
         availability: Availability::Common,
         min_abbrev: 3, // :lsp (no abbreviation in Vim docs)
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "match",
         description: "define a match to highlight",
         availability: Availability::Common,
         min_abbrev: 3, // :mat[ch]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "mode",
         description: "show or change the screen mode",
         availability: Availability::Common,
         min_abbrev: 3, // :mod[e]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "noautocmd",
         description: "following commands don't trigger autocommands",
         availability: Availability::Common,
         min_abbrev: 3, // :noa[utocmd]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "noswapfile",
         description: "following commands don't create a swap file",
         availability: Availability::Common,
         min_abbrev: 3, // :nos[wapfile]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "open",
         description: "start open mode (not implemented)",
         availability: Availability::Common,
         min_abbrev: 1, // :o[pen]
+        range: true,
+        bang: false,
+        args: CommandArgs::Pattern,
     },
     BuiltinCommand {
         name: "options",
         description: "open the options-window",
         availability: Availability::Common,
         min_abbrev: 3, // :opt[ions]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "ownsyntax",
         description: "set new local syntax highlight for this window",
         availability: Availability::Common,
         min_abbrev: 2, // :ow[nsyntax]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "packadd",
         description: "add a plugin from 'packpath'",
         availability: Availability::Common,
         min_abbrev: 2, // :pa[ckadd]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "packloadall",
         description: "load all packages under 'packpath'",
         availability: Availability::Common,
         min_abbrev: 5, // :packl[oadall]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "range",
         description: "go to last line in {range}",
         availability: Availability::Common,
         min_abbrev: 5, // :range (special command, no abbreviation)
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "redir",
         description: "redirect messages to a file or register",
         availability: Availability::Common,
         min_abbrev: 4, // :redi[r]
+        range: false,
+        bang: true,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "redrawstatus",
         description: "force a redraw of the status line(s)",
         availability: Availability::Common,
         min_abbrev: 7, // :redraws[tatus]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "redrawtabline",
         description: "force a redraw of the tabline",
         availability: Availability::Common,
         min_abbrev: 7, // :redrawt[abline]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "redrawtabpanel",
         description: "force a redraw of the tabpanel",
         availability: Availability::Common,
         min_abbrev: 10, // :redrawtabp[anel]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "restart",
         description: "restart Vim",
         availability: Availability::Common,
         min_abbrev: 7, // :restart (no abbreviation in Vim docs)
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sandbox",
         description: "execute a command in the sandbox",
         availability: Availability::Common,
         min_abbrev: 3, // :san[dbox]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "setfiletype",
         description: "set 'filetype', unless it was set already",
         availability: Availability::Common,
         min_abbrev: 4, // :setf[iletype]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "shell",
         description: "escape to a shell",
         availability: Availability::Common,
         min_abbrev: 2, // :sh[ell]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "sign",
         description: "manipulate signs",
         availability: Availability::Common,
         min_abbrev: 3, // :sig[n]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "sleep!",
         description: "do nothing for a few seconds, without the cursor visible",
         availability: Availability::Common,
         min_abbrev: 2, // :sl[eep]!
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "smile",
         description: "make the user happy",
         availability: Availability::Common,
         min_abbrev: 3, // :smi[le]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "star",
         description: "use the last Visual area, like :'<,'>",
         availability: Availability::Common,
         min_abbrev: 4, // :* (special command)
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "startgreplace",
         description: "start Virtual Replace mode",
         availability: Availability::Common,
         min_abbrev: 6, // :startg[replace]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "startinsert",
         description: "start Insert mode",
         availability: Availability::Common,
         min_abbrev: 4, // :star[tinsert]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "startreplace",
         description: "start Replace mode",
         availability: Availability::Common,
         min_abbrev: 6, // :startr[eplace]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "stop",
         description: "suspend the editor or escape to a shell",
         availability: Availability::Common,
         min_abbrev: 2, // :st[op]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "stopinsert",
         description: "stop Insert mode",
         availability: Availability::Common,
         min_abbrev: 5, // :stopi[nsert]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "suspend",
         description: "same as \":stop\"",
         availability: Availability::Common,
         min_abbrev: 3, // :sus[pend]
+        range: false,
+        bang: true,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "syncbind",
         description: "sync scroll binding",
         availability: Availability::Common,
         min_abbrev: 4, // :sync[bind]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "syntime",
         description: "measure syntax highlighting speed",
         availability: Availability::Common,
         min_abbrev: 5, // :synti[me]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "unsilent",
         description: "run a command not silently",
         availability: Availability::Common,
         min_abbrev: 3, // :uns[ilent]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "verbose",
         description: "execute command with 'verbose' set",
         availability: Availability::Common,
         min_abbrev: 4, // :verb[ose]
+        range: true,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "echoconsole",
         description: "like :echomsg but write to stdout",
         availability: Availability::Common,
         min_abbrev: 5, // :echoc[onsole]
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "fclose",
         description: "close file dialog",
         availability: Availability::Common,
         min_abbrev: 6, // :fclose (no abbreviation in Vim docs)
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "gui",
         description: "start the GUI",
         availability: Availability::Common,
         min_abbrev: 2, // :gu[i]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "gvim",
         description: "start the GUI",
         availability: Availability::Common,
         min_abbrev: 2, // :gv[im]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "promptfind",
         description: "open GUI dialog for searching",
         availability: Availability::Common,
         min_abbrev: 3, // :pro[mptfind]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "promptrepl",
         description: "open GUI dialog for search/replace",
         min_abbrev: 7, // :promptr[epl]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
         availability: Availability::Common,
     },
     BuiltinCommand {
@@ -8169,324 +5584,486 @@ pub static BUILTIN_COMMANDS: &[BuiltinCommand] = &[
         description: "Win32 GUI: simulate Windows ALT key",
         availability: Availability::Common,
         min_abbrev: 3, // :sim[alt]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "wlrestore",
         description: "restore the Wayland compositor connection",
         availability: Availability::Common,
         min_abbrev: 2, // :wl[restore]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "xrestore",
         description: "restores the X server connection",
         availability: Availability::Common,
         min_abbrev: 2, // :xr[estore]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "perl",
         description: "execute Perl command",
         availability: Availability::Common,
         min_abbrev: 2, // :pe[rl]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "perldo",
         description: "execute Perl command for each line",
         availability: Availability::Common,
         min_abbrev: 5, // :perld[o]
+        range: true,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "perlfile",
         description: "execute Perl script file",
         availability: Availability::Common,
         min_abbrev: 8, // :perlfile (no abbreviation in Vim docs)
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "py3",
         description: "execute Python 3 command",
         availability: Availability::Common,
         min_abbrev: 3, // :py3
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "py3do",
         description: "execute Python 3 command for each line",
         availability: Availability::Common,
         min_abbrev: 4, // :py3d[o]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "py3file",
         description: "execute Python 3 script file",
         availability: Availability::Common,
         min_abbrev: 4, // :py3f[ile]
+        range: true,
+        bang: false,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "pydo",
         description: "execute Python command for each line",
         availability: Availability::Common,
         min_abbrev: 3, // :pyd[o]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "pyfile",
         description: "execute Python script file",
         availability: Availability::Common,
         min_abbrev: 3, // :pyf[ile]
+        range: true,
+        bang: false,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "python",
         description: "execute Python command",
         availability: Availability::Common,
         min_abbrev: 2, // :py[thon]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "python3",
         description: "same as :py3",
         availability: Availability::Common,
         min_abbrev: 7, // :python3
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "pythonx",
         description: "same as :pyx",
         availability: Availability::Common,
         min_abbrev: 7, // :pythonx
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "pyx",
         description: "execute python_x command",
         availability: Availability::Common,
         min_abbrev: 3, // :pyx
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "pyxdo",
         description: "execute python_x command for each line",
         availability: Availability::Common,
         min_abbrev: 4, // :pyxd[o]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "pyxfile",
         description: "execute python_x script file",
         availability: Availability::Common,
         min_abbrev: 4, // :pyxf[ile]
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "ruby",
         description: "execute Ruby command",
         availability: Availability::Common,
         min_abbrev: 3, // :rub[y]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "rubydo",
         description: "execute Ruby command for each line",
         availability: Availability::Common,
         min_abbrev: 5, // :rubyd[o]
+        range: true,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "rubyfile",
         description: "execute Ruby script file",
         availability: Availability::Common,
         min_abbrev: 5, // :rubyf[ile]
+        range: false,
+        bang: false,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "tcl",
         description: "execute Tcl command",
         availability: Availability::Common,
         min_abbrev: 3, // :tcl
+        range: true,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "tcldo",
         description: "execute Tcl command for each line",
         availability: Availability::Common,
         min_abbrev: 4, // :tcld[o]
+        range: true,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "tclfile",
         description: "execute Tcl script file",
         availability: Availability::Common,
         min_abbrev: 4, // :tclf[ile]
+        range: false,
+        bang: false,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "mzfile",
         description: "execute MzScheme script file",
         availability: Availability::Common,
         min_abbrev: 3, // :mzf[ile]
+        range: true,
+        bang: false,
+        args: CommandArgs::File,
     },
     BuiltinCommand {
         name: "mzscheme",
         description: "execute MzScheme command",
         availability: Availability::Common,
         min_abbrev: 2, // :mz[scheme]
+        range: true,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "nbclose",
         description: "close the current Netbeans session",
         availability: Availability::Common,
         min_abbrev: 3, // :nbc[lose]
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "nbkey",
         description: "pass a key to Netbeans",
         availability: Availability::Common,
         min_abbrev: 2, // :nb[key]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "nbstart",
         description: "start a new Netbeans session",
         availability: Availability::Common,
         min_abbrev: 3, // :nbs[tart]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "checkhealth",
         description: "run health checks",
         availability: Availability::NeovimOnly,
         min_abbrev: 11, // :checkhealth (Neovim only)
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "terminal",
         description: "open a terminal window",
         availability: Availability::NeovimOnly,
         min_abbrev: 3, // :ter[minal]
+        range: true,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "rshada",
         description: "read from shada file",
         availability: Availability::NeovimOnly,
         min_abbrev: 6, // :rshada (Neovim only)
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "wshada",
         description: "write to shada file",
         availability: Availability::NeovimOnly,
         min_abbrev: 6, // :wshada (Neovim only)
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "detach",
         description: "detach the current UI",
         availability: Availability::NeovimOnly,
         min_abbrev: 6, // :detach (Neovim only)
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "trust",
         description: "manage trusted files",
         availability: Availability::NeovimOnly,
         min_abbrev: 5, // :trust (Neovim only)
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "abstract",
         description: "declare a Vim9 abstract class",
         availability: Availability::VimOnly,
         min_abbrev: 8, // :abstract
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "class",
         description: "start of a class declaration",
         availability: Availability::VimOnly,
         min_abbrev: 5, // :class
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "def",
         description: "define a Vim9 user function",
         availability: Availability::VimOnly,
         min_abbrev: 3, // :def
+        range: false,
+        bang: true,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "defcompile",
         description: "compile Vim9 user functions in current script",
         availability: Availability::VimOnly,
         min_abbrev: 4, // :defc[ompile]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "disassemble",
         description: "disassemble Vim9 user function",
         availability: Availability::VimOnly,
         min_abbrev: 4, // :disa[ssemble]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "endclass",
         description: "end of a class declaration",
         availability: Availability::VimOnly,
         min_abbrev: 8, // :endclass
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "enddef",
         description: "end of a user function started with :def",
         availability: Availability::VimOnly,
         min_abbrev: 6, // :enddef
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "endenum",
         description: "end of an enum declaration",
         availability: Availability::VimOnly,
         min_abbrev: 7, // :endenum
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "endinterface",
         description: "end of an interface declaration",
         availability: Availability::VimOnly,
         min_abbrev: 12, // :endinterface
+        range: false,
+        bang: false,
+        args: CommandArgs::None,
     },
     BuiltinCommand {
         name: "enum",
         description: "start of an enum declaration",
         availability: Availability::VimOnly,
         min_abbrev: 4, // :enum
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "export",
         description: "Vim9: export an item from a script",
         availability: Availability::VimOnly,
         min_abbrev: 3, // :exp[ort]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
     BuiltinCommand {
         name: "final",
         description: "declare an immutable variable in Vim9",
         availability: Availability::VimOnly,
         min_abbrev: 5, // :final
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "import",
         description: "Vim9: import an item from another script",
         availability: Availability::VimOnly,
         min_abbrev: 3, // :imp[ort]
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "interface",
         description: "start of an interface declaration",
         availability: Availability::VimOnly,
         min_abbrev: 9, // :interface
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "public",
         description: "prefix for a class or object member",
         availability: Availability::VimOnly,
         min_abbrev: 6, // :public
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "static",
         description: "prefix for a class member or function",
         availability: Availability::VimOnly,
         min_abbrev: 6, // :static
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "this",
         description: "prefix for an object member during declaration",
         availability: Availability::VimOnly,
         min_abbrev: 4, // :this
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "type",
         description: "create a type alias",
         availability: Availability::VimOnly,
         min_abbrev: 4, // :type
+        range: false,
+        bang: false,
+        args: CommandArgs::Other,
     },
     BuiltinCommand {
         name: "var",
         description: "variable declaration in Vim9",
         availability: Availability::VimOnly,
         min_abbrev: 3, // :var
+        range: false,
+        bang: false,
+        args: CommandArgs::Expression,
     },
     BuiltinCommand {
         name: "vim9cmd",
         description: "make following command use Vim9 script syntax",
         availability: Availability::VimOnly,
         min_abbrev: 4, // :vim9[cmd]
+        range: false,
+        bang: false,
+        args: CommandArgs::Command,
     },
 ];
 
@@ -8501,6 +6078,50 @@ pub struct AutocmdEvent {
     pub availability: Availability,
 }
 
+impl AutocmdEvent {
+    /// Find an event by name (case-insensitive, like Vim)
+    pub fn find(name: &str) -> Option<&'static AutocmdEvent> {
+        AUTOCMD_EVENTS
+            .iter()
+            .find(|e| e.name.eq_ignore_ascii_case(name))
+    }
+
+    /// Versions that added this event
+    pub fn since(&self) -> Option<&'static Since> {
+        Since::find(versions::EVENT_VERSIONS, self.name)
+    }
+
+    /// What `<amatch>` expands to while executing this event
+    /// Reference: :help <amatch>
+    pub fn amatch(&self) -> &'static str {
+        match self.name {
+            "FileType" => "the new value of 'filetype'",
+            "Syntax" => "the new value of 'syntax'",
+            "OptionSet" => "the name of the option that was set",
+            "ColorScheme" | "ColorSchemePre" => "the name of the color scheme",
+            "FuncUndefined" => "the name of the undefined function",
+            "CmdUndefined" => "the name of the undefined command",
+            "SpellFileMissing" => "the language of the missing spell file",
+            "EncodingChanged" => "the new value of 'encoding'",
+            "QuickFixCmdPre" | "QuickFixCmdPost" => "the name of the quickfix command",
+            "CmdlineChanged" | "CmdlineEnter" | "CmdlineLeave" | "CmdwinEnter" | "CmdwinLeave" => {
+                "the command-line type character (`:`, `/`, ...)"
+            }
+            "ModeChanged" => "`old_mode:new_mode`",
+            "WinScrolled" | "WinResized" | "WinClosed" => "the window ID",
+            "User" => "the pattern given to `:doautocmd User`",
+            name if name.starts_with("Buf")
+                || name.starts_with("File")
+                || name.starts_with("Source")
+                || name.starts_with("Swap") =>
+            {
+                "the full path of the file"
+            }
+            _ => "the matched pattern",
+        }
+    }
+}
+
 /// List of autocmd events
 /// Reference: :help autocmd-events
 pub static AUTOCMD_EVENTS: &[AutocmdEvent] = &[
@@ -9186,6 +6807,115 @@ pub static AUTOCMD_EVENTS: &[AutocmdEvent] = &[
     },
 ];
 
+// ============================================================================
+// Autocmd Patterns
+// ============================================================================
+
+/// Common filetype names for `autocmd FileType` patterns
+/// Reference: $VIMRUNTIME/filetype.vim
+pub static FILETYPES: &[&str] = &[
+    "asm",
+    "awk",
+    "bash",
+    "bib",
+    "c",
+    "changelog",
+    "clojure",
+    "cmake",
+    "conf",
+    "cpp",
+    "cs",
+    "css",
+    "csv",
+    "cuda",
+    "dart",
+    "diff",
+    "dockerfile",
+    "dosbatch",
+    "dot",
+    "elixir",
+    "elm",
+    "erlang",
+    "fish",
+    "fortran",
+    "gitcommit",
+    "gitconfig",
+    "gitrebase",
+    "go",
+    "gomod",
+    "graphql",
+    "groovy",
+    "haskell",
+    "help",
+    "html",
+    "ini",
+    "java",
+    "javascript",
+    "javascriptreact",
+    "json",
+    "jsonc",
+    "julia",
+    "kotlin",
+    "less",
+    "lisp",
+    "lua",
+    "make",
+    "man",
+    "markdown",
+    "netrw",
+    "nix",
+    "ocaml",
+    "perl",
+    "php",
+    "plaintex",
+    "powershell",
+    "proto",
+    "ps1",
+    "python",
+    "qf",
+    "r",
+    "racket",
+    "ruby",
+    "rust",
+    "sass",
+    "scala",
+    "scheme",
+    "scss",
+    "sh",
+    "sql",
+    "svelte",
+    "swift",
+    "tcl",
+    "terraform",
+    "tex",
+    "text",
+    "toml",
+    "typescript",
+    "typescriptreact",
+    "vim",
+    "vue",
+    "xml",
+    "yaml",
+    "zig",
+    "zsh",
+];
+
+/// Pattern snippets for file-based autocmd events (BufRead, BufNewFile, ...)
+///
+/// Each entry is (label, snippet, description).
+pub static AUTOCMD_PATTERN_SNIPPETS: &[(&str, &str, &str)] = &[
+    ("*", "*", "Any file"),
+    ("*.ext", "*.${1:ext}", "Files with an extension"),
+    (
+        "*.{ext1,ext2}",
+        "*.{${1:ext1},${2:ext2}}",
+        "Files with any of several extensions",
+    ),
+    ("*/dir/*", "*/${1:dir}/*", "Files under a directory"),
+    ("name", "${1:name}", "Files with an exact name"),
+    ("<buffer>", "<buffer>", "The current buffer only"),
+];
+
 // ============================================================================
 // Options
 // ============================================================================
@@ -9196,6 +6926,141 @@ pub struct BuiltinOption {
     pub short: Option<&'static str>,
     pub description: &'static str,
     pub availability: Availability,
+    pub kind: OptionType,
+    /// Default value in Vim on Unix, or None when it depends on the system
+    /// (Neovim's own defaults are in `NEOVIM_OPTION_DEFAULTS`)
+    pub default: Option<&'static str>,
+    pub scope: OptionScope,
+    /// Whether the value is a comma-separated list (`+=` and `-=` work on items)
+    pub comma_list: bool,
+    /// Whether the value is a string of single-character flags
+    pub flag_list: bool,
+}
+
+/// Type of an option value
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionType {
+    /// Set with `:set opt` and `:set noopt`
+    Boolean,
+    Number,
+    String,
+}
+
+impl OptionType {
+    pub fn label(self) -> &'static str {
+        match self {
+            OptionType::Boolean => "boolean",
+            OptionType::Number => "number",
+            OptionType::String => "string",
+        }
+    }
+}
+
+/// Where an option value lives
+/// Reference: :help local-options, :help global-local
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionScope {
+    Global,
+    Buffer,
+    Window,
+    /// Global, with an optional buffer-local value
+    GlobalOrBuffer,
+    /// Global, with an optional window-local value
+    GlobalOrWindow,
+    /// Global, with an optional tab page-local value
+    GlobalOrTab,
+}
+
+impl OptionScope {
+    /// Description as written in the help (e.g., "local to buffer")
+    pub fn label(self) -> &'static str {
+        match self {
+            OptionScope::Global => "global",
+            OptionScope::Buffer => "local to buffer",
+            OptionScope::Window => "local to window",
+            OptionScope::GlobalOrBuffer => "global or local to buffer",
+            OptionScope::GlobalOrWindow => "global or local to window",
+            OptionScope::GlobalOrTab => "global or local to tab page",
+        }
+    }
+}
+
+impl BuiltinOption {
+    /// Versions that added this option
+    pub fn since(&self) -> Option<&'static Since> {
+        Since::find(versions::OPTION_VERSIONS, self.name)
+    }
+
+    /// Default value in Neovim, when it differs from Vim's
+    pub fn neovim_default(&self) -> Option<&'static str> {
+        NEOVIM_OPTION_DEFAULTS
+            .iter()
+            .find(|(name, _)| *name == self.name)
+            .map(|(_, value)| *value)
+    }
+
+    /// Default value in an editor (Vim's default in both editors)
+    pub fn default_value(&self, editor: EditorMode) -> Option<&'static str> {
+        match editor {
+            EditorMode::NeovimOnly => self.neovim_default().or(self.default),
+            _ => self.default,
+        }
+    }
+
+    /// Describe the type, default and scope (e.g., "number (default 8), local to buffer")
+    pub fn summary(&self, editor: EditorMode) -> String {
+        let quote = |value: &str| match self.kind {
+            OptionType::String => format!("\"{}\"", value),
+            _ => value.to_string(),
+        };
+        let default = match (self.default_value(editor), self.neovim_default()) {
+            (Some(vim), Some(nvim)) if editor == EditorMode::Both => {
+                format!(" (default {}, Neovim {})", quote(vim), quote(nvim))
+            }
+            (Some(value), _) => format!(" (default {})", quote(value)),
+            (None, Some(nvim)) if editor == EditorMode::Both => {
+                format!(" (Neovim default {})", quote(nvim))
+            }
+            (None, _) => String::new(),
+        };
+        let list = if self.comma_list {
+            ", comma-separated list"
+        } else if self.flag_list {
+            ", list of flags"
+        } else {
+            ""
+        };
+        format!(
+            "{}{}{}, {}",
+            self.kind.label(),
+            default,
+            list,
+            self.scope.label()
+        )
+    }
+
+    /// Find an option by its long or short name
+    ///
+    /// The name index is built on first use.
+    pub fn find(name: &str) -> Option<&'static BuiltinOption> {
+        static INDEX: OnceLock<HashMap<&'static str, &'static BuiltinOption>> = OnceLock::new();
+        INDEX
+            .get_or_init(|| {
+                let mut index = HashMap::with_capacity(BUILTIN_OPTIONS.len() * 2);
+                for opt in BUILTIN_OPTIONS {
+                    index.entry(opt.name).or_insert(opt);
+                }
+                // Long names take precedence over short ones
+                for opt in BUILTIN_OPTIONS {
+                    if let Some(short) = opt.short {
+                        index.entry(short).or_insert(opt);
+                    }
+                }
+                index
+            })
+            .get(name)
+            .copied()
+    }
 }
 
 /// List of Vim/Neovim options
@@ -9208,1992 +7073,3654 @@ pub static BUILTIN_OPTIONS: &[BuiltinOption] = &[
         short: Some("ari"),
         description: "Allow CTRL-_ in Insert mode for right-to-left",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "ambiwidth",
         short: Some("ambw"),
         description: "Width of ambiguous width characters",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("single"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "arabic",
         short: Some("arab"),
         description: "Enable Arabic language support",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "arabicshape",
         short: Some("arshape"),
         description: "Perform shaping of Arabic characters",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "autochdir",
         short: Some("acd"),
         description: "Auto change directory to file location",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "autocomplete",
         short: Some("ac"),
         description: "Enable automatic completion",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "autocompletedelay",
         short: Some("acl"),
         description: "Delay before auto completion starts",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "autocompletetimeout",
         short: Some("act"),
         description: "Timeout for auto completion",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("80"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "autoindent",
         short: Some("ai"),
         description: "Copy indent from current line when starting new line",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "autoread",
         short: Some("ar"),
         description: "Auto-read file when changed outside",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "autowrite",
         short: Some("aw"),
         description: "Auto-write file before certain commands",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "autowriteall",
         short: Some("awa"),
         description: "Like autowrite but for more commands",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "background",
         short: Some("bg"),
         description: "Background color brightness (dark/light)",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("light"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "backspace",
         short: Some("bs"),
         description: "How backspace works in Insert mode",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "backup",
         short: Some("bk"),
         description: "Keep backup file after overwriting",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "backupcopy",
         short: Some("bkc"),
         description: "How to create backup (copy/rename)",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("auto"),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "backupdir",
         short: Some("bdir"),
         description: "Directory for backup files",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(".,~/tmp,~/"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "backupext",
         short: Some("bex"),
         description: "Extension for backup files",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("~"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "backupskip",
         short: Some("bsk"),
         description: "Patterns for files to skip backup",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("/tmp/*,$TMPDIR/*,$TMP/*,$TEMP/*"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "belloff",
         short: Some("bo"),
         description: "Events to not ring bell for",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "binary",
         short: Some("bin"),
         description: "Binary file editing mode",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "bomb",
         short: None,
         description: "Prepend BOM to file",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "breakat",
         short: Some("brk"),
         description: "Characters for line breaking",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(" ^I!@*-+;:,./?"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "breakindent",
         short: Some("bri"),
         description: "Preserve indent on wrapped lines",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "breakindentopt",
         short: Some("briopt"),
         description: "Options for breakindent",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Window,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "bufhidden",
         short: Some("bh"),
         description: "What to do when buffer is no longer displayed",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "buflisted",
         short: Some("bl"),
         description: "Whether buffer shows in buffer list",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "buftype",
         short: Some("bt"),
         description: "Special type of buffer",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "casemap",
         short: Some("cmp"),
         description: "Case changing behavior",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("internal,keepascii"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cdhome",
         short: Some("cdh"),
         description: ":cd without argument goes home",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cdpath",
         short: Some("cd"),
         description: "Search path for :cd command",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(",,"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cedit",
         short: None,
         description: "Key to open command-line window",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("<C-F>"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "charconvert",
         short: Some("ccv"),
         description: "Expression for character encoding conversion",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "chistory",
         short: Some("chi"),
         description: "Number of command-lines to remember",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("10"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cindent",
         short: Some("cin"),
         description: "Enable C-style indenting",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cinkeys",
         short: Some("cink"),
         description: "Keys that trigger C-indent",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("0{,0},0),0],:,0#,!^F,o,O,e"),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cinoptions",
         short: Some("cino"),
         description: "Options for C-indenting",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cinscopedecls",
         short: Some("cinsd"),
         description: "Scope declaration names for cindent",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("public,protected,private"),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cinwords",
         short: Some("cinw"),
         description: "Words that start extra indent",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("if,else,while,do,for,switch"),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "clipboard",
         short: Some("cb"),
         description: "Use system clipboard",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("autoselect,exclude:cons\\|linux"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cmdheight",
         short: Some("ch"),
         description: "Height of command-line",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("1"),
+        scope: OptionScope::GlobalOrTab,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cmdwinheight",
         short: Some("cwh"),
         description: "Height of command-line window",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("7"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "colorcolumn",
         short: Some("cc"),
         description: "Columns to highlight",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Window,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "columns",
         short: Some("co"),
         description: "Number of columns in display",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("80"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "comments",
         short: Some("com"),
         description: "Patterns for comment leaders",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("s1:/,ex:*/,://,b:#,:%,:XCOMM,n:>,fb:-"),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "commentstring",
         short: Some("cms"),
         description: "Template for comments",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("//"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "complete",
         short: Some("cpt"),
         description: "Sources for keyword completion",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(".,w,b,u,t,i"),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "completefunc",
         short: Some("cfu"),
         description: "Function for Insert mode completion",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "completeitemalign",
         short: Some("cia"),
         description: "Alignment of completion items",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("abbr,kind,menu"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "completeopt",
         short: Some("cot"),
         description: "Options for completion",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("menu,preview"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "completeslash",
         short: Some("csl"),
         description: "Slash style for completion",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "completetimeout",
         short: Some("cto"),
         description: "Timeout for completion",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "concealcursor",
         short: Some("cocu"),
         description: "Modes where text is concealed",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: true,
     },
     BuiltinOption {
         name: "conceallevel",
         short: Some("cole"),
         description: "How to show concealed text",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "confirm",
         short: Some("cf"),
         description: "Confirm dialog for unsaved changes",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "copyindent",
         short: Some("ci"),
         description: "Copy structure of existing indent",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cpoptions",
         short: Some("cpo"),
         description: "Vi-compatible behavior flags",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("aABceFs"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: true,
     },
     BuiltinOption {
         name: "cursorbind",
         short: Some("crb"),
         description: "Bind cursor movement between windows",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cursorcolumn",
         short: Some("cuc"),
         description: "Highlight cursor column",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cursorline",
         short: Some("cul"),
         description: "Highlight cursor line",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cursorlineopt",
         short: Some("culopt"),
         description: "Options for cursorline",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("number,line"),
+        scope: OptionScope::Window,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "debug",
         short: None,
         description: "Debug mode settings",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "define",
         short: Some("def"),
         description: "Pattern for macro definition",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("^\\sdefine"),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "delcombine",
         short: Some("deco"),
         description: "Delete combining characters separately",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "dictionary",
         short: Some("dict"),
         description: "Files for keyword completion",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "diff",
         short: None,
         description: "Diff mode for window",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "diffanchors",
         short: Some("dia"),
         description: "Anchors for diff alignment",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "diffexpr",
         short: Some("dex"),
         description: "Expression for diff output",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "diffopt",
         short: Some("dip"),
         description: "Options for diff mode",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("internal,filler,closeoff"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "digraph",
         short: Some("dg"),
         description: "Enable digraph entry",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "directory",
         short: Some("dir"),
         description: "Directory for swap files",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(".,~/tmp,/var/tmp,/tmp"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "display",
         short: Some("dy"),
         description: "How to display certain characters",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "eadirection",
         short: Some("ead"),
         description: "Direction for equalalways",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("both"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "emoji",
         short: Some("emo"),
         description: "Emoji characters are full width",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "encoding",
         short: Some("enc"),
         description: "Internal character encoding",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "endoffile",
         short: Some("eof"),
         description: "Write CTRL-Z at end of file",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "endofline",
         short: Some("eol"),
         description: "Write newline at end of file",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "equalalways",
         short: Some("ea"),
         description: "Make windows equal size after split",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "equalprg",
         short: Some("ep"),
         description: "External program for = command",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "errorbells",
         short: Some("eb"),
         description: "Ring bell on errors",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "errorfile",
         short: Some("ef"),
         description: "File for error messages",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("errors.err"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "errorformat",
         short: Some("efm"),
         description: "Format for error messages",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "eventignore",
         short: Some("ei"),
         description: "Autocommand events to ignore",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "eventignorewin",
         short: Some("eiw"),
         description: "Window-local events to ignore",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Window,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "expandtab",
         short: Some("et"),
         description: "Use spaces instead of tabs",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "exrc",
         short: Some("ex"),
         description: "Read .vimrc/.nvimrc in current directory",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "fileencoding",
         short: Some("fenc"),
         description: "File encoding for current buffer",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "fileencodings",
         short: Some("fencs"),
         description: "Encoding detection order",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("ucs-bom"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "fileformat",
         short: Some("ff"),
         description: "File format (unix/dos/mac)",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("unix"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "fileformats",
         short: Some("ffs"),
         description: "File format detection order",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("unix,dos"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "fileignorecase",
         short: Some("fic"),
         description: "Ignore case in file names",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "filetype",
         short: Some("ft"),
         description: "File type for current buffer",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "fillchars",
         short: Some("fcs"),
         description: "Characters for window separators",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("vert:|,fold:-,eob:~"),
+        scope: OptionScope::GlobalOrWindow,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "findfunc",
         short: Some("ffu"),
         description: "Function for :find command",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "fixendofline",
         short: Some("fixeol"),
         description: "Fix missing EOL at end of file",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "foldclose",
         short: Some("fcl"),
         description: "When to close folds",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "foldcolumn",
         short: Some("fdc"),
         description: "Width of fold column",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "foldenable",
         short: Some("fen"),
         description: "Enable folding",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "foldexpr",
         short: Some("fde"),
         description: "Expression for fold level",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("0"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "foldignore",
         short: Some("fdi"),
         description: "Character for fold detection",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("#"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "foldlevel",
         short: Some("fdl"),
         description: "Initial fold level",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "foldlevelstart",
         short: Some("fdls"),
         description: "Fold level when starting to edit",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("-1"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "foldmarker",
         short: Some("fmr"),
         description: "Markers for fold method marker",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("{{{,}}}"),
+        scope: OptionScope::Window,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "foldmethod",
         short: Some("fdm"),
         description: "Folding type (manual/indent/expr/marker/syntax/diff)",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("manual"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "foldminlines",
         short: Some("fml"),
         description: "Minimum lines for fold",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("1"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "foldnestmax",
         short: Some("fdn"),
         description: "Maximum fold nesting level",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("20"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "foldopen",
         short: Some("fdo"),
         description: "Commands that open folds",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("block,hor,mark,percent,quickfix, search,tag,undo"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "foldtext",
         short: Some("fdt"),
         description: "Expression for fold text",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("foldtext()"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "formatexpr",
         short: Some("fex"),
         description: "Expression for formatting",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "formatlistpat",
         short: Some("flp"),
         description: "Pattern for list item",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("^\\s"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "formatoptions",
         short: Some("fo"),
         description: "Auto-formatting options",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("tcq"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: true,
     },
     BuiltinOption {
         name: "formatprg",
         short: Some("fp"),
         description: "External program for formatting",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "fsync",
         short: Some("fs"),
         description: "Fsync after writing file",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "grepformat",
         short: Some("gfm"),
         description: "Format for :grep output",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("%f:%l:%m,%f:%l%m,%f %l%m"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "grepprg",
         short: Some("gp"),
         description: "Program for :grep command",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("grep -n $* /dev/null"),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "guicursor",
         short: Some("gcr"),
         description: "Cursor shape and blinking",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(
+            "n-v-c:block-Cursor/lCursor,ve:ver35-Cursor,o:hor50-Cursor,i-ci:ver25-Cursor/lCursor,r-cr:hor20-Cursor/lCursor,sm:block-Cursor-blinkwait175-blinkoff150-blinkon175",
+        ),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "guifont",
         short: Some("gfn"),
         description: "Font for GUI",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "guifontwide",
         short: Some("gfw"),
         description: "Font for double-width characters",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "helpfile",
         short: Some("hf"),
         description: "Main help file name",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("$VIMRUNTIME/doc/help.txt"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "helpheight",
         short: Some("hh"),
         description: "Minimum height of help window",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("20"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "helplang",
         short: Some("hlg"),
         description: "Preferred help languages",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "hidden",
         short: Some("hid"),
         description: "Allow hidden buffers",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "history",
         short: Some("hi"),
         description: "Number of command-lines to remember",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("50"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "hlsearch",
         short: Some("hls"),
         description: "Highlight search matches",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "icon",
         short: None,
         description: "Set icon text of window",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "iconstring",
         short: None,
         description: "String for window icon text",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "ignorecase",
         short: Some("ic"),
         description: "Ignore case in search patterns",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "iminsert",
         short: Some("imi"),
         description: "Input method state for Insert mode",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "imsearch",
         short: Some("ims"),
         description: "Input method state for search",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("-1"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "include",
         short: Some("inc"),
         description: "Pattern for include command",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("^\\sinclude"),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "includeexpr",
         short: Some("inex"),
         description: "Expression for include file name",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "incsearch",
         short: Some("is"),
         description: "Incremental search",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "indentexpr",
         short: Some("inde"),
         description: "Expression for indent",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "indentkeys",
         short: Some("indk"),
         description: "Keys that trigger indenting",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("0{,0},0),0],:,0#,!^F,o,O,e"),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "infercase",
         short: Some("inf"),
         description: "Adjust case of completion match",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "isfname",
         short: Some("isf"),
         description: "Characters in file names",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("@,48-57,/,.,-,_,+,,,#,$,%,~,="),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "isident",
         short: Some("isi"),
         description: "Characters in identifiers",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("@,48-57,_,192-255"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "iskeyword",
         short: Some("isk"),
         description: "Characters in keywords",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("@,48-57,_,192-255"),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "isprint",
         short: Some("isp"),
         description: "Printable characters",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("@,161-255"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "joinspaces",
         short: Some("js"),
         description: "Two spaces after period on join",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "jumpoptions",
         short: Some("jop"),
         description: "Options for jump commands",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "keymap",
         short: Some("kmp"),
         description: "Keyboard mapping name",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "keymodel",
         short: Some("km"),
         description: "Enable special keys behavior",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "keywordprg",
         short: Some("kp"),
         description: "Program for K command",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("man"),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "langmap",
         short: Some("lmap"),
         description: "Map keyboard for langmap mode",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "langmenu",
         short: Some("lm"),
         description: "Language for menus",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "langremap",
         short: Some("lrm"),
         description: "Langmap applies to mapped chars",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "laststatus",
         short: Some("ls"),
         description: "When to show status line",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("1"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "lazyredraw",
         short: Some("lz"),
         description: "Do not redraw during macros",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "lhistory",
         short: Some("lhi"),
         description: "Number of input lines to remember",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("10"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "linebreak",
         short: Some("lbr"),
         description: "Wrap at word boundaries",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "lines",
         short: None,
         description: "Number of lines in display",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("24"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "linespace",
         short: Some("lsp"),
         description: "Pixels between lines",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "lisp",
         short: None,
         description: "Lisp mode for indenting",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "lispoptions",
         short: Some("lop"),
         description: "Options for Lisp indenting",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "lispwords",
         short: Some("lw"),
         description: "Words for Lisp indent",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "list",
         short: None,
         description: "Show tabs and trailing spaces",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "listchars",
         short: Some("lcs"),
         description: "Characters to use for list mode",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("eol:$"),
+        scope: OptionScope::GlobalOrWindow,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "loadplugins",
         short: Some("lpl"),
         description: "Load plugin scripts on startup",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "magic",
         short: None,
         description: "Special chars in search patterns",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "makeef",
         short: Some("mef"),
         description: "Name of error file for :make",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "makeencoding",
         short: Some("menc"),
         description: "Encoding of :make output",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "makeprg",
         short: Some("mp"),
         description: "Program for :make command",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("make"),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "matchpairs",
         short: Some("mps"),
         description: "Pairs of matching characters",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("(:),{:},[:]"),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "matchtime",
         short: Some("mat"),
         description: "Tenths of second to show match",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("5"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "maxfuncdepth",
         short: Some("mfd"),
         description: "Maximum function call depth",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("100"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "maxmapdepth",
         short: Some("mmd"),
         description: "Maximum mapping nesting",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("1000"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "maxmempattern",
         short: Some("mmp"),
         description: "Maximum memory for pattern matching",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("1000"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "maxsearchcount",
         short: Some("msc"),
         description: "Maximum search count message",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("999"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "menuitems",
         short: Some("mis"),
         description: "Maximum items in a menu",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("25"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "messagesopt",
         short: Some("mopt"),
         description: "Options for messages",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("hit-enter,history:500"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "mkspellmem",
         short: Some("msm"),
         description: "Memory used by :mkspell",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("460000,2000,500"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "modeline",
         short: Some("ml"),
         description: "Enable modeline processing",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "modelineexpr",
         short: Some("mle"),
         description: "Allow expressions in modelines",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "modelines",
         short: Some("mls"),
         description: "Lines to check for modelines",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("5"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "modifiable",
         short: Some("ma"),
         description: "Buffer can be modified",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "modified",
         short: Some("mod"),
         description: "Buffer has been modified",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "more",
         short: None,
         description: "Pause listings when screen fills",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "mouse",
         short: None,
         description: "Enable mouse support",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: true,
     },
     BuiltinOption {
         name: "mousefocus",
         short: Some("mousef"),
         description: "Focus follows mouse",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "mousehide",
         short: Some("mh"),
         description: "Hide mouse while typing",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "mousemodel",
         short: Some("mousem"),
         description: "Mouse button behavior",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("extend"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "mousemoveevent",
         short: Some("mousemev"),
         description: "Report mouse move events",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "mousetime",
         short: Some("mouset"),
         description: "Maximum time between clicks",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("500"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "nrformats",
         short: Some("nf"),
         description: "Number formats for CTRL-A/CTRL-X",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("bin,octal,hex"),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "number",
         short: Some("nu"),
         description: "Show line numbers",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "numberwidth",
         short: Some("nuw"),
         description: "Minimum width of number column",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("4"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "omnifunc",
         short: Some("ofu"),
         description: "Function for omni completion",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "operatorfunc",
         short: Some("opfunc"),
         description: "Function for g@ operator",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "packpath",
         short: Some("pp"),
         description: "Search path for packages",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "paragraphs",
         short: Some("para"),
         description: "Nroff macros for paragraphs",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("IPLPPPQPP TPHPLIPpLpItpplpipbp"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "patchexpr",
         short: Some("pex"),
         description: "Expression for patch output",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "patchmode",
         short: Some("pm"),
         description: "Keep oldest version of file",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "path",
         short: Some("pa"),
         description: "Search path for gf and :find",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(".,/usr/include,,"),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "preserveindent",
         short: Some("pi"),
         description: "Preserve indent structure",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "previewheight",
         short: Some("pvh"),
         description: "Height of preview window",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("12"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "previewwindow",
         short: Some("pvw"),
         description: "Window is preview window",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "pumborder",
         short: Some("pb"),
         description: "Enable popup menu border",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "pumheight",
         short: Some("ph"),
         description: "Maximum popup menu height",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "pummaxwidth",
         short: Some("pmw"),
         description: "Maximum popup menu width",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "pumwidth",
         short: Some("pw"),
         description: "Minimum popup menu width",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("15"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "pyxversion",
         short: Some("pyx"),
         description: "Python version for pyx commands",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "quickfixtextfunc",
         short: Some("qftf"),
         description: "Function for quickfix text",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "quoteescape",
         short: Some("qe"),
         description: "Escape character in strings",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("\\"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "readonly",
         short: Some("ro"),
         description: "Buffer is read-only",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "redrawtime",
         short: Some("rdt"),
         description: "Timeout for syntax highlighting",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("2000"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "regexpengine",
         short: Some("re"),
         description: "Regexp engine to use",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "relativenumber",
         short: Some("rnu"),
         description: "Show relative line numbers",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "report",
         short: None,
         description: "Minimum lines to report changes",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("2"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "revins",
         short: Some("ri"),
         description: "Insert characters backwards",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "rightleft",
         short: Some("rl"),
         description: "Window is right-to-left",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "rightleftcmd",
         short: Some("rlc"),
         description: "Commands edited right-to-left",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("search"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "ruler",
         short: Some("ru"),
         description: "Show cursor position in status line",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "rulerformat",
         short: Some("ruf"),
         description: "Format for ruler",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "runtimepath",
         short: Some("rtp"),
         description: "Search path for runtime files",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("$HOME/.vim,$VIM/vimfiles,$VIMRUNTIME,$VIM/vimfiles/after,$HOME/.vim/after"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "scroll",
         short: Some("scr"),
         description: "Lines to scroll with CTRL-U/D",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: None,
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "scrollbind",
         short: Some("scb"),
         description: "Bind scroll to other windows",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "scrolljump",
         short: Some("sj"),
         description: "Minimum lines to scroll",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("1"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "scrolloff",
         short: Some("so"),
         description: "Lines to keep above/below cursor",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::GlobalOrWindow,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "scrollopt",
         short: Some("sbo"),
         description: "Options for scrollbind",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("ver,jump"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "sections",
         short: Some("sect"),
         description: "Nroff macros for sections",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("SHNHH HUnhsh"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "selection",
         short: Some("sel"),
         description: "What type of selection to use",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("inclusive"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "selectmode",
         short: Some("slm"),
         description: "When to start Select mode",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "sessionoptions",
         short: Some("ssop"),
         description: "Options for :mksession",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("blank,buffers,curdir,folds, help,options,tabpages,winsize,terminal"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shell",
         short: Some("sh"),
         description: "Shell to use for :! commands",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shellcmdflag",
         short: Some("shcf"),
         description: "Flag for shell to execute command",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("-c"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shellpipe",
         short: Some("sp"),
         description: "String for :make output",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shellquote",
         short: Some("shq"),
         description: "Quote for shell command",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shellredir",
         short: Some("srr"),
         description: "String for output redirection",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shellslash",
         short: Some("ssl"),
         description: "Use forward slash in file names",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shelltemp",
         short: Some("stmp"),
         description: "Use temp files for shell commands",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shellxescape",
         short: Some("sxe"),
         description: "Characters to escape for shellxquote",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shellxquote",
         short: Some("sxq"),
         description: "Like shellquote for :! commands",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shiftround",
         short: Some("sr"),
         description: "Round indent to shiftwidth multiple",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shiftwidth",
         short: Some("sw"),
         description: "Spaces for each indent step",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("8"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shortmess",
         short: Some("shm"),
         description: "List of flags to shorten messages",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("filnxtToOS"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: true,
     },
     BuiltinOption {
         name: "showbreak",
         short: Some("sbr"),
         description: "String to put at start of wrapped lines",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::GlobalOrWindow,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "showcmd",
         short: Some("sc"),
         description: "Show partial command",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "showcmdloc",
         short: Some("sloc"),
         description: "Location of showcmd",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("last"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "showfulltag",
         short: Some("sft"),
         description: "Show full tag pattern in completion",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "showmatch",
         short: Some("sm"),
         description: "Briefly jump to matching bracket",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "showmode",
         short: Some("smd"),
         description: "Show mode in command line",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "showtabline",
         short: Some("stal"),
         description: "When to show tab line",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("1"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "sidescroll",
         short: Some("ss"),
         description: "Minimum columns to scroll horizontally",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "sidescrolloff",
         short: Some("siso"),
         description: "Columns to keep left/right of cursor",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::GlobalOrWindow,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "signcolumn",
         short: Some("scl"),
         description: "When to display sign column",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("auto"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "smartcase",
         short: Some("scs"),
         description: "Override ignorecase if pattern has uppercase",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "smartindent",
         short: Some("si"),
         description: "Smart autoindenting",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "smarttab",
         short: Some("sta"),
         description: "Tab key respects shiftwidth",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "smoothscroll",
         short: Some("sms"),
         description: "Scroll by screen line",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "softtabstop",
         short: Some("sts"),
         description: "Spaces for tab while editing",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "spell",
         short: None,
         description: "Enable spell checking",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "spellcapcheck",
         short: Some("spc"),
         description: "Pattern for capital letter check",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("[.?!]\\_[\\])'\" \\t]\\+"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "spellfile",
         short: Some("spf"),
         description: "Files for zg and zw commands",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "spelllang",
         short: Some("spl"),
         description: "Languages for spell checking",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("en"),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "spelloptions",
         short: Some("spo"),
         description: "Options for spell checking",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "spellsuggest",
         short: Some("sps"),
         description: "Methods for spell suggestions",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("best"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "splitbelow",
         short: Some("sb"),
         description: "New window goes below current",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "splitkeep",
         short: Some("spk"),
         description: "Keep topline/cursor on split",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("cursor"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "splitright",
         short: Some("spr"),
         description: "New window goes right of current",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "startofline",
         short: Some("sol"),
         description: "Commands move cursor to first non-blank",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "statusline",
         short: Some("stl"),
         description: "Custom format for status line",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::GlobalOrWindow,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "suffixes",
         short: Some("su"),
         description: "Suffixes to ignore in file completion",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(".bak,~,.o,.h,.info,.swp,.obj"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "suffixesadd",
         short: Some("sua"),
         description: "Suffixes added when searching for file",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "swapfile",
         short: Some("swf"),
         description: "Use a swap file for buffer",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "switchbuf",
         short: Some("swb"),
         description: "Window switching behavior",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "synmaxcol",
         short: Some("smc"),
         description: "Maximum column for syntax highlighting",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("3000"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "syntax",
         short: Some("syn"),
         description: "Syntax to use for highlighting",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "tabclose",
         short: None,
         description: "Which tab to focus when closing",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "tabline",
         short: Some("tal"),
         description: "Custom format for tab line",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "tabpagemax",
         short: Some("tpm"),
         description: "Maximum tabs for -p and :tab all",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("10"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "tabstop",
         short: Some("ts"),
         description: "Spaces that a tab counts for",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("8"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "tagbsearch",
         short: Some("tbs"),
         description: "Use binary search in tags files",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "tagcase",
         short: Some("tc"),
         description: "How to handle case in tag search",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("followic"),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "tagfunc",
         short: Some("tfu"),
         description: "Function for tag search",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "taglength",
         short: Some("tl"),
         description: "Significant characters in tag name",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "tagrelative",
         short: Some("tr"),
         description: "File names in tags file are relative",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "tags",
         short: Some("tag"),
         description: "List of tag files",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("./tags,tags"),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "tagstack",
         short: Some("tgst"),
         description: "Push tags onto tag stack",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "termbidi",
         short: Some("tbidi"),
         description: "Terminal handles bidirectional text",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "termguicolors",
         short: Some("tgc"),
         description: "Use GUI colors in terminal",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "textwidth",
         short: Some("tw"),
         description: "Maximum width of inserted text",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "thesaurus",
         short: Some("tsr"),
         description: "Files for thesaurus completion",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "thesaurusfunc",
         short: Some("tsrfu"),
         description: "Function for thesaurus completion",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "tildeop",
         short: Some("top"),
         description: "Tilde command behaves as operator",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "timeout",
         short: Some("to"),
         description: "Timeout for mapped sequences",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "timeoutlen",
         short: Some("tm"),
         description: "Timeout in milliseconds",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("1000"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "title",
         short: None,
         description: "Set window title",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "titlelen",
         short: Some("tsl"),
         description: "Percentage of columns for title",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("85"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "titleold",
         short: None,
         description: "Old title to restore when exiting",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("Thanks for flying Vim"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "titlestring",
         short: None,
         description: "String for window title",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "ttimeout",
         short: None,
         description: "Timeout for key codes",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "ttimeoutlen",
         short: Some("ttm"),
         description: "Timeout for key codes in ms",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("-1"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "undodir",
         short: Some("udir"),
         description: "Directory for undo files",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("."),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "undofile",
         short: Some("udf"),
         description: "Save undo history to file",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "undolevels",
         short: Some("ul"),
         description: "Maximum number of undo changes",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("1000"),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "undoreload",
         short: Some("ur"),
         description: "Maximum lines to save for undo on reload",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("10000"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "updatecount",
         short: Some("uc"),
         description: "Characters typed before swap file update",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("200"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "updatetime",
         short: Some("ut"),
         description: "Milliseconds for swap file update",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("4000"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "varsofttabstop",
         short: Some("vsts"),
         description: "Variable soft tab stops",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "vartabstop",
         short: Some("vts"),
         description: "Variable tab stops",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "verbose",
         short: Some("vbs"),
         description: "Verbosity level",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "verbosefile",
         short: Some("vfile"),
         description: "File to write verbose messages",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "viewdir",
         short: Some("vdir"),
         description: "Directory for view files",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("~/.vim/view"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "viewoptions",
         short: Some("vop"),
         description: "Options for :mkview",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("folds,options,cursor,curdir"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "virtualedit",
         short: Some("ve"),
         description: "Allow cursor past end of line",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::GlobalOrWindow,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "visualbell",
         short: Some("vb"),
         description: "Use visual bell instead of beeping",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "warn",
         short: None,
         description: "Warn for shell command in modified buffer",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "whichwrap",
         short: Some("ww"),
         description: "Allow cursor keys to wrap lines",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("b,s"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: true,
     },
     BuiltinOption {
         name: "wildchar",
         short: Some("wc"),
         description: "Character for command-line completion",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("<Tab>"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "wildcharm",
         short: Some("wcm"),
         description: "Like wildchar in mappings",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "wildignore",
         short: Some("wig"),
         description: "Patterns to ignore for file completion",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "wildignorecase",
         short: Some("wic"),
         description: "Ignore case in file completion",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "wildmenu",
         short: Some("wmnu"),
         description: "Enhanced command-line completion",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "wildmode",
         short: Some("wim"),
         description: "Mode for wildchar completion",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("full"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "wildoptions",
         short: Some("wop"),
         description: "Options for command-line completion",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "winaltkeys",
         short: Some("wak"),
         description: "How Alt key works with menus",
         availability: Availability::Common,
+        kind: OptionType::String,
+        default: Some("menu"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "window",
         short: Some("wi"),
         description: "Lines in window for CTRL-F/CTRL-B",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "winfixbuf",
         short: Some("wfb"),
         description: "Window shows specific buffer",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "winfixheight",
         short: Some("wfh"),
         description: "Keep window height fixed",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "winfixwidth",
         short: Some("wfw"),
         description: "Keep window width fixed",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "winheight",
         short: Some("wh"),
         description: "Minimum height for active window",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("1"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "winminheight",
         short: Some("wmh"),
         description: "Minimum height for any window",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("1"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "winminwidth",
         short: Some("wmw"),
         description: "Minimum width for any window",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("1"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "winwidth",
         short: Some("wiw"),
         description: "Minimum width for active window",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("20"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "wrap",
         short: None,
         description: "Long lines wrap",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "wrapmargin",
         short: Some("wm"),
         description: "Characters from edge to wrap",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "wrapscan",
         short: Some("ws"),
         description: "Search wraps around end of file",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "write",
         short: None,
         description: "Writing to file allowed",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "writeany",
         short: Some("wa"),
         description: "Write to any file without asking",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "writebackup",
         short: Some("wb"),
         description: "Make backup before overwriting",
         availability: Availability::Common,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "writedelay",
         short: Some("wd"),
         description: "Delay in ms for each char written",
         availability: Availability::Common,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     // ============================================================================
     // ============================================================================
@@ -11202,684 +10729,1256 @@ pub static BUILTIN_OPTIONS: &[BuiltinOption] = &[
         short: Some("al"),
         description: "ASCII code of letter Aleph",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: Some("224"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "altkeymap",
         short: Some("akm"),
         description: "Alternative keyboard mapping",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "antialias",
         short: Some("anti"),
         description: "Use antialiased fonts in GUI",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "autoshelldir",
         short: Some("asd"),
         description: "Auto change shell directory",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "balloondelay",
         short: Some("bdlay"),
         description: "Delay for balloon popup",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: Some("600"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "ballooneval",
         short: Some("beval"),
         description: "Enable balloon evaluation in GUI",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "balloonevalterm",
         short: Some("bevalterm"),
         description: "Enable balloon evaluation in terminal",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "balloonexpr",
         short: Some("bexpr"),
         description: "Expression for balloon text",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "bioskey",
         short: Some("biosk"),
         description: "Use BIOS for keyboard input",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "browsedir",
         short: Some("bsdir"),
         description: "Directory for file browser",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some("last"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "clipmethod",
         short: Some("cpm"),
         description: "Method to use for clipboard",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some("wayland,x11"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "compatible",
         short: Some("cp"),
         description: "Behave Vi-compatible",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "completefuzzycollect",
         short: Some("cfc"),
         description: "Fuzzy collect for completion",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "completepopup",
         short: Some("cpp"),
         description: "Popup window options for completion",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "conskey",
         short: Some("consk"),
         description: "Directly read console keyboard",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cryptmethod",
         short: Some("cm"),
         description: "Encryption method for file",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some("blowfish2"),
+        scope: OptionScope::GlobalOrBuffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cscopepathcomp",
         short: Some("cspc"),
         description: "Path components to show in cscope",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cscopeprg",
         short: Some("csprg"),
         description: "Program for cscope command",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some("cscope"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cscopequickfix",
         short: Some("csqf"),
         description: "Use quickfix window for cscope",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cscoperelative",
         short: Some("csre"),
         description: "Use relative paths for cscope",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cscopetag",
         short: Some("cst"),
         description: "Use cscope for tag commands",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cscopetagorder",
         short: Some("csto"),
         description: "Order of cscope and tag search",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "cscopeverbose",
         short: Some("csverb"),
         description: "Show cscope messages",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "edcompatible",
         short: Some("ed"),
         description: "Toggle flags for :substitute",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "esckeys",
         short: Some("ek"),
         description: "Recognize function keys in Insert mode",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "fkmap",
         short: Some("fk"),
         description: "Farsi keyboard mapping",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "gdefault",
         short: Some("gd"),
         description: "Substitute replaces all in line by default",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "guifontset",
         short: Some("gfs"),
         description: "List of fonts for multi-byte text",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "guiheadroom",
         short: Some("ghr"),
         description: "Pixels for GUI window decorations",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: Some("50"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "guiligatures",
         short: Some("gli"),
         description: "Font ligatures for GUI",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "guioptions",
         short: Some("go"),
         description: "GUI option flags",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some("aegimrLtT"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: true,
     },
     BuiltinOption {
         name: "guipty",
         short: None,
         description: "Use pseudo-tty for :! commands",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "guitablabel",
         short: Some("gtl"),
         description: "Custom format for GUI tab label",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "guitabtooltip",
         short: Some("gtt"),
         description: "Tooltip for GUI tabs",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "highlight",
         short: Some("hl"),
         description: "Highlight groups for various occasions",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "hkmap",
         short: Some("hk"),
         description: "Hebrew keyboard mapping",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "hkmapp",
         short: Some("hkp"),
         description: "Phonetic Hebrew keyboard mapping",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "imactivatefunc",
         short: Some("imaf"),
         description: "Function to activate input method",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "imactivatekey",
         short: Some("imak"),
         description: "Key to activate input method",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "imcmdline",
         short: Some("imc"),
         description: "Use IM when entering command line",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "imdisable",
         short: Some("imd"),
         description: "Disable input method",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "imstatusfunc",
         short: Some("imsf"),
         description: "Function for IM status",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "imstyle",
         short: Some("imst"),
         description: "Input method style",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: Some("1"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "insertmode",
         short: Some("im"),
         description: "Start in Insert mode",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "key",
         short: None,
         description: "Encryption key for current file",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "keyprotocol",
         short: Some("kpc"),
         description: "Protocol for terminal keys",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "langnoremap",
         short: Some("lnr"),
         description: "Do not langmap langmap",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "luadll",
         short: None,
         description: "Name of Lua dynamic library",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "macatsui",
         short: None,
         description: "Use ATSUI text drawing on Mac",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "maxcombine",
         short: Some("mco"),
         description: "Maximum combining characters displayed",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: Some("2"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "maxmem",
         short: Some("mm"),
         description: "Maximum memory in KB for one buffer",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "maxmemtot",
         short: Some("mmt"),
         description: "Maximum memory in KB for all buffers",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "mouseshape",
         short: Some("mouses"),
         description: "Shape of mouse pointer",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(
+            "i-r:beam,s:updown,sd:udsizing, vs:leftright,vd:lrsizing,m:no, ml:up-arrow,v:rightup-arrow",
+        ),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "mzquantum",
         short: Some("mzq"),
         description: "Interval for MzScheme threads",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: Some("100"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "mzschemedll",
         short: None,
         description: "Name of MzScheme dynamic library",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "mzschemegcdll",
         short: None,
         description: "Name of MzScheme GC dynamic library",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "opendevice",
         short: Some("odev"),
         description: "Allow opening devices",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "osctimeoutlen",
         short: Some("ost"),
         description: "Timeout for terminal responses",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: Some("1000"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "osfiletype",
         short: Some("oft"),
         description: "File type for OS/2",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "paste",
         short: None,
         description: "Paste mode enabled",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "pastetoggle",
         short: Some("pt"),
         description: "Key to toggle paste mode",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "perldll",
         short: None,
         description: "Name of Perl dynamic library",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "previewpopup",
         short: Some("pvp"),
         description: "Use popup window for preview",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "printdevice",
         short: Some("pdev"),
         description: "Printer device name",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "printencoding",
         short: Some("penc"),
         description: "Encoding for printing",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "printexpr",
         short: Some("pexpr"),
         description: "Expression for printing PostScript",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "printfont",
         short: Some("pfn"),
         description: "Font for printing",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some("courier"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "printheader",
         short: Some("pheader"),
         description: "Format of header for printing",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some("%<%f%h%m%=Page %N"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "printmbcharset",
         short: Some("pmbcs"),
         description: "Multi-byte character set for printing",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "printmbfont",
         short: Some("pmbfn"),
         description: "Font names for multi-byte printing",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "printoptions",
         short: Some("popt"),
         description: "Options for printing",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "prompt",
         short: None,
         description: "Enable prompt in Ex mode",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "pythondll",
         short: None,
         description: "Name of Python 2 dynamic library",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "pythonhome",
         short: None,
         description: "Home directory for Python 2",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "pythonthreedll",
         short: None,
         description: "Name of Python 3 dynamic library",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "pythonthreehome",
         short: None,
         description: "Home directory for Python 3",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "remap",
         short: None,
         description: "Allow nested mappings",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "renderoptions",
         short: Some("rop"),
         description: "Options for text rendering",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "restorescreen",
         short: Some("rs"),
         description: "Restore screen when exiting",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "rubydll",
         short: None,
         description: "Name of Ruby dynamic library",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "scrollfocus",
         short: Some("scf"),
         description: "Scroll window under mouse",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "secure",
         short: None,
         description: "Secure mode for untrusted files",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shelltype",
         short: Some("st"),
         description: "Type of shell for Amiga",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shortname",
         short: Some("sn"),
         description: "Use old 8.3 file names",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "showtabpanel",
         short: Some("stpl"),
         description: "When to show tab panel",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: Some("1"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "swapsync",
         short: Some("sws"),
         description: "Sync swap file with fsync",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some("fsync"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "tabpanel",
         short: Some("tpl"),
         description: "Custom format for tab panel",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "tabpanelopt",
         short: Some("tplo"),
         description: "Options for tab panel",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some("align:left,columns:20"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "tcldll",
         short: None,
         description: "Name of Tcl dynamic library",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "term",
         short: None,
         description: "Name of terminal type",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "termencoding",
         short: Some("tenc"),
         description: "Encoding of terminal output",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "termwinkey",
         short: Some("twk"),
         description: "Key for terminal window commands",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "termwinscroll",
         short: Some("twsl"),
         description: "Scrollback lines for terminal",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: Some("10000"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "termwinsize",
         short: Some("tws"),
         description: "Size of terminal window",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "termwintype",
         short: Some("twt"),
         description: "Type of terminal window",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "terse",
         short: None,
         description: "Show shorter messages",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "textauto",
         short: Some("ta"),
         description: "Auto detect file format",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "textmode",
         short: Some("tx"),
         description: "File is in text mode",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "toolbar",
         short: Some("tb"),
         description: "Items shown in toolbar",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some("icons,tooltips"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "toolbariconsize",
         short: Some("tbis"),
         description: "Size of toolbar icons",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some("small"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "ttybuiltin",
         short: None,
         description: "Use builtin termcap entries first",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "ttyfast",
         short: Some("tf"),
         description: "Fast terminal connection",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "ttymouse",
         short: Some("ttym"),
         description: "Type of mouse for terminal",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "ttyscroll",
         short: None,
         description: "Maximum lines to scroll",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: Some("999"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "ttytype",
         short: None,
         description: "Alias for term",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: None,
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "viminfo",
         short: Some("vi"),
         description: "Use viminfo file",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some("'100,<50,s10,h"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "viminfofile",
         short: Some("vif"),
         description: "Name of viminfo file",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "weirdinvert",
         short: Some("wiv"),
         description: "Special handling for invert",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "wincolor",
         short: Some("wcr"),
         description: "Highlight group for window",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "winptydll",
         short: None,
         description: "Name of winpty dynamic library",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some("winpty32.dll"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "wlseat",
         short: Some("wse"),
         description: "Wayland seat name",
         availability: Availability::VimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "wlsteal",
         short: Some("wst"),
         description: "Steal focus in Wayland",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("off"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "wltimeoutlen",
         short: Some("wtm"),
         description: "Timeout for Wayland requests",
         availability: Availability::VimOnly,
+        kind: OptionType::Number,
+        default: Some("500"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "xtermcodes",
         short: None,
         description: "Request xterm-style codes",
         availability: Availability::VimOnly,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     // ============================================================================
     // ============================================================================
@@ -11888,96 +11987,750 @@ pub static BUILTIN_OPTIONS: &[BuiltinOption] = &[
         short: None,
         description: "Terminal busy indicator",
         availability: Availability::NeovimOnly,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "channel",
         short: None,
         description: "Channel connected to buffer",
         availability: Availability::NeovimOnly,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "inccommand",
         short: Some("icm"),
         description: "Live preview of :substitute",
         availability: Availability::NeovimOnly,
+        kind: OptionType::String,
+        default: Some("nosplit"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "mousescroll",
         short: None,
         description: "Mouse scroll wheel behavior",
         availability: Availability::NeovimOnly,
+        kind: OptionType::String,
+        default: Some("ver:3,hor:6"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "pumblend",
         short: None,
         description: "Popup menu pseudo-transparency",
         availability: Availability::NeovimOnly,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "redrawdebug",
         short: Some("rdb"),
         description: "Debug flags for redrawing",
         availability: Availability::NeovimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "scrollback",
         short: Some("scbk"),
         description: "Lines for terminal scrollback",
         availability: Availability::NeovimOnly,
+        kind: OptionType::Number,
+        default: Some("10000"),
+        scope: OptionScope::Buffer,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shada",
         short: Some("sd"),
         description: "Use shada file",
         availability: Availability::NeovimOnly,
+        kind: OptionType::String,
+        default: Some("!,'100,<50,s10,h"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "shadafile",
         short: Some("sdf"),
         description: "Name of shada file",
         availability: Availability::NeovimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "statuscolumn",
         short: Some("stc"),
         description: "Custom format for status column",
         availability: Availability::NeovimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "termpastefilter",
         short: Some("tpf"),
         description: "Filter for terminal paste",
         availability: Availability::NeovimOnly,
+        kind: OptionType::String,
+        default: Some("BS,HT,ESC,DEL"),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "termsync",
         short: None,
         description: "Terminal synchronized output",
         availability: Availability::NeovimOnly,
+        kind: OptionType::Boolean,
+        default: Some("on"),
+        scope: OptionScope::Global,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "winbar",
         short: Some("wbr"),
         description: "Custom format for window bar",
         availability: Availability::NeovimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::GlobalOrWindow,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "winblend",
         short: None,
         description: "Window pseudo-transparency",
         availability: Availability::NeovimOnly,
+        kind: OptionType::Number,
+        default: Some("0"),
+        scope: OptionScope::Window,
+        comma_list: false,
+        flag_list: false,
     },
     BuiltinOption {
         name: "winborder",
         short: None,
         description: "Default border style for windows",
         availability: Availability::NeovimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Global,
+        comma_list: true,
+        flag_list: false,
     },
     BuiltinOption {
         name: "winhighlight",
         short: Some("winhl"),
         description: "Window-local highlight groups",
         availability: Availability::NeovimOnly,
+        kind: OptionType::String,
+        default: Some(""),
+        scope: OptionScope::Window,
+        comma_list: true,
+        flag_list: false,
+    },
+];
+
+/// Options whose default differs in Neovim
+/// Reference: :help nvim-defaults (Neovim)
+pub static NEOVIM_OPTION_DEFAULTS: &[(&str, &str)] = &[
+    ("autoindent", "on"),
+    ("autoread", "on"),
+    ("background", "dark"),
+    ("backspace", "indent,eol,start"),
+    ("backupdir", ".,$XDG_STATE_HOME/nvim/backup//"),
+    ("belloff", "all"),
+    ("compatible", "off"),
+    ("complete", ".,w,b,u,t"),
+    ("define", ""),
+    ("directory", "$XDG_STATE_HOME/nvim/swap//"),
+    ("display", "lastline"),
+    ("encoding", "utf-8"),
+    ("fillchars", "vert:│,fold:·,foldsep:│"),
+    ("formatoptions", "tcqj"),
+    ("fsync", "off"),
+    ("hidden", "on"),
+    ("history", "10000"),
+    ("hlsearch", "on"),
+    ("include", ""),
+    ("incsearch", "on"),
+    ("joinspaces", "off"),
+    ("langnoremap", "on"),
+    ("langremap", "off"),
+    ("laststatus", "2"),
+    ("listchars", "tab:> ,trail:-,nbsp:+"),
+    ("mouse", "nvi"),
+    ("mousemodel", "popup_setpos"),
+    ("nrformats", "bin,hex"),
+    ("ruler", "on"),
+    (
+        "sessionoptions",
+        "blank,buffers,curdir,folds,help,tabpages,winsize,terminal",
+    ),
+    ("shortmess", "ltToOCF"),
+    ("showcmd", "on"),
+    ("sidescroll", "1"),
+    ("smarttab", "on"),
+    ("startofline", "off"),
+    ("switchbuf", "uselast"),
+    ("tabpagemax", "50"),
+    ("tags", "./tags;,tags"),
+    ("ttimeoutlen", "50"),
+    ("ttyfast", "on"),
+    ("undodir", "$XDG_STATE_HOME/nvim/undo//"),
+    ("viewdir", "$XDG_STATE_HOME/nvim/view//"),
+    ("wildmenu", "on"),
+    ("wildoptions", "pum,tagfile"),
+];
+
+// ============================================================================
+// Option Values
+// ============================================================================
+
+/// How the value of an enumerated option is composed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptionValueKind {
+    /// A single value (e.g., `background=dark`)
+    Single,
+    /// A comma-separated list of values (e.g., `completeopt=menu,preview`)
+    CommaList,
+    /// A string of single-character flags (e.g., `mouse=nv`)
+    Flags,
+}
+
+/// Valid values of an enumerated option
+pub struct OptionValues {
+    /// Long option name
+    pub option: &'static str,
+    /// Valid values (or flags) and their descriptions
+    pub values: &'static [(&'static str, &'static str)],
+}
+
+impl OptionValues {
+    /// Find the values of an option by its long or short name
+    pub fn find(name: &str) -> Option<&'static OptionValues> {
+        let long = BuiltinOption::find(name).map_or(name, |opt| opt.name);
+        OPTION_VALUES.iter().find(|v| v.option == long)
+    }
+
+    /// How the value is composed, from the option's metadata
+    pub fn kind(&self) -> OptionValueKind {
+        match BuiltinOption::find(self.option) {
+            Some(opt) if opt.comma_list => OptionValueKind::CommaList,
+            Some(opt) if opt.flag_list => OptionValueKind::Flags,
+            _ => OptionValueKind::Single,
+        }
+    }
+}
+
+/// Valid values of enumerated options
+/// Reference: :help options.txt
+pub static OPTION_VALUES: &[OptionValues] = &[
+    OptionValues {
+        option: "ambiwidth",
+        values: &[
+            ("single", "Use the same width as US-ASCII characters"),
+            ("double", "Use twice the width of ASCII characters"),
+        ],
+    },
+    OptionValues {
+        option: "background",
+        values: &[
+            ("dark", "Use colors that look good on a dark background"),
+            ("light", "Use colors that look good on a light background"),
+        ],
+    },
+    OptionValues {
+        option: "backspace",
+        values: &[
+            ("indent", "Allow backspacing over autoindent"),
+            ("eol", "Allow backspacing over line breaks"),
+            ("start", "Allow backspacing over the start of insert"),
+            (
+                "nostop",
+                "Like start, but don't stop at the start of insert",
+            ),
+        ],
+    },
+    OptionValues {
+        option: "belloff",
+        values: &[
+            ("all", "All events"),
+            ("backspace", "Backspace or Delete with an error"),
+            ("cursor", "Fail to move around using the cursor keys"),
+            ("complete", "Error with i_CTRL-X_CTRL-K and friends"),
+            ("copy", "Cannot copy char from insert mode"),
+            ("ctrlg", "Unknown char after CTRL-G in insert mode"),
+            ("error", "Other errors"),
+            ("esc", "Hitting Escape in Normal mode"),
+            ("ex", "Errors in Ex mode"),
+            ("insertmode", "Pressing Escape in 'insertmode'"),
+            ("lang", "Calling the beep module for Lua/Mzscheme/TCL"),
+            ("mess", "No output available for g<"),
+            ("operator", "Empty region error"),
+            ("register", "Unknown register after CTRL-R"),
+            ("shell", "Bell from shell output"),
+            ("showmatch", "Error occurred for 'showmatch'"),
+            ("spell", "Error happened on spell suggest"),
+            ("wildmode", "More matches in cmdline-completion available"),
+            ("hangul", "Error in Hangul input"),
+        ],
+    },
+    OptionValues {
+        option: "bufhidden",
+        values: &[
+            ("hide", "Hide the buffer"),
+            ("unload", "Unload the buffer"),
+            ("delete", "Delete the buffer from the buffer list"),
+            ("wipe", "Wipe out the buffer"),
+        ],
+    },
+    OptionValues {
+        option: "buftype",
+        values: &[
+            ("acwrite", "Buffer will always be written with BufWriteCmd"),
+            ("help", "Help buffer"),
+            ("nofile", "Buffer is not related to a file"),
+            ("nowrite", "Buffer will not be written"),
+            ("quickfix", "List of errors or locations"),
+            ("terminal", "Terminal buffer"),
+            ("prompt", "Buffer where only the last line can be edited"),
+            ("popup", "Buffer used in a popup window"),
+        ],
+    },
+    OptionValues {
+        option: "casemap",
+        values: &[
+            ("internal", "Use internal case mapping functions"),
+            ("keepascii", "Use ASCII case mapping for ASCII characters"),
+        ],
+    },
+    OptionValues {
+        option: "clipboard",
+        values: &[
+            ("unnamed", "Use the * register for unnamed yank and put"),
+            ("unnamedplus", "Use the + register for unnamed yank and put"),
+            ("autoselect", "Put Visual selection in the * register"),
+            ("autoselectplus", "Put Visual selection in the + register"),
+            (
+                "autoselectml",
+                "Like autoselect, but for the modeless selection",
+            ),
+            ("html", "Use HTML when copying to the clipboard"),
+            ("exclude:", "Pattern of terminal names not to connect to"),
+        ],
+    },
+    OptionValues {
+        option: "completeopt",
+        values: &[
+            ("menu", "Use a popup menu to show the possible completions"),
+            (
+                "menuone",
+                "Use the popup menu also when there is only one match",
+            ),
+            (
+                "longest",
+                "Only insert the longest common text of the matches",
+            ),
+            ("preview", "Show extra information in the preview window"),
+            ("popup", "Show extra information in a popup window"),
+            ("popuphidden", "Like popup, but initially hidden"),
+            (
+                "noinsert",
+                "Do not insert any text until the user selects a match",
+            ),
+            ("noselect", "Do not select a match in the menu"),
+            ("fuzzy", "Enable fuzzy matching for completion candidates"),
+            ("nosort", "Disable sorting of completion candidates"),
+            ("preinsert", "Preinsert the portion of the first candidate"),
+            ("nearest", "Sort matches by distance from the cursor"),
+        ],
+    },
+    OptionValues {
+        option: "concealcursor",
+        values: &[
+            ("n", "Normal mode"),
+            ("v", "Visual mode"),
+            ("i", "Insert mode"),
+            ("c", "Command line editing"),
+        ],
+    },
+    OptionValues {
+        option: "conceallevel",
+        values: &[
+            ("0", "Text is shown normally"),
+            (
+                "1",
+                "Each block of concealed text is replaced with one character",
+            ),
+            (
+                "2",
+                "Concealed text is hidden unless it has a replacement character",
+            ),
+            ("3", "Concealed text is completely hidden"),
+        ],
+    },
+    OptionValues {
+        option: "cursorlineopt",
+        values: &[
+            ("line", "Highlight the text line of the cursor"),
+            ("screenline", "Highlight only the screen line of the cursor"),
+            ("number", "Highlight the line number of the cursor"),
+            ("both", "Alias for the values line,number"),
+        ],
+    },
+    OptionValues {
+        option: "diffopt",
+        values: &[
+            ("filler", "Show filler lines"),
+            ("context:", "Number of context lines around a change"),
+            ("iblank", "Ignore changes where lines are all blank"),
+            ("icase", "Ignore changes in case of text"),
+            ("iwhite", "Ignore changes in amount of white space"),
+            ("iwhiteall", "Ignore all white space changes"),
+            ("iwhiteeol", "Ignore white space changes at end of line"),
+            ("horizontal", "Start diff mode with horizontal splits"),
+            ("vertical", "Start diff mode with vertical splits"),
+            (
+                "closeoff",
+                "Run :diffoff when closing the second to last diff window",
+            ),
+            ("hiddenoff", "Do not use diff mode for a hidden buffer"),
+            ("foldcolumn:", "Set 'foldcolumn' when starting diff mode"),
+            ("followwrap", "Follow the 'wrap' option"),
+            ("internal", "Use the internal diff library"),
+            (
+                "indent-heuristic",
+                "Use the indent heuristic for the internal diff",
+            ),
+            ("algorithm:", "Diff algorithm to use with the internal diff"),
+            ("linematch:", "Align and mark changes between similar lines"),
+            (
+                "inline:",
+                "Highlight inline differences: none, simple, char or word",
+            ),
+            ("anchor", "Use 'diffanchors' to align the diff"),
+        ],
+    },
+    OptionValues {
+        option: "display",
+        values: &[
+            ("lastline", "Show as much as possible of the last line"),
+            (
+                "truncate",
+                "Like lastline, but show @@@ at the start of the last line",
+            ),
+            ("uhex", "Show unprintable characters hexadecimal as <xx>"),
+        ],
+    },
+    OptionValues {
+        option: "fileformat",
+        values: &[
+            ("unix", "<NL> line endings"),
+            ("dos", "<CR><NL> line endings"),
+            ("mac", "<CR> line endings"),
+        ],
+    },
+    OptionValues {
+        option: "fileformats",
+        values: &[
+            ("unix", "<NL> line endings"),
+            ("dos", "<CR><NL> line endings"),
+            ("mac", "<CR> line endings"),
+        ],
+    },
+    OptionValues {
+        option: "foldclose",
+        values: &[("all", "Close a fold when the cursor moves out of it")],
+    },
+    OptionValues {
+        option: "foldmethod",
+        values: &[
+            ("manual", "Folds are created manually"),
+            ("indent", "Lines with equal indent form a fold"),
+            ("expr", "'foldexpr' gives the fold level of a line"),
+            ("marker", "Markers are used to specify folds"),
+            ("syntax", "Syntax highlighting items specify folds"),
+            ("diff", "Fold text that is not changed"),
+        ],
+    },
+    OptionValues {
+        option: "foldopen",
+        values: &[
+            ("all", "Any"),
+            ("block", "(, {, [[, [{, etc."),
+            ("hor", "Horizontal movements"),
+            ("insert", "Any command in Insert mode"),
+            ("jump", "Far jumps"),
+            ("mark", "Jumping to a mark"),
+            ("percent", "%"),
+            ("quickfix", ":cn, :crew, :make, etc."),
+            ("search", "Search for a pattern"),
+            ("tag", "Jumping to a tag"),
+            ("undo", "Undo or redo"),
+        ],
+    },
+    OptionValues {
+        option: "inccommand",
+        values: &[
+            (
+                "nosplit",
+                "Show the effects of a command incrementally in the buffer",
+            ),
+            (
+                "split",
+                "Like nosplit, but also show partial off-screen results",
+            ),
+        ],
+    },
+    OptionValues {
+        option: "jumpoptions",
+        values: &[
+            ("stack", "Make the jumplist behave like a tagstack"),
+            ("view", "Restore the view when jumping"),
+            ("clean", "Remove unloaded buffers from the jumplist"),
+        ],
+    },
+    OptionValues {
+        option: "keymodel",
+        values: &[
+            ("startsel", "Using a shifted special key starts selection"),
+            ("stopsel", "Using a non-shifted special key stops selection"),
+        ],
+    },
+    OptionValues {
+        option: "laststatus",
+        values: &[
+            ("0", "Never show a status line"),
+            ("1", "Only if there are at least two windows"),
+            ("2", "Always show a status line"),
+            ("3", "Have a global status line"),
+        ],
+    },
+    OptionValues {
+        option: "mouse",
+        values: &[
+            ("n", "Normal mode"),
+            ("v", "Visual mode"),
+            ("i", "Insert mode"),
+            ("c", "Command-line mode"),
+            ("h", "All previous modes when editing a help file"),
+            ("a", "All previous modes"),
+            ("r", "For hit-enter and more-prompt prompt"),
+        ],
+    },
+    OptionValues {
+        option: "mousemodel",
+        values: &[
+            ("extend", "Right mouse button extends a selection"),
+            ("popup", "Right mouse button pops up a menu"),
+            ("popup_setpos", "Like popup, but the cursor is moved"),
+        ],
+    },
+    OptionValues {
+        option: "nrformats",
+        values: &[
+            ("alpha", "Single alphabetical characters"),
+            ("octal", "Numbers starting with 0"),
+            ("hex", "Numbers starting with 0x or 0X"),
+            ("bin", "Numbers starting with 0b or 0B"),
+            ("unsigned", "Treat numbers as unsigned"),
+            ("blank", "Treat numbers as signed only after a blank"),
+        ],
+    },
+    OptionValues {
+        option: "regexpengine",
+        values: &[
+            ("0", "Automatic selection"),
+            ("1", "Old engine"),
+            ("2", "NFA engine"),
+        ],
+    },
+    OptionValues {
+        option: "selection",
+        values: &[
+            ("old", "Not allowed past line, inclusive"),
+            ("inclusive", "Allowed past line, inclusive"),
+            ("exclusive", "Allowed past line, exclusive"),
+        ],
+    },
+    OptionValues {
+        option: "selectmode",
+        values: &[
+            ("mouse", "When using the mouse"),
+            ("key", "When using shifted special keys"),
+            ("cmd", "When using v, V or CTRL-V"),
+        ],
+    },
+    OptionValues {
+        option: "sessionoptions",
+        values: &[
+            ("blank", "Empty windows"),
+            ("buffers", "Hidden and unloaded buffers"),
+            ("curdir", "The current directory"),
+            ("folds", "Manually created folds, opened/closed folds"),
+            (
+                "globals",
+                "Global variables that start with an uppercase letter",
+            ),
+            ("help", "The help window"),
+            (
+                "localoptions",
+                "Options and mappings local to a window or buffer",
+            ),
+            ("options", "All options and mappings"),
+            ("skiprtp", "Exclude 'runtimepath' and 'packpath'"),
+            ("resize", "Size of the Vim window"),
+            (
+                "sesdir",
+                "The directory of the session file is the current directory",
+            ),
+            (
+                "slash",
+                "Backslashes in file names replaced with forward slashes",
+            ),
+            ("tabpages", "All tab pages"),
+            ("terminal", "Include terminal windows"),
+            ("unix", "With Unix end-of-line format"),
+            ("winpos", "Position of the whole Vim window"),
+            ("winsize", "Window sizes"),
+        ],
+    },
+    OptionValues {
+        option: "showtabline",
+        values: &[
+            ("0", "Never show the tab page labels"),
+            ("1", "Only if there are at least two tab pages"),
+            ("2", "Always show the tab page labels"),
+        ],
+    },
+    OptionValues {
+        option: "signcolumn",
+        values: &[
+            ("auto", "Only when there is a sign to display"),
+            ("no", "Never"),
+            ("yes", "Always"),
+            ("number", "Display signs in the 'number' column"),
+        ],
+    },
+    OptionValues {
+        option: "splitkeep",
+        values: &[
+            ("cursor", "Keep the same relative cursor position"),
+            ("screen", "Keep the text on the same screen line"),
+            ("topline", "Keep the topline the same"),
+        ],
+    },
+    OptionValues {
+        option: "switchbuf",
+        values: &[
+            (
+                "useopen",
+                "Jump to the first open window that contains the buffer",
+            ),
+            ("usetab", "Like useopen, but also consider other tab pages"),
+            ("split", "Split the current window before loading a buffer"),
+            ("vsplit", "Split vertically"),
+            ("newtab", "Open a new tab page"),
+            ("uselast", "Jump to the previously used window"),
+        ],
+    },
+    OptionValues {
+        option: "tagcase",
+        values: &[
+            ("followic", "Follow the 'ignorecase' option"),
+            (
+                "followscs",
+                "Follow the 'smartcase' and 'ignorecase' options",
+            ),
+            ("ignore", "Ignore case"),
+            ("match", "Match case"),
+            ("smart", "Ignore case unless an upper case letter is used"),
+        ],
+    },
+    OptionValues {
+        option: "viewoptions",
+        values: &[
+            ("cursor", "Cursor position in file and in window"),
+            ("curdir", "Local current directory, if set with :lcd"),
+            ("folds", "Manually created folds, opened/closed folds"),
+            (
+                "options",
+                "Options and mappings local to a window or buffer",
+            ),
+            ("localoptions", "Same as options"),
+            (
+                "slash",
+                "Backslashes in file names replaced with forward slashes",
+            ),
+            ("unix", "With Unix end-of-line format"),
+        ],
+    },
+    OptionValues {
+        option: "virtualedit",
+        values: &[
+            ("block", "Allow virtual editing in Visual block mode"),
+            ("insert", "Allow virtual editing in Insert mode"),
+            ("all", "Allow virtual editing in all modes"),
+            (
+                "onemore",
+                "Allow the cursor to move just past the end of the line",
+            ),
+            ("none", "Use the global value"),
+            ("NONE", "Alternative spelling of none"),
+        ],
+    },
+    OptionValues {
+        option: "wildmode",
+        values: &[
+            ("full", "Complete the next full match"),
+            ("longest", "Complete till the longest common string"),
+            ("longest:full", "Like longest, but also start 'wildmenu'"),
+            ("list", "List all matches"),
+            ("list:full", "List all matches and complete the first match"),
+            (
+                "list:longest",
+                "List all matches and complete till longest common string",
+            ),
+            ("lastused", "Sort buffer names by last used time"),
+        ],
+    },
+    OptionValues {
+        option: "wildoptions",
+        values: &[
+            ("fuzzy", "Use fuzzy matching to find completion matches"),
+            ("pum", "Display the completion matches in a popup menu"),
+            ("tagfile", "Show the kind of tag and the file of the tag"),
+        ],
     },
 ];
 
@@ -12024,6 +12777,532 @@ pub static MAP_OPTIONS: &[MapOption] = &[
     },
 ];
 
+// ============================================================================
+// Key Notations
+// ============================================================================
+
+/// Information about a special key notation
+pub struct KeyNotation {
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+/// List of special key notations usable in mappings
+/// Reference: :help key-notation
+pub static KEY_NOTATIONS: &[KeyNotation] = &[
+    KeyNotation {
+        name: "<CR>",
+        description: "Carriage return (Enter)",
+    },
+    KeyNotation {
+        name: "<Esc>",
+        description: "Escape",
+    },
+    KeyNotation {
+        name: "<Tab>",
+        description: "Tab",
+    },
+    KeyNotation {
+        name: "<S-Tab>",
+        description: "Shift-Tab",
+    },
+    KeyNotation {
+        name: "<BS>",
+        description: "Backspace",
+    },
+    KeyNotation {
+        name: "<Del>",
+        description: "Delete",
+    },
+    KeyNotation {
+        name: "<Space>",
+        description: "Space",
+    },
+    KeyNotation {
+        name: "<Bar>",
+        description: "Vertical bar `|`, which would otherwise end the command",
+    },
+    KeyNotation {
+        name: "<Bslash>",
+        description: "Backslash `\\`",
+    },
+    KeyNotation {
+        name: "<lt>",
+        description: "Less-than `<`",
+    },
+    KeyNotation {
+        name: "<NL>",
+        description: "Linefeed",
+    },
+    KeyNotation {
+        name: "<Nop>",
+        description: "No operation; disables the key",
+    },
+    KeyNotation {
+        name: "<Leader>",
+        description: "Replaced with the value of `g:mapleader` (default `\\`)",
+    },
+    KeyNotation {
+        name: "<LocalLeader>",
+        description: "Replaced with the value of `g:maplocalleader`",
+    },
+    KeyNotation {
+        name: "<Plug>",
+        description: "Internal key for plugin mappings that cannot be typed",
+    },
+    KeyNotation {
+        name: "<SID>",
+        description: "Script ID, for calling script-local functions from mappings",
+    },
+    KeyNotation {
+        name: "<Cmd>",
+        description: "Execute a command without changing mode",
+    },
+    KeyNotation {
+        name: "<ScriptCmd>",
+        description: "Like <Cmd>, but in the context of the script defining the mapping",
+    },
+    KeyNotation {
+        name: "<C-w>",
+        description: "CTRL-W: window commands (delete word in Insert mode)",
+    },
+    KeyNotation {
+        name: "<C-r>",
+        description: "CTRL-R: insert register (redo in Normal mode)",
+    },
+    KeyNotation {
+        name: "<C-o>",
+        description: "CTRL-O: execute one Normal mode command (older jump position)",
+    },
+    KeyNotation {
+        name: "<C-u>",
+        description: "CTRL-U: delete to start of line (scroll up in Normal mode)",
+    },
+    KeyNotation {
+        name: "<C-n>",
+        description: "CTRL-N: next match",
+    },
+    KeyNotation {
+        name: "<C-p>",
+        description: "CTRL-P: previous match",
+    },
+    KeyNotation {
+        name: "<C-x>",
+        description: "CTRL-X: completion sub-mode (decrement in Normal mode)",
+    },
+    KeyNotation {
+        name: "<C-a>",
+        description: "CTRL-A: increment (insert last text in Insert mode)",
+    },
+    KeyNotation {
+        name: "<C-]>",
+        description: "CTRL-]: jump to tag",
+    },
+    KeyNotation {
+        name: "<C-\\><C-n>",
+        description: "Go to Normal mode from any mode (including Terminal mode)",
+    },
+    KeyNotation {
+        name: "<Up>",
+        description: "Cursor up",
+    },
+    KeyNotation {
+        name: "<Down>",
+        description: "Cursor down",
+    },
+    KeyNotation {
+        name: "<Left>",
+        description: "Cursor left",
+    },
+    KeyNotation {
+        name: "<Right>",
+        description: "Cursor right",
+    },
+    KeyNotation {
+        name: "<Home>",
+        description: "Home",
+    },
+    KeyNotation {
+        name: "<End>",
+        description: "End",
+    },
+    KeyNotation {
+        name: "<PageUp>",
+        description: "Page up",
+    },
+    KeyNotation {
+        name: "<PageDown>",
+        description: "Page down",
+    },
+    KeyNotation {
+        name: "<Insert>",
+        description: "Insert key",
+    },
+    KeyNotation {
+        name: "<F1>",
+        description: "Function key 1 (<F1> to <F12>)",
+    },
+    KeyNotation {
+        name: "<LeftMouse>",
+        description: "Left mouse click",
+    },
+    KeyNotation {
+        name: "<RightMouse>",
+        description: "Right mouse click",
+    },
+    KeyNotation {
+        name: "<ScrollWheelUp>",
+        description: "Mouse wheel up",
+    },
+    KeyNotation {
+        name: "<ScrollWheelDown>",
+        description: "Mouse wheel down",
+    },
+];
+
+/// Modifier prefixes of key notations (e.g., `<C-x>`, `<M-S-x>`)
+/// Reference: :help <>
+static KEY_MODIFIERS: &[(&str, &str)] = &[
+    ("C", "Ctrl"),
+    ("S", "Shift"),
+    ("M", "Meta (Alt)"),
+    ("A", "Alt"),
+    ("D", "Command"),
+    ("T", "Meta (GUI)"),
+];
+
+/// Describe a key notation used in mappings (e.g., "<CR>", "<C-S-x>", "<F5>")
+///
+/// Returns None when `name` is not a known key notation.
+pub fn describe_key_notation(name: &str) -> Option<String> {
+    if let Some(key) = KEY_NOTATIONS
+        .iter()
+        .find(|k| k.name.eq_ignore_ascii_case(name))
+    {
+        return Some(key.description.to_string());
+    }
+    let inner = name.strip_prefix('<')?.strip_suffix('>')?;
+
+    // Function keys: <F1> .. <F37>
+    if let Some(number) = inner
+        .strip_prefix(['F', 'f'])
+        .filter(|number| number.parse::<u8>().is_ok_and(|n| (1..=37).contains(&n)))
+    {
+        return Some(format!("Function key {}", number));
+    }
+
+    // Modified keys: <C-x>, <M-S-Left>, ...
+    let mut modifiers = Vec::new();
+    let mut rest = inner;
+    // The trailing `-` of `<C-->` is the key itself
+    while let Some((prefix, after)) = rest.split_once('-').filter(|(_, after)| !after.is_empty()) {
+        let (_, modifier) = KEY_MODIFIERS
+            .iter()
+            .find(|(m, _)| m.eq_ignore_ascii_case(prefix))?;
+        modifiers.push(*modifier);
+        rest = after;
+    }
+    if modifiers.is_empty() || rest.is_empty() {
+        return None;
+    }
+    let key = if rest.chars().count() == 1 {
+        format!("`{}`", rest)
+    } else {
+        let notation = format!("<{}>", rest);
+        KEY_NOTATIONS
+            .iter()
+            .find(|k| k.name.eq_ignore_ascii_case(&notation))?;
+        format!("`{}`", notation)
+    };
+    Some(format!("{} + {}", modifiers.join(" + "), key))
+}
+
+// ============================================================================
+// User Command Special Arguments
+// ============================================================================
+
+/// Information about a special argument in a `:command` replacement text
+pub struct CommandSpecialArg {
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+/// List of special arguments replaced in `:command` definitions
+/// Reference: :help <args>
+pub static COMMAND_SPECIAL_ARGS: &[CommandSpecialArg] = &[
+    CommandSpecialArg {
+        name: "<args>",
+        description: "The command arguments, exactly as supplied",
+    },
+    CommandSpecialArg {
+        name: "<q-args>",
+        description: "The command arguments, quoted as a single string",
+    },
+    CommandSpecialArg {
+        name: "<f-args>",
+        description: "The command arguments, split on white space and quoted as function arguments",
+    },
+    CommandSpecialArg {
+        name: "<bang>",
+        description: "`!` if the command was executed with a bang, empty otherwise",
+    },
+    CommandSpecialArg {
+        name: "<line1>",
+        description: "The starting line of the command range",
+    },
+    CommandSpecialArg {
+        name: "<line2>",
+        description: "The final line of the command range",
+    },
+    CommandSpecialArg {
+        name: "<range>",
+        description: "The number of items in the command range: 0, 1 or 2",
+    },
+    CommandSpecialArg {
+        name: "<count>",
+        description: "Any count supplied (as described for -range and -count)",
+    },
+    CommandSpecialArg {
+        name: "<reg>",
+        description: "The optional register, if specified (same as <register>)",
+    },
+    CommandSpecialArg {
+        name: "<register>",
+        description: "The optional register, if specified",
+    },
+    CommandSpecialArg {
+        name: "<mods>",
+        description: "The command modifiers, if specified (e.g., `:vertical`)",
+    },
+    CommandSpecialArg {
+        name: "<q-mods>",
+        description: "The command modifiers, quoted as a single string",
+    },
+    CommandSpecialArg {
+        name: "<lt>",
+        description: "A single `<` character",
+    },
+];
+
+// ============================================================================
+// :syntax Sub-commands and Arguments
+// ============================================================================
+
+/// Information about a `:syntax` sub-command
+pub struct SyntaxSubcommand {
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+/// List of `:syntax` sub-commands
+/// Reference: :help :syntax-commands
+pub static SYNTAX_SUBCOMMANDS: &[SyntaxSubcommand] = &[
+    SyntaxSubcommand {
+        name: "case",
+        description: "Set case sensitivity for following items (match/ignore)",
+    },
+    SyntaxSubcommand {
+        name: "clear",
+        description: "Clear syntax items for the current buffer or a group",
+    },
+    SyntaxSubcommand {
+        name: "cluster",
+        description: "Define a cluster of syntax groups",
+    },
+    SyntaxSubcommand {
+        name: "conceal",
+        description: "Set whether following items are concealed (on/off)",
+    },
+    SyntaxSubcommand {
+        name: "enable",
+        description: "Enable syntax highlighting, keeping current colors",
+    },
+    SyntaxSubcommand {
+        name: "foldlevel",
+        description: "Set how the fold level is computed (start/minimum)",
+    },
+    SyntaxSubcommand {
+        name: "include",
+        description: "Include syntax items from another syntax file",
+    },
+    SyntaxSubcommand {
+        name: "iskeyword",
+        description: "Set the keyword characters for syntax keywords",
+    },
+    SyntaxSubcommand {
+        name: "keyword",
+        description: "Define keyword items",
+    },
+    SyntaxSubcommand {
+        name: "list",
+        description: "List syntax items",
+    },
+    SyntaxSubcommand {
+        name: "manual",
+        description: "Enable syntax highlighting only when 'syntax' is set",
+    },
+    SyntaxSubcommand {
+        name: "match",
+        description: "Define a match item using a pattern",
+    },
+    SyntaxSubcommand {
+        name: "off",
+        description: "Disable syntax highlighting",
+    },
+    SyntaxSubcommand {
+        name: "on",
+        description: "Enable syntax highlighting, overruling current colors",
+    },
+    SyntaxSubcommand {
+        name: "region",
+        description: "Define a region item with start/skip/end patterns",
+    },
+    SyntaxSubcommand {
+        name: "reset",
+        description: "Reset highlight groups to their defaults",
+    },
+    SyntaxSubcommand {
+        name: "spell",
+        description: "Set where spell checking is done (toplevel/notoplevel/default)",
+    },
+    SyntaxSubcommand {
+        name: "sync",
+        description: "Set how to synchronize syntax highlighting",
+    },
+];
+
+/// Information about an argument of `:syntax keyword/match/region/cluster`
+pub struct SyntaxArgument {
+    pub name: &'static str,
+    pub description: &'static str,
+    /// Sub-commands that accept this argument
+    pub subcommands: &'static [&'static str],
+}
+
+const SYN_KMR: &[&str] = &["keyword", "match", "region"];
+const SYN_MR: &[&str] = &["match", "region"];
+const SYN_R: &[&str] = &["region"];
+
+/// List of `:syntax` arguments
+/// Reference: :help :syn-arguments
+pub static SYNTAX_ARGUMENTS: &[SyntaxArgument] = &[
+    SyntaxArgument {
+        name: "conceal",
+        description: "Item is concealed when 'conceallevel' is non-zero",
+        subcommands: SYN_KMR,
+    },
+    SyntaxArgument {
+        name: "concealends",
+        description: "Conceal the start and end of the region",
+        subcommands: SYN_R,
+    },
+    SyntaxArgument {
+        name: "cchar=",
+        description: "Character shown in place of concealed text",
+        subcommands: SYN_KMR,
+    },
+    SyntaxArgument {
+        name: "contained",
+        description: "Item is only recognized inside a `contains` of another item",
+        subcommands: SYN_KMR,
+    },
+    SyntaxArgument {
+        name: "containedin=",
+        description: "Item can appear inside the listed groups",
+        subcommands: SYN_KMR,
+    },
+    SyntaxArgument {
+        name: "nextgroup=",
+        description: "Groups tried after the end of this item",
+        subcommands: SYN_KMR,
+    },
+    SyntaxArgument {
+        name: "transparent",
+        description: "Item takes the highlighting of the containing item",
+        subcommands: SYN_KMR,
+    },
+    SyntaxArgument {
+        name: "skipwhite",
+        description: "Skip white space before `nextgroup`",
+        subcommands: SYN_KMR,
+    },
+    SyntaxArgument {
+        name: "skipnl",
+        description: "Skip end-of-line before `nextgroup`",
+        subcommands: SYN_KMR,
+    },
+    SyntaxArgument {
+        name: "skipempty",
+        description: "Skip empty lines before `nextgroup`",
+        subcommands: SYN_KMR,
+    },
+    SyntaxArgument {
+        name: "contains=",
+        description: "Groups allowed inside this item (ALL, ALLBUT, TOP, CONTAINED, @cluster)",
+        subcommands: &["match", "region", "cluster"],
+    },
+    SyntaxArgument {
+        name: "oneline",
+        description: "Region does not cross a line boundary",
+        subcommands: SYN_R,
+    },
+    SyntaxArgument {
+        name: "fold",
+        description: "Increase the fold level by one for this item",
+        subcommands: SYN_MR,
+    },
+    SyntaxArgument {
+        name: "display",
+        description: "Skip item when detecting highlighting for a line not displayed",
+        subcommands: SYN_MR,
+    },
+    SyntaxArgument {
+        name: "extend",
+        description: "Item may extend past the end of the containing item",
+        subcommands: SYN_MR,
+    },
+    SyntaxArgument {
+        name: "excludenl",
+        description: "Pattern with `$` does not extend a containing item past the line",
+        subcommands: SYN_MR,
+    },
+    SyntaxArgument {
+        name: "keepend",
+        description: "Matches of contained items do not extend the region",
+        subcommands: SYN_MR,
+    },
+    SyntaxArgument {
+        name: "matchgroup=",
+        description: "Highlight group for the following start/end patterns",
+        subcommands: SYN_R,
+    },
+    SyntaxArgument {
+        name: "start=",
+        description: "Pattern that starts the region",
+        subcommands: SYN_R,
+    },
+    SyntaxArgument {
+        name: "skip=",
+        description: "Pattern inside the region where the end pattern is not searched",
+        subcommands: SYN_R,
+    },
+    SyntaxArgument {
+        name: "end=",
+        description: "Pattern that ends the region",
+        subcommands: SYN_R,
+    },
+    SyntaxArgument {
+        name: "add=",
+        description: "Groups added to the cluster",
+        subcommands: &["cluster"],
+    },
+    SyntaxArgument {
+        name: "remove=",
+        description: "Groups removed from the cluster",
+        subcommands: &["cluster"],
+    },
+];
+
 // ============================================================================
 // has() Features
 // ============================================================================
@@ -12037,10 +13316,28 @@ pub struct HasFeature {
 
 /// Version prefixes for has() that should not be warned about
 /// Reference: :help has()
-/// Note: Will be used for diagnostics to allow patch/version checks
-#[allow(dead_code)]
 pub static HAS_VERSION_PREFIXES: &[&str] = &["patch-", "nvim-"];
 
+/// Describe a version check passed to has() (e.g., "patch-8.2.1234", "nvim-0.9")
+///
+/// Returns None when `name` is not a version check.
+pub fn describe_has_version(name: &str) -> Option<String> {
+    let prefix = HAS_VERSION_PREFIXES
+        .iter()
+        .find(|prefix| name.starts_with(*prefix))?;
+    let version = &name[prefix.len()..];
+    match *prefix {
+        "patch-" => {
+            let (release, patch) = version.rsplit_once('.')?;
+            Some(format!(
+                "True when running Vim {} with patch {} or later",
+                release, patch
+            ))
+        }
+        _ => Some(format!("True when running Neovim {} or later", version)),
+    }
+}
+
 /// List of has() features
 /// Reference: :help feature-list
 pub static HAS_FEATURES: &[HasFeature] = &[
@@ -13099,639 +14396,766 @@ pub static BUILTIN_VARIABLES: &[BuiltinVariable] = &[
         name: "v:argv",
         description: "Command line arguments Vim was invoked with",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:char",
         description: "Argument for evaluating 'formatexpr' and typed character in abbreviation",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:charconvert_from",
         description: "Encoding of file to be converted (valid in 'charconvert')",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:charconvert_to",
         description: "Encoding of file after conversion (valid in 'charconvert')",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:cmdarg",
         description: "Extra arguments (++p, ++enc=, ++ff=) given to file read/write command",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:cmdbang",
         description: "Set to 1 when '!' was used with file read/write command, otherwise 0",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:collate",
         description: "Current locale setting for collation order (LC_COLLATE)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:completed_item",
         description: "Dictionary containing complete-items for most recently completed word",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:count",
         description: "Count given for the last Normal mode command (0 if no count)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:count1",
         description: "Like v:count, but defaults to 1 when no count is used",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:ctype",
         description: "Current locale setting for characters (LC_CTYPE)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:dying",
         description: "Set to 1 when a deadly signal is caught, increases with each signal",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:echospace",
         description: "Number of screen cells available for :echo message in last line",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:errmsg",
         description: "Last error message that occurred (modifiable)",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:errors",
         description: "List of errors found by assert functions",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:event",
         description: "Dictionary of event data for the current autocommand",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:exception",
         description: "Value of exception most recently caught and not finished",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:exiting",
         description: "Exit code, or v:null before VimLeavePre/VimLeave autocmds",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:false",
         description: "Special value for 'false' in JSON/msgpack (converts to 0 as Number)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:fcs_choice",
         description: "What should happen after FileChangedShell event (reload/edit/ask/empty)",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:fcs_reason",
         description: "Reason why FileChangedShell was triggered (deleted/conflict/changed/mode/time)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:fname",
         description: "File name detected when evaluating 'includeexpr'",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:fname_diff",
         description: "Name of diff (patch) file (valid in 'patchexpr')",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:fname_in",
         description: "Name of input file (valid in 'charconvert', 'diffexpr', 'patchexpr')",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:fname_new",
         description: "Name of new version of file (valid in 'diffexpr')",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:fname_out",
         description: "Name of output file (valid in 'charconvert', 'diffexpr', 'patchexpr')",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:folddashes",
         description: "Dashes representing foldlevel of closed fold (for 'foldtext')",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:foldend",
         description: "Last line of closed fold (for 'foldtext')",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:foldlevel",
         description: "Foldlevel of closed fold (for 'foldtext')",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:foldstart",
         description: "First line of closed fold (for 'foldtext')",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:hlsearch",
         description: "Variable that indicates whether search highlighting is on",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:insertmode",
         description: "Mode for InsertEnter/InsertChange events (i/r/v)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:key",
         description: "Key of current Dictionary item (valid in map()/filter())",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:lang",
         description: "Current locale setting for messages (LC_MESSAGES)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:lc_time",
         description: "Current locale setting for time messages (LC_TIME)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:lnum",
         description: "Line number for 'foldexpr', 'formatexpr', 'indentexpr', 'statuscolumn'",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:maxcol",
         description: "Maximum line length (currently 2147483647)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:mouse_col",
         description: "Column number for mouse click from getchar()",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:mouse_lnum",
         description: "Line number for mouse click from getchar()",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:mouse_win",
         description: "Window number for mouse click from getchar()",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:mouse_winid",
         description: "Window ID for mouse click from getchar()",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:null",
         description: "Special value for 'null' in JSON/msgpack (converts to 0 as Number)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:numbermax",
         description: "Maximum value of a Number",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:numbermin",
         description: "Minimum value of a Number (negative)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:numbersize",
         description: "Number of bits in a Number (normally 64, sometimes 32)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:oldfiles",
         description: "List of file names loaded from shada/viminfo file on startup",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:operator",
         description: "Last operator given in Normal mode",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:option_command",
         description: "Command used to set option (setlocal/setglobal/set/modeline)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:option_new",
         description: "New value of option (valid in OptionSet)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:option_old",
         description: "Old value of option (valid in OptionSet)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:option_oldglobal",
         description: "Old global value of option (valid in OptionSet)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:option_oldlocal",
         description: "Old local value of option (valid in OptionSet)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:option_type",
         description: "Scope of set command: 'global' or 'local' (valid in OptionSet)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:prevcount",
         description: "Count given for last but one Normal mode command",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:profiling",
         description: "Set to 1 after using ':profile start'",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:progname",
         description: "Name by which Vim/Nvim was invoked (path removed)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:progpath",
         description: "Absolute path to current running Vim/Nvim",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:register",
         description: "Name of register in effect for current normal mode command",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:scrollstart",
         description: "String describing script/function that caused screen scroll",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:searchforward",
         description: "Search direction: 1 after forward search, 0 after backward search",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:servername",
         description: "Primary listen-address/server name of Vim/Nvim",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:shell_error",
         description: "Result of last shell command (-1 if command could not be executed)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:stacktrace",
         description: "Stack trace of exception most recently caught and not finished",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:statusmsg",
         description: "Last given status message (modifiable)",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:swapchoice",
         description: "SwapExists autocommand choice for handling existing swapfile",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:swapcommand",
         description: "Normal mode command to execute after file opened from SwapExists",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:swapname",
         description: "Name of swapfile found (valid during SwapExists event)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_blob",
         description: "Value of Blob type for type()",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_bool",
         description: "Value of Boolean type for type()",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_dict",
         description: "Value of Dictionary type for type()",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_float",
         description: "Value of Float type for type()",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_func",
         description: "Value of Funcref type for type()",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_list",
         description: "Value of List type for type()",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_number",
         description: "Value of Number type for type()",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_string",
         description: "Value of String type for type()",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:termrequest",
         description: "Value of most recent OSC/DCS/APC control sequence from terminal",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:termresponse",
         description: "Escape sequence returned by terminal for t_RV termcap entry",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:testing",
         description: "Must be set before using test_garbagecollect_now()",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:this_session",
         description: "Full filename of last loaded or saved session file",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:throwpoint",
         description: "Point where exception most recently caught was thrown",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:true",
         description: "Special value for 'true' in JSON/msgpack (converts to 1 as Number)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:val",
         description: "Value of current List/Dictionary item (valid in map()/filter())",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:version",
         description: "Vim version number: major*100 + minor (e.g., 901 for Vim 9.1)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:versionlong",
         description: "Vim version including patchlevel (e.g., 9010123 for 9.1.123)",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:vim_did_enter",
         description: "0 during startup, 1 just before VimEnter",
         availability: Availability::Common,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:warningmsg",
         description: "Last given warning message (modifiable)",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:windowid",
         description: "Application-specific window handle (not Vim window-ID)",
         availability: Availability::Common,
+        readonly: true,
     },
     // === Vim only ===
     BuiltinVariable {
         name: "v:beval_bufnr",
         description: "Buffer number over which mouse pointer is (for 'balloonexpr')",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:beval_col",
         description: "Column number (byte index) for balloon eval",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:beval_lnum",
         description: "Line number for balloon eval",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:beval_text",
         description: "Text under or after mouse pointer for balloon eval",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:beval_winid",
         description: "Window ID for balloon eval",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:beval_winnr",
         description: "Window number for balloon eval",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:clipmethod",
         description: "Current method of accessing clipboard (wayland/x11)",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:clipproviders",
         description: "List of available clipboard providers",
         availability: Availability::VimOnly,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:colornames",
         description: "Dictionary that maps color names to hex color strings",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:none",
         description: "Special value for empty in JSON (evaluates to 'v:none' as String)",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:python3_version",
         description: "Version of Python 3 that Vim was built against",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:sizeofint",
         description: "Number of bytes in an int",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:sizeoflong",
         description: "Number of bytes in a long",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:sizeofpointer",
         description: "Number of bytes in a pointer",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_channel",
         description: "Value of Channel type for type()",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_class",
         description: "Value of class type for type()",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_enum",
         description: "Value of enum type for type()",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_enumvalue",
         description: "Value of enumvalue type for type()",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_job",
         description: "Value of Job type for type()",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_none",
         description: "Value of None type for type()",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_object",
         description: "Value of object type for type()",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_tuple",
         description: "Value of Tuple type for type()",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:t_typealias",
         description: "Value of typealias type for type()",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:termblinkresp",
         description: "Escape sequence returned by terminal for t_RC (cursor blink)",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:termda1",
         description: "Escape sequence returned by primary device attributes (DA1) query",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:termosc",
         description: "Escape sequence of most recent OSC response from terminal",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:termrbgresp",
         description: "Escape sequence returned by terminal for t_RB (background color)",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:termrfgresp",
         description: "Escape sequence returned by terminal for t_RF (foreground color)",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:termstyleresp",
         description: "Escape sequence returned by terminal for t_RS (cursor shape)",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:termu7resp",
         description: "Escape sequence returned by terminal for t_u7 (ambiguous width)",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:var",
         description: "Dummy variable used to access v: scope in expressions",
         availability: Availability::VimOnly,
+        readonly: false,
     },
     BuiltinVariable {
         name: "v:wayland_display",
         description: "Name of Wayland display that Vim is connected to",
         availability: Availability::VimOnly,
+        readonly: true,
     },
     // === Neovim only ===
     BuiltinVariable {
         name: "v:lua",
         description: "Prefix for calling Lua functions from Vimscript expressions",
         availability: Availability::NeovimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:msgpack_types",
         description: "Dictionary containing msgpack types for msgpackparse()/msgpackdump()",
         availability: Availability::NeovimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:relnum",
         description: "Relative line number for 'statuscolumn' expression",
         availability: Availability::NeovimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:stderr",
         description: "Channel-id corresponding to stderr (always 2)",
         availability: Availability::NeovimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:virtnum",
         description: "Virtual line number for 'statuscolumn' expression",
         availability: Availability::NeovimOnly,
+        readonly: true,
     },
     BuiltinVariable {
         name: "v:vim_did_init",
         description: "0 during initialization, 1 after sourcing vimrc and before load-plugins",
         availability: Availability::NeovimOnly,
+        readonly: true,
     },
     // === Buffer-local predefined variables (b:) ===
     BuiltinVariable {
         name: "b:changedtick",
         description: "Total number of changes to the current buffer",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "b:current_syntax",
         description: "Name of the current syntax (set by syntax files)",
         availability: Availability::Common,
+        readonly: false,
     },
     // === Global predefined variables (g:) ===
     // Common (Vim and Neovim)
@@ -13739,108 +15163,129 @@ pub static BUILTIN_VARIABLES: &[BuiltinVariable] = &[
         name: "g:colors_name",
         description: "Name of the currently active color scheme",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:syntax_on",
         description: "Set when syntax highlighting is enabled",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:actual_curbuf",
         description: "Buffer number of the actual current buffer during statusline evaluation",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:actual_curwin",
         description: "Window ID of the actual current window during statusline evaluation",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:statusline_winid",
         description: "Window ID of the window the statusline belongs to",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:mapleader",
         description: "Key used as <Leader> in mappings (default: backslash)",
         availability: Availability::Common,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:maplocalleader",
         description: "Key used as <LocalLeader> in buffer-local mappings",
         availability: Availability::Common,
+        readonly: false,
     },
     // Vim only
     BuiltinVariable {
         name: "g:actual_curtabpage",
         description: "Tab page number of the actual current tab during tabpanel evaluation",
         availability: Availability::VimOnly,
+        readonly: false,
     },
     // Neovim only
     BuiltinVariable {
         name: "g:health",
         description: "Dictionary for :checkhealth configuration (e.g., style='float')",
         availability: Availability::NeovimOnly,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:clipboard",
         description: "Clipboard tool configuration (name or dict with copy/paste commands)",
         availability: Availability::NeovimOnly,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:termfeatures",
         description: "Dictionary of terminal feature flags (e.g., osc52)",
         availability: Availability::NeovimOnly,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:editorconfig",
         description: "Enable/disable EditorConfig integration (default: true)",
         availability: Availability::NeovimOnly,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:did_load_filetypes",
         description: "Set to disable the builtin filetype detection",
         availability: Availability::NeovimOnly,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:python3_host_prog",
         description: "Path to Python 3 executable for the Python provider",
         availability: Availability::NeovimOnly,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:loaded_python3_provider",
         description: "Set to 0 to disable Python 3 provider",
         availability: Availability::NeovimOnly,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:ruby_host_prog",
         description: "Path to Ruby executable for the Ruby provider",
         availability: Availability::NeovimOnly,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:loaded_ruby_provider",
         description: "Set to 0 to disable Ruby provider",
         availability: Availability::NeovimOnly,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:perl_host_prog",
         description: "Path to Perl executable for the Perl provider",
         availability: Availability::NeovimOnly,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:loaded_perl_provider",
         description: "Set to 0 to disable Perl provider",
         availability: Availability::NeovimOnly,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:node_host_prog",
         description: "Path to Node.js executable for the Node provider",
         availability: Availability::NeovimOnly,
+        readonly: false,
     },
     BuiltinVariable {
         name: "g:loaded_node_provider",
         description: "Set to 0 to disable Node.js provider",
         availability: Availability::NeovimOnly,
+        readonly: false,
     },
 ];
 
@@ -13863,6 +15308,101 @@ mod tests {
         assert!(Availability::NeovimOnly.is_compatible(EditorMode::NeovimOnly));
     }
 
+    #[test]
+    fn test_find_builtin_function() {
+        let f = BuiltinFunction::find("strlen").unwrap();
+        assert_eq!(f.signature, "strlen({string})");
+        assert!(BuiltinFunction::find("nvim_buf_get_lines").is_some());
+        assert!(BuiltinFunction::find("NotABuiltin").is_none());
+    }
+
+    #[test]
+    fn test_find_builtin_option() {
+        assert_eq!(BuiltinOption::find("tabstop").unwrap().name, "tabstop");
+        assert_eq!(BuiltinOption::find("ts").unwrap().name, "tabstop");
+        assert!(BuiltinOption::find("notanoption").is_none());
+    }
+
+    #[test]
+    fn test_since() {
+        let reduce = BuiltinFunction::find("reduce").unwrap().since().unwrap();
+        assert_eq!(reduce.vim, Some("8.2.0878"));
+        assert_eq!(
+            reduce.label(EditorMode::Both).as_deref(),
+            Some("Added in Vim 8.2.0878")
+        );
+        assert_eq!(reduce.label(EditorMode::NeovimOnly), None);
+        assert!(BuiltinFunction::find("strlen").unwrap().since().is_none());
+        assert!(BuiltinOption::find("wincolor").unwrap().since().is_some());
+        assert!(
+            AutocmdEvent::find("textyankpost")
+                .unwrap()
+                .since()
+                .is_some()
+        );
+        assert_eq!(parse_version("8.2.0878"), (8, 2, 878));
+        assert_eq!(parse_version("0.10"), (0, 10, 0));
+    }
+
+    #[test]
+    fn test_command_synopsis() {
+        let find = |name| {
+            BUILTIN_COMMANDS
+                .iter()
+                .find(|cmd| cmd.name == name)
+                .unwrap()
+        };
+        assert_eq!(find("quit").abbreviated_name(), "q[uit]");
+        assert_eq!(find("if").abbreviated_name(), "if");
+        assert_eq!(find("delete").synopsis(), ":[range]d[elete] {args}");
+        assert_eq!(find("edit").synopsis(), ":e[dit][!] {file}");
+        assert_eq!(find("echo").synopsis(), ":ec[ho] {expr}");
+        assert_eq!(find("nohlsearch").synopsis(), ":noh[lsearch]");
+    }
+
+    #[test]
+    fn test_describe_has_version() {
+        assert_eq!(
+            describe_has_version("patch-8.2.1234").as_deref(),
+            Some("True when running Vim 8.2 with patch 1234 or later")
+        );
+        assert_eq!(
+            describe_has_version("nvim-0.10").as_deref(),
+            Some("True when running Neovim 0.10 or later")
+        );
+        assert_eq!(describe_has_version("python3"), None);
+    }
+
+    #[test]
+    fn test_describe_key_notation() {
+        assert_eq!(
+            describe_key_notation("<cr>").as_deref(),
+            Some("Carriage return (Enter)")
+        );
+        assert_eq!(
+            describe_key_notation("<C-r>").as_deref(),
+            Some("CTRL-R: insert register (redo in Normal mode)")
+        );
+        assert_eq!(
+            describe_key_notation("<C-S-Left>").as_deref(),
+            Some("Ctrl + Shift + `<Left>`")
+        );
+        assert_eq!(
+            describe_key_notation("<M-j>").as_deref(),
+            Some("Meta (Alt) + `j`")
+        );
+        assert_eq!(
+            describe_key_notation("<C-->").as_deref(),
+            Some("Ctrl + `-`")
+        );
+        assert_eq!(
+            describe_key_notation("<F5>").as_deref(),
+            Some("Function key 5")
+        );
+        assert_eq!(describe_key_notation("<C-Foo>"), None);
+        assert_eq!(describe_key_notation("<buffer>"), None);
+    }
+
     #[test]
     fn test_availability_label_suffix() {
         assert_eq!(Availability::Common.label_suffix(), "");
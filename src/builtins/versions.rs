@@ -0,0 +1,2252 @@
+//! Versions of Vim and Neovim that added builtin functions, options and
+//! autocmd events
+//!
+//! Generated from their help by `examples/gen_builtins.rs`. Regenerate with
+//! `just gen-builtins <vim-runtime> <neovim-runtime>` instead of editing by hand.
+
+use super::Since;
+
+/// Functions by name, sorted for binary search
+pub static FUNCTION_VERSIONS: &[(&str, Since)] = &[
+    (
+        "acos",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "and",
+        Since {
+            vim: Some("7.3.377"),
+            neovim: None,
+        },
+    ),
+    (
+        "appendbufline",
+        Since {
+            vim: Some("8.1.0037"),
+            neovim: None,
+        },
+    ),
+    (
+        "arglistid",
+        Since {
+            vim: Some("7.4.312"),
+            neovim: None,
+        },
+    ),
+    (
+        "asin",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "assert_beeps",
+        Since {
+            vim: Some("8.0.1510"),
+            neovim: None,
+        },
+    ),
+    (
+        "assert_equal",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "assert_equalfile",
+        Since {
+            vim: Some("8.0.1523"),
+            neovim: None,
+        },
+    ),
+    (
+        "assert_exception",
+        Since {
+            vim: Some("7.4.1092"),
+            neovim: None,
+        },
+    ),
+    (
+        "assert_fails",
+        Since {
+            vim: Some("7.4.1096"),
+            neovim: None,
+        },
+    ),
+    (
+        "assert_false",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "assert_inrange",
+        Since {
+            vim: Some("7.4.2095"),
+            neovim: None,
+        },
+    ),
+    (
+        "assert_match",
+        Since {
+            vim: Some("7.4.1663"),
+            neovim: None,
+        },
+    ),
+    (
+        "assert_nobeep",
+        Since {
+            vim: Some("8.2.2694"),
+            neovim: None,
+        },
+    ),
+    (
+        "assert_notequal",
+        Since {
+            vim: Some("7.4.1703"),
+            neovim: None,
+        },
+    ),
+    (
+        "assert_notmatch",
+        Since {
+            vim: Some("7.4.1703"),
+            neovim: None,
+        },
+    ),
+    (
+        "assert_report",
+        Since {
+            vim: Some("8.0.0477"),
+            neovim: None,
+        },
+    ),
+    (
+        "assert_true",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "atan2",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "autocmd_add",
+        Since {
+            vim: Some("8.2.5011"),
+            neovim: None,
+        },
+    ),
+    (
+        "autocmd_delete",
+        Since {
+            vim: Some("9.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "autocmd_get",
+        Since {
+            vim: Some("9.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "balloon_gettext",
+        Since {
+            vim: Some("8.1.1303"),
+            neovim: None,
+        },
+    ),
+    (
+        "balloon_show",
+        Since {
+            vim: Some("8.0.0396"),
+            neovim: None,
+        },
+    ),
+    (
+        "balloon_split",
+        Since {
+            vim: Some("8.0.1318"),
+            neovim: None,
+        },
+    ),
+    (
+        "blob2list",
+        Since {
+            vim: Some("8.2.3438"),
+            neovim: None,
+        },
+    ),
+    (
+        "bufadd",
+        Since {
+            vim: Some("8.1.1610"),
+            neovim: None,
+        },
+    ),
+    (
+        "bufload",
+        Since {
+            vim: Some("8.1.1610"),
+            neovim: None,
+        },
+    ),
+    (
+        "bufwinid",
+        Since {
+            vim: Some("7.4.1893"),
+            neovim: None,
+        },
+    ),
+    (
+        "byteidxcomp",
+        Since {
+            vim: Some("7.4.057"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_canread",
+        Since {
+            vim: Some("8.0.0105"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_close",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_close_in",
+        Since {
+            vim: Some("7.4.2298"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_evalexpr",
+        Since {
+            vim: Some("7.4.1435"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_evalraw",
+        Since {
+            vim: Some("7.4.1435"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_getbufnr",
+        Since {
+            vim: Some("7.4.1438"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_getjob",
+        Since {
+            vim: Some("7.4.1382"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_info",
+        Since {
+            vim: Some("7.4.1624"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_log",
+        Since {
+            vim: Some("7.4.1351"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_logfile",
+        Since {
+            vim: Some("7.4.1310"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_open",
+        Since {
+            vim: Some("7.4.1286"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_read",
+        Since {
+            vim: Some("7.4.1372"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_readblob",
+        Since {
+            vim: Some("8.2"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_readraw",
+        Since {
+            vim: Some("7.4.1372"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_sendexpr",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_sendraw",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_setoptions",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "ch_status",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "charclass",
+        Since {
+            vim: Some("8.2.1536"),
+            neovim: None,
+        },
+    ),
+    (
+        "charcol",
+        Since {
+            vim: Some("9.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "charidx",
+        Since {
+            vim: Some("8.2.2233"),
+            neovim: None,
+        },
+    ),
+    (
+        "chdir",
+        Since {
+            vim: Some("8.1.1291"),
+            neovim: None,
+        },
+    ),
+    (
+        "cosh",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "debugbreak",
+        Since {
+            vim: Some("8.1.0091"),
+            neovim: None,
+        },
+    ),
+    (
+        "deletebufline",
+        Since {
+            vim: Some("8.1.0039"),
+            neovim: None,
+        },
+    ),
+    (
+        "digraph_get",
+        Since {
+            vim: Some("9.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "digraph_getlist",
+        Since {
+            vim: Some("9.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "digraph_set",
+        Since {
+            vim: Some("9.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "digraph_setlist",
+        Since {
+            vim: Some("9.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "echoraw",
+        Since {
+            vim: Some("8.2.0258"),
+            neovim: None,
+        },
+    ),
+    (
+        "environ",
+        Since {
+            vim: Some("8.1.1305"),
+            neovim: None,
+        },
+    ),
+    (
+        "execute",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "exepath",
+        Since {
+            vim: Some("7.4.235"),
+            neovim: None,
+        },
+    ),
+    (
+        "exists_compiled",
+        Since {
+            vim: Some("8.2.3314"),
+            neovim: None,
+        },
+    ),
+    (
+        "exp",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "expandcmd",
+        Since {
+            vim: Some("8.1.1510"),
+            neovim: None,
+        },
+    ),
+    (
+        "extendnew",
+        Since {
+            vim: Some("8.2.2336"),
+            neovim: None,
+        },
+    ),
+    (
+        "flatten",
+        Since {
+            vim: Some("8.2.0935"),
+            neovim: None,
+        },
+    ),
+    (
+        "flattennew",
+        Since {
+            vim: Some("8.2.2449"),
+            neovim: None,
+        },
+    ),
+    (
+        "fmod",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "fullcommand",
+        Since {
+            vim: Some("8.2.2468"),
+            neovim: None,
+        },
+    ),
+    (
+        "funcref",
+        Since {
+            vim: Some("7.4.2137"),
+            neovim: None,
+        },
+    ),
+    (
+        "getbufinfo",
+        Since {
+            vim: Some("7.4.2204"),
+            neovim: None,
+        },
+    ),
+    (
+        "getchangelist",
+        Since {
+            vim: Some("8.0.1514"),
+            neovim: None,
+        },
+    ),
+    (
+        "getcharpos",
+        Since {
+            vim: Some("9.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "getcharsearch",
+        Since {
+            vim: Some("7.4.813"),
+            neovim: None,
+        },
+    ),
+    (
+        "getcharstr",
+        Since {
+            vim: Some("8.2.2957"),
+            neovim: None,
+        },
+    ),
+    (
+        "getcmdcompltype",
+        Since {
+            vim: Some("8.2.4903"),
+            neovim: None,
+        },
+    ),
+    (
+        "getcmdscreenpos",
+        Since {
+            vim: Some("8.2.4903"),
+            neovim: None,
+        },
+    ),
+    (
+        "getcmdwintype",
+        Since {
+            vim: Some("7.4.392"),
+            neovim: None,
+        },
+    ),
+    (
+        "getcompletion",
+        Since {
+            vim: Some("7.4.2011"),
+            neovim: None,
+        },
+    ),
+    (
+        "getcurpos",
+        Since {
+            vim: Some("7.4.313"),
+            neovim: None,
+        },
+    ),
+    (
+        "getcursorcharpos",
+        Since {
+            vim: Some("9.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "getenv",
+        Since {
+            vim: Some("8.1.1305"),
+            neovim: None,
+        },
+    ),
+    (
+        "getimstatus",
+        Since {
+            vim: Some("8.1.2000"),
+            neovim: None,
+        },
+    ),
+    (
+        "getjumplist",
+        Since {
+            vim: Some("8.0.1497"),
+            neovim: None,
+        },
+    ),
+    (
+        "getmarklist",
+        Since {
+            vim: Some("8.2.0861"),
+            neovim: None,
+        },
+    ),
+    (
+        "getmousepos",
+        Since {
+            vim: Some("8.1.2304"),
+            neovim: None,
+        },
+    ),
+    (
+        "getreginfo",
+        Since {
+            vim: Some("8.2.0924"),
+            neovim: None,
+        },
+    ),
+    (
+        "gettabinfo",
+        Since {
+            vim: Some("7.4.2204"),
+            neovim: None,
+        },
+    ),
+    (
+        "gettabvar",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "gettagstack",
+        Since {
+            vim: Some("8.1.0519"),
+            neovim: None,
+        },
+    ),
+    (
+        "gettext",
+        Since {
+            vim: Some("8.2.1544"),
+            neovim: None,
+        },
+    ),
+    (
+        "getwininfo",
+        Since {
+            vim: Some("7.4.2204"),
+            neovim: None,
+        },
+    ),
+    (
+        "getwinpos",
+        Since {
+            vim: Some("8.0.1563"),
+            neovim: None,
+        },
+    ),
+    (
+        "glob2regpat",
+        Since {
+            vim: Some("7.4.668"),
+            neovim: None,
+        },
+    ),
+    (
+        "hlget",
+        Since {
+            vim: Some("8.2.3578"),
+            neovim: None,
+        },
+    ),
+    (
+        "hlset",
+        Since {
+            vim: Some("8.2.3578"),
+            neovim: None,
+        },
+    ),
+    (
+        "interrupt",
+        Since {
+            vim: Some("8.1.2341"),
+            neovim: None,
+        },
+    ),
+    (
+        "invert",
+        Since {
+            vim: Some("7.3.377"),
+            neovim: None,
+        },
+    ),
+    (
+        "isabsolutepath",
+        Since {
+            vim: Some("8.2.4838"),
+            neovim: None,
+        },
+    ),
+    (
+        "isinf",
+        Since {
+            vim: Some("8.1.1111"),
+            neovim: None,
+        },
+    ),
+    (
+        "isnan",
+        Since {
+            vim: Some("7.4.1407"),
+            neovim: None,
+        },
+    ),
+    (
+        "job_getchannel",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "job_info",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "job_setoptions",
+        Since {
+            vim: Some("7.4.1378"),
+            neovim: None,
+        },
+    ),
+    (
+        "job_start",
+        Since {
+            vim: Some("7.4.1274"),
+            neovim: None,
+        },
+    ),
+    (
+        "job_status",
+        Since {
+            vim: Some("7.4.1274"),
+            neovim: None,
+        },
+    ),
+    (
+        "job_stop",
+        Since {
+            vim: Some("7.4.1274"),
+            neovim: None,
+        },
+    ),
+    (
+        "js_decode",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "js_encode",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "json_decode",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "json_encode",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "list2blob",
+        Since {
+            vim: Some("8.2.3438"),
+            neovim: None,
+        },
+    ),
+    (
+        "list2str",
+        Since {
+            vim: Some("8.1.1122"),
+            neovim: None,
+        },
+    ),
+    (
+        "listener_add",
+        Since {
+            vim: Some("8.1.1320"),
+            neovim: None,
+        },
+    ),
+    (
+        "listener_flush",
+        Since {
+            vim: Some("8.1.1332"),
+            neovim: None,
+        },
+    ),
+    (
+        "listener_remove",
+        Since {
+            vim: Some("8.1.1320"),
+            neovim: None,
+        },
+    ),
+    (
+        "log",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "luaeval",
+        Since {
+            vim: Some("7.3.490"),
+            neovim: None,
+        },
+    ),
+    (
+        "maplist",
+        Since {
+            vim: Some("8.2.4825"),
+            neovim: None,
+        },
+    ),
+    (
+        "mapnew",
+        Since {
+            vim: Some("8.2.1969"),
+            neovim: None,
+        },
+    ),
+    (
+        "mapset",
+        Since {
+            vim: Some("8.2.0807"),
+            neovim: None,
+        },
+    ),
+    (
+        "matchaddpos",
+        Since {
+            vim: Some("7.4.330"),
+            neovim: None,
+        },
+    ),
+    (
+        "matchfuzzy",
+        Since {
+            vim: Some("8.2.1665"),
+            neovim: None,
+        },
+    ),
+    (
+        "matchfuzzypos",
+        Since {
+            vim: Some("8.2.1726"),
+            neovim: None,
+        },
+    ),
+    (
+        "matchstrpos",
+        Since {
+            vim: Some("7.4.1685"),
+            neovim: None,
+        },
+    ),
+    (
+        "menu_info",
+        Since {
+            vim: Some("8.2.0385"),
+            neovim: None,
+        },
+    ),
+    (
+        "or",
+        Since {
+            vim: Some("7.3.377"),
+            neovim: None,
+        },
+    ),
+    (
+        "perleval",
+        Since {
+            vim: Some("7.4.1125"),
+            neovim: None,
+        },
+    ),
+    (
+        "popup_list",
+        Since {
+            vim: Some("8.2.0748"),
+            neovim: None,
+        },
+    ),
+    (
+        "prompt_getprompt",
+        Since {
+            vim: Some("8.2.1588"),
+            neovim: None,
+        },
+    ),
+    (
+        "prompt_setcallback",
+        Since {
+            vim: Some("8.2"),
+            neovim: None,
+        },
+    ),
+    (
+        "prompt_setinterrupt",
+        Since {
+            vim: Some("8.1.0069"),
+            neovim: None,
+        },
+    ),
+    (
+        "prompt_setprompt",
+        Since {
+            vim: Some("8.2"),
+            neovim: None,
+        },
+    ),
+    (
+        "prop_add_list",
+        Since {
+            vim: Some("8.2.3356"),
+            neovim: None,
+        },
+    ),
+    (
+        "prop_find",
+        Since {
+            vim: Some("8.2.0110"),
+            neovim: None,
+        },
+    ),
+    (
+        "pum_getpos",
+        Since {
+            vim: Some("8.1.1875"),
+            neovim: None,
+        },
+    ),
+    (
+        "py3eval",
+        Since {
+            vim: Some("7.3.569"),
+            neovim: None,
+        },
+    ),
+    (
+        "pyeval",
+        Since {
+            vim: Some("7.3.569"),
+            neovim: None,
+        },
+    ),
+    (
+        "pyxeval",
+        Since {
+            vim: Some("8.1"),
+            neovim: None,
+        },
+    ),
+    (
+        "rand",
+        Since {
+            vim: Some("8.1.2342"),
+            neovim: None,
+        },
+    ),
+    (
+        "readblob",
+        Since {
+            vim: Some("8.2.2343"),
+            neovim: None,
+        },
+    ),
+    (
+        "readdir",
+        Since {
+            vim: Some("8.1.1120"),
+            neovim: None,
+        },
+    ),
+    (
+        "readdirex",
+        Since {
+            vim: Some("8.2.0875"),
+            neovim: None,
+        },
+    ),
+    (
+        "reduce",
+        Since {
+            vim: Some("8.2.0878"),
+            neovim: None,
+        },
+    ),
+    (
+        "reg_executing",
+        Since {
+            vim: Some("8.1.0020"),
+            neovim: None,
+        },
+    ),
+    (
+        "reg_recording",
+        Since {
+            vim: Some("8.1.0020"),
+            neovim: None,
+        },
+    ),
+    (
+        "reltimefloat",
+        Since {
+            vim: Some("7.4.1285"),
+            neovim: None,
+        },
+    ),
+    (
+        "remote_startserver",
+        Since {
+            vim: Some("8.0.0475"),
+            neovim: None,
+        },
+    ),
+    (
+        "rubyeval",
+        Since {
+            vim: Some("8.1.1056"),
+            neovim: None,
+        },
+    ),
+    (
+        "screenattr",
+        Since {
+            vim: Some("7.3.1164"),
+            neovim: None,
+        },
+    ),
+    (
+        "screenchar",
+        Since {
+            vim: Some("7.3.1164"),
+            neovim: None,
+        },
+    ),
+    (
+        "screenchars",
+        Since {
+            vim: Some("8.1.1071"),
+            neovim: None,
+        },
+    ),
+    (
+        "screencol",
+        Since {
+            vim: Some("7.3.748"),
+            neovim: None,
+        },
+    ),
+    (
+        "screenpos",
+        Since {
+            vim: Some("8.1.1645"),
+            neovim: None,
+        },
+    ),
+    (
+        "screenrow",
+        Since {
+            vim: Some("7.3.748"),
+            neovim: None,
+        },
+    ),
+    (
+        "screenstring",
+        Since {
+            vim: Some("8.1.1071"),
+            neovim: None,
+        },
+    ),
+    (
+        "searchcount",
+        Since {
+            vim: Some("8.2.0877"),
+            neovim: None,
+        },
+    ),
+    (
+        "setbufline",
+        Since {
+            vim: Some("8.0.1039"),
+            neovim: None,
+        },
+    ),
+    (
+        "setcellwidths",
+        Since {
+            vim: Some("8.2.1535"),
+            neovim: None,
+        },
+    ),
+    (
+        "setcharpos",
+        Since {
+            vim: Some("9.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "setcharsearch",
+        Since {
+            vim: Some("7.4.813"),
+            neovim: None,
+        },
+    ),
+    (
+        "setcursorcharpos",
+        Since {
+            vim: Some("9.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "setenv",
+        Since {
+            vim: Some("8.1.1305"),
+            neovim: None,
+        },
+    ),
+    (
+        "setfperm",
+        Since {
+            vim: Some("7.4.1516"),
+            neovim: None,
+        },
+    ),
+    (
+        "settabvar",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "settagstack",
+        Since {
+            vim: Some("8.1.0519"),
+            neovim: None,
+        },
+    ),
+    (
+        "sha256",
+        Since {
+            vim: Some("7.3.816"),
+            neovim: None,
+        },
+    ),
+    (
+        "sinh",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "slice",
+        Since {
+            vim: Some("8.2.2344"),
+            neovim: None,
+        },
+    ),
+    (
+        "srand",
+        Since {
+            vim: Some("8.1.2342"),
+            neovim: None,
+        },
+    ),
+    (
+        "state",
+        Since {
+            vim: Some("8.1.2047"),
+            neovim: None,
+        },
+    ),
+    (
+        "str2list",
+        Since {
+            vim: Some("8.1.1122"),
+            neovim: None,
+        },
+    ),
+    (
+        "strcharlen",
+        Since {
+            vim: Some("8.2.2606"),
+            neovim: None,
+        },
+    ),
+    (
+        "strcharpart",
+        Since {
+            vim: Some("7.4.1730"),
+            neovim: None,
+        },
+    ),
+    (
+        "strchars",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "strdisplaywidth",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "strgetchar",
+        Since {
+            vim: Some("7.4.1730"),
+            neovim: None,
+        },
+    ),
+    (
+        "strptime",
+        Since {
+            vim: Some("8.1.2326"),
+            neovim: None,
+        },
+    ),
+    (
+        "strwidth",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "swapinfo",
+        Since {
+            vim: Some("8.1.0313"),
+            neovim: None,
+        },
+    ),
+    (
+        "swapname",
+        Since {
+            vim: Some("8.1.0401"),
+            neovim: None,
+        },
+    ),
+    (
+        "synconcealed",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "systemlist",
+        Since {
+            vim: Some("7.4.248"),
+            neovim: None,
+        },
+    ),
+    (
+        "tan",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "tanh",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "term_setapi",
+        Since {
+            vim: Some("8.1.2080"),
+            neovim: None,
+        },
+    ),
+    (
+        "terminalprops",
+        Since {
+            vim: Some("8.2.0970"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_alloc_fail",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_autochdir",
+        Since {
+            vim: Some("7.4.2015"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_garbagecollect_now",
+        Since {
+            vim: Some("7.4.1838"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_getvalue",
+        Since {
+            vim: Some("8.1.1334"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_gui_event",
+        Since {
+            vim: Some("9.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_ignore_error",
+        Since {
+            vim: Some("8.0.0392"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_null_blob",
+        Since {
+            vim: Some("8.1.0736"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_null_channel",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_null_dict",
+        Since {
+            vim: Some("7.4.1838"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_null_function",
+        Since {
+            vim: Some("9.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_null_job",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_null_list",
+        Since {
+            vim: Some("7.4.1838"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_null_partial",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_null_string",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_override",
+        Since {
+            vim: Some("8.0.0440"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_refcount",
+        Since {
+            vim: Some("8.1.1044"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_settime",
+        Since {
+            vim: Some("7.4.1903"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_srand_seed",
+        Since {
+            vim: Some("9.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_unknown",
+        Since {
+            vim: Some("8.2.0299"),
+            neovim: None,
+        },
+    ),
+    (
+        "test_void",
+        Since {
+            vim: Some("8.2.0299"),
+            neovim: None,
+        },
+    ),
+    (
+        "timer_info",
+        Since {
+            vim: Some("7.4.2170"),
+            neovim: None,
+        },
+    ),
+    (
+        "timer_pause",
+        Since {
+            vim: Some("7.4.2180"),
+            neovim: None,
+        },
+    ),
+    (
+        "timer_start",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "timer_stop",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "timer_stopall",
+        Since {
+            vim: Some("7.4.2180"),
+            neovim: None,
+        },
+    ),
+    (
+        "trim",
+        Since {
+            vim: Some("8.0.1630"),
+            neovim: None,
+        },
+    ),
+    (
+        "typename",
+        Since {
+            vim: Some("8.2.2339"),
+            neovim: None,
+        },
+    ),
+    (
+        "uniq",
+        Since {
+            vim: Some("7.4.218"),
+            neovim: None,
+        },
+    ),
+    (
+        "virtcol2col",
+        Since {
+            vim: Some("8.2.5034"),
+            neovim: None,
+        },
+    ),
+    (
+        "wildmenumode",
+        Since {
+            vim: Some("7.3.828"),
+            neovim: None,
+        },
+    ),
+    (
+        "win_execute",
+        Since {
+            vim: Some("8.2"),
+            neovim: None,
+        },
+    ),
+    (
+        "win_findbuf",
+        Since {
+            vim: Some("7.4.1558"),
+            neovim: None,
+        },
+    ),
+    (
+        "win_getid",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "win_gettype",
+        Since {
+            vim: Some("8.2.0257"),
+            neovim: None,
+        },
+    ),
+    (
+        "win_gotoid",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "win_id2tabwin",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "win_id2win",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "win_move_separator",
+        Since {
+            vim: Some("8.2.4052"),
+            neovim: None,
+        },
+    ),
+    (
+        "win_move_statusline",
+        Since {
+            vim: Some("8.2.4052"),
+            neovim: None,
+        },
+    ),
+    (
+        "win_screenpos",
+        Since {
+            vim: Some("8.0.1364"),
+            neovim: None,
+        },
+    ),
+    (
+        "win_splitmove",
+        Since {
+            vim: Some("8.1.2020"),
+            neovim: None,
+        },
+    ),
+    (
+        "windowsversion",
+        Since {
+            vim: Some("8.2.0047"),
+            neovim: None,
+        },
+    ),
+    (
+        "winlayout",
+        Since {
+            vim: Some("8.1.0307"),
+            neovim: None,
+        },
+    ),
+    (
+        "wordcount",
+        Since {
+            vim: Some("7.4.1042"),
+            neovim: None,
+        },
+    ),
+    (
+        "xor",
+        Since {
+            vim: Some("7.3.377"),
+            neovim: None,
+        },
+    ),
+];
+
+/// Options by name, sorted for binary search
+pub static OPTION_VERSIONS: &[(&str, Since)] = &[
+    (
+        "autoshelldir",
+        Since {
+            vim: Some("8.2.2675"),
+            neovim: None,
+        },
+    ),
+    (
+        "balloonevalterm",
+        Since {
+            vim: Some("8.0.1309"),
+            neovim: None,
+        },
+    ),
+    (
+        "belloff",
+        Since {
+            vim: Some("7.4.793"),
+            neovim: None,
+        },
+    ),
+    (
+        "breakindent",
+        Since {
+            vim: Some("7.4.338"),
+            neovim: None,
+        },
+    ),
+    (
+        "breakindentopt",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "cdhome",
+        Since {
+            vim: Some("8.2.3780"),
+            neovim: None,
+        },
+    ),
+    (
+        "cinscopedecls",
+        Since {
+            vim: Some("8.2.4702"),
+            neovim: None,
+        },
+    ),
+    (
+        "colorcolumn",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "completepopup",
+        Since {
+            vim: Some("8.1.1882"),
+            neovim: None,
+        },
+    ),
+    (
+        "completeslash",
+        Since {
+            vim: Some("8.1.1769"),
+            neovim: None,
+        },
+    ),
+    (
+        "conceallevel",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "cursorbind",
+        Since {
+            vim: Some("7.3"),
+            neovim: None,
+        },
+    ),
+    (
+        "cursorlineopt",
+        Since {
+            vim: Some("8.1.2019"),
+            neovim: None,
+        },
+    ),
+    (
+        "emoji",
+        Since {
+            vim: Some("7.4.1604"),
+            neovim: None,
+        },
+    ),
+    (
+        "fixendofline",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "guiligatures",
+        Since {
+            vim: Some("8.2.3524"),
+            neovim: None,
+        },
+    ),
+    (
+        "imstyle",
+        Since {
+            vim: Some("8.1"),
+            neovim: None,
+        },
+    ),
+    (
+        "langremap",
+        Since {
+            vim: Some("7.4.2236"),
+            neovim: None,
+        },
+    ),
+    (
+        "luadll",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "makeencoding",
+        Since {
+            vim: Some("8.0.0420"),
+            neovim: None,
+        },
+    ),
+    (
+        "modelineexpr",
+        Since {
+            vim: Some("8.1.1366"),
+            neovim: None,
+        },
+    ),
+    (
+        "mousemoveevent",
+        Since {
+            vim: Some("8.2.4674"),
+            neovim: None,
+        },
+    ),
+    (
+        "mzschemedll",
+        Since {
+            vim: Some("8.0.1182"),
+            neovim: None,
+        },
+    ),
+    (
+        "mzschemegcdll",
+        Since {
+            vim: Some("8.0.1182"),
+            neovim: None,
+        },
+    ),
+    (
+        "packpath",
+        Since {
+            vim: Some("7.4.1384"),
+            neovim: None,
+        },
+    ),
+    (
+        "perldll",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "previewpopup",
+        Since {
+            vim: Some("8.1.1714"),
+            neovim: None,
+        },
+    ),
+    (
+        "pumwidth",
+        Since {
+            vim: Some("8.0.1491"),
+            neovim: None,
+        },
+    ),
+    (
+        "pythondll",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "pythonhome",
+        Since {
+            vim: Some("8.0.1451"),
+            neovim: None,
+        },
+    ),
+    (
+        "pythonthreedll",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "pythonthreehome",
+        Since {
+            vim: Some("8.0.1451"),
+            neovim: None,
+        },
+    ),
+    (
+        "pyxversion",
+        Since {
+            vim: Some("8.0.0251"),
+            neovim: None,
+        },
+    ),
+    (
+        "quickfixtextfunc",
+        Since {
+            vim: Some("8.2.0869"),
+            neovim: None,
+        },
+    ),
+    (
+        "renderoptions",
+        Since {
+            vim: Some("7.4.393"),
+            neovim: None,
+        },
+    ),
+    (
+        "rubydll",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "scrollfocus",
+        Since {
+            vim: Some("8.1.2257"),
+            neovim: None,
+        },
+    ),
+    (
+        "signcolumn",
+        Since {
+            vim: Some("7.4.2201"),
+            neovim: None,
+        },
+    ),
+    (
+        "spelloptions",
+        Since {
+            vim: Some("8.2.0953"),
+            neovim: None,
+        },
+    ),
+    (
+        "tagcase",
+        Since {
+            vim: Some("7.4.941"),
+            neovim: None,
+        },
+    ),
+    (
+        "tagfunc",
+        Since {
+            vim: Some("8.2"),
+            neovim: None,
+        },
+    ),
+    (
+        "tcldll",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "termguicolors",
+        Since {
+            vim: Some("8.0"),
+            neovim: None,
+        },
+    ),
+    (
+        "termwinkey",
+        Since {
+            vim: Some("8.1"),
+            neovim: None,
+        },
+    ),
+    (
+        "termwinscroll",
+        Since {
+            vim: Some("8.0.1848"),
+            neovim: None,
+        },
+    ),
+    (
+        "termwinsize",
+        Since {
+            vim: Some("8.1"),
+            neovim: None,
+        },
+    ),
+    (
+        "termwintype",
+        Since {
+            vim: Some("8.2"),
+            neovim: None,
+        },
+    ),
+    (
+        "thesaurusfunc",
+        Since {
+            vim: Some("8.2.3520"),
+            neovim: None,
+        },
+    ),
+    (
+        "varsofttabstop",
+        Since {
+            vim: Some("8.2"),
+            neovim: None,
+        },
+    ),
+    (
+        "vartabstop",
+        Since {
+            vim: Some("8.2"),
+            neovim: None,
+        },
+    ),
+    (
+        "viminfofile",
+        Since {
+            vim: Some("8.0.0716"),
+            neovim: None,
+        },
+    ),
+    (
+        "wincolor",
+        Since {
+            vim: Some("8.1.1391"),
+            neovim: None,
+        },
+    ),
+    (
+        "winptydll",
+        Since {
+            vim: Some("8.0.0949"),
+            neovim: None,
+        },
+    ),
+    (
+        "xtermcodes",
+        Since {
+            vim: Some("8.2.3864"),
+            neovim: None,
+        },
+    ),
+];
+
+/// Autocmd events by name, sorted for binary search
+pub static EVENT_VERSIONS: &[(&str, Since)] = &[
+    (
+        "CmdUndefined",
+        Since {
+            vim: Some("7.4.414"),
+            neovim: None,
+        },
+    ),
+    (
+        "CmdlineChanged",
+        Since {
+            vim: Some("8.0.1445"),
+            neovim: None,
+        },
+    ),
+    (
+        "CmdlineEnter",
+        Since {
+            vim: Some("8.0.1206"),
+            neovim: None,
+        },
+    ),
+    (
+        "CmdlineLeave",
+        Since {
+            vim: Some("8.0.1206"),
+            neovim: None,
+        },
+    ),
+    (
+        "ColorSchemePre",
+        Since {
+            vim: Some("8.0.1777"),
+            neovim: None,
+        },
+    ),
+    (
+        "CompleteChanged",
+        Since {
+            vim: Some("8.1.1138"),
+            neovim: None,
+        },
+    ),
+    (
+        "CompleteDone",
+        Since {
+            vim: Some("7.4"),
+            neovim: None,
+        },
+    ),
+    (
+        "CompleteDonePre",
+        Since {
+            vim: Some("8.2.0158"),
+            neovim: None,
+        },
+    ),
+    (
+        "DiffUpdated",
+        Since {
+            vim: Some("8.1.0397"),
+            neovim: None,
+        },
+    ),
+    (
+        "DirChanged",
+        Since {
+            vim: Some("8.0.1459"),
+            neovim: None,
+        },
+    ),
+    (
+        "DirChangedPre",
+        Since {
+            vim: Some("8.2.4335"),
+            neovim: None,
+        },
+    ),
+    (
+        "ExitPre",
+        Since {
+            vim: Some("8.0.1595"),
+            neovim: None,
+        },
+    ),
+    (
+        "InsertLeavePre",
+        Since {
+            vim: Some("8.2.1874"),
+            neovim: None,
+        },
+    ),
+    (
+        "ModeChanged",
+        Since {
+            vim: Some("8.2.3430"),
+            neovim: None,
+        },
+    ),
+    (
+        "OptionSet",
+        Since {
+            vim: Some("7.4.786"),
+            neovim: None,
+        },
+    ),
+    (
+        "QuitPre",
+        Since {
+            vim: Some("7.3.544"),
+            neovim: None,
+        },
+    ),
+    (
+        "SafeState",
+        Since {
+            vim: Some("8.1.2044"),
+            neovim: None,
+        },
+    ),
+    (
+        "SafeStateAgain",
+        Since {
+            vim: Some("8.1.2046"),
+            neovim: None,
+        },
+    ),
+    (
+        "SourcePost",
+        Since {
+            vim: Some("8.1.0729"),
+            neovim: None,
+        },
+    ),
+    (
+        "TabClosed",
+        Since {
+            vim: Some("7.4.2077"),
+            neovim: None,
+        },
+    ),
+    (
+        "TabNew",
+        Since {
+            vim: Some("7.4.2075"),
+            neovim: None,
+        },
+    ),
+    (
+        "TerminalOpen",
+        Since {
+            vim: Some("8.0.1596"),
+            neovim: None,
+        },
+    ),
+    (
+        "TerminalWinOpen",
+        Since {
+            vim: Some("8.1.2219"),
+            neovim: None,
+        },
+    ),
+    (
+        "TextChanged",
+        Since {
+            vim: Some("7.3.867"),
+            neovim: None,
+        },
+    ),
+    (
+        "TextChangedI",
+        Since {
+            vim: Some("7.3.867"),
+            neovim: None,
+        },
+    ),
+    (
+        "TextChangedP",
+        Since {
+            vim: Some("8.0.1494"),
+            neovim: None,
+        },
+    ),
+    (
+        "TextYankPost",
+        Since {
+            vim: Some("8.0.1394"),
+            neovim: None,
+        },
+    ),
+    (
+        "VimResume",
+        Since {
+            vim: Some("8.2.2128"),
+            neovim: None,
+        },
+    ),
+    (
+        "VimSuspend",
+        Since {
+            vim: Some("8.2.2128"),
+            neovim: None,
+        },
+    ),
+    (
+        "WinNew",
+        Since {
+            vim: Some("7.4.2075"),
+            neovim: None,
+        },
+    ),
+    (
+        "WinScrolled",
+        Since {
+            vim: Some("8.2.4713"),
+            neovim: None,
+        },
+    ),
+];
@@ -0,0 +1,581 @@
+//! Offline linting: `hjkls check <paths>`
+//!
+//! Runs the diagnostic passes over files on disk without an LSP client, so
+//! that hjkls can be used in pre-commit hooks and CI. The current directory
+//! is the workspace root: its `.hjkls.toml` is loaded and its files are
+//! indexed for cross-file checks.
+
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use tower_lsp_server::ls_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Range};
+
+use crate::backend::{Backend, scan_directory_recursive};
+use crate::diagnostics::{self, RULES};
+
+/// Output format of the findings (`--format=...`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputFormat {
+    /// `file:line:col: severity: message [rule]` lines
+    #[default]
+    Text,
+    /// A JSON array of findings
+    Json,
+    /// A SARIF 2.1.0 log, for code scanning services
+    Sarif,
+    /// GitHub Actions workflow commands, shown as annotations
+    Github,
+}
+
+impl OutputFormat {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "text" => Some(Self::Text),
+            "json" => Some(Self::Json),
+            "sarif" => Some(Self::Sarif),
+            "github" => Some(Self::Github),
+            _ => None,
+        }
+    }
+}
+
+/// The findings in one file
+struct FileReport {
+    path: PathBuf,
+    source: String,
+    diagnostics: Vec<Diagnostic>,
+}
+
+/// Expand the given files and directories into the files to process
+///
+/// Directories are searched for the files the workspace index would pick up.
+/// Also returns whether a path doesn't exist, which is reported.
+pub fn collect_files(backend: &Backend, paths: &[PathBuf]) -> (Vec<PathBuf>, bool) {
+    let filter = backend.index_filter();
+    let mut files = Vec::new();
+    let mut missing = false;
+    for path in paths {
+        if path.is_dir() {
+            let mut found = Vec::new();
+            scan_directory_recursive(path, path, &filter, &mut found);
+            found.sort();
+            files.extend(found);
+        } else if path.exists() {
+            files.push(path.clone());
+        } else {
+            eprintln!("error: no such file or directory: {}", path.display());
+            missing = true;
+        }
+    }
+    (files, missing)
+}
+
+/// Lint the given files and directories, printing the findings
+///
+/// With `stdin_filename`, the content piped on stdin is linted instead, as if
+/// it were that file (which doesn't have to exist).
+///
+/// Returns the process exit code: 1 when a warning or error was found (or a
+/// path couldn't be read), 0 otherwise.
+pub fn run(
+    backend: &Backend,
+    paths: &[PathBuf],
+    stdin_filename: Option<&Path>,
+    format: OutputFormat,
+) -> i32 {
+    let root = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    backend.index_workspace(root);
+
+    let (files, mut failed) = match stdin_filename {
+        Some(path) => (vec![path.to_path_buf()], false),
+        None => collect_files(backend, paths),
+    };
+
+    let mut reports = Vec::new();
+    for path in files {
+        let source = if stdin_filename.is_some() {
+            let mut source = String::new();
+            std::io::stdin().read_to_string(&mut source).map(|_| source)
+        } else {
+            std::fs::read_to_string(&path)
+        };
+        let linted = source.and_then(|source| {
+            let diagnostics = backend.lint_source(&path, &source)?;
+            Ok((source, diagnostics))
+        });
+        match linted {
+            Ok((source, mut diagnostics)) => {
+                diagnostics.sort_by_key(|d| (d.range.start.line, d.range.start.character));
+                reports.push(FileReport {
+                    path,
+                    source,
+                    diagnostics,
+                });
+            }
+            Err(e) => {
+                eprintln!("error: {}: {}", path.display(), e);
+                failed = true;
+            }
+        }
+    }
+
+    match format {
+        OutputFormat::Text => {
+            for report in &reports {
+                for diagnostic in &report.diagnostics {
+                    println!("{}", format_diagnostic(&report.path, diagnostic));
+                }
+            }
+        }
+        OutputFormat::Json => println!("{}", to_json(&reports)),
+        OutputFormat::Sarif => println!("{}", to_sarif(&reports)),
+        OutputFormat::Github => {
+            for report in &reports {
+                for diagnostic in &report.diagnostics {
+                    println!("{}", github_annotation(&report.path, diagnostic));
+                }
+            }
+        }
+    }
+
+    let mut counts = Counts::default();
+    for report in &reports {
+        counts.add_file(&report.diagnostics);
+    }
+    if counts.total() > 0 {
+        eprintln!("{}", counts.summary());
+    }
+    i32::from(failed || counts.errors + counts.warnings > 0)
+}
+
+/// Number of findings by severity, and of files with findings
+#[derive(Debug, Default)]
+struct Counts {
+    errors: usize,
+    warnings: usize,
+    others: usize,
+    files: usize,
+}
+
+impl Counts {
+    fn add_file(&mut self, diagnostics: &[Diagnostic]) {
+        for diagnostic in diagnostics {
+            match diagnostic.severity {
+                Some(DiagnosticSeverity::ERROR) => self.errors += 1,
+                Some(DiagnosticSeverity::WARNING) => self.warnings += 1,
+                _ => self.others += 1,
+            }
+        }
+        self.files += usize::from(!diagnostics.is_empty());
+    }
+
+    fn total(&self) -> usize {
+        self.errors + self.warnings + self.others
+    }
+
+    /// Summary line, e.g. "3 problems (1 error, 2 warnings) in 1 file"
+    fn summary(&self) -> String {
+        format!(
+            "{} ({}, {}) in {}",
+            plural(self.total(), "problem"),
+            plural(self.errors, "error"),
+            plural(self.warnings, "warning"),
+            plural(self.files, "file")
+        )
+    }
+}
+
+/// Count a noun: "1 file", "2 files"
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
+
+/// Name of a diagnostic severity as printed in the output
+pub fn severity_name(severity: Option<DiagnosticSeverity>) -> &'static str {
+    match severity {
+        Some(DiagnosticSeverity::ERROR) => "error",
+        Some(DiagnosticSeverity::WARNING) => "warning",
+        Some(DiagnosticSeverity::INFORMATION) => "info",
+        _ => "hint",
+    }
+}
+
+/// Get the rule ID of a diagnostic (e.g., "hjkls/match_case")
+fn rule_id(diagnostic: &Diagnostic) -> Option<&str> {
+    match diagnostic.code.as_ref()? {
+        NumberOrString::String(code) => Some(code),
+        NumberOrString::Number(_) => None,
+    }
+}
+
+/// Format a finding as `file:line:col: severity: message [rule]`
+///
+/// Lines and columns are 1-based.
+fn format_diagnostic(file: &Path, diagnostic: &Diagnostic) -> String {
+    let start = diagnostic.range.start;
+    let mut line = format!(
+        "{}:{}:{}: {}: {}",
+        file.display(),
+        start.line + 1,
+        start.character + 1,
+        severity_name(diagnostic.severity),
+        diagnostic.message
+    );
+    if let Some(rule) = rule_id(diagnostic) {
+        line.push_str(&format!(" [{rule}]"));
+    }
+    line
+}
+
+/// Escape the message of a GitHub Actions workflow command
+fn escape_github_data(text: &str) -> String {
+    text.replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+/// Escape a property value of a GitHub Actions workflow command
+fn escape_github_property(text: &str) -> String {
+    escape_github_data(text)
+        .replace(':', "%3A")
+        .replace(',', "%2C")
+}
+
+/// Format a finding as a GitHub Actions annotation
+///
+/// e.g., `::warning file=plugin/foo.vim,line=3,col=5,endLine=3,endColumn=13,title=hjkls/normal_bang::...`
+fn github_annotation(file: &Path, diagnostic: &Diagnostic) -> String {
+    let command = match diagnostic.severity {
+        Some(DiagnosticSeverity::ERROR) => "error",
+        Some(DiagnosticSeverity::WARNING) => "warning",
+        _ => "notice",
+    };
+    let range = JsonRange::from(diagnostic.range);
+    let mut properties = format!(
+        "file={},line={},col={},endLine={},endColumn={}",
+        escape_github_property(&artifact_uri(file)),
+        range.start.line,
+        range.start.column,
+        range.end.line,
+        range.end.column
+    );
+    if let Some(rule) = rule_id(diagnostic) {
+        properties.push_str(&format!(",title={}", escape_github_property(rule)));
+    }
+    format!(
+        "::{} {}::{}",
+        command,
+        properties,
+        escape_github_data(&diagnostic.message)
+    )
+}
+
+/// A finding in the JSON output
+#[derive(Serialize)]
+struct JsonDiagnostic<'a> {
+    file: String,
+    rule: Option<&'a str>,
+    severity: &'static str,
+    range: JsonRange,
+    message: &'a str,
+    fix: Option<JsonFix>,
+}
+
+/// A range in the JSON output, with 1-based lines and columns
+#[derive(Serialize)]
+struct JsonRange {
+    start: JsonPosition,
+    end: JsonPosition,
+}
+
+#[derive(Serialize)]
+struct JsonPosition {
+    line: u32,
+    column: u32,
+}
+
+/// The suggested fix of a finding: replace `range` with `replacement`
+#[derive(Serialize)]
+struct JsonFix {
+    title: String,
+    range: JsonRange,
+    replacement: String,
+}
+
+impl From<Range> for JsonRange {
+    fn from(range: Range) -> Self {
+        let position = |p: tower_lsp_server::ls_types::Position| JsonPosition {
+            line: p.line + 1,
+            column: p.character + 1,
+        };
+        Self {
+            start: position(range.start),
+            end: position(range.end),
+        }
+    }
+}
+
+/// Format the findings of all files as a JSON array
+fn to_json(reports: &[FileReport]) -> String {
+    let diagnostics: Vec<JsonDiagnostic> = reports
+        .iter()
+        .flat_map(|report| {
+            report.diagnostics.iter().map(|diagnostic| JsonDiagnostic {
+                file: report.path.display().to_string(),
+                rule: rule_id(diagnostic),
+                severity: severity_name(diagnostic.severity),
+                range: diagnostic.range.into(),
+                message: &diagnostic.message,
+                fix: Backend::quick_fix(&report.source, diagnostic).map(
+                    |(title, range, replacement)| JsonFix {
+                        title,
+                        range: range.into(),
+                        replacement,
+                    },
+                ),
+            })
+        })
+        .collect();
+    serde_json::to_string(&diagnostics).unwrap_or_else(|_| "[]".to_string())
+}
+
+/// SARIF level of a diagnostic severity
+fn sarif_level(severity: Option<DiagnosticSeverity>) -> &'static str {
+    match severity {
+        Some(DiagnosticSeverity::ERROR) => "error",
+        Some(DiagnosticSeverity::WARNING) => "warning",
+        _ => "note",
+    }
+}
+
+/// Path of a file as a SARIF artifact URI, relative to the current directory
+fn artifact_uri(path: &Path) -> String {
+    let relative = std::env::current_dir()
+        .ok()
+        .and_then(|cwd| path.strip_prefix(cwd).ok())
+        .unwrap_or(path);
+    relative.to_string_lossy().replace('\\', "/")
+}
+
+/// Format the findings of all files as a SARIF 2.1.0 log
+///
+/// The tool's rules carry their descriptions and documentation links, and
+/// results refer to them by index.
+fn to_sarif(reports: &[FileReport]) -> String {
+    let rules: Vec<serde_json::Value> = RULES
+        .iter()
+        .map(|rule| {
+            serde_json::json!({
+                "id": format!("hjkls/{}", rule.name),
+                "name": rule.name,
+                "shortDescription": { "text": rule.description },
+                "helpUri": diagnostics::rule_doc_url(rule),
+                "defaultConfiguration": { "level": sarif_level(Some(rule.severity)) },
+                "properties": { "tags": rule.category.into_iter().collect::<Vec<_>>() },
+            })
+        })
+        .collect();
+
+    let results: Vec<serde_json::Value> = reports
+        .iter()
+        .flat_map(|report| {
+            let uri = artifact_uri(&report.path);
+            report.diagnostics.iter().map(move |diagnostic| {
+                let range = JsonRange::from(diagnostic.range);
+                let mut result = serde_json::json!({
+                    "level": sarif_level(diagnostic.severity),
+                    "message": { "text": diagnostic.message },
+                    "locations": [{
+                        "physicalLocation": {
+                            "artifactLocation": { "uri": uri },
+                            "region": {
+                                "startLine": range.start.line,
+                                "startColumn": range.start.column,
+                                "endLine": range.end.line,
+                                "endColumn": range.end.column,
+                            },
+                        },
+                    }],
+                });
+                if let Some(rule) = rule_id(diagnostic) {
+                    result["ruleId"] = rule.into();
+                    if let Some(index) = diagnostics::find_rule(rule)
+                        .and_then(|found| RULES.iter().position(|r| r == found))
+                    {
+                        result["ruleIndex"] = index.into();
+                    }
+                }
+                result
+            })
+        })
+        .collect();
+
+    let log = serde_json::json!({
+        "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+        "version": "2.1.0",
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": env!("CARGO_PKG_NAME"),
+                    "version": env!("CARGO_PKG_VERSION"),
+                    "informationUri": env!("CARGO_PKG_REPOSITORY"),
+                    "rules": rules,
+                },
+            },
+            "results": results,
+        }],
+    });
+    log.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tower_lsp_server::ls_types::Position;
+
+    fn normal_bang() -> Diagnostic {
+        Diagnostic {
+            range: Range {
+                start: Position {
+                    line: 2,
+                    character: 4,
+                },
+                end: Position {
+                    line: 2,
+                    character: 12,
+                },
+            },
+            severity: Some(DiagnosticSeverity::WARNING),
+            code: Some(NumberOrString::String("hjkls/normal_bang".to_string())),
+            message: "Use normal! instead of normal".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_format_diagnostic() {
+        let diagnostic = normal_bang();
+        assert_eq!(
+            format_diagnostic(Path::new("plugin/foo.vim"), &diagnostic),
+            "plugin/foo.vim:3:5: warning: Use normal! instead of normal [hjkls/normal_bang]"
+        );
+
+        let mut counts = Counts::default();
+        let hint = Diagnostic {
+            severity: Some(DiagnosticSeverity::HINT),
+            ..diagnostic.clone()
+        };
+        counts.add_file(&[diagnostic, hint]);
+        assert_eq!((counts.warnings, counts.total()), (1, 2));
+    }
+
+    #[test]
+    fn test_counts_summary() {
+        let mut counts = Counts::default();
+        counts.add_file(&[normal_bang()]);
+        counts.add_file(&[]);
+        assert_eq!(
+            counts.summary(),
+            "1 problem (0 errors, 1 warning) in 1 file"
+        );
+
+        let error = Diagnostic {
+            severity: Some(DiagnosticSeverity::ERROR),
+            ..normal_bang()
+        };
+        counts.add_file(&[error, normal_bang()]);
+        assert_eq!(
+            counts.summary(),
+            "3 problems (1 error, 2 warnings) in 2 files"
+        );
+    }
+
+    #[test]
+    fn test_to_sarif() {
+        let reports = [FileReport {
+            path: PathBuf::from("plugin/foo.vim"),
+            source: "\n\n    normal j\n".to_string(),
+            diagnostics: vec![normal_bang()],
+        }];
+        let log: serde_json::Value = serde_json::from_str(&to_sarif(&reports)).unwrap();
+        let run = &log["runs"][0];
+        let result = &run["results"][0];
+        assert_eq!(result["ruleId"], "hjkls/normal_bang");
+        assert_eq!(result["level"], "warning");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "plugin/foo.vim"
+        );
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["region"]["startColumn"],
+            5
+        );
+
+        let rule = &run["tool"]["driver"]["rules"][result["ruleIndex"].as_u64().unwrap() as usize];
+        assert_eq!(rule["id"], "hjkls/normal_bang");
+        assert_eq!(rule["shortDescription"]["text"], "`normal` without `!`");
+        assert_eq!(rule["defaultConfiguration"]["level"], "warning");
+        assert!(
+            rule["helpUri"]
+                .as_str()
+                .unwrap()
+                .ends_with("LINTING.md#normal_bang")
+        );
+    }
+
+    #[test]
+    fn test_github_annotation() {
+        let diagnostic = Diagnostic {
+            message: "Line 1, col 2: 100%\nNext".to_string(),
+            ..normal_bang()
+        };
+        assert_eq!(
+            github_annotation(Path::new("plugin/a,b.vim"), &diagnostic),
+            "::warning file=plugin/a%2Cb.vim,line=3,col=5,endLine=3,endColumn=13,\
+             title=hjkls/normal_bang::Line 1, col 2: 100%25%0ANext"
+        );
+
+        let hint = Diagnostic {
+            severity: Some(DiagnosticSeverity::HINT),
+            code: None,
+            ..normal_bang()
+        };
+        assert!(github_annotation(Path::new("a.vim"), &hint).starts_with("::notice file=a.vim,"));
+    }
+
+    #[test]
+    fn test_to_json() {
+        let reports = [FileReport {
+            path: PathBuf::from("plugin/foo.vim"),
+            source: "\n\n    normal j\n".to_string(),
+            diagnostics: vec![normal_bang()],
+        }];
+        let json: serde_json::Value = serde_json::from_str(&to_json(&reports)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!([{
+                "file": "plugin/foo.vim",
+                "rule": "hjkls/normal_bang",
+                "severity": "warning",
+                "range": {
+                    "start": { "line": 3, "column": 5 },
+                    "end": { "line": 3, "column": 13 }
+                },
+                "message": "Use normal! instead of normal",
+                "fix": {
+                    "title": "Use `normal!` to ignore user mappings",
+                    "range": {
+                        "start": { "line": 3, "column": 5 },
+                        "end": { "line": 3, "column": 11 }
+                    },
+                    "replacement": "normal!"
+                }
+            }])
+        );
+    }
+}
@@ -3,6 +3,10 @@
 //! This module provides context-aware completion by analyzing cursor position
 //! to determine what kind of completion candidates should be offered.
 
+use tower_lsp_server::ls_types::CompletionItem;
+
+use crate::builtins::{AUTOCMD_EVENTS, MAP_OPTIONS};
+
 /// Completion context based on cursor position
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum CompletionContext {
@@ -10,16 +14,72 @@ pub enum CompletionContext {
     Command,
     /// After autocmd -> event names
     AutocmdEvent,
+    /// After autocmd EVENT -> filetypes or file patterns
+    AutocmdPattern,
+    /// After expr-> -> functions usable as methods
+    Method,
     /// After set/setlocal -> option names
     Option,
-    /// After map command, typing <... -> map options
+    /// After set option= -> option values
+    OptionValue,
+    /// After source/runtime/edit -> file paths
+    FilePath,
+    /// After colorscheme -> color scheme names
+    Colorscheme,
+    /// After map command, typing <... before the LHS -> map options and key notations
     MapOption,
+    /// In a mapping LHS/RHS, typing <... -> key notations (<CR>, <Leader>, ...)
+    KeyNotation,
     /// Inside has('...') -> feature names
     HasFeature,
+    /// After <Plug> in a map command -> <Plug> mapping names
+    PlugMapping,
+    /// After syntax -> sub-command names (keyword, match, region, ...)
+    SyntaxSubcommand,
+    /// After syntax keyword/match/region/cluster -> arguments (contained, contains=, ...)
+    SyntaxArgument,
     /// Expression/function call context -> functions and variables
     Function,
 }
 
+/// Ranking tier of a completion item, encoded into its sortText
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CompletionRank {
+    /// Defined in the current file
+    Local,
+    /// Defined elsewhere in the workspace
+    Workspace,
+    /// Built-in ranked above other built-ins (e.g., `var` in Vim9 script)
+    PreferredBuiltin,
+    /// Built-in
+    Builtin,
+}
+
+/// Build the sortText of an item from its rank and label
+pub fn sort_text(rank: CompletionRank, label: &str) -> String {
+    format!("{}{}", rank as u8, label)
+}
+
+/// Finalize sortText of completion items
+///
+/// Items whose label (or filterText) starts with the typed text come first,
+/// then items are ordered by rank (see `sort_text`; items without one are
+/// built-ins), then by label.
+pub fn apply_sort_text(items: &mut [CompletionItem], typed: &str) {
+    for item in items {
+        let ranked = item
+            .sort_text
+            .take()
+            .unwrap_or_else(|| sort_text(CompletionRank::Builtin, &item.label));
+        let is_prefix = item.label.starts_with(typed)
+            || item
+                .filter_text
+                .as_deref()
+                .is_some_and(|f| f.starts_with(typed));
+        item.sort_text = Some(format!("{}{}", u8::from(!is_prefix), ranked));
+    }
+}
+
 /// Find the start position of a completion token, including scope prefix.
 /// For Vim script, this includes scope prefixes like s:, g:, l:, a:, b:, w:, t:, v:
 /// e.g., for "call s:Priv|" (| is cursor), returns the position of 's'
@@ -56,6 +116,265 @@ pub fn find_completion_token_start(line: &str, cursor_col: usize) -> usize {
     start
 }
 
+/// Parameters of a function when called as a method (`expr->name(...)`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MethodParameters<'a> {
+    /// Required parameters after the first one (the receiver)
+    pub remaining: Vec<&'a str>,
+    /// Whether optional parameters follow
+    pub has_optional: bool,
+}
+
+impl MethodParameters<'_> {
+    /// Snippet inserting the call with placeholders for the remaining parameters
+    ///
+    /// e.g., `filter(${1:expr2})` for "filter({expr1}, {expr2})"
+    pub fn snippet(&self, name: &str) -> String {
+        let placeholders: Vec<String> = self
+            .remaining
+            .iter()
+            .enumerate()
+            .map(|(i, param)| {
+                let param = param.trim_start_matches('{').trim_end_matches('}');
+                format!("${{{}:{}}}", i + 1, param)
+            })
+            .collect();
+        if placeholders.is_empty() && self.has_optional {
+            format!("{}($1)$0", name)
+        } else {
+            format!("{}({})$0", name, placeholders.join(", "))
+        }
+    }
+}
+
+/// Get the parameters of a function signature when called as a method
+///
+/// Returns None when the function takes no parameters. Parameters in `[...]`,
+/// with a default value (`x = 1`) or variadic (`...`) are optional.
+///
+/// e.g., "strchars({string} [, {skipcc}])" -> no remaining, has optional
+pub fn method_parameters(signature: &str) -> Option<MethodParameters<'_>> {
+    let open = signature.find('(')?;
+    let close = signature.rfind(')')?;
+    let inside = signature.get(open + 1..close)?;
+    let (required, optional) = match inside.find('[') {
+        Some(pos) => (&inside[..pos], true),
+        None => (inside, false),
+    };
+    let mut params = required.split(',').map(str::trim).filter(|p| !p.is_empty());
+    let mut has_optional = optional;
+    let mut remaining = Vec::new();
+    let receiver = params.next();
+    if receiver.is_none() && !has_optional {
+        return None;
+    }
+    if receiver.is_some_and(|p| p.starts_with("...")) {
+        has_optional = true;
+    }
+    for param in params {
+        if param.starts_with("...") || param.contains('=') {
+            has_optional = true;
+        } else {
+            remaining.push(param);
+        }
+    }
+    Some(MethodParameters {
+        remaining,
+        has_optional,
+    })
+}
+
+/// Find the object of a member access ending at `token_start`
+/// e.g., for "echo this.na|" returns "this", for "obj.|" returns "obj".
+/// Returns None for string concatenation (`..`) and scoped names (`s:obj.`).
+pub fn find_member_object(line: &str, token_start: usize) -> Option<&str> {
+    let before = line.get(..token_start)?.strip_suffix('.')?;
+    let start = before
+        .rfind(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .map_or(0, |i| i + 1);
+    let object = &before[start..];
+    let prev = before[..start].chars().last();
+    if object.is_empty() || prev.is_some_and(|c| c == '.' || c == ':' || c == '#') {
+        return None;
+    }
+    Some(object)
+}
+
+/// Get the sub-command of a `:syntax` line (e.g., "region" for "syn region Foo")
+pub fn syntax_subcommand(line: &str) -> Option<&str> {
+    let mut words = line.trim_start().trim_start_matches(':').split_whitespace();
+    let cmd = words.next()?;
+    if !is_syntax_command(cmd) {
+        return None;
+    }
+    words.next()
+}
+
+/// Whether `word` is `:syntax` or one of its abbreviations (`:sy` and longer)
+fn is_syntax_command(word: &str) -> bool {
+    word.len() >= 2 && "syntax".starts_with(word)
+}
+
+/// Determine the completion context within a `:syntax` command
+fn get_syntax_context(trimmed: &str) -> Option<CompletionContext> {
+    let trimmed = trimmed.trim_start_matches(':');
+    let cmd_end = trimmed.find(char::is_whitespace)?;
+    if !is_syntax_command(&trimmed[..cmd_end]) {
+        return None;
+    }
+    let rest = &trimmed[cmd_end..];
+    let words: Vec<&str> = rest.split_whitespace().collect();
+
+    // Still typing the sub-command
+    if words.is_empty() || (words.len() == 1 && !rest.ends_with(char::is_whitespace)) {
+        return Some(CompletionContext::SyntaxSubcommand);
+    }
+
+    matches!(words[0], "keyword" | "match" | "region" | "cluster")
+        .then_some(CompletionContext::SyntaxArgument)
+}
+
+/// Find the option whose value is being typed in a `:set` line
+///
+/// `before_cursor` is the line text up to the cursor. Returns the option name
+/// (without `+=`, `-=` or `^=`) and the byte offset where its value starts,
+/// e.g., `("completeopt", 16)` for "set completeopt=menu,".
+pub fn find_option_value(before_cursor: &str) -> Option<(&str, usize)> {
+    // The last argument of the `:set` command
+    let arg_start = before_cursor
+        .rfind(char::is_whitespace)
+        .map_or(0, |pos| pos + 1);
+    let arg = &before_cursor[arg_start..];
+    let eq = arg.find(['=', ':'])?;
+    let name = arg[..eq].trim_end_matches(['+', '-', '^']);
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+    Some((name, arg_start + eq + 1))
+}
+
+/// Split the arguments of an `:autocmd` line
+///
+/// Returns the arguments, the index of the argument under the cursor and the
+/// index of the event argument (0, or 1 when a group name comes first).
+fn autocmd_arguments(trimmed: &str) -> Option<(Vec<&str>, usize, usize)> {
+    let rest = trimmed
+        .strip_prefix("autocmd")
+        .or_else(|| trimmed.strip_prefix("au"))?;
+    let rest = rest.trim_start_matches('!');
+    if !rest.is_empty() && !rest.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let words: Vec<&str> = rest.split_whitespace().collect();
+    let typing = if rest.ends_with(char::is_whitespace) || words.is_empty() {
+        words.len()
+    } else {
+        words.len() - 1
+    };
+    // Skip optional group name (if it doesn't look like an event)
+    let event_idx = match words.first() {
+        Some(word) if typing > 0 && is_autocmd_event(word) => 0,
+        _ => 1,
+    };
+    Some((words, typing, event_idx))
+}
+
+/// Whether `word` is a known event name (or a comma-separated list of them)
+fn is_autocmd_event(word: &str) -> bool {
+    word.split(',').all(|event| {
+        event == "*"
+            || AUTOCMD_EVENTS
+                .iter()
+                .any(|e| e.name.eq_ignore_ascii_case(event))
+    })
+}
+
+/// Get the events of an `:autocmd` line when the cursor is on its pattern
+///
+/// e.g., `["BufRead", "BufNewFile"]` for "autocmd BufRead,BufNewFile *.m"
+pub fn autocmd_pattern_events(before_cursor: &str) -> Option<Vec<&str>> {
+    let (words, typing, event_idx) = autocmd_arguments(before_cursor.trim_start())?;
+    if typing != event_idx + 1 {
+        return None;
+    }
+    Some(words.get(event_idx)?.split(',').collect())
+}
+
+/// Ex commands taking a file argument, with their minimal abbreviation length
+const FILE_COMMANDS: &[(&str, usize)] = &[
+    ("source", 2),
+    ("runtime", 2),
+    ("edit", 1),
+    ("split", 2),
+    ("vsplit", 2),
+    ("new", 3),
+    ("vnew", 3),
+    ("tabedit", 4),
+    ("tabnew", 6),
+    ("view", 3),
+    ("read", 1),
+    ("badd", 3),
+];
+
+/// Whether the given Ex command is a file-taking command (`:runtime` or not)
+///
+/// Returns Some(true) for `:runtime`, whose argument is relative to
+/// 'runtimepath', Some(false) for other file commands and None otherwise.
+pub fn file_command_kind(command: &str) -> Option<bool> {
+    let command = command.trim_start_matches(':').trim_end_matches('!');
+    FILE_COMMANDS
+        .iter()
+        .find(|(name, min)| command.len() >= *min && name.starts_with(command))
+        .map(|(name, _)| *name == "runtime")
+}
+
+/// Get the file path being typed as the argument of a file-taking command
+///
+/// e.g., "autoload/fo" for "source autoload/fo"
+pub fn file_argument(before_cursor: &str) -> Option<&str> {
+    let trimmed = before_cursor.trim_start();
+    let cmd_end = trimmed.find(char::is_whitespace)?;
+    file_command_kind(&trimmed[..cmd_end])?;
+    // `e = 1` is an assignment in Vim9 script
+    if trimmed[cmd_end..].trim_start().starts_with('=') {
+        return None;
+    }
+    let arg_start = before_cursor
+        .rfind(char::is_whitespace)
+        .map_or(0, |pos| pos + 1);
+    Some(&before_cursor[arg_start..])
+}
+
+/// Get the color scheme name being typed as the argument of `:colorscheme`
+///
+/// e.g., "des" for "colorscheme des"
+pub fn colorscheme_argument(before_cursor: &str) -> Option<&str> {
+    let trimmed = before_cursor.trim_start().trim_start_matches(':');
+    let cmd_end = trimmed.find(char::is_whitespace)?;
+    let cmd = &trimmed[..cmd_end];
+    if cmd.len() < 4 || !"colorscheme".starts_with(cmd) {
+        return None;
+    }
+    let arg = trimmed[cmd_end..].trim_start();
+    (!arg.contains(char::is_whitespace)).then_some(arg)
+}
+
+/// Find the start of a `<...>` key notation being typed (text up to the cursor)
+///
+/// e.g., the position of the second `<` for "nnoremap <Leader>w <C-"
+pub fn key_notation_start(before_cursor: &str) -> Option<usize> {
+    let start = before_cursor.rfind('<')?;
+    let typed = &before_cursor[start + 1..];
+    (!typed.contains(['>', ' ', '\t'])).then_some(start)
+}
+
+/// Whether `word` is a map argument like `<silent>` (case-insensitive)
+fn is_map_option(word: &str) -> bool {
+    MAP_OPTIONS
+        .iter()
+        .any(|opt| opt.name.eq_ignore_ascii_case(word))
+}
+
 /// Determine what kind of completion is appropriate based on cursor context
 pub fn get_completion_context(line: &str, col: usize) -> CompletionContext {
     let before_cursor = &line[..col.min(line.len())];
@@ -66,22 +385,38 @@ pub fn get_completion_context(line: &str, col: usize) -> CompletionContext {
         return CompletionContext::Command;
     }
 
-    // Check for specific command patterns
-    // autocmd [group] EVENT -> autocmd event completion
-    if let Some(rest) = trimmed
-        .strip_prefix("autocmd")
-        .or_else(|| trimmed.strip_prefix("au "))
+    // expr->name -> method completion
+    if before_cursor
+        .trim_end_matches(|c: char| c.is_alphanumeric() || c == '_' || c == '#' || c == ':')
+        .ends_with("->")
     {
-        let rest = rest.trim_start();
-        // Skip optional group name (if it doesn't look like an event)
-        // Events are typically CamelCase, groups can be anything
-        let parts: Vec<&str> = rest.split_whitespace().collect();
-        // If we have 0 or 1 parts, we're likely typing the event (or group then event)
-        if parts.len() <= 1 {
+        return CompletionContext::Method;
+    }
+
+    // Check for specific command patterns
+    // autocmd [group] EVENT PATTERN -> autocmd event or pattern completion
+    if let Some((_, typing, event_idx)) = autocmd_arguments(trimmed) {
+        if typing <= event_idx {
             return CompletionContext::AutocmdEvent;
         }
-        // If 2+ parts, check if cursor is still in the "event" area
-        // This is a simplification - we assume event comes after optional group
+        if typing == event_idx + 1 {
+            return CompletionContext::AutocmdPattern;
+        }
+    }
+
+    // syntax SUBCOMMAND [args] -> sub-command or argument completion
+    if let Some(context) = get_syntax_context(trimmed) {
+        return context;
+    }
+
+    // source/runtime/edit FILE -> file path completion
+    if file_argument(before_cursor).is_some() {
+        return CompletionContext::FilePath;
+    }
+
+    // colorscheme NAME -> color scheme completion
+    if colorscheme_argument(before_cursor).is_some() {
+        return CompletionContext::Colorscheme;
     }
 
     // set/setlocal/setglobal OPTION -> option completion
@@ -92,6 +427,9 @@ pub fn get_completion_context(line: &str, col: usize) -> CompletionContext {
         || trimmed.starts_with("setl ")
         || trimmed.starts_with("setg ")
     {
+        if find_option_value(before_cursor).is_some() {
+            return CompletionContext::OptionValue;
+        }
         return CompletionContext::Option;
     }
 
@@ -105,14 +443,27 @@ pub fn get_completion_context(line: &str, col: usize) -> CompletionContext {
         if let Some(rest) = trimmed.strip_prefix(cmd) {
             if rest.starts_with(' ') || rest.is_empty() {
                 let rest = rest.trim_start();
-                // If typing <... it's a map option
-                if rest.ends_with('<')
-                    || rest
+                // If typing <Plug>... it's a <Plug> mapping target
+                if !rest.ends_with(char::is_whitespace)
+                    && rest
                         .split_whitespace()
                         .last()
-                        .is_some_and(|s| s.starts_with('<'))
+                        .is_some_and(|s| s.to_ascii_lowercase().starts_with("<plug>"))
                 {
-                    return CompletionContext::MapOption;
+                    return CompletionContext::PlugMapping;
+                }
+                // If typing <... it's a map option (before the LHS) or a key notation
+                if let Some(key_start) = key_notation_start(before_cursor) {
+                    let rest_start = before_cursor.len() - rest.len();
+                    let preceding = &before_cursor[rest_start..key_start];
+                    let in_options = preceding.is_empty()
+                        || (preceding.ends_with(char::is_whitespace)
+                            && preceding.split_whitespace().all(is_map_option));
+                    return if in_options {
+                        CompletionContext::MapOption
+                    } else {
+                        CompletionContext::KeyNotation
+                    };
                 }
             }
         }
@@ -179,6 +530,132 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_autocmd_pattern_context() {
+        assert_eq!(
+            get_completion_context("autocmd FileType ", 17),
+            CompletionContext::AutocmdPattern
+        );
+        assert_eq!(
+            get_completion_context("au MyGroup BufRead,BufNewFile *.m", 33),
+            CompletionContext::AutocmdPattern
+        );
+        // A group name is followed by the event
+        assert_eq!(
+            get_completion_context("autocmd MyGroup ", 16),
+            CompletionContext::AutocmdEvent
+        );
+        assert_eq!(
+            autocmd_pattern_events("autocmd! BufRead,BufNewFile *.m"),
+            Some(vec!["BufRead", "BufNewFile"])
+        );
+        assert_eq!(
+            autocmd_pattern_events("autocmd FileType vim setlocal"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_file_path_context() {
+        assert_eq!(
+            get_completion_context("source autoload/fo", 18),
+            CompletionContext::FilePath
+        );
+        assert_eq!(
+            get_completion_context("runtime! plugin/", 16),
+            CompletionContext::FilePath
+        );
+        assert_eq!(get_completion_context("e ", 2), CompletionContext::FilePath);
+        // Not a file command
+        assert_eq!(
+            get_completion_context("echo ", 5),
+            CompletionContext::Function
+        );
+
+        assert_eq!(file_argument("so ~/.vim/vi"), Some("~/.vim/vi"));
+        assert_eq!(file_argument("source"), None);
+        assert_eq!(file_command_kind("ru"), Some(true));
+        assert_eq!(file_command_kind("tabe"), Some(false));
+        assert_eq!(file_command_kind("r"), Some(false));
+        assert_eq!(file_command_kind("echo"), None);
+    }
+
+    #[test]
+    fn test_colorscheme_context() {
+        assert_eq!(
+            get_completion_context("colorscheme des", 15),
+            CompletionContext::Colorscheme
+        );
+        assert_eq!(
+            get_completion_context("colo ", 5),
+            CompletionContext::Colorscheme
+        );
+
+        assert_eq!(colorscheme_argument(":colorscheme de"), Some("de"));
+        assert_eq!(colorscheme_argument("colorscheme"), None);
+        assert_eq!(colorscheme_argument("col x"), None);
+        assert_eq!(colorscheme_argument("colorscheme a b"), None);
+    }
+
+    #[test]
+    fn test_apply_sort_text() {
+        let item = |label: &str, rank: Option<CompletionRank>| CompletionItem {
+            label: label.to_string(),
+            sort_text: rank.map(|r| sort_text(r, label)),
+            ..Default::default()
+        };
+        let mut items = vec![
+            item("strlen", None),
+            item("s:str_util", Some(CompletionRank::Local)),
+            item("str#Join", Some(CompletionRank::Workspace)),
+            item("MyStr", Some(CompletionRank::Local)),
+        ];
+        items[1].filter_text = Some("str_util".to_string());
+        apply_sort_text(&mut items, "str");
+        items.sort_by(|a, b| a.sort_text.cmp(&b.sort_text));
+        let labels: Vec<_> = items.iter().map(|i| i.label.as_str()).collect();
+        // Exact-prefix matches first (case-sensitive), then local > workspace > builtin
+        assert_eq!(labels, vec!["s:str_util", "str#Join", "strlen", "MyStr"]);
+    }
+
+    #[test]
+    fn test_method_context() {
+        assert_eq!(
+            get_completion_context("echo mylist->fil", 16),
+            CompletionContext::Method
+        );
+        assert_eq!(
+            get_completion_context("call x->", 8),
+            CompletionContext::Method
+        );
+        assert_eq!(
+            get_completion_context("echo a - b", 10),
+            CompletionContext::Function
+        );
+    }
+
+    #[test]
+    fn test_method_parameters() {
+        let params = method_parameters("filter({expr1}, {expr2})").unwrap();
+        assert_eq!(params.remaining, vec!["{expr2}"]);
+        assert!(!params.has_optional);
+        assert_eq!(params.snippet("filter"), "filter(${1:expr2})$0");
+
+        let params = method_parameters("strchars({string} [, {skipcc}])").unwrap();
+        assert!(params.remaining.is_empty());
+        assert!(params.has_optional);
+        assert_eq!(params.snippet("strchars"), "strchars($1)$0");
+
+        let params = method_parameters("bufnr([{buf} [, {create}]])").unwrap();
+        assert!(params.remaining.is_empty());
+
+        let params = method_parameters("MyFunc(a, b, ...)").unwrap();
+        assert_eq!(params.remaining, vec!["b"]);
+        assert!(params.has_optional);
+
+        assert_eq!(method_parameters("localtime()"), None);
+    }
+
     #[test]
     fn test_set_option_context() {
         // "set " followed by option name
@@ -196,6 +673,38 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_option_value_context() {
+        assert_eq!(
+            get_completion_context("set background=", 15),
+            CompletionContext::OptionValue
+        );
+        assert_eq!(
+            get_completion_context("set cot+=menu,no", 16),
+            CompletionContext::OptionValue
+        );
+        // Next option after a value
+        assert_eq!(
+            get_completion_context("set bg=dark ", 12),
+            CompletionContext::Option
+        );
+    }
+
+    #[test]
+    fn test_find_option_value() {
+        assert_eq!(
+            find_option_value("set background=da"),
+            Some(("background", 15))
+        );
+        assert_eq!(find_option_value("set cot+=menu,"), Some(("cot", 9)));
+        assert_eq!(
+            find_option_value("set wildmode=list:"),
+            Some(("wildmode", 13))
+        );
+        assert_eq!(find_option_value("set number"), None);
+        assert_eq!(find_option_value("set bg=dark "), None);
+    }
+
     #[test]
     fn test_map_option_context() {
         // Map commands with <...> options
@@ -211,6 +720,28 @@ mod tests {
             get_completion_context("inoremap <", 10),
             CompletionContext::MapOption
         );
+        assert_eq!(
+            get_completion_context("nnoremap <silent> <Lea", 22),
+            CompletionContext::MapOption
+        );
+    }
+
+    #[test]
+    fn test_key_notation_context() {
+        assert_eq!(
+            get_completion_context("nnoremap <Leader>w <C-", 22),
+            CompletionContext::KeyNotation
+        );
+        assert_eq!(
+            get_completion_context("inoremap jj <Es", 15),
+            CompletionContext::KeyNotation
+        );
+        assert_eq!(
+            get_completion_context("nnoremap <silent> x<", 20),
+            CompletionContext::KeyNotation
+        );
+        assert_eq!(key_notation_start("nmap <Leader>w <C-"), Some(15));
+        assert_eq!(key_notation_start("nmap <Leader>"), None);
     }
 
     #[test]
@@ -276,4 +807,60 @@ mod tests {
         assert_eq!(get_completion_context("<", 1), CompletionContext::Command);
         assert_eq!(get_completion_context(">", 1), CompletionContext::Command);
     }
+
+    #[test]
+    fn test_find_member_object() {
+        assert_eq!(find_member_object("echo this.na", 10), Some("this"));
+        assert_eq!(find_member_object("  obj.", 6), Some("obj"));
+        assert_eq!(find_member_object("echo 'a'..b", 10), None);
+        assert_eq!(find_member_object("echo s:obj.", 11), None);
+        assert_eq!(find_member_object("echo foo", 5), None);
+    }
+
+    #[test]
+    fn test_syntax_context() {
+        assert_eq!(
+            get_completion_context("syntax ", 7),
+            CompletionContext::SyntaxSubcommand
+        );
+        assert_eq!(
+            get_completion_context("syn reg", 7),
+            CompletionContext::SyntaxSubcommand
+        );
+        assert_eq!(
+            get_completion_context("syn region vimString start=/'/ ", 31),
+            CompletionContext::SyntaxArgument
+        );
+        assert_eq!(
+            get_completion_context("syntax keyword Foo bar cont", 27),
+            CompletionContext::SyntaxArgument
+        );
+        // Typing the command itself
+        assert_eq!(get_completion_context("syn", 3), CompletionContext::Command);
+        // Other sub-commands take group names, not arguments
+        assert_ne!(
+            get_completion_context("syntax clear ", 13),
+            CompletionContext::SyntaxArgument
+        );
+
+        assert_eq!(syntax_subcommand("  syn match Foo /x/"), Some("match"));
+        assert_eq!(syntax_subcommand("set syntax=vim"), None);
+    }
+
+    #[test]
+    fn test_plug_mapping_context() {
+        assert_eq!(
+            get_completion_context("nmap x <Plug>(my", 16),
+            CompletionContext::PlugMapping
+        );
+        assert_eq!(
+            get_completion_context("nmap <silent> x <plug>", 22),
+            CompletionContext::PlugMapping
+        );
+        // Still map options when typing another <...>
+        assert_eq!(
+            get_completion_context("nmap <sil", 9),
+            CompletionContext::MapOption
+        );
+    }
 }
@@ -9,6 +9,7 @@
 //! correctness = true   # default: true
 //! suspicious = true    # default: true
 //! style = false        # default: false
+//! max_diagnostics = 200 # default: 200, 0 for no limit
 //!
 //! [lint.rules.suspicious]
 //! normal_bang = "off"
@@ -16,6 +17,20 @@
 //! [lint.rules.style]
 //! double_dot = "warn"
 //!
+//! [lint.rules]
+//! match_case = "error"  # by rule name alone, with a severity
+//!
+//! [lint.thresholds]
+//! line_length = 120     # default: 120
+//! function_length = 80  # default: 80
+//! nesting_depth = 4     # default: 4
+//!
+//! [overrides."test"]      # [lint] settings for files under test/
+//! correctness = false
+//!
+//! [overrides."vendored".rules]
+//! line_length = "off"
+//!
 //! [format]
 //! indent_width = 2                # default: 2
 //! use_tabs = false                # default: false
@@ -27,29 +42,68 @@
 //! space_after_comma = true        # default: true
 //! space_after_colon = true        # default: true
 //! trim_inside_brackets = true     # default: true
+//!
+//! [rename]
+//! string_references = true        # default: true
+//!
+//! [index]
+//! vimruntime = false              # default: false
+//! runtimepath_from_scripts = true # default: false
+//! opt_packages = true             # default: false
+//! exclude = ["vendor/**"]         # default: ["**/.*", "**/node_modules", "**/target"]
+//! include = ["**/*.vimrc"]        # default: []
+//!
+//! [globals]
+//! functions = ["denops#request({name}, {method}, {params})", "vital#*"] # default: []
 //! ```
 
+use glob::{MatchOptions, Pattern};
 use serde::Deserialize;
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use tower_lsp_server::ls_types::DiagnosticSeverity;
+
+use crate::builtins::EditorMode;
 
 /// The configuration file name
 pub const CONFIG_FILE_NAME: &str = ".hjkls.toml";
 
-/// Rule state: enabled or disabled
+/// Default maximum number of diagnostics published per file
+pub const DEFAULT_MAX_DIAGNOSTICS: usize = 200;
+
+/// Rule state: disabled, or enabled with its default or a given severity
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum RuleState {
     /// Rule is disabled
     Off,
-    /// Rule is enabled (default for most rules)
+    /// Rule is enabled with the severity of its category (default for most rules)
     #[default]
     Warn,
+    /// Rule is enabled and reported as an error
+    Error,
+    /// Rule is enabled and reported as a warning
+    Warning,
+    /// Rule is enabled and reported as information
+    Info,
+    /// Rule is enabled and reported as a hint
+    Hint,
 }
 
 impl RuleState {
     pub fn is_enabled(self) -> bool {
-        matches!(self, RuleState::Warn)
+        self != RuleState::Off
+    }
+
+    /// The severity this state reports the rule with, if it overrides the default
+    pub fn severity(self) -> Option<DiagnosticSeverity> {
+        match self {
+            RuleState::Off | RuleState::Warn => None,
+            RuleState::Error => Some(DiagnosticSeverity::ERROR),
+            RuleState::Warning => Some(DiagnosticSeverity::WARNING),
+            RuleState::Info => Some(DiagnosticSeverity::INFORMATION),
+            RuleState::Hint => Some(DiagnosticSeverity::HINT),
+        }
     }
 }
 
@@ -115,8 +169,61 @@ pub struct LintConfig {
     pub suspicious: Option<bool>,
     /// Enable/disable style category (default: false)
     pub style: Option<bool>,
+    /// Maximum number of diagnostics published per file, 0 for no limit
+    /// (default: 200)
+    pub max_diagnostics: Option<usize>,
     /// Per-rule overrides
     pub rules: RulesConfig,
+    /// Thresholds of the metric rules
+    pub thresholds: ThresholdsConfig,
+}
+
+impl LintConfig {
+    /// Override these settings with the ones set in `other`
+    pub fn merge(&mut self, other: LintConfig) {
+        self.correctness = other.correctness.or(self.correctness);
+        self.suspicious = other.suspicious.or(self.suspicious);
+        self.style = other.style.or(self.style);
+        self.max_diagnostics = other.max_diagnostics.or(self.max_diagnostics);
+        self.rules.correctness.extend(other.rules.correctness);
+        self.rules.suspicious.extend(other.rules.suspicious);
+        self.rules.style.extend(other.rules.style);
+        self.rules.named.extend(other.rules.named);
+        self.thresholds.merge(other.thresholds);
+    }
+}
+
+/// Thresholds of the metric rules (`line_length`, `function_length`, `nesting_depth`)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ThresholdsConfig {
+    /// Maximum number of characters in a line (default: 120)
+    pub line_length: Option<usize>,
+    /// Maximum number of lines in a function body (default: 80)
+    pub function_length: Option<usize>,
+    /// Maximum depth of nested `if`/`for`/`while`/`try` blocks (default: 4)
+    pub nesting_depth: Option<usize>,
+}
+
+impl ThresholdsConfig {
+    pub fn line_length(&self) -> usize {
+        self.line_length.unwrap_or(120)
+    }
+
+    pub fn function_length(&self) -> usize {
+        self.function_length.unwrap_or(80)
+    }
+
+    pub fn nesting_depth(&self) -> usize {
+        self.nesting_depth.unwrap_or(4)
+    }
+
+    /// Override these thresholds with the ones set in `other`
+    fn merge(&mut self, other: ThresholdsConfig) {
+        self.line_length = other.line_length.or(self.line_length);
+        self.function_length = other.function_length.or(self.function_length);
+        self.nesting_depth = other.nesting_depth.or(self.nesting_depth);
+    }
 }
 
 /// Per-category rule overrides
@@ -129,6 +236,193 @@ pub struct RulesConfig {
     pub suspicious: HashMap<String, RuleState>,
     /// Style rule overrides
     pub style: HashMap<String, RuleState>,
+    /// Overrides by rule name alone (e.g., `rules.match_case = "error"`),
+    /// also for uncategorized rules like `syntax_error`
+    #[serde(flatten)]
+    pub named: HashMap<String, RuleState>,
+}
+
+/// Rename configuration section
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct RenameConfig {
+    /// Also rename function names written as text: in strings
+    /// (`function('Name')`, callbacks), mappings and `:command` bodies,
+    /// default: true
+    pub string_references: bool,
+}
+
+impl Default for RenameConfig {
+    fn default() -> Self {
+        Self {
+            string_references: true,
+        }
+    }
+}
+
+/// Paths skipped by workspace indexing unless `[index] exclude` is set
+pub const DEFAULT_INDEX_EXCLUDE: &[&str] = &["**/.*", "**/node_modules", "**/target"];
+
+/// Default pattern excluding hidden files and directories
+const HIDDEN_PATTERN: &str = "**/.*";
+
+/// Names of Vim script startup files, which don't have the `.vim` extension
+/// Reference: :help vimrc, :help gvimrc, :help exrc
+pub const VIMRC_NAMES: &[&str] = &[
+    ".vimrc",
+    "_vimrc",
+    "vimrc",
+    ".gvimrc",
+    "_gvimrc",
+    "gvimrc",
+    ".exrc",
+    "_exrc",
+    ".ideavimrc",
+];
+
+/// Whether a path is a Vim script: a `*.vim` file or a startup file
+pub fn is_vim_script(path: &str) -> bool {
+    let name = path.rsplit(['/', '\\']).next().unwrap_or(path);
+    name.ends_with(".vim") || VIMRC_NAMES.contains(&name)
+}
+
+/// Index configuration section
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct IndexConfig {
+    /// Also index `autoload/` and `plugin/` under $VIMRUNTIME in the
+    /// background, default: false
+    pub vimruntime: bool,
+    /// Add the plugins declared by the scripts of the workspace (`:set rtp+=`,
+    /// vim-plug's `:Plug`, `:packadd`) to the runtime path, default: false
+    pub runtimepath_from_scripts: bool,
+    /// Also resolve files from the optional packages of the workspace
+    /// (`pack/*/opt/*`), not only the `pack/*/start/*` ones, default: false
+    pub opt_packages: bool,
+    /// Glob patterns (relative to the workspace root) of files and
+    /// directories not indexed, default: hidden, `node_modules` and `target`
+    pub exclude: Vec<String>,
+    /// Glob patterns of files indexed in addition to `*.vim` and startup
+    /// files like `.vimrc`, default: none
+    pub include: Vec<String>,
+}
+
+impl Default for IndexConfig {
+    fn default() -> Self {
+        Self {
+            vimruntime: false,
+            runtimepath_from_scripts: false,
+            opt_packages: false,
+            exclude: DEFAULT_INDEX_EXCLUDE
+                .iter()
+                .map(|p| p.to_string())
+                .collect(),
+            include: Vec::new(),
+        }
+    }
+}
+
+/// Compiled `[index]` patterns deciding which files are indexed
+///
+/// Paths are matched relative to the scanned root, with `/` separators.
+/// `*` doesn't match across directories; `**` does.
+#[derive(Debug, Clone)]
+pub struct IndexFilter {
+    exclude: Vec<Pattern>,
+    include: Vec<Pattern>,
+}
+
+impl IndexFilter {
+    /// Compile the patterns of an index configuration, skipping invalid ones
+    pub fn new(config: &IndexConfig) -> Self {
+        let compile = |patterns: &[String]| {
+            patterns
+                .iter()
+                .filter_map(|p| Pattern::new(p).ok())
+                .collect()
+        };
+        Self {
+            exclude: compile(&config.exclude),
+            include: compile(&config.include),
+        }
+    }
+
+    fn matches(patterns: &[Pattern], relative: &str) -> bool {
+        let options = MatchOptions {
+            case_sensitive: true,
+            require_literal_separator: true,
+            require_literal_leading_dot: false,
+        };
+        patterns.iter().any(|p| p.matches_with(relative, options))
+    }
+
+    /// Whether a directory is skipped, so that it isn't scanned at all
+    pub fn is_excluded_dir(&self, relative: &str) -> bool {
+        // `dir/**` also excludes `dir` itself
+        Self::matches(&self.exclude, relative)
+            || self.exclude.iter().any(|p| {
+                p.as_str()
+                    .strip_suffix("/**")
+                    .and_then(|prefix| Pattern::new(prefix).ok())
+                    .is_some_and(|prefix| Self::matches(&[prefix], relative))
+            })
+    }
+
+    /// Whether a file is indexed: a Vim script or an included one, not excluded
+    ///
+    /// Startup files like `.vimrc` are hidden, but the default pattern
+    /// excluding hidden files doesn't apply to them.
+    pub fn is_indexed_file(&self, relative: &str) -> bool {
+        let vim_script = is_vim_script(relative);
+        let exclude: Vec<Pattern> = self
+            .exclude
+            .iter()
+            .filter(|p| !(vim_script && p.as_str() == HIDDEN_PATTERN))
+            .cloned()
+            .collect();
+        (vim_script || Self::matches(&self.include, relative)) && !Self::matches(&exclude, relative)
+    }
+}
+
+impl Default for IndexFilter {
+    fn default() -> Self {
+        Self::new(&IndexConfig::default())
+    }
+}
+
+/// Globals provided outside the workspace, e.g., by frameworks like denops.vim
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct GlobalsConfig {
+    /// Functions treated as defined: signatures like the builtin ones
+    /// (`plug#begin([{dir}])`), names without arity checks, or `ns#*` for
+    /// every function of an autoload namespace, default: none
+    pub functions: Vec<String>,
+}
+
+impl GlobalsConfig {
+    /// Whether a function is declared
+    pub fn declares_function(&self, name: &str) -> bool {
+        self.functions
+            .iter()
+            .any(|function| match function.trim().strip_suffix('*') {
+                Some(prefix) => name.starts_with(prefix),
+                None => declared_name(function) == name,
+            })
+    }
+
+    /// Get the signature a function is declared with, if any
+    pub fn function_signature(&self, name: &str) -> Option<&str> {
+        self.functions
+            .iter()
+            .map(|function| function.trim())
+            .find(|function| function.contains('(') && declared_name(function) == name)
+    }
+}
+
+/// The name of a declared function, without its parameters
+fn declared_name(function: &str) -> &str {
+    function.split('(').next().unwrap_or(function).trim()
 }
 
 /// Root configuration structure
@@ -139,6 +433,61 @@ pub struct Config {
     pub lint: LintConfig,
     /// Format configuration
     pub format: FormatConfig,
+    /// Rename configuration
+    pub rename: RenameConfig,
+    /// Index configuration
+    pub index: IndexConfig,
+    /// Globals provided outside the workspace
+    pub globals: GlobalsConfig,
+    /// Lint settings overriding `[lint]` for files under a directory,
+    /// keyed by the path relative to the workspace root
+    pub overrides: HashMap<String, LintConfig>,
+}
+
+/// Server settings sent by the client in `initializationOptions`
+///
+/// ```json
+/// {
+///   "editorMode": "neovim",
+///   "vimruntime": "/usr/share/nvim/runtime",
+///   "runtimepath": ["/home/user/.vim", "/home/user/.vim/pack/plugins/start/foo"],
+///   "log": "/tmp/hjkls.log",
+///   "lint": { "style": true, "rules": { "suspicious": { "normal_bang": "off" } } }
+/// }
+/// ```
+///
+/// Settings given here override the command-line flags, and `lint`
+/// overrides the `[lint]` section of `.hjkls.toml`. Each configuration
+/// replaces the previous one: a setting left out reverts to its flag.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default, rename_all = "camelCase")]
+pub struct Settings {
+    /// Editor mode: "vim", "neovim" or "both"
+    pub editor_mode: Option<EditorMode>,
+    /// Vim runtime path for autoload resolution
+    pub vimruntime: Option<PathBuf>,
+    /// Runtime directories searched before $VIMRUNTIME, like 'runtimepath'
+    pub runtimepath: Option<Vec<PathBuf>>,
+    /// Debug log file path
+    pub log: Option<String>,
+    /// Lint configuration, in the same shape as `[lint]`
+    pub lint: Option<LintConfig>,
+}
+
+impl Settings {
+    /// Parse settings from `initializationOptions` or `workspace/configuration`
+    ///
+    /// Clients may nest the settings under an `hjkls` section, and send
+    /// `null` when there are none.
+    pub fn from_value(mut value: serde_json::Value) -> Result<Self, serde_json::Error> {
+        if let Some(section) = value.get_mut("hjkls") {
+            value = section.take();
+        }
+        if value.is_null() {
+            return Ok(Self::default());
+        }
+        serde_json::from_value(value)
+    }
 }
 
 impl Config {
@@ -175,19 +524,57 @@ impl Config {
         None
     }
 
+    /// Get the configuration applying to a file, given its path relative to
+    /// the workspace root
+    ///
+    /// The override with the longest path matching the file is merged over
+    /// `[lint]`; other overrides don't apply.
+    pub fn for_path(&self, relative: &str) -> Config {
+        let mut config = self.clone();
+        let matching = self
+            .overrides
+            .iter()
+            .map(|(prefix, lint)| (prefix.trim_matches('/'), lint))
+            .filter(|(prefix, _)| {
+                prefix.is_empty()
+                    || relative
+                        .strip_prefix(prefix)
+                        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+            })
+            .max_by_key(|(prefix, _)| prefix.len());
+        if let Some((_, lint)) = matching {
+            config.lint.merge(lint.clone());
+        }
+        config
+    }
+
+    /// Get the per-rule override of a rule
+    ///
+    /// An override in the rule's category table takes priority over one by
+    /// rule name alone.
+    pub fn rule_state(&self, category: &str, rule: &str) -> Option<RuleState> {
+        let rules = &self.lint.rules;
+        let category_override = match category {
+            "correctness" => rules.correctness.get(rule),
+            "suspicious" => rules.suspicious.get(rule),
+            "style" => rules.style.get(rule),
+            _ => None,
+        };
+        category_override.or_else(|| rules.named.get(rule)).copied()
+    }
+
+    /// Get the severity configured for a rule, if it overrides the default
+    pub fn rule_severity(&self, category: &str, rule: &str) -> Option<DiagnosticSeverity> {
+        self.rule_state(category, rule)
+            .and_then(RuleState::severity)
+    }
+
     /// Check if a rule is enabled
     ///
     /// Priority: per-rule override > category setting > default
     pub fn is_rule_enabled(&self, category: &str, rule: &str) -> bool {
         // Check per-rule override first
-        let rule_override = match category {
-            "correctness" => self.lint.rules.correctness.get(rule),
-            "suspicious" => self.lint.rules.suspicious.get(rule),
-            "style" => self.lint.rules.style.get(rule),
-            _ => None,
-        };
-
-        if let Some(state) = rule_override {
+        if let Some(state) = self.rule_state(category, rule) {
             return state.is_enabled();
         }
 
@@ -199,6 +586,14 @@ impl Config {
             _ => true,
         }
     }
+
+    /// Maximum number of diagnostics published per file, `None` for no limit
+    pub fn max_diagnostics(&self) -> Option<usize> {
+        match self.lint.max_diagnostics.unwrap_or(DEFAULT_MAX_DIAGNOSTICS) {
+            0 => None,
+            max => Some(max),
+        }
+    }
 }
 
 /// Configuration error types
@@ -232,6 +627,182 @@ mod tests {
         assert!(config.is_rule_enabled("correctness", "undefined_function"));
         assert!(config.is_rule_enabled("suspicious", "normal_bang"));
         assert!(!config.is_rule_enabled("style", "double_dot")); // style default: false
+        assert!(config.rename.string_references);
+        assert_eq!(config.max_diagnostics(), Some(DEFAULT_MAX_DIAGNOSTICS));
+    }
+
+    #[test]
+    fn test_parse_max_diagnostics() {
+        let config = Config::parse("[lint]\nmax_diagnostics = 50\n").unwrap();
+        assert_eq!(config.max_diagnostics(), Some(50));
+
+        let config = Config::parse("[lint]\nmax_diagnostics = 0\n").unwrap();
+        assert_eq!(config.max_diagnostics(), None);
+    }
+
+    #[test]
+    fn test_parse_settings() {
+        let settings = Settings::from_value(serde_json::json!({
+            "editorMode": "neovim",
+            "vimruntime": "/usr/share/nvim/runtime",
+            "runtimepath": ["/home/user/.vim", "/home/user/.vim/after"],
+            "lint": {
+                "style": true,
+                "rules": { "suspicious": { "normal_bang": "off" } }
+            }
+        }))
+        .unwrap();
+        assert_eq!(settings.editor_mode, Some(EditorMode::NeovimOnly));
+        assert_eq!(
+            settings.vimruntime.as_deref(),
+            Some(Path::new("/usr/share/nvim/runtime"))
+        );
+        assert_eq!(
+            settings.runtimepath,
+            Some(vec![
+                PathBuf::from("/home/user/.vim"),
+                PathBuf::from("/home/user/.vim/after")
+            ])
+        );
+        assert!(settings.log.is_none());
+
+        let mut config = Config::parse("[lint]\nstyle = false\nsuspicious = false\n").unwrap();
+        config.lint.merge(settings.lint.unwrap());
+        assert!(config.is_rule_enabled("style", "double_dot"));
+        // Settings not given by the client are kept
+        assert!(!config.is_rule_enabled("suspicious", "match_case"));
+        assert!(!config.is_rule_enabled("suspicious", "normal_bang"));
+
+        assert!(Settings::from_value(serde_json::json!({ "editorMode": "emacs" })).is_err());
+
+        // Settings nested under a section, and no settings at all
+        let settings =
+            Settings::from_value(serde_json::json!({ "hjkls": { "editorMode": "vim" } })).unwrap();
+        assert_eq!(settings.editor_mode, Some(EditorMode::VimOnly));
+        let settings = Settings::from_value(serde_json::Value::Null).unwrap();
+        assert!(settings.editor_mode.is_none() && settings.lint.is_none());
+    }
+
+    #[test]
+    fn test_path_overrides() {
+        let config = Config::parse(
+            r#"
+[lint]
+style = true
+
+[overrides."test"]
+correctness = false
+
+[overrides."test/unit/".rules]
+undefined_function = "error"
+
+[overrides.vendored]
+style = false
+"#,
+        )
+        .unwrap();
+
+        let test = config.for_path("test/foo.vim");
+        assert!(!test.is_rule_enabled("correctness", "undefined_function"));
+        assert!(test.is_rule_enabled("style", "double_dot"));
+
+        // The longest matching path wins, over the base `[lint]` only
+        let unit = config.for_path("test/unit/foo.vim");
+        assert_eq!(
+            unit.rule_severity("correctness", "undefined_function"),
+            Some(DiagnosticSeverity::ERROR)
+        );
+        assert!(unit.is_rule_enabled("correctness", "arity_mismatch"));
+
+        assert!(
+            !config
+                .for_path("vendored/a/b.vim")
+                .is_rule_enabled("style", "double_dot")
+        );
+
+        // Paths match whole directory names
+        let tests = config.for_path("tests/foo.vim");
+        assert!(tests.is_rule_enabled("correctness", "undefined_function"));
+        assert!(tests.is_rule_enabled("style", "double_dot"));
+    }
+
+    #[test]
+    fn test_parse_rename_config() {
+        let config = Config::parse(
+            r#"
+            [rename]
+            string_references = false
+            "#,
+        )
+        .unwrap();
+
+        assert!(!config.rename.string_references);
+    }
+
+    #[test]
+    fn test_parse_index_config() {
+        assert!(!Config::default().index.vimruntime);
+        assert!(!Config::default().index.runtimepath_from_scripts);
+        assert!(!Config::default().index.opt_packages);
+
+        let config = Config::parse(
+            r#"
+            [index]
+            vimruntime = true
+            runtimepath_from_scripts = true
+            opt_packages = true
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.index.vimruntime);
+        assert!(config.index.runtimepath_from_scripts);
+        assert!(config.index.opt_packages);
+    }
+
+    #[test]
+    fn test_parse_thresholds() {
+        let config = Config::parse("[lint.thresholds]\nline_length = 80\n").unwrap();
+        assert_eq!(config.lint.thresholds.line_length(), 80);
+        assert_eq!(config.lint.thresholds.function_length(), 80);
+        assert_eq!(config.lint.thresholds.nesting_depth(), 4);
+    }
+
+    #[test]
+    fn test_index_filter() {
+        let filter = IndexFilter::default();
+        assert!(filter.is_indexed_file("autoload/foo.vim"));
+        assert!(!filter.is_indexed_file("autoload/foo.lua"));
+        assert!(filter.is_indexed_file(".vimrc"));
+        assert!(filter.is_indexed_file("vim/_vimrc"));
+        assert!(filter.is_indexed_file("home/.ideavimrc"));
+        assert!(!filter.is_indexed_file(".bashrc"));
+        assert!(!filter.is_indexed_file("myvimrc"));
+        assert!(filter.is_excluded_dir(".git"));
+        assert!(filter.is_excluded_dir("sub/node_modules"));
+        assert!(!filter.is_excluded_dir("autoload"));
+
+        let config = Config::parse(
+            r#"
+            [index]
+            exclude = ["vendor/**", "**/pack/*/opt/**"]
+            include = ["**/*.vimrc"]
+            "#,
+        )
+        .unwrap();
+        let filter = IndexFilter::new(&config.index);
+        assert!(filter.is_excluded_dir("vendor"));
+        assert!(filter.is_excluded_dir("pack/plugins/opt"));
+        assert!(!filter.is_excluded_dir("pack/plugins/start"));
+        assert!(!filter.is_indexed_file("vendor/foo.vim"));
+        assert!(filter.is_indexed_file("dotfiles/work.vimrc"));
+        // Setting `exclude` replaces the default patterns
+        assert!(!filter.is_excluded_dir(".vim"));
+        // Other patterns still exclude startup files
+        let config = Config::parse("[index]\nexclude = [\"**/.*\", \"old/**\"]\n").unwrap();
+        let filter = IndexFilter::new(&config.index);
+        assert!(filter.is_indexed_file(".vimrc"));
+        assert!(!filter.is_indexed_file("old/.vimrc"));
     }
 
     #[test]
@@ -293,4 +864,61 @@ mod tests {
         assert!(!config.is_rule_enabled("suspicious", "normal_bang"));
         assert!(config.is_rule_enabled("suspicious", "match_case"));
     }
+
+    #[test]
+    fn test_parse_rule_severities() {
+        let config = Config::parse(
+            r#"
+            [lint]
+            style = false
+
+            [lint.rules]
+            match_case = "error"
+            double_dot = "hint"
+            syntax_error = "warning"
+            normal_bang = "info"
+
+            [lint.rules.suspicious]
+            normal_bang = "off"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.rule_severity("suspicious", "match_case"),
+            Some(DiagnosticSeverity::ERROR)
+        );
+        // A severity enables a rule of a disabled category
+        assert!(config.is_rule_enabled("style", "double_dot"));
+        // Uncategorized rules are configured by name
+        assert_eq!(
+            config.rule_severity("", "syntax_error"),
+            Some(DiagnosticSeverity::WARNING)
+        );
+        // The category table takes priority over the rule name alone
+        assert!(!config.is_rule_enabled("suspicious", "normal_bang"));
+        assert_eq!(config.rule_severity("suspicious", "abort"), None);
+    }
+
+    #[test]
+    fn test_parse_globals() {
+        let config = Config::parse(
+            r#"
+            [globals]
+            functions = ["denops#request({name}, {method}, {params})", "MyHelper", "vital#*"]
+            "#,
+        )
+        .unwrap();
+
+        let globals = &config.globals;
+        assert!(globals.declares_function("denops#request"));
+        assert!(globals.declares_function("MyHelper"));
+        assert!(globals.declares_function("vital#myplugin#of"));
+        assert!(!globals.declares_function("denops#notify"));
+        assert_eq!(
+            globals.function_signature("denops#request"),
+            Some("denops#request({name}, {method}, {params})")
+        );
+        assert_eq!(globals.function_signature("MyHelper"), None);
+    }
 }
@@ -1,7 +1,9 @@
 //! Salsa database for incremental computation
 
-use crate::symbols::{Symbol, VimScope};
+use crate::symbols::{PlugMapping, Symbol, UserCommand, VimScope};
 use salsa::Database;
+use tower_lsp_server::ls_types::Diagnostic;
+use tree_sitter::Tree;
 
 /// The salsa database for hjkls
 #[salsa::db]
@@ -14,29 +16,126 @@ pub struct HjklsDatabase {
 impl Database for HjklsDatabase {}
 
 /// Source file input for salsa
+///
+/// Only open documents keep their text in the database; indexed workspace
+/// files are read from disk on demand by [`file_content`].
 #[salsa::input]
 pub struct SourceFile {
-    /// File URI
+    /// File URI (open documents) or path (indexed files)
     pub uri: String,
-    /// File content
-    pub content: String,
+    /// Text of an open document, `None` for indexed files
+    pub text: Option<String>,
+    /// Tree of an open document, incrementally parsed on each change
+    ///
+    /// `None` when the text wasn't parsed yet: [`syntax_tree`] parses it.
+    pub tree: Option<SyntaxTree>,
+    /// Bumped when an indexed file changes on disk
+    pub revision: u64,
 }
 
-/// Parse symbols from a source file (memoized by salsa)
-#[salsa::tracked]
-pub fn parse_symbols(db: &dyn Database, file: SourceFile) -> Vec<Symbol> {
-    let content = file.content(db);
+/// Get the content of a source file (memoized by salsa)
+///
+/// Only the most recently used contents are kept in memory; evicted ones
+/// are re-read from disk when needed again. Results derived from a file
+/// (symbols, commands...) stay cached independently of its content, until
+/// the revision of the file is bumped.
+#[salsa::tracked(lru = 128)]
+pub fn file_content(db: &dyn Database, file: SourceFile) -> String {
+    match file.text(db) {
+        Some(text) => text,
+        None => {
+            // Read again once the file changes
+            let _revision = file.revision(db);
+            std::fs::read_to_string(file.uri(db)).unwrap_or_default()
+        }
+    }
+}
+
+/// A tree-sitter syntax tree stored in the salsa database
+///
+/// Trees are never considered equal: queries depending on a reparsed file
+/// are always recomputed.
+#[derive(Clone)]
+pub struct SyntaxTree(Tree);
+
+impl From<Tree> for SyntaxTree {
+    fn from(tree: Tree) -> Self {
+        Self(tree)
+    }
+}
+
+impl PartialEq for SyntaxTree {
+    fn eq(&self, _: &Self) -> bool {
+        false
+    }
+}
+
+impl std::ops::Deref for SyntaxTree {
+    type Target = Tree;
+
+    fn deref(&self) -> &Tree {
+        &self.0
+    }
+}
+
+/// Parse a source file with tree-sitter (memoized by salsa)
+///
+/// Open documents reuse their incrementally parsed tree. Only the most
+/// recently used trees are kept; lint passes and symbol extraction share them.
+#[salsa::tracked(returns(ref), no_eq, lru = 16)]
+pub fn syntax_tree(db: &dyn Database, file: SourceFile) -> Option<SyntaxTree> {
+    if let Some(tree) = file.tree(db) {
+        return Some(tree);
+    }
+    let content = file_content(db, file);
 
-    // Parse with tree-sitter
     let mut parser = tree_sitter::Parser::new();
     parser
         .set_language(&tree_sitter_vim::language())
         .expect("Error loading vim grammar");
+    parser
+        .parse(crate::heredoc::mask_heredocs(&content).as_ref(), None)
+        .map(SyntaxTree)
+}
 
-    if let Some(tree) = parser.parse(&content, None) {
-        crate::symbols::extract_symbols(&tree, &content)
-    } else {
-        Vec::new()
+/// Run the lint passes only looking at a source file (memoized by salsa)
+#[salsa::tracked]
+pub fn file_diagnostics(db: &dyn Database, file: SourceFile) -> Vec<Diagnostic> {
+    let Some(tree) = syntax_tree(db, file) else {
+        return Vec::new();
+    };
+    let content = file_content(db, file);
+    let uri = file.uri(db).parse().ok();
+    crate::diagnostics::FILE_PASSES
+        .iter()
+        .flat_map(|pass| (pass.run)(tree, &content, uri.as_ref()))
+        .collect()
+}
+
+/// Parse symbols from a source file (memoized by salsa)
+#[salsa::tracked]
+pub fn parse_symbols(db: &dyn Database, file: SourceFile) -> Vec<Symbol> {
+    match syntax_tree(db, file) {
+        Some(tree) => crate::symbols::extract_symbols(tree, &file_content(db, file)),
+        None => Vec::new(),
+    }
+}
+
+/// Parse user-defined commands from a source file (memoized by salsa)
+#[salsa::tracked]
+pub fn parse_user_commands(db: &dyn Database, file: SourceFile) -> Vec<UserCommand> {
+    match syntax_tree(db, file) {
+        Some(tree) => crate::symbols::extract_user_commands(tree, &file_content(db, file)),
+        None => Vec::new(),
+    }
+}
+
+/// Parse `<Plug>` mapping definitions from a source file (memoized by salsa)
+#[salsa::tracked]
+pub fn parse_plug_mappings(db: &dyn Database, file: SourceFile) -> Vec<PlugMapping> {
+    match syntax_tree(db, file) {
+        Some(tree) => crate::symbols::extract_plug_mappings(tree, &file_content(db, file)),
+        None => Vec::new(),
     }
 }
 
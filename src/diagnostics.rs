@@ -9,49 +9,389 @@
 //! - `hjkls:ignore <rules>` - ignore to end of file
 //! - `hjkls:ignore-next-line <rules>` - ignore next line only
 
+pub mod commands;
+pub mod dedup;
+pub mod deprecated;
+pub mod dialect;
 pub mod ignore;
+pub mod metrics;
+pub mod options;
 pub mod style;
 pub mod suspicious;
+pub mod variables;
+pub mod versions;
 
-use tower_lsp_server::ls_types::Diagnostic;
+use std::str::FromStr;
 
+use tower_lsp_server::ls_types::{
+    CodeDescription, Diagnostic, DiagnosticSeverity, NumberOrString, Uri,
+};
+use tree_sitter::Tree;
+
+use crate::builtins::EditorMode;
 use crate::config::Config;
+use crate::help;
 
 // Re-export commonly used functions
+pub use commands::collect_command_warnings;
+pub use dedup::{cap_diagnostics, dedup_diagnostics};
+pub use deprecated::collect_deprecation_warnings;
+pub use dialect::Dialect;
 pub use ignore::{filter_diagnostics, parse_ignore_directives};
+pub use metrics::collect_metric_hints;
+pub use options::collect_option_warnings;
 pub use style::collect_style_hints;
 pub use suspicious::collect_suspicious_warnings;
+pub use variables::collect_variable_warnings;
+pub use versions::collect_version_warnings;
+
+/// A lint rule and its documentation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rule {
+    /// Rule name, the diagnostic code without the "hjkls/" prefix
+    pub name: &'static str,
+    /// Category, or None for rules only configurable by name
+    pub category: Option<&'static str>,
+    /// Severity reported unless configured otherwise
+    pub severity: DiagnosticSeverity,
+    /// One-line description
+    pub description: &'static str,
+}
+
+/// All rules reported by hjkls, by category
+pub const RULES: &[Rule] = &[
+    // Correctness rules
+    Rule {
+        name: "autoload_missing",
+        category: Some("correctness"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "Autoload file not found",
+    },
+    Rule {
+        name: "arity_mismatch",
+        category: Some("correctness"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "Wrong number of function arguments",
+    },
+    Rule {
+        name: "scope_violation",
+        category: Some("correctness"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "Invalid scope usage (l:, a:)",
+    },
+    Rule {
+        name: "undefined_function",
+        category: Some("correctness"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "Undefined function call",
+    },
+    Rule {
+        name: "import_unresolved",
+        category: Some("correctness"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "Vim9 import target not found",
+    },
+    Rule {
+        name: "import_not_exported",
+        category: Some("correctness"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "Imported name is not exported",
+    },
+    Rule {
+        name: "type_mismatch",
+        category: Some("correctness"),
+        severity: DiagnosticSeverity::ERROR,
+        description: "Vim9 type mismatch (E1012)",
+    },
+    Rule {
+        name: "bang_not_allowed",
+        category: Some("correctness"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "`!` after a command that doesn't accept it",
+    },
+    Rule {
+        name: "unknown_option",
+        category: Some("correctness"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "`:set` of an option that doesn't exist",
+    },
+    Rule {
+        name: "invalid_option_value",
+        category: Some("correctness"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "Option value of the wrong type or not allowed",
+    },
+    Rule {
+        name: "readonly_variable",
+        category: Some("correctness"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "Assignment to a read-only `v:` variable",
+    },
+    // Suspicious rules
+    Rule {
+        name: "normal_bang",
+        category: Some("suspicious"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "`normal` without `!`",
+    },
+    Rule {
+        name: "match_case",
+        category: Some("suspicious"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "`=~` without case modifier",
+    },
+    Rule {
+        name: "autocmd_group",
+        category: Some("suspicious"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "`autocmd` outside `augroup`",
+    },
+    Rule {
+        name: "set_compatible",
+        category: Some("suspicious"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "`set compatible` enabled",
+    },
+    Rule {
+        name: "vim9script_position",
+        category: Some("suspicious"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "`vim9script` not at file start",
+    },
+    Rule {
+        name: "version_guard",
+        category: Some("suspicious"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "Builtin newer than the version checked for",
+    },
+    Rule {
+        name: "deprecated",
+        category: Some("suspicious"),
+        severity: DiagnosticSeverity::WARNING,
+        description: "Deprecated builtin function",
+    },
+    // Style rules
+    Rule {
+        name: "double_dot",
+        category: Some("style"),
+        severity: DiagnosticSeverity::HINT,
+        description: "`.` instead of `..` for concatenation",
+    },
+    Rule {
+        name: "function_bang",
+        category: Some("style"),
+        severity: DiagnosticSeverity::HINT,
+        description: "Unnecessary `!` on s: functions",
+    },
+    Rule {
+        name: "abort",
+        category: Some("style"),
+        severity: DiagnosticSeverity::HINT,
+        description: "Missing `abort` attribute",
+    },
+    Rule {
+        name: "single_quote",
+        category: Some("style"),
+        severity: DiagnosticSeverity::HINT,
+        description: "Double quotes when single would work",
+    },
+    Rule {
+        name: "key_notation",
+        category: Some("style"),
+        severity: DiagnosticSeverity::HINT,
+        description: "Non-standard key notation",
+    },
+    Rule {
+        name: "plug_noremap",
+        category: Some("style"),
+        severity: DiagnosticSeverity::HINT,
+        description: "`map` instead of `noremap` for `<Plug>`",
+    },
+    Rule {
+        name: "line_length",
+        category: Some("style"),
+        severity: DiagnosticSeverity::HINT,
+        description: "Line longer than the threshold",
+    },
+    Rule {
+        name: "function_length",
+        category: Some("style"),
+        severity: DiagnosticSeverity::HINT,
+        description: "Function body longer than the threshold",
+    },
+    Rule {
+        name: "nesting_depth",
+        category: Some("style"),
+        severity: DiagnosticSeverity::HINT,
+        description: "Blocks nested deeper than the threshold",
+    },
+    // Uncategorized rules
+    Rule {
+        name: "syntax_error",
+        category: None,
+        severity: DiagnosticSeverity::ERROR,
+        description: "Syntax error reported by the parser",
+    },
+    Rule {
+        name: "max_diagnostics",
+        category: None,
+        severity: DiagnosticSeverity::INFORMATION,
+        description: "Diagnostics over the per-file limit hidden",
+    },
+];
+
+/// A lint pass only looking at the file it checks
+pub(crate) struct LintPass {
+    /// Rules reported by the pass
+    pub name: &'static str,
+    /// Collect the diagnostics of a source; given the URI of the file, they
+    /// can point at related code in it
+    pub run: fn(&Tree, &str, Option<&Uri>) -> Vec<Diagnostic>,
+}
+
+/// Lint passes only looking at the file they check, shared by the server,
+/// `analyze()` and the `bench` command
+///
+/// Passes looking up definitions in the workspace, and metric hints using
+/// configured thresholds, are run separately.
+pub(crate) const FILE_PASSES: &[LintPass] = &[
+    LintPass {
+        name: "syntax_error",
+        run: |tree, source, _| {
+            let mut diagnostics = Vec::new();
+            crate::backend::collect_errors(&mut tree.walk(), source, &mut diagnostics);
+            diagnostics
+        },
+    },
+    LintPass {
+        name: "scope_violation",
+        run: crate::backend::Backend::collect_scope_violations,
+    },
+    LintPass {
+        name: "bang_not_allowed",
+        run: |tree, source, _| collect_command_warnings(tree, source),
+    },
+    LintPass {
+        name: "unknown_option, invalid_option_value",
+        run: |tree, source, _| collect_option_warnings(tree, source),
+    },
+    LintPass {
+        name: "readonly_variable",
+        run: |tree, source, _| collect_variable_warnings(tree, source),
+    },
+    LintPass {
+        name: "type_mismatch",
+        run: |_, source, _| crate::vim9::typecheck::collect_type_mismatches(source),
+    },
+    LintPass {
+        name: "suspicious rules",
+        run: |tree, source, _| collect_suspicious_warnings(tree, source),
+    },
+    LintPass {
+        name: "version_guard",
+        run: |tree, source, _| collect_version_warnings(tree, source),
+    },
+    LintPass {
+        name: "deprecated",
+        run: |tree, source, _| collect_deprecation_warnings(tree, source),
+    },
+    LintPass {
+        name: "style rules",
+        run: |tree, source, _| collect_style_hints(tree, source),
+    },
+];
+
+/// Find a rule by its name or diagnostic code (e.g., "hjkls/normal_bang")
+pub fn find_rule(code: &str) -> Option<&'static Rule> {
+    let rule_name = code.strip_prefix("hjkls/").unwrap_or(code);
+    RULES.iter().find(|rule| rule.name == rule_name)
+}
+
+/// Link to the documentation of a rule in LINTING.md
+pub fn rule_doc_url(rule: &Rule) -> String {
+    doc_url(rule.name)
+}
+
+/// Link to a section of LINTING.md
+pub fn doc_url(anchor: &str) -> String {
+    format!(
+        "{}/blob/main/LINTING.md#{}",
+        env!("CARGO_PKG_REPOSITORY"),
+        anchor
+    )
+}
 
 /// Map a diagnostic code to its category
 ///
 /// Returns the category name for a given rule code (e.g., "hjkls/normal_bang" -> "suspicious")
 fn get_rule_category(code: &str) -> Option<&'static str> {
-    // Strip "hjkls/" prefix if present
-    let rule_name = code.strip_prefix("hjkls/").unwrap_or(code);
+    find_rule(code)?.category
+}
+
+/// Get the category of a rule in a dialect, or "" for uncategorized rules
+fn rule_category(rule_name: &str, dialect: &Dialect) -> &'static str {
+    dialect
+        .category_override(rule_name)
+        .or_else(|| get_rule_category(rule_name))
+        .unwrap_or("")
+}
 
-    // Map rule names to categories
+/// Map a lint rule to the `:help` tag documenting it and the help file defining the tag
+fn rule_help_tag(rule_name: &str) -> Option<(&'static str, &'static str)> {
     match rule_name {
         // Correctness rules
-        "autoload_missing" | "arity_mismatch" | "scope_violation" | "undefined_function" => {
-            Some("correctness")
-        }
+        "autoload_missing" => Some(("autoload", "userfunc.txt")),
+        "arity_mismatch" => Some(("E118", "eval.txt")),
+        "scope_violation" => Some(("internal-variables", "eval.txt")),
+        "undefined_function" => Some(("E117", "userfunc.txt")),
+        "import_unresolved" => Some((":import", "vim9.txt")),
+        "import_not_exported" => Some((":export", "vim9.txt")),
+        "type_mismatch" => Some(("E1012", "vim9.txt")),
+        "bang_not_allowed" => Some(("E477", "message.txt")),
+        "unknown_option" => Some(("E518", "options.txt")),
+        "invalid_option_value" => Some(("E474", "message.txt")),
+        "readonly_variable" => Some(("E46", "message.txt")),
         // Suspicious rules
-        "normal_bang"
-        | "match_case"
-        | "autocmd_group"
-        | "set_compatible"
-        | "vim9script_position" => Some("suspicious"),
+        "normal_bang" => Some((":normal", "various.txt")),
+        "match_case" => Some(("expr-=~", "eval.txt")),
+        "autocmd_group" => Some((":augroup", "autocmd.txt")),
+        "set_compatible" => Some(("'compatible'", "options.txt")),
+        "vim9script_position" => Some((":vim9script", "vim9.txt")),
+        "version_guard" => Some(("has-patch", "builtin.txt")),
         // Style rules
-        "double_dot" | "function_bang" | "abort" | "single_quote" | "key_notation"
-        | "plug_noremap" => Some("style"),
+        "double_dot" => Some(("expr-..", "eval.txt")),
+        "function_bang" => Some((":function", "userfunc.txt")),
+        "abort" => Some((":func-abort", "userfunc.txt")),
+        "single_quote" => Some(("literal-string", "eval.txt")),
+        "key_notation" => Some(("key-notation", "intro.txt")),
+        "plug_noremap" => Some(("<Plug>", "map.txt")),
         _ => None,
     }
 }
 
-/// Filter diagnostics based on configuration settings
+/// Link to the `:help` topic of a lint rule, for `Diagnostic.code_description`
 ///
-/// Removes diagnostics for rules that are disabled in the config.
-pub fn filter_by_config(diagnostics: Vec<Diagnostic>, config: &Config) -> Vec<Diagnostic> {
+/// Rules without a help topic (hjkls original ones like `line_length`) link
+/// to their documentation in LINTING.md.
+fn rule_code_description(rule_name: &str, dialect: &Dialect) -> Option<CodeDescription> {
+    let url = match rule_help_tag(rule_name) {
+        Some((tag, file)) => help::url(tag, Some(file), dialect.editor == EditorMode::NeovimOnly)?,
+        None => rule_doc_url(find_rule(rule_name)?),
+    };
+    let href = Uri::from_str(&url).ok()?;
+    Some(CodeDescription { href })
+}
+
+/// Filter diagnostics based on configuration settings and the file's dialect
+///
+/// Removes diagnostics for rules that are disabled in the config or that do
+/// not apply to the dialect, raises the severity of rules the dialect treats
+/// as errors, and links lint findings to their `:help` topic.
+pub fn filter_by_config(
+    diagnostics: Vec<Diagnostic>,
+    config: &Config,
+    dialect: &Dialect,
+) -> Vec<Diagnostic> {
     diagnostics
         .into_iter()
         .filter(|diag| {
@@ -62,8 +402,8 @@ pub fn filter_by_config(diagnostics: Vec<Diagnostic>, config: &Config) -> Vec<Di
             };
 
             let code_str = match code {
-                tower_lsp_server::ls_types::NumberOrString::String(s) => s.as_str(),
-                tower_lsp_server::ls_types::NumberOrString::Number(_) => {
+                NumberOrString::String(s) => s.as_str(),
+                NumberOrString::Number(_) => {
                     // Numeric codes are always shown
                     return true;
                 }
@@ -72,22 +412,54 @@ pub fn filter_by_config(diagnostics: Vec<Diagnostic>, config: &Config) -> Vec<Di
             // Get the rule name (strip "hjkls/" prefix)
             let rule_name = code_str.strip_prefix("hjkls/").unwrap_or(code_str);
 
-            // Get the category for this rule
-            let Some(category) = get_rule_category(code_str) else {
-                // Unknown rules are always shown
-                return true;
-            };
+            if !dialect.applies(rule_name) {
+                return false;
+            }
 
-            // Check if the rule is enabled
-            config.is_rule_enabled(category, rule_name)
+            // Check if the rule is enabled; uncategorized rules (e.g., syntax
+            // errors) are shown unless turned off by name
+            config.is_rule_enabled(rule_category(rule_name, dialect), rule_name)
+        })
+        .map(|mut diag| {
+            if let Some(NumberOrString::String(code)) = &diag.code {
+                let rule_name = code.strip_prefix("hjkls/").unwrap_or(code);
+                // A configured severity takes priority over the dialect's
+                let severity = config
+                    .rule_severity(rule_category(rule_name, dialect), rule_name)
+                    .or_else(|| dialect.severity_override(rule_name));
+                if let Some(severity) = severity {
+                    diag.severity = Some(severity);
+                }
+                if diag.code_description.is_none() {
+                    diag.code_description = rule_code_description(rule_name, dialect);
+                }
+            }
+            diag
         })
         .collect()
 }
 
+/// Post-process the findings of all passes over a file
+///
+/// Drops duplicates and findings cascading from syntax errors, and applies the
+/// inline ignore directives and the config. The findings are not capped:
+/// only the server limits what it publishes, see `cap_diagnostics()`.
+pub fn finish_diagnostics(
+    diagnostics: Vec<Diagnostic>,
+    source: &str,
+    config: &Config,
+    dialect: &Dialect,
+) -> Vec<Diagnostic> {
+    let diagnostics = dedup_diagnostics(diagnostics);
+    let directives = parse_ignore_directives(source);
+    let diagnostics = filter_diagnostics(diagnostics, &directives);
+    filter_by_config(diagnostics, config, dialect)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tower_lsp_server::ls_types::{NumberOrString, Position, Range};
+    use tower_lsp_server::ls_types::{DiagnosticSeverity, Position, Range};
 
     fn make_diagnostic(code: &str) -> Diagnostic {
         Diagnostic {
@@ -125,6 +497,18 @@ mod tests {
         assert_eq!(get_rule_category("normal_bang"), Some("suspicious"));
     }
 
+    #[test]
+    fn test_find_rule() {
+        let rule = find_rule("hjkls/abort").unwrap();
+        assert_eq!(rule.category, Some("style"));
+        assert_eq!(
+            rule_doc_url(rule),
+            "https://github.com/kawarimidoll/hjkls/blob/main/LINTING.md#abort"
+        );
+        assert_eq!(find_rule("syntax_error").unwrap().category, None);
+        assert!(find_rule("hjkls/unknown_rule").is_none());
+    }
+
     #[test]
     fn test_filter_by_config_default() {
         let config = Config::default();
@@ -135,7 +519,7 @@ mod tests {
             make_diagnostic("hjkls/double_dot"),         // style: disabled by default
         ];
 
-        let filtered = filter_by_config(diagnostics, &config);
+        let filtered = filter_by_config(diagnostics, &config, &Dialect::default());
         assert_eq!(filtered.len(), 2);
         assert!(
             filtered
@@ -165,7 +549,7 @@ mod tests {
             make_diagnostic("hjkls/double_dot"),  // style: now enabled
         ];
 
-        let filtered = filter_by_config(diagnostics, &config);
+        let filtered = filter_by_config(diagnostics, &config, &Dialect::default());
         assert_eq!(filtered.len(), 1);
         assert!(
             filtered
@@ -192,7 +576,7 @@ mod tests {
             make_diagnostic("hjkls/match_case"),  // still enabled (category is on)
         ];
 
-        let filtered = filter_by_config(diagnostics, &config);
+        let filtered = filter_by_config(diagnostics, &config, &Dialect::default());
         assert_eq!(filtered.len(), 1);
         assert!(
             filtered
@@ -200,4 +584,75 @@ mod tests {
                 .any(|d| d.code == Some(NumberOrString::String("hjkls/match_case".into())))
         );
     }
+
+    #[test]
+    fn test_filter_by_config_dialect() {
+        let config = Config::default();
+        let vim9 = Dialect::detect("vim9script\n", None, crate::builtins::EditorMode::Both);
+
+        let diagnostics = vec![
+            make_diagnostic("hjkls/scope_violation"), // legacy only
+            make_diagnostic("hjkls/double_dot"),      // style, but an error in Vim9
+            make_diagnostic("hjkls/normal_bang"),     // all dialects
+        ];
+
+        let filtered = filter_by_config(diagnostics, &config, &vim9);
+        let codes: Vec<_> = filtered.iter().map(|d| d.code.clone()).collect();
+        assert_eq!(
+            codes,
+            vec![
+                Some(NumberOrString::String("hjkls/double_dot".into())),
+                Some(NumberOrString::String("hjkls/normal_bang".into())),
+            ]
+        );
+        assert_eq!(filtered[0].severity, Some(DiagnosticSeverity::ERROR));
+    }
+
+    #[test]
+    fn test_filter_by_config_code_description() {
+        let config = Config::default();
+        let diagnostics = vec![
+            make_diagnostic("hjkls/normal_bang"),
+            make_diagnostic("hjkls/syntax_error"),
+        ];
+
+        let filtered = filter_by_config(diagnostics.clone(), &config, &Dialect::default());
+        let href = filtered[0]
+            .code_description
+            .as_ref()
+            .map(|d| d.href.as_str());
+        assert_eq!(href, Some("https://vimhelp.org/various.txt.html#%3Anormal"));
+        // Rules without a help topic link to LINTING.md
+        let href = filtered[1]
+            .code_description
+            .as_ref()
+            .map(|d| d.href.as_str());
+        assert_eq!(
+            href,
+            Some("https://github.com/kawarimidoll/hjkls/blob/main/LINTING.md#syntax_error")
+        );
+        // Every rule has a link
+        for rule in RULES {
+            let code = format!("hjkls/{}", rule.name);
+            assert!(
+                rule_code_description(rule.name, &Dialect::default()).is_some(),
+                "{}",
+                code
+            );
+        }
+
+        let nvim = Dialect {
+            editor: EditorMode::NeovimOnly,
+            ..Dialect::default()
+        };
+        let filtered = filter_by_config(diagnostics, &config, &nvim);
+        let href = filtered[0]
+            .code_description
+            .as_ref()
+            .map(|d| d.href.as_str());
+        assert_eq!(
+            href,
+            Some("https://neovim.io/doc/user/helptag.html?tag=%3Anormal")
+        );
+    }
 }
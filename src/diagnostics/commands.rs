@@ -0,0 +1,86 @@
+//! Ex command usage checks based on the command metadata (DiagnosticSeverity::WARNING)
+//!
+//! - `bang_not_allowed`: `!` after a command that doesn't accept one (E477)
+
+use tower_lsp_server::ls_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+use tree_sitter::{Node, Tree};
+
+use crate::builtins::{BUILTIN_COMMANDS, BuiltinCommand};
+
+/// Collect all command usage warnings from the syntax tree
+pub fn collect_command_warnings(tree: &Tree, source: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    collect_bang_warnings_recursive(&tree.root_node(), source, &mut diagnostics);
+    diagnostics
+}
+
+/// Collect warnings for `!` after commands that don't accept it
+fn collect_bang_warnings_recursive(node: &Node, source: &str, diagnostics: &mut Vec<Diagnostic>) {
+    let command = Some(node)
+        .filter(|node| node.kind() == "bang")
+        .and_then(|node| node.prev_sibling())
+        .and_then(|name| name.utf8_text(source.as_bytes()).ok())
+        .and_then(find_command)
+        .filter(|command| !command.bang);
+    if let Some(command) = command {
+        let start = node.start_position();
+        let end = node.end_position();
+        diagnostics.push(Diagnostic {
+            range: Range {
+                start: Position {
+                    line: start.row as u32,
+                    character: start.column as u32,
+                },
+                end: Position {
+                    line: end.row as u32,
+                    character: end.column as u32,
+                },
+            },
+            severity: Some(DiagnosticSeverity::WARNING),
+            source: Some("hjkls".to_string()),
+            message: format!("`:{}` doesn't accept `!` (E477)", command.name),
+            code: Some(NumberOrString::String("hjkls/bang_not_allowed".to_string())),
+            ..Default::default()
+        });
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_bang_warnings_recursive(&child, source, diagnostics);
+    }
+}
+
+/// Find the builtin command a (possibly abbreviated) name refers to
+fn find_command(name: &str) -> Option<&'static BuiltinCommand> {
+    BUILTIN_COMMANDS.iter().find(|cmd| cmd.matches(name))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bang_warnings(code: &str) -> Vec<Diagnostic> {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&tree_sitter_vim::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        collect_command_warnings(&tree, code)
+    }
+
+    #[test]
+    fn test_bang_not_allowed() {
+        let diagnostics = bang_warnings("copen!\nnohlsearch!\n");
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].message, "`:copen` doesn't accept `!` (E477)");
+        assert_eq!(diagnostics[0].range.start.character, 5);
+        assert_eq!(diagnostics[0].range.end.character, 6);
+        assert_eq!(diagnostics[1].range.start.line, 1);
+    }
+
+    #[test]
+    fn test_bang_allowed() {
+        let code = "normal! x\nsilent! echo 1\nhi! link A B\nbd!\nquit!\nsplit! foo\nfunction! F()\nendfunction\n";
+        assert!(bang_warnings(code).is_empty());
+        // User commands and unknown names are not checked
+        assert!(bang_warnings("MyCommand!\n").is_empty());
+    }
+}
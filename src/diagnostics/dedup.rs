@@ -0,0 +1,181 @@
+//! Deduplication and capping of diagnostics
+//!
+//! A single typo can make tree-sitter wrap a large region in an ERROR node,
+//! and lint rules then report the broken code again. Diagnostics inside a
+//! syntax error are dropped, identical diagnostics are merged, and the number
+//! of diagnostics per file is capped with a summary message.
+
+use std::str::FromStr;
+
+use tower_lsp_server::ls_types::{
+    CodeDescription, Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range, Uri,
+};
+
+/// Whether a diagnostic is a syntax error
+fn is_syntax_error(diag: &Diagnostic) -> bool {
+    matches!(&diag.code, Some(NumberOrString::String(code)) if code == "hjkls/syntax_error")
+}
+
+/// Whether `inner` lies within `outer`
+fn contains(outer: &Range, inner: &Range) -> bool {
+    outer.start <= inner.start && inner.end <= outer.end
+}
+
+/// Remove duplicate diagnostics and lint findings inside syntax errors
+///
+/// Syntax errors themselves are kept; any other diagnostic whose range lies
+/// within a syntax error is a cascade of the same mistake.
+pub fn dedup_diagnostics(diagnostics: Vec<Diagnostic>) -> Vec<Diagnostic> {
+    let error_ranges: Vec<Range> = diagnostics
+        .iter()
+        .filter(|diag| is_syntax_error(diag))
+        .map(|diag| diag.range)
+        .collect();
+
+    let mut result: Vec<Diagnostic> = Vec::with_capacity(diagnostics.len());
+    for diag in diagnostics {
+        if !is_syntax_error(&diag) && error_ranges.iter().any(|r| contains(r, &diag.range)) {
+            continue;
+        }
+        let duplicate = result.iter().any(|seen| {
+            seen.range == diag.range && seen.code == diag.code && seen.message == diag.message
+        });
+        if !duplicate {
+            result.push(diag);
+        }
+    }
+    result
+}
+
+/// Keep at most `max` diagnostics, most severe first, and summarize the rest
+///
+/// When diagnostics are dropped, an information diagnostic at the top of the
+/// file reports how many were hidden.
+pub fn cap_diagnostics(mut diagnostics: Vec<Diagnostic>, max: Option<usize>) -> Vec<Diagnostic> {
+    let Some(max) = max else {
+        return diagnostics;
+    };
+    if diagnostics.len() <= max {
+        return diagnostics;
+    }
+
+    // DiagnosticSeverity has no ordering; ERROR (1) is the most severe
+    let rank = |diag: &Diagnostic| {
+        let severity = match diag.severity {
+            Some(DiagnosticSeverity::ERROR) => 0,
+            Some(DiagnosticSeverity::WARNING) => 1,
+            Some(DiagnosticSeverity::INFORMATION) => 2,
+            _ => 3,
+        };
+        (severity, diag.range.start)
+    };
+    diagnostics.sort_by_key(rank);
+
+    let hidden = diagnostics.len() - max;
+    diagnostics.truncate(max);
+    diagnostics.push(Diagnostic {
+        range: Range {
+            start: Position::new(0, 0),
+            end: Position::new(0, 0),
+        },
+        severity: Some(DiagnosticSeverity::INFORMATION),
+        code: Some(NumberOrString::String("hjkls/max_diagnostics".to_string())),
+        code_description: Uri::from_str(&super::doc_url("max_diagnostics"))
+            .ok()
+            .map(|href| CodeDescription { href }),
+        source: Some("hjkls".to_string()),
+        message: format!(
+            "{hidden} more diagnostics not shown (limit: {max}, see `lint.max_diagnostics`)"
+        ),
+        ..Default::default()
+    });
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn diag(
+        line: u32,
+        start: u32,
+        end: u32,
+        severity: DiagnosticSeverity,
+        code: Option<&str>,
+    ) -> Diagnostic {
+        Diagnostic {
+            range: Range {
+                start: Position::new(line, start),
+                end: Position::new(line, end),
+            },
+            severity: Some(severity),
+            code: Some(NumberOrString::String(
+                code.unwrap_or("hjkls/syntax_error").to_string(),
+            )),
+            message: code.unwrap_or("Syntax error").to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_dedup_drops_findings_inside_syntax_errors() {
+        let diagnostics = vec![
+            diag(0, 0, 20, DiagnosticSeverity::ERROR, None),
+            diag(
+                0,
+                5,
+                10,
+                DiagnosticSeverity::WARNING,
+                Some("hjkls/undefined_function"),
+            ),
+            diag(
+                1,
+                0,
+                3,
+                DiagnosticSeverity::WARNING,
+                Some("hjkls/undefined_function"),
+            ),
+        ];
+        let result = dedup_diagnostics(diagnostics);
+        assert_eq!(result.len(), 2);
+        assert!(is_syntax_error(&result[0]));
+        assert_eq!(result[1].range.start.line, 1);
+    }
+
+    #[test]
+    fn test_dedup_merges_identical_diagnostics() {
+        let diagnostics = vec![
+            diag(0, 0, 3, DiagnosticSeverity::HINT, Some("hjkls/double_dot")),
+            diag(0, 0, 3, DiagnosticSeverity::HINT, Some("hjkls/double_dot")),
+            diag(0, 0, 3, DiagnosticSeverity::HINT, Some("hjkls/abort")),
+        ];
+        assert_eq!(dedup_diagnostics(diagnostics).len(), 2);
+    }
+
+    #[test]
+    fn test_cap_diagnostics() {
+        let diagnostics = vec![
+            diag(0, 0, 1, DiagnosticSeverity::HINT, Some("hjkls/abort")),
+            diag(
+                1,
+                0,
+                1,
+                DiagnosticSeverity::WARNING,
+                Some("hjkls/normal_bang"),
+            ),
+            diag(2, 0, 1, DiagnosticSeverity::ERROR, None),
+        ];
+        assert_eq!(cap_diagnostics(diagnostics.clone(), None).len(), 3);
+        assert_eq!(cap_diagnostics(diagnostics.clone(), Some(3)).len(), 3);
+
+        let result = cap_diagnostics(diagnostics, Some(2));
+        assert_eq!(result.len(), 3);
+        assert_eq!(result[0].severity, Some(DiagnosticSeverity::ERROR));
+        assert_eq!(result[1].severity, Some(DiagnosticSeverity::WARNING));
+        assert!(
+            result[2]
+                .message
+                .starts_with("1 more diagnostics not shown")
+        );
+    }
+}
@@ -0,0 +1,207 @@
+//! Deprecation checks based on the deprecation database (DiagnosticSeverity::WARNING)
+//!
+//! - `deprecated`: a call of a deprecated or obsolete builtin function
+//!
+//! The diagnostics are tagged DEPRECATED and carry the edit switching to the
+//! replacement in `Diagnostic.data`, for the quick fix.
+
+use std::str::FromStr;
+
+use tower_lsp_server::ls_types::{
+    CodeDescription, Diagnostic, DiagnosticSeverity, DiagnosticTag, NumberOrString, Position,
+    Range, TextEdit, Uri,
+};
+use tree_sitter::{Node, Tree};
+
+use crate::builtins::{Availability, Deprecation, DeprecationFix, DeprecationKind};
+use crate::help;
+
+/// Collect all deprecation warnings from the syntax tree
+pub fn collect_deprecation_warnings(tree: &Tree, source: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    collect_deprecated_calls_recursive(&tree.root_node(), source, &mut diagnostics);
+    diagnostics
+}
+
+/// Collect warnings for calls of deprecated functions
+fn collect_deprecated_calls_recursive(
+    node: &Node,
+    source: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let function = Some(node)
+        .filter(|node| node.kind() == "call_expression")
+        .and_then(|node| node.child_by_field_name("function"))
+        .filter(|function| function.kind() == "identifier");
+    if let Some(function) = function {
+        let deprecation = function
+            .utf8_text(source.as_bytes())
+            .ok()
+            .and_then(|name| Deprecation::find(DeprecationKind::Function, name));
+        if let Some(deprecation) = deprecation {
+            diagnostics.push(deprecated_call(node, &function, source, deprecation));
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_deprecated_calls_recursive(&child, source, diagnostics);
+    }
+}
+
+/// The diagnostic of a deprecated call, on the function name
+fn deprecated_call(
+    call: &Node,
+    function: &Node,
+    source: &str,
+    deprecation: &Deprecation,
+) -> Diagnostic {
+    let fix = match deprecation.fix {
+        DeprecationFix::Rename => Some(TextEdit {
+            range: node_range(function),
+            new_text: deprecation.replacement.to_string(),
+        }),
+        DeprecationFix::Call(_) => {
+            let mut cursor = call.walk();
+            let arguments: Vec<&str> = call
+                .named_children(&mut cursor)
+                .filter(|child| child.id() != function.id())
+                .filter_map(|child| child.utf8_text(source.as_bytes()).ok())
+                .collect();
+            deprecation
+                .rewrite_call(&arguments)
+                .map(|new_text| TextEdit {
+                    range: node_range(call),
+                    new_text,
+                })
+        }
+        DeprecationFix::Manual => None,
+    };
+
+    let tag = format!("{}()", deprecation.replacement);
+    let neovim = deprecation.availability == Availability::NeovimOnly;
+    let code_description = help::url(&tag, Some(deprecation.help_file()), neovim)
+        .and_then(|url| Uri::from_str(&url).ok())
+        .map(|href| CodeDescription { href });
+
+    Diagnostic {
+        range: node_range(function),
+        severity: Some(DiagnosticSeverity::WARNING),
+        code: Some(NumberOrString::String("hjkls/deprecated".to_string())),
+        code_description,
+        source: Some("hjkls".to_string()),
+        message: format!(
+            "`{}()` is deprecated. Use `{}()` instead.",
+            deprecation.name, deprecation.replacement
+        ),
+        tags: Some(vec![DiagnosticTag::DEPRECATED]),
+        data: fix.and_then(|edit| serde_json::to_value(edit).ok()),
+        ..Default::default()
+    }
+}
+
+fn node_range(node: &Node) -> Range {
+    let start = node.start_position();
+    let end = node.end_position();
+    Range {
+        start: Position {
+            line: start.row as u32,
+            character: start.column as u32,
+        },
+        end: Position {
+            line: end.row as u32,
+            character: end.column as u32,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn deprecation_warnings(code: &str) -> Vec<Diagnostic> {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&tree_sitter_vim::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        collect_deprecation_warnings(&tree, code)
+    }
+
+    fn fix(diagnostic: &Diagnostic) -> Option<TextEdit> {
+        serde_json::from_value(diagnostic.data.clone()?).ok()
+    }
+
+    #[test]
+    fn test_deprecated_rename() {
+        let diagnostics = deprecation_warnings("if buffer_exists(1)\nendif\n");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "`buffer_exists()` is deprecated. Use `bufexists()` instead."
+        );
+        assert_eq!(diagnostics[0].tags, Some(vec![DiagnosticTag::DEPRECATED]));
+        let edit = fix(&diagnostics[0]).unwrap();
+        assert_eq!(edit.new_text, "bufexists");
+        assert_eq!(edit.range, diagnostics[0].range);
+        assert_eq!(edit.range.start.character, 3);
+        assert_eq!(edit.range.end.character, 16);
+    }
+
+    #[test]
+    fn test_deprecated_help_link() {
+        let diagnostics = deprecation_warnings("echo buffer_exists(1)\ncall jobsend(1, 'x')\n");
+        let hrefs: Vec<&str> = diagnostics
+            .iter()
+            .filter_map(|d| Some(d.code_description.as_ref()?.href.as_str()))
+            .collect();
+        assert_eq!(
+            hrefs,
+            vec![
+                "https://vimhelp.org/builtin.txt.html#bufexists%28%29",
+                "https://neovim.io/doc/user/helptag.html?tag=chansend%28%29",
+            ]
+        );
+        assert_eq!(fix(&diagnostics[1]).unwrap().new_text, "chansend");
+
+        // API functions are documented in api.txt
+        let deprecation = Deprecation::find(DeprecationKind::Function, "nvim_exec").unwrap();
+        assert_eq!(deprecation.help_file(), "api.txt");
+    }
+
+    #[test]
+    fn test_deprecated_call_rewrite() {
+        let diagnostics = deprecation_warnings(
+            "call nvim_buf_set_option(0, 'filetype', 'vim')\nlet x = termopen(['sh'])\n",
+        );
+        let edits: Vec<String> = diagnostics
+            .iter()
+            .map(|d| fix(d).unwrap().new_text)
+            .collect();
+        assert_eq!(
+            edits,
+            vec![
+                "nvim_set_option_value('filetype', 'vim', {'buf': 0})",
+                "jobstart(['sh'], {'term': v:true})",
+            ]
+        );
+        // The edit replaces the whole call
+        let edit = fix(&diagnostics[0]).unwrap();
+        assert_eq!(edit.range.start.character, 5);
+        assert_eq!(edit.range.end.character, 46);
+    }
+
+    #[test]
+    fn test_deprecated_without_fix() {
+        // Wrong number of arguments, and replacements that work differently
+        let diagnostics = deprecation_warnings(
+            "call nvim_buf_get_option(0)\necho nvim_get_hl_by_name('Normal', 1)\n",
+        );
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics.iter().all(|d| d.data.is_none()));
+    }
+
+    #[test]
+    fn test_not_deprecated() {
+        let code = "call nvim_set_option_value('filetype', 'vim', {})\nlet l:Buffer_exists = 1\necho bufexists(1)\n";
+        assert!(deprecation_warnings(code).is_empty());
+    }
+}
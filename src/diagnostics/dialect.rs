@@ -0,0 +1,169 @@
+//! Per-file dialect detection for lint rules
+//!
+//! Vim script comes in several dialects: legacy script vs Vim9 script, and
+//! Vim vs Neovim. Some rules only make sense in one of them (e.g., `l:` scope
+//! does not exist in Vim9 script), and some become more severe (e.g., `.`
+//! concatenation is an error in Vim9 script).
+
+use std::path::Path;
+
+use tower_lsp_server::ls_types::DiagnosticSeverity;
+
+use crate::builtins::{Availability, EditorMode};
+use crate::vim9;
+
+/// Script dialect of a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScriptDialect {
+    /// Legacy Vim script
+    #[default]
+    Legacy,
+    /// Vim9 script (file starts with `vim9script`)
+    Vim9,
+}
+
+/// Dialect of a single file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Dialect {
+    /// Legacy or Vim9 script
+    pub script: ScriptDialect,
+    /// Target editor (`Both` when unknown)
+    pub editor: EditorMode,
+}
+
+impl Dialect {
+    /// Detect the dialect of a file from its content and path
+    ///
+    /// - Vim9 script is detected from a leading `vim9script`; it implies Vim
+    /// - `--vim-only` / `--neovim-only` fix the target editor
+    /// - Otherwise, files under an `nvim` directory (e.g., `~/.config/nvim`)
+    ///   are treated as Neovim-only
+    pub fn detect(source: &str, path: Option<&Path>, editor_mode: EditorMode) -> Self {
+        let script = if vim9::is_vim9script(source) {
+            ScriptDialect::Vim9
+        } else {
+            ScriptDialect::Legacy
+        };
+
+        let editor = if script == ScriptDialect::Vim9 {
+            // Neovim does not support Vim9 script
+            EditorMode::VimOnly
+        } else if editor_mode != EditorMode::Both {
+            editor_mode
+        } else if path.is_some_and(|p| p.components().any(|c| c.as_os_str() == "nvim")) {
+            EditorMode::NeovimOnly
+        } else {
+            EditorMode::Both
+        };
+
+        Self { script, editor }
+    }
+
+    /// Whether a rule applies to this dialect
+    pub fn applies(&self, rule_name: &str) -> bool {
+        let (script, availability) = rule_dialect(rule_name);
+        script.is_none_or(|s| s == self.script) && availability.is_compatible(self.editor)
+    }
+
+    /// Category override for a rule in this dialect
+    ///
+    /// Returns None when the rule keeps its default category.
+    pub fn category_override(&self, rule_name: &str) -> Option<&'static str> {
+        match (self.script, rule_name) {
+            // `.` concatenation is a syntax error in Vim9 script
+            (ScriptDialect::Vim9, "double_dot") => Some("correctness"),
+            _ => None,
+        }
+    }
+
+    /// Severity override for a rule in this dialect
+    pub fn severity_override(&self, rule_name: &str) -> Option<DiagnosticSeverity> {
+        self.category_override(rule_name)
+            .filter(|c| *c == "correctness")
+            .map(|_| DiagnosticSeverity::ERROR)
+    }
+}
+
+/// Describe the dialects a rule applies to (e.g., "all", "Vim9 script")
+pub fn rule_applicability(rule_name: &str) -> &'static str {
+    match rule_dialect(rule_name) {
+        (Some(ScriptDialect::Legacy), _) => "legacy script",
+        (Some(ScriptDialect::Vim9), _) => "Vim9 script",
+        (None, Availability::VimOnly) => "Vim",
+        (None, Availability::NeovimOnly) => "Neovim",
+        (None, Availability::Common) => "all",
+    }
+}
+
+/// Dialects a rule applies to: the script dialect (None for both) and the editor
+fn rule_dialect(rule_name: &str) -> (Option<ScriptDialect>, Availability) {
+    match rule_name {
+        // `l:`/`a:` scopes and `function` attributes only exist in legacy script
+        "scope_violation" | "abort" | "function_bang" => {
+            (Some(ScriptDialect::Legacy), Availability::Common)
+        }
+        // Vim9 imports and types
+        "import_unresolved" | "import_not_exported" | "type_mismatch" => {
+            (Some(ScriptDialect::Vim9), Availability::VimOnly)
+        }
+        // Neovim does not support Vim9 script at all
+        "vim9script_position" => (None, Availability::VimOnly),
+        _ => (None, Availability::Common),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_script_dialect() {
+        let legacy = Dialect::detect("let x = 1\n", None, EditorMode::Both);
+        assert_eq!(legacy.script, ScriptDialect::Legacy);
+        assert_eq!(legacy.editor, EditorMode::Both);
+
+        let vim9 = Dialect::detect("vim9script\nvar x = 1\n", None, EditorMode::Both);
+        assert_eq!(vim9.script, ScriptDialect::Vim9);
+        assert_eq!(vim9.editor, EditorMode::VimOnly);
+    }
+
+    #[test]
+    fn test_detect_editor_from_path() {
+        let path = Path::new("/home/user/.config/nvim/plugin/foo.vim");
+        let dialect = Dialect::detect("", Some(path), EditorMode::Both);
+        assert_eq!(dialect.editor, EditorMode::NeovimOnly);
+
+        // Explicit editor mode takes precedence over the path
+        let dialect = Dialect::detect("", Some(path), EditorMode::VimOnly);
+        assert_eq!(dialect.editor, EditorMode::VimOnly);
+    }
+
+    #[test]
+    fn test_rule_gating() {
+        let legacy = Dialect::default();
+        let vim9 = Dialect::detect("vim9script\n", None, EditorMode::Both);
+        let nvim = Dialect {
+            script: ScriptDialect::Legacy,
+            editor: EditorMode::NeovimOnly,
+        };
+
+        assert!(legacy.applies("scope_violation"));
+        assert!(!vim9.applies("scope_violation"));
+        assert!(!legacy.applies("type_mismatch"));
+        assert!(vim9.applies("type_mismatch"));
+        assert!(!nvim.applies("vim9script_position"));
+        assert!(nvim.applies("normal_bang"));
+
+        assert_eq!(rule_applicability("normal_bang"), "all");
+        assert_eq!(rule_applicability("abort"), "legacy script");
+        assert_eq!(rule_applicability("type_mismatch"), "Vim9 script");
+        assert_eq!(rule_applicability("vim9script_position"), "Vim");
+
+        assert_eq!(legacy.category_override("double_dot"), None);
+        assert_eq!(vim9.category_override("double_dot"), Some("correctness"));
+        assert_eq!(
+            vim9.severity_override("double_dot"),
+            Some(DiagnosticSeverity::ERROR)
+        );
+    }
+}
@@ -0,0 +1,237 @@
+//! Metric hints for Vim script (DiagnosticSeverity::HINT)
+//!
+//! These style rules report code exceeding size thresholds, which are set in
+//! the `[lint.thresholds]` section of the configuration.
+
+use tower_lsp_server::ls_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+use tree_sitter::{Node, Tree};
+
+use crate::config::ThresholdsConfig;
+use crate::heredoc;
+
+/// Blocks counted by `nesting_depth`
+const NESTING_KINDS: &[&str] = &["if_statement", "for_loop", "while_loop", "try_statement"];
+
+/// Collect all metric hints from the syntax tree
+pub fn collect_metric_hints(
+    tree: &Tree,
+    source: &str,
+    thresholds: &ThresholdsConfig,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    let root = tree.root_node();
+
+    // line_length: lines longer than the threshold
+    collect_line_length_hints(source, thresholds.line_length(), &mut diagnostics);
+
+    // function_length: function bodies longer than the threshold
+    collect_function_length_hints_recursive(
+        &root,
+        source,
+        thresholds.function_length(),
+        &mut diagnostics,
+    );
+
+    // nesting_depth: blocks nested deeper than the threshold
+    collect_nesting_depth_hints_recursive(
+        &root,
+        source,
+        0,
+        thresholds.nesting_depth(),
+        &mut diagnostics,
+    );
+
+    diagnostics
+}
+
+/// Build a hint on the first line of a node
+fn first_line_hint(node: &Node, source: &str, message: String, code: &str) -> Diagnostic {
+    let start = node.start_position();
+    let text = node.utf8_text(source.as_bytes()).unwrap_or("");
+    let first_line = text.lines().next().unwrap_or(text);
+    Diagnostic {
+        range: Range {
+            start: Position {
+                line: start.row as u32,
+                character: start.column as u32,
+            },
+            end: Position {
+                line: start.row as u32,
+                character: (start.column + first_line.len()) as u32,
+            },
+        },
+        severity: Some(DiagnosticSeverity::HINT),
+        source: Some("hjkls".to_string()),
+        message,
+        code: Some(NumberOrString::String(code.to_string())),
+        ..Default::default()
+    }
+}
+
+/// Collect hints for lines longer than `max` characters
+///
+/// The embedded code of heredocs (`lua << EOF`) is not checked.
+fn collect_line_length_hints(source: &str, max: usize, diagnostics: &mut Vec<Diagnostic>) {
+    let heredocs = heredoc::find_heredocs(source);
+    for (row, line) in source.lines().enumerate() {
+        if heredocs.iter().any(|embedded| embedded.contains_line(row)) {
+            continue;
+        }
+        // Highlight the part of the line beyond the limit
+        let Some((start, _)) = line.char_indices().nth(max) else {
+            continue;
+        };
+        let length = line.chars().count();
+        diagnostics.push(Diagnostic {
+            range: Range {
+                start: Position {
+                    line: row as u32,
+                    character: start as u32,
+                },
+                end: Position {
+                    line: row as u32,
+                    character: line.len() as u32,
+                },
+            },
+            severity: Some(DiagnosticSeverity::HINT),
+            source: Some("hjkls".to_string()),
+            message: format!("Style: line is {length} characters long (limit: {max})."),
+            code: Some(NumberOrString::String("hjkls/line_length".to_string())),
+            ..Default::default()
+        });
+    }
+}
+
+/// Collect hints for functions whose body is longer than `max` lines
+fn collect_function_length_hints_recursive(
+    node: &Node,
+    source: &str,
+    max: usize,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    if node.kind() == "function_definition" {
+        let start = node.start_position();
+        let end = node.end_position();
+        // The node may end at the start of the line after `endfunction`
+        let last_row = if end.column == 0 && end.row > start.row {
+            end.row - 1
+        } else {
+            end.row
+        };
+        // Exclude the header and `endfunction` lines
+        let body_lines = last_row.saturating_sub(start.row).saturating_sub(1);
+        if body_lines > max {
+            diagnostics.push(first_line_hint(
+                node,
+                source,
+                format!("Style: function body has {body_lines} lines (limit: {max})."),
+                "hjkls/function_length",
+            ));
+        }
+    }
+
+    // Recurse into children
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_function_length_hints_recursive(&child, source, max, diagnostics);
+    }
+}
+
+/// Collect hints for blocks nested deeper than `max`
+///
+/// Only the outermost block beyond the limit is reported. Function
+/// definitions start counting again from zero.
+fn collect_nesting_depth_hints_recursive(
+    node: &Node,
+    source: &str,
+    depth: usize,
+    max: usize,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let depth = if node.kind() == "function_definition" {
+        0
+    } else if NESTING_KINDS.contains(&node.kind()) {
+        depth + 1
+    } else {
+        depth
+    };
+
+    if depth > max {
+        diagnostics.push(first_line_hint(
+            node,
+            source,
+            format!("Style: block is nested {depth} levels deep (limit: {max})."),
+            "hjkls/nesting_depth",
+        ));
+        return;
+    }
+
+    // Recurse into children
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_nesting_depth_hints_recursive(&child, source, depth, max, diagnostics);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tree_sitter::Parser;
+
+    fn hints(code: &str, thresholds: &ThresholdsConfig, rule: &str) -> Vec<Diagnostic> {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_vim::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        collect_metric_hints(&tree, code, thresholds)
+            .into_iter()
+            .filter(|d| d.code == Some(NumberOrString::String(format!("hjkls/{rule}"))))
+            .collect()
+    }
+
+    #[test]
+    fn test_line_length_hint() {
+        let thresholds = ThresholdsConfig {
+            line_length: Some(10),
+            ..Default::default()
+        };
+        let code = "echo 'ok'\necho 'too long'\necho 'あいう'\n";
+        let diagnostics = hints(code, &thresholds, "line_length");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start, Position::new(1, 10));
+        assert_eq!(diagnostics[0].range.end, Position::new(1, 15));
+
+        // Embedded code is not Vim script
+        let code = "py3 << E\nprint('too long')\nE\n";
+        assert!(hints(code, &thresholds, "line_length").is_empty());
+    }
+
+    #[test]
+    fn test_function_length_hint() {
+        let thresholds = ThresholdsConfig {
+            function_length: Some(2),
+            ..Default::default()
+        };
+        let code = "function! Short() abort\n  echo 1\n  echo 2\nendfunction\n\
+                    function! Long() abort\n  echo 1\n  echo 2\n  echo 3\nendfunction\n";
+        let diagnostics = hints(code, &thresholds, "function_length");
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start.line, 4);
+        assert!(diagnostics[0].message.contains("3 lines"));
+    }
+
+    #[test]
+    fn test_nesting_depth_hint() {
+        let thresholds = ThresholdsConfig {
+            nesting_depth: Some(2),
+            ..Default::default()
+        };
+        let code = "if 1\n  for i in []\n    while 0\n      if 1\n      endif\n    endwhile\n  endfor\nendif\n";
+        let diagnostics = hints(code, &thresholds, "nesting_depth");
+        // Only the outermost block beyond the limit
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].range.start, Position::new(2, 4));
+
+        let code = "if 1\n  for i in []\n  endfor\nendif\n";
+        assert!(hints(code, &thresholds, "nesting_depth").is_empty());
+    }
+}
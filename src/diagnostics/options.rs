@@ -0,0 +1,221 @@
+//! `:set` checks based on the option metadata (DiagnosticSeverity::WARNING)
+//!
+//! - `unknown_option`: an option that doesn't exist (E518)
+//! - `invalid_option_value`: a value of the wrong type, `no`/`inv` on a
+//!   non-boolean option, or a value not among an option's valid values (E474, E521)
+
+use tower_lsp_server::ls_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+use tree_sitter::{Node, Tree};
+
+use crate::builtins::{BuiltinOption, OptionType, OptionValueKind, OptionValues};
+
+/// Collect all option warnings from the syntax tree
+pub fn collect_option_warnings(tree: &Tree, source: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    collect_option_warnings_recursive(&tree.root_node(), source, &mut diagnostics);
+    diagnostics
+}
+
+fn collect_option_warnings_recursive(node: &Node, source: &str, diagnostics: &mut Vec<Diagnostic>) {
+    if node.kind() == "set_item" {
+        check_set_item(node, source, diagnostics);
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_option_warnings_recursive(&child, source, diagnostics);
+    }
+}
+
+/// Check one `option`, `nooption`, `option!` or `option=value` of `:set`
+fn check_set_item(item: &Node, source: &str, diagnostics: &mut Vec<Diagnostic>) {
+    let Some(option_node) = item.child_by_field_name("option") else {
+        return;
+    };
+    let name_node = if option_node.kind() == "option_name" {
+        option_node
+    } else {
+        let mut cursor = option_node.walk();
+        let Some(name_node) = option_node
+            .children(&mut cursor)
+            .find(|c| c.kind() == "option_name")
+        else {
+            return;
+        };
+        name_node
+    };
+    let Ok(name) = name_node.utf8_text(source.as_bytes()) else {
+        return;
+    };
+    // Terminal options (t_Co) and the `:set all` / `:set termcap` listings
+    if name.starts_with("t_") || matches!(name, "all" | "termcap") {
+        return;
+    }
+
+    let Some(option) = BuiltinOption::find(name) else {
+        diagnostics.push(warning(
+            &name_node,
+            format!("Unknown option: '{}' (E518)", name),
+            "hjkls/unknown_option",
+        ));
+        return;
+    };
+
+    if matches!(option_node.kind(), "no_option" | "inv_option") {
+        if option.kind != OptionType::Boolean {
+            diagnostics.push(warning(
+                &option_node,
+                format!(
+                    "'{}' is a {} option: `no`, `inv` and `!` only apply to boolean options (E474)",
+                    option.name,
+                    option.kind.label()
+                ),
+                "hjkls/invalid_option_value",
+            ));
+        }
+        return;
+    }
+
+    let Some(value_node) = item.child_by_field_name("value") else {
+        return;
+    };
+    let value = value_node.utf8_text(source.as_bytes()).unwrap_or("");
+    // The parser may run a value into a following `|` command
+    let value = value.split('|').next().unwrap_or(value);
+    if let Some(message) = check_value(option, value) {
+        diagnostics.push(warning(&value_node, message, "hjkls/invalid_option_value"));
+    }
+}
+
+/// Check the value given to an option, returning the problem found
+fn check_value(option: &BuiltinOption, value: &str) -> Option<String> {
+    match option.kind {
+        OptionType::Boolean => Some(format!(
+            "'{}' is a boolean option and takes no value: use `set {}` or `set no{}` (E474)",
+            option.name, option.name, option.name
+        )),
+        OptionType::Number if !is_number(value) => Some(format!(
+            "'{}' takes a number, got '{}' (E521)",
+            option.name, value
+        )),
+        OptionType::Number => None,
+        OptionType::String => {
+            // Values using environment variables or escapes are not checked,
+            // nor are numbers (the old form of 'backspace', e.g. `bs=2`)
+            if value.is_empty() || value.contains(['$', '\\']) || is_number(value) {
+                return None;
+            }
+            let values = OptionValues::find(option.name)?;
+            let items: Vec<&str> = match values.kind() {
+                OptionValueKind::Single => vec![value],
+                OptionValueKind::CommaList => value.split(',').filter(|v| !v.is_empty()).collect(),
+                OptionValueKind::Flags => return None,
+            };
+            let invalid = items.into_iter().find(|item| {
+                let key = item.split(':').next().unwrap_or(item);
+                !values
+                    .values
+                    .iter()
+                    .any(|(valid, _)| valid.split(':').next() == Some(key))
+            })?;
+            Some(format!(
+                "Invalid value for '{}': '{}' (E474)",
+                option.name, invalid
+            ))
+        }
+    }
+}
+
+/// Whether a value is a number as `:set` reads it (decimal, or hex with 0x)
+fn is_number(value: &str) -> bool {
+    let digits = value.strip_prefix('-').unwrap_or(value);
+    match digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        Some(hex) => !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()),
+        None => !digits.is_empty() && digits.chars().all(|c| c.is_ascii_digit()),
+    }
+}
+
+fn warning(node: &Node, message: String, code: &str) -> Diagnostic {
+    let start = node.start_position();
+    let end = node.end_position();
+    Diagnostic {
+        range: Range {
+            start: Position {
+                line: start.row as u32,
+                character: start.column as u32,
+            },
+            end: Position {
+                line: end.row as u32,
+                character: end.column as u32,
+            },
+        },
+        severity: Some(DiagnosticSeverity::WARNING),
+        source: Some("hjkls".to_string()),
+        message,
+        code: Some(NumberOrString::String(code.to_string())),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn option_warnings(code: &str) -> Vec<(String, String)> {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&tree_sitter_vim::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        collect_option_warnings(&tree, code)
+            .into_iter()
+            .map(|d| {
+                let Some(NumberOrString::String(code)) = d.code else {
+                    unreachable!()
+                };
+                (code, d.message)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_options() {
+        let code = "set number ts=4 nowrap sw& cpo&vim\nset t_Co=256\nset cot+=menu,popup\nset bg=dark tw=0x10\nsetlocal invlist\nset ts?\nset wildmode=list:longest,full\nset ff=$FF\nset bs=2\nau BufEnter * setlocal fdm=marker|if 1|endif\n";
+        assert_eq!(option_warnings(code), vec![]);
+    }
+
+    #[test]
+    fn test_unknown_option() {
+        let warnings = option_warnings("set foobar\n");
+        assert_eq!(
+            warnings,
+            vec![(
+                "hjkls/unknown_option".to_string(),
+                "Unknown option: 'foobar' (E518)".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_invalid_option_value() {
+        let code = "set number=1\nset ts=abc\nset nots\nset bg=blue\nset cot+=menu,foo\n";
+        let messages: Vec<String> = option_warnings(code)
+            .into_iter()
+            .map(|(code, message)| {
+                assert_eq!(code, "hjkls/invalid_option_value");
+                message
+            })
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "'number' is a boolean option and takes no value: use `set number` or `set nonumber` (E474)",
+                "'tabstop' takes a number, got 'abc' (E521)",
+                "'tabstop' is a number option: `no`, `inv` and `!` only apply to boolean options (E474)",
+                "Invalid value for 'background': 'blue' (E474)",
+                "Invalid value for 'completeopt': 'foo' (E474)",
+            ]
+        );
+    }
+}
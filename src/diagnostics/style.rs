@@ -3,9 +3,15 @@
 //! These rules suggest improvements for code style. They don't indicate bugs
 //! but help maintain consistency and readability.
 
-use tower_lsp_server::ls_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+use tower_lsp_server::ls_types::{
+    Diagnostic, DiagnosticSeverity, DiagnosticTag, NumberOrString, Position, Range,
+};
 use tree_sitter::Tree;
 
+use crate::builtins::{Deprecation, DeprecationKind};
+use crate::vim9;
+use crate::vim9::typecheck::has_top_level_operator;
+
 /// Collect all style hints from the syntax tree
 pub fn collect_style_hints(tree: &Tree, source: &str) -> Vec<Diagnostic> {
     let mut diagnostics = Vec::new();
@@ -13,6 +19,7 @@ pub fn collect_style_hints(tree: &Tree, source: &str) -> Vec<Diagnostic> {
 
     // double_dot: prefer `..` over `.` for string concatenation
     collect_double_dot_hints_recursive(&root, source, &mut diagnostics);
+    collect_vim9_double_dot_hints(source, &mut diagnostics);
 
     // function_bang: s: functions don't need `!`
     collect_function_bang_hints_recursive(&root, source, &mut diagnostics);
@@ -50,27 +57,17 @@ fn collect_double_dot_hints_recursive(
             let start = node.start_position();
             let end = node.end_position();
             let text = node.utf8_text(source.as_bytes()).unwrap_or(".");
-
-            diagnostics.push(Diagnostic {
-                range: Range {
-                    start: Position {
-                        line: start.row as u32,
-                        character: start.column as u32,
-                    },
-                    end: Position {
-                        line: end.row as u32,
-                        character: end.column as u32,
-                    },
+            let range = Range {
+                start: Position {
+                    line: start.row as u32,
+                    character: start.column as u32,
                 },
-                severity: Some(DiagnosticSeverity::HINT),
-                source: Some("hjkls".to_string()),
-                message: format!(
-                    "Style: '{}' uses `.` for string concatenation. Use `..` instead. In Vim9 script, `..` is required.",
-                    text.trim()
-                ),
-                code: Some(NumberOrString::String("hjkls/double_dot".to_string())),
-                ..Default::default()
-            });
+                end: Position {
+                    line: end.row as u32,
+                    character: end.column as u32,
+                },
+            };
+            diagnostics.push(double_dot_hint(range, text));
         }
     }
 
@@ -81,6 +78,50 @@ fn collect_double_dot_hints_recursive(
     }
 }
 
+/// Collect hints for `.` concatenation in Vim9 `var`, `const` and `final`
+/// initializers, which the parser doesn't understand (see `crate::vim9`)
+///
+/// Only a `.` with white space around it concatenates: `dict.key` and `1.5`
+/// are left alone.
+fn collect_vim9_double_dot_hints(source: &str, diagnostics: &mut Vec<Diagnostic>) {
+    if !vim9::is_vim9script(source) {
+        return;
+    }
+    for initializer in vim9::parse_initializers(source) {
+        if !has_top_level_operator(&initializer.expr, ".") {
+            continue;
+        }
+        let range = Range {
+            start: Position {
+                line: initializer.line as u32,
+                character: initializer.start_col as u32,
+            },
+            end: Position {
+                line: initializer.line as u32,
+                character: initializer.end_col as u32,
+            },
+        };
+        diagnostics.push(double_dot_hint(range, &initializer.expr));
+    }
+}
+
+/// The `double_dot` hint of a concatenation
+fn double_dot_hint(range: Range, text: &str) -> Diagnostic {
+    Diagnostic {
+        range,
+        severity: Some(DiagnosticSeverity::HINT),
+        source: Some("hjkls".to_string()),
+        message: format!(
+            "Style: '{}' uses `.` for string concatenation. Use `..` instead. In Vim9 script, `..` is required.",
+            text.trim()
+        ),
+        code: Some(NumberOrString::String("hjkls/double_dot".to_string())),
+        tags: Deprecation::find(DeprecationKind::Operator, ".")
+            .map(|_| vec![DiagnosticTag::DEPRECATED]),
+        ..Default::default()
+    }
+}
+
 /// Collect hints for `function!` with `s:` scope (bang is unnecessary)
 fn collect_function_bang_hints_recursive(
     node: &tree_sitter::Node,
@@ -637,6 +678,34 @@ mod tests {
         assert_eq!(normalize_key_notation("<x>"), None);
     }
 
+    #[test]
+    fn test_double_dot_hint() {
+        let double_dots = |code: &str| -> Vec<(u32, u32)> {
+            let mut parser = tree_sitter::Parser::new();
+            parser.set_language(&tree_sitter_vim::language()).unwrap();
+            let tree = parser.parse(code, None).unwrap();
+            collect_style_hints(&tree, code)
+                .into_iter()
+                .filter(|d| d.code == Some(NumberOrString::String("hjkls/double_dot".into())))
+                .map(|d| (d.range.start.line, d.range.start.character))
+                .collect()
+        };
+
+        assert_eq!(
+            double_dots("let s = 'a' . 'b'\nlet t = 'a' .. 'b'\n"),
+            vec![(0, 8)]
+        );
+
+        // Vim9 declarations, which the parser reads as unknown commands
+        let code = "vim9script\n\
+                    var t = 'a' . 'b'\n\
+                    const u: string = x . y # comment\n\
+                    final w = 'a' .. 'b'\n\
+                    var n = 1.5\n\
+                    var z = dict.key\n";
+        assert_eq!(double_dots(code), vec![(1, 8), (2, 18)]);
+    }
+
     #[test]
     fn test_get_noremap_equivalent() {
         assert_eq!(get_noremap_equivalent("nmap"), Some("nnoremap"));
@@ -0,0 +1,115 @@
+//! Builtin variable checks based on the variable metadata (DiagnosticSeverity::WARNING)
+//!
+//! - `readonly_variable`: assignment to a read-only `v:` variable (E46)
+
+use tower_lsp_server::ls_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+use tree_sitter::{Node, Tree};
+
+use crate::builtins::BUILTIN_VARIABLES;
+
+/// Collect all builtin variable warnings from the syntax tree
+pub fn collect_variable_warnings(tree: &Tree, source: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    collect_readonly_warnings_recursive(&tree.root_node(), source, &mut diagnostics);
+    diagnostics
+}
+
+/// Collect warnings for `:let` and `:const` assigning read-only `v:` variables
+fn collect_readonly_warnings_recursive(
+    node: &Node,
+    source: &str,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let target = Some(node)
+        .filter(|node| matches!(node.kind(), "let_statement" | "const_statement"))
+        .and_then(|node| node.named_child(0));
+    if let Some(target) = target {
+        // `let [a, b] = ...` assigns each item of the list
+        let targets = if target.kind() == "list_assignment" {
+            let mut cursor = target.walk();
+            target.named_children(&mut cursor).collect()
+        } else {
+            vec![target]
+        };
+        for target in targets {
+            check_target(&target, source, diagnostics);
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_readonly_warnings_recursive(&child, source, diagnostics);
+    }
+}
+
+/// Check one assigned variable (items of a `v:` List or Dictionary are not checked)
+fn check_target(target: &Node, source: &str, diagnostics: &mut Vec<Diagnostic>) {
+    if target.kind() != "scoped_identifier" {
+        return;
+    }
+    let Ok(name) = target.utf8_text(source.as_bytes()) else {
+        return;
+    };
+    if !BUILTIN_VARIABLES
+        .iter()
+        .any(|var| var.readonly && var.name == name)
+    {
+        return;
+    }
+    let start = target.start_position();
+    let end = target.end_position();
+    diagnostics.push(Diagnostic {
+        range: Range {
+            start: Position {
+                line: start.row as u32,
+                character: start.column as u32,
+            },
+            end: Position {
+                line: end.row as u32,
+                character: end.column as u32,
+            },
+        },
+        severity: Some(DiagnosticSeverity::WARNING),
+        source: Some("hjkls".to_string()),
+        message: format!("`{}` is read-only (E46)", name),
+        code: Some(NumberOrString::String(
+            "hjkls/readonly_variable".to_string(),
+        )),
+        ..Default::default()
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn variable_warnings(code: &str) -> Vec<Diagnostic> {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&tree_sitter_vim::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        collect_variable_warnings(&tree, code)
+    }
+
+    #[test]
+    fn test_readonly_variable() {
+        let diagnostics =
+            variable_warnings("let v:count = 1\nlet [v:version, x] = [1, 2]\nconst v:true = 0\n");
+        let messages: Vec<&str> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            vec![
+                "`v:count` is read-only (E46)",
+                "`v:version` is read-only (E46)",
+                "`v:true` is read-only (E46)",
+            ]
+        );
+        assert_eq!(diagnostics[1].range.start.character, 5);
+        assert_eq!(diagnostics[1].range.end.character, 14);
+    }
+
+    #[test]
+    fn test_writable_variable() {
+        let code = "let v:errmsg = ''\nlet v:errors += ['x']\nlet v:colornames['x'] = '#000000'\nlet v:char = 'x'\nlet count = v:count\n";
+        assert!(variable_warnings(code).is_empty());
+    }
+}
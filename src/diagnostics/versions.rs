@@ -0,0 +1,389 @@
+//! Version guard checks based on the versions that added builtins
+//! (DiagnosticSeverity::WARNING)
+//!
+//! - `version_guard`: a builtin function, option or event newer than the
+//!   version the script checks for with `has('patch-…')`, `has('nvim-…')` or
+//!   `v:version`
+//!
+//! Scripts without a version check are not checked: they don't say which
+//! versions they support.
+
+use tower_lsp_server::ls_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+use tree_sitter::{Node, Tree};
+
+use crate::builtins::{AutocmdEvent, BuiltinFunction, BuiltinOption, Since, parse_version};
+
+type Version = (u32, u32, u32);
+
+/// Versions a part of a script makes sure of
+#[derive(Clone, Default)]
+struct Guard {
+    /// Minimum Vim version and the check written in the script
+    vim: Option<(Version, String)>,
+    /// Minimum Neovim version and the check written in the script
+    neovim: Option<(Version, String)>,
+    /// Inside `if has('nvim')`, where Vim versions don't matter
+    in_neovim: bool,
+    /// Names checked with `exists()` (`*func`, `+option`, `##Event`)
+    checked: Vec<String>,
+}
+
+impl Guard {
+    /// Combine with the guard of a condition that holds too
+    fn and(&mut self, other: Guard) {
+        if other.vim.as_ref().map(|(v, _)| v) > self.vim.as_ref().map(|(v, _)| v) {
+            self.vim = other.vim;
+        }
+        if other.neovim.as_ref().map(|(v, _)| v) > self.neovim.as_ref().map(|(v, _)| v) {
+            self.neovim = other.neovim;
+        }
+        self.in_neovim |= other.in_neovim;
+        self.checked.extend(other.checked);
+    }
+}
+
+/// Collect all version guard warnings from the syntax tree
+pub fn collect_version_warnings(tree: &Tree, source: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    walk(
+        &tree.root_node(),
+        source,
+        Guard::default(),
+        &mut diagnostics,
+    );
+    diagnostics
+}
+
+/// Check the statements of a block in order: `if !has('patch-…') | finish |
+/// endif` guards the statements after it
+fn walk(node: &Node, source: &str, mut guard: Guard, diagnostics: &mut Vec<Diagnostic>) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "if_statement" => {
+                check_if(&child, source, &guard, diagnostics);
+                if let Some(condition) = child
+                    .child_by_field_name("condition")
+                    .filter(|_| ends_script(&child, source))
+                {
+                    guard.and(negated_guard(&condition, source));
+                }
+            }
+            _ => {
+                check_node(&child, source, &guard, diagnostics);
+                walk(&child, source, guard.clone(), diagnostics);
+            }
+        }
+    }
+}
+
+/// Check an `if` statement: its condition holds in its body
+fn check_if(node: &Node, source: &str, guard: &Guard, diagnostics: &mut Vec<Diagnostic>) {
+    let mut body_guard = guard.clone();
+    if let Some(condition) = node.child_by_field_name("condition") {
+        check_node(&condition, source, guard, diagnostics);
+        walk(&condition, source, guard.clone(), diagnostics);
+        body_guard.and(condition_guard(&condition, source));
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "body" => walk(&child, source, body_guard.clone(), diagnostics),
+            "elseif_statement" => check_if(&child, source, guard, diagnostics),
+            "else_statement" => walk(&child, source, guard.clone(), diagnostics),
+            _ => {}
+        }
+    }
+}
+
+/// Whether an `if` body ends the script (`finish`)
+fn ends_script(node: &Node, source: &str) -> bool {
+    let mut cursor = node.walk();
+    let Some(body) = node.children(&mut cursor).find(|c| c.kind() == "body") else {
+        return false;
+    };
+    let mut cursor = body.walk();
+    body.children(&mut cursor).any(|statement| {
+        statement.kind() == "unknown_builtin_statement"
+            && statement
+                .utf8_text(source.as_bytes())
+                .is_ok_and(|text| text.trim() == "finish" || text.trim() == "fini")
+    })
+}
+
+/// The guard of a condition that holds
+fn condition_guard(node: &Node, source: &str) -> Guard {
+    let mut guard = Guard::default();
+    match node.kind() {
+        "binary_operation" => {
+            let (Some(left), Some(right)) = (
+                node.child_by_field_name("left"),
+                node.child_by_field_name("right"),
+            ) else {
+                return guard;
+            };
+            match operator(node, source) {
+                "&&" => {
+                    guard.and(condition_guard(&left, source));
+                    guard.and(condition_guard(&right, source));
+                }
+                op => guard.vim = version_comparison(&left, op, &right, source, false),
+            }
+        }
+        "call_expression" => match call_argument(node, source) {
+            Some(("has", "nvim")) => guard.in_neovim = true,
+            Some(("has", feature)) => {
+                if let Some(version) = feature.strip_prefix("patch-") {
+                    guard.vim = Some((parse_version(version), text(node, source)));
+                } else if let Some(version) = feature.strip_prefix("nvim-") {
+                    guard.neovim = Some((parse_version(version), text(node, source)));
+                }
+            }
+            Some(("exists", name)) => guard
+                .checked
+                .push(name.trim_start_matches(['*', '+', '&', '#']).to_string()),
+            _ => {}
+        },
+        "parenthesized_expression" => {
+            if let Some(inner) = node.named_child(0) {
+                guard = condition_guard(&inner, source);
+            }
+        }
+        _ => {}
+    }
+    guard
+}
+
+/// The guard of a condition that doesn't hold: `!has('patch-…')` or
+/// `v:version < 801`, joined with `||`
+fn negated_guard(node: &Node, source: &str) -> Guard {
+    let mut guard = Guard::default();
+    match node.kind() {
+        "unary_operation" if operator(node, source) == "!" => {
+            if let Some(operand) = node.named_child(0) {
+                let positive = condition_guard(&operand, source);
+                guard.vim = positive.vim;
+                guard.neovim = positive.neovim;
+            }
+        }
+        "binary_operation" => {
+            let (Some(left), Some(right)) = (
+                node.child_by_field_name("left"),
+                node.child_by_field_name("right"),
+            ) else {
+                return guard;
+            };
+            match operator(node, source) {
+                "||" => {
+                    guard.and(negated_guard(&left, source));
+                    guard.and(negated_guard(&right, source));
+                }
+                op => guard.vim = version_comparison(&left, op, &right, source, true),
+            }
+        }
+        "parenthesized_expression" => {
+            if let Some(inner) = node.named_child(0) {
+                guard = negated_guard(&inner, source);
+            }
+        }
+        _ => {}
+    }
+    guard
+}
+
+/// The minimum Vim version of `v:version >= 801` (or of `v:version < 801`
+/// when `negated`)
+fn version_comparison(
+    left: &Node,
+    op: &str,
+    right: &Node,
+    source: &str,
+    negated: bool,
+) -> Option<(Version, String)> {
+    if text(left, source) != "v:version" {
+        return None;
+    }
+    let number: u32 = text(right, source).parse().ok()?;
+    let minimum = match (op, negated) {
+        (">=", false) | ("<", true) => number,
+        (">", false) | ("<=", true) => number + 1,
+        _ => return None,
+    };
+    let condition = if negated {
+        format!("v:version >= {}", minimum)
+    } else {
+        format!("v:version {} {}", op, number)
+    };
+    Some(((minimum / 100, minimum % 100, 0), condition))
+}
+
+/// Check a builtin function call, option or autocmd event
+fn check_node(node: &Node, source: &str, guard: &Guard, diagnostics: &mut Vec<Diagnostic>) {
+    let (name_node, item, since) = match node.kind() {
+        "call_expression" => {
+            let Some(function) = node
+                .child_by_field_name("function")
+                .filter(|f| f.kind() == "identifier")
+            else {
+                return;
+            };
+            let Some(builtin) = BuiltinFunction::find(&text(&function, source)) else {
+                return;
+            };
+            (function, format!("{}()", builtin.name), builtin.since())
+        }
+        "option_name" => {
+            let Some(option) = BuiltinOption::find(&text(node, source)) else {
+                return;
+            };
+            (*node, format!("'{}'", option.name), option.since())
+        }
+        "au_event" => {
+            let Some(event) = AutocmdEvent::find(&text(node, source)) else {
+                return;
+            };
+            (*node, event.name.to_string(), event.since())
+        }
+        _ => return,
+    };
+    let Some(since) = since else {
+        return;
+    };
+    let name = item.trim_matches(['(', ')', '\'']);
+    if guard.checked.iter().any(|checked| checked == name) {
+        return;
+    }
+    if let Some(message) = missing_version(&item, since, guard) {
+        diagnostics.push(warning(&name_node, message));
+    }
+}
+
+/// Describe the version an item needs beyond the guard, if any
+fn missing_version(item: &str, since: &Since, guard: &Guard) -> Option<String> {
+    if let (Some(required), Some((minimum, condition))) = (since.vim, &guard.vim)
+        && !guard.in_neovim
+        && parse_version(required) > *minimum
+    {
+        let required = if required.matches('.').count() == 2 {
+            format!("patch-{}", required)
+        } else {
+            format!("Vim {}", required)
+        };
+        return Some(format!(
+            "`{}` requires {} but this script only guards {}",
+            item, required, condition
+        ));
+    }
+    if let (Some(required), Some((minimum, condition))) = (since.neovim, &guard.neovim)
+        && parse_version(required) > *minimum
+    {
+        return Some(format!(
+            "`{}` requires Neovim {} but this script only guards {}",
+            item, required, condition
+        ));
+    }
+    None
+}
+
+/// The function name and string argument of a call like `has('patch-8.2.1')`
+fn call_argument<'a>(node: &Node, source: &'a str) -> Option<(&'a str, &'a str)> {
+    let function = node.child_by_field_name("function")?;
+    let argument = node
+        .named_child(1)
+        .filter(|a| a.kind() == "string_literal")?;
+    let name = function.utf8_text(source.as_bytes()).ok()?;
+    let value = argument.utf8_text(source.as_bytes()).ok()?;
+    Some((name, value.get(1..value.len().saturating_sub(1))?))
+}
+
+fn operator<'a>(node: &Node, source: &'a str) -> &'a str {
+    let mut cursor = node.walk();
+    node.children(&mut cursor)
+        .find(|c| !c.is_named())
+        .and_then(|c| c.utf8_text(source.as_bytes()).ok())
+        .unwrap_or("")
+}
+
+fn text(node: &Node, source: &str) -> String {
+    node.utf8_text(source.as_bytes()).unwrap_or("").to_string()
+}
+
+fn warning(node: &Node, message: String) -> Diagnostic {
+    let start = node.start_position();
+    let end = node.end_position();
+    Diagnostic {
+        range: Range {
+            start: Position {
+                line: start.row as u32,
+                character: start.column as u32,
+            },
+            end: Position {
+                line: end.row as u32,
+                character: end.column as u32,
+            },
+        },
+        severity: Some(DiagnosticSeverity::WARNING),
+        source: Some("hjkls".to_string()),
+        message,
+        code: Some(NumberOrString::String("hjkls/version_guard".to_string())),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn version_warnings(code: &str) -> Vec<String> {
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&tree_sitter_vim::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        collect_version_warnings(&tree, code)
+            .into_iter()
+            .map(|d| d.message)
+            .collect()
+    }
+
+    #[test]
+    fn test_guard_too_old() {
+        let code = "if !has('patch-8.1.0')\n  finish\nendif\necho reduce([1], {a, b -> a + b})\n";
+        assert_eq!(
+            version_warnings(code),
+            vec![
+                "`reduce()` requires patch-8.2.0878 but this script only guards has('patch-8.1.0')"
+            ]
+        );
+        let code = "if v:version < 801 | finish | endif\nset wincolor=Normal\n";
+        assert_eq!(
+            version_warnings(code),
+            vec![
+                "`'wincolor'` requires patch-8.1.1391 but this script only guards v:version >= 801"
+            ]
+        );
+        let code = "if has('patch-8.0.0')\n  autocmd TextYankPost * echo\nendif\n";
+        assert_eq!(
+            version_warnings(code),
+            vec![
+                "`TextYankPost` requires patch-8.0.1394 but this script only guards has('patch-8.0.0')"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_guard_enough() {
+        // No version check
+        assert!(version_warnings("echo reduce([1], {a, b -> a + b})\n").is_empty());
+        // The check covers the function
+        let code = "if !has('patch-8.2.0878') | finish | endif\necho reduce([], {a, b -> a})\n";
+        assert!(version_warnings(code).is_empty());
+        // Checked with exists(), or in a Neovim branch
+        let code =
+            "if has('patch-8.1.0') && exists('*reduce')\n  echo reduce([], {a, b -> a})\nendif\n";
+        assert!(version_warnings(code).is_empty());
+        let code = "if !has('patch-8.1.0') | finish | endif\nif has('nvim')\n  echo reduce([], {a, b -> a})\nendif\n";
+        assert!(version_warnings(code).is_empty());
+        // The else branch isn't guarded
+        let code = "if has('patch-8.1.0')\nelse\n  echo reduce([], {a, b -> a})\nendif\n";
+        assert!(version_warnings(code).is_empty());
+    }
+}
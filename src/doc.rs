@@ -0,0 +1,145 @@
+//! Documentation lookup from the command line: `hjkls doc <name>`
+//!
+//! Prints the entry of a builtin function, option, autocmd event, variable or
+//! Ex command from the bundled database, followed by its help section when
+//! `$VIMRUNTIME` is available.
+
+use crate::backend::Backend;
+use crate::builtins::{
+    AutocmdEvent, BUILTIN_COMMANDS, BUILTIN_VARIABLES, BuiltinFunction, BuiltinOption,
+};
+
+/// A builtin found in the bundled database
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Entry {
+    /// Help tag of the builtin (e.g., "strlen()", "'number'")
+    tag: String,
+    /// First line: the signature or name, with its availability
+    heading: String,
+    description: &'static str,
+}
+
+/// Print the documentation of a builtin
+///
+/// Returns the process exit code: 1 when the name is unknown.
+pub fn run(backend: &Backend, name: &str) -> i32 {
+    let Some(entry) = lookup(name) else {
+        eprintln!("error: no builtin named {name}");
+        return 1;
+    };
+    println!("{}", entry.heading);
+    println!("{}", entry.description);
+    if let Some(section) = backend.help_section(&entry.tag) {
+        println!();
+        println!("{section}");
+    }
+    0
+}
+
+/// Find a builtin by name
+///
+/// The name may be written like its help tag to pick a kind: `strlen()`,
+/// `'number'`, `:echo`. Otherwise functions are looked up first, then
+/// options, events, and variables (`v:` may be omitted).
+fn lookup(name: &str) -> Option<Entry> {
+    if let Some(command) = name.strip_prefix(':') {
+        return lookup_command(command);
+    }
+    if let Some(option) = name
+        .strip_prefix('\'')
+        .and_then(|name| name.strip_suffix('\''))
+    {
+        return lookup_option(option);
+    }
+    if let Some(function) = name.strip_suffix("()") {
+        return lookup_function(function);
+    }
+    lookup_function(name)
+        .or_else(|| lookup_option(name))
+        .or_else(|| lookup_event(name))
+        .or_else(|| lookup_variable(name))
+}
+
+fn lookup_function(name: &str) -> Option<Entry> {
+    let function = BuiltinFunction::find(name)?;
+    Some(Entry {
+        tag: format!("{}()", function.name),
+        heading: format!(
+            "{}{}",
+            function.signature,
+            function.availability.label_suffix()
+        ),
+        description: function.description,
+    })
+}
+
+fn lookup_option(name: &str) -> Option<Entry> {
+    let option = BuiltinOption::find(name)?;
+    let short = option
+        .short
+        .map(|short| format!(" '{}'", short))
+        .unwrap_or_default();
+    Some(Entry {
+        tag: format!("'{}'", option.name),
+        heading: format!(
+            "'{}'{}{}",
+            option.name,
+            short,
+            option.availability.label_suffix()
+        ),
+        description: option.description,
+    })
+}
+
+fn lookup_event(name: &str) -> Option<Entry> {
+    let event = AutocmdEvent::find(name)?;
+    Some(Entry {
+        tag: event.name.to_string(),
+        heading: format!("{}{}", event.name, event.availability.label_suffix()),
+        description: event.description,
+    })
+}
+
+fn lookup_variable(name: &str) -> Option<Entry> {
+    let name = if name.starts_with("v:") {
+        name.to_string()
+    } else {
+        format!("v:{}", name)
+    };
+    let variable = BUILTIN_VARIABLES.iter().find(|v| v.name == name)?;
+    Some(Entry {
+        tag: variable.name.to_string(),
+        heading: format!("{}{}", variable.name, variable.availability.label_suffix()),
+        description: variable.description,
+    })
+}
+
+fn lookup_command(name: &str) -> Option<Entry> {
+    let command = BUILTIN_COMMANDS.iter().find(|cmd| cmd.matches(name))?;
+    Some(Entry {
+        tag: format!(":{}", command.name),
+        heading: format!(":{}{}", command.name, command.availability.label_suffix()),
+        description: command.description,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lookup() {
+        let entry = lookup("strlen").unwrap();
+        assert_eq!(entry.tag, "strlen()");
+        assert_eq!(entry.heading, "strlen({string})");
+        assert_eq!(lookup("strlen()"), Some(entry));
+
+        assert_eq!(lookup("'nu'").unwrap().tag, "'number'");
+        assert_eq!(lookup("number").unwrap().heading, "'number' 'nu'");
+        assert_eq!(lookup("bufenter").unwrap().tag, "BufEnter");
+        assert_eq!(lookup("count").unwrap().tag, "count()");
+        assert_eq!(lookup("v:count").unwrap().tag, "v:count");
+        assert_eq!(lookup(":ec").unwrap().tag, ":echo");
+        assert_eq!(lookup("no_such_builtin"), None);
+    }
+}
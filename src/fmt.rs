@@ -0,0 +1,120 @@
+//! Formatting from the command line: `hjkls format [paths]`
+//!
+//! Uses the same formatter and `[format]` configuration as
+//! `textDocument/formatting`. Without paths, the content is read from stdin.
+
+use std::io::{Read, Write};
+use std::path::PathBuf;
+
+use tree_sitter::Parser;
+
+use crate::backend::Backend;
+use crate::check;
+use crate::config::FormatConfig;
+use crate::formatter;
+use crate::modeline::Modeline;
+
+/// What to do with the formatted content
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FormatMode {
+    /// Print the formatted content
+    #[default]
+    Print,
+    /// Only report the files that need formatting
+    Check,
+    /// Write the formatted content back to the files
+    Write,
+}
+
+/// Format the given files and directories, or stdin when there are none
+///
+/// Returns the process exit code: 1 when a file needs formatting in check
+/// mode (or a path couldn't be read or written), 0 otherwise.
+pub fn run(backend: &Backend, paths: &[PathBuf], mode: FormatMode) -> i32 {
+    let root = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    backend.set_workspace_root(root);
+    let config = backend.format_config();
+
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_vim::language())
+        .expect("Error loading vim grammar");
+
+    if paths.is_empty() {
+        let mut source = String::new();
+        if let Err(e) = std::io::stdin().read_to_string(&mut source) {
+            eprintln!("error: stdin: {e}");
+            return 1;
+        }
+        let formatted = format_source(&mut parser, &source, &config);
+        return match mode {
+            FormatMode::Check => i32::from(formatted != source),
+            FormatMode::Print | FormatMode::Write => {
+                let _ = std::io::stdout().write_all(formatted.as_bytes());
+                0
+            }
+        };
+    }
+
+    let (files, mut failed) = check::collect_files(backend, paths);
+    let mut unformatted = false;
+    for path in files {
+        let source = match std::fs::read_to_string(&path) {
+            Ok(source) => source,
+            Err(e) => {
+                eprintln!("error: {}: {}", path.display(), e);
+                failed = true;
+                continue;
+            }
+        };
+        let formatted = format_source(&mut parser, &source, &config);
+        match mode {
+            FormatMode::Print => print!("{formatted}"),
+            FormatMode::Check => {
+                if formatted != source {
+                    println!("{}", path.display());
+                    unformatted = true;
+                }
+            }
+            FormatMode::Write => {
+                if formatted != source {
+                    if let Err(e) = std::fs::write(&path, formatted) {
+                        eprintln!("error: {}: {}", path.display(), e);
+                        failed = true;
+                    }
+                }
+            }
+        }
+    }
+    i32::from(failed || unformatted)
+}
+
+/// Format Vim script source code, keeping it as-is when it can't be parsed
+///
+/// The modeline of the source overrides the indentation settings of `config`.
+fn format_source(parser: &mut Parser, source: &str, config: &FormatConfig) -> String {
+    let mut config = config.clone();
+    Modeline::parse(source).apply_format(&mut config);
+    match parser.parse(crate::heredoc::mask_heredocs(source).as_ref(), None) {
+        Some(tree) => formatter::format_to_string(source, &tree, &config),
+        None => source.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_source() {
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_vim::language()).unwrap();
+        let config = FormatConfig::default();
+        assert_eq!(
+            format_source(&mut parser, "if 1\nlet x=1\nendif", &config),
+            "if 1\n  let x = 1\nendif\n"
+        );
+        let formatted = "echo 1\n";
+        assert_eq!(format_source(&mut parser, formatted, &config), formatted);
+    }
+}
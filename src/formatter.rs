@@ -9,6 +9,10 @@
 //! - Block indentation (function/if/for/while/try/augroup)
 //! - Line continuation indentation (\ at end of line)
 //!
+//! The lines of heredocs (`let x =<< END`, `lua << EOF`, `python3 << EOF`)
+//! are left as-is: they are text or embedded code, and their end marker must
+//! stay in place.
+//!
 //! # Example
 //!
 //! ```ignore
@@ -31,8 +35,12 @@ mod spaces;
 
 pub use crate::config::FormatConfig;
 
+use std::ops::RangeInclusive;
+
 use tower_lsp_server::ls_types::TextEdit;
-use tree_sitter::Tree;
+use tree_sitter::{Node, Tree};
+
+use crate::heredoc;
 
 /// Format Vim script source code and return text edits
 ///
@@ -83,6 +91,14 @@ pub fn format(source: &str, tree: &Tree, config: &FormatConfig) -> Vec<TextEdit>
     // Compute line-level edits (trailing whitespace, final newline)
     edits.extend(rules::compute_line_edits(source, config));
 
+    // Leave heredoc lines alone
+    let heredocs = heredoc_lines(source, tree);
+    edits.retain(|edit| {
+        !heredocs.iter().any(|lines| {
+            lines.contains(&edit.range.start.line) || lines.contains(&edit.range.end.line)
+        })
+    });
+
     // Sort edits by position (in reverse order for correct application)
     // Note: This ordering is important for conflict resolution between modules.
     // When spaces and operators modules produce overlapping edits, the sort ensures
@@ -103,6 +119,36 @@ pub fn format(source: &str, tree: &Tree, config: &FormatConfig) -> Vec<TextEdit>
     edits
 }
 
+/// Lines of heredoc bodies and end markers, after the line starting the heredoc
+fn heredoc_lines(source: &str, tree: &Tree) -> Vec<RangeInclusive<u32>> {
+    fn collect(node: &Node, lines: &mut Vec<RangeInclusive<u32>>) {
+        if node.kind() == "heredoc" {
+            let start = node.start_position().row as u32;
+            let end = node.end_position().row as u32;
+            if end > start {
+                lines.push(start + 1..=end);
+            }
+            return;
+        }
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            collect(&child, lines);
+        }
+    }
+
+    // `let` heredocs
+    let mut lines = Vec::new();
+    collect(&tree.root_node(), &mut lines);
+    // Heredocs of embedded code, some of them are masked out of the tree
+    lines.extend(
+        heredoc::find_heredocs(source)
+            .into_iter()
+            .filter(|embedded| embedded.end_line > embedded.start_line)
+            .map(|embedded| embedded.start_line as u32 + 1..=embedded.end_line as u32),
+    );
+    lines
+}
+
 /// Format Vim script source code and return the formatted string
 ///
 /// This is a convenience function that applies all edits and returns
@@ -117,7 +163,6 @@ pub fn format(source: &str, tree: &Tree, config: &FormatConfig) -> Vec<TextEdit>
 /// # Returns
 ///
 /// The formatted source code as a string
-#[cfg(test)]
 pub fn format_to_string(source: &str, tree: &Tree, config: &FormatConfig) -> String {
     let edits = format(source, tree, config);
     apply_edits(source, &edits)
@@ -126,7 +171,6 @@ pub fn format_to_string(source: &str, tree: &Tree, config: &FormatConfig) -> Str
 /// Apply text edits to source code
 ///
 /// Edits are expected to be sorted in reverse order (last position first)
-#[cfg(test)]
 fn apply_edits(source: &str, edits: &[TextEdit]) -> String {
     let mut result = source.to_string();
 
@@ -143,7 +187,6 @@ fn apply_edits(source: &str, edits: &[TextEdit]) -> String {
 }
 
 /// Convert LSP position to byte offset
-#[cfg(test)]
 fn position_to_offset(
     source: &str,
     position: tower_lsp_server::ls_types::Position,
@@ -237,6 +280,25 @@ mod tests {
         assert!(result.contains("\" This is a comment"));
     }
 
+    #[test]
+    fn test_format_preserves_heredocs() {
+        let source = "function! Test()\nlet x =<< END\n a\nEND\nlua << EOF\n  local y = { 1,2 }\nEOF\nlua << trim EOF\n  print(1)  \n  EOF\npython3 << EOF\nprint( 2 )\nEOF\nendfunction\n";
+        let masked = heredoc::mask_heredocs(source);
+        let mut parser = Parser::new();
+        parser
+            .set_language(&tree_sitter_vim::language())
+            .expect("Error loading vim grammar");
+        let tree = parser.parse(masked.as_ref(), None).unwrap();
+        let config = FormatConfig::default();
+
+        let result = format_to_string(source, &tree, &config);
+
+        assert_eq!(
+            result,
+            "function! Test()\n  let x =<< END\n a\nEND\n  lua << EOF\n  local y = { 1,2 }\nEOF\n  lua << trim EOF\n  print(1)  \n  EOF\n  python3 << EOF\nprint( 2 )\nEOF\nendfunction\n"
+        );
+    }
+
     #[test]
     fn test_format_nested_blocks() {
         let source = "function! Test()\nif a == 1\nlet x = 1\nendif\nendfunction\n";
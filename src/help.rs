@@ -0,0 +1,230 @@
+//! Links to the online Vim/Neovim help, and help sections from `$VIMRUNTIME`
+//! and the `doc/` directories of the workspace
+//!
+//! Help topics are addressed by their tag (e.g., `strlen()`, `:echo`,
+//! `'number'`). Vim's help is linked on vimhelp.org, which needs the help
+//! file containing the tag; Neovim's help has a tag redirect page.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+/// Map from help tag to the help file defining it (from `doc/tags`)
+#[derive(Debug, Clone, Default)]
+pub struct HelpTags {
+    files: HashMap<String, String>,
+}
+
+impl HelpTags {
+    /// Load `$VIMRUNTIME/doc/tags`
+    pub fn load(runtime: &Path) -> Self {
+        std::fs::read_to_string(runtime.join("doc").join("tags"))
+            .map(|content| Self::parse(&content))
+            .unwrap_or_default()
+    }
+
+    /// Parse the content of a `doc/tags` file (`tag<Tab>file<Tab>pattern` lines)
+    pub fn parse(content: &str) -> Self {
+        let files = content
+            .lines()
+            .filter_map(|line| {
+                let mut fields = line.split('\t');
+                Some((fields.next()?.to_string(), fields.next()?.to_string()))
+            })
+            .collect();
+        Self { files }
+    }
+
+    /// Collect the tags defined by the help files (`*.txt`) of a `doc/`
+    /// directory, like `:helptags` does
+    pub fn scan(doc: &Path) -> Self {
+        let Ok(entries) = std::fs::read_dir(doc) else {
+            return Self::default();
+        };
+        let mut paths: Vec<PathBuf> = entries
+            .flatten()
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == "txt"))
+            .collect();
+        paths.sort();
+
+        let mut files = HashMap::new();
+        for path in paths {
+            let (Some(file), Ok(content)) = (
+                path.file_name().and_then(|name| name.to_str()),
+                std::fs::read_to_string(&path),
+            ) else {
+                continue;
+            };
+            for tag in definitions(&content) {
+                files.entry(tag).or_insert_with(|| file.to_string());
+            }
+        }
+        Self { files }
+    }
+
+    /// Get the help file defining a tag
+    pub fn file(&self, tag: &str) -> Option<&str> {
+        self.files.get(tag).map(String::as_str)
+    }
+}
+
+/// Get the tags defined in a help file (`*tag*` words)
+pub fn definitions(content: &str) -> Vec<String> {
+    content
+        .lines()
+        .flat_map(str::split_whitespace)
+        .filter_map(|word| word.strip_prefix('*')?.strip_suffix('*'))
+        .filter(|tag| !tag.is_empty() && !tag.contains(['*', '|']))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Build the URL of a help tag
+///
+/// Neovim help is linked through its tag redirect page; Vim help needs the
+/// help file (e.g., "builtin.txt") to build a vimhelp.org URL.
+pub fn url(tag: &str, file: Option<&str>, neovim: bool) -> Option<String> {
+    if neovim {
+        return Some(format!(
+            "https://neovim.io/doc/user/helptag.html?tag={}",
+            encode(tag)
+        ));
+    }
+    Some(format!(
+        "https://vimhelp.org/{}.html#{}",
+        file?,
+        encode(tag)
+    ))
+}
+
+/// Build a Markdown link to a help tag (e.g., "[`:help strlen()`](...)")
+pub fn markdown_link(tag: &str, url: &str) -> String {
+    format!("[`:help {}`]({})", tag, url)
+}
+
+/// Extract the section of a help file starting at a tag definition (`*tag*`)
+///
+/// The section ends before the next entry (a line ending with another tag
+/// definition) or a `===` separator line.
+pub fn section(content: &str, tag: &str) -> Option<String> {
+    let definition = format!("*{}*", tag);
+    let lines: Vec<&str> = content.lines().collect();
+    let start = lines
+        .iter()
+        .position(|line| line.split_whitespace().any(|word| word == definition))?;
+
+    // Tag lines right after the first one belong to the same entry
+    let mut end = start + 1;
+    while end < lines.len() && defines_tag(lines[end]) {
+        end += 1;
+    }
+    while end < lines.len() && !defines_tag(lines[end]) && !lines[end].starts_with("===") {
+        end += 1;
+    }
+
+    let section = lines[start..end].join("\n");
+    Some(section.trim_end().to_string())
+}
+
+/// Whether a help line ends with a tag definition (e.g., `strlen()  *strlen()*`)
+fn defines_tag(line: &str) -> bool {
+    line.split_whitespace()
+        .next_back()
+        .is_some_and(|word| word.len() > 2 && word.starts_with('*') && word.ends_with('*'))
+}
+
+/// Percent-encode a help tag for use in a URL
+fn encode(tag: &str) -> String {
+    tag.bytes()
+        .map(|b| match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' => (b as char).to_string(),
+            _ => format!("%{:02X}", b),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_tags() {
+        let tags =
+            HelpTags::parse("strlen()\tbuiltin.txt\t/*strlen()*\n:echo\teval.txt\t/*:echo*\n");
+        assert_eq!(tags.file("strlen()"), Some("builtin.txt"));
+        assert_eq!(tags.file(":echo"), Some("eval.txt"));
+        assert_eq!(tags.file("missing"), None);
+    }
+
+    #[test]
+    fn test_definitions() {
+        let content = "\
+*myplugin.txt*\tA plugin
+
+myplugin#open({path})\t\t\t\t*myplugin#open()*
+\t\tOpen {path}. See |myplugin-usage|, a *bold* word and 2 * 3 *.
+";
+        assert_eq!(
+            definitions(content),
+            vec!["myplugin.txt", "myplugin#open()", "bold"]
+        );
+    }
+
+    #[test]
+    fn test_section() {
+        let content = "\
+strlen({string})\t\t\t\t\t*strlen()*
+\t\tThe result is a Number, which is the length of the String
+\t\t{string} in bytes.
+
+\t\tReturn type: |Number|
+
+strpart({src}, {start} [, {len} [, {chars}]])\t\t\t*strpart()*
+\t\tThe result is a String.
+";
+        assert_eq!(
+            section(content, "strlen()").as_deref(),
+            Some(
+                "strlen({string})\t\t\t\t\t*strlen()*\n\t\tThe result is a Number, which is the length of the String\n\t\t{string} in bytes.\n\n\t\tReturn type: |Number|"
+            )
+        );
+        assert_eq!(
+            section(content, "strpart()").as_deref(),
+            Some(
+                "strpart({src}, {start} [, {len} [, {chars}]])\t\t\t*strpart()*\n\t\tThe result is a String."
+            )
+        );
+        assert_eq!(section(content, "missing()"), None);
+
+        // Consecutive tag lines start the same entry
+        let options = "\
+\t\t\t\t\t\t*'number'* *'nu'*
+'number' 'nu'\t\tboolean\t(default off)
+\t\t\tPrint the line number in front of each line.
+\t\t\t\t\t\t*'numberwidth'* *'nuw'*
+";
+        assert_eq!(
+            section(options, "'number'").as_deref(),
+            Some(
+                "\t\t\t\t\t\t*'number'* *'nu'*\n'number' 'nu'\t\tboolean\t(default off)\n\t\t\tPrint the line number in front of each line."
+            )
+        );
+    }
+
+    #[test]
+    fn test_url() {
+        assert_eq!(
+            url("strlen()", Some("builtin.txt"), false).as_deref(),
+            Some("https://vimhelp.org/builtin.txt.html#strlen%28%29")
+        );
+        assert_eq!(
+            url(":echo", None, true).as_deref(),
+            Some("https://neovim.io/doc/user/helptag.html?tag=%3Aecho")
+        );
+        assert_eq!(url(":echo", None, false), None);
+        assert_eq!(
+            markdown_link("'number'", "https://example.com"),
+            "[`:help 'number'`](https://example.com)"
+        );
+    }
+}
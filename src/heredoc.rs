@@ -0,0 +1,232 @@
+//! Heredocs of embedded languages (`lua << EOF`, `python3 << EOF`, ...)
+//!
+//! The lines after `:lua << EOF` up to the end marker are Lua code, and so on
+//! for the other script interfaces. The parser handles the heredocs of
+//! `:lua`, `:python`, `:ruby` and `:perl`, but not the `trim` variant nor the
+//! ones of `:python3` and `:pythonx`. Those are masked before parsing so that
+//! their code doesn't turn into Vim script syntax errors.
+
+use std::borrow::Cow;
+
+/// Script interface commands taking a heredoc, with their language
+/// Reference: :help script-here
+const COMMANDS: &[(&str, &str)] = &[
+    ("lua", "lua"),
+    ("pe", "perl"),
+    ("per", "perl"),
+    ("perl", "perl"),
+    ("py", "python"),
+    ("pyt", "python"),
+    ("pyth", "python"),
+    ("pytho", "python"),
+    ("python", "python"),
+    ("py3", "python"),
+    ("python3", "python"),
+    ("pyx", "python"),
+    ("pythonx", "python"),
+    ("rub", "ruby"),
+    ("ruby", "ruby"),
+];
+
+/// Commands whose heredoc the parser doesn't handle
+const UNPARSED_COMMANDS: &[&str] = &["py3", "python3", "pyx", "pythonx"];
+
+/// A heredoc of embedded code (`lua << EOF`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Heredoc {
+    /// Language of the code ("lua", "perl", "python" or "ruby")
+    pub language: &'static str,
+    /// 0-based line of the command
+    pub start_line: usize,
+    /// 0-based line of the end marker, or the last line when unterminated
+    pub end_line: usize,
+    /// `lua << trim EOF`: the lines and the end marker may be indented
+    pub trim: bool,
+    /// Whether it is masked before parsing
+    pub masked: bool,
+}
+
+impl Heredoc {
+    /// Whether a 0-based line is code of the heredoc (the end marker included)
+    pub fn contains_line(&self, line: usize) -> bool {
+        self.start_line < line && line <= self.end_line
+    }
+}
+
+/// Find the heredocs of embedded code in a script
+pub fn find_heredocs(source: &str) -> Vec<Heredoc> {
+    let lines: Vec<&str> = source.lines().collect();
+    let mut heredocs = Vec::new();
+    let mut row = 0;
+    while row < lines.len() {
+        let Some(header) = parse_header(lines[row]) else {
+            row += 1;
+            continue;
+        };
+        let is_end = |line: &str| {
+            let line = line.trim_end_matches('\r');
+            if header.trim {
+                line.trim_start() == header.marker
+            } else {
+                line == header.marker
+            }
+        };
+        let end_line = (row + 1..lines.len())
+            .find(|&i| is_end(lines[i]))
+            .unwrap_or(lines.len() - 1);
+        heredocs.push(Heredoc {
+            language: header.language,
+            start_line: row,
+            end_line,
+            trim: header.trim,
+            masked: header.trim || UNPARSED_COMMANDS.contains(&header.command),
+        });
+        row = end_line + 1;
+    }
+    heredocs
+}
+
+/// Mask the heredocs the parser can't handle
+///
+/// The `<<` is blanked out so that the command parses as a one-line script
+/// command, and the following lines up to the end marker are blanked out.
+/// Byte offsets don't change, so nodes still point into the original source.
+pub fn mask_heredocs(source: &str) -> Cow<'_, str> {
+    let heredocs: Vec<Heredoc> = find_heredocs(source)
+        .into_iter()
+        .filter(|heredoc| heredoc.masked)
+        .collect();
+    if heredocs.is_empty() {
+        return Cow::Borrowed(source);
+    }
+
+    let mut masked = String::with_capacity(source.len());
+    for (row, line) in source.split_inclusive('\n').enumerate() {
+        let Some(heredoc) = heredocs
+            .iter()
+            .find(|h| (h.start_line..=h.end_line).contains(&row))
+        else {
+            masked.push_str(line);
+            continue;
+        };
+        if row == heredoc.start_line {
+            let operator = parse_header(line).map_or(line.len(), |header| header.operator);
+            masked.push_str(&line[..operator]);
+            masked.push_str("  ");
+            masked.push_str(&line[operator + 2..]);
+        } else {
+            // Keep the line ending, blank out each byte of the rest
+            let content = line.trim_end_matches(['\r', '\n']);
+            masked.extend(std::iter::repeat_n(' ', content.len()));
+            masked.push_str(&line[content.len()..]);
+        }
+    }
+    Cow::Owned(masked)
+}
+
+/// A `{command} << [trim] [{endmarker}]` line
+struct Header<'a> {
+    command: &'a str,
+    language: &'static str,
+    /// Byte offset of `<<`
+    operator: usize,
+    trim: bool,
+    /// End marker, "." when omitted
+    marker: &'a str,
+}
+
+fn parse_header(line: &str) -> Option<Header<'_>> {
+    let command_line = line.trim_start().trim_start_matches(':');
+    let name_len = command_line
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(command_line.len());
+    let command = &command_line[..name_len];
+    let &(_, language) = COMMANDS.iter().find(|(name, _)| *name == command)?;
+    let args = command_line[name_len..].trim_start();
+    if !args.starts_with("<<") {
+        return None;
+    }
+    let operator = line.len() - args.len();
+    let args = args[2..].trim_start();
+    let (trim, marker) = match args.strip_prefix("trim") {
+        Some(after) if after.is_empty() || after.starts_with(char::is_whitespace) => {
+            (true, after.trim())
+        }
+        _ => (false, args.trim()),
+    };
+    Some(Header {
+        command,
+        language,
+        operator,
+        trim,
+        marker: if marker.is_empty() { "." } else { marker },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn heredoc(language: &'static str, lines: (usize, usize), trim: bool) -> Heredoc {
+        Heredoc {
+            language,
+            start_line: lines.0,
+            end_line: lines.1,
+            trim,
+            masked: trim,
+        }
+    }
+
+    #[test]
+    fn test_find_heredocs() {
+        let source = "lua << EOF\nprint(1)\nEOF\nfunction! F() abort\n  lua <<trim END\n    print(2)\n  END\n  lua print(3)\n  ruby <<\n  x = 1\n.\nendfunction\n";
+        assert_eq!(
+            find_heredocs(source),
+            vec![
+                heredoc("lua", (0, 2), false),
+                heredoc("lua", (4, 6), true),
+                heredoc("ruby", (8, 10), false),
+            ]
+        );
+        // An unterminated heredoc runs to the end of the file
+        assert_eq!(
+            find_heredocs("lua << EOF\nprint(1)\n  EOF\n")[0].end_line,
+            2
+        );
+        // `python3` heredocs are masked, `python` ones are not
+        let source = "python3 << EOF\nprint(1)\nEOF\npy << EOF\nprint(2)\nEOF\n";
+        let heredocs = find_heredocs(source);
+        assert_eq!(heredocs.len(), 2);
+        assert!(heredocs.iter().all(|h| h.language == "python"));
+        assert!(heredocs[0].masked);
+        assert!(!heredocs[1].masked);
+        // Not script commands
+        assert!(find_heredocs("luafile << EOF\nlet x =<< EOF\n").is_empty());
+    }
+
+    #[test]
+    fn test_mask_heredocs() {
+        // Heredocs the parser handles are not masked
+        let source = "lua << EOF\nprint(1)\nEOF\n";
+        assert!(matches!(mask_heredocs(source), Cow::Borrowed(_)));
+
+        let source = "function! F() abort\r\n  lua << trim END\r\n    print('é')\r\n  END\r\nendfunction\r\n";
+        let masked = mask_heredocs(source);
+        assert_eq!(
+            masked,
+            "function! F() abort\r\n  lua    trim END\r\n               \r\n     \r\nendfunction\r\n"
+        );
+        assert_eq!(masked.len(), source.len());
+
+        let mut parser = tree_sitter::Parser::new();
+        parser.set_language(&tree_sitter_vim::language()).unwrap();
+        for source in [
+            "function! F() abort\n  lua << trim END\n    print(1)\n  END\nendfunction\n",
+            "function! F() abort\n  python3 << EOF\nx = [1, 'a']\nif x: print(x)\nEOF\nendfunction\n",
+            "py3 << trim EOF\n  import vim\n  EOF\n",
+        ] {
+            let tree = parser.parse(mask_heredocs(source).as_ref(), None).unwrap();
+            assert!(!tree.root_node().has_error(), "{}", source);
+        }
+    }
+}
@@ -0,0 +1,74 @@
+//! hjkls: Vim script Language Server
+//!
+//! Besides the server run by the `hjkls` binary, the crate exposes its
+//! analysis to tools that embed it without speaking LSP: `analyze()` lints a
+//! source and `extract_symbols()` lists its definitions.
+//!
+//! ```
+//! let findings = hjkls::analyze("let l:x = 1\n", &hjkls::AnalyzeOptions::default());
+//! assert_eq!(findings[0].rule.as_deref(), Some("hjkls/scope_violation"));
+//! ```
+
+pub mod analysis;
+mod backend;
+mod bench;
+pub mod builtins;
+mod check;
+mod completion;
+pub mod config;
+mod db;
+pub mod diagnostics;
+mod doc;
+mod fmt;
+mod formatter;
+mod help;
+mod heredoc;
+mod logger;
+mod lua;
+mod modeline;
+mod rules;
+mod runtimepath;
+mod symbol_dump;
+mod symbols;
+mod sync;
+mod tags;
+mod transport;
+mod vim9;
+mod watchdog;
+
+pub use analysis::{AnalyzeOptions, Finding, analyze, extract_symbols};
+pub use symbols::{Symbol, SymbolKind, VimScope};
+
+/// Entry points of the `hjkls` binary, not part of the library API
+#[doc(hidden)]
+pub mod cli {
+    pub use crate::backend::Backend;
+
+    pub mod bench {
+        pub use crate::bench::run;
+    }
+    pub mod check {
+        pub use crate::check::{OutputFormat, run};
+    }
+    pub mod doc {
+        pub use crate::doc::run;
+    }
+    pub mod fmt {
+        pub use crate::fmt::{FormatMode, run};
+    }
+    pub mod logger {
+        pub use crate::logger::init;
+    }
+    pub mod rules {
+        pub use crate::rules::run;
+    }
+    pub mod symbol_dump {
+        pub use crate::symbol_dump::{DumpFormat, run};
+    }
+    pub mod transport {
+        pub use crate::transport::{serve_socket, serve_tcp};
+    }
+    pub mod watchdog {
+        pub use crate::watchdog::{ActivityReader, shutdown, spawn_idle_timeout, watch_client};
+    }
+}
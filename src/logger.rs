@@ -1,16 +1,18 @@
 //! Simple file-based logger for debugging
 //!
-//! Usage: hjkls --log=/path/to/hjkls.log
+//! Usage: hjkls --log=/path/to/hjkls.log, or `log` in `initializationOptions`
 
 use std::fs::OpenOptions;
 use std::io::Write;
-use std::sync::OnceLock;
+use std::sync::RwLock;
 
-static LOG_PATH: OnceLock<Option<String>> = OnceLock::new();
+static LOG_PATH: RwLock<Option<String>> = RwLock::new(None);
 
 /// Initialize the logger with the given path
+///
+/// Called again when the client settings name another log file.
 pub fn init(path: Option<String>) {
-    LOG_PATH.get_or_init(|| path);
+    *LOG_PATH.write().unwrap() = path;
 }
 
 /// Log a message to the file if logging is enabled
@@ -23,7 +25,7 @@ macro_rules! log_debug {
 
 /// Write a log message to the file
 pub fn log(message: &str) {
-    let Some(Some(path)) = LOG_PATH.get() else {
+    let Some(path) = LOG_PATH.read().unwrap().clone() else {
         return;
     };
 
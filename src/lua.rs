@@ -0,0 +1,384 @@
+//! Lua interop for Neovim
+//!
+//! Neovim configs often mix Vim script and Lua: Lua functions are called via
+//! `v:lua.require'mod'.func()` and Lua code is evaluated via `luaeval()`,
+//! which in turn calls back Vim functions via `vim.fn.name()`.
+//! This module discovers Lua modules under `lua/` directories and recognizes
+//! these call sites for completion, hover and goto definition.
+
+use std::path::{Path, PathBuf};
+
+/// A Lua module found under a `lua/` directory
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LuaModule {
+    /// Module name as passed to `require()` (e.g., "foo.bar")
+    pub name: String,
+    /// Path to the module file
+    pub path: PathBuf,
+}
+
+/// A function exported by a Lua module (`function M.name(...)`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LuaFunction {
+    pub name: String,
+    /// Parameter list without parentheses (e.g., "a, b")
+    pub params: String,
+    /// 0-based line of the definition
+    pub line: u32,
+}
+
+/// What is being completed after `v:lua.`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VLuaCompletion<'a> {
+    /// `v:lua.|` -> `require'module'`; the partial text after `v:lua.`
+    Module(&'a str),
+    /// `v:lua.require'module'.|` -> functions of the module
+    Function { module: &'a str, partial: &'a str },
+}
+
+/// How a reference crosses between Vim script and Lua
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LuaBridge {
+    /// `v:lua.require'mod'.func()` or `v:lua.func()`
+    VLua,
+    /// `luaeval("require'mod'.func()")`
+    Luaeval,
+    /// `luaeval("vim.fn.func()")`: a Vim function called from Lua
+    VimFn,
+}
+
+/// A Lua module (and function) referenced from Vim script
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LuaReference {
+    /// Required module, None for global Lua functions (`v:lua.myfunc()`)
+    /// and Vim functions (`vim.fn.func()`)
+    pub module: Option<String>,
+    pub function: Option<String>,
+    pub bridge: LuaBridge,
+    /// Byte column range of the reference in the line
+    pub range: (usize, usize),
+}
+
+/// Get the module name of a Lua file relative to a `lua/` directory
+///
+/// "foo/bar.lua" -> "foo.bar", "foo/init.lua" -> "foo"
+pub fn module_name(relative: &Path) -> Option<String> {
+    if relative.extension()? != "lua" {
+        return None;
+    }
+    let stem = relative.with_extension("");
+    let mut parts = stem
+        .components()
+        .map(|c| c.as_os_str().to_str())
+        .collect::<Option<Vec<_>>>()?;
+    if parts.len() > 1 && parts.last() == Some(&"init") {
+        parts.pop();
+    }
+    Some(parts.join("."))
+}
+
+/// Find all Lua modules under the given `lua/` directories
+pub fn find_modules(lua_dirs: &[PathBuf]) -> Vec<LuaModule> {
+    let mut modules = Vec::new();
+    for dir in lua_dirs {
+        collect_modules(dir, dir, &mut modules);
+    }
+    modules.sort_by(|a, b| a.name.cmp(&b.name));
+    modules.dedup_by(|a, b| a.name == b.name);
+    modules
+}
+
+fn collect_modules(lua_dir: &Path, dir: &Path, modules: &mut Vec<LuaModule>) {
+    let Ok(entries) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in entries.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_modules(lua_dir, &path, modules);
+        } else if let Some(name) = path.strip_prefix(lua_dir).ok().and_then(module_name) {
+            modules.push(LuaModule { name, path });
+        }
+    }
+}
+
+/// Resolve a module name to its file under a `lua/` directory
+pub fn module_path(lua_dir: &Path, name: &str) -> Option<PathBuf> {
+    let relative: PathBuf = name.split('.').collect();
+    let file = lua_dir.join(&relative).with_extension("lua");
+    if file.is_file() {
+        return Some(file);
+    }
+    let init = lua_dir.join(relative).join("init.lua");
+    init.is_file().then_some(init)
+}
+
+/// Extract the functions of a Lua module table
+///
+/// Recognizes `function M.name(...)`, `function M:name(...)` and
+/// `M.name = function(...)`, where `M` is the table returned at the end of
+/// the file (any table when there is no top-level `return`).
+pub fn parse_module_functions(source: &str) -> Vec<LuaFunction> {
+    let returned = source
+        .lines()
+        .rev()
+        .map(str::trim)
+        .find(|line| !line.is_empty() && !line.starts_with("--"))
+        .and_then(|line| line.strip_prefix("return "))
+        .map(str::trim)
+        .filter(|name| is_identifier(name));
+
+    let mut functions = Vec::new();
+    for (line_num, line) in source.lines().enumerate() {
+        let trimmed = line.trim_start();
+        let (table, name, params) = if let Some(rest) = trimmed.strip_prefix("function ") {
+            let Some(open) = rest.find('(') else {
+                continue;
+            };
+            let Some((table, name)) = rest[..open].trim().split_once(['.', ':']) else {
+                continue;
+            };
+            (table, name, &rest[open + 1..])
+        } else if let Some((lhs, rhs)) = trimmed.split_once('=') {
+            let Some(rest) = rhs.trim_start().strip_prefix("function") else {
+                continue;
+            };
+            let Some((table, name)) = lhs.trim().split_once('.') else {
+                continue;
+            };
+            let Some(open) = rest.find('(') else {
+                continue;
+            };
+            (table, name, &rest[open + 1..])
+        } else {
+            continue;
+        };
+
+        if !is_identifier(table) || !is_identifier(name) || returned.is_some_and(|r| r != table) {
+            continue;
+        }
+        let params = params.split(')').next().unwrap_or("").trim();
+        functions.push(LuaFunction {
+            name: name.to_string(),
+            params: params.to_string(),
+            line: line_num as u32,
+        });
+    }
+    functions
+}
+
+/// Determine what is being completed after `v:lua.` (text up to the cursor)
+pub fn v_lua_completion(before_cursor: &str) -> Option<VLuaCompletion<'_>> {
+    let start = before_cursor.rfind("v:lua.")?;
+    let rest = &before_cursor[start + "v:lua.".len()..];
+    if rest.chars().all(is_identifier_char) {
+        return Some(VLuaCompletion::Module(rest));
+    }
+    let (module, after) = parse_require(rest)?;
+    let partial = after.strip_prefix('.')?;
+    partial
+        .chars()
+        .all(is_identifier_char)
+        .then_some(VLuaCompletion::Function { module, partial })
+}
+
+/// Find the Lua reference (`v:lua...` or inside `luaeval(...)`) at a column
+pub fn find_reference(line: &str, col: usize) -> Option<LuaReference> {
+    // v:lua.require'mod'.func / v:lua.func
+    for (start, _) in line.match_indices("v:lua.") {
+        let name_start = start + "v:lua.".len();
+        let rest = &line[name_start..];
+        let (module, function_start, end) = match parse_require(rest) {
+            Some((module, after)) => {
+                let require_end = line.len() - after.len();
+                match after.strip_prefix('.') {
+                    Some(_) => (Some(module), Some(require_end + 1), require_end),
+                    None => (Some(module), None, require_end),
+                }
+            }
+            None => (None, Some(name_start), name_start),
+        };
+        let function_end = function_start.map(|fs| {
+            line[fs..]
+                .find(|c: char| !is_identifier_char(c))
+                .map_or(line.len(), |pos| fs + pos)
+        });
+        let end = function_end.unwrap_or(end).max(name_start);
+        if (start..end).contains(&col) {
+            return Some(LuaReference {
+                module: module.map(str::to_string),
+                function: function_start
+                    .zip(function_end)
+                    .map(|(s, e)| &line[s..e])
+                    .filter(|f| !f.is_empty())
+                    .map(str::to_string),
+                bridge: LuaBridge::VLua,
+                range: (start, end),
+            });
+        }
+    }
+
+    // luaeval("require'mod'.func()")
+    for (start, _) in line.match_indices("luaeval(") {
+        let end = line[start..]
+            .rfind(')')
+            .map_or(line.len(), |pos| start + pos + 1);
+        if !(start..end).contains(&col) {
+            continue;
+        }
+        let body = &line[start..end];
+        // vim.fn.func: the Vim function under the cursor
+        for (fn_start, prefix) in body.match_indices("vim.fn.") {
+            let name_start = start + fn_start + prefix.len();
+            let name_end = line[name_start..]
+                .find(|c: char| !is_identifier_char(c) && c != '#')
+                .map_or(line.len(), |pos| name_start + pos);
+            if name_end > name_start && (start + fn_start..name_end).contains(&col) {
+                return Some(LuaReference {
+                    module: None,
+                    function: Some(line[name_start..name_end].to_string()),
+                    bridge: LuaBridge::VimFn,
+                    range: (start + fn_start, name_end),
+                });
+            }
+        }
+        let require = body.find("require")?;
+        let (module, after) = parse_require(&body[require..])?;
+        let function = after
+            .strip_prefix('.')
+            .map(|after| {
+                let end = after
+                    .find(|c: char| !is_identifier_char(c))
+                    .unwrap_or(after.len());
+                &after[..end]
+            })
+            .filter(|f| !f.is_empty())
+            .map(str::to_string);
+        return Some(LuaReference {
+            module: Some(module.to_string()),
+            function,
+            bridge: LuaBridge::Luaeval,
+            range: (start, end),
+        });
+    }
+    None
+}
+
+/// Parse `require'mod'`, `require"mod"`, `require('mod')` or `require("mod")`
+///
+/// Returns the module name and the text after the call.
+fn parse_require(text: &str) -> Option<(&str, &str)> {
+    let rest = text.strip_prefix("require")?.trim_start();
+    let (rest, paren) = match rest.strip_prefix('(') {
+        Some(rest) => (rest.trim_start(), true),
+        None => (rest, false),
+    };
+    let quote = rest.chars().next().filter(|c| *c == '\'' || *c == '"')?;
+    let close = rest[1..].find(quote)? + 1;
+    let module = &rest[1..close];
+    let mut after = &rest[close + 1..];
+    if paren {
+        after = after.trim_start().strip_prefix(')')?;
+    }
+    Some((module, after))
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+fn is_identifier(s: &str) -> bool {
+    !s.is_empty()
+        && !s.starts_with(|c: char| c.is_ascii_digit())
+        && s.chars().all(is_identifier_char)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_name() {
+        assert_eq!(
+            module_name(Path::new("foo/bar.lua")),
+            Some("foo.bar".to_string())
+        );
+        assert_eq!(
+            module_name(Path::new("foo/init.lua")),
+            Some("foo".to_string())
+        );
+        assert_eq!(module_name(Path::new("init.lua")), Some("init".to_string()));
+        assert_eq!(module_name(Path::new("foo.vim")), None);
+    }
+
+    #[test]
+    fn test_parse_module_functions() {
+        let source = r#"local M = {}
+local function helper() end
+function M.setup(opts)
+end
+M.toggle = function(buf, force) end
+function M:method() end
+return M
+"#;
+        let functions = parse_module_functions(source);
+        let names: Vec<_> = functions.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["setup", "toggle", "method"]);
+        assert_eq!(functions[0].params, "opts");
+        assert_eq!(functions[1].params, "buf, force");
+        assert_eq!(functions[1].line, 4);
+    }
+
+    #[test]
+    fn test_v_lua_completion() {
+        assert_eq!(
+            v_lua_completion("call v:lua."),
+            Some(VLuaCompletion::Module(""))
+        );
+        assert_eq!(
+            v_lua_completion("call v:lua.req"),
+            Some(VLuaCompletion::Module("req"))
+        );
+        assert_eq!(
+            v_lua_completion("call v:lua.require'foo.bar'.se"),
+            Some(VLuaCompletion::Function {
+                module: "foo.bar",
+                partial: "se"
+            })
+        );
+        assert_eq!(v_lua_completion("call v:lua.require'foo'"), None);
+        assert_eq!(v_lua_completion("call foo."), None);
+    }
+
+    #[test]
+    fn test_find_reference() {
+        let line = "call v:lua.require'foo'.setup({})";
+        let reference = find_reference(line, 26).unwrap();
+        assert_eq!(reference.module.as_deref(), Some("foo"));
+        assert_eq!(reference.function.as_deref(), Some("setup"));
+        assert_eq!(reference.range, (5, 29));
+        assert_eq!(reference.bridge, LuaBridge::VLua);
+        // After the call
+        assert_eq!(find_reference(line, 32), None);
+
+        let line = "call v:lua.my_global()";
+        let reference = find_reference(line, 12).unwrap();
+        assert_eq!(reference.module, None);
+        assert_eq!(reference.function.as_deref(), Some("my_global"));
+
+        let line = r#"echo luaeval("require('foo.bar').run()")"#;
+        let reference = find_reference(line, 20).unwrap();
+        assert_eq!(reference.module.as_deref(), Some("foo.bar"));
+        assert_eq!(reference.function.as_deref(), Some("run"));
+        assert_eq!(reference.bridge, LuaBridge::Luaeval);
+
+        let line = "echo luaeval('vim.fn.expand(\"%\") .. vim.fn.MyFunc()')";
+        let reference = find_reference(line, 45).unwrap();
+        assert_eq!(reference.module, None);
+        assert_eq!(reference.function.as_deref(), Some("MyFunc"));
+        assert_eq!(reference.bridge, LuaBridge::VimFn);
+        assert_eq!(reference.range, (36, 49));
+        // Elsewhere in a luaeval() without require
+        assert_eq!(find_reference(line, 8), None);
+    }
+}
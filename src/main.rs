@@ -1,19 +1,12 @@
-mod backend;
-mod builtins;
-mod completion;
-mod config;
-mod db;
-mod diagnostics;
-mod formatter;
-mod logger;
-mod symbols;
-
 use std::path::PathBuf;
+use std::time::Duration;
 
-use tower_lsp_server::{LspService, Server};
+use tower_lsp_server::Server;
 
-use backend::Backend;
-use builtins::EditorMode;
+use hjkls::builtins::EditorMode;
+use hjkls::cli::{
+    Backend, bench, check, doc, fmt, logger, rules, symbol_dump, transport, watchdog,
+};
 
 fn print_version() {
     println!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"));
@@ -24,20 +17,53 @@ fn print_help() {
         "{} - {}
 
 Usage: {} [OPTIONS]
+       {} check [OPTIONS] <PATHS>...
+       {} check [OPTIONS] --stdin [--stdin-filename <PATH>]
+       {} format [OPTIONS] [PATHS]...
+       {} tags [OPTIONS]
+       {} rules
+       {} doc <NAME>
+       {} bench [PATHS]...
+
+Commands:
+  check <PATHS>...       Lint files and directories, exiting with 1 on warnings or errors
+      --format=<FORMAT>  Output format of check: text (default), json, sarif or github
+      --stdin            Lint the content on stdin instead of files
+      --stdin-filename <PATH> Path of the content on stdin, for path-dependent rules
+  format [PATHS]...      Format files and directories, or stdin, printing the result
+      --check            Only list the files needing formatting, exiting with 1 if any
+      --write            Write the formatted files in place
+  bench [PATHS]...       Time indexing, and parsing and each lint pass of the files
+  doc <NAME>             Show the documentation of a builtin function, option or event
+  rules                  List the lint rules with their default severity and dialects
+  tags                   Print a tags file of the symbols defined in the workspace
+      --format=<FORMAT>  Output format of tags: tags (default) or json
 
 Options:
   -V, --version          Show version information
       --vim-only         Show only Vim-compatible functions in completion
       --neovim-only      Show only Neovim-compatible functions in completion
       --vimruntime=<PATH> Override $VIMRUNTIME path for autoload resolution
+      --runtimepath=<PATHS> Comma-separated runtime directories searched before $VIMRUNTIME
       --config=<PATH>    Use specified config file (overrides workspace .hjkls.toml)
       --log=<PATH>       Enable debug logging to specified file
+      --port=<PORT>      Serve LSP over TCP on the port instead of stdio
+      --host=<ADDR>      Address to listen on with --port (default: 127.0.0.1)
+      --socket=<PATH>    Serve LSP over a unix domain socket (named pipe on Windows)
+      --idle-timeout=<SECS> Exit after receiving no message for the given seconds
   -h, --help             Show this help message
 
 This is an LSP server for Vim script. It communicates via stdin/stdout
-using the Language Server Protocol.",
+(or a socket with --port or --socket) using the Language Server Protocol.",
         env!("CARGO_PKG_NAME"),
         env!("CARGO_PKG_DESCRIPTION"),
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_NAME"),
         env!("CARGO_PKG_NAME")
     );
 }
@@ -93,6 +119,19 @@ async fn main() {
         .or_else(|| std::env::var("VIMRUNTIME").ok().map(PathBuf::from))
         .filter(|p| p.exists());
 
+    // Parse --runtimepath=PATH,PATH,... argument
+    let runtimepath: Vec<PathBuf> = args
+        .iter()
+        .find_map(|arg| arg.strip_prefix("--runtimepath="))
+        .map(|paths| {
+            paths
+                .split(',')
+                .map(PathBuf::from)
+                .filter(|p| p.is_dir())
+                .collect()
+        })
+        .unwrap_or_default();
+
     // Parse --config=PATH argument
     let config_path: Option<PathBuf> = args
         .iter()
@@ -106,11 +145,170 @@ async fn main() {
         }
     }
 
-    let stdin = tokio::io::stdin();
+    // Parse --port=N and --host=ADDR arguments
+    let port: Option<u16> = args
+        .iter()
+        .find_map(|arg| arg.strip_prefix("--port="))
+        .map(|port| {
+            port.parse().unwrap_or_else(|_| {
+                eprintln!("error: invalid port: {port}");
+                std::process::exit(1);
+            })
+        });
+    let host = args
+        .iter()
+        .find_map(|arg| arg.strip_prefix("--host=").map(String::from))
+        .unwrap_or_else(|| "127.0.0.1".to_string());
+
+    // Parse --socket=PATH argument
+    let socket_path: Option<PathBuf> = args
+        .iter()
+        .find_map(|arg| arg.strip_prefix("--socket=").map(PathBuf::from));
+    if port.is_some() && socket_path.is_some() {
+        eprintln!("error: --port and --socket cannot be used together");
+        std::process::exit(1);
+    }
+
+    // Parse --idle-timeout=SECS argument
+    let idle_timeout: Option<Duration> = args
+        .iter()
+        .find_map(|arg| arg.strip_prefix("--idle-timeout="))
+        .map(|secs| match secs.parse() {
+            Ok(secs) => Duration::from_secs(secs),
+            Err(_) => {
+                eprintln!("error: invalid idle timeout: {secs}");
+                std::process::exit(1);
+            }
+        });
+
+    // Subcommands run without an LSP client
+    if let Some(command) = args.get(1).filter(|arg| !arg.starts_with('-')) {
+        // `--stdin-filename` also takes its value in the next argument
+        let stdin_filename_index = args
+            .iter()
+            .position(|arg| arg == "--stdin-filename")
+            .map(|i| i + 1);
+        if stdin_filename_index.is_some_and(|i| i >= args.len()) {
+            eprintln!("error: --stdin-filename requires a path");
+            std::process::exit(2);
+        }
+        let operands: Vec<PathBuf> = args
+            .iter()
+            .enumerate()
+            .skip(2)
+            .filter(|(i, arg)| !arg.starts_with('-') && Some(*i) != stdin_filename_index)
+            .map(|(_, arg)| PathBuf::from(arg))
+            .collect();
+        let (service, _) = Backend::service(editor_mode, vimruntime, runtimepath, config_path);
+        let code = match command.as_str() {
+            "check" if operands.is_empty() && !args.iter().any(|arg| arg == "--stdin") => {
+                eprintln!("error: check requires at least one path, or --stdin");
+                2
+            }
+            "check" => {
+                let format = match args.iter().find_map(|arg| arg.strip_prefix("--format=")) {
+                    Some(name) => match check::OutputFormat::parse(name) {
+                        Some(format) => format,
+                        None => {
+                            eprintln!("error: unknown format: {name}");
+                            std::process::exit(2);
+                        }
+                    },
+                    None => check::OutputFormat::default(),
+                };
+                // Content on stdin is linted as this file, for path-dependent rules
+                let stdin_filename = args.iter().any(|arg| arg == "--stdin").then(|| {
+                    args.iter()
+                        .find_map(|arg| arg.strip_prefix("--stdin-filename="))
+                        .or_else(|| stdin_filename_index.map(|i| args[i].as_str()))
+                        .map_or_else(|| PathBuf::from("stdin.vim"), PathBuf::from)
+                });
+                check::run(
+                    service.inner(),
+                    &operands,
+                    stdin_filename.as_deref(),
+                    format,
+                )
+            }
+            "format" => {
+                let mode = if args.iter().any(|arg| arg == "--check") {
+                    fmt::FormatMode::Check
+                } else if args.iter().any(|arg| arg == "--write") {
+                    fmt::FormatMode::Write
+                } else {
+                    fmt::FormatMode::Print
+                };
+                fmt::run(service.inner(), &operands, mode)
+            }
+            "bench" => bench::run(service.inner(), &operands),
+            "doc" => match args.get(2) {
+                Some(name) => doc::run(service.inner(), name),
+                None => {
+                    eprintln!("error: doc requires a name");
+                    2
+                }
+            },
+            "rules" => rules::run(),
+            "tags" => {
+                let format = match args.iter().find_map(|arg| arg.strip_prefix("--format=")) {
+                    Some(name) => match symbol_dump::DumpFormat::parse(name) {
+                        Some(format) => format,
+                        None => {
+                            eprintln!("error: unknown format: {name}");
+                            std::process::exit(2);
+                        }
+                    },
+                    None => symbol_dump::DumpFormat::default(),
+                };
+                symbol_dump::run(service.inner(), format)
+            }
+            _ => {
+                eprintln!("error: unknown command: {command}");
+                2
+            }
+        };
+        std::process::exit(code);
+    }
+
+    let new_service = || {
+        Backend::service(
+            editor_mode,
+            vimruntime.clone(),
+            runtimepath.clone(),
+            config_path.clone(),
+        )
+    };
+
+    if let Some(timeout) = idle_timeout {
+        watchdog::spawn_idle_timeout(timeout);
+    }
+
+    // Serve over a socket instead of stdio, one session per connection
+    let served = if let Some(port) = port {
+        Some(transport::serve_tcp(&host, port, new_service).await)
+    } else if let Some(path) = socket_path {
+        Some(transport::serve_socket(&path, new_service).await)
+    } else {
+        None
+    };
+    if let Some(result) = served {
+        if let Err(e) = result {
+            eprintln!("error: cannot listen: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    // The server belongs to a single client on stdio: exit when it's gone
+    watchdog::watch_client(true);
+    let stdin = watchdog::ActivityReader(tokio::io::stdin());
     let stdout = tokio::io::stdout();
 
-    let (service, socket) = LspService::new(|client| {
-        Backend::new(client, editor_mode, vimruntime.clone(), config_path.clone())
-    });
-    Server::new(stdin, stdout, socket).serve(service).await;
+    let (service, socket) = new_service();
+    tokio::select! {
+        _ = Server::new(stdin, stdout, socket).serve(service) => {}
+        // Nothing to clean up, and the runtime would wait for the blocked
+        // read of stdin
+        _ = watchdog::shutdown() => std::process::exit(0),
+    }
 }
@@ -0,0 +1,251 @@
+//! Vim modelines (`" vim: set sw=2 et:`)
+//!
+//! Vim reads options from the first and last lines of a file. The ones about
+//! indentation and line width seed the formatter settings and the
+//! `line_length` threshold of the file, and 'foldmarker' the folding ranges,
+//! so that hjkls agrees with the editor.
+//! Reference: :help modeline
+
+use crate::config::{FormatConfig, ThresholdsConfig};
+
+/// Number of lines checked at the top and at the bottom ('modelines' default)
+const MODELINES: usize = 5;
+
+/// Options set by the modelines of a file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Modeline {
+    pub shiftwidth: Option<usize>,
+    pub tabstop: Option<usize>,
+    pub expandtab: Option<bool>,
+    pub textwidth: Option<usize>,
+    /// Start and end fold markers ('foldmarker', `{{{,}}}` by default)
+    pub foldmarker: Option<(String, String)>,
+}
+
+impl Modeline {
+    /// Read the modelines of a file
+    ///
+    /// Like in Vim, a later modeline overrides the options of an earlier one.
+    pub fn parse(source: &str) -> Self {
+        let lines: Vec<&str> = source.lines().collect();
+        let top = lines.len().min(MODELINES);
+        let bottom = lines.len().saturating_sub(MODELINES).max(top);
+        let mut modeline = Self::default();
+        for line in lines[..top].iter().chain(&lines[bottom..]) {
+            for option in options(line).unwrap_or_default() {
+                modeline.set(&option);
+            }
+        }
+        modeline
+    }
+
+    /// Set one option (`sw=2`, `et`, `noexpandtab`)
+    fn set(&mut self, option: &str) {
+        let number = |value: &str| value.parse::<usize>().ok();
+        match option.split_once('=') {
+            Some(("sw" | "shiftwidth", value)) => self.shiftwidth = number(value),
+            Some(("ts" | "tabstop", value)) => self.tabstop = number(value),
+            Some(("tw" | "textwidth", value)) => self.textwidth = number(value),
+            Some(("fmr" | "foldmarker", value)) => {
+                self.foldmarker = value
+                    .split_once(',')
+                    .filter(|(start, end)| !start.is_empty() && !end.is_empty())
+                    .map(|(start, end)| (start.to_string(), end.to_string()));
+            }
+            Some(_) => {}
+            None => match option {
+                "et" | "expandtab" => self.expandtab = Some(true),
+                "noet" | "noexpandtab" => self.expandtab = Some(false),
+                _ => {}
+            },
+        }
+    }
+
+    /// Indent width: 'shiftwidth', or 'tabstop' when it is zero
+    fn indent_width(&self) -> Option<usize> {
+        match self.shiftwidth {
+            Some(0) | None => self.tabstop.filter(|&ts| ts > 0),
+            shiftwidth => shiftwidth,
+        }
+    }
+
+    /// Override the formatter settings with the ones of the modeline
+    pub fn apply_format(&self, config: &mut FormatConfig) {
+        if let Some(width) = self.indent_width() {
+            config.indent_width = width;
+        }
+        if let Some(expandtab) = self.expandtab {
+            config.use_tabs = !expandtab;
+        }
+    }
+
+    /// Override the `line_length` threshold with 'textwidth' (0 doesn't wrap)
+    pub fn apply_thresholds(&self, thresholds: &mut ThresholdsConfig) {
+        if let Some(textwidth) = self.textwidth.filter(|&tw| tw > 0) {
+            thresholds.line_length = Some(textwidth);
+        }
+    }
+}
+
+/// Get the options of a modeline, or None when the line isn't one
+///
+/// Either `[text{white}]{vi:|vim:|ex:}[white]{options}` with options separated
+/// by spaces or colons, or `[text{white}]{vi:|vim:|Vim:|ex:}[white]se[t]
+/// {options}:[text]` with options separated by spaces.
+fn options(line: &str) -> Option<Vec<String>> {
+    let (after, capital) = find_marker(line)?;
+    let after = after.trim_start();
+    let set = ["set ", "se "]
+        .iter()
+        .find_map(|prefix| after.strip_prefix(prefix));
+    let options = match set {
+        Some(rest) => split_options(rest, true),
+        // `Vim:` is only recognized with `set`
+        None if capital => return None,
+        None => split_options(after, false),
+    };
+    Some(options)
+}
+
+/// Find `vi:`, `vim:` (`vim700:`, `vim<800:`), `Vim:` or `ex:` at the start
+/// of the line or after white space
+///
+/// Returns the text after the colon and whether the marker is `Vim:`.
+fn find_marker(line: &str) -> Option<(&str, bool)> {
+    let mut prev: Option<char> = None;
+    for (i, c) in line.char_indices() {
+        if prev.is_none_or(char::is_whitespace) {
+            let rest = &line[i..];
+            if let Some(after) = rest.strip_prefix("vi:") {
+                return Some((after, false));
+            }
+            // `ex:` isn't recognized at the start of the line
+            if let Some(after) = rest.strip_prefix("ex:").filter(|_| prev.is_some()) {
+                return Some((after, false));
+            }
+            for (marker, capital) in [("vim", false), ("Vim", true)] {
+                let Some(version) = rest.strip_prefix(marker) else {
+                    continue;
+                };
+                let version = version.strip_prefix(['<', '=', '>']).unwrap_or(version);
+                let digits = version.len()
+                    - version
+                        .trim_start_matches(|c: char| c.is_ascii_digit())
+                        .len();
+                if let Some(after) = version[digits..].strip_prefix(':')
+                    && (!capital || digits == 0)
+                {
+                    return Some((after, capital));
+                }
+            }
+        }
+        prev = Some(c);
+    }
+    None
+}
+
+/// Split options at white space (and colons without `set`), up to the colon
+/// ending a `set` modeline; `\:` is a literal colon
+fn split_options(text: &str, set: bool) -> Vec<String> {
+    let mut options = Vec::new();
+    let mut current = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&':') => {
+                current.push(':');
+                chars.next();
+            }
+            ':' if set => break,
+            ':' => options.push(std::mem::take(&mut current)),
+            c if c.is_whitespace() => options.push(std::mem::take(&mut current)),
+            c => current.push(c),
+        }
+    }
+    options.push(current);
+    options.retain(|option| !option.is_empty());
+    options
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_options() {
+        assert_eq!(
+            options("\" vim: set sw=2 et:"),
+            Some(vec!["sw=2".to_string(), "et".to_string()])
+        );
+        assert_eq!(
+            options("\" vim:sw=4:ts=8:noet"),
+            Some(vec![
+                "sw=4".to_string(),
+                "ts=8".to_string(),
+                "noet".to_string()
+            ])
+        );
+        assert_eq!(
+            options("\" Vim: se tw=78 : trailing text"),
+            Some(vec!["tw=78".to_string()])
+        );
+        assert_eq!(
+            options("vim700: set fdm=marker\\:x:"),
+            Some(vec!["fdm=marker:x".to_string()])
+        );
+        assert_eq!(options("  ex: sw=3"), Some(vec!["sw=3".to_string()]));
+        // Not modelines: no white space before the marker, `ex:` at the
+        // start, `Vim:` without `set`
+        assert_eq!(options("\"vim: set sw=2:"), None);
+        assert_eq!(options("ex: sw=3"), None);
+        assert_eq!(options("\" Vim: sw=2"), None);
+        assert_eq!(options("let g:vim = 1"), None);
+    }
+
+    #[test]
+    fn test_parse() {
+        let mut source = String::from("\" vim: set sw=4 ts=8 noet:\n");
+        source.push_str(&"echo 1\n".repeat(10));
+        source.push_str("\" vim: set tw=100:\n");
+        assert_eq!(
+            Modeline::parse(&source),
+            Modeline {
+                shiftwidth: Some(4),
+                tabstop: Some(8),
+                expandtab: Some(false),
+                textwidth: Some(100),
+                foldmarker: None,
+            }
+        );
+        assert_eq!(
+            Modeline::parse("\" vim: set fdm=marker fmr=<<<,>>>:\n").foldmarker,
+            Some(("<<<".to_string(), ">>>".to_string()))
+        );
+
+        // Only the first and last lines are read
+        let mut source = "echo 1\n".repeat(6);
+        source.push_str("\" vim: set sw=4:\n");
+        source.push_str(&"echo 1\n".repeat(5));
+        assert_eq!(Modeline::parse(&source), Modeline::default());
+    }
+
+    #[test]
+    fn test_apply() {
+        let modeline = Modeline::parse("\" vim: set sw=0 ts=4 noet tw=0:\n");
+        let mut format = FormatConfig::default();
+        modeline.apply_format(&mut format);
+        assert_eq!(format.indent_width, 4);
+        assert!(format.use_tabs);
+
+        // `tw=0` keeps the configured threshold
+        let mut thresholds = ThresholdsConfig {
+            line_length: Some(90),
+            ..Default::default()
+        };
+        modeline.apply_thresholds(&mut thresholds);
+        assert_eq!(thresholds.line_length(), 90);
+
+        Modeline::parse("\" vim: tw=78\n").apply_thresholds(&mut thresholds);
+        assert_eq!(thresholds.line_length(), 78);
+    }
+}
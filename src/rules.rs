@@ -0,0 +1,64 @@
+//! Rule listing from the command line: `hjkls rules`
+//!
+//! Lists the rules that can be configured in `.hjkls.toml` or suppressed
+//! with `hjkls-ignore` comments.
+
+use crate::check::severity_name;
+use crate::diagnostics::dialect::rule_applicability;
+use crate::diagnostics::{RULES, Rule};
+
+/// Categories disabled unless enabled in `[lint]`
+const DISABLED_CATEGORIES: &[&str] = &["style"];
+
+/// Print every rule with its category, default severity, dialects and description
+pub fn run() -> i32 {
+    println!(
+        "{:<28} {:<12} {:<9} {:<14} DESCRIPTION",
+        "RULE", "CATEGORY", "SEVERITY", "DIALECT"
+    );
+    for rule in RULES {
+        println!("{}", format_rule(rule));
+    }
+    println!();
+    println!("* disabled by default, enabled with `style = true` in [lint]");
+    0
+}
+
+/// Format a rule as a row of the listing
+fn format_rule(rule: &Rule) -> String {
+    let disabled = rule
+        .category
+        .is_some_and(|category| DISABLED_CATEGORIES.contains(&category));
+    let severity = format!(
+        "{}{}",
+        severity_name(Some(rule.severity)),
+        if disabled { "*" } else { "" }
+    );
+    format!(
+        "{:<28} {:<12} {:<9} {:<14} {}",
+        format!("hjkls/{}", rule.name),
+        rule.category.unwrap_or("-"),
+        severity,
+        rule_applicability(rule.name),
+        rule.description
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::diagnostics::find_rule;
+
+    #[test]
+    fn test_format_rule() {
+        let rule = find_rule("normal_bang").unwrap();
+        assert_eq!(
+            format_rule(rule),
+            "hjkls/normal_bang            suspicious   warning   all            `normal` without `!`"
+        );
+        let rule = find_rule("abort").unwrap();
+        assert!(format_rule(rule).contains(" hint*     legacy script "));
+        let rule = find_rule("syntax_error").unwrap();
+        assert!(format_rule(rule).contains(" -            error "));
+    }
+}
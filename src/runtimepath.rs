@@ -0,0 +1,381 @@
+//! Runtime directories declared by the vimrc of the workspace
+//!
+//! A dotfiles repository adds plugins to 'runtimepath' with `:set rtp+=`,
+//! a plugin manager (vim-plug's `:Plug`) or `:packadd`. Reading those
+//! declarations tells where installed plugins live, so that their autoload
+//! functions and `:runtime` files resolve like in the editor.
+//! Reference: :help 'runtimepath', :help :packadd
+
+use std::path::{Path, PathBuf};
+
+/// Default vim-plug directories of Vim and Neovim (`plug#begin()`)
+const PLUG_DIRS: &[&str] = &["~/.vim/plugged", "~/.local/share/nvim/plugged"];
+
+/// Default user directories of 'runtimepath' and 'packpath' of Vim and Neovim
+const USER_DIRS: &[&str] = &[
+    "~/.vim",
+    "~/vimfiles",
+    "~/.config/nvim",
+    "~/.local/share/nvim/site",
+];
+
+/// Runtime directories and packages declared by scripts
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Declarations {
+    /// Entries added with `:set runtimepath+=`
+    pub runtimepath: Vec<PathBuf>,
+    /// Entries added with `:set packpath+=`
+    pub packpath: Vec<PathBuf>,
+    /// Candidate directories of `:Plug` plugins (one per plugin directory)
+    pub plugins: Vec<PathBuf>,
+    /// Names loaded with `:packadd`
+    pub packages: Vec<String>,
+}
+
+impl Declarations {
+    /// Read the declarations of a script
+    ///
+    /// Only literal values are understood; `~` and environment variables are
+    /// expanded, and entries that can't be expanded are ignored.
+    pub fn parse(source: &str, home: Option<&Path>) -> Self {
+        let mut declarations = Self::default();
+        let mut plug_dirs: Vec<PathBuf> = PLUG_DIRS
+            .iter()
+            .filter_map(|dir| expand(dir, home))
+            .collect();
+        for line in source.lines() {
+            let line = line.trim_start().trim_start_matches([':', ' ', '\t']);
+            let (command, args) = split_command(line);
+            match command {
+                "se" | "set" | "setg" | "setglobal" => {
+                    declarations.parse_set(args, home);
+                }
+                "let" => declarations.parse_let(args, home),
+                "call" => {
+                    if let Some(args) = args.trim_start().strip_prefix("plug#begin(") {
+                        plug_dirs = match string_literal(args) {
+                            Some((dir, _)) => expand(&dir, home).into_iter().collect(),
+                            None => PLUG_DIRS.iter().filter_map(|d| expand(d, home)).collect(),
+                        };
+                    }
+                }
+                "Plug" => declarations.parse_plug(args, &plug_dirs, home),
+                "pa" | "packadd" | "pa!" | "packadd!" => {
+                    if let Some(name) = args.split_whitespace().next() {
+                        declarations.packages.push(name.to_string());
+                    }
+                }
+                _ => {}
+            }
+        }
+        declarations
+    }
+
+    /// Whether nothing is declared
+    pub fn is_empty(&self) -> bool {
+        self.runtimepath.is_empty()
+            && self.packpath.is_empty()
+            && self.plugins.is_empty()
+            && self.packages.is_empty()
+    }
+
+    /// Add the declarations of another script
+    pub fn extend(&mut self, other: Self) {
+        self.runtimepath.extend(other.runtimepath);
+        self.packpath.extend(other.packpath);
+        self.plugins.extend(other.plugins);
+        self.packages.extend(other.packages);
+    }
+
+    /// `:set runtimepath+=~/foo,~/bar packpath^=~/baz`
+    fn parse_set(&mut self, args: &str, home: Option<&Path>) {
+        for option in split_set_args(args) {
+            let Some((name, value)) = option.split_once('=') else {
+                continue;
+            };
+            // `:set rtp=` also replaces the default entries, which don't matter
+            let name = name.strip_suffix(['+', '^']).unwrap_or(name);
+            let target = match name {
+                "rtp" | "runtimepath" => &mut self.runtimepath,
+                "pp" | "packpath" => &mut self.packpath,
+                _ => continue,
+            };
+            target.extend(split_path_list(value).filter_map(|path| expand(&path, home)));
+        }
+    }
+
+    /// `let &runtimepath .= ',~/foo'`
+    fn parse_let(&mut self, args: &str, home: Option<&Path>) {
+        let args = args.trim_start();
+        let Some((name, rest)) = args.strip_prefix('&').and_then(|a| {
+            let end = a.find(|c: char| !c.is_ascii_alphanumeric())?;
+            Some((&a[..end], a[end..].trim_start()))
+        }) else {
+            return;
+        };
+        let target = match name {
+            "rtp" | "runtimepath" => &mut self.runtimepath,
+            "pp" | "packpath" => &mut self.packpath,
+            _ => return,
+        };
+        let Some(rest) = rest.strip_prefix(".=").or_else(|| rest.strip_prefix("..=")) else {
+            return;
+        };
+        if let Some((value, _)) = string_literal(rest.trim_start()) {
+            target.extend(split_path_list(&value).filter_map(|path| expand(&path, home)));
+        }
+    }
+
+    /// `Plug 'owner/repo', { 'as': 'name', 'dir': '~/foo', 'rtp': 'vim' }`
+    fn parse_plug(&mut self, args: &str, plug_dirs: &[PathBuf], home: Option<&Path>) {
+        let Some((repo, options)) = string_literal(args.trim_start()) else {
+            return;
+        };
+        let name = option_value(options, "as").unwrap_or_else(|| {
+            let name = repo
+                .trim_end_matches('/')
+                .rsplit('/')
+                .next()
+                .unwrap_or(&repo);
+            name.strip_suffix(".git").unwrap_or(name).to_string()
+        });
+        let dirs: Vec<PathBuf> = match option_value(options, "dir") {
+            Some(dir) => expand(&dir, home).into_iter().collect(),
+            None => plug_dirs.iter().map(|dir| dir.join(&name)).collect(),
+        };
+        let subdir = option_value(options, "rtp");
+        self.plugins
+            .extend(dirs.into_iter().map(|dir| match &subdir {
+                Some(subdir) => dir.join(subdir),
+                None => dir,
+            }));
+    }
+
+    /// The existing runtime directories: the default user directories (where
+    /// the plugin managers themselves live), then the declared ones
+    ///
+    /// Packages are searched under `pack/*/{opt,start}/` of the 'packpath'
+    /// entries, the default ones and the workspace roots.
+    pub fn resolve(&self, roots: &[PathBuf], home: Option<&Path>) -> Vec<PathBuf> {
+        let user_dirs: Vec<PathBuf> = USER_DIRS
+            .iter()
+            .filter_map(|dir| expand(dir, home))
+            .collect();
+        let mut packpath: Vec<PathBuf> = self.packpath.clone();
+        packpath.extend(user_dirs.iter().cloned());
+        packpath.extend(roots.iter().cloned());
+
+        let packages = self
+            .packages
+            .iter()
+            .filter_map(|name| packpath.iter().find_map(|dir| find_package(dir, name)));
+        let mut dirs: Vec<PathBuf> = Vec::new();
+        for dir in user_dirs
+            .iter()
+            .chain(&self.runtimepath)
+            .chain(&self.plugins)
+            .cloned()
+            .chain(packages)
+        {
+            if dir.is_dir() && !dirs.contains(&dir) && !roots.contains(&dir) {
+                dirs.push(dir);
+            }
+        }
+        dirs
+    }
+}
+
+/// Find `pack/*/opt/{name}` (or `pack/*/start/{name}`) under a 'packpath' entry
+fn find_package(dir: &Path, name: &str) -> Option<PathBuf> {
+    let mut packs: Vec<PathBuf> = std::fs::read_dir(dir.join("pack"))
+        .ok()?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .collect();
+    packs.sort();
+    ["opt", "start"].iter().find_map(|kind| {
+        packs
+            .iter()
+            .map(|pack| pack.join(kind).join(name))
+            .find(|path| path.is_dir())
+    })
+}
+
+/// Split a command line into the command name (with a `!`) and its arguments
+fn split_command(line: &str) -> (&str, &str) {
+    let end = line
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(line.len());
+    let end = if line[end..].starts_with('!') {
+        end + 1
+    } else {
+        end
+    };
+    (&line[..end], &line[end..])
+}
+
+/// Split the arguments of `:set` at unescaped white space, up to a comment
+fn split_set_args(args: &str) -> Vec<String> {
+    let mut options = Vec::new();
+    let mut current = String::new();
+    let mut chars = args.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            '"' if current.is_empty() => break,
+            '|' => break,
+            c if c.is_whitespace() => options.push(std::mem::take(&mut current)),
+            c => current.push(c),
+        }
+    }
+    options.push(current);
+    options.retain(|option| !option.is_empty());
+    options
+}
+
+/// Split a comma-separated path list (`\,` is a literal comma)
+fn split_path_list(value: &str) -> impl Iterator<Item = String> + '_ {
+    let mut paths = Vec::new();
+    let mut current = String::new();
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' if chars.peek() == Some(&',') => {
+                current.push(',');
+                chars.next();
+            }
+            ',' => paths.push(std::mem::take(&mut current)),
+            c => current.push(c),
+        }
+    }
+    paths.push(current);
+    paths.into_iter().filter(|path| !path.is_empty())
+}
+
+/// Expand `~` and `$VAR` at the start of a path
+fn expand(path: &str, home: Option<&Path>) -> Option<PathBuf> {
+    if path == "~" {
+        return home.map(Path::to_path_buf);
+    }
+    if let Some(rest) = path.strip_prefix("~/") {
+        return home.map(|home| home.join(rest));
+    }
+    if let Some(rest) = path.strip_prefix('$') {
+        let (name, rest) = match rest.find('/') {
+            Some(slash) => (&rest[..slash], &rest[slash + 1..]),
+            None => (rest, ""),
+        };
+        let value = match name {
+            "HOME" => home.map(Path::to_path_buf),
+            _ => std::env::var_os(name).map(PathBuf::from),
+        }?;
+        return Some(if rest.is_empty() {
+            value
+        } else {
+            value.join(rest)
+        });
+    }
+    (!path.contains('$')).then(|| PathBuf::from(path))
+}
+
+/// Read a leading string literal, returning its value and the text after it
+fn string_literal(text: &str) -> Option<(String, &str)> {
+    let quote = text.chars().next().filter(|c| *c == '\'' || *c == '"')?;
+    let mut value = String::new();
+    let mut chars = text.char_indices().skip(1).peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == '\\' && quote == '"' {
+            if let Some((_, next)) = chars.next() {
+                value.push(next);
+            }
+        } else if c == quote {
+            // `''` is a literal quote in a single-quoted string
+            if quote == '\'' && chars.peek().is_some_and(|&(_, next)| next == '\'') {
+                value.push('\'');
+                chars.next();
+            } else {
+                return Some((value, &text[i + 1..]));
+            }
+        } else {
+            value.push(c);
+        }
+    }
+    None
+}
+
+/// Get the string value of a key in a dictionary literal (`{ 'dir': '~/x' }`)
+fn option_value(options: &str, key: &str) -> Option<String> {
+    ["'", "\""].iter().find_map(|quote| {
+        let pattern = format!("{quote}{key}{quote}");
+        let after = &options[options.find(&pattern)? + pattern.len()..];
+        let value = after.trim_start().strip_prefix(':')?.trim_start();
+        string_literal(value).map(|(value, _)| value)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let home = Path::new("/home/user");
+        let source = r#"
+set nocompatible
+set runtimepath+=~/src/foo.vim,/opt/bar rtp^=~/baz
+let &rtp .= ',~/qux'
+call plug#begin('~/.vim/bundle')
+Plug 'junegunn/fzf.vim'
+Plug 'https://github.com/owner/repo.git', { 'as': 'other' }
+Plug 'owner/lsp', { 'rtp': 'vim' }
+Plug '~/my-plugin', { 'dir': '~/my-plugin' }
+call plug#end()
+packadd! matchit
+packadd cfilter
+"#;
+        let declarations = Declarations::parse(source, Some(home));
+        assert_eq!(
+            declarations.runtimepath,
+            vec![
+                home.join("src/foo.vim"),
+                PathBuf::from("/opt/bar"),
+                home.join("baz"),
+                home.join("qux"),
+            ]
+        );
+        assert_eq!(
+            declarations.plugins,
+            vec![
+                home.join(".vim/bundle/fzf.vim"),
+                home.join(".vim/bundle/other"),
+                home.join(".vim/bundle/lsp/vim"),
+                home.join("my-plugin"),
+            ]
+        );
+        assert_eq!(declarations.packages, vec!["matchit", "cfilter"]);
+    }
+
+    #[test]
+    fn test_parse_defaults() {
+        let home = Path::new("/home/user");
+        // Without a directory, vim-plug uses the one of the editor
+        let declarations =
+            Declarations::parse("call plug#begin()\nPlug 'tpope/vim-surround'\n", Some(home));
+        assert_eq!(
+            declarations.plugins,
+            vec![
+                home.join(".vim/plugged/vim-surround"),
+                home.join(".local/share/nvim/plugged/vim-surround"),
+            ]
+        );
+        // Comments, other options and unknown variables
+        let declarations = Declarations::parse(
+            "\" set rtp+=~/a\nset rtp+=$UNDEFINED_HJKLS_VAR/x sw=2\nset path+=~/b\n",
+            Some(home),
+        );
+        assert_eq!(declarations, Declarations::default());
+    }
+}
@@ -0,0 +1,194 @@
+//! Symbol dump from the command line: `hjkls tags`
+//!
+//! Indexes the workspace in the current directory and prints the functions,
+//! user commands and variables defined in it, as a ctags-compatible tags file
+//! (for editors without LSP) or as JSON (for inspecting the index).
+
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use crate::backend::Backend;
+use crate::symbols::{Symbol, SymbolKind, UserCommand, VimScope};
+use crate::tags::{self, TagEntry};
+
+/// Output format of the symbols (`--format=...`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DumpFormat {
+    /// A sorted tags file
+    #[default]
+    Tags,
+    /// A JSON array of symbols
+    Json,
+}
+
+impl DumpFormat {
+    pub fn parse(name: &str) -> Option<Self> {
+        match name {
+            "tags" => Some(Self::Tags),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+}
+
+/// A symbol defined in the workspace
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+struct Definition {
+    /// Name with its scope prefix (e.g., "s:Helper", "g:loaded_foo")
+    name: String,
+    kind: &'static str,
+    /// Path relative to the workspace root
+    file: String,
+    /// 1-based line
+    line: usize,
+    /// 1-based column
+    column: usize,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    signature: Option<String>,
+}
+
+impl Definition {
+    /// Kind letter in a tags file
+    fn tag_kind(&self) -> char {
+        match self.kind {
+            "function" => 'f',
+            "command" => 'c',
+            _ => 'v',
+        }
+    }
+}
+
+/// Print the symbols defined in the workspace
+///
+/// Returns the process exit code.
+pub fn run(backend: &Backend, format: DumpFormat) -> i32 {
+    let root = std::env::current_dir().unwrap_or_else(|_| PathBuf::from("."));
+    backend.index_workspace(root.clone());
+
+    let mut definitions = Vec::new();
+    for (path, symbols, commands) in backend.workspace_definitions() {
+        let file = relative_path(Path::new(&path), &root);
+        definitions.extend(collect_definitions(&file, &symbols, &commands));
+    }
+
+    match format {
+        DumpFormat::Tags => {
+            // Tags files are sorted by name, bytewise
+            definitions.sort_by(|a, b| (&a.name, &a.file, a.line).cmp(&(&b.name, &b.file, b.line)));
+            print!("{}", tags::header());
+            for definition in &definitions {
+                let entry = TagEntry {
+                    name: definition.name.clone(),
+                    file: definition.file.clone(),
+                    address: definition.line.to_string(),
+                };
+                println!("{}", entry.to_line(definition.tag_kind()));
+            }
+        }
+        DumpFormat::Json => match serde_json::to_string(&definitions) {
+            Ok(json) => println!("{json}"),
+            Err(e) => {
+                eprintln!("error: {e}");
+                return 1;
+            }
+        },
+    }
+    0
+}
+
+/// Format a path relative to the workspace root, with `/` separators
+fn relative_path(path: &Path, root: &Path) -> String {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .to_string_lossy()
+        .replace('\\', "/")
+}
+
+/// Collect the definitions of a file visible outside of functions
+///
+/// Function-local variables and arguments are skipped.
+fn collect_definitions(
+    file: &str,
+    symbols: &[Symbol],
+    commands: &[UserCommand],
+) -> Vec<Definition> {
+    let functions: Vec<&Symbol> = symbols
+        .iter()
+        .filter(|sym| sym.kind == SymbolKind::Function)
+        .collect();
+    let is_local = |sym: &Symbol| match sym.scope {
+        VimScope::Local | VimScope::Argument => true,
+        VimScope::Implicit => functions
+            .iter()
+            .any(|func| func.extent.0 < sym.start && sym.start < func.extent.1),
+        _ => false,
+    };
+
+    let mut definitions: Vec<Definition> = symbols
+        .iter()
+        .filter(|sym| sym.kind == SymbolKind::Function || !is_local(sym))
+        .map(|sym| Definition {
+            name: sym.full_name(),
+            kind: if sym.kind == SymbolKind::Function {
+                "function"
+            } else {
+                "variable"
+            },
+            file: file.to_string(),
+            line: sym.start.0 + 1,
+            column: sym.start.1 + 1,
+            signature: sym.signature.clone(),
+        })
+        .collect();
+    definitions.extend(commands.iter().map(|cmd| Definition {
+        name: cmd.name.clone(),
+        kind: "command",
+        file: file.to_string(),
+        line: cmd.start.0 + 1,
+        column: cmd.start.1 + 1,
+        signature: None,
+    }));
+    definitions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::symbols::{extract_symbols, extract_user_commands};
+    use tree_sitter::Parser;
+
+    #[test]
+    fn test_collect_definitions() {
+        let code = r#"let g:loaded_foo = 1
+let s:count = 0
+function! s:Helper(name) abort
+  let result = a:name
+  return result
+endfunction
+command! -nargs=1 Foo call s:Helper(<q-args>)
+"#;
+        let mut parser = Parser::new();
+        parser.set_language(&tree_sitter_vim::language()).unwrap();
+        let tree = parser.parse(code, None).unwrap();
+        let definitions = collect_definitions(
+            "plugin/foo.vim",
+            &extract_symbols(&tree, code),
+            &extract_user_commands(&tree, code),
+        );
+        let names: Vec<(&str, &str, usize)> = definitions
+            .iter()
+            .map(|d| (d.name.as_str(), d.kind, d.line))
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                ("g:loaded_foo", "variable", 1),
+                ("s:count", "variable", 2),
+                ("s:Helper", "function", 3),
+                ("Foo", "command", 7),
+            ]
+        );
+        assert_eq!(definitions[2].tag_kind(), 'f');
+    }
+}
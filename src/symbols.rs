@@ -3,6 +3,7 @@
 // Some items will be used in go-to-definition/hover implementation
 #![allow(dead_code)]
 
+use tower_lsp_server::ls_types::Position;
 use tree_sitter::{Node, Tree};
 
 /// Vim script variable scope
@@ -30,7 +31,7 @@ pub enum VimScope {
 
 impl VimScope {
     /// Parse scope from tree-sitter scope node text
-    pub fn from_str(s: &str) -> Self {
+    pub fn from_prefix(s: &str) -> Self {
         match s {
             "g:" => Self::Global,
             "s:" => Self::Script,
@@ -81,8 +82,12 @@ pub struct Symbol {
     pub start: (usize, usize),
     /// End position (row, column)
     pub end: (usize, usize),
+    /// Start and end of the whole definition (e.g., `function` to `endfunction`)
+    pub extent: ((usize, usize), (usize, usize)),
     /// Function signature (for functions)
     pub signature: Option<String>,
+    /// Doc comment: the comment block immediately preceding a function definition
+    pub doc: Option<String>,
 }
 
 impl Symbol {
@@ -195,6 +200,55 @@ pub fn find_identifier_at_position(
     find_identifier_in_node(&root, source, row, col)
 }
 
+/// Find the innermost node of one of `kinds` containing a position
+pub fn find_node_of_kind<'a>(
+    tree: &'a Tree,
+    row: usize,
+    col: usize,
+    kinds: &[&str],
+) -> Option<Node<'a>> {
+    let point = tree_sitter::Point::new(row, col);
+    let mut node = tree.root_node().descendant_for_point_range(point, point)?;
+    loop {
+        if kinds.contains(&node.kind()) {
+            return Some(node);
+        }
+        node = node.parent()?;
+    }
+}
+
+/// Find the token (leaf node) at a given position
+///
+/// Scoped identifiers (e.g., `g:foo`) are returned as a whole.
+pub fn find_token_at_position(tree: &Tree, row: usize, col: usize) -> Option<Node<'_>> {
+    let point = tree_sitter::Point::new(row, col);
+    let node = tree.root_node().descendant_for_point_range(point, point)?;
+    match node.parent() {
+        Some(parent) if parent.kind() == "scoped_identifier" => Some(parent),
+        _ => Some(node),
+    }
+}
+
+/// Find the `<...>` token (e.g., `<silent>`, `<args>`) containing a byte column
+///
+/// Returns the byte range of the token including the angle brackets. A column
+/// inside a multibyte character counts as its start.
+pub fn find_angle_bracket_token(line: &str, col: usize) -> Option<(usize, usize)> {
+    let mut col = col.min(line.len());
+    while !line.is_char_boundary(col) {
+        col -= 1;
+    }
+    let start = line[..col]
+        .rfind('<')
+        .or_else(|| line[col..].starts_with('<').then_some(col))?;
+    let end = start + line[start..].find('>')? + 1;
+    let token = &line[start + 1..end - 1];
+    if col >= end || token.is_empty() || token.contains(char::is_whitespace) {
+        return None;
+    }
+    Some((start, end))
+}
+
 /// Find an Ex command name at a given position in the syntax tree
 /// Returns the command name if the cursor is on an unknown_command_name node
 /// or a known command node (like echo, call, etc.)
@@ -310,7 +364,7 @@ fn find_identifier_in_node(node: &Node, source: &str, row: usize, col: usize) ->
             let is_call = node.parent().is_some_and(|p| p.kind() == "call_expression");
             Some(Reference {
                 name,
-                scope: VimScope::from_str(scope_text),
+                scope: VimScope::from_prefix(scope_text),
                 is_call,
                 autoload: None,
             })
@@ -319,6 +373,179 @@ fn find_identifier_in_node(node: &Node, source: &str, row: usize, col: usize) ->
     }
 }
 
+/// Find a function referenced by name in a string or a mapping at a position
+///
+/// Plugins often refer to functions by name: `function('s:Func')`,
+/// `timer_start(10, 'MyCb')`, `{'callback': 'ns#fn'}`, or
+/// `nnoremap x :call <SID>Func()<CR>`. These are not identifiers in the
+/// syntax tree, so the name is extracted from the text.
+pub fn find_function_name_reference(
+    tree: &Tree,
+    source: &str,
+    row: usize,
+    col: usize,
+) -> Option<Reference> {
+    let node = find_node_of_kind(tree, row, col, &["string_literal", "map_statement"])?;
+    let name = if node.kind() == "string_literal" {
+        let text = node.utf8_text(source.as_bytes()).ok()?;
+        text.get(1..text.len().saturating_sub(1))?
+    } else {
+        let line = source.lines().nth(row)?;
+        let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '#' || c == ':';
+        // `col` counts UTF-16 units, like LSP positions
+        let col = crate::sync::position_to_byte(line, Position::new(0, col as u32));
+        // On `<SID>` itself, the name follows it
+        let col = match find_angle_bracket_token(line, col) {
+            Some((start, end)) if line[start..end].eq_ignore_ascii_case("<SID>") => end,
+            _ => col,
+        };
+        let start = line[..col]
+            .char_indices()
+            .rev()
+            .find(|&(_, c)| !is_name_char(c))
+            .map_or(0, |(pos, c)| pos + c.len_utf8());
+        let end = line[col..]
+            .find(|c: char| !is_name_char(c))
+            .map_or(line.len(), |pos| col + pos);
+        let start = if ends_with_script_prefix(&line[..start]) {
+            start - "<SID>".len()
+        } else {
+            start
+        };
+        &line[start..end]
+    };
+    parse_function_name(name)
+}
+
+/// Get the length of a script-local function prefix: `<SID>` or `<SNR>{nr}_`
+pub fn script_prefix_len(text: &str) -> Option<usize> {
+    let prefix = text.get(..5)?;
+    if prefix.eq_ignore_ascii_case("<SID>") {
+        return Some(5);
+    }
+    if !prefix.eq_ignore_ascii_case("<SNR>") {
+        return None;
+    }
+    let digits = text[5..].bytes().take_while(u8::is_ascii_digit).count();
+    (digits > 0 && text[5 + digits..].starts_with('_')).then_some(5 + digits + 1)
+}
+
+/// Whether text ends with `<SID>` or `<SNR>`, which prefix a following name
+fn ends_with_script_prefix(text: &str) -> bool {
+    let lower = text.to_ascii_lowercase();
+    lower.ends_with("<sid>") || lower.ends_with("<snr>")
+}
+
+/// Parse a function name as written in a string
+/// (e.g., "s:Func", "<SID>Func", "<SNR>12_Func", "ns#fn")
+pub fn parse_function_name(text: &str) -> Option<Reference> {
+    let (scope, name) = if let Some(len) = script_prefix_len(text) {
+        (VimScope::Script, &text[len..])
+    } else if let Some(name) = text.strip_prefix("s:") {
+        (VimScope::Script, name)
+    } else if let Some(name) = text.strip_prefix("g:") {
+        (VimScope::Global, name)
+    } else {
+        (VimScope::Implicit, text)
+    };
+    let valid = name
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '#');
+    if !valid {
+        return None;
+    }
+    let autoload = if scope == VimScope::Implicit {
+        AutoloadRef::parse(name)
+    } else {
+        None
+    };
+    Some(Reference {
+        name: name.to_string(),
+        scope,
+        is_call: true,
+        autoload,
+    })
+}
+
+/// A file argument of `:source` or `:runtime`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SourcedFile {
+    /// The path as written (may contain `~/` or wildcards)
+    pub path: String,
+    /// Whether the path is relative to 'runtimepath' (`:runtime`)
+    pub runtime: bool,
+}
+
+/// Find the file argument of `:source`/`:runtime` at a position
+///
+/// Also handles commands built as a string, e.g.,
+/// `execute 'source ' . 'lib.vim'`, as long as all parts are literals.
+pub fn find_sourced_file(tree: &Tree, source: &str, row: usize, col: usize) -> Option<SourcedFile> {
+    let node = find_node_of_kind(
+        tree,
+        row,
+        col,
+        &["filename", "string_literal", "execute_statement"],
+    )?;
+    match node.kind() {
+        "filename" => {
+            let parent = node.parent()?;
+            let runtime = match parent.kind() {
+                "source_statement" => false,
+                "filenames" if parent.parent()?.kind() == "runtime_statement" => true,
+                _ => return None,
+            };
+            Some(SourcedFile {
+                path: node.utf8_text(source.as_bytes()).ok()?.to_string(),
+                runtime,
+            })
+        }
+        "string_literal" => {
+            let statement = find_node_of_kind(tree, row, col, &["execute_statement"])?;
+            let mut command = String::new();
+            for i in 0..statement.named_child_count() {
+                collect_string_literals(&statement.named_child(i)?, source, &mut command)?;
+            }
+            let (name, argument) = command.trim_start().split_once(' ')?;
+            let runtime = match name.trim_start_matches(':').trim_end_matches('!') {
+                name if name.len() >= 2 && "source".starts_with(name) => false,
+                name if name.len() >= 2 && "runtime".starts_with(name) => true,
+                _ => return None,
+            };
+            let path = argument.split_whitespace().next()?;
+            Some(SourcedFile {
+                path: path.to_string(),
+                runtime,
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Concatenate the contents of string literals joined with `.`/`..`
+///
+/// Returns None if the expression is not made of literals only.
+fn collect_string_literals(node: &Node, source: &str, out: &mut String) -> Option<()> {
+    match node.kind() {
+        "string_literal" => {
+            let text = node.utf8_text(source.as_bytes()).ok()?;
+            out.push_str(text.get(1..text.len().saturating_sub(1))?);
+            Some(())
+        }
+        "binary_operation" => {
+            for i in 0..node.named_child_count() {
+                collect_string_literals(&node.named_child(i)?, source, out)?;
+            }
+            Some(())
+        }
+        _ => None,
+    }
+}
+
 /// A location in the source code
 #[derive(Debug, Clone)]
 pub struct SourceLocation {
@@ -380,7 +607,7 @@ fn find_references_with_kind_in_node(
                     scope_node.utf8_text(source.as_bytes()),
                     ident_node.utf8_text(source.as_bytes()),
                 ) {
-                    let scope = VimScope::from_str(scope_text);
+                    let scope = VimScope::from_prefix(scope_text);
                     if name == target_name && scope == target_scope {
                         let is_declaration = is_declaration_node(node);
                         references.push(ReferenceWithKind {
@@ -394,6 +621,16 @@ fn find_references_with_kind_in_node(
                 }
             }
         }
+        "string_literal" | "map_statement" | "command_statement" => {
+            for location in
+                find_function_name_references_in_node(node, source, target_name, target_scope)
+            {
+                references.push(ReferenceWithKind {
+                    location,
+                    is_declaration: false,
+                });
+            }
+        }
         _ => {}
     }
 
@@ -460,7 +697,7 @@ fn find_references_in_node(
                     scope_node.utf8_text(source.as_bytes()),
                     ident_node.utf8_text(source.as_bytes()),
                 ) {
-                    let scope = VimScope::from_str(scope_text);
+                    let scope = VimScope::from_prefix(scope_text);
                     if name == target_name && scope == target_scope {
                         let is_declaration = is_declaration_node(node);
                         if include_declaration || !is_declaration {
@@ -473,6 +710,14 @@ fn find_references_in_node(
                 }
             }
         }
+        "string_literal" | "map_statement" | "command_statement" => {
+            locations.extend(find_function_name_references_in_node(
+                node,
+                source,
+                target_name,
+                target_scope,
+            ));
+        }
         _ => {}
     }
 
@@ -490,6 +735,30 @@ fn find_references_in_node(
     }
 }
 
+/// Find references to a function written as text in the syntax tree
+///
+/// These are the function names in strings, mappings and `:command` bodies
+/// that [`find_references`] also returns; they are matched by text, so
+/// renaming them may be unwanted.
+pub fn find_text_references(
+    tree: &Tree,
+    source: &str,
+    name: &str,
+    scope: VimScope,
+) -> Vec<SourceLocation> {
+    let mut locations = Vec::new();
+    let mut stack = vec![tree.root_node()];
+    while let Some(node) = stack.pop() {
+        locations.extend(find_function_name_references_in_node(
+            &node, source, name, scope,
+        ));
+        let mut cursor = node.walk();
+        stack.extend(node.children(&mut cursor));
+    }
+    locations.sort_by_key(|loc| loc.start);
+    locations
+}
+
 /// Check if a node is part of a declaration (function definition or let/const statement)
 fn is_declaration_node(node: &Node) -> bool {
     let mut current = node.parent();
@@ -503,6 +772,251 @@ fn is_declaration_node(node: &Node) -> bool {
     false
 }
 
+/// Functions taking a function name as a string argument
+const FUNCTION_NAME_ARGUMENT_CALLS: &[&str] = &["function", "funcref", "call", "timer_start"];
+
+/// Whether a dictionary key holds a callback name (e.g., 'callback', 'exit_cb', 'on_exit')
+fn is_callback_key(key: &str) -> bool {
+    key.ends_with("callback") || key.ends_with("_cb") || key.starts_with("on_")
+}
+
+/// Find function names matching a target in a string or mapping context
+///
+/// Function names appear as text in `function('s:Func')`, callback values
+/// of dictionaries (`{'callback': 'Func'}`), mapping right-hand sides and
+/// `:command` bodies. The returned locations cover the whole name as
+/// written (e.g., `s:Func`, `<SID>Func`, `<SNR>12_Func`).
+fn find_function_name_references_in_node(
+    node: &Node,
+    source: &str,
+    target_name: &str,
+    target_scope: VimScope,
+) -> Vec<SourceLocation> {
+    // Length of the matching text, counted from its end: an implicit target
+    // (a bare name) matches the name part of any scoped name, like identifiers
+    let matched_len = |text: &str| {
+        let reference = parse_function_name(text)?;
+        if reference.name != target_name {
+            return None;
+        }
+        match target_scope {
+            VimScope::Implicit => Some(reference.name.len()),
+            scope if scope == reference.scope => Some(text.len()),
+            _ => None,
+        }
+    };
+    match node.kind() {
+        "string_literal" => {
+            let Some(parent) = node.parent() else {
+                return vec![];
+            };
+            let is_name_context = match parent.kind() {
+                "call_expression" => parent
+                    .child(0)
+                    .and_then(|f| f.utf8_text(source.as_bytes()).ok())
+                    .is_some_and(|f| FUNCTION_NAME_ARGUMENT_CALLS.contains(&f)),
+                "dictionnary_entry" => {
+                    parent.child(0).is_some_and(|key| key.id() != node.id())
+                        && parent
+                            .child(0)
+                            .and_then(|key| key.utf8_text(source.as_bytes()).ok())
+                            .and_then(|key| key.get(1..key.len().saturating_sub(1)))
+                            .is_some_and(is_callback_key)
+                }
+                _ => false,
+            };
+            let Ok(text) = node.utf8_text(source.as_bytes()) else {
+                return vec![];
+            };
+            let Some(content) = text.get(1..text.len().saturating_sub(1)) else {
+                return vec![];
+            };
+            if !is_name_context {
+                return vec![];
+            }
+            let Some(len) = matched_len(content) else {
+                return vec![];
+            };
+            let start = node.start_position();
+            let end = start.column + 1 + content.len();
+            vec![SourceLocation {
+                start: (start.row, end - len),
+                end: (start.row, end),
+            }]
+        }
+        "map_statement" | "command_statement" => {
+            // The right-hand side of a mapping or the body of a command
+            let mut cursor = node.walk();
+            let body = match node.kind() {
+                "map_statement" => node
+                    .children(&mut cursor)
+                    .filter(|c| c.kind() == "map_side")
+                    .last(),
+                _ => node
+                    .children(&mut cursor)
+                    .find(|c| c.kind() == "command" && c.is_named()),
+            };
+            let Some(body) = body else {
+                return vec![];
+            };
+            let Ok(text) = body.utf8_text(source.as_bytes()) else {
+                return vec![];
+            };
+            let start = body.start_position();
+            let mut locations = Vec::new();
+            for (i, line) in text.split('\n').enumerate() {
+                let (row, offset) = if i == 0 {
+                    (start.row, start.column)
+                } else {
+                    (start.row + i, 0)
+                };
+                for (name_start, name_end) in function_calls_in_text(line) {
+                    if let Some(len) = matched_len(&line[name_start..name_end]) {
+                        locations.push(SourceLocation {
+                            start: (row, offset + name_end - len),
+                            end: (row, offset + name_end),
+                        });
+                    }
+                }
+            }
+            locations
+        }
+        _ => vec![],
+    }
+}
+
+/// Find the byte ranges of called function names (`Name(`) in raw text
+///
+/// A `<SID>` or `<SNR>{nr}_` prefix is included in the range.
+fn function_calls_in_text(text: &str) -> Vec<(usize, usize)> {
+    let is_name_char = |c: char| c.is_ascii_alphanumeric() || c == '_' || c == '#' || c == ':';
+    let mut ranges = Vec::new();
+    let mut pos = 0;
+    while pos < text.len() {
+        let Some(offset) = text[pos..].find(is_name_char) else {
+            break;
+        };
+        let start = pos + offset;
+        let end = text[start..]
+            .find(|c: char| !is_name_char(c))
+            .map_or(text.len(), |len| start + len);
+        pos = end;
+        if !text[end..].starts_with('(') {
+            continue;
+        }
+        let start = start + text[start..end].len() - text[start..end].trim_start_matches(':').len();
+        let start = if ends_with_script_prefix(&text[..start]) {
+            start - "<SID>".len()
+        } else {
+            start
+        };
+        if start < end {
+            ranges.push((start, end));
+        }
+    }
+    ranges
+}
+
+/// A user-defined Ex command (`:command MyCmd ...`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct UserCommand {
+    /// Command name
+    pub name: String,
+    /// Full definition (e.g., "command! -nargs=1 MyCmd call Foo(<q-args>)")
+    pub definition: String,
+    /// Start position of the name (row, column)
+    pub start: (usize, usize),
+    /// End position of the name (row, column)
+    pub end: (usize, usize),
+}
+
+/// Extract all user-defined commands from a syntax tree
+pub fn extract_user_commands(tree: &Tree, source: &str) -> Vec<UserCommand> {
+    let mut commands = Vec::new();
+    extract_user_commands_from_node(&tree.root_node(), source, &mut commands);
+    commands
+}
+
+fn extract_user_commands_from_node(node: &Node, source: &str, commands: &mut Vec<UserCommand>) {
+    if node.kind() == "command_statement" {
+        let mut cursor = node.walk();
+        let name_node = node
+            .children(&mut cursor)
+            .find(|c| c.kind() == "command_name");
+        if let Some(name_node) = name_node {
+            if let (Ok(name), Ok(definition)) = (
+                name_node.utf8_text(source.as_bytes()),
+                node.utf8_text(source.as_bytes()),
+            ) {
+                let start = name_node.start_position();
+                let end = name_node.end_position();
+                commands.push(UserCommand {
+                    name: name.to_string(),
+                    definition: definition.trim().to_string(),
+                    start: (start.row, start.column),
+                    end: (end.row, end.column),
+                });
+            }
+        }
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        extract_user_commands_from_node(&child, source, commands);
+    }
+}
+
+/// A `<Plug>` mapping definition (e.g., `nnoremap <Plug>(my-action) ...`)
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PlugMapping {
+    /// Mapping LHS including `<Plug>` (e.g., "<Plug>(my-action)")
+    pub name: String,
+    /// Full mapping definition
+    pub definition: String,
+    /// Start position of the LHS (row, column)
+    pub start: (usize, usize),
+    /// End position of the LHS (row, column)
+    pub end: (usize, usize),
+}
+
+/// Extract all `<Plug>` mapping definitions from a syntax tree
+pub fn extract_plug_mappings(tree: &Tree, source: &str) -> Vec<PlugMapping> {
+    let mut mappings = Vec::new();
+    extract_plug_mappings_from_node(&tree.root_node(), source, &mut mappings);
+    mappings
+}
+
+fn extract_plug_mappings_from_node(node: &Node, source: &str, mappings: &mut Vec<PlugMapping>) {
+    if node.kind() == "map_statement" {
+        let mut cursor = node.walk();
+        let lhs = node.children(&mut cursor).find(|c| c.kind() == "map_side");
+        if let Some(lhs) = lhs {
+            if let (Ok(name), Ok(definition)) = (
+                lhs.utf8_text(source.as_bytes()),
+                node.utf8_text(source.as_bytes()),
+            ) {
+                if name.to_ascii_lowercase().starts_with("<plug>") {
+                    let start = lhs.start_position();
+                    let end = lhs.end_position();
+                    mappings.push(PlugMapping {
+                        name: name.to_string(),
+                        definition: definition.trim().to_string(),
+                        start: (start.row, start.column),
+                        end: (end.row, end.column),
+                    });
+                }
+            }
+        }
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        extract_plug_mappings_from_node(&child, source, mappings);
+    }
+}
+
 /// Extract symbols from a syntax tree
 pub fn extract_symbols(tree: &Tree, source: &str) -> Vec<Symbol> {
     let mut symbols = Vec::new();
@@ -555,10 +1069,156 @@ fn extract_function_symbol(node: &Node, source: &str) -> Option<Symbol> {
         kind: SymbolKind::Function,
         start: (name_start.row, name_start.column),
         end: (name_end.row, name_end.column),
+        extent: node_extent(node),
         signature: Some(signature),
+        doc: extract_doc_comment(source, node.start_position().row),
     })
 }
 
+/// A literal value assigned to a variable
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssignedValue {
+    /// The literal as written (truncated when long)
+    pub value: String,
+    /// Inferred type (e.g., "Number", "String")
+    pub type_name: &'static str,
+}
+
+/// Maximum length of a displayed assigned value
+const MAX_VALUE_LEN: usize = 80;
+
+/// Find the most recent assignment to a variable before a position, if it
+/// assigns a literal (`let g:foo = 'bar'`, `let s:count = 3`)
+///
+/// Returns None when the most recent assignment is not a plain `=` of a
+/// literal (e.g., `let x += 1` or `let x = Compute()`).
+pub fn find_assigned_value(
+    tree: &Tree,
+    source: &str,
+    name: &str,
+    scope: VimScope,
+    row: usize,
+    col: usize,
+) -> Option<AssignedValue> {
+    let mut assignments = Vec::new();
+    collect_assignments(&tree.root_node(), source, name, scope, &mut assignments);
+    let position = tree_sitter::Point::new(row, col);
+    let statement = assignments
+        .into_iter()
+        .filter(|node| node.start_position() <= position)
+        .max_by_key(|node| node.start_position())?;
+
+    let mut cursor = statement.walk();
+    let children: Vec<_> = statement.children(&mut cursor).collect();
+    let operator = children.iter().position(|c| c.kind().ends_with('='))?;
+    if children[operator].kind() != "=" {
+        return None;
+    }
+    let value_node = children.get(operator + 1)?;
+    let value = value_node.utf8_text(source.as_bytes()).ok()?;
+    let type_name = match value_node.kind() {
+        "integer_literal" => "Number",
+        "float_literal" => "Float",
+        "string_literal" => "String",
+        "list" => "List",
+        "dictionnary" | "literal_dictionary" => "Dict",
+        "scoped_identifier" => match value {
+            "v:true" | "v:false" => "Boolean",
+            "v:null" | "v:none" => "Special",
+            _ => return None,
+        },
+        _ => return None,
+    };
+    let value = if value.len() > MAX_VALUE_LEN {
+        let mut end = MAX_VALUE_LEN - 3;
+        while !value.is_char_boundary(end) {
+            end -= 1;
+        }
+        format!("{}...", &value[..end])
+    } else {
+        value.to_string()
+    };
+    Some(AssignedValue { value, type_name })
+}
+
+fn collect_assignments<'a>(
+    node: &Node<'a>,
+    source: &str,
+    name: &str,
+    scope: VimScope,
+    assignments: &mut Vec<Node<'a>>,
+) {
+    if matches!(node.kind(), "let_statement" | "const_statement") {
+        let mut cursor = node.walk();
+        let target = node
+            .children(&mut cursor)
+            .find(|c| c.kind() == "identifier" || c.kind() == "scoped_identifier")
+            .and_then(|target| extract_name_and_scope(&target, source));
+        if let Some((target_name, target_scope, _, _)) = target {
+            if target_name == name && (scope == VimScope::Implicit || target_scope == scope) {
+                assignments.push(*node);
+            }
+        }
+        return;
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_assignments(&child, source, name, scope, assignments);
+    }
+}
+
+/// Find the value of the last `let` assignment to a global variable
+///
+/// Both `let mapleader = ","` and `let g:mapleader = ","` are recognized.
+/// Returns the value expression as written.
+pub fn find_global_assignment(source: &str, name: &str) -> Option<String> {
+    source
+        .lines()
+        .rev()
+        .filter_map(|line| {
+            let rest = line.trim_start().strip_prefix("let")?;
+            if !rest.starts_with(char::is_whitespace) {
+                return None;
+            }
+            let rest = rest.trim_start();
+            let rest = rest.strip_prefix("g:").unwrap_or(rest).strip_prefix(name)?;
+            let value = rest.trim_start().strip_prefix('=')?;
+            if value.starts_with('=') {
+                return None;
+            }
+            Some(value.trim().to_string())
+        })
+        .find(|value| !value.is_empty())
+}
+
+/// Extract the contiguous comment block immediately preceding a line
+///
+/// Both legacy (`"`) and Vim9 (`#`) comments are recognized. The comment
+/// markers and one following space are stripped.
+pub fn extract_doc_comment(source: &str, row: usize) -> Option<String> {
+    let lines: Vec<&str> = source.lines().take(row).collect();
+    let mut doc: Vec<&str> = lines
+        .iter()
+        .rev()
+        .map(|line| line.trim_start())
+        .map_while(|line| {
+            let text = line
+                .strip_prefix('"')
+                .or_else(|| line.strip_prefix('#').filter(|rest| !rest.starts_with('{')))?;
+            Some(text.strip_prefix(' ').unwrap_or(text).trim_end())
+        })
+        .collect();
+    doc.reverse();
+    // Drop leading/trailing blank comment lines
+    while doc.first().is_some_and(|line| line.is_empty()) {
+        doc.remove(0);
+    }
+    while doc.last().is_some_and(|line| line.is_empty()) {
+        doc.pop();
+    }
+    (!doc.is_empty()).then(|| doc.join("\n"))
+}
+
 fn extract_variable_symbol(node: &Node, source: &str) -> Option<Symbol> {
     // Find the identifier or scoped_identifier
     let mut cursor = node.walk();
@@ -574,10 +1234,17 @@ fn extract_variable_symbol(node: &Node, source: &str) -> Option<Symbol> {
         kind: SymbolKind::Variable,
         start: (start.row, start.column),
         end: (end.row, end.column),
+        extent: node_extent(node),
         signature: None,
+        doc: None,
     })
 }
 
+fn node_extent(node: &Node) -> ((usize, usize), (usize, usize)) {
+    let (start, end) = (node.start_position(), node.end_position());
+    ((start.row, start.column), (end.row, end.column))
+}
+
 fn extract_name_and_scope(
     node: &Node,
     source: &str,
@@ -604,7 +1271,7 @@ fn extract_name_and_scope(
 
             Some((
                 name,
-                VimScope::from_str(scope_text),
+                VimScope::from_prefix(scope_text),
                 ident_node.start_position(),
                 ident_node.end_position(),
             ))
@@ -689,6 +1356,128 @@ pub fn find_call_at_position(
 ) -> Option<CallInfo> {
     let root = tree.root_node();
     find_call_in_node(&root, source, row, col)
+        .or_else(|| find_call_in_line(source.lines().nth(row)?, col))
+}
+
+/// Find the Ex command whose arguments are being typed at the given position
+///
+/// Returns the command name as written (possibly abbreviated) when the cursor
+/// is past it, e.g. `edit |` or `1,3delete |`. Leading `:` and a range made of
+/// line numbers, marks and `.,$%;+-` are skipped.
+pub fn find_command_call_at_position(source: &str, row: usize, col: usize) -> Option<String> {
+    let line = source.lines().nth(row)?;
+    let before = line.get(..col.min(line.len()))?;
+    let head = before.trim_start_matches(|c: char| c.is_whitespace() || c == ':');
+    let mut chars = head.char_indices().peekable();
+    while let Some(&(_, c)) = chars.peek() {
+        match c {
+            '0'..='9' | '.' | ',' | '$' | '%' | ';' | '+' | '-' | ' ' => {
+                chars.next();
+            }
+            // A mark such as 'a or '<
+            '\'' => {
+                chars.next();
+                chars.next();
+            }
+            _ => break,
+        }
+    }
+    let start = chars.peek()?.0;
+    let rest = &head[start..];
+    let end = rest
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|&end| end > 0)?;
+    if !rest[..1].chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+    // Not a command but an expression (e.g., `x = 1`, `foo.bar`, `Func()`)
+    let after = &rest[end..];
+    if after.starts_with(['(', '.', '[', ':', '#', '-', '_']) || after.trim_start().starts_with('=')
+    {
+        return None;
+    }
+    Some(rest[..end].to_string())
+}
+
+/// Text-based fallback for lines tree-sitter-vim cannot parse
+///
+/// This handles incomplete calls being typed and Vim9 lambdas such as
+/// `(i, v) => v > 1`, which are parsed as ERROR nodes. Only the current line
+/// is scanned.
+fn find_call_in_line(line: &str, col: usize) -> Option<CallInfo> {
+    /// An unclosed bracket before the cursor
+    struct Frame {
+        /// Function name for call parentheses, empty for other brackets
+        name: String,
+        /// Whether the call is a method call (`expr->name(`)
+        is_method: bool,
+        /// Number of commas seen at this level
+        commas: usize,
+    }
+
+    let before = line.get(..col.min(line.len()))?;
+    let mut stack: Vec<Frame> = Vec::new();
+    let mut in_string: Option<char> = None;
+    let mut prev_escape = false;
+
+    for (i, c) in before.char_indices() {
+        if let Some(q) = in_string {
+            if q == '"' && c == '\\' && !prev_escape {
+                prev_escape = true;
+                continue;
+            }
+            if c == q && !prev_escape {
+                in_string = None;
+            }
+            prev_escape = false;
+            continue;
+        }
+        match c {
+            '\'' | '"' => in_string = Some(c),
+            '(' => {
+                let head = &before[..i];
+                let name_start = head
+                    .rfind(|ch: char| {
+                        !(ch.is_alphanumeric() || matches!(ch, '_' | ':' | '#' | '.'))
+                    })
+                    .map_or(0, |p| p + 1);
+                let name = &head[name_start..];
+                // A name must start with a letter, `_` or a scope (not `.5` or `:`)
+                let name = if name.starts_with(|ch: char| ch.is_alphabetic() || ch == '_') {
+                    name
+                } else {
+                    ""
+                };
+                stack.push(Frame {
+                    name: name.to_string(),
+                    is_method: !name.is_empty() && head[..name_start].ends_with("->"),
+                    commas: 0,
+                });
+            }
+            '[' | '{' => stack.push(Frame {
+                name: String::new(),
+                is_method: false,
+                commas: 0,
+            }),
+            ')' | ']' | '}' => {
+                stack.pop();
+            }
+            ',' => {
+                if let Some(frame) = stack.last_mut() {
+                    frame.commas += 1;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    // Innermost call; lambda parameter lists and other brackets are skipped
+    let frame = stack.iter().rev().find(|f| !f.name.is_empty())?;
+    Some(CallInfo {
+        function_name: frame.name.clone(),
+        autoload: AutoloadRef::parse(&frame.name),
+        active_param: frame.commas + usize::from(frame.is_method),
+    })
 }
 
 fn find_call_in_node(node: &Node, source: &str, row: usize, col: usize) -> Option<CallInfo> {
@@ -750,7 +1539,12 @@ fn extract_call_info(node: &Node, source: &str, row: usize, col: usize) -> Optio
 
     // Calculate active parameter by counting commas before cursor position
     // tree-sitter-vim doesn't have an "arguments" node - args are direct children of call_expression
-    let active_param = calculate_active_param(&children, row, col);
+    let mut active_param = calculate_active_param(&children, row, col);
+
+    // In a method call (`expr->func(...)`), `expr` is passed as the first argument
+    if node.prev_sibling().is_some_and(|s| s.kind() == "->") {
+        active_param += 1;
+    }
 
     Some(CallInfo {
         function_name,
@@ -809,6 +1603,272 @@ mod tests {
         parser.parse(code, None).unwrap()
     }
 
+    #[test]
+    fn test_function_doc_comment() {
+        let code = r#"let g:x = 1
+
+" Greet someone.
+"
+" Returns the greeting.
+function! Greet(name) abort
+  return 'Hello ' . a:name
+endfunction
+
+function! NoDoc() abort
+endfunction
+"#;
+        let tree = parse(code);
+        let symbols = extract_symbols(&tree, code);
+        let greet = symbols.iter().find(|s| s.name == "Greet").unwrap();
+        assert_eq!(
+            greet.doc.as_deref(),
+            Some("Greet someone.\n\nReturns the greeting.")
+        );
+        let no_doc = symbols.iter().find(|s| s.name == "NoDoc").unwrap();
+        assert_eq!(no_doc.doc, None);
+
+        let vim9 = "vim9script\n# Add numbers\ndef Add(a: number, b: number): number\n  return a + b\nenddef\n";
+        assert_eq!(extract_doc_comment(vim9, 2).as_deref(), Some("Add numbers"));
+    }
+
+    #[test]
+    fn test_find_sourced_file() {
+        let code = r#"source ~/foo.vim
+runtime! plugin/*.vim
+execute "runtime autoload/y.vim"
+execute "source " . "lib.vim"
+execute "echo 'x'"
+"#;
+        let tree = parse(code);
+        let find = |row, col| find_sourced_file(&tree, code, row, col);
+
+        let file = find(0, 10).unwrap();
+        assert_eq!((file.path.as_str(), file.runtime), ("~/foo.vim", false));
+        let file = find(1, 12).unwrap();
+        assert_eq!((file.path.as_str(), file.runtime), ("plugin/*.vim", true));
+        let file = find(2, 20).unwrap();
+        assert_eq!((file.path.as_str(), file.runtime), ("autoload/y.vim", true));
+        let file = find(3, 22).unwrap();
+        assert_eq!((file.path.as_str(), file.runtime), ("lib.vim", false));
+        assert_eq!(find(4, 12), None);
+        assert_eq!(find(0, 2), None);
+    }
+
+    #[test]
+    fn test_find_function_name_reference() {
+        let code = r#"call timer_start(10, 'MyCb')
+let F = function('s:Func')
+let opts = {'callback': 'ns#util#fn'}
+nnoremap <silent> x :call <SID>Func()<CR>
+nnoremap y :call s:Other(1)<CR>
+echo 'not a function name'
+nnoremap <silent> あいう :call <SID>Foo()<CR>
+"#;
+        let tree = parse(code);
+        let find = |row, col| find_function_name_reference(&tree, code, row, col);
+
+        let reference = find(0, 23).unwrap();
+        assert_eq!(reference.name, "MyCb");
+        assert_eq!(reference.scope, VimScope::Implicit);
+        assert!(reference.is_call);
+
+        let reference = find(1, 20).unwrap();
+        assert_eq!(reference.name, "Func");
+        assert_eq!(reference.scope, VimScope::Script);
+
+        let reference = find(2, 28).unwrap();
+        assert_eq!(
+            reference.autoload.map(|a| a.full_name).as_deref(),
+            Some("ns#util#fn")
+        );
+
+        // On the name and on `<SID>` itself
+        let reference = find(3, 33).unwrap();
+        assert_eq!(
+            (reference.name.as_str(), reference.scope),
+            ("Func", VimScope::Script)
+        );
+        let reference = find(3, 27).unwrap();
+        assert_eq!(
+            (reference.name.as_str(), reference.scope),
+            ("Func", VimScope::Script)
+        );
+
+        let reference = find(4, 20).unwrap();
+        assert_eq!(
+            (reference.name.as_str(), reference.scope),
+            ("Other", VimScope::Script)
+        );
+
+        assert!(find(5, 8).is_none());
+
+        // Columns count UTF-16 units after multibyte text
+        assert!(find(6, 19).is_none());
+        let reference = find(6, 34).unwrap();
+        assert_eq!(
+            (reference.name.as_str(), reference.scope),
+            ("Foo", VimScope::Script)
+        );
+    }
+
+    #[test]
+    fn test_script_prefix_len() {
+        assert_eq!(script_prefix_len("<SID>Func"), Some(5));
+        assert_eq!(script_prefix_len("<sid>Func"), Some(5));
+        assert_eq!(script_prefix_len("<SNR>12_Func"), Some(8));
+        assert_eq!(script_prefix_len("<SNR>Func"), None);
+        assert_eq!(script_prefix_len("s:Func"), None);
+
+        let code = "nnoremap x :call <SNR>12_Func()<CR>\nlet F = function('<SNR>3_Func')\n";
+        let tree = parse(code);
+        let starts: Vec<_> = find_references(&tree, code, "Func", VimScope::Script, true)
+            .iter()
+            .map(|loc| (loc.start, loc.end))
+            .collect();
+        assert_eq!(starts, vec![((0, 17), (0, 29)), ((1, 18), (1, 29))]);
+    }
+
+    #[test]
+    fn test_find_references_in_strings_and_mappings() {
+        let code = r#"function! s:Bar() abort
+endfunction
+call s:Bar()
+let F = function('s:Bar')
+let opts = {'callback': 's:Bar', 'name': 's:Bar'}
+nnoremap x :call <SID>Bar()<CR>
+command! Foo call s:Bar()
+echo 's:Bar'
+"#;
+        let tree = parse(code);
+        let starts: Vec<_> = find_references(&tree, code, "Bar", VimScope::Script, true)
+            .iter()
+            .map(|loc| (loc.start, loc.end))
+            .collect();
+        assert_eq!(
+            starts,
+            vec![
+                ((0, 10), (0, 15)),
+                ((2, 5), (2, 10)),
+                ((3, 18), (3, 23)),
+                ((4, 25), (4, 30)),
+                ((5, 17), (5, 25)),
+                ((6, 18), (6, 23)),
+            ]
+        );
+        let declarations = find_references_with_kind(&tree, code, "Bar", VimScope::Script)
+            .iter()
+            .filter(|r| r.is_declaration)
+            .count();
+        assert_eq!(declarations, 1);
+        let text_starts: Vec<_> = find_text_references(&tree, code, "Bar", VimScope::Script)
+            .iter()
+            .map(|loc| loc.start)
+            .collect();
+        assert_eq!(text_starts, vec![(3, 18), (4, 25), (5, 17), (6, 18)]);
+
+        // A bare name matches the name part only, like identifiers
+        let starts: Vec<_> = find_references(&tree, code, "Bar", VimScope::Implicit, true)
+            .iter()
+            .map(|loc| loc.start)
+            .collect();
+        assert_eq!(
+            starts,
+            vec![(0, 12), (2, 7), (3, 20), (4, 27), (5, 22), (6, 20)]
+        );
+    }
+
+    #[test]
+    fn test_find_assigned_value() {
+        let code = r#"let g:foo = 'bar'
+let s:count = 3
+echo s:count
+let s:count += 1
+echo s:count
+let s:items = [1, 2]
+let s:items = Compute()
+echo s:items
+"#;
+        let tree = parse(code);
+        let find = |name, scope, row| find_assigned_value(&tree, code, name, scope, row, 5);
+
+        let value = find("foo", VimScope::Global, 0).unwrap();
+        assert_eq!(value.value, "'bar'");
+        assert_eq!(value.type_name, "String");
+
+        // The most recent assignment before the position wins
+        let value = find("count", VimScope::Script, 2).unwrap();
+        assert_eq!(value.value, "3");
+        assert_eq!(value.type_name, "Number");
+        assert_eq!(find("count", VimScope::Script, 4), None);
+        assert_eq!(
+            find("items", VimScope::Script, 5).map(|v| v.type_name),
+            Some("List")
+        );
+        assert_eq!(find("items", VimScope::Script, 7), None);
+        assert_eq!(find("foo", VimScope::Script, 7), None);
+    }
+
+    #[test]
+    fn test_find_global_assignment() {
+        let code =
+            "let mapleader = \",\"\nlet g:mapleader = \"\\<Space>\"\nlet maplocalleader=' '\n";
+        assert_eq!(
+            find_global_assignment(code, "mapleader").as_deref(),
+            Some("\"\\<Space>\"")
+        );
+        assert_eq!(
+            find_global_assignment(code, "maplocalleader").as_deref(),
+            Some("' '")
+        );
+        assert_eq!(
+            find_global_assignment("if mapleader == ','", "mapleader"),
+            None
+        );
+        assert_eq!(
+            find_global_assignment("let mapleaders = 1", "mapleader"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_find_token_at_position() {
+        let code = "call hi#greet(g:x)\nwrite\n";
+        let tree = parse(code);
+        let range = |node: Node| (node.start_position().column, node.end_position().column);
+
+        let token = find_token_at_position(&tree, 0, 7).unwrap();
+        assert_eq!(range(token), (5, 13));
+        // Scope and name are a single token
+        let token = find_token_at_position(&tree, 0, 14).unwrap();
+        assert_eq!(token.kind(), "scoped_identifier");
+        assert_eq!(range(token), (14, 17));
+        let token = find_token_at_position(&tree, 1, 2).unwrap();
+        assert_eq!(token.utf8_text(code.as_bytes()).unwrap(), "write");
+    }
+
+    #[test]
+    fn test_find_angle_bracket_token() {
+        let line = "nnoremap <silent> x :call F(<f-args>)<CR>";
+        assert_eq!(find_angle_bracket_token(line, 9), Some((9, 17)));
+        assert_eq!(find_angle_bracket_token(line, 12), Some((9, 17)));
+        assert_eq!(find_angle_bracket_token(line, 17), None);
+        assert_eq!(find_angle_bracket_token(line, 30), Some((28, 36)));
+        assert_eq!(find_angle_bracket_token("if a < b", 7), None);
+        // Columns inside a multibyte character don't panic
+        let line = "nnoremap <silent> あいう :call <SID>Foo()<CR>";
+        assert_eq!(find_angle_bracket_token(line, 19), None);
+        assert_eq!(find_angle_bracket_token(line, 35), Some((34, 39)));
+    }
+
+    #[test]
+    fn test_find_node_of_kind() {
+        let source = "autocmd BufRead,BufNewFile *.foo setfiletype foo\n";
+        let tree = parse(source);
+        let node = find_node_of_kind(&tree, 0, 18, &["au_event"]).unwrap();
+        assert_eq!(node.utf8_text(source.as_bytes()).unwrap(), "BufNewFile");
+        assert!(find_node_of_kind(&tree, 0, 29, &["au_event"]).is_none());
+    }
+
     #[test]
     fn test_extract_global_function() {
         let code = "function! MyFunc(a, b)\nendfunction";
@@ -820,6 +1880,8 @@ mod tests {
         assert_eq!(symbols[0].scope, VimScope::Implicit);
         assert_eq!(symbols[0].kind, SymbolKind::Function);
         assert_eq!(symbols[0].signature, Some("MyFunc(a, b)".to_string()));
+        assert_eq!(symbols[0].start, (0, 10));
+        assert_eq!(symbols[0].extent, ((0, 0), (1, 11)));
     }
 
     #[test]
@@ -898,4 +1960,90 @@ mod tests {
         let info = find_call_at_position(&tree, code, 0, 33).unwrap();
         assert_eq!(info.active_param, 3);
     }
+
+    #[test]
+    fn test_find_command_call_at_position() {
+        let find = |line: &str| find_command_call_at_position(line, 0, line.len());
+        assert_eq!(find("edit "), Some("edit".to_string()));
+        assert_eq!(find("  :1,$s/a/b/").as_deref(), Some("s"));
+        assert_eq!(find("x = 1"), None);
+        assert_eq!(find("'<,'>sort! ").as_deref(), Some("sort"));
+        assert_eq!(find("bd!").as_deref(), Some("bd"));
+        // Still typing the name
+        assert_eq!(find("edi"), None);
+        assert_eq!(find(""), None);
+        assert_eq!(find("\"comment "), None);
+    }
+
+    #[test]
+    fn test_find_call_at_position_method_chain() {
+        // Method call: the base expression is the first argument
+        //          0         1         2         3
+        //          0123456789012345678901234567890123456789
+        let code = "let x = l->filter({i, v -> v > 1})->map({_, v -> v}, 3)";
+        let tree = parse(code);
+
+        // Inside the lambda passed to filter(): second argument of filter()
+        let info = find_call_at_position(&tree, code, 0, 28).unwrap();
+        assert_eq!(info.function_name, "filter");
+        assert_eq!(info.active_param, 1);
+
+        // After the comma in map(): third argument of map()
+        let info = find_call_at_position(&tree, code, 0, 53).unwrap();
+        assert_eq!(info.function_name, "map");
+        assert_eq!(info.active_param, 2);
+    }
+
+    #[test]
+    fn test_find_call_at_position_vim9_lambda() {
+        // Vim9 lambdas are not parsed by tree-sitter-vim
+        let code = "var x = l->filter((i, v) => v > 1)->map((_, v) => ";
+        let tree = parse(code);
+        let info = find_call_at_position(&tree, code, 0, code.len()).unwrap();
+        assert_eq!(info.function_name, "map");
+        assert_eq!(info.active_param, 1);
+
+        // Cursor inside the lambda parameter list: still the enclosing call
+        let code = "call filter(l, (i, ";
+        let tree = parse(code);
+        let info = find_call_at_position(&tree, code, 0, code.len()).unwrap();
+        assert_eq!(info.function_name, "filter");
+        assert_eq!(info.active_param, 1);
+
+        // Incomplete call with a string containing parentheses
+        let code = "echo substitute('(a,b)', ";
+        let tree = parse(code);
+        let info = find_call_at_position(&tree, code, 0, code.len()).unwrap();
+        assert_eq!(info.function_name, "substitute");
+        assert_eq!(info.active_param, 1);
+    }
+
+    #[test]
+    fn test_extract_user_commands() {
+        let code = "command! -nargs=1 -bang MyCmd call Foo(<q-args>)\ncom Bar echo 1\n";
+        let tree = parse(code);
+        let commands = extract_user_commands(&tree, code);
+
+        assert_eq!(commands.len(), 2);
+        assert_eq!(commands[0].name, "MyCmd");
+        assert_eq!(
+            commands[0].definition,
+            "command! -nargs=1 -bang MyCmd call Foo(<q-args>)"
+        );
+        assert_eq!(commands[0].start, (0, 24));
+        assert_eq!(commands[1].name, "Bar");
+    }
+
+    #[test]
+    fn test_extract_plug_mappings() {
+        let code = "nnoremap <silent> <Plug>(my-action) :<C-u>call Foo()<CR>\n\
+                    nmap <Plug>MyOld <Plug>(my-action)\n\
+                    nmap x <Plug>(my-action)\n";
+        let tree = parse(code);
+        let mappings = extract_plug_mappings(&tree, code);
+
+        let names: Vec<_> = mappings.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["<Plug>(my-action)", "<Plug>MyOld"]);
+        assert_eq!(mappings[0].start, (0, 18));
+    }
 }
@@ -0,0 +1,106 @@
+//! Incremental text document synchronization
+//!
+//! Clients send the edited ranges of a document instead of its full text.
+//! Each change is applied to the text and turned into a tree-sitter
+//! `InputEdit`, so that the previous syntax tree can be reused when reparsing.
+
+use tower_lsp_server::ls_types::{Position, Range};
+use tree_sitter::{InputEdit, Point};
+
+/// Convert an LSP position (UTF-16 column) to a byte offset in the text
+///
+/// Positions past the end of a line or of the text are clamped.
+pub fn position_to_byte(text: &str, position: Position) -> usize {
+    let line_start = if position.line == 0 {
+        0
+    } else {
+        match text
+            .match_indices('\n')
+            .nth(position.line as usize - 1)
+            .map(|(i, _)| i + 1)
+        {
+            Some(start) => start,
+            None => return text.len(),
+        }
+    };
+    let line = &text[line_start..];
+    let line = &line[..line.find('\n').unwrap_or(line.len())];
+    let mut utf16_column = 0;
+    for (i, c) in line.char_indices() {
+        if utf16_column >= position.character as usize {
+            return line_start + i;
+        }
+        utf16_column += c.len_utf16();
+    }
+    line_start + line.len()
+}
+
+/// Get the tree-sitter point (row, byte column) of a byte offset
+fn byte_to_point(text: &str, byte: usize) -> Point {
+    let before = &text[..byte];
+    let row = before.matches('\n').count();
+    let column = before.rfind('\n').map_or(byte, |i| byte - i - 1);
+    Point::new(row, column)
+}
+
+/// Replace a range of the text, returning the corresponding tree edit
+pub fn apply_change(text: &mut String, range: Range, new_text: &str) -> InputEdit {
+    let start_byte = position_to_byte(text, range.start);
+    let old_end_byte = position_to_byte(text, range.end).max(start_byte);
+    let start_position = byte_to_point(text, start_byte);
+    let old_end_position = byte_to_point(text, old_end_byte);
+
+    text.replace_range(start_byte..old_end_byte, new_text);
+    let new_end_byte = start_byte + new_text.len();
+
+    InputEdit {
+        start_byte,
+        old_end_byte,
+        new_end_byte,
+        start_position,
+        old_end_position,
+        new_end_position: byte_to_point(text, new_end_byte),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn range(start: (u32, u32), end: (u32, u32)) -> Range {
+        Range {
+            start: Position::new(start.0, start.1),
+            end: Position::new(end.0, end.1),
+        }
+    }
+
+    #[test]
+    fn test_position_to_byte() {
+        let text = "let x = 1\necho 'あい' x\n";
+        assert_eq!(position_to_byte(text, Position::new(0, 4)), 4);
+        assert_eq!(position_to_byte(text, Position::new(1, 0)), 10);
+        // `あ` is one UTF-16 unit but three bytes
+        assert_eq!(position_to_byte(text, Position::new(1, 8)), 10 + 6 + 6);
+        // Clamped to the end of the line and of the text
+        assert_eq!(position_to_byte(text, Position::new(0, 99)), 9);
+        assert_eq!(position_to_byte(text, Position::new(9, 0)), text.len());
+    }
+
+    #[test]
+    fn test_apply_change() {
+        let mut text = String::from("let x = 1\necho x\n");
+        let edit = apply_change(&mut text, range((1, 5), (1, 6)), "y\necho z");
+        assert_eq!(text, "let x = 1\necho y\necho z\n");
+        assert_eq!(edit.start_byte, 15);
+        assert_eq!(edit.old_end_byte, 16);
+        assert_eq!(edit.new_end_byte, 23);
+        assert_eq!(edit.start_position, Point::new(1, 5));
+        assert_eq!(edit.old_end_position, Point::new(1, 6));
+        assert_eq!(edit.new_end_position, Point::new(2, 6));
+
+        // Deleting a line
+        let edit = apply_change(&mut text, range((0, 0), (1, 0)), "");
+        assert_eq!(text, "echo y\necho z\n");
+        assert_eq!(edit.new_end_position, Point::new(0, 0));
+    }
+}
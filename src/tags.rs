@@ -0,0 +1,191 @@
+//! ctags-compatible tags files
+//!
+//! Huge runtimes are often indexed with ctags. When a symbol cannot be
+//! resolved from the workspace index, the `tags` files Vim would use are
+//! consulted as a fallback.
+
+use std::path::{Path, PathBuf};
+
+/// A tag entry (`name<Tab>file<Tab>address[;"<Tab>fields...]`)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagEntry {
+    pub name: String,
+    /// File path, relative to the directory of the tags file
+    pub file: String,
+    /// Ex command locating the tag: a line number or a search pattern
+    pub address: String,
+}
+
+impl TagEntry {
+    /// Parse a line of a tags file
+    ///
+    /// Returns None for pseudo-tags (`!_TAG_...`) and malformed lines.
+    pub fn parse(line: &str) -> Option<Self> {
+        if line.starts_with("!_TAG_") {
+            return None;
+        }
+        let mut fields = line.splitn(3, '\t');
+        let name = fields.next()?;
+        let file = fields.next()?;
+        let rest = fields.next()?;
+        // The address ends at `;"` followed by extension fields
+        let address = rest.split(";\"\t").next().unwrap_or(rest);
+        let address = address.strip_suffix(";\"").unwrap_or(address);
+        Some(Self {
+            name: name.to_string(),
+            file: file.to_string(),
+            address: address.to_string(),
+        })
+    }
+
+    /// Format the entry as a line of a tags file, with a kind field
+    ///
+    /// Kinds follow Universal Ctags for Vim: `f` function, `c` command,
+    /// `v` variable.
+    pub fn to_line(&self, kind: char) -> String {
+        format!(
+            "{}\t{}\t{};\"\t{}",
+            self.name, self.file, self.address, kind
+        )
+    }
+
+    /// Find the 0-based line of the tag in the content of its file
+    pub fn line(&self, content: &str) -> Option<usize> {
+        if let Ok(line) = self.address.parse::<usize>() {
+            return Some(line.saturating_sub(1));
+        }
+        let pattern = self
+            .address
+            .strip_prefix('/')
+            .and_then(|p| p.strip_suffix('/'))
+            .or_else(|| {
+                self.address
+                    .strip_prefix('?')
+                    .and_then(|p| p.strip_suffix('?'))
+            })?;
+        let (pattern, start_anchor) = match pattern.strip_prefix('^') {
+            Some(pattern) => (pattern, true),
+            None => (pattern, false),
+        };
+        let (pattern, end_anchor) = match pattern.strip_suffix('$') {
+            Some(pattern) => (pattern, true),
+            None => (pattern, false),
+        };
+        let pattern = pattern.replace("\\/", "/").replace("\\\\", "\\");
+        content
+            .lines()
+            .position(|line| match (start_anchor, end_anchor) {
+                (true, true) => line == pattern,
+                (true, false) => line.starts_with(&pattern),
+                (false, true) => line.ends_with(&pattern),
+                (false, false) => line.contains(&pattern),
+            })
+    }
+}
+
+/// Pseudo-tags at the start of a sorted tags file generated by hjkls
+pub fn header() -> String {
+    format!(
+        "!_TAG_FILE_FORMAT\t2\t/extended format/\n\
+         !_TAG_FILE_SORTED\t1\t/0=unsorted, 1=sorted/\n\
+         !_TAG_PROGRAM_NAME\t{}\t//\n\
+         !_TAG_PROGRAM_VERSION\t{}\t//\n",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION")
+    )
+}
+
+/// Find the first entry for any of `names` in the content of a tags file
+pub fn find_entry(content: &str, names: &[&str]) -> Option<TagEntry> {
+    content
+        .lines()
+        .filter(|line| {
+            line.split('\t')
+                .next()
+                .is_some_and(|name| names.contains(&name))
+        })
+        .find_map(TagEntry::parse)
+}
+
+/// Candidate tags files for a file, like Vim's default `'tags'` (`./tags;,tags`)
+///
+/// The directory of the file and its ancestors up to a workspace root are
+/// searched, followed by the workspace roots themselves.
+pub fn candidates(file: Option<&Path>, roots: &[PathBuf]) -> Vec<PathBuf> {
+    let mut candidates = Vec::new();
+    if let Some(dir) = file.and_then(Path::parent) {
+        for ancestor in dir.ancestors() {
+            candidates.push(ancestor.join("tags"));
+            if roots.iter().any(|root| root == ancestor) {
+                break;
+            }
+        }
+    }
+    for root in roots {
+        let tags = root.join("tags");
+        if !candidates.contains(&tags) {
+            candidates.push(tags);
+        }
+    }
+    candidates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_entry() {
+        let entry =
+            TagEntry::parse("s:Helper\tplugin/foo.vim\t/^function! s:Helper()$/;\"\tf").unwrap();
+        assert_eq!(entry.name, "s:Helper");
+        assert_eq!(entry.file, "plugin/foo.vim");
+        assert_eq!(entry.address, "/^function! s:Helper()$/");
+
+        let entry = TagEntry::parse("Foo\tfoo.vim\t12;\"\tf").unwrap();
+        assert_eq!(entry.address, "12");
+        assert_eq!(TagEntry::parse("!_TAG_FILE_FORMAT\t2\t/extended/"), None);
+    }
+
+    #[test]
+    fn test_entry_to_line() {
+        let entry = TagEntry {
+            name: "s:Helper".to_string(),
+            file: "plugin/foo.vim".to_string(),
+            address: "12".to_string(),
+        };
+        let line = entry.to_line('f');
+        assert_eq!(line, "s:Helper\tplugin/foo.vim\t12;\"\tf");
+        assert_eq!(TagEntry::parse(&line), Some(entry));
+        assert!(header().lines().all(|line| TagEntry::parse(line).is_none()));
+    }
+
+    #[test]
+    fn test_entry_line() {
+        let content = "\" comment\nfunction! s:Helper()\nendfunction\n";
+        let entry = TagEntry::parse("s:Helper\tfoo.vim\t/^function! s:Helper()$/;\"\tf").unwrap();
+        assert_eq!(entry.line(content), Some(1));
+        let entry = TagEntry::parse("s:Helper\tfoo.vim\t3").unwrap();
+        assert_eq!(entry.line(content), Some(2));
+        let entry = TagEntry::parse("Missing\tfoo.vim\t/^function! Missing()$/").unwrap();
+        assert_eq!(entry.line(content), None);
+    }
+
+    #[test]
+    fn test_find_entry() {
+        let content = "!_TAG_FILE_SORTED\t1\t//\nBar\tbar.vim\t1\ns:Foo\tfoo.vim\t2\n";
+        let entry = find_entry(content, &["Foo", "s:Foo"]).unwrap();
+        assert_eq!(entry.file, "foo.vim");
+        assert_eq!(find_entry(content, &["Baz"]), None);
+    }
+
+    #[test]
+    fn test_candidates() {
+        let roots = vec![PathBuf::from("/ws")];
+        let candidates = candidates(Some(Path::new("/ws/plugin/foo.vim")), &roots);
+        assert_eq!(
+            candidates,
+            vec![PathBuf::from("/ws/plugin/tags"), PathBuf::from("/ws/tags")]
+        );
+    }
+}
@@ -0,0 +1,140 @@
+//! Transports other than stdio: TCP (`--port`) and local sockets (`--socket`)
+//!
+//! Each connection gets its own LSP session, created by `new_service`.
+
+use std::path::{Path, PathBuf};
+
+use tokio::io::{AsyncRead, AsyncWrite};
+use tower_lsp_server::{ClientSocket, LspService, Server};
+
+use crate::backend::Backend;
+use crate::log_debug;
+use crate::watchdog::{self, ActivityReader};
+
+/// Serve LSP sessions over TCP until the process is terminated or the idle
+/// timeout elapses
+pub async fn serve_tcp<F>(host: &str, port: u16, new_service: F) -> std::io::Result<()>
+where
+    F: Fn() -> (LspService<Backend>, ClientSocket),
+{
+    let listener = tokio::net::TcpListener::bind((host, port)).await?;
+    if let Ok(addr) = listener.local_addr() {
+        eprintln!("listening on {addr}");
+    }
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, peer)) => {
+                    log_debug!("accepted connection from {}", peer);
+                    spawn_session(stream, &new_service);
+                }
+                Err(e) => log_debug!("accept failed: {}", e),
+            },
+            _ = watchdog::shutdown() => return Ok(()),
+        }
+    }
+}
+
+/// Serve LSP sessions over a unix domain socket until the process is terminated
+/// or the idle timeout elapses
+///
+/// The socket file is removed on SIGINT, SIGTERM and the idle timeout. A stale socket file left
+/// by a killed server is replaced, but a socket another server is listening
+/// on is not.
+#[cfg(unix)]
+pub async fn serve_socket<F>(path: &Path, new_service: F) -> std::io::Result<()>
+where
+    F: Fn() -> (LspService<Backend>, ClientSocket),
+{
+    use std::os::unix::fs::FileTypeExt;
+    use tokio::net::{UnixListener, UnixStream};
+    use tokio::signal::unix::{SignalKind, signal};
+
+    if let Ok(metadata) = std::fs::symlink_metadata(path) {
+        if !metadata.file_type().is_socket() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AlreadyExists,
+                "the path exists and is not a socket",
+            ));
+        }
+        if UnixStream::connect(path).await.is_ok() {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::AddrInUse,
+                "another server is listening on the socket",
+            ));
+        }
+        std::fs::remove_file(path)?;
+    }
+    let listener = UnixListener::bind(path)?;
+    let _guard = SocketFile(path.to_path_buf());
+    eprintln!("listening on {}", path.display());
+
+    let mut interrupt = signal(SignalKind::interrupt())?;
+    let mut terminate = signal(SignalKind::terminate())?;
+    loop {
+        tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok((stream, _)) => {
+                    log_debug!("accepted connection on {}", path.display());
+                    spawn_session(stream, &new_service);
+                }
+                Err(e) => log_debug!("accept failed: {}", e),
+            },
+            _ = interrupt.recv() => return Ok(()),
+            _ = terminate.recv() => return Ok(()),
+            _ = watchdog::shutdown() => return Ok(()),
+        }
+    }
+}
+
+/// Serve LSP sessions over a named pipe (e.g., `\\.\pipe\hjkls`) until the
+/// process is terminated or the idle timeout elapses
+///
+/// The pipe is gone once the server exits, so there is nothing to clean up.
+#[cfg(windows)]
+pub async fn serve_socket<F>(path: &Path, new_service: F) -> std::io::Result<()>
+where
+    F: Fn() -> (LspService<Backend>, ClientSocket),
+{
+    use tokio::net::windows::named_pipe::ServerOptions;
+
+    let mut server = ServerOptions::new()
+        .first_pipe_instance(true)
+        .create(path)?;
+    eprintln!("listening on {}", path.display());
+    loop {
+        tokio::select! {
+            connected = server.connect() => {
+                connected?;
+                log_debug!("accepted connection on {}", path.display());
+                // Create the next instance before handing this one over
+                let next = ServerOptions::new().create(path)?;
+                spawn_session(std::mem::replace(&mut server, next), &new_service);
+            }
+            _ = tokio::signal::ctrl_c() => return Ok(()),
+            _ = watchdog::shutdown() => return Ok(()),
+        }
+    }
+}
+
+/// Run an LSP session on a connection in the background
+fn spawn_session<S, F>(stream: S, new_service: &F)
+where
+    S: AsyncRead + AsyncWrite + Send + 'static,
+    F: Fn() -> (LspService<Backend>, ClientSocket),
+{
+    let (read, write) = tokio::io::split(stream);
+    let (service, socket) = new_service();
+    tokio::spawn(Server::new(ActivityReader(read), write, socket).serve(service));
+}
+
+/// Removes the socket file when the server stops
+#[cfg(unix)]
+struct SocketFile(PathBuf);
+
+#[cfg(unix)]
+impl Drop for SocketFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
@@ -0,0 +1,774 @@
+//! Vim9 script support
+//!
+//! tree-sitter-vim v0.4.0 does not understand Vim9 syntax: `def`, `var`,
+//! `import` and `export` are all parsed as `unknown_builtin_statement`.
+//! This module therefore works on the raw source text, one line at a time.
+
+pub mod typecheck;
+
+/// Kind of an exported item
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportKind {
+    /// `export def Name()`
+    Function,
+    /// `export var name`
+    Variable,
+    /// `export const NAME` / `export final name`
+    Constant,
+    /// `export class Name` / `export abstract class Name`
+    Class,
+    /// `export interface Name`
+    Interface,
+    /// `export enum Name`
+    Enum,
+    /// `export type Name = ...`
+    Type,
+}
+
+/// An `export` statement in a Vim9 script
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Export {
+    /// Exported name
+    pub name: String,
+    /// Kind of the exported item
+    pub kind: ExportKind,
+    /// Line number (0-indexed)
+    pub line: usize,
+    /// Start column of the name (byte offset)
+    pub start_col: usize,
+    /// End column of the name (byte offset)
+    pub end_col: usize,
+}
+
+/// An `import` statement in a Vim9 script
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Import {
+    /// The path as written, without quotes (e.g., "./util.vim")
+    pub path: String,
+    /// Whether this is an `import autoload` statement
+    pub autoload: bool,
+    /// Explicit alias from `as Name`
+    pub alias: Option<String>,
+    /// Line number (0-indexed)
+    pub line: usize,
+    /// Start column of the quoted path (byte offset, including the quote)
+    pub path_start: usize,
+    /// End column of the quoted path (byte offset, including the quote)
+    pub path_end: usize,
+}
+
+impl Import {
+    /// Name used to refer to the imported script
+    ///
+    /// This is the `as` alias if present, otherwise the file name without
+    /// its `.vim` extension (e.g., "./util.vim" -> "util").
+    pub fn name(&self) -> String {
+        if let Some(alias) = &self.alias {
+            return alias.clone();
+        }
+        let file = self.path.rsplit(['/', '\\']).next().unwrap_or(&self.path);
+        file.strip_suffix(".vim").unwrap_or(file).to_string()
+    }
+
+    /// Whether the path is relative to the importing script
+    pub fn is_relative(&self) -> bool {
+        self.path.starts_with("./") || self.path.starts_with("../")
+    }
+
+    /// Whether the path is absolute (or home-relative)
+    pub fn is_absolute(&self) -> bool {
+        self.path.starts_with('/') || self.path.starts_with('~')
+    }
+
+    /// Runtimepath subdirectory searched for non-relative paths
+    pub fn runtime_subdir(&self) -> &'static str {
+        if self.autoload { "autoload" } else { "import" }
+    }
+}
+
+/// A use of an imported item, such as `Alias.Func()` or `Alias.value`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedRef {
+    /// The import name (alias or script name)
+    pub alias: String,
+    /// The member accessed through the alias
+    pub member: String,
+    /// Line number (0-indexed)
+    pub line: usize,
+    /// Start column of the member (byte offset)
+    pub start_col: usize,
+    /// End column of the member (byte offset)
+    pub end_col: usize,
+}
+
+/// The initializer of a `var`, `const` or `final` declaration
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Initializer {
+    /// The expression after `=` (e.g., "'a' .. 'b'")
+    pub expr: String,
+    /// Line number (0-indexed)
+    pub line: usize,
+    /// Start column of the expression (byte offset)
+    pub start_col: usize,
+    /// End column of the expression (byte offset)
+    pub end_col: usize,
+}
+
+/// Kind of a class member
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClassMemberKind {
+    /// `def Name()`
+    Method,
+    /// `def new()` / `def newFoo()`
+    Constructor,
+    /// `var name` / `final name` / `const name`
+    Variable,
+}
+
+/// A method or member variable declared in a Vim9 class
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ClassMember {
+    /// Member name (without `this.`)
+    pub name: String,
+    /// Kind of the member
+    pub kind: ClassMemberKind,
+    /// Whether the member is declared `static`
+    pub is_static: bool,
+    /// Declaration text (e.g., "def Area(): number" or "var width: number")
+    pub signature: String,
+    /// Line number (0-indexed)
+    pub line: usize,
+    /// Start column of the name (byte offset)
+    pub start_col: usize,
+    /// End column of the name (byte offset)
+    pub end_col: usize,
+}
+
+/// A `class` (or `interface`) definition in a Vim9 script
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Class {
+    /// Class name
+    pub name: String,
+    /// Whether this is an `interface` rather than a `class`
+    pub is_interface: bool,
+    /// Parent class from `extends Parent`
+    pub extends: Option<String>,
+    /// Line of the `class` statement (0-indexed)
+    pub line: usize,
+    /// Start column of the name (byte offset)
+    pub start_col: usize,
+    /// End column of the name (byte offset)
+    pub end_col: usize,
+    /// Line of the `endclass` / `endinterface` statement (0-indexed)
+    pub end_line: usize,
+    /// Methods and member variables
+    pub members: Vec<ClassMember>,
+}
+
+/// Split a line into its code part, dropping a trailing `#` comment
+fn strip_comment(line: &str) -> &str {
+    let mut in_string: Option<char> = None;
+    let mut prev = ' ';
+    for (i, c) in line.char_indices() {
+        match in_string {
+            Some(q) if c == q => in_string = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' => in_string = Some(c),
+            None if c == '#' && prev.is_whitespace() => return &line[..i],
+            None => {}
+        }
+        prev = c;
+    }
+    line
+}
+
+/// Whether a character may appear in a Vim9 identifier
+fn is_ident_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || c == '_'
+}
+
+/// Read an identifier from the start of `text`
+fn take_ident(text: &str) -> &str {
+    let end = text.find(|c: char| !is_ident_char(c)).unwrap_or(text.len());
+    &text[..end]
+}
+
+/// Byte offset of `sub` within `line` (`sub` must be a subslice of `line`)
+fn col_of(line: &str, sub: &str) -> usize {
+    sub.as_ptr() as usize - line.as_ptr() as usize
+}
+
+/// Whether a source file is a Vim9 script
+///
+/// A Vim9 script starts with `vim9script` (optionally followed by arguments
+/// such as `noclear`) on its first non-empty line.
+pub fn is_vim9script(source: &str) -> bool {
+    source
+        .lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .is_some_and(|line| {
+            line.strip_prefix("vim9script")
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with(char::is_whitespace))
+        })
+}
+
+/// Parse all `export` statements in a source file
+pub fn parse_exports(source: &str) -> Vec<Export> {
+    let mut exports = Vec::new();
+
+    for (line_num, line) in source.lines().enumerate() {
+        let Some(mut rest) = line.trim_start().strip_prefix("export") else {
+            continue;
+        };
+        if !rest.starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        // `export abstract class Name`
+        rest = rest.trim_start();
+        if let Some(after) = rest.strip_prefix("abstract") {
+            rest = after.trim_start();
+        }
+
+        let keyword = take_ident(rest);
+        let kind = match keyword {
+            "def" => ExportKind::Function,
+            "var" => ExportKind::Variable,
+            "const" | "final" => ExportKind::Constant,
+            "class" => ExportKind::Class,
+            "interface" => ExportKind::Interface,
+            "enum" => ExportKind::Enum,
+            "type" => ExportKind::Type,
+            _ => continue,
+        };
+
+        let name = take_ident(rest[keyword.len()..].trim_start());
+        if name.is_empty() {
+            continue;
+        }
+        let start_col = col_of(line, name);
+
+        exports.push(Export {
+            name: name.to_string(),
+            kind,
+            line: line_num,
+            start_col,
+            end_col: start_col + name.len(),
+        });
+    }
+
+    exports
+}
+
+/// Parse the initializers of all `var`, `const` and `final` declarations
+///
+/// Declarations without `=` (`var name: number`) have none.
+pub fn parse_initializers(source: &str) -> Vec<Initializer> {
+    let mut initializers = Vec::new();
+
+    for (line_num, line) in source.lines().enumerate() {
+        let code = strip_comment(line);
+        let mut rest = code.trim_start();
+        if let Some(after) = rest.strip_prefix("export") {
+            rest = after.trim_start();
+        }
+        let keyword = take_ident(rest);
+        if !matches!(keyword, "var" | "const" | "final") {
+            continue;
+        }
+        let Some((_, expr)) = typecheck::split_assignment(&rest[keyword.len()..]) else {
+            continue;
+        };
+        let expr = expr.trim();
+        if expr.is_empty() {
+            continue;
+        }
+        let start_col = col_of(line, expr);
+
+        initializers.push(Initializer {
+            expr: expr.to_string(),
+            line: line_num,
+            start_col,
+            end_col: start_col + expr.len(),
+        });
+    }
+
+    initializers
+}
+
+/// Parse all `import` statements in a source file
+///
+/// Supported forms:
+/// - `import "path.vim"`
+/// - `import "path.vim" as Name`
+/// - `import autoload "path.vim"`
+/// - `import autoload "path.vim" as Name`
+pub fn parse_imports(source: &str) -> Vec<Import> {
+    let mut imports = Vec::new();
+
+    for (line_num, line) in source.lines().enumerate() {
+        let code = strip_comment(line);
+        let Some(rest) = code.trim_start().strip_prefix("import") else {
+            continue;
+        };
+        if !rest.starts_with(char::is_whitespace) {
+            continue;
+        }
+
+        let mut rest = rest.trim_start();
+        let autoload = match rest.strip_prefix("autoload") {
+            Some(after) if after.starts_with(char::is_whitespace) => {
+                rest = after.trim_start();
+                true
+            }
+            _ => false,
+        };
+
+        // Quoted path
+        let Some(quote) = rest.chars().next().filter(|c| *c == '\'' || *c == '"') else {
+            continue;
+        };
+        let Some(close) = rest[1..].find(quote) else {
+            continue;
+        };
+        let path_start = col_of(line, rest);
+        let path_end = path_start + close + 2;
+
+        // Optional `as Name`
+        let alias = rest[close + 2..]
+            .trim_start()
+            .strip_prefix("as")
+            .filter(|s| s.starts_with(char::is_whitespace))
+            .map(|s| take_ident(s.trim_start()).to_string())
+            .filter(|s| !s.is_empty());
+
+        imports.push(Import {
+            path: rest[1..1 + close].to_string(),
+            autoload,
+            alias,
+            line: line_num,
+            path_start,
+            path_end,
+        });
+    }
+
+    imports
+}
+
+/// Parse all `class` and `interface` definitions in a source file
+pub fn parse_classes(source: &str) -> Vec<Class> {
+    let mut classes: Vec<Class> = Vec::new();
+    let mut current: Option<Class> = None;
+    let mut in_method = false;
+
+    for (line_num, line) in source.lines().enumerate() {
+        let code = strip_comment(line);
+        let trimmed = code.trim();
+
+        let Some(class) = current.as_mut() else {
+            // Look for `[export] [abstract] class Name` / `interface Name`
+            let mut rest = trimmed;
+            for prefix in ["export", "abstract"] {
+                if let Some(after) = rest.strip_prefix(prefix) {
+                    if after.starts_with(char::is_whitespace) {
+                        rest = after.trim_start();
+                    }
+                }
+            }
+            let keyword = take_ident(rest);
+            if keyword != "class" && keyword != "interface" {
+                continue;
+            }
+            let after = rest[keyword.len()..].trim_start();
+            let name = take_ident(after);
+            if name.is_empty() {
+                continue;
+            }
+            let start_col = col_of(line, name);
+            let extends = after[name.len()..]
+                .split_whitespace()
+                .skip_while(|w| *w != "extends")
+                .nth(1)
+                .map(|w| take_ident(w).to_string());
+            current = Some(Class {
+                name: name.to_string(),
+                is_interface: keyword == "interface",
+                extends,
+                line: line_num,
+                start_col,
+                end_col: start_col + name.len(),
+                end_line: line_num,
+                members: Vec::new(),
+            });
+            in_method = false;
+            continue;
+        };
+
+        if trimmed == "endclass" || trimmed == "endinterface" {
+            class.end_line = line_num;
+            classes.extend(current.take());
+            continue;
+        }
+        if in_method {
+            if trimmed == "enddef" {
+                in_method = false;
+            }
+            continue;
+        }
+
+        // Strip member modifiers
+        let mut rest = trimmed;
+        let mut is_static = false;
+        let mut is_abstract = false;
+        loop {
+            let word = take_ident(rest);
+            match word {
+                "public" | "static" | "abstract"
+                    if rest[word.len()..].starts_with(char::is_whitespace) =>
+                {
+                    is_static |= word == "static";
+                    is_abstract |= word == "abstract";
+                    rest = rest[word.len()..].trim_start();
+                }
+                _ => break,
+            }
+        }
+
+        let keyword = take_ident(rest);
+        let after = rest[keyword.len()..].trim_start();
+        let kind = match keyword {
+            "def" => ClassMemberKind::Method,
+            "var" | "final" | "const" => ClassMemberKind::Variable,
+            _ => continue,
+        };
+        // Old syntax: `var this.name`
+        let after = after.strip_prefix("this.").unwrap_or(after);
+        let name = take_ident(after);
+        if name.is_empty() {
+            continue;
+        }
+        let kind = if kind == ClassMemberKind::Method && name.starts_with("new") {
+            ClassMemberKind::Constructor
+        } else {
+            kind
+        };
+        // Interface and abstract methods have no body
+        if kind != ClassMemberKind::Variable && !class.is_interface && !is_abstract {
+            in_method = true;
+        }
+
+        let start_col = col_of(line, name);
+        class.members.push(ClassMember {
+            name: name.to_string(),
+            kind,
+            is_static,
+            signature: rest.to_string(),
+            line: line_num,
+            start_col,
+            end_col: start_col + name.len(),
+        });
+    }
+
+    classes
+}
+
+/// Find the class whose body contains `line`
+pub fn class_at_line(classes: &[Class], line: usize) -> Option<&Class> {
+    classes.iter().find(|c| c.line < line && line < c.end_line)
+}
+
+/// Collect the members of a class, including those inherited via `extends`
+///
+/// Only parent classes defined in `classes` are followed.
+pub fn class_members<'a>(classes: &'a [Class], name: &str) -> Vec<&'a ClassMember> {
+    let mut members = Vec::new();
+    let mut visited = Vec::new();
+    let mut next = Some(name);
+    while let Some(name) = next {
+        if visited.contains(&name) {
+            break;
+        }
+        visited.push(name);
+        let Some(class) = classes.iter().find(|c| c.name == name) else {
+            break;
+        };
+        for member in &class.members {
+            if !members.iter().any(|m: &&ClassMember| m.name == member.name) {
+                members.push(member);
+            }
+        }
+        next = class.extends.as_deref();
+    }
+    members
+}
+
+/// Find the class type of a variable declared before `before_line`
+///
+/// Recognized declarations:
+/// - `var name: ClassName`
+/// - `var name = ClassName.new(...)`
+/// - `def Func(name: ClassName)`
+pub fn find_variable_class(source: &str, var: &str, before_line: usize) -> Option<String> {
+    for line in source
+        .lines()
+        .take(before_line + 1)
+        .collect::<Vec<_>>()
+        .into_iter()
+        .rev()
+    {
+        let code = strip_comment(line);
+        let mut search = code;
+        while let Some(pos) = search.find(var) {
+            let prev = search[..pos].chars().last();
+            let after = &search[pos + var.len()..];
+            search = after;
+            if prev.is_some_and(|c| is_ident_char(c) || c == '.')
+                || after.starts_with(is_ident_char)
+            {
+                continue;
+            }
+            let after = after.trim_start();
+            // `name: ClassName`
+            if let Some(ty) = after.strip_prefix(':') {
+                let ty = take_ident(ty.trim_start());
+                if ty.starts_with(|c: char| c.is_ascii_uppercase()) {
+                    return Some(ty.to_string());
+                }
+            }
+            // `name = ClassName.new(...)`
+            if let Some(value) = after.strip_prefix('=').filter(|v| !v.starts_with('=')) {
+                let value = value.trim_start();
+                let class = take_ident(value);
+                if class.starts_with(|c: char| c.is_ascii_uppercase())
+                    && value[class.len()..].starts_with(".new")
+                {
+                    return Some(class.to_string());
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Find `Alias.member` uses for the given import names
+///
+/// String literals and `#` comments are skipped. `import` lines themselves are
+/// ignored so that the alias declaration is not reported as a use.
+pub fn find_imported_refs(source: &str, aliases: &[String]) -> Vec<ImportedRef> {
+    let mut refs = Vec::new();
+    if aliases.is_empty() {
+        return refs;
+    }
+
+    for (line_num, line) in source.lines().enumerate() {
+        let code = strip_comment(line);
+        if code.trim_start().starts_with("import ") {
+            continue;
+        }
+
+        let chars: Vec<(usize, char)> = code.char_indices().collect();
+        let mut in_string: Option<char> = None;
+        let mut i = 0;
+        while i < chars.len() {
+            let (pos, c) = chars[i];
+            if let Some(q) = in_string {
+                if c == q {
+                    in_string = None;
+                }
+                i += 1;
+                continue;
+            }
+            if c == '\'' || c == '"' {
+                in_string = Some(c);
+                i += 1;
+                continue;
+            }
+
+            let at_word_start = i == 0 || {
+                let prev = chars[i - 1].1;
+                !is_ident_char(prev) && prev != '.' && prev != ':' && prev != '#'
+            };
+            if at_word_start && is_ident_char(c) {
+                let word = take_ident(&code[pos..]);
+                let after = pos + word.len();
+                if aliases.iter().any(|a| a == word) && code[after..].starts_with('.') {
+                    let member = take_ident(&code[after + 1..]);
+                    if !member.is_empty() {
+                        refs.push(ImportedRef {
+                            alias: word.to_string(),
+                            member: member.to_string(),
+                            line: line_num,
+                            start_col: after + 1,
+                            end_col: after + 1 + member.len(),
+                        });
+                    }
+                }
+                i += word.chars().count();
+                continue;
+            }
+            i += 1;
+        }
+    }
+
+    refs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_exports() {
+        let source = "vim9script\n\
+                      export def Foo(a: number): string\n\
+                      enddef\n\
+                      export var bar = 1\n\
+                      export const BAZ = 2\n\
+                      export abstract class Shape\n\
+                      def Private()\n";
+        let exports = parse_exports(source);
+        let names: Vec<_> = exports.iter().map(|e| (e.name.as_str(), e.kind)).collect();
+        assert_eq!(
+            names,
+            vec![
+                ("Foo", ExportKind::Function),
+                ("bar", ExportKind::Variable),
+                ("BAZ", ExportKind::Constant),
+                ("Shape", ExportKind::Class),
+            ]
+        );
+        assert_eq!(exports[0].line, 1);
+        assert_eq!(exports[0].start_col, 11);
+        assert_eq!(exports[0].end_col, 14);
+    }
+
+    #[test]
+    fn test_parse_initializers() {
+        let source = "vim9script\n\
+                      var x: number\n\
+                      export const NAME: string = 'a' .. 'b' # comment\n\
+                      var y = x == 1\n\
+                      variable = 2\n";
+        let initializers = parse_initializers(source);
+        let exprs: Vec<_> = initializers.iter().map(|i| i.expr.as_str()).collect();
+        assert_eq!(exprs, vec!["'a' .. 'b'", "x == 1"]);
+        assert_eq!(initializers[0].line, 2);
+        assert_eq!(initializers[0].start_col, 28);
+        assert_eq!(initializers[0].end_col, 38);
+    }
+
+    #[test]
+    fn test_parse_imports() {
+        let source = "vim9script\n\
+                      import autoload './x.vim'\n\
+                      import \"./y.vim\" as Y\n\
+                      import 'dist/util.vim' # comment\n";
+        let imports = parse_imports(source);
+        assert_eq!(imports.len(), 3);
+
+        assert_eq!(imports[0].path, "./x.vim");
+        assert!(imports[0].autoload);
+        assert_eq!(imports[0].name(), "x");
+        assert_eq!(imports[0].path_start, 16);
+        assert_eq!(imports[0].path_end, 25);
+        assert!(imports[0].is_relative());
+
+        assert_eq!(imports[1].path, "./y.vim");
+        assert!(!imports[1].autoload);
+        assert_eq!(imports[1].alias.as_deref(), Some("Y"));
+        assert_eq!(imports[1].name(), "Y");
+
+        assert_eq!(imports[2].name(), "util");
+        assert!(!imports[2].is_relative());
+        assert_eq!(imports[2].runtime_subdir(), "import");
+    }
+
+    #[test]
+    fn test_parse_classes() {
+        let source = "vim9script\n\
+                      export class Shape\n\
+                      \x20 var name: string\n\
+                      \x20 static var count = 0\n\
+                      \x20 def new(this.name)\n\
+                      \x20   var local = 1\n\
+                      \x20 enddef\n\
+                      \x20 def Area(): number\n\
+                      \x20   return 0\n\
+                      \x20 enddef\n\
+                      endclass\n\
+                      class Square extends Shape\n\
+                      \x20 var side: number\n\
+                      endclass\n";
+        let classes = parse_classes(source);
+        assert_eq!(classes.len(), 2);
+
+        let shape = &classes[0];
+        assert_eq!(shape.name, "Shape");
+        assert_eq!((shape.line, shape.end_line), (1, 10));
+        let members: Vec<_> = shape
+            .members
+            .iter()
+            .map(|m| (m.name.as_str(), m.kind, m.is_static))
+            .collect();
+        assert_eq!(
+            members,
+            vec![
+                ("name", ClassMemberKind::Variable, false),
+                ("count", ClassMemberKind::Variable, true),
+                ("new", ClassMemberKind::Constructor, false),
+                ("Area", ClassMemberKind::Method, false),
+            ]
+        );
+        assert_eq!(shape.members[3].signature, "def Area(): number");
+
+        assert_eq!(classes[1].extends.as_deref(), Some("Shape"));
+        let names: Vec<_> = class_members(&classes, "Square")
+            .iter()
+            .map(|m| m.name.as_str())
+            .collect();
+        assert_eq!(names, vec!["side", "name", "count", "new", "Area"]);
+
+        assert_eq!(
+            class_at_line(&classes, 7).map(|c| c.name.as_str()),
+            Some("Shape")
+        );
+        assert!(class_at_line(&classes, 11).is_none());
+    }
+
+    #[test]
+    fn test_find_variable_class() {
+        let source = "vim9script\n\
+                      var a: Shape\n\
+                      var b = Square.new(1)\n\
+                      def F(c: Circle)\n\
+                      \x20 echo c.\n\
+                      enddef\n";
+        assert_eq!(
+            find_variable_class(source, "a", 4).as_deref(),
+            Some("Shape")
+        );
+        assert_eq!(
+            find_variable_class(source, "b", 4).as_deref(),
+            Some("Square")
+        );
+        assert_eq!(
+            find_variable_class(source, "c", 4).as_deref(),
+            Some("Circle")
+        );
+        assert_eq!(find_variable_class(source, "b", 1), None);
+    }
+
+    #[test]
+    fn test_find_imported_refs() {
+        let source = "import './y.vim' as Y\n\
+                      Y.Hello()\n\
+                      var s = 'Y.NotARef'\n\
+                      echo Y.count + X.other # Y.comment\n";
+        let refs = find_imported_refs(source, &["Y".to_string()]);
+        let members: Vec<_> = refs.iter().map(|r| (r.line, r.member.as_str())).collect();
+        assert_eq!(members, vec![(1, "Hello"), (3, "count")]);
+        assert_eq!(refs[0].start_col, 2);
+        assert_eq!(refs[0].end_col, 7);
+    }
+}
@@ -0,0 +1,841 @@
+//! Static type checking for Vim9 script
+//!
+//! Vim only reports type errors (E1012) when a line is compiled or executed.
+//! This pass checks the obvious cases ahead of time:
+//!
+//! - `var name: type = expr` declarations
+//! - `name = expr` assignments to variables with a known type
+//! - arguments passed to `def` functions with typed parameters
+//! - `return expr` against the declared return type of the enclosing `def`
+//!
+//! Expression types are only inferred for literals, known variables, calls to
+//! `def` functions with a declared return type, calls to builtins with a known
+//! return type, and a few operators. Anything else is treated as unknown and
+//! never reported.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use tower_lsp_server::ls_types::{Diagnostic, DiagnosticSeverity, NumberOrString, Position, Range};
+
+use super::{col_of, is_ident_char, strip_comment, take_ident};
+
+/// A Vim9 type
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Type {
+    Any,
+    Number,
+    Float,
+    String,
+    Bool,
+    Blob,
+    Special,
+    Void,
+    Job,
+    Channel,
+    List(Box<Type>),
+    Dict(Box<Type>),
+    Tuple,
+    Func,
+    /// A class, interface, enum or type alias name
+    Named(String),
+    /// Type could not be determined; never reported
+    Unknown,
+}
+
+impl Type {
+    /// Parse a type annotation such as `number` or `list<dict<string>>`
+    pub fn parse(text: &str) -> Type {
+        let text = text.trim();
+        if let Some(inner) = generic_arg(text, "list") {
+            return Type::List(Box::new(Type::parse(inner)));
+        }
+        if let Some(inner) = generic_arg(text, "dict") {
+            return Type::Dict(Box::new(Type::parse(inner)));
+        }
+        if text.starts_with("tuple<") {
+            return Type::Tuple;
+        }
+        if text == "func" || text.starts_with("func(") || text.starts_with("func:") {
+            return Type::Func;
+        }
+        match text {
+            "any" => Type::Any,
+            "number" => Type::Number,
+            "float" => Type::Float,
+            "string" => Type::String,
+            "bool" => Type::Bool,
+            "blob" => Type::Blob,
+            "void" => Type::Void,
+            "job" => Type::Job,
+            "channel" => Type::Channel,
+            "list" => Type::List(Box::new(Type::Any)),
+            "dict" => Type::Dict(Box::new(Type::Any)),
+            "" => Type::Unknown,
+            name if name.chars().next().is_some_and(|c| c.is_ascii_uppercase()) => {
+                Type::Named(name.to_string())
+            }
+            _ => Type::Unknown,
+        }
+    }
+
+    /// Whether a value of type `actual` can be assigned to this type
+    ///
+    /// Unknown types are always accepted so that only certain mismatches are
+    /// reported.
+    pub fn accepts(&self, actual: &Type) -> bool {
+        match (self, actual) {
+            (Type::Unknown, _) | (_, Type::Unknown) => true,
+            (Type::Any, _) | (_, Type::Any) => true,
+            // null (v:null, null_string, ...) is accepted by every type
+            (_, Type::Special) => true,
+            // A number is automatically converted to a float
+            (Type::Float, Type::Number) => true,
+            (Type::List(expected), Type::List(actual)) => expected.accepts(actual),
+            (Type::Dict(expected), Type::Dict(actual)) => expected.accepts(actual),
+            // Class hierarchies are not tracked
+            (Type::Named(_), _) | (_, Type::Named(_)) => true,
+            (expected, actual) => expected == actual,
+        }
+    }
+
+    /// Join element types of a list or dict literal
+    fn join(types: impl IntoIterator<Item = Type>) -> Type {
+        let mut result: Option<Type> = None;
+        for ty in types {
+            result = Some(match result {
+                None => ty,
+                Some(prev) if prev == ty => prev,
+                Some(Type::Unknown) => Type::Unknown,
+                Some(_) if ty == Type::Unknown => Type::Unknown,
+                Some(_) => Type::Any,
+            });
+        }
+        // Empty literal: `[]` and `{}` match any element type
+        result.unwrap_or(Type::Unknown)
+    }
+}
+
+impl std::fmt::Display for Type {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Type::Any => write!(f, "any"),
+            Type::Number => write!(f, "number"),
+            Type::Float => write!(f, "float"),
+            Type::String => write!(f, "string"),
+            Type::Bool => write!(f, "bool"),
+            Type::Blob => write!(f, "blob"),
+            Type::Special => write!(f, "special"),
+            Type::Void => write!(f, "void"),
+            Type::Job => write!(f, "job"),
+            Type::Channel => write!(f, "channel"),
+            Type::List(inner) => write!(f, "list<{}>", inner),
+            Type::Dict(inner) => write!(f, "dict<{}>", inner),
+            Type::Tuple => write!(f, "tuple"),
+            Type::Func => write!(f, "func"),
+            Type::Named(name) => write!(f, "{}", name),
+            Type::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// Extract `T` from `name<T>`
+fn generic_arg<'a>(text: &'a str, name: &str) -> Option<&'a str> {
+    text.strip_prefix(name)?
+        .strip_prefix('<')?
+        .strip_suffix('>')
+}
+
+/// Return types of builtin functions commonly used in Vim9 script
+///
+/// Only functions whose return type does not depend on their arguments are
+/// listed; functions such as `get()` or `copy()` are inferred as unknown.
+static BUILTIN_RETURN_TYPES: &[(&str, &str)] = &[
+    ("abs", "number"),
+    ("and", "number"),
+    ("bufnr", "number"),
+    ("bufname", "string"),
+    ("byteidx", "number"),
+    ("col", "number"),
+    ("count", "number"),
+    ("empty", "bool"),
+    ("escape", "string"),
+    ("exists", "bool"),
+    ("expand", "string"),
+    ("fnamemodify", "string"),
+    ("getcwd", "string"),
+    ("getline", "string"),
+    ("has", "bool"),
+    ("has_key", "bool"),
+    ("index", "number"),
+    ("join", "string"),
+    ("keys", "list<string>"),
+    ("len", "number"),
+    ("line", "number"),
+    ("localtime", "number"),
+    ("match", "number"),
+    ("matchstr", "string"),
+    ("or", "number"),
+    ("printf", "string"),
+    ("repeat", "string"),
+    ("split", "list<string>"),
+    ("str2float", "float"),
+    ("str2nr", "number"),
+    ("strcharpart", "string"),
+    ("strchars", "number"),
+    ("strdisplaywidth", "number"),
+    ("string", "string"),
+    ("stridx", "number"),
+    ("strlen", "number"),
+    ("strpart", "string"),
+    ("strtrans", "string"),
+    ("strwidth", "number"),
+    ("substitute", "string"),
+    ("tolower", "string"),
+    ("toupper", "string"),
+    ("trim", "string"),
+    ("type", "number"),
+    ("typename", "string"),
+    ("winnr", "number"),
+];
+
+/// Look up the return type of a builtin function
+fn builtin_return_type(name: &str) -> Option<Type> {
+    static INDEX: OnceLock<HashMap<&'static str, &'static str>> = OnceLock::new();
+    INDEX
+        .get_or_init(|| BUILTIN_RETURN_TYPES.iter().copied().collect())
+        .get(name)
+        .map(|ty| Type::parse(ty))
+}
+
+/// Signature of a `def` function
+#[derive(Debug, Clone)]
+struct DefSignature {
+    /// Parameter types (Unknown when not annotated)
+    params: Vec<Type>,
+    /// Declared return type (Void when omitted)
+    return_type: Type,
+}
+
+/// Split `text` on top-level occurrences of `sep`, ignoring brackets and strings
+fn split_top_level(text: &str, sep: char) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0i32;
+    let mut in_string: Option<char> = None;
+    let mut start = 0;
+    for (i, c) in text.char_indices() {
+        match in_string {
+            Some(q) if c == q => in_string = None,
+            Some(_) => {}
+            None => match c {
+                '\'' | '"' => in_string = Some(c),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                _ if c == sep && depth == 0 => {
+                    parts.push(&text[start..i]);
+                    start = i + c.len_utf8();
+                }
+                _ => {}
+            },
+        }
+    }
+    parts.push(&text[start..]);
+    parts
+}
+
+/// Split `lhs = rhs` on the first top-level `=` that is not part of an operator
+pub(super) fn split_assignment(text: &str) -> Option<(&str, &str)> {
+    let mut depth = 0i32;
+    let mut in_string: Option<char> = None;
+    let mut prev = ' ';
+    for (i, c) in text.char_indices() {
+        match in_string {
+            Some(q) if c == q => in_string = None,
+            Some(_) => {}
+            None => match c {
+                '\'' | '"' => in_string = Some(c),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                '=' if depth == 0
+                    && !matches!(prev, '=' | '!' | '<' | '>' | '~')
+                    && !matches!(text[i + 1..].chars().next(), Some('=' | '~' | '>')) =>
+                {
+                    return Some((&text[..i], &text[i + 1..]));
+                }
+                _ => {}
+            },
+        }
+        prev = c;
+    }
+    None
+}
+
+/// Find the byte index of the bracket closing the one at `open`
+fn matching_close(text: &str, open: usize) -> Option<usize> {
+    let open_char = text[open..].chars().next()?;
+    let close_char = match open_char {
+        '(' => ')',
+        '[' => ']',
+        '{' => '}',
+        _ => return None,
+    };
+    let mut depth = 0;
+    let mut in_string: Option<char> = None;
+    for (i, c) in text[open..].char_indices() {
+        match in_string {
+            Some(q) if c == q => in_string = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' => in_string = Some(c),
+            None if c == open_char => depth += 1,
+            None if c == close_char => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(open + i);
+                }
+            }
+            None => {}
+        }
+    }
+    None
+}
+
+/// Whether `expr` contains a top-level binary operator `op`
+pub(crate) fn has_top_level_operator(expr: &str, op: &str) -> bool {
+    let mut depth = 0i32;
+    let mut in_string: Option<char> = None;
+    for (i, c) in expr.char_indices() {
+        match in_string {
+            Some(q) if c == q => in_string = None,
+            Some(_) => {}
+            None => match c {
+                '\'' | '"' => in_string = Some(c),
+                '(' | '[' | '{' => depth += 1,
+                ')' | ']' | '}' => depth -= 1,
+                _ if depth == 0 && expr[i..].starts_with(op) => {
+                    let before = expr[..i].chars().last();
+                    let after = expr[i + op.len()..].chars().next();
+                    if before.is_some_and(char::is_whitespace)
+                        && after.is_some_and(char::is_whitespace)
+                    {
+                        return true;
+                    }
+                }
+                _ => {}
+            },
+        }
+    }
+    false
+}
+
+/// Variable and function types visible at a given point
+#[derive(Default)]
+struct Env {
+    /// Script-level variables
+    script_vars: HashMap<String, Type>,
+    /// Variables local to the current `def`
+    local_vars: HashMap<String, Type>,
+    /// `def` function signatures
+    functions: HashMap<String, DefSignature>,
+}
+
+impl Env {
+    fn var_type(&self, name: &str) -> Option<&Type> {
+        self.local_vars
+            .get(name)
+            .or_else(|| self.script_vars.get(name))
+    }
+
+    /// Check `expr` against `expected`, returning the inferred type on mismatch
+    fn check(&self, expected: &Type, expr: &str) -> Option<Type> {
+        let expr = expr.trim();
+        // The number literals 0 and 1 are accepted where a bool is expected
+        if *expected == Type::Bool && (expr == "0" || expr == "1") {
+            return None;
+        }
+        let actual = self.infer(expr);
+        (!expected.accepts(&actual)).then_some(actual)
+    }
+
+    /// Infer the type of an expression
+    fn infer(&self, expr: &str) -> Type {
+        let expr = expr.trim();
+        if expr.is_empty() {
+            return Type::Unknown;
+        }
+
+        // Whole-expression string literal
+        if let Some(quote) = expr.chars().next().filter(|c| *c == '\'' || *c == '"') {
+            if expr.len() >= 2 && expr.ends_with(quote) && split_top_level(expr, ' ').len() == 1 {
+                return Type::String;
+            }
+        }
+
+        match expr {
+            "true" | "false" | "v:true" | "v:false" => return Type::Bool,
+            "null" | "v:null" | "v:none" | "null_string" | "null_list" | "null_dict"
+            | "null_blob" | "null_function" | "null_partial" | "null_job" | "null_channel"
+            | "null_object" | "null_class" => return Type::Special,
+            _ => {}
+        }
+
+        // Numeric literals
+        let unsigned = expr.strip_prefix('-').unwrap_or(expr);
+        if !unsigned.is_empty() {
+            let digits = unsigned.replace('\'', "");
+            if digits.chars().all(|c| c.is_ascii_digit())
+                || digits
+                    .strip_prefix("0x")
+                    .or_else(|| digits.strip_prefix("0X"))
+                    .is_some_and(|h| !h.is_empty() && h.chars().all(|c| c.is_ascii_hexdigit()))
+                || digits
+                    .strip_prefix("0b")
+                    .is_some_and(|b| !b.is_empty() && b.chars().all(|c| c == '0' || c == '1'))
+            {
+                return Type::Number;
+            }
+            if digits.parse::<f64>().is_ok() && digits.contains('.') {
+                return Type::Float;
+            }
+        }
+        if expr.starts_with("0z") {
+            return Type::Blob;
+        }
+
+        // List literal
+        if expr.starts_with('[') && matching_close(expr, 0) == Some(expr.len() - 1) {
+            let inner = expr[1..expr.len() - 1].trim();
+            if inner.is_empty() {
+                return Type::List(Box::new(Type::Unknown));
+            }
+            let elem = Type::join(
+                split_top_level(inner, ',')
+                    .into_iter()
+                    .filter(|e| !e.trim().is_empty())
+                    .map(|e| self.infer(e)),
+            );
+            return Type::List(Box::new(elem));
+        }
+
+        // Dict literal
+        if expr.starts_with('{') && matching_close(expr, 0) == Some(expr.len() - 1) {
+            let inner = expr[1..expr.len() - 1].trim();
+            if inner.is_empty() {
+                return Type::Dict(Box::new(Type::Unknown));
+            }
+            // Lambdas also start with `{` in legacy syntax
+            if inner.contains("->") || inner.contains("=>") {
+                return Type::Unknown;
+            }
+            let value = Type::join(split_top_level(inner, ',').into_iter().filter_map(|e| {
+                let parts = split_top_level(e, ':');
+                (parts.len() == 2).then(|| self.infer(parts[1]))
+            }));
+            return Type::Dict(Box::new(value));
+        }
+
+        // Lambda
+        if expr.starts_with('(') && expr.contains("=>") {
+            return Type::Func;
+        }
+
+        // String concatenation and comparisons
+        if has_top_level_operator(expr, "..") {
+            return Type::String;
+        }
+        for op in ["==", "!=", ">=", "<=", "=~", "!~", " is ", " isnot "] {
+            if has_top_level_operator(expr, op.trim()) {
+                return Type::Bool;
+            }
+        }
+        if has_top_level_operator(expr, "&&") || has_top_level_operator(expr, "||") {
+            return Type::Bool;
+        }
+
+        // Function call: Name(args)
+        let name = take_ident(expr);
+        if !name.is_empty() && expr[name.len()..].starts_with('(') {
+            if matching_close(expr, name.len()) == Some(expr.len() - 1) {
+                if let Some(sig) = self.functions.get(name) {
+                    return sig.return_type.clone();
+                }
+                if let Some(ty) = builtin_return_type(name) {
+                    return ty;
+                }
+            }
+            return Type::Unknown;
+        }
+
+        // Plain variable
+        if name.len() == expr.len() {
+            if let Some(ty) = self.var_type(name) {
+                return ty.clone();
+            }
+        }
+
+        Type::Unknown
+    }
+}
+
+/// Parse the parameter list and return type of a `def` line
+///
+/// `rest` is the text after `def Name`, starting at `(`.
+fn parse_def_signature(rest: &str) -> Option<(DefSignature, Vec<(String, Type)>)> {
+    let close = matching_close(rest, 0)?;
+    let params_text = &rest[1..close];
+    let mut params = Vec::new();
+    let mut named = Vec::new();
+
+    for param in split_top_level(params_text, ',') {
+        let param = param.trim();
+        if param.is_empty() {
+            continue;
+        }
+        if let Some(rest) = param.strip_prefix("...") {
+            let name = take_ident(rest).to_string();
+            named.push((name, Type::List(Box::new(Type::Any))));
+            continue;
+        }
+        let decl = split_assignment(param).map_or(param, |(decl, _)| decl);
+        let name = take_ident(decl.trim()).to_string();
+        let ty = decl
+            .split_once(':')
+            .map(|(_, ty)| Type::parse(ty))
+            .unwrap_or(Type::Unknown);
+        params.push(ty.clone());
+        named.push((name, ty));
+    }
+
+    let return_type = rest[close + 1..]
+        .trim_start()
+        .strip_prefix(':')
+        .map(Type::parse)
+        .unwrap_or(Type::Void);
+
+    Some((
+        DefSignature {
+            params,
+            return_type,
+        },
+        named,
+    ))
+}
+
+/// Parse a `def` line, returning the function name and the text after it
+fn def_header(code: &str) -> Option<(&str, &str)> {
+    let mut rest = code.trim_start();
+    for prefix in ["export ", "static ", "abstract "] {
+        if let Some(after) = rest.strip_prefix(prefix) {
+            rest = after.trim_start();
+        }
+    }
+    let after_def = rest.strip_prefix("def")?;
+    let after_def = after_def.strip_prefix('!').unwrap_or(after_def);
+    if !after_def.starts_with(char::is_whitespace) {
+        return None;
+    }
+    let after_def = after_def.trim_start();
+    let name = take_ident(after_def);
+    if name.is_empty() {
+        return None;
+    }
+    Some((name, &after_def[name.len()..]))
+}
+
+/// Build a type mismatch diagnostic
+fn mismatch(line: usize, start: usize, end: usize, expected: &Type, actual: &Type) -> Diagnostic {
+    Diagnostic {
+        range: Range {
+            start: Position {
+                line: line as u32,
+                character: start as u32,
+            },
+            end: Position {
+                line: line as u32,
+                character: end as u32,
+            },
+        },
+        severity: Some(DiagnosticSeverity::ERROR),
+        source: Some("hjkls".to_string()),
+        message: format!(
+            "E1012: Type mismatch; expected {} but got {}",
+            expected, actual
+        ),
+        code: Some(NumberOrString::String("hjkls/type_mismatch".to_string())),
+        ..Default::default()
+    }
+}
+
+/// Range of `expr` within `line`, trimmed of surrounding whitespace
+fn expr_range(line: &str, expr: &str) -> (usize, usize) {
+    let trimmed = expr.trim();
+    let start = col_of(line, trimmed);
+    (start, start + trimmed.len())
+}
+
+/// Check arguments of calls to `def` functions found anywhere in `code`
+fn check_calls(
+    env: &Env,
+    line: &str,
+    code: &str,
+    line_num: usize,
+    diagnostics: &mut Vec<Diagnostic>,
+) {
+    let mut in_string: Option<char> = None;
+    let mut prev = ' ';
+    for (i, c) in code.char_indices() {
+        match in_string {
+            Some(q) if c == q => in_string = None,
+            Some(_) => {}
+            None if c == '\'' || c == '"' => in_string = Some(c),
+            None if is_ident_char(c)
+                && !is_ident_char(prev)
+                && !matches!(prev, '.' | ':' | '#') =>
+            {
+                let name = take_ident(&code[i..]);
+                let open = i + name.len();
+                if let Some(sig) = env.functions.get(name) {
+                    if code[open..].starts_with('(') {
+                        if let Some(close) = matching_close(code, open) {
+                            let args_text = &code[open + 1..close];
+                            if !args_text.trim().is_empty() {
+                                for (arg, expected) in
+                                    split_top_level(args_text, ',').iter().zip(&sig.params)
+                                {
+                                    if let Some(actual) = env.check(expected, arg) {
+                                        let (start, end) = expr_range(line, arg);
+                                        diagnostics.push(mismatch(
+                                            line_num, start, end, expected, &actual,
+                                        ));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+            None => {}
+        }
+        prev = c;
+    }
+}
+
+/// Collect type mismatch errors for a Vim9 script
+///
+/// Returns no diagnostics for legacy scripts.
+pub fn collect_type_mismatches(source: &str) -> Vec<Diagnostic> {
+    let mut diagnostics = Vec::new();
+    if !super::is_vim9script(source) {
+        return diagnostics;
+    }
+
+    let mut env = Env::default();
+
+    // First pass: collect `def` signatures so calls before the definition are checked
+    for line in source.lines() {
+        let code = strip_comment(line);
+        if let Some((name, rest)) = def_header(code) {
+            if let Some((sig, _)) = parse_def_signature(rest) {
+                env.functions.insert(name.to_string(), sig);
+            }
+        }
+    }
+
+    let mut current_return: Option<Type> = None;
+
+    for (line_num, line) in source.lines().enumerate() {
+        let code = strip_comment(line);
+        let trimmed = code.trim_start();
+
+        if let Some((_, rest)) = def_header(code) {
+            if let Some((sig, named)) = parse_def_signature(rest) {
+                current_return = Some(sig.return_type);
+                env.local_vars = named
+                    .into_iter()
+                    .filter(|(name, _)| !name.is_empty())
+                    .collect();
+            }
+            continue;
+        }
+        if trimmed.starts_with("enddef") {
+            current_return = None;
+            env.local_vars.clear();
+            continue;
+        }
+
+        // var/const/final declarations
+        let decl = [
+            "var ",
+            "const ",
+            "final ",
+            "export var ",
+            "export const ",
+            "export final ",
+        ]
+        .iter()
+        .find_map(|kw| trimmed.strip_prefix(kw));
+        if let Some(decl) = decl {
+            let (lhs, rhs) = match split_assignment(decl) {
+                Some((lhs, rhs)) => (lhs, Some(rhs)),
+                None => (decl, None),
+            };
+            let name = take_ident(lhs.trim_start()).to_string();
+            let declared = lhs.split_once(':').map(|(_, ty)| Type::parse(ty));
+            if let (Some(expected), Some(rhs)) = (&declared, rhs) {
+                if let Some(actual) = env.check(expected, rhs) {
+                    let (start, end) = expr_range(line, rhs);
+                    diagnostics.push(mismatch(line_num, start, end, expected, &actual));
+                }
+            }
+
+            let ty = declared
+                .or_else(|| rhs.map(|rhs| env.infer(rhs)))
+                .unwrap_or(Type::Unknown);
+            if !name.is_empty() {
+                if current_return.is_some() {
+                    env.local_vars.insert(name, ty);
+                } else {
+                    env.script_vars.insert(name, ty);
+                }
+            }
+            if let Some(rhs) = rhs {
+                check_calls(&env, line, rhs, line_num, &mut diagnostics);
+            }
+            continue;
+        }
+
+        // return statements
+        if let Some(expr) = trimmed.strip_prefix("return") {
+            if let Some(expected) = &current_return {
+                let expr_trimmed = expr.trim();
+                if !expr_trimmed.is_empty() && expr.starts_with(char::is_whitespace) {
+                    let actual = if *expected == Type::Void {
+                        Some(env.infer(expr_trimmed))
+                    } else {
+                        env.check(expected, expr_trimmed)
+                    };
+                    if let Some(actual) = actual {
+                        let (start, end) = expr_range(line, expr_trimmed);
+                        diagnostics.push(mismatch(line_num, start, end, expected, &actual));
+                    }
+                    check_calls(&env, line, expr_trimmed, line_num, &mut diagnostics);
+                }
+            }
+            continue;
+        }
+
+        // Plain assignment: `name = expr`
+        let name = take_ident(trimmed);
+        if !name.is_empty() {
+            let after = trimmed[name.len()..].trim_start();
+            if let Some(rhs) = after.strip_prefix('=').filter(|r| !r.starts_with('=')) {
+                if let Some(expected) = env.var_type(name).cloned() {
+                    if let Some(actual) = env.check(&expected, rhs) {
+                        let (start, end) = expr_range(line, rhs);
+                        diagnostics.push(mismatch(line_num, start, end, &expected, &actual));
+                    }
+                }
+                check_calls(&env, line, rhs, line_num, &mut diagnostics);
+                continue;
+            }
+        }
+
+        check_calls(&env, line, code, line_num, &mut diagnostics);
+    }
+
+    diagnostics
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn messages(source: &str) -> Vec<(u32, String)> {
+        collect_type_mismatches(source)
+            .into_iter()
+            .map(|d| (d.range.start.line, d.message))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_type() {
+        assert_eq!(Type::parse("number"), Type::Number);
+        assert_eq!(
+            Type::parse("list<dict<string>>"),
+            Type::List(Box::new(Type::Dict(Box::new(Type::String))))
+        );
+        assert_eq!(Type::parse("func(number): string"), Type::Func);
+        assert_eq!(Type::parse("MyClass"), Type::Named("MyClass".to_string()));
+    }
+
+    #[test]
+    fn test_infer_literals() {
+        let env = Env::default();
+        assert_eq!(env.infer("42"), Type::Number);
+        assert_eq!(env.infer("-0x1F"), Type::Number);
+        assert_eq!(env.infer("1.5"), Type::Float);
+        assert_eq!(env.infer("'abc'"), Type::String);
+        assert_eq!(env.infer("true"), Type::Bool);
+        assert_eq!(env.infer("[1, 2]"), Type::List(Box::new(Type::Number)));
+        assert_eq!(env.infer("[1, 'a']"), Type::List(Box::new(Type::Any)));
+        assert_eq!(env.infer("{a: 'x'}"), Type::Dict(Box::new(Type::String)));
+        assert_eq!(env.infer("'a' .. 1"), Type::String);
+        assert_eq!(env.infer("strlen('a')"), Type::Number);
+        assert_eq!(env.infer("Unknown()"), Type::Unknown);
+    }
+
+    #[test]
+    fn test_declaration_mismatch() {
+        let source = "vim9script\nvar n: number = 'text'\nvar s: string = 'ok'\nvar f: float = 1\nvar b: bool = 0\nvar c = a == b\n";
+        assert_eq!(
+            messages(source),
+            vec![(
+                1,
+                "E1012: Type mismatch; expected number but got string".to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_assignment_mismatch() {
+        let source = "vim9script\nvar count = 0\ncount = 'x'\ncount = 2\n";
+        let result = messages(source);
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].0, 2);
+    }
+
+    #[test]
+    fn test_argument_and_return_mismatch() {
+        let source = "vim9script\n\
+                      def Greet(name: string, times: number = 1): string\n  \
+                        return 42\n\
+                      enddef\n\
+                      Greet(1)\n\
+                      Greet('a', 2)\n\
+                      def NoReturn()\n  \
+                        return 1\n\
+                      enddef\n";
+        let result = messages(source);
+        assert_eq!(
+            result,
+            vec![
+                (
+                    2,
+                    "E1012: Type mismatch; expected string but got number".to_string()
+                ),
+                (
+                    4,
+                    "E1012: Type mismatch; expected string but got number".to_string()
+                ),
+                (
+                    7,
+                    "E1012: Type mismatch; expected void but got number".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_legacy_script_not_checked() {
+        assert!(messages("let n = 1\ndef F(a: number)\nenddef\ncall F('x')\n").is_empty());
+    }
+}
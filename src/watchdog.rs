@@ -0,0 +1,167 @@
+//! Exit when the server is no longer needed
+//!
+//! - The client process: `InitializeParams.process_id` is polled, and the
+//!   server exits once it is gone (e.g., after an editor crash). Only done on
+//!   stdio, where the server belongs to a single client.
+//! - Idle timeout: `--idle-timeout=<SECS>` stops the server after no message
+//!   has been received for that long. Servers wait for [`shutdown()`], so that
+//!   they clean up (e.g., remove their socket file) before exiting.
+
+use std::pin::Pin;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use tokio::io::{AsyncRead, ReadBuf};
+use tokio::sync::Notify;
+
+use crate::log_debug;
+
+/// How often the client process is checked
+const POLL_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Whether the server exits with the client process
+static WATCH_CLIENT: AtomicBool = AtomicBool::new(false);
+
+/// Milliseconds from `START` to the last read from a client
+static LAST_ACTIVITY: AtomicU64 = AtomicU64::new(0);
+
+static START: OnceLock<Instant> = OnceLock::new();
+
+/// Notified once the server should stop
+static SHUTDOWN: Notify = Notify::const_new();
+
+fn elapsed_millis() -> u64 {
+    START.get_or_init(Instant::now).elapsed().as_millis() as u64
+}
+
+/// Exit with the client process given in `initialize` (for stdio)
+pub fn watch_client(enabled: bool) {
+    WATCH_CLIENT.store(enabled, Ordering::Relaxed);
+}
+
+/// Poll the client process in the background, exiting once it is gone
+pub fn spawn_process_watch(pid: u32) {
+    if !WATCH_CLIENT.load(Ordering::Relaxed) {
+        return;
+    }
+    log_debug!("watching client process {}", pid);
+    tokio::spawn(async move {
+        loop {
+            tokio::time::sleep(POLL_INTERVAL).await;
+            let alive = tokio::task::spawn_blocking(move || process_alive(pid))
+                .await
+                .unwrap_or(true);
+            if !alive {
+                log_debug!("client process {} is gone, exiting", pid);
+                std::process::exit(1);
+            }
+        }
+    });
+}
+
+/// Wait until the server should stop (after the idle timeout)
+pub async fn shutdown() {
+    SHUTDOWN.notified().await;
+}
+
+/// Signal [`shutdown()`] in the background once no message has been read for
+/// `timeout`
+pub fn spawn_idle_timeout(timeout: Duration) {
+    LAST_ACTIVITY.store(elapsed_millis(), Ordering::Relaxed);
+    tokio::spawn(async move {
+        loop {
+            let idle = Duration::from_millis(
+                elapsed_millis().saturating_sub(LAST_ACTIVITY.load(Ordering::Relaxed)),
+            );
+            if idle >= timeout {
+                log_debug!("idle for {:?}, shutting down", idle);
+                // Stores a permit if the server isn't waiting yet
+                SHUTDOWN.notify_one();
+                return;
+            }
+            tokio::time::sleep(timeout - idle).await;
+        }
+    });
+}
+
+/// Whether a process is running
+#[cfg(unix)]
+fn process_alive(pid: u32) -> bool {
+    let proc = std::path::Path::new("/proc");
+    if proc.join("self").exists() {
+        return proc.join(pid.to_string()).exists();
+    }
+    // `kill -0` checks the process without sending a signal
+    std::process::Command::new("kill")
+        .args(["-0", &pid.to_string()])
+        .stderr(std::process::Stdio::null())
+        .status()
+        .map_or(true, |status| status.success())
+}
+
+/// Whether a process is running
+#[cfg(windows)]
+fn process_alive(pid: u32) -> bool {
+    std::process::Command::new("tasklist")
+        .args(["/NH", "/FO", "CSV", "/FI", &format!("PID eq {pid}")])
+        .output()
+        .map_or(true, |output| {
+            tasklist_has_pid(&String::from_utf8_lossy(&output.stdout), pid)
+        })
+}
+
+/// Whether `tasklist /FO CSV` output lists a process ID
+///
+/// Rows are `"name","pid","session","number","memory"`; without a match,
+/// tasklist prints an informational message instead.
+#[cfg(any(windows, test))]
+fn tasklist_has_pid(output: &str, pid: u32) -> bool {
+    let pid = pid.to_string();
+    output.lines().any(|line| {
+        line.trim()
+            .strip_prefix('"')
+            .and_then(|row| row.split("\",\"").nth(1))
+            .is_some_and(|field| field == pid)
+    })
+}
+
+/// A reader recording when messages are received, for the idle timeout
+pub struct ActivityReader<R>(pub R);
+
+impl<R: AsyncRead + Unpin> AsyncRead for ActivityReader<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let filled = buf.filled().len();
+        let poll = Pin::new(&mut self.0).poll_read(cx, buf);
+        if buf.filled().len() > filled {
+            LAST_ACTIVITY.store(elapsed_millis(), Ordering::Relaxed);
+        }
+        poll
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_process_alive() {
+        assert!(process_alive(std::process::id()));
+        assert!(!process_alive(u32::MAX - 1));
+    }
+
+    #[test]
+    fn test_tasklist_has_pid() {
+        let output = "\r\n\"hjkls.exe\",\"1234\",\"Console\",\"1\",\"12,345 K\"\r\n";
+        assert!(tasklist_has_pid(output, 1234));
+        assert!(!tasklist_has_pid(output, 123));
+        assert!(!tasklist_has_pid(output, 1));
+        let none = "INFO: No tasks are running which match the specified criteria.\r\n";
+        assert!(!tasklist_has_pid(none, 1234));
+    }
+}
@@ -0,0 +1,6 @@
+" Calls global functions defined in sample.vim
+" Press gd on Hello to jump to its definition in another file
+call Hello('World')
+
+" Press gd on g:my_var to jump to its assignment in sample.vim
+echo g:my_var
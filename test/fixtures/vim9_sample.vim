@@ -0,0 +1,13 @@
+vim9script
+# Sample Vim9 script for E2E tests
+
+class Counter
+  var count: number = 0
+
+  def Increment(): number
+    this.count += 1
+    return this.count
+  enddef
+endclass
+
+var counter = Counter.new()
@@ -61,13 +61,14 @@ function M.get_completions(child)
   return child.lua_get("_G._test_result")
 end
 
---- Get definition location at current cursor position
+--- Get the location returned by a definition-like request at current cursor position
 ---@param child table MiniTest child process
----@return table|nil Definition location {uri, line} or nil
-function M.get_definition(child)
-  child.lua([[
+---@param method string LSP method (e.g., "textDocument/definition")
+---@return table|nil Location {uri, line, character, selection_character} or nil
+local function get_location(child, method)
+  child.lua(string.format([[
     local params = vim.lsp.util.make_position_params()
-    local results = vim.lsp.buf_request_sync(0, 'textDocument/definition', params, 3000)
+    local results = vim.lsp.buf_request_sync(0, %q, params, 3000)
     _G._test_result = nil
     if results and results[1] and results[1].result then
       local result = results[1].result
@@ -77,16 +78,34 @@ function M.get_definition(child)
         loc = result[1]
       end
       if loc and (loc.uri or loc.targetUri) then
+        local range = loc.range or loc.targetRange
+        local selection = loc.range or loc.targetSelectionRange
         _G._test_result = {
           uri = loc.uri or loc.targetUri,
-          line = (loc.range or loc.targetRange).start.line,
+          line = range.start.line,
+          character = range.start.character,
+          selection_character = selection.start.character,
         }
       end
     end
-  ]])
+  ]], method))
   return normalize(child.lua_get("_G._test_result"))
 end
 
+--- Get definition location at current cursor position
+---@param child table MiniTest child process
+---@return table|nil Definition location {uri, line, character} or nil
+function M.get_definition(child)
+  return get_location(child, "textDocument/definition")
+end
+
+--- Get declaration location at current cursor position
+---@param child table MiniTest child process
+---@return table|nil Declaration location {uri, line, character} or nil
+function M.get_declaration(child)
+  return get_location(child, "textDocument/declaration")
+end
+
 --- Get hover content at current cursor position
 ---@param child table MiniTest child process
 ---@return string|nil Hover content or nil
@@ -113,6 +113,26 @@ T["completion"]["context: command at line start"] = function()
   child.stop()
 end
 
+T["completion"]["vim9: declaration commands without legacy let"] = function()
+  local child = H.create_child()
+  child.cmd("edit " .. _G.TEST_PATHS.fixtures_dir .. "/vim9_sample.vim")
+  H.wait_for_lsp(child)
+
+  -- Type at line start (command context) in a vim9script file
+  child.cmd("normal! G")
+  child.cmd("normal! o")
+  child.type_keys("le")
+
+  local completions = H.get_completions(child)
+
+  -- :let is not available in Vim9 script
+  local has_let = vim.tbl_contains(completions, "let")
+
+  MiniTest.expect.equality(has_let, false, "Expected NO 'let' command in vim9script completions")
+
+  child.stop()
+end
+
 T["completion"]["context: function in expression"] = function()
   local child = H.create_child()
   child.cmd("edit " .. _G.TEST_PATHS.fixtures_dir .. "/sample.vim")
@@ -224,4 +244,29 @@ T["completion"]["context: has() features"] = function()
   child.stop()
 end
 
+T["completion"]["context: syntax sub-commands and arguments"] = function()
+  local child = H.create_child()
+  child.cmd("edit " .. _G.TEST_PATHS.fixtures_dir .. "/sample.vim")
+  H.wait_for_lsp(child)
+
+  -- Type after syntax (sub-command context)
+  child.cmd("normal! G")
+  child.cmd("normal! o")
+  child.type_keys("syntax reg")
+
+  local completions = H.get_completions(child)
+  local has_region = vim.tbl_contains(completions, "region")
+  MiniTest.expect.equality(has_region, true, "Expected 'region' sub-command after syntax")
+
+  -- Type region arguments (argument context)
+  child.cmd("normal! o")
+  child.type_keys("syntax region myString start=/'/ end=/'/ cont")
+
+  completions = H.get_completions(child)
+  local has_contains = vim.tbl_contains(completions, "contains=")
+  MiniTest.expect.equality(has_contains, true, "Expected 'contains=' argument for syntax region")
+
+  child.stop()
+end
+
 return T
@@ -37,6 +37,31 @@ T["definition"]["jumps to function definition in same file"] = function()
   child.stop()
 end
 
+T["definition"]["declaration points at the function name"] = function()
+  local child = H.create_child()
+  child.cmd("edit " .. _G.TEST_PATHS.fixtures_dir .. "/sample.vim")
+  H.wait_for_lsp(child)
+
+  -- Go to line 17 where Hello is called: call Hello(a:who)
+  child.cmd("normal! 17G")
+  child.cmd("normal! 0f(b") -- Position on "Hello"
+
+  local def = H.get_definition(child)
+  local decl = H.get_declaration(child)
+
+  MiniTest.expect.equality(decl ~= nil, true, "Expected declaration to be found")
+  if def and decl then
+    -- Definition links span the whole function and select its name,
+    -- declaration is only its name
+    MiniTest.expect.equality(def.character, 0, "Expected definition to start at `function!`")
+    MiniTest.expect.equality(def.selection_character, 10, "Expected definition to select the name")
+    MiniTest.expect.equality(decl.line, 5, "Expected declaration on line 6")
+    MiniTest.expect.equality(decl.character, 10, "Expected declaration at the function name")
+  end
+
+  child.stop()
+end
+
 T["definition"]["jumps to variable definition"] = function()
   local child = H.create_child()
   child.cmd("edit " .. _G.TEST_PATHS.fixtures_dir .. "/sample.vim")
@@ -84,6 +109,54 @@ T["definition"]["jumps to autoload function in different file"] = function()
   child.stop()
 end
 
+T["definition"]["jumps to global function in different file"] = function()
+  local child = H.create_child()
+  child.cmd("edit " .. _G.TEST_PATHS.fixtures_dir .. "/plugin/cross_file.vim")
+  H.wait_for_lsp(child)
+
+  -- Wait for indexing
+  child.lua("vim.wait(2000)")
+
+  -- Go to line 3: call Hello('World')
+  child.cmd("normal! 3G")
+  child.cmd("normal! 0fH") -- Position on "Hello"
+
+  local def = H.get_definition(child)
+
+  MiniTest.expect.equality(def ~= nil, true, "Expected definition to be found for global function")
+  if def then
+    local is_sample_file = def.uri:match("sample%.vim") ~= nil
+    MiniTest.expect.equality(is_sample_file, true, "Expected definition in sample.vim")
+    MiniTest.expect.equality(def.line, 5, "Expected definition on line 6")
+  end
+
+  child.stop()
+end
+
+T["definition"]["jumps to global variable assigned in different file"] = function()
+  local child = H.create_child()
+  child.cmd("edit " .. _G.TEST_PATHS.fixtures_dir .. "/plugin/cross_file.vim")
+  H.wait_for_lsp(child)
+
+  -- Wait for indexing
+  child.lua("vim.wait(2000)")
+
+  -- Go to line 6: echo g:my_var
+  child.cmd("normal! 6G")
+  child.cmd("normal! 0fm") -- Position on "my_var"
+
+  local def = H.get_definition(child)
+
+  MiniTest.expect.equality(def ~= nil, true, "Expected definition to be found for g:my_var")
+  if def then
+    local is_sample_file = def.uri:match("sample%.vim") ~= nil
+    MiniTest.expect.equality(is_sample_file, true, "Expected definition in sample.vim")
+    MiniTest.expect.equality(def.line, 25, "Expected definition on line 26")
+  end
+
+  child.stop()
+end
+
 T["definition"]["returns nil for builtin functions"] = function()
   local child = H.create_child()
   child.cmd("edit " .. _G.TEST_PATHS.fixtures_dir .. "/sample.vim")
@@ -179,6 +179,62 @@ T["diagnostics"]["detects undefined function calls"] = function()
   child.stop()
 end
 
+T["diagnostics"]["re-lints callers when a definition is saved"] = function()
+  local child = H.create_child()
+  local dir = child.fn.tempname()
+  child.fn.mkdir(dir, "p")
+  child.fn.writefile({ "call RelintTarget()" }, dir .. "/caller.vim")
+  child.fn.writefile({ '" RelintTarget is not defined yet' }, dir .. "/target.vim")
+
+  child.cmd("edit " .. dir .. "/caller.vim")
+  H.wait_for_lsp(child)
+  H.wait_for_diagnostics(child)
+  local caller_buf = child.api.nvim_get_current_buf()
+
+  local undefined = find_diagnostic_at_line(H.get_diagnostics(child), 0)
+  MiniTest.expect.equality(undefined ~= nil, true, "Expected undefined function warning for RelintTarget")
+
+  -- Define the function in another file and save it
+  child.cmd("split " .. dir .. "/target.vim")
+  H.wait_for_lsp(child)
+  child.api.nvim_buf_set_lines(0, 0, -1, false, { "function! RelintTarget() abort", "endfunction" })
+  child.cmd("write")
+
+  child.lua("vim.wait(2000, function() return #vim.diagnostic.get(" .. caller_buf .. ") == 0 end, 100)")
+  local diagnostics = child.lua_get("vim.diagnostic.get(" .. caller_buf .. ")")
+  MiniTest.expect.equality(#diagnostics, 0, "Expected the caller to be re-linted without re-editing it")
+
+  child.stop()
+end
+
+T["diagnostics"]["applies changed settings to open documents"] = function()
+  local child = H.create_child()
+  local dir = child.fn.tempname()
+  child.fn.mkdir(dir, "p")
+  child.fn.writefile({ "normal j" }, dir .. "/settings.vim")
+
+  child.cmd("edit " .. dir .. "/settings.vim")
+  H.wait_for_lsp(child)
+  H.wait_for_diagnostics(child)
+  local buf = child.api.nvim_get_current_buf()
+
+  local normal_bang = find_diagnostic_at_line(H.get_diagnostics(child), 0)
+  MiniTest.expect.equality(normal_bang ~= nil, true, "Expected normal_bang warning before the change")
+
+  -- The server pulls the `hjkls` section of client.settings
+  child.lua([[
+    local client = vim.lsp.get_clients({ bufnr = 0 })[1]
+    client.settings = { hjkls = { lint = { suspicious = false } } }
+    client:notify("workspace/didChangeConfiguration", { settings = client.settings })
+  ]])
+
+  child.lua("vim.wait(2000, function() return #vim.diagnostic.get(" .. buf .. ") == 0 end, 100)")
+  local diagnostics = child.lua_get("vim.diagnostic.get(" .. buf .. ")")
+  MiniTest.expect.equality(#diagnostics, 0, "Expected suspicious warnings to be disabled by the new settings")
+
+  child.stop()
+end
+
 T["diagnostics"]["no false positives for dynamic function calls"] = function()
   local child = H.create_child()
   child.cmd("edit " .. _G.TEST_PATHS.fixtures_dir .. "/sample.vim")
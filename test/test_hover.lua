@@ -82,4 +82,27 @@ T["hover"]["shows autoload function info"] = function()
   child.stop()
 end
 
+T["hover"]["shows defining file of autoload function"] = function()
+  local child = H.create_child()
+  child.cmd("edit " .. _G.TEST_PATHS.fixtures_dir .. "/sample.vim")
+  H.wait_for_lsp(child)
+
+  -- Wait for indexing
+  child.lua("vim.wait(2000)")
+
+  -- Go to line 47 where autoload function is called
+  child.cmd("normal! 47G")
+  child.cmd("normal! 0fm") -- Position on "myplugin"
+
+  local hover = H.get_hover(child)
+
+  MiniTest.expect.equality(hover ~= nil, true, "Expected hover content for autoload function")
+  if hover then
+    local has_location = hover:match("Defined in `.*autoload/myplugin/util%.vim:%d+`") ~= nil
+    MiniTest.expect.equality(has_location, true, "Expected defining file in hover content")
+  end
+
+  child.stop()
+end
+
 return T